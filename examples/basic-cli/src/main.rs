@@ -96,8 +96,8 @@ mod handler {
             PacketRef::MonVer(packet) => {
                 println!(
                     "SW version: {} HW version: {}; Extensions: {:?}",
-                    packet.software_version(),
-                    packet.hardware_version(),
+                    packet.software_version().unwrap_or_default(),
+                    packet.hardware_version().unwrap_or_default(),
                     packet.extension().collect::<Vec<&str>>()
                 );
                 println!("{packet:?}");
@@ -143,8 +143,8 @@ mod handler {
             PacketRef::MonVer(packet) => {
                 println!(
                     "SW version: {} HW version: {}; Extensions: {:?}",
-                    packet.software_version(),
-                    packet.hardware_version(),
+                    packet.software_version().unwrap_or_default(),
+                    packet.hardware_version().unwrap_or_default(),
                     packet.extension().collect::<Vec<&str>>()
                 );
                 println!("{packet:?}");
@@ -190,8 +190,8 @@ mod handler {
             PacketRef::MonVer(packet) => {
                 println!(
                     "SW version: {} HW version: {}; Extensions: {:?}",
-                    packet.software_version(),
-                    packet.hardware_version(),
+                    packet.software_version().unwrap_or_default(),
+                    packet.hardware_version().unwrap_or_default(),
                     packet.extension().collect::<Vec<&str>>()
                 );
                 println!("{packet:?}");
@@ -237,8 +237,8 @@ mod handler {
             PacketRef::MonVer(packet) => {
                 println!(
                     "SW version: {} HW version: {}; Extensions: {:?}",
-                    packet.software_version(),
-                    packet.hardware_version(),
+                    packet.software_version().unwrap_or_default(),
+                    packet.hardware_version().unwrap_or_default(),
                     packet.extension().collect::<Vec<&str>>()
                 );
                 println!("{packet:?}");
@@ -284,8 +284,8 @@ mod handler {
             PacketRef::MonVer(packet) => {
                 println!(
                     "SW version: {} HW version: {}; Extensions: {:?}",
-                    packet.software_version(),
-                    packet.hardware_version(),
+                    packet.software_version().unwrap_or_default(),
+                    packet.hardware_version().unwrap_or_default(),
                     packet.extension().collect::<Vec<&str>>()
                 );
                 println!("{packet:?}");
@@ -161,8 +161,8 @@ impl PkgHandler {
                 debug!("{packet:?}");
                 info!(
                     "MonVer: SW version: {} HW version: {}; Extensions: {:?}",
-                    packet.software_version(),
-                    packet.hardware_version(),
+                    packet.software_version().unwrap_or_default(),
+                    packet.hardware_version().unwrap_or_default(),
                     packet.extension().collect::<Vec<&str>>()
                 );
             },
@@ -188,8 +188,8 @@ impl PkgHandler {
                 debug!("{packet:?}");
                 info!(
                     "MonVer: SW version: {} HW version: {}; Extensions: {:?}",
-                    packet.software_version(),
-                    packet.hardware_version(),
+                    packet.software_version().unwrap_or_default(),
+                    packet.hardware_version().unwrap_or_default(),
                     packet.extension().collect::<Vec<&str>>()
                 );
             },
@@ -210,8 +210,8 @@ impl PkgHandler {
                 debug!("{packet:?}");
                 info!(
                     "MonVer: SW version: {} HW version: {}; Extensions: {:?}",
-                    packet.software_version(),
-                    packet.hardware_version(),
+                    packet.software_version().unwrap_or_default(),
+                    packet.hardware_version().unwrap_or_default(),
                     packet.extension().collect::<Vec<&str>>()
                 );
             },
@@ -232,8 +232,8 @@ impl PkgHandler {
                 debug!("{packet:?}");
                 info!(
                     "MonVer: SW version: {} HW version: {}; Extensions: {:?}",
-                    packet.software_version(),
-                    packet.hardware_version(),
+                    packet.software_version().unwrap_or_default(),
+                    packet.hardware_version().unwrap_or_default(),
                     packet.extension().collect::<Vec<&str>>()
                 );
             },
@@ -0,0 +1,78 @@
+//! A receiver driven from an async runtime, the `tokio-device` binary
+//!
+//! The parser does no I/O, so the async version of [Device](ublox_device::Device) only
+//! replaces the reads and writes of the serial port with those of a tokio stream, e.g. a TCP
+//! connection to a serial server like ser2net.
+
+use std::{io, time::Duration};
+
+use tokio::{
+    io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt},
+    time::{timeout_at, Instant},
+};
+use ublox_device::{
+    ublox::{Parser, UbxPacket, UbxPacketRequest, UbxProtocol},
+    Error, Pollable,
+};
+
+pub struct AsyncDevice<S, P: UbxProtocol> {
+    stream: S,
+    parser: Parser<Vec<u8>, P>,
+}
+
+impl<S: AsyncRead + AsyncWrite + Unpin, P: UbxProtocol> AsyncDevice<S, P> {
+    pub fn new(stream: S) -> Self {
+        Self {
+            stream,
+            parser: Parser::new(Vec::new()),
+        }
+    }
+
+    pub async fn write_all(&mut self, data: &[u8]) -> io::Result<()> {
+        self.stream.write_all(data).await?;
+        self.stream.flush().await
+    }
+
+    /// Waits for data and calls `handler` with each packet in it, returning the number of bytes
+    /// read. Zero means the stream was closed.
+    pub async fn read_packets(&mut self, mut handler: impl FnMut(UbxPacket)) -> io::Result<usize> {
+        let mut buf = [0; 1240];
+        let len = self.stream.read(&mut buf).await?;
+        let mut it = self.parser.consume_ubx(&buf[..len]);
+        while let Some(result) = it.next() {
+            match result {
+                Ok(packet) => handler(packet),
+                Err(e) => eprintln!("Malformed packet, ignore it; cause {e}"),
+            }
+        }
+        Ok(len)
+    }
+
+    /// Requests message `M` and waits up to `timeout` for the response
+    pub async fn poll<M: Pollable>(&mut self, timeout: Duration) -> Result<M::Owned, Error> {
+        self.write_all(&UbxPacketRequest::request_for::<M>().into_packet_bytes())
+            .await?;
+
+        let deadline = Instant::now() + timeout;
+        loop {
+            let mut response = None;
+            let read = timeout_at(
+                deadline,
+                self.read_packets(|packet| {
+                    if response.is_none() {
+                        response = M::owned_from(&packet);
+                    }
+                }),
+            );
+            match read.await {
+                Ok(Ok(0)) => return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into()),
+                Ok(Ok(_)) => {},
+                Ok(Err(e)) => return Err(e.into()),
+                Err(_) => return Err(Error::Timeout(M::CLASS, M::ID)),
+            }
+            if let Some(response) = response {
+                return Ok(response);
+            }
+        }
+    }
+}
@@ -0,0 +1,74 @@
+//! RTK base station setup, the `base-station` binary
+//!
+//! The receiver surveys its antenna position in, then outputs RTCM3 corrections for rovers on
+//! UART1. This uses the configuration keys of generation 9 receivers (protocol 27 and later),
+//! e.g. the ZED-F9P.
+
+use std::time::{Duration, Instant};
+
+use ublox_device::{
+    ublox::{
+        cfg_tmode2::CfgTModeModes,
+        cfg_val::{keys, CfgItems, CfgLayerSet, CfgValSet},
+        tim_svin::{TimSvin, TimSvinOwned},
+        UbxProtocol,
+    },
+    Device, Error, Transport,
+};
+
+/// Survey-in settings, the survey ends once both limits are reached
+#[derive(Debug, Clone, Copy)]
+pub struct SurveyIn {
+    pub min_duration: Duration,
+    /// Position accuracy limit, in the units of `keys::TModeSvInAccLimit`
+    pub accuracy_limit: u32,
+}
+
+/// Starts the survey-in and enables the RTCM3 messages rovers need: the antenna position
+/// (1005), the GPS, GLONASS, Galileo and BeiDou observations (1074, 1084, 1094, 1124) and the
+/// GLONASS code-phase biases (1230)
+pub fn configure<P: UbxProtocol, T: Transport>(
+    device: &mut Device<P, T>,
+    survey_in: SurveyIn,
+) -> Result<(), Error> {
+    let min_duration = u32::try_from(survey_in.min_duration.as_secs()).unwrap_or(u32::MAX);
+    let items = CfgItems::new()
+        .set(keys::TModeModeDef, CfgTModeModes::SurveyIn)
+        .set(keys::TModeSvInMinDur, min_duration)
+        .set(keys::TModeSvInAccLimit, survey_in.accuracy_limit)
+        .set(keys::Uart1OutProtRtcm3x, true)
+        .set(keys::MsgOutRtcm3Xtype1005Uart1, 1)
+        .set(keys::MsgOutRtcm3Xtype1074Uart1, 1)
+        .set(keys::MsgOutRtcm3Xtype1084Uart1, 1)
+        .set(keys::MsgOutRtcm3Xtype1094Uart1, 1)
+        .set(keys::MsgOutRtcm3Xtype1124Uart1, 1)
+        .set(keys::MsgOutRtcm3Xtype1230Uart1, 5);
+    let frames = items
+        .to_packets(CfgLayerSet::RAM)
+        .expect("the keys can be set in RAM");
+    for frame in frames {
+        device.write_all(&frame)?;
+        device.wait_for_ack::<CfgValSet>()?;
+    }
+    Ok(())
+}
+
+/// Polls UBX-TIM-SVIN every `interval` until the survey-in completed with a valid position,
+/// giving up after `timeout`. `report` is called with each status received.
+pub fn wait_for_survey_in<P: UbxProtocol, T: Transport>(
+    device: &mut Device<P, T>,
+    interval: Duration,
+    timeout: Duration,
+    mut report: impl FnMut(&TimSvinOwned),
+) -> Result<Option<TimSvinOwned>, Error> {
+    let deadline = Instant::now() + timeout;
+    while Instant::now() < deadline {
+        let status = device.poll::<TimSvin>(interval)?;
+        report(&status);
+        if status.valid() == 1 && status.active() == 0 {
+            return Ok(Some(status));
+        }
+        std::thread::sleep(interval);
+    }
+    Ok(None)
+}
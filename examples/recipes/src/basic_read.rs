@@ -0,0 +1,44 @@
+//! Reading the packets a receiver sends and printing them, the `serial-read` binary
+
+use std::io::{self, Write};
+
+use ublox_device::{
+    ublox::{
+        cfg_msg::{CfgMsgAllPorts, CfgMsgAllPortsBuilder},
+        UbxProtocol,
+    },
+    Device, Error, Transport,
+};
+
+/// Enables UBX-NAV-PVT on the UART and USB ports and waits for the receiver to acknowledge it
+pub fn enable_nav_pvt<P: UbxProtocol, T: Transport>(
+    device: &mut Device<P, T>,
+) -> Result<(), Error> {
+    // The NavPvt types differ between protocols, but not their class and message id
+    let enable = CfgMsgAllPortsBuilder {
+        msg_class: 0x01,
+        msg_id: 0x07,
+        // Once per navigation solution on UART1, UART2 and USB
+        rates: [0, 1, 1, 1, 0, 0],
+    };
+    device.write_all(&enable.into_packet_bytes())?;
+    device.wait_for_ack::<CfgMsgAllPorts>()
+}
+
+/// Writes one line per received packet to `out` until `limit` packets were printed or no more
+/// data arrives, returning the number of packets printed
+pub fn print_packets<P: UbxProtocol, T: Transport>(
+    device: &mut Device<P, T>,
+    out: &mut impl Write,
+    limit: usize,
+) -> io::Result<usize> {
+    let mut printed = 0;
+    let mut result = Ok(());
+    device.on_data_available(|packet| {
+        if printed < limit && result.is_ok() {
+            result = writeln!(out, "{packet:?}");
+            printed += 1;
+        }
+    })?;
+    result.map(|()| printed)
+}
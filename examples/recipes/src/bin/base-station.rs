@@ -0,0 +1,55 @@
+use std::time::Duration;
+
+use clap::{value_parser, Arg};
+use recipes::{
+    base_station::{self, SurveyIn},
+    Proto,
+};
+use ublox_device::{cli, Device};
+
+fn main() -> anyhow::Result<()> {
+    let cli = cli::CommandBuilder::default()
+        .build()
+        .about("Surveys the antenna position in and outputs RTCM3 corrections on UART1")
+        .name("base-station")
+        .arg(
+            Arg::new("min-duration")
+                .long("min-duration")
+                .default_value("300")
+                .value_parser(value_parser!(u64))
+                .help("Minimum survey-in duration in seconds"),
+        )
+        .arg(
+            Arg::new("accuracy-limit")
+                .long("accuracy-limit")
+                .default_value("20000")
+                .value_parser(value_parser!(u32))
+                .help("Survey-in position accuracy limit in 0.1 mm"),
+        );
+    let matches = cli.clone().get_matches();
+    let survey_in = SurveyIn {
+        min_duration: Duration::from_secs(*matches.get_one::<u64>("min-duration").unwrap()),
+        accuracy_limit: *matches.get_one::<u32>("accuracy-limit").unwrap(),
+    };
+    let port = cli::Command::serialport(cli)?;
+    let mut device: Device<Proto> = Device::new(port);
+
+    base_station::configure(&mut device, survey_in)?;
+    let status = base_station::wait_for_survey_in(
+        &mut device,
+        Duration::from_secs(1),
+        survey_in.min_duration * 10,
+        |status| {
+            eprintln!(
+                "Survey-in: {} s, {} observations",
+                status.dur(),
+                status.obs()
+            );
+        },
+    )?;
+    match status {
+        Some(_) => println!("Survey-in completed, RTCM3 output enabled on UART1"),
+        None => anyhow::bail!("Survey-in did not complete"),
+    }
+    Ok(())
+}
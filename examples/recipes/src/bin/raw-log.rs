@@ -0,0 +1,40 @@
+use std::{fs::File, io::BufWriter, path::PathBuf};
+
+use clap::{value_parser, Arg};
+use recipes::{raw_log::RawLogger, Proto};
+use ublox_device::cli;
+
+fn main() -> anyhow::Result<()> {
+    let cli = cli::CommandBuilder::default()
+        .build()
+        .about("Logs everything the receiver sends to a file")
+        .name("raw-log")
+        .arg(
+            Arg::new("output")
+                .long("output")
+                .short('o')
+                .required(true)
+                .value_parser(value_parser!(PathBuf))
+                .help("File to write the log to"),
+        );
+    let output: PathBuf = cli
+        .clone()
+        .get_matches()
+        .get_one::<PathBuf>("output")
+        .cloned()
+        .expect("output is required");
+    let mut port = cli::Command::serialport(cli)?;
+
+    let mut logger = RawLogger::<_, Proto>::new(BufWriter::new(File::create(&output)?));
+    loop {
+        if logger.log_from(&mut port)? > 0 {
+            let stats = logger.stats();
+            eprint!(
+                "\rUBX: {} RTCM3: {} NMEA: {}",
+                stats.ubx_frames_total(),
+                stats.rtcm_frames,
+                stats.nmea_sentences
+            );
+        }
+    }
+}
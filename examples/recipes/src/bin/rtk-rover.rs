@@ -0,0 +1,64 @@
+use std::{net::TcpStream, time::Duration};
+
+use clap::Arg;
+use recipes::{
+    ntrip::{self, NtripRequest},
+    rtk_rover::{self, CarrierSolution},
+    Proto,
+};
+use ublox_device::{cli, Device};
+
+fn main() -> anyhow::Result<()> {
+    let cli = cli::CommandBuilder::default()
+        .build()
+        .about("Feeds RTCM3 corrections from an NTRIP caster to the receiver")
+        .name("rtk-rover")
+        .arg(
+            Arg::new("caster")
+                .long("caster")
+                .required(true)
+                .help("Host and port of the NTRIP caster, e.g. rtk2go.com:2101"),
+        )
+        .arg(
+            Arg::new("mountpoint")
+                .long("mountpoint")
+                .required(true)
+                .help("Mountpoint of the correction stream"),
+        )
+        .arg(
+            Arg::new("user")
+                .long("user")
+                .help("User name for the caster, given as user:password"),
+        );
+    let matches = cli.clone().get_matches();
+    let caster = matches.get_one::<String>("caster").unwrap();
+    let credentials = matches
+        .get_one::<String>("user")
+        .and_then(|user| user.split_once(':'));
+    let request = NtripRequest {
+        host: caster.split(':').next().unwrap_or(caster),
+        mountpoint: matches.get_one::<String>("mountpoint").unwrap(),
+        credentials,
+    };
+
+    let stream = TcpStream::connect(caster)?;
+    stream.set_read_timeout(Some(Duration::from_millis(100)))?;
+    let mut corrections = ntrip::connect(stream, &request)?;
+    println!("Connected to {caster}/{}", request.mountpoint);
+
+    let port = cli::Command::serialport(cli)?;
+    let mut device: Device<Proto> = Device::new(port);
+    let mut last = None;
+    loop {
+        rtk_rover::step(&mut device, &mut corrections, |status| {
+            if last != Some(status) {
+                match status {
+                    CarrierSolution::None => println!("No carrier phase solution"),
+                    CarrierSolution::Float => println!("RTK float"),
+                    CarrierSolution::Fixed => println!("RTK fixed"),
+                }
+                last = Some(status);
+            }
+        })?;
+    }
+}
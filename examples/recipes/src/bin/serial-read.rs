@@ -0,0 +1,19 @@
+use std::io;
+
+use recipes::{basic_read, Proto};
+use ublox_device::{cli, Device};
+
+fn main() -> anyhow::Result<()> {
+    let cli = cli::CommandBuilder::default()
+        .build()
+        .about("Enables UBX-NAV-PVT and prints the packets the receiver sends")
+        .name("serial-read");
+    let port = cli::Command::serialport(cli)?;
+    let mut device: Device<Proto> = Device::new(port);
+
+    basic_read::enable_nav_pvt(&mut device)?;
+    let mut stdout = io::stdout().lock();
+    loop {
+        basic_read::print_packets(&mut device, &mut stdout, usize::MAX)?;
+    }
+}
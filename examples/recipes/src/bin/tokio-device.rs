@@ -0,0 +1,31 @@
+use std::time::Duration;
+
+use clap::{Arg, Command};
+use recipes::{async_device::AsyncDevice, Proto};
+use tokio::net::TcpStream;
+use ublox_device::ublox::mon_ver::MonVer;
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() -> anyhow::Result<()> {
+    let matches = Command::new("tokio-device")
+        .about("Reads a receiver exposed over TCP, e.g. by ser2net, from a tokio runtime")
+        .arg(
+            Arg::new("address")
+                .required(true)
+                .help("Host and port to connect to, e.g. localhost:4000"),
+        )
+        .get_matches();
+    let address = matches.get_one::<String>("address").unwrap();
+
+    let stream = TcpStream::connect(address).await?;
+    let mut device = AsyncDevice::<_, Proto>::new(stream);
+
+    let version = device.poll::<MonVer>(Duration::from_secs(1)).await?;
+    println!(
+        "SW version: {} HW version: {}",
+        version.software_version().unwrap_or_default(),
+        version.hardware_version().unwrap_or_default()
+    );
+    while device.read_packets(|packet| println!("{packet:?}")).await? > 0 {}
+    Ok(())
+}
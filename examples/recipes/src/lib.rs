@@ -0,0 +1,75 @@
+//! Canonical starting points for common tasks with a uBlox receiver.
+//!
+//! Each recipe is a function over a [Device](ublox_device::Device) or a byte stream, so that
+//! the binaries in `src/bin` run it on a serial port and the tests on a
+//! [MockTransport](ublox_device::mock::MockTransport).
+
+use ublox_device::ublox;
+
+/// Evaluates to `Some($body)` if `$packet`, a [UbxPacket](ublox::UbxPacket), is a
+/// `PacketRef::$variant` of any enabled protocol, with the packet bound to `$binding`
+macro_rules! match_packet {
+    ($packet:expr, $variant:ident($binding:ident) => $body:expr) => {
+        match $packet {
+            #[cfg(feature = "ubx_proto14")]
+            $crate::ublox::UbxPacket::Proto14($crate::ublox::proto14::PacketRef::$variant(
+                $binding,
+            )) => Some($body),
+            #[cfg(feature = "ubx_proto23")]
+            $crate::ublox::UbxPacket::Proto23($crate::ublox::proto23::PacketRef::$variant(
+                $binding,
+            )) => Some($body),
+            #[cfg(feature = "ubx_proto27")]
+            $crate::ublox::UbxPacket::Proto27($crate::ublox::proto27::PacketRef::$variant(
+                $binding,
+            )) => Some($body),
+            #[cfg(feature = "ubx_proto31")]
+            $crate::ublox::UbxPacket::Proto31($crate::ublox::proto31::PacketRef::$variant(
+                $binding,
+            )) => Some($body),
+            #[cfg(feature = "ubx_proto33")]
+            $crate::ublox::UbxPacket::Proto33($crate::ublox::proto33::PacketRef::$variant(
+                $binding,
+            )) => Some($body),
+            #[allow(unreachable_patterns, reason = "Not all protocols may be enabled")]
+            _ => None,
+        }
+    };
+}
+
+pub mod async_device;
+pub mod base_station;
+pub mod basic_read;
+pub mod ntrip;
+pub mod raw_log;
+pub mod rtk_rover;
+
+/// Use proto23 if enabled, otherwise use proto27 if enabled, otherwise use proto31, otherwise use proto33, otherwise use proto14
+#[cfg(feature = "ubx_proto23")]
+pub type Proto = ublox::proto23::Proto23;
+#[cfg(all(feature = "ubx_proto27", not(feature = "ubx_proto23")))]
+pub type Proto = ublox::proto27::Proto27;
+#[cfg(all(
+    feature = "ubx_proto31",
+    not(any(feature = "ubx_proto23", feature = "ubx_proto27"))
+))]
+pub type Proto = ublox::proto31::Proto31;
+#[cfg(all(
+    feature = "ubx_proto33",
+    not(any(
+        feature = "ubx_proto23",
+        feature = "ubx_proto27",
+        feature = "ubx_proto31",
+    ))
+))]
+pub type Proto = ublox::proto33::Proto33;
+#[cfg(all(
+    feature = "ubx_proto14",
+    not(any(
+        feature = "ubx_proto23",
+        feature = "ubx_proto27",
+        feature = "ubx_proto31",
+        feature = "ubx_proto33",
+    ))
+))]
+pub type Proto = ublox::proto14::Proto14;
@@ -0,0 +1,113 @@
+//! Minimal NTRIP client receiving RTCM3 corrections from a caster, used by the `rtk-rover`
+//! binary
+//!
+//! Only the request for a single mountpoint is implemented, casters answer it with
+//! `ICY 200 OK` (NTRIP v1) or `HTTP/1.x 200 OK` (NTRIP v2) followed by the corrections.
+
+use std::io::{self, BufRead, BufReader, Read, Write};
+
+/// The stream requested from the caster
+#[derive(Debug, Clone)]
+pub struct NtripRequest<'a> {
+    /// Host name of the caster, sent in the `Host` header
+    pub host: &'a str,
+    pub mountpoint: &'a str,
+    /// User name and password, if the caster requires them
+    pub credentials: Option<(&'a str, &'a str)>,
+}
+
+impl NtripRequest<'_> {
+    /// The HTTP request for the mountpoint
+    pub fn to_request(&self) -> String {
+        let mut request = format!(
+            "GET /{} HTTP/1.0\r\nHost: {}\r\nUser-Agent: NTRIP ublox-rs\r\nNtrip-Version: Ntrip/2.0\r\n",
+            self.mountpoint, self.host
+        );
+        if let Some((user, password)) = self.credentials {
+            let token = base64(format!("{user}:{password}").as_bytes());
+            request.push_str(&format!("Authorization: Basic {token}\r\n"));
+        }
+        request.push_str("\r\n");
+        request
+    }
+}
+
+/// The corrections sent by the caster, following the response header
+pub struct NtripStream<S: Read> {
+    inner: BufReader<S>,
+}
+
+impl<S: Read> Read for NtripStream<S> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.inner.read(buf)
+    }
+}
+
+/// Requests the mountpoint over `stream`, usually a TCP connection to the caster, and reads
+/// the response header. Fails if the caster refuses the request, e.g. with its source table
+/// for an unknown mountpoint.
+pub fn connect<S: Read + Write>(
+    mut stream: S,
+    request: &NtripRequest,
+) -> io::Result<NtripStream<S>> {
+    stream.write_all(request.to_request().as_bytes())?;
+    stream.flush()?;
+
+    let mut inner = BufReader::new(stream);
+    let mut status = String::new();
+    inner.read_line(&mut status)?;
+    let accepted = status.starts_with("ICY 200")
+        || (status.starts_with("HTTP/1.") && status.split(' ').nth(1) == Some("200"));
+    if !accepted {
+        return Err(io::Error::other(format!(
+            "Caster refused the request: {}",
+            status.trim_end()
+        )));
+    }
+
+    // NTRIP v1 casters answer with the status line only, v2 ones send headers until an
+    // empty line
+    if status.starts_with("HTTP/") {
+        loop {
+            let mut header = String::new();
+            if inner.read_line(&mut header)? == 0 || header.trim_end().is_empty() {
+                break;
+            }
+        }
+    }
+    Ok(NtripStream { inner })
+}
+
+/// Standard base64 encoding with padding, for the basic authentication header
+fn base64(data: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut encoded = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let bytes = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let bits = u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(char::from(ALPHABET[(bits >> (18 - 6 * i) & 0x3f) as usize]));
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn base64_pads_partial_chunks() {
+        assert_eq!(base64(b"user:pass"), "dXNlcjpwYXNz");
+        assert_eq!(base64(b"user:pas"), "dXNlcjpwYXM=");
+        assert_eq!(base64(b"user:pa"), "dXNlcjpwYQ==");
+    }
+}
@@ -0,0 +1,56 @@
+//! Logging everything a receiver sends to a file, e.g. for post-processing of UBX-RXM-RAWX
+//! measurements, the `raw-log` binary
+//!
+//! The data is written as received, so that the log can be replayed into a parser or
+//! converted by other tools. It is parsed on the way only to report what it contains.
+
+use std::io::{self, Read, Write};
+
+use ublox_device::ublox::{Parser, ParserStats, UbxProtocol};
+
+/// Writes the received data to a log and counts the frames in it
+pub struct RawLogger<W: Write, P: UbxProtocol> {
+    log: W,
+    parser: Parser<Vec<u8>, P>,
+}
+
+impl<W: Write, P: UbxProtocol> RawLogger<W, P> {
+    pub fn new(log: W) -> Self {
+        Self {
+            log,
+            parser: Parser::new(Vec::new()),
+        }
+    }
+
+    /// Reads once from `input` and logs the data, returning the number of bytes logged. Read
+    /// timeouts are reported as no data.
+    pub fn log_from(&mut self, input: &mut impl Read) -> io::Result<usize> {
+        let mut buf = [0; 1024];
+        let len = match input.read(&mut buf) {
+            Ok(len) => len,
+            Err(e) if e.kind() == io::ErrorKind::TimedOut => 0,
+            Err(e) => return Err(e),
+        };
+        self.log(&buf[..len])?;
+        Ok(len)
+    }
+
+    /// Logs `data`
+    pub fn log(&mut self, data: &[u8]) -> io::Result<()> {
+        self.log.write_all(data)?;
+        let mut it = self.parser.consume_ubx_rtcm_nmea(data);
+        while it.next().is_some() {}
+        Ok(())
+    }
+
+    /// Frame counts of the data logged so far
+    pub fn stats(&self) -> &ParserStats {
+        self.parser.stats()
+    }
+
+    /// Flushes the log and returns it
+    pub fn into_log(mut self) -> io::Result<W> {
+        self.log.flush()?;
+        Ok(self.log)
+    }
+}
@@ -0,0 +1,69 @@
+//! RTK rover fed with corrections from an NTRIP caster, the `rtk-rover` binary
+//!
+//! The RTCM3 corrections are written to the receiver unchanged, the receiver reports the
+//! resulting carrier phase solution in the flags of UBX-NAV-PVT.
+
+use std::io::{self, Read};
+
+use ublox_device::{
+    ublox::{nav_pvt::common::NavPvtFlags, UbxPacket, UbxProtocol},
+    Device, Transport,
+};
+
+/// Carrier phase range solution of the receiver
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CarrierSolution {
+    /// No carrier phase solution, e.g. no corrections received yet
+    None,
+    /// Floating ambiguities, decimetre level
+    Float,
+    /// Fixed ambiguities, centimetre level
+    Fixed,
+}
+
+impl CarrierSolution {
+    /// The carrier solution reported by `packet`, if it is a UBX-NAV-PVT
+    pub fn from_packet(packet: &UbxPacket) -> Option<Self> {
+        match_packet!(packet, NavPvt(pvt) => Self::from_flags(pvt.flags()))
+    }
+
+    fn from_flags(flags: NavPvtFlags) -> Self {
+        if flags.contains(NavPvtFlags::CARR_SOLN_FIXED) {
+            Self::Fixed
+        } else if flags.contains(NavPvtFlags::CARR_SOLN_FLOAT) {
+            Self::Float
+        } else {
+            Self::None
+        }
+    }
+}
+
+/// Forwards the corrections available from `corrections` to the receiver, then processes its
+/// packets, calling `on_status` with the carrier solution of each UBX-NAV-PVT. Returns the
+/// number of correction bytes forwarded.
+pub fn step<P: UbxProtocol, T: Transport>(
+    device: &mut Device<P, T>,
+    corrections: &mut impl Read,
+    mut on_status: impl FnMut(CarrierSolution),
+) -> io::Result<usize> {
+    let mut buf = [0; 1024];
+    let len = match corrections.read(&mut buf) {
+        Ok(len) => len,
+        Err(e)
+            if matches!(
+                e.kind(),
+                io::ErrorKind::TimedOut | io::ErrorKind::WouldBlock
+            ) =>
+        {
+            0
+        },
+        Err(e) => return Err(e),
+    };
+    device.write_all(&buf[..len])?;
+    device.on_data_available(|packet| {
+        if let Some(status) = CarrierSolution::from_packet(&packet) {
+            on_status(status);
+        }
+    })?;
+    Ok(len)
+}
@@ -0,0 +1,199 @@
+//! Runs each recipe against a mock receiver
+
+use std::{
+    io::{self, Cursor, Read, Write},
+    time::Duration,
+};
+
+use recipes::{
+    async_device::AsyncDevice,
+    base_station::{self, SurveyIn},
+    basic_read,
+    ntrip::{self, NtripRequest},
+    raw_log::RawLogger,
+    rtk_rover::{self, CarrierSolution},
+    Proto,
+};
+use tokio::io::AsyncWriteExt;
+use ublox_device::{
+    mock::{frame, MockTransport},
+    ublox::{cfg_msg::CfgMsgAllPorts, mon_ver::MonVer, tim_svin::TimSvin},
+    Device, Error,
+};
+
+/// A UBX-NAV-PVT frame with the given fix status flags
+fn nav_pvt(flags: u8) -> Vec<u8> {
+    let mut payload = [0; 92];
+    // Fix type 3D
+    payload[20] = 3;
+    payload[21] = flags;
+    frame(0x01, 0x07, &payload)
+}
+
+fn mon_ver() -> Vec<u8> {
+    let mut payload = [0; 40];
+    payload[..7].copy_from_slice(b"EXT 1.0");
+    payload[30..38].copy_from_slice(b"00080000");
+    frame(0x0a, 0x04, &payload)
+}
+
+#[test]
+fn basic_read_enables_and_prints_nav_pvt() {
+    let mut device = Device::<Proto, _>::new(MockTransport::new());
+    basic_read::enable_nav_pvt(&mut device).unwrap();
+    assert_eq!(device.transport().written_frames(), [(0x06, 0x01)]);
+
+    device.transport_mut().push_input(&nav_pvt(0x01));
+    device.transport_mut().push_input(&nav_pvt(0x01));
+    let mut out = Vec::new();
+    assert_eq!(
+        basic_read::print_packets(&mut device, &mut out, 1).unwrap(),
+        1
+    );
+    assert!(String::from_utf8(out).unwrap().contains("NavPvt"));
+}
+
+#[test]
+fn basic_read_reports_rejected_configuration() {
+    let mut device = Device::<Proto, _>::new(MockTransport::new().nak::<CfgMsgAllPorts>());
+    assert!(matches!(
+        basic_read::enable_nav_pvt(&mut device),
+        Err(Error::UnsupportedByFirmware(0x06, 0x01))
+    ));
+}
+
+#[test]
+fn base_station_configures_and_waits_for_survey_in() {
+    let mut svin = [0; 28];
+    svin[0..4].copy_from_slice(&300_u32.to_le_bytes());
+    svin[20..24].copy_from_slice(&1200_u32.to_le_bytes());
+    // Valid, no longer active
+    svin[24] = 1;
+    let transport = MockTransport::new().reply_to::<TimSvin>(frame(0x0d, 0x04, &svin));
+    let mut device = Device::<Proto, _>::new(transport);
+
+    let survey_in = SurveyIn {
+        min_duration: Duration::from_secs(300),
+        accuracy_limit: 20_000,
+    };
+    base_station::configure(&mut device, survey_in).unwrap();
+    assert_eq!(device.transport().written_frames(), [(0x06, 0x8a)]);
+
+    let mut reports = 0;
+    let status = base_station::wait_for_survey_in(
+        &mut device,
+        Duration::from_millis(10),
+        Duration::from_secs(1),
+        |_| reports += 1,
+    )
+    .unwrap()
+    .expect("survey-in completed");
+    assert_eq!(reports, 1);
+    assert_eq!(status.dur(), 300);
+    assert_eq!(status.obs(), 1200);
+}
+
+#[test]
+fn rtk_rover_forwards_corrections_and_reports_fix() {
+    let mut device = Device::<Proto, _>::new(MockTransport::new());
+    device.transport_mut().push_input(&nav_pvt(0x01 | 0x40));
+    device.transport_mut().push_input(&nav_pvt(0x01 | 0x80));
+
+    // An RTCM3 frame header, forwarded as is
+    let rtcm = [0xd3, 0x00, 0x13, 0x3e, 0xd0];
+    let mut statuses = Vec::new();
+    let forwarded = rtk_rover::step(&mut device, &mut &rtcm[..], |s| statuses.push(s)).unwrap();
+    assert_eq!(forwarded, rtcm.len());
+    assert_eq!(device.transport().written(), rtcm);
+    assert_eq!(statuses, [CarrierSolution::Float, CarrierSolution::Fixed]);
+}
+
+/// A caster connection answering with `response`
+struct Caster {
+    response: Cursor<Vec<u8>>,
+    request: Vec<u8>,
+}
+
+impl Caster {
+    fn new(response: &[u8]) -> Self {
+        Self {
+            response: Cursor::new(response.to_vec()),
+            request: Vec::new(),
+        }
+    }
+}
+
+impl Read for Caster {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.response.read(buf)
+    }
+}
+
+impl Write for Caster {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.request.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn ntrip_client_skips_the_response_header() {
+    let request = NtripRequest {
+        host: "caster.example",
+        mountpoint: "BASE1",
+        credentials: Some(("user", "pass")),
+    };
+    for response in [
+        &b"ICY 200 OK\r\n\xd3\x00\x00"[..],
+        &b"HTTP/1.1 200 OK\r\nNtrip-Version: Ntrip/2.0\r\n\r\n\xd3\x00\x00"[..],
+    ] {
+        let mut corrections = ntrip::connect(Caster::new(response), &request).unwrap();
+        let mut data = Vec::new();
+        corrections.read_to_end(&mut data).unwrap();
+        assert_eq!(data, [0xd3, 0x00, 0x00]);
+    }
+
+    let request = request.to_request();
+    assert!(request.starts_with("GET /BASE1 HTTP/1.0\r\n"));
+    assert!(request.contains("Authorization: Basic dXNlcjpwYXNz\r\n"));
+}
+
+#[test]
+fn ntrip_client_rejects_source_table() {
+    let request = NtripRequest {
+        host: "caster.example",
+        mountpoint: "UNKNOWN",
+        credentials: None,
+    };
+    let caster = Caster::new(b"SOURCETABLE 200 OK\r\nSTR;BASE1;\r\nENDSOURCETABLE\r\n");
+    assert!(ntrip::connect(caster, &request).is_err());
+}
+
+#[test]
+fn raw_log_keeps_data_and_counts_frames() {
+    let mut input = MockTransport::new();
+    let mut data = nav_pvt(0x01);
+    data.extend_from_slice(b"$GPGGA,,,,,,0,,,,,,,,*66\r\n");
+    input.push_input(&data);
+
+    let mut logger = RawLogger::<_, Proto>::new(Vec::new());
+    assert_eq!(logger.log_from(&mut input).unwrap(), data.len());
+    assert_eq!(logger.log_from(&mut input).unwrap(), 0);
+    assert_eq!(logger.stats().ubx_frames(0x01), 1);
+    assert_eq!(logger.into_log().unwrap(), data);
+}
+
+#[tokio::test]
+async fn async_device_polls_over_a_stream() {
+    let (stream, mut receiver) = tokio::io::duplex(1024);
+    receiver.write_all(&mon_ver()).await.unwrap();
+
+    let mut device = AsyncDevice::<_, Proto>::new(stream);
+    let version = device.poll::<MonVer>(Duration::from_secs(1)).await.unwrap();
+    assert_eq!(version.software_version(), Ok("EXT 1.0"));
+    assert_eq!(version.hardware_version(), Ok("00080000"));
+}
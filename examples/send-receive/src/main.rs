@@ -65,8 +65,8 @@ fn main() {
                         PacketRef::MonVer(packet) => {
                             println!(
                                 "SW version: {} HW version: {}; Extensions: {:?}",
-                                packet.software_version(),
-                                packet.hardware_version(),
+                                packet.software_version().unwrap_or_default(),
+                                packet.hardware_version().unwrap_or_default(),
                                 packet.extension().collect::<Vec<&str>>()
                             );
                         },
@@ -113,8 +113,8 @@ fn main() {
                         PacketRef::MonVer(packet) => {
                             println!(
                                 "SW version: {} HW version: {}; Extensions: {:?}",
-                                packet.software_version(),
-                                packet.hardware_version(),
+                                packet.software_version().unwrap_or_default(),
+                                packet.hardware_version().unwrap_or_default(),
                                 packet.extension().collect::<Vec<&str>>()
                             );
                         },
@@ -161,8 +161,8 @@ fn main() {
                         PacketRef::MonVer(packet) => {
                             println!(
                                 "SW version: {} HW version: {}; Extensions: {:?}",
-                                packet.software_version(),
-                                packet.hardware_version(),
+                                packet.software_version().unwrap_or_default(),
+                                packet.hardware_version().unwrap_or_default(),
                                 packet.extension().collect::<Vec<&str>>()
                             );
                         },
@@ -209,8 +209,8 @@ fn main() {
                         PacketRef::MonVer(packet) => {
                             println!(
                                 "SW version: {} HW version: {}; Extensions: {:?}",
-                                packet.software_version(),
-                                packet.hardware_version(),
+                                packet.software_version().unwrap_or_default(),
+                                packet.hardware_version().unwrap_or_default(),
                                 packet.extension().collect::<Vec<&str>>()
                             );
                         },
@@ -1,12 +1,26 @@
 use cli::UbxPortConfiguration;
-use std::time::Duration;
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    time::{Duration, Instant},
+};
 
 pub mod cli;
+pub mod mock;
+pub mod scenario;
+pub mod status;
+pub mod supervisor;
+pub mod warning;
 pub use ublox;
 use ublox::{
+    ack_tracker::{AckEvent, AckTracker},
     cfg_prt::{CfgPrtUart, CfgPrtUartBuilder, UartMode},
-    Parser, UbxPacket, UbxPacketMeta, UbxProtocol,
+    cfg_val::{CfgKey, CfgLayerGet, CfgValGetRequest, CfgValGetRequestBuilder},
+    firmware::FirmwareIdentity,
+    ttff::{StartType, TtffStats, TtffTimer},
+    Parser, ParserError, UbxPacket, UbxPacketMeta, UbxPacketRequest, UbxProtocol,
 };
+use warning::Warning;
 
 pub trait UbxPacketHandler {
     fn handle(&mut self, _packet: UbxPacket) {}
@@ -19,15 +33,237 @@ impl<F: FnMut(UbxPacket)> UbxPacketHandler for F {
     }
 }
 
-pub struct Device<P: UbxProtocol> {
-    port: Box<dyn serialport::SerialPort>,
+/// A packet that can be requested with [Device::poll], for all enabled protocol versions
+pub trait Pollable: UbxPacketMeta {
+    type Owned;
+
+    /// Copies `packet` if it is the response to the poll
+    fn owned_from(packet: &UbxPacket<'_>) -> Option<Self::Owned>;
+}
+
+macro_rules! impl_pollable {
+    ($module:ident::$name:ident => $owned:ident) => {
+        impl_pollable!($module::$name => $owned, [
+            "ubx_proto14" proto14 Proto14,
+            "ubx_proto23" proto23 Proto23,
+            "ubx_proto27" proto27 Proto27,
+            "ubx_proto31" proto31 Proto31,
+            "ubx_proto33" proto33 Proto33
+        ]);
+    };
+    ($module:ident::$name:ident => $owned:ident, [$($feature:literal $proto:ident $variant:ident),*]) => {
+        impl Pollable for ublox::$module::$name {
+            type Owned = ublox::$module::$owned;
+
+            fn owned_from(packet: &UbxPacket<'_>) -> Option<Self::Owned> {
+                match packet {
+                    $(
+                        #[cfg(feature = $feature)]
+                        UbxPacket::$variant(ublox::$proto::PacketRef::$name(p)) => Some(p.into()),
+                    )*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+impl_pollable!(cfg_ant::CfgAnt => CfgAntOwned);
+impl_pollable!(cfg_nav5::CfgNav5 => CfgNav5Owned);
+impl_pollable!(mon_gnss::MonGnss => MonGnssOwned);
+impl_pollable!(mon_hw::MonHw => MonHwOwned);
+impl_pollable!(mon_ver::MonVer => MonVerOwned);
+impl_pollable!(nav_dop::NavDop => NavDopOwned);
+impl_pollable!(nav_sat::NavSat => NavSatOwned);
+impl_pollable!(nav_status::NavStatus => NavStatusOwned);
+impl_pollable!(tim_svin::TimSvin => TimSvinOwned);
+// Only defined from protocol 27 on
+impl_pollable!(mon_comms::MonComms => MonCommsOwned, [
+    "ubx_proto27" proto27 Proto27,
+    "ubx_proto31" proto31 Proto31,
+    "ubx_proto33" proto33 Proto33
+]);
+#[cfg(any(
+    feature = "ubx_proto27",
+    feature = "ubx_proto31",
+    feature = "ubx_proto33",
+))]
+impl_pollable!(mon_rf::MonRf => MonRfOwned, [
+    "ubx_proto27" proto27 Proto27,
+    "ubx_proto31" proto31 Proto31,
+    "ubx_proto33" proto33 Proto33
+]);
+
+#[derive(Debug)]
+pub enum Error {
+    Io(std::io::Error),
+    /// The receiver answered the message with class and message id with a UBX-ACK-NAK
+    UnsupportedByFirmware(u8, u8),
+    /// The receiver did not answer the poll of the message with class and message id
+    Timeout(u8, u8),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "I/O error: {e}"),
+            Error::UnsupportedByFirmware(class, msg_id) => write!(
+                f,
+                "Message (0x{class:02x}, 0x{msg_id:02x}) is not supported by the receiver firmware"
+            ),
+            Error::Timeout(class, msg_id) => write!(
+                f,
+                "No response to the poll of message (0x{class:02x}, 0x{msg_id:02x})"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            Error::UnsupportedByFirmware(..) | Error::Timeout(..) => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+/// Number of times [Device::poll] sends the request before giving up
+pub const POLL_ATTEMPTS: u32 = 3;
+
+/// Time [Device::supports_key] waits for each answer
+pub const PROBE_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Time the receiver has to acknowledge a configuration message
+pub const ACK_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Everything reported by [Device::run], so that an application can handle the packets, the
+/// link errors and the answers to its configuration in a single loop
+#[derive(Debug)]
+pub enum Event<'a> {
+    /// A packet was received
+    Packet(UbxPacket<'a>),
+    /// The parser dropped `discarded` bytes to find the next frame, e.g. line noise or the
+    /// frames reported by [Event::ChecksumError]
+    SyncLost { discarded: u64 },
+    /// A UBX frame was dropped because of its checksum
+    ChecksumError { expect: u16, got: u16 },
+    /// A message sent with [Device::send_config] was acknowledged, rejected or not answered
+    /// within [ACK_TIMEOUT]
+    AckResolved(AckEvent),
+    /// All messages sent with [Device::send_config] since the last `ConfigApplied` were
+    /// acknowledged. Not reported if one of them was rejected or timed out.
+    ConfigApplied,
+    /// Nothing was received for `silent_for`, longer than the [watchdog](Device::with_watchdog).
+    /// Reported once until data arrives again.
+    DeviceTimeout { silent_for: Duration },
+    /// A message was written that the receiver is likely to reject or ignore, see [Warning].
+    /// Reported once the receiver was identified from its UBX-MON-VER, e.g. polled with
+    /// [Device::poll], or set with [Device::set_identity].
+    Warning(Warning),
+}
+
+/// The messages sent with [Device::send_config] waiting for their acknowledgement
+struct PendingConfig {
+    acks: AckTracker,
+    /// Whether one of them was rejected or timed out
+    failed: bool,
+}
+
+impl PendingConfig {
+    /// Reports `ack`, followed by [Event::ConfigApplied] if it resolved the last message
+    fn resolve(
+        &mut self,
+        ack: AckEvent,
+        unsupported: &mut HashSet<(u8, u8)>,
+        handler: &mut impl FnMut(Event<'_>),
+    ) {
+        match ack {
+            AckEvent::Acked { .. } => {},
+            AckEvent::Nacked { class, msg_id, .. } => {
+                unsupported.insert((class, msg_id));
+                self.failed = true;
+            },
+            AckEvent::TimedOut { .. } => self.failed = true,
+            // Answers to messages written otherwise, still reported as packets
+            AckEvent::Unexpected { .. } => return,
+        }
+        handler(Event::AckResolved(ack));
+        if self.acks.is_idle() && !std::mem::take(&mut self.failed) {
+            handler(Event::ConfigApplied);
+        }
+    }
+}
+
+/// Byte stream to the receiver, a serial port or a [mock::MockTransport] in tests
+pub trait Transport: std::io::Read + std::io::Write {
+    /// Drops the data received but not read yet
+    fn clear_input(&mut self) -> std::io::Result<()>;
+}
+
+impl Transport for Box<dyn serialport::SerialPort> {
+    fn clear_input(&mut self) -> std::io::Result<()> {
+        Ok(self.clear(serialport::ClearBuffer::Input)?)
+    }
+}
+
+pub struct Device<P: UbxProtocol, T: Transport = Box<dyn serialport::SerialPort>> {
+    port: T,
     parser: Parser<Vec<u8>, P>,
+    /// Class and message ids rejected by the receiver with a UBX-ACK-NAK
+    unsupported: HashSet<(u8, u8)>,
+    /// Results of [Device::supports_key]
+    key_support: HashMap<CfgKey, bool>,
+    config: PendingConfig,
+    /// Reference of the timestamps passed to the [AckTracker]
+    epoch: Instant,
+    watchdog: Option<Duration>,
+    last_received: Instant,
+    /// Whether [Event::DeviceTimeout] was reported since `last_received`
+    watchdog_fired: bool,
+    /// Identity of the receiver, from the last UBX-MON-VER received
+    identity: Option<FirmwareIdentity>,
+    /// Warnings about the messages written, reported by [Device::run] once the receiver is
+    /// identified
+    warnings: Vec<Warning>,
+    /// Legacy configuration messages already warned about or queued in `warnings`
+    legacy_seen: HashSet<(u8, u8)>,
 }
 
-impl<P: UbxProtocol> Device<P> {
-    pub fn new(port: Box<dyn serialport::SerialPort>) -> Device<P> {
+impl<P: UbxProtocol, T: Transport> Device<P, T> {
+    pub fn new(port: T) -> Device<P, T> {
         let parser = Parser::<_, P>::new(vec![]);
-        Device { port, parser }
+        let now = Instant::now();
+        Device {
+            port,
+            parser,
+            unsupported: HashSet::new(),
+            key_support: HashMap::new(),
+            config: PendingConfig {
+                acks: AckTracker::new(ACK_TIMEOUT),
+                failed: false,
+            },
+            epoch: now,
+            watchdog: None,
+            last_received: now,
+            watchdog_fired: false,
+            identity: None,
+            warnings: Vec::new(),
+            legacy_seen: HashSet::new(),
+        }
+    }
+
+    /// Report [Event::DeviceTimeout] from [Device::run] when the receiver sends nothing for
+    /// `timeout`
+    pub fn with_watchdog(mut self, timeout: Duration) -> Self {
+        self.watchdog = Some(timeout);
+        self
     }
 
     pub fn configure_port(
@@ -36,7 +272,9 @@ impl<P: UbxProtocol> Device<P> {
     ) -> anyhow::Result<()> {
         if let Some(config) = port_config {
             println!("Configuring '{}' port ...", config.port_name.to_uppercase());
-            self.write_all(
+            // Written to the port directly, the legacy message is the device's choice and no
+            // mistake of the application to warn about
+            self.port.write_all(
                 &CfgPrtUartBuilder {
                     portid: config.port_id.unwrap(),
                     reserved0: 0,
@@ -57,9 +295,44 @@ impl<P: UbxProtocol> Device<P> {
     }
 
     pub fn write_all(&mut self, data: &[u8]) -> std::io::Result<()> {
+        for (class, msg_id) in warning::frame_ids(data) {
+            if let Some(legacy) = warning::legacy_config(class, msg_id) {
+                if self.legacy_seen.insert((class, msg_id)) {
+                    self.warnings.push(legacy);
+                }
+            }
+        }
         self.port.write_all(data)
     }
 
+    /// Identity of the receiver, from the last UBX-MON-VER read
+    pub fn identity(&self) -> Option<&FirmwareIdentity> {
+        self.identity.as_ref()
+    }
+
+    /// Set the identity of the receiver when it is known without reading its UBX-MON-VER,
+    /// e.g. from a previous session
+    pub fn set_identity(&mut self, identity: FirmwareIdentity) {
+        self.identity = Some(identity);
+    }
+
+    pub fn transport(&self) -> &T {
+        &self.port
+    }
+
+    pub fn transport_mut(&mut self) -> &mut T {
+        &mut self.port
+    }
+
+    /// Writes the configuration message `frame`, e.g. from a builder's `into_packet_bytes()`,
+    /// whose acknowledgement is reported by [Device::run]
+    pub fn send_config(&mut self, frame: &[u8]) -> std::io::Result<()> {
+        self.write_all(frame)?;
+        // Beyond the tracker's capacity the acknowledgements are only reported as packets
+        self.config.acks.record_frame(frame, self.epoch.elapsed());
+        Ok(())
+    }
+
     pub fn on_data_available<F: FnMut(ublox::UbxPacket)>(
         &mut self,
         mut callback: F,
@@ -69,7 +342,6 @@ impl<P: UbxProtocol> Device<P> {
 
     pub fn process(&mut self, handler: &mut impl UbxPacketHandler) -> std::io::Result<()> {
         loop {
-            const MAX_PAYLOAD_LEN: usize = 1240;
             let mut local_buf = [0; MAX_PAYLOAD_LEN];
             let nbytes = self.read_port(&mut local_buf)?;
             if nbytes == 0 {
@@ -83,6 +355,9 @@ impl<P: UbxProtocol> Device<P> {
             loop {
                 match it.next() {
                     Some(Ok(packet)) => {
+                        if let Some(identity) = mon_ver_identity(&packet) {
+                            self.identity = Some(identity);
+                        }
                         handler.handle(packet);
                     },
                     Some(Err(e)) => {
@@ -98,54 +373,160 @@ impl<P: UbxProtocol> Device<P> {
         Ok(())
     }
 
-    pub fn wait_for_ack<T: UbxPacketMeta>(&mut self) -> std::io::Result<()> {
+    /// Reads the data available from the receiver and passes everything that happened to
+    /// `handler`, see [Event]. Returns once no more data is available, call it again from the
+    /// application's main loop.
+    ///
+    /// ```no_run
+    /// # fn configure(device: &mut ublox_device::Device<ublox::proto23::Proto23>, frame: &[u8]) -> std::io::Result<()> {
+    /// use ublox_device::Event;
+    ///
+    /// device.send_config(frame)?;
+    /// loop {
+    ///     device.run(|event| match event {
+    ///         Event::Packet(packet) => println!("{packet:?}"),
+    ///         Event::AckResolved(ack) => println!("{ack:?}"),
+    ///         Event::ConfigApplied => println!("Configured"),
+    ///         event => eprintln!("{event:?}"),
+    ///     })?;
+    /// }
+    /// # }
+    /// ```
+    pub fn run(&mut self, mut handler: impl FnMut(Event<'_>)) -> std::io::Result<()> {
+        loop {
+            let mut local_buf = [0; MAX_PAYLOAD_LEN];
+            let nbytes = self.read_port(&mut local_buf)?;
+            if nbytes == 0 {
+                break;
+            }
+            self.last_received = Instant::now();
+            self.watchdog_fired = false;
+
+            let discarded = self.parser.stats().bytes_discarded;
+            let mut it = self.parser.consume_ubx(&local_buf[..nbytes]);
+            while let Some(result) = it.next() {
+                let packet = match result {
+                    Ok(packet) => packet,
+                    Err(ParserError::InvalidChecksum { expect, got }) => {
+                        handler(Event::ChecksumError { expect, got });
+                        continue;
+                    },
+                    Err(e) => {
+                        eprintln!("Malformed packet, ignore it; cause {e}");
+                        continue;
+                    },
+                };
+                if let Some(identity) = mon_ver_identity(&packet) {
+                    self.identity = Some(identity);
+                }
+                let ack = self.config.acks.update(&packet, self.epoch.elapsed());
+                handler(Event::Packet(packet));
+                if let Some(ack) = ack {
+                    self.config
+                        .resolve(ack, &mut self.unsupported, &mut handler);
+                }
+            }
+            drop(it);
+            let discarded = self.parser.stats().bytes_discarded - discarded;
+            if discarded > 0 {
+                handler(Event::SyncLost { discarded });
+            }
+        }
+
+        let now = self.epoch.elapsed();
+        while let Some(ack) = self.config.acks.poll_timeout(now) {
+            self.config
+                .resolve(ack, &mut self.unsupported, &mut handler);
+        }
+        if let Some(identity) = &self.identity {
+            let valset = warning::uses_cfg_valset(identity);
+            for warning in self.warnings.drain(..) {
+                match warning {
+                    Warning::LegacyConfig { .. } if !valset => {},
+                    warning => handler(Event::Warning(warning)),
+                }
+            }
+        }
+        let silent_for = self.last_received.elapsed();
+        if !self.watchdog_fired && self.watchdog.is_some_and(|timeout| silent_for >= timeout) {
+            self.watchdog_fired = true;
+            handler(Event::DeviceTimeout { silent_for });
+        }
+        Ok(())
+    }
+
+    /// Waits up to [ACK_TIMEOUT] for the UBX-ACK-ACK of message `M`.
+    ///
+    /// A UBX-ACK-NAK marks `M` as unsupported for the lifetime of this `Device`, so that
+    /// further attempts fail right away with [Error::UnsupportedByFirmware] instead of
+    /// reconfiguring older firmware in a loop.
+    pub fn wait_for_ack<M: UbxPacketMeta>(&mut self) -> Result<(), Error> {
+        if self.unsupported.contains(&(M::CLASS, M::ID)) {
+            return Err(Error::UnsupportedByFirmware(M::CLASS, M::ID));
+        }
+
         let mut found_packet = false;
+        let mut nak_received = false;
         let start = std::time::SystemTime::now();
-        let timeout = Duration::from_secs(3);
+        let timeout = ACK_TIMEOUT;
         while !found_packet {
             self.on_data_available(|packet| match packet {
                 #[cfg(feature = "ubx_proto23")]
-                UbxPacket::Proto23(packet_ref) => {
-                    if let ublox::proto23::PacketRef::AckAck(ack) = packet_ref {
-                        if ack.class() == T::CLASS && ack.msg_id() == T::ID {
-                            found_packet = true;
-                        }
-                    }
+                UbxPacket::Proto23(packet_ref) => match packet_ref {
+                    ublox::proto23::PacketRef::AckAck(ack) if ack.is_ack_for::<M>() => {
+                        found_packet = true;
+                    },
+                    ublox::proto23::PacketRef::AckNak(nak) if nak.is_nak_for::<M>() => {
+                        nak_received = true;
+                    },
+                    _ => {},
                 },
                 #[cfg(feature = "ubx_proto27")]
-                UbxPacket::Proto27(packet_ref) => {
-                    if let ublox::proto27::PacketRef::AckAck(ack) = packet_ref {
-                        if ack.class() == T::CLASS && ack.msg_id() == T::ID {
-                            found_packet = true;
-                        }
-                    }
+                UbxPacket::Proto27(packet_ref) => match packet_ref {
+                    ublox::proto27::PacketRef::AckAck(ack) if ack.is_ack_for::<M>() => {
+                        found_packet = true;
+                    },
+                    ublox::proto27::PacketRef::AckNak(nak) if nak.is_nak_for::<M>() => {
+                        nak_received = true;
+                    },
+                    _ => {},
                 },
                 #[cfg(feature = "ubx_proto31")]
-                UbxPacket::Proto31(packet_ref) => {
-                    if let ublox::proto31::PacketRef::AckAck(ack) = packet_ref {
-                        if ack.class() == T::CLASS && ack.msg_id() == T::ID {
-                            found_packet = true;
-                        }
-                    }
+                UbxPacket::Proto31(packet_ref) => match packet_ref {
+                    ublox::proto31::PacketRef::AckAck(ack) if ack.is_ack_for::<M>() => {
+                        found_packet = true;
+                    },
+                    ublox::proto31::PacketRef::AckNak(nak) if nak.is_nak_for::<M>() => {
+                        nak_received = true;
+                    },
+                    _ => {},
                 },
                 #[cfg(feature = "ubx_proto33")]
-                UbxPacket::Proto33(packet_ref) => {
-                    if let ublox::proto33::PacketRef::AckAck(ack) = packet_ref {
-                        if ack.class() == T::CLASS && ack.msg_id() == T::ID {
-                            found_packet = true;
-                        }
-                    }
+                UbxPacket::Proto33(packet_ref) => match packet_ref {
+                    ublox::proto33::PacketRef::AckAck(ack) if ack.is_ack_for::<M>() => {
+                        found_packet = true;
+                    },
+                    ublox::proto33::PacketRef::AckNak(nak) if nak.is_nak_for::<M>() => {
+                        nak_received = true;
+                    },
+                    _ => {},
                 },
                 #[cfg(feature = "ubx_proto14")]
-                UbxPacket::Proto14(packet_ref) => {
-                    if let ublox::proto14::PacketRef::AckAck(ack) = packet_ref {
-                        if ack.class() == T::CLASS && ack.msg_id() == T::ID {
-                            found_packet = true;
-                        }
-                    }
+                UbxPacket::Proto14(packet_ref) => match packet_ref {
+                    ublox::proto14::PacketRef::AckAck(ack) if ack.is_ack_for::<M>() => {
+                        found_packet = true;
+                    },
+                    ublox::proto14::PacketRef::AckNak(nak) if nak.is_nak_for::<M>() => {
+                        nak_received = true;
+                    },
+                    _ => {},
                 },
             })?;
 
+            if nak_received {
+                self.unsupported.insert((M::CLASS, M::ID));
+                return Err(Error::UnsupportedByFirmware(M::CLASS, M::ID));
+            }
             if start.elapsed().unwrap().as_millis() > timeout.as_millis() {
                 eprintln!("Did not receive ACK message for request");
                 break;
@@ -154,6 +535,138 @@ impl<P: UbxProtocol> Device<P> {
         Ok(())
     }
 
+    /// Requests packet `M` from the receiver and waits up to `timeout` for the response,
+    /// sending the request up to [POLL_ATTEMPTS] times. The data received is read once more
+    /// before each resend, so that a late UBX-ACK-NAK ends the attempts. Other packets received
+    /// meanwhile are dropped.
+    ///
+    /// ```no_run
+    /// # fn version(device: &mut ublox_device::Device<ublox::proto23::Proto23>) {
+    /// use std::time::Duration;
+    /// use ublox::mon_ver::MonVer;
+    ///
+    /// let version = device.poll::<MonVer>(Duration::from_secs(1)).unwrap();
+    /// println!("{}", version.software_version().unwrap_or_default());
+    /// # }
+    /// ```
+    pub fn poll<M: Pollable>(&mut self, timeout: Duration) -> Result<M::Owned, Error> {
+        if self.is_unsupported::<M>() {
+            return Err(Error::UnsupportedByFirmware(M::CLASS, M::ID));
+        }
+
+        let request = UbxPacketRequest::request_for::<M>().into_packet_bytes();
+        for _ in 0..POLL_ATTEMPTS {
+            self.write_all(&request)?;
+
+            let deadline = Instant::now() + timeout;
+            let mut response = None;
+            let mut nak_received = false;
+            // Read at least once after the deadline, right before the request is resent
+            loop {
+                self.on_data_available(|packet| {
+                    if response.is_none() {
+                        response = M::owned_from(&packet);
+                    }
+                    nak_received |= is_nak_for::<M>(&packet);
+                })?;
+                if response.is_some() || nak_received || Instant::now() >= deadline {
+                    break;
+                }
+            }
+
+            if let Some(response) = response {
+                return Ok(response);
+            }
+            if nak_received {
+                self.unsupported.insert((M::CLASS, M::ID));
+                return Err(Error::UnsupportedByFirmware(M::CLASS, M::ID));
+            }
+        }
+        Err(Error::Timeout(M::CLASS, M::ID))
+    }
+
+    /// Whether the receiver knows the configuration key `key`, e.g. to hide the options a
+    /// receiver model lacks instead of failing when applying them.
+    ///
+    /// Reads the value of `key` from the RAM layer with UBX-CFG-VALGET, sending the request up
+    /// to [POLL_ATTEMPTS] times and waiting [PROBE_TIMEOUT] for each answer. A UBX-ACK-NAK
+    /// means that the key is unsupported, as on receivers older than protocol 27, responses
+    /// with the value of another key are ignored. The result is cached for the lifetime of this
+    /// `Device`.
+    pub fn supports_key(&mut self, key: CfgKey) -> Result<bool, Error> {
+        if let Some(&supported) = self.key_support.get(&key) {
+            return Ok(supported);
+        }
+
+        let mut request = Vec::new();
+        CfgValGetRequestBuilder {
+            version: 0,
+            layers: CfgLayerGet::Ram,
+            position: 0,
+            cfg_keys: &[key],
+        }
+        .extend_to(&mut request);
+        for _ in 0..POLL_ATTEMPTS {
+            self.write_all(&request)?;
+
+            let deadline = Instant::now() + PROBE_TIMEOUT;
+            let mut answer = None;
+            while answer.is_none() && Instant::now() < deadline {
+                self.on_data_available(|packet| {
+                    answer = answer.or_else(|| key_probe_answer(&packet, key));
+                })?;
+            }
+
+            if let Some(supported) = answer {
+                self.key_support.insert(key, supported);
+                return Ok(supported);
+            }
+        }
+        Err(Error::Timeout(
+            CfgValGetRequest::CLASS,
+            CfgValGetRequest::ID,
+        ))
+    }
+
+    /// Whether the receiver rejected message `T` with a UBX-ACK-NAK during this session
+    pub fn is_unsupported<M: UbxPacketMeta>(&self) -> bool {
+        self.unsupported.contains(&(M::CLASS, M::ID))
+    }
+
+    /// Restarts the receiver `iterations` times for each of `start_types` and measures the
+    /// time to first fix, giving up on a start after `timeout`. Requires UBX-NAV-STATUS or
+    /// UBX-NAV-PVT to be enabled on the port. The report is serializable with the `serde`
+    /// feature.
+    pub fn benchmark_starts(
+        &mut self,
+        start_types: &[StartType],
+        iterations: u32,
+        timeout: Duration,
+    ) -> std::io::Result<Vec<TtffStats>> {
+        let epoch = Instant::now();
+        let mut report = Vec::with_capacity(start_types.len());
+        for &start_type in start_types {
+            let mut stats = TtffStats::new(start_type);
+            for _ in 0..iterations {
+                // Drop fixes from before the reset still waiting to be read
+                self.port.clear_input()?;
+                self.write_all(&start_type.reset_packet())?;
+
+                let mut timer = TtffTimer::new();
+                timer.start(epoch.elapsed());
+                let deadline = Instant::now() + timeout;
+                while timer.is_running() && Instant::now() < deadline {
+                    self.on_data_available(|packet| {
+                        timer.update(&packet, epoch.elapsed());
+                    })?;
+                }
+                stats.add(timer.ttff().map(|ttff| ttff.duration));
+            }
+            report.push(stats);
+        }
+        Ok(report)
+    }
+
     /// Reads the serial port, converting timeouts into "no data received"
     fn read_port(&mut self, output: &mut [u8]) -> std::io::Result<usize> {
         match self.port.read(output) {
@@ -168,3 +681,56 @@ impl<P: UbxProtocol> Device<P> {
         }
     }
 }
+
+/// Size of the chunks read from the receiver
+const MAX_PAYLOAD_LEN: usize = 1240;
+
+/// `Some(true)` if `packet` answers the UBX-CFG-VALGET of `key` with its value, `Some(false)`
+/// if it is a UBX-ACK-NAK of the request, `None` if it is no answer to it
+fn key_probe_answer(packet: &UbxPacket<'_>, key: CfgKey) -> Option<bool> {
+    if is_nak_for::<CfgValGetRequest>(packet) {
+        return Some(false);
+    }
+    let raw = packet.as_raw();
+    if (raw.class, raw.msg_id) != (CfgValGetRequest::CLASS, CfgValGetRequest::ID) {
+        return None;
+    }
+    // The values follow the version, layer and position, a response starting with another
+    // key is a stale or unrelated one
+    let key_id = (key as u32).to_le_bytes();
+    (raw.payload.get(4..8) == Some(&key_id[..])).then_some(true)
+}
+
+/// Identity of the receiver if `packet` is a UBX-MON-VER
+fn mon_ver_identity(packet: &UbxPacket<'_>) -> Option<FirmwareIdentity> {
+    match packet {
+        #[cfg(feature = "ubx_proto14")]
+        UbxPacket::Proto14(ublox::proto14::PacketRef::MonVer(ver)) => Some(ver.into()),
+        #[cfg(feature = "ubx_proto23")]
+        UbxPacket::Proto23(ublox::proto23::PacketRef::MonVer(ver)) => Some(ver.into()),
+        #[cfg(feature = "ubx_proto27")]
+        UbxPacket::Proto27(ublox::proto27::PacketRef::MonVer(ver)) => Some(ver.into()),
+        #[cfg(feature = "ubx_proto31")]
+        UbxPacket::Proto31(ublox::proto31::PacketRef::MonVer(ver)) => Some(ver.into()),
+        #[cfg(feature = "ubx_proto33")]
+        UbxPacket::Proto33(ublox::proto33::PacketRef::MonVer(ver)) => Some(ver.into()),
+        _ => None,
+    }
+}
+
+/// Whether `packet` is a UBX-ACK-NAK rejecting message `T`
+fn is_nak_for<T: UbxPacketMeta>(packet: &UbxPacket<'_>) -> bool {
+    match packet {
+        #[cfg(feature = "ubx_proto14")]
+        UbxPacket::Proto14(ublox::proto14::PacketRef::AckNak(nak)) => nak.is_nak_for::<T>(),
+        #[cfg(feature = "ubx_proto23")]
+        UbxPacket::Proto23(ublox::proto23::PacketRef::AckNak(nak)) => nak.is_nak_for::<T>(),
+        #[cfg(feature = "ubx_proto27")]
+        UbxPacket::Proto27(ublox::proto27::PacketRef::AckNak(nak)) => nak.is_nak_for::<T>(),
+        #[cfg(feature = "ubx_proto31")]
+        UbxPacket::Proto31(ublox::proto31::PacketRef::AckNak(nak)) => nak.is_nak_for::<T>(),
+        #[cfg(feature = "ubx_proto33")]
+        UbxPacket::Proto33(ublox::proto33::PacketRef::AckNak(nak)) => nak.is_nak_for::<T>(),
+        _ => false,
+    }
+}
@@ -0,0 +1,273 @@
+//! In-memory [Transport] standing in for a receiver, to run the examples without hardware
+
+use std::{
+    collections::{BTreeMap, HashMap, HashSet, VecDeque},
+    io,
+};
+
+use ublox::{
+    cfg_val::{CfgKey, CfgVal, CfgValDel, CfgValGetRequest, CfgValSet, CfgValueType},
+    ubx_checksum, UbxPacketMeta,
+};
+
+use crate::Transport;
+
+/// UBX class of the configuration messages, acknowledged by the receiver
+const CFG_CLASS: u8 = 0x06;
+
+/// A fake receiver: returns the data queued with [MockTransport::push_input] from `read`,
+/// answers polls with the responses registered with [MockTransport::reply_to] and acknowledges
+/// all UBX-CFG messages written to it, or those of its [ReceiverModel] if it has one.
+///
+/// `read` fails with [io::ErrorKind::TimedOut] once the queued data is consumed, as a serial
+/// port without incoming data does.
+#[derive(Debug, Default)]
+pub struct MockTransport {
+    input: VecDeque<u8>,
+    written: Vec<u8>,
+    /// Offset of the first written byte not checked for complete frames yet
+    scanned: usize,
+    replies: HashMap<(u8, u8), Vec<u8>>,
+    nak: Vec<(u8, u8)>,
+    model: Option<ReceiverModel>,
+}
+
+impl MockTransport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue `data` as sent by the receiver
+    pub fn push_input(&mut self, data: &[u8]) {
+        self.input.extend(data);
+    }
+
+    /// Answer polls of message `M` with `response`, a complete frame
+    pub fn reply_to<M: UbxPacketMeta>(mut self, response: Vec<u8>) -> Self {
+        self.replies.insert((M::CLASS, M::ID), response);
+        self
+    }
+
+    /// Reject message `M` with a UBX-ACK-NAK instead of acknowledging it
+    pub fn nak<M: UbxPacketMeta>(mut self) -> Self {
+        self.nak.push((M::CLASS, M::ID));
+        self
+    }
+
+    /// Answer UBX-CFG messages as the receiver described by `model`
+    pub fn with_model(mut self, model: ReceiverModel) -> Self {
+        self.model = Some(model);
+        self
+    }
+
+    /// The receiver model, with the configuration values written so far
+    pub fn model(&self) -> Option<&ReceiverModel> {
+        self.model.as_ref()
+    }
+
+    /// All data written to the receiver
+    pub fn written(&self) -> &[u8] {
+        &self.written
+    }
+
+    /// Class and message ids of the UBX frames written to the receiver, in order
+    pub fn written_frames(&self) -> Vec<(u8, u8)> {
+        let mut frames = Vec::new();
+        let mut offset = 0;
+        while let Some((class, msg_id, _, next)) = next_frame(&self.written, offset) {
+            frames.push((class, msg_id));
+            offset = next;
+        }
+        frames
+    }
+
+    fn answer_written_frames(&mut self) {
+        while let Some((class, msg_id, payload_len, next)) = next_frame(&self.written, self.scanned)
+        {
+            self.scanned = next;
+            if payload_len == 0 {
+                if let Some(response) = self.replies.get(&(class, msg_id)) {
+                    self.input.extend(response);
+                    continue;
+                }
+            }
+            if class != CFG_CLASS {
+                continue;
+            }
+            let accepted = if self.nak.contains(&(class, msg_id)) {
+                false
+            } else if let Some(model) = &mut self.model {
+                let payload = &self.written[next - 2 - payload_len..next - 2];
+                match model.handle(msg_id, payload) {
+                    Answer::Ack(accepted) => accepted,
+                    Answer::Response(response) => {
+                        self.input.extend(response);
+                        continue;
+                    },
+                }
+            } else {
+                true
+            };
+            let ack_id = if accepted { 0x01 } else { 0x00 };
+            self.input.extend(frame(0x05, ack_id, &[class, msg_id]));
+        }
+    }
+}
+
+/// Simulated receiver configuration for [MockTransport::with_model]: the UBX-CFG messages it
+/// accepts and the configuration keys it knows, with their current value.
+///
+/// UBX-CFG-VALSET is acknowledged if all of its keys are known, and then updates their values.
+/// UBX-CFG-VALGET is answered with the values of its keys, or rejected if one of them is
+/// unknown. UBX-CFG-VALDEL is acknowledged. Any other UBX-CFG message is acknowledged if it
+/// was registered with [ReceiverModel::accepts], and rejected otherwise.
+#[derive(Debug, Clone, Default)]
+pub struct ReceiverModel {
+    /// Message ids of the accepted UBX-CFG messages
+    accepted: HashSet<u8>,
+    /// Raw values by key id
+    values: BTreeMap<u32, Vec<u8>>,
+}
+
+/// What the model answers to a UBX-CFG message
+enum Answer {
+    /// A UBX-ACK-ACK if set, otherwise a UBX-ACK-NAK
+    Ack(bool),
+    /// A frame other than an acknowledgement
+    Response(Vec<u8>),
+}
+
+impl ReceiverModel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Acknowledge UBX-CFG message `M`
+    pub fn accepts<M: UbxPacketMeta>(mut self) -> Self {
+        self.accepted.insert(M::ID);
+        self
+    }
+
+    /// Know the configuration key of `value`, starting out with that value
+    pub fn with_value(mut self, value: CfgVal) -> Self {
+        let mut bytes = Vec::new();
+        value.extend_to(&mut bytes);
+        let key_id = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        self.values.insert(key_id, bytes.split_off(4));
+        self
+    }
+
+    /// Current value of `key`, `None` if the receiver does not know it
+    pub fn value(&self, key: CfgKey) -> Option<CfgVal> {
+        let key_id = key as u32;
+        let mut bytes = key_id.to_le_bytes().to_vec();
+        bytes.extend_from_slice(self.values.get(&key_id)?);
+        CfgVal::parse(&bytes)
+    }
+
+    fn handle(&mut self, msg_id: u8, payload: &[u8]) -> Answer {
+        match msg_id {
+            CfgValSet::ID => Answer::Ack(self.set(payload)),
+            CfgValGetRequest::ID => match self.get(payload) {
+                Some(response) => Answer::Response(response),
+                None => Answer::Ack(false),
+            },
+            CfgValDel::ID => Answer::Ack(true),
+            msg_id => Answer::Ack(self.accepted.contains(&msg_id)),
+        }
+    }
+
+    /// Applies the values of a UBX-CFG-VALSET payload, returns whether all keys were known
+    fn set(&mut self, payload: &[u8]) -> bool {
+        let mut values = Vec::new();
+        let mut data = payload.get(4..).unwrap_or_default();
+        while let [a, b, c, d, rest @ ..] = data {
+            let key_id = u32::from_le_bytes([*a, *b, *c, *d]);
+            let Some(size) = CfgValueType::raw_for_key(key_id).map(CfgValueType::size) else {
+                return false;
+            };
+            if !self.values.contains_key(&key_id) || rest.len() < size {
+                return false;
+            }
+            values.push((key_id, rest[..size].to_vec()));
+            data = &rest[size..];
+        }
+        self.values.extend(values);
+        true
+    }
+
+    /// The UBX-CFG-VALGET response to a request payload, `None` if a key is unknown
+    fn get(&self, payload: &[u8]) -> Option<Vec<u8>> {
+        let (header, keys) = payload.split_at_checked(4)?;
+        let mut response = vec![1, header[1], header[2], header[3]];
+        for key_id in keys.chunks_exact(4) {
+            let value = self
+                .values
+                .get(&u32::from_le_bytes(key_id.try_into().unwrap()))?;
+            response.extend_from_slice(key_id);
+            response.extend_from_slice(value);
+        }
+        Some(frame(CFG_CLASS, CfgValGetRequest::ID, &response))
+    }
+}
+
+impl io::Read for MockTransport {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.input.is_empty() {
+            return Err(io::ErrorKind::TimedOut.into());
+        }
+        let len = buf.len().min(self.input.len());
+        for (dst, src) in buf.iter_mut().zip(self.input.drain(..len)) {
+            *dst = src;
+        }
+        Ok(len)
+    }
+}
+
+impl io::Write for MockTransport {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.written.extend_from_slice(buf);
+        self.answer_written_frames();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Transport for MockTransport {
+    fn clear_input(&mut self) -> io::Result<()> {
+        self.input.clear();
+        Ok(())
+    }
+}
+
+/// A UBX frame with the given class, message id and payload
+pub fn frame(class: u8, msg_id: u8, payload: &[u8]) -> Vec<u8> {
+    let len = u16::try_from(payload.len()).expect("UBX payloads are at most 65535 bytes");
+    let mut frame = vec![0xb5, 0x62, class, msg_id];
+    frame.extend_from_slice(&len.to_le_bytes());
+    frame.extend_from_slice(payload);
+    let (ck_a, ck_b) = ubx_checksum(&frame[2..]);
+    frame.extend_from_slice(&[ck_a, ck_b]);
+    frame
+}
+
+/// Finds the next complete UBX frame in `data` from `offset` on, skipping other data. Returns
+/// its class, message id and payload length along with the offset right after it.
+pub(crate) fn next_frame(data: &[u8], mut offset: usize) -> Option<(u8, u8, usize, usize)> {
+    while offset + 6 <= data.len() {
+        if data[offset..offset + 2] != [0xb5, 0x62] {
+            offset += 1;
+            continue;
+        }
+        let payload_len = usize::from(u16::from_le_bytes([data[offset + 4], data[offset + 5]]));
+        let end = offset + 6 + payload_len + 2;
+        if end > data.len() {
+            return None;
+        }
+        return Some((data[offset + 2], data[offset + 3], payload_len, end));
+    }
+    None
+}
@@ -0,0 +1,308 @@
+//! Scripted exchanges with a receiver, for behavior tests reading like the protocol trace
+//!
+//! A [Scenario] is a [Transport] following a script of steps: frames the host must write, and
+//! frames the receiver sends back once the host wrote the frames before them. Unlike
+//! [MockTransport](crate::mock::MockTransport), which answers anything, a scenario fails on
+//! the first frame written out of order, and [Scenario::verify] tells whether the host went
+//! through the whole script.
+//!
+//! ```
+//! use ublox_device::{
+//!     mock::frame,
+//!     scenario::Scenario,
+//!     ublox::{
+//!         cfg_rate::{AlignmentToReferenceTime, CfgRate, CfgRateBuilder},
+//!         proto23::Proto23,
+//!     },
+//!     Device,
+//! };
+//!
+//! let nav_pvt = frame(0x01, 0x07, &[0; 92]);
+//! let scenario = Scenario::new()
+//!     .host_sends::<CfgRate>()
+//!     .device_acks()
+//!     .device_streams(&nav_pvt, 3);
+//! let mut device = Device::<Proto23, _>::new(scenario);
+//!
+//! let rate = CfgRateBuilder {
+//!     measure_rate_ms: 100,
+//!     nav_rate: 1,
+//!     time_ref: AlignmentToReferenceTime::Gps,
+//! };
+//! device.write_all(&rate.into_packet_bytes()).unwrap();
+//! device.wait_for_ack::<CfgRate>().unwrap();
+//! device.transport().verify().unwrap();
+//! ```
+
+use std::{collections::VecDeque, fmt, io};
+
+use ublox::UbxPacketMeta;
+
+use crate::{
+    mock::{frame, next_frame},
+    Transport,
+};
+
+/// UBX class of UBX-ACK-ACK and UBX-ACK-NAK
+const ACK_CLASS: u8 = 0x05;
+
+#[derive(Debug, Clone)]
+enum Step {
+    /// The host writes a frame with the class and message id, and exactly `frame` if given
+    HostSends {
+        class: u8,
+        msg_id: u8,
+        frame: Option<Vec<u8>>,
+    },
+    /// The receiver sends `data`
+    DeviceSends(Vec<u8>),
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Step::HostSends { class, msg_id, .. } => {
+                write!(f, "host sends (0x{class:02x}, 0x{msg_id:02x})")
+            },
+            Step::DeviceSends(data) => match next_frame(data, 0) {
+                Some((class, msg_id, ..)) => {
+                    write!(f, "device sends (0x{class:02x}, 0x{msg_id:02x})")
+                },
+                None => write!(f, "device sends {} bytes", data.len()),
+            },
+        }
+    }
+}
+
+/// Deviation of the host from the script of a [Scenario]. Steps are numbered from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScenarioError {
+    /// The host wrote the frame with class and message id when `expected` was step `step`,
+    /// or after the end of the script
+    UnexpectedFrame {
+        step: usize,
+        expected: String,
+        class: u8,
+        msg_id: u8,
+    },
+    /// The host stopped before step `step`, `expected`
+    Incomplete { step: usize, expected: String },
+}
+
+impl fmt::Display for ScenarioError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScenarioError::UnexpectedFrame {
+                step,
+                expected,
+                class,
+                msg_id,
+            } => write!(
+                f,
+                "step {step}: expected {expected}, host sent (0x{class:02x}, 0x{msg_id:02x})"
+            ),
+            ScenarioError::Incomplete { step, expected } => {
+                write!(f, "step {step}: expected {expected}, host stopped")
+            },
+        }
+    }
+}
+
+impl std::error::Error for ScenarioError {}
+
+/// A fake receiver following a script, see the [module documentation](self)
+///
+/// The frames the receiver sends before the first frame the host writes are available right
+/// away. `read` fails with [io::ErrorKind::TimedOut] once the data released so far is
+/// consumed, and `write` with [io::ErrorKind::InvalidData] from the first frame written out of
+/// order on.
+#[derive(Debug, Default)]
+pub struct Scenario {
+    /// Steps not reached yet
+    steps: VecDeque<Step>,
+    /// Number of steps done
+    done: usize,
+    input: VecDeque<u8>,
+    written: Vec<u8>,
+    /// Offset of the first written byte not checked for complete frames yet
+    scanned: usize,
+    error: Option<ScenarioError>,
+}
+
+impl Scenario {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The host writes message `M`, with any payload
+    pub fn host_sends<M: UbxPacketMeta>(self) -> Self {
+        self.step(Step::HostSends {
+            class: M::CLASS,
+            msg_id: M::ID,
+            frame: None,
+        })
+    }
+
+    /// The host writes `frame`, a complete UBX frame, byte for byte
+    pub fn host_sends_frame(self, frame: &[u8]) -> Self {
+        let (class, msg_id, ..) = next_frame(frame, 0).expect("a complete UBX frame");
+        self.step(Step::HostSends {
+            class,
+            msg_id,
+            frame: Some(frame.to_vec()),
+        })
+    }
+
+    /// The receiver sends `data`, usually a complete frame
+    pub fn device_sends(self, data: &[u8]) -> Self {
+        self.step(Step::DeviceSends(data.to_vec()))
+    }
+
+    /// The receiver sends `data` `count` times, e.g. periodic navigation solutions
+    pub fn device_streams(self, data: &[u8], count: usize) -> Self {
+        (0..count).fold(self, |scenario, _| scenario.device_sends(data))
+    }
+
+    /// The receiver acknowledges the last frame the host sends with a UBX-ACK-ACK
+    ///
+    /// # Panics
+    ///
+    /// Panics if no host step precedes it.
+    pub fn device_acks(self) -> Self {
+        self.device_answers(0x01)
+    }
+
+    /// The receiver rejects the last frame the host sends with a UBX-ACK-NAK
+    ///
+    /// # Panics
+    ///
+    /// Panics if no host step precedes it.
+    pub fn device_naks(self) -> Self {
+        self.device_answers(0x00)
+    }
+
+    fn device_answers(self, ack_id: u8) -> Self {
+        let (class, msg_id) = self
+            .steps
+            .iter()
+            .rev()
+            .find_map(|step| match step {
+                Step::HostSends { class, msg_id, .. } => Some((*class, *msg_id)),
+                Step::DeviceSends(_) => None,
+            })
+            .expect("an acknowledgement answers a frame sent by the host");
+        self.device_sends(&frame(ACK_CLASS, ack_id, &[class, msg_id]))
+    }
+
+    fn step(mut self, step: Step) -> Self {
+        self.steps.push_back(step);
+        // Only releases anything before the first host step
+        self.release_device_steps();
+        self
+    }
+
+    /// Ok if the host went through the whole script without deviating from it. Data sent by
+    /// the receiver but not read by the host does not count.
+    pub fn verify(&self) -> Result<(), ScenarioError> {
+        if let Some(error) = &self.error {
+            return Err(error.clone());
+        }
+        match self.steps.front() {
+            Some(step) => Err(ScenarioError::Incomplete {
+                step: self.done + 1,
+                expected: step.to_string(),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// All data written by the host
+    pub fn written(&self) -> &[u8] {
+        &self.written
+    }
+
+    /// Moves the receiver steps at the front of the script to the input
+    fn release_device_steps(&mut self) {
+        while let Some(Step::DeviceSends(data)) = self.steps.front() {
+            self.input.extend(data);
+            self.steps.pop_front();
+            self.done += 1;
+        }
+    }
+
+    fn check_written_frames(&mut self) -> Result<(), ScenarioError> {
+        while let Some((class, msg_id, payload_len, next)) = next_frame(&self.written, self.scanned)
+        {
+            let written = &self.written[next - payload_len - 8..next];
+            self.scanned = next;
+            let matches = match self.steps.front() {
+                Some(Step::HostSends {
+                    class: expected_class,
+                    msg_id: expected_id,
+                    frame,
+                }) => {
+                    (class, msg_id) == (*expected_class, *expected_id)
+                        && frame.as_ref().is_none_or(|frame| frame == written)
+                },
+                _ => false,
+            };
+            if !matches {
+                return Err(ScenarioError::UnexpectedFrame {
+                    step: self.done + 1,
+                    expected: self
+                        .steps
+                        .front()
+                        .map_or_else(|| "the end".to_string(), Step::to_string),
+                    class,
+                    msg_id,
+                });
+            }
+            self.steps.pop_front();
+            self.done += 1;
+            self.release_device_steps();
+        }
+        Ok(())
+    }
+}
+
+impl io::Read for Scenario {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.input.is_empty() {
+            return Err(io::ErrorKind::TimedOut.into());
+        }
+        let len = buf.len().min(self.input.len());
+        for (dst, src) in buf.iter_mut().zip(self.input.drain(..len)) {
+            *dst = src;
+        }
+        Ok(len)
+    }
+}
+
+impl io::Write for Scenario {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.error.is_none() {
+            self.written.extend_from_slice(buf);
+            if let Err(error) = self.check_written_frames() {
+                self.error = Some(error);
+            }
+        }
+        match &self.error {
+            Some(error) => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                error.to_string(),
+            )),
+            None => Ok(buf.len()),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Transport for Scenario {
+    fn clear_input(&mut self) -> io::Result<()> {
+        self.input.clear();
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+use std::time::{Duration, SystemTime};
+
+#[cfg(any(
+    feature = "ubx_proto27",
+    feature = "ubx_proto31",
+    feature = "ubx_proto33",
+))]
+use ublox::mon_rf::{MonRf, MonRfOwned};
+use ublox::{
+    mon_comms::{MonComms, MonCommsOwned},
+    mon_hw::{MonHw, MonHwOwned},
+    nav_dop::{NavDop, NavDopOwned},
+    nav_sat::{NavSat, NavSatOwned},
+    nav_status::{NavStatus, NavStatusOwned},
+    UbxProtocol,
+};
+
+use crate::{Device, Error};
+
+/// Receiver health at one point in time, e.g. for a dashboard or a health endpoint
+///
+/// Each message is polled on its own, so a message the receiver does not support or answer
+/// only fails its own field. UBX-MON-RF and UBX-MON-COMMS need protocol version 27 or later,
+/// UBX-MON-RF is left out when building without any of these protocols.
+#[derive(Debug)]
+pub struct StatusSnapshot {
+    /// When polling started
+    pub taken_at: SystemTime,
+    pub mon_hw: Result<MonHwOwned, Error>,
+    #[cfg(any(
+        feature = "ubx_proto27",
+        feature = "ubx_proto31",
+        feature = "ubx_proto33",
+    ))]
+    pub mon_rf: Result<MonRfOwned, Error>,
+    pub mon_comms: Result<MonCommsOwned, Error>,
+    pub nav_status: Result<NavStatusOwned, Error>,
+    pub nav_dop: Result<NavDopOwned, Error>,
+    pub nav_sat: Result<NavSatOwned, Error>,
+}
+
+impl StatusSnapshot {
+    /// Polls all messages of the snapshot one after the other, waiting up to `timeout` for
+    /// each response with the retries of [Device::poll]
+    pub fn poll<P: UbxProtocol>(device: &mut Device<P>, timeout: Duration) -> Self {
+        Self {
+            taken_at: SystemTime::now(),
+            mon_hw: device.poll::<MonHw>(timeout),
+            #[cfg(any(
+                feature = "ubx_proto27",
+                feature = "ubx_proto31",
+                feature = "ubx_proto33",
+            ))]
+            mon_rf: device.poll::<MonRf>(timeout),
+            mon_comms: device.poll::<MonComms>(timeout),
+            nav_status: device.poll::<NavStatus>(timeout),
+            nav_dop: device.poll::<NavDop>(timeout),
+            nav_sat: device.poll::<NavSat>(timeout),
+        }
+    }
+
+    /// Whether every message of the snapshot was received
+    pub fn is_complete(&self) -> bool {
+        #[cfg(any(
+            feature = "ubx_proto27",
+            feature = "ubx_proto31",
+            feature = "ubx_proto33",
+        ))]
+        if self.mon_rf.is_err() {
+            return false;
+        }
+        self.mon_hw.is_ok()
+            && self.mon_comms.is_ok()
+            && self.nav_status.is_ok()
+            && self.nav_dop.is_ok()
+            && self.nav_sat.is_ok()
+    }
+}
@@ -0,0 +1,217 @@
+//! Connection supervision with automatic reconnection, for receivers that may be unplugged or
+//! reached over a network link that can drop.
+//!
+//! A [Supervisor] opens the transport with a user provided function, applies the registered
+//! configuration profile and runs the [Device]. When reading or writing fails, or the
+//! [watchdog](Supervisor::with_watchdog) fires, it drops the connection and opens a new one
+//! after a [Backoff] delay growing with each failed attempt. Every change of the
+//! [ConnectionState] is reported along with the events of the device:
+//!
+//! ```no_run
+//! use std::time::Duration;
+//! use ublox::proto23::Proto23;
+//! use ublox_device::supervisor::{Supervisor, SupervisorEvent};
+//!
+//! # fn profile() -> Vec<Vec<u8>> { vec![] }
+//! let mut supervisor = Supervisor::<Proto23, _, _>::new(|| {
+//!     Ok(serialport::new("/dev/ttyACM0", 9600)
+//!         .timeout(Duration::from_millis(10))
+//!         .open()?)
+//! })
+//! .with_profile(profile())
+//! .with_watchdog(Duration::from_secs(5));
+//!
+//! loop {
+//!     supervisor.poll(|event| match event {
+//!         SupervisorEvent::StateChanged(state) => println!("{state:?}"),
+//!         SupervisorEvent::Error(e) => eprintln!("{e}"),
+//!         SupervisorEvent::Device(event) => println!("{event:?}"),
+//!     });
+//!     std::thread::sleep(Duration::from_millis(10));
+//! }
+//! ```
+
+use std::{
+    io,
+    time::{Duration, Instant},
+};
+
+use ublox::UbxProtocol;
+
+use crate::{Device, Event, Transport};
+
+/// State of the connection, reported by [SupervisorEvent::StateChanged]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionState {
+    /// Opening the transport after `failures` consecutive failed attempts, counting the loss of
+    /// the previous connection as one
+    Connecting { failures: u32 },
+    /// The transport is open and the profile was sent
+    Connected,
+    /// The connection failed or was lost, the next attempt is made in `retry_in`
+    Disconnected { retry_in: Duration },
+}
+
+/// Reported by [Supervisor::poll]
+#[derive(Debug)]
+pub enum SupervisorEvent<'a> {
+    StateChanged(ConnectionState),
+    /// Opening the transport failed or the connection was lost
+    Error(io::Error),
+    /// Reported by the connected [Device]
+    Device(Event<'a>),
+}
+
+/// Delays between connection attempts, doubling from `initial` up to `max`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Backoff {
+    pub initial: Duration,
+    pub max: Duration,
+}
+
+impl Backoff {
+    /// Delay before the next attempt after `failures` consecutive failures, at least 1
+    pub fn delay(&self, failures: u32) -> Duration {
+        let factor = 1u32
+            .checked_shl(failures.saturating_sub(1))
+            .unwrap_or(u32::MAX);
+        self.initial.saturating_mul(factor).min(self.max)
+    }
+}
+
+impl Default for Backoff {
+    /// 1 s, doubling up to 30 s
+    fn default() -> Self {
+        Self {
+            initial: Duration::from_secs(1),
+            max: Duration::from_secs(30),
+        }
+    }
+}
+
+/// Reconnecting wrapper of a [Device], see the [module documentation](self)
+pub struct Supervisor<P: UbxProtocol, T: Transport, F: FnMut() -> io::Result<T>> {
+    open: F,
+    device: Option<Device<P, T>>,
+    /// Configuration frames sent with [Device::send_config] on each connection
+    profile: Vec<Vec<u8>>,
+    backoff: Backoff,
+    watchdog: Option<Duration>,
+    /// Consecutive failed attempts, counting the loss of the connection as one
+    failures: u32,
+    next_attempt: Instant,
+}
+
+impl<P: UbxProtocol, T: Transport, F: FnMut() -> io::Result<T>> Supervisor<P, T, F> {
+    /// Create a supervisor connecting with `open` on the first call to [Supervisor::poll]
+    pub fn new(open: F) -> Self {
+        Self {
+            open,
+            device: None,
+            profile: Vec::new(),
+            backoff: Backoff::default(),
+            watchdog: None,
+            failures: 0,
+            next_attempt: Instant::now(),
+        }
+    }
+
+    /// Configuration frames, e.g. from builders' `into_packet_bytes()`, applied on each
+    /// connection. Their acknowledgements are reported as [Event::AckResolved] and
+    /// [Event::ConfigApplied].
+    pub fn with_profile(mut self, profile: Vec<Vec<u8>>) -> Self {
+        self.profile = profile;
+        self
+    }
+
+    pub fn with_backoff(mut self, backoff: Backoff) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Consider the connection lost when the receiver sends nothing for `timeout`. Needed for
+    /// links that do not report their loss, e.g. a TCP connection whose peer vanished.
+    pub fn with_watchdog(mut self, timeout: Duration) -> Self {
+        self.watchdog = Some(timeout);
+        self
+    }
+
+    /// The connected device, e.g. to send other messages
+    pub fn device_mut(&mut self) -> Option<&mut Device<P, T>> {
+        self.device.as_mut()
+    }
+
+    pub fn state(&self) -> ConnectionState {
+        if self.device.is_some() {
+            ConnectionState::Connected
+        } else {
+            ConnectionState::Disconnected {
+                retry_in: self.next_attempt.saturating_duration_since(Instant::now()),
+            }
+        }
+    }
+
+    /// Connects when the next attempt is due, otherwise reads the data available with
+    /// [Device::run]. Call it from the application's main loop.
+    pub fn poll(&mut self, mut handler: impl FnMut(SupervisorEvent<'_>)) {
+        let Some(device) = self.device.as_mut() else {
+            if Instant::now() >= self.next_attempt {
+                self.connect(&mut handler);
+            }
+            return;
+        };
+
+        let mut silent = false;
+        let result = device.run(|event| {
+            silent |= matches!(event, Event::DeviceTimeout { .. });
+            handler(SupervisorEvent::Device(event));
+        });
+        let error = match result {
+            Err(e) => e,
+            Ok(()) if silent => io::Error::new(
+                io::ErrorKind::TimedOut,
+                "Nothing received within the watchdog timeout",
+            ),
+            Ok(()) => return,
+        };
+        self.device = None;
+        handler(SupervisorEvent::Error(error));
+        self.schedule_retry(&mut handler);
+    }
+
+    fn connect(&mut self, handler: &mut impl FnMut(SupervisorEvent<'_>)) {
+        handler(SupervisorEvent::StateChanged(ConnectionState::Connecting {
+            failures: self.failures,
+        }));
+        let device = (self.open)().and_then(|port| {
+            let mut device = Device::new(port);
+            if let Some(timeout) = self.watchdog {
+                device = device.with_watchdog(timeout);
+            }
+            for frame in &self.profile {
+                device.send_config(frame)?;
+            }
+            Ok(device)
+        });
+        match device {
+            Ok(device) => {
+                self.device = Some(device);
+                self.failures = 0;
+                handler(SupervisorEvent::StateChanged(ConnectionState::Connected));
+            },
+            Err(e) => {
+                handler(SupervisorEvent::Error(e));
+                self.schedule_retry(handler);
+            },
+        }
+    }
+
+    fn schedule_retry(&mut self, handler: &mut impl FnMut(SupervisorEvent<'_>)) {
+        self.failures += 1;
+        let retry_in = self.backoff.delay(self.failures);
+        self.next_attempt = Instant::now() + retry_in;
+        handler(SupervisorEvent::StateChanged(
+            ConnectionState::Disconnected { retry_in },
+        ));
+    }
+}
@@ -0,0 +1,108 @@
+//! Warnings about the use of the receiver reported by [Event::Warning](crate::Event::Warning),
+//! for mistakes that do not fail outright.
+
+use std::fmt;
+
+use ublox::{
+    firmware::{FirmwareIdentity, ProtocolVersion},
+    product::ProductFamily,
+};
+
+/// Reported once per kind and message, by [Event::Warning](crate::Event::Warning)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Warning {
+    /// A legacy UBX-CFG message, deprecated from u-blox 9 on, was sent to a receiver
+    /// configured with UBX-CFG-VALSET. `replacement` names the configuration keys to set
+    /// instead. Polls are not reported.
+    LegacyConfig {
+        class: u8,
+        msg_id: u8,
+        name: &'static str,
+        replacement: &'static str,
+    },
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::LegacyConfig {
+                name, replacement, ..
+            } => write!(
+                f,
+                "UBX-{name} is deprecated, set {replacement} with UBX-CFG-VALSET instead"
+            ),
+        }
+    }
+}
+
+/// Name and configuration keys replacing the legacy UBX-CFG messages, by message id
+const LEGACY_CFG: &[(u8, &str, &str)] = &[
+    (
+        0x00,
+        "CFG-PRT",
+        "CFG-UART1-*, CFG-UART1INPROT-* and CFG-UART1OUTPROT-*",
+    ),
+    (0x01, "CFG-MSG", "CFG-MSGOUT-*"),
+    (0x02, "CFG-INF", "CFG-INFMSG-*"),
+    (0x06, "CFG-DAT", "CFG-NAVSPG-USRDAT*"),
+    (0x08, "CFG-RATE", "CFG-RATE-*"),
+    (0x13, "CFG-ANT", "CFG-HW-ANT_*"),
+    (0x16, "CFG-SBAS", "CFG-SBAS-*"),
+    (0x17, "CFG-NMEA", "CFG-NMEA-*"),
+    (0x1b, "CFG-USB", "CFG-USB-*"),
+    (0x1e, "CFG-ODO", "CFG-ODO-*"),
+    (0x23, "CFG-NAVX5", "CFG-NAVSPG-*"),
+    (0x24, "CFG-NAV5", "CFG-NAVSPG-*"),
+    (0x31, "CFG-TP5", "CFG-TP-*"),
+    (0x39, "CFG-ITFM", "CFG-ITFM-*"),
+    (0x3b, "CFG-PM2", "CFG-PM-*"),
+    (0x3e, "CFG-GNSS", "CFG-SIGNAL-*"),
+    (0x56, "CFG-ESFALG", "CFG-SFIMU-IMU_MNT_ALG_*"),
+    (0x5c, "CFG-HNR", "CFG-RATE-NAV_PRIO"),
+    (0x69, "CFG-GEOFENCE", "CFG-GEOFENCE-*"),
+    (0x70, "CFG-DGNSS", "CFG-NAVHPG-DGNSSMODE"),
+    (0x71, "CFG-TMODE3", "CFG-TMODE-*"),
+    (0x82, "CFG-ESFWT", "CFG-SFODO-*"),
+];
+
+/// [Warning::LegacyConfig] for the message with `class` and `msg_id`, if it is a legacy
+/// UBX-CFG message
+pub fn legacy_config(class: u8, msg_id: u8) -> Option<Warning> {
+    if class != 0x06 {
+        return None;
+    }
+    let &(_, name, replacement) = LEGACY_CFG.iter().find(|(id, ..)| *id == msg_id)?;
+    Some(Warning::LegacyConfig {
+        class,
+        msg_id,
+        name,
+        replacement,
+    })
+}
+
+/// Whether the receiver is configured with UBX-CFG-VALSET, as from u-blox 9 on. Unknown
+/// products are told apart by their protocol version.
+pub fn uses_cfg_valset(identity: &FirmwareIdentity) -> bool {
+    match ProductFamily::from_identity(identity) {
+        Some(family) => family.uses_cfg_valset(),
+        None => identity
+            .protocol_version()
+            .is_some_and(|version| version >= ProtocolVersion::new(27, 0)),
+    }
+}
+
+/// Class and message id of the UBX frames at the start of `data`, stopping at the first bytes
+/// that are not a frame header. Frames without payload, i.e. polls, are skipped.
+pub(crate) fn frame_ids(mut data: &[u8]) -> impl Iterator<Item = (u8, u8)> + '_ {
+    std::iter::from_fn(move || loop {
+        let [0xb5, 0x62, class, msg_id, len_lo, len_hi, ..] = *data else {
+            return None;
+        };
+        let payload_len = usize::from(u16::from_le_bytes([len_lo, len_hi]));
+        data = data.get(8 + payload_len..).unwrap_or_default();
+        if payload_len > 0 {
+            return Some((class, msg_id));
+        }
+    })
+}
@@ -0,0 +1,86 @@
+//! Configuration against the receiver model of the mock transport
+
+use ublox_device::{
+    mock::{frame, MockTransport, ReceiverModel},
+    ublox::{
+        ack_tracker::AckEvent,
+        cfg_msg::CfgMsgAllPorts,
+        cfg_rate::{AlignmentToReferenceTime, CfgRate, CfgRateBuilder},
+        cfg_val::{CfgKey, CfgLayerSet, CfgTransaction, CfgVal, CfgValSetBuilder},
+        proto23::Proto23,
+        UbxPacketMeta,
+    },
+    Device, Event,
+};
+
+fn cfg_valset(values: &[CfgVal]) -> Vec<u8> {
+    let mut frame = Vec::new();
+    CfgValSetBuilder {
+        version: 0,
+        layers: CfgLayerSet::RAM,
+        transaction: CfgTransaction::None,
+        reserved0: 0,
+        cfg_data: values,
+    }
+    .extend_to(&mut frame);
+    frame
+}
+
+/// Sends `frame` and returns whether the receiver acknowledged it
+fn apply(device: &mut Device<Proto23, MockTransport>, frame: &[u8]) -> bool {
+    device.send_config(frame).unwrap();
+    let mut acked = None;
+    device
+        .run(|event| match event {
+            Event::AckResolved(AckEvent::Acked { .. }) => acked = Some(true),
+            Event::AckResolved(AckEvent::Nacked { .. }) => acked = Some(false),
+            _ => {},
+        })
+        .unwrap();
+    acked.expect("no acknowledgement")
+}
+
+#[test]
+fn model_applies_known_keys_and_rejects_the_others() {
+    let model = ReceiverModel::new()
+        .accepts::<CfgRate>()
+        .with_value(CfgVal::RateMeas(1000))
+        .with_value(CfgVal::Uart1Baudrate(9600));
+    let mut device = Device::<Proto23, _>::new(MockTransport::new().with_model(model));
+
+    assert!(apply(
+        &mut device,
+        &cfg_valset(&[CfgVal::RateMeas(200), CfgVal::Uart1Baudrate(115_200)])
+    ));
+    // Nothing is applied if a key is unknown
+    assert!(!apply(
+        &mut device,
+        &cfg_valset(&[CfgVal::RateMeas(100), CfgVal::SignalGpsL5Ena(true)])
+    ));
+    let model = device.transport().model().unwrap();
+    assert_eq!(model.value(CfgKey::RateMeas), Some(CfgVal::RateMeas(200)));
+    assert_eq!(
+        model.value(CfgKey::Uart1Baudrate),
+        Some(CfgVal::Uart1Baudrate(115_200))
+    );
+    assert_eq!(model.value(CfgKey::SignalGpsL5Ena), None);
+
+    // Read back with UBX-CFG-VALGET
+    assert!(device.supports_key(CfgKey::RateMeas).unwrap());
+    assert!(!device.supports_key(CfgKey::SignalGpsL5Ena).unwrap());
+
+    // Other UBX-CFG messages
+    let cfg_rate = CfgRateBuilder {
+        measure_rate_ms: 100,
+        nav_rate: 1,
+        time_ref: AlignmentToReferenceTime::Gps,
+    }
+    .into_packet_bytes();
+    assert!(apply(&mut device, &cfg_rate));
+    let cfg_msg = frame(
+        CfgMsgAllPorts::CLASS,
+        CfgMsgAllPorts::ID,
+        &[0x01, 0x07, 0, 1, 0, 0, 0, 0],
+    );
+    assert!(!apply(&mut device, &cfg_msg));
+}
@@ -0,0 +1,433 @@
+//! Behavior of the device helpers in scripted exchanges with a receiver
+
+use std::time::Duration;
+
+use ublox_device::{
+    mock::{frame, MockTransport},
+    scenario::{Scenario, ScenarioError},
+    ublox::{
+        ack_tracker::{AckEvent, AckTracker},
+        cfg_rate::{AlignmentToReferenceTime, CfgRate, CfgRateBuilder},
+        cfg_rst::CfgRst,
+        cfg_val::{CfgKey, CfgLayerGet, CfgValGetRequestBuilder},
+        mon_ver::MonVer,
+        proto23::{PacketRef, Proto23},
+        UbxPacket, UbxPacketRequest,
+    },
+    warning::Warning,
+    Device, Error, Event,
+};
+
+fn cfg_rate() -> Vec<u8> {
+    CfgRateBuilder {
+        measure_rate_ms: 100,
+        nav_rate: 1,
+        time_ref: AlignmentToReferenceTime::Gps,
+    }
+    .into_packet_bytes()
+    .to_vec()
+}
+
+fn mon_ver() -> Vec<u8> {
+    let mut payload = [0; 40];
+    payload[..7].copy_from_slice(b"EXT 1.0");
+    payload[30..38].copy_from_slice(b"00080000");
+    frame(0x0a, 0x04, &payload)
+}
+
+/// UBX-MON-VER of a receiver with the given extensions
+fn mon_ver_with(extensions: &[&str]) -> Vec<u8> {
+    let mut payload = vec![0; 40 + 30 * extensions.len()];
+    payload[..7].copy_from_slice(b"EXT 1.0");
+    payload[30..38].copy_from_slice(b"00190000");
+    for (i, extension) in extensions.iter().enumerate() {
+        let start = 40 + 30 * i;
+        payload[start..start + extension.len()].copy_from_slice(extension.as_bytes());
+    }
+    frame(0x0a, 0x04, &payload)
+}
+
+#[test]
+fn configuration_is_acknowledged_then_fixes_stream() {
+    let nav_pvt = frame(0x01, 0x07, &[0; 92]);
+    let scenario = Scenario::new()
+        .host_sends::<CfgRate>()
+        .device_acks()
+        .device_streams(&nav_pvt, 3);
+    let mut device = Device::<Proto23, _>::new(scenario);
+
+    device.write_all(&cfg_rate()).unwrap();
+    let mut acked = false;
+    let mut fixes = 0;
+    device
+        .on_data_available(|packet| match packet {
+            UbxPacket::Proto23(PacketRef::AckAck(ack)) => acked |= ack.is_ack_for::<CfgRate>(),
+            UbxPacket::Proto23(PacketRef::NavPvt(_)) => fixes += 1,
+            _ => {},
+        })
+        .unwrap();
+    assert!(acked);
+    assert_eq!(fixes, 3);
+    device.transport().verify().unwrap();
+}
+
+#[test]
+fn rejected_configuration_is_remembered() {
+    let scenario = Scenario::new().host_sends::<CfgRate>().device_naks();
+    let mut device = Device::<Proto23, _>::new(scenario);
+
+    device.write_all(&cfg_rate()).unwrap();
+    assert!(matches!(
+        device.wait_for_ack::<CfgRate>(),
+        Err(Error::UnsupportedByFirmware(0x06, 0x08))
+    ));
+    assert!(device.is_unsupported::<CfgRate>());
+    device.transport().verify().unwrap();
+}
+
+/// Short description of `event`, leaving out the packet contents and ack latencies
+fn describe(event: &Event<'_>) -> String {
+    match event {
+        Event::Packet(UbxPacket::Proto23(PacketRef::NavPvt(_))) => "NAV-PVT".to_string(),
+        Event::Packet(_) => "other packet".to_string(),
+        Event::AckResolved(AckEvent::Acked { class, msg_id, .. }) => {
+            format!("acked {class:#04x} {msg_id:#04x}")
+        },
+        Event::AckResolved(AckEvent::Nacked { class, msg_id, .. }) => {
+            format!("nacked {class:#04x} {msg_id:#04x}")
+        },
+        event => format!("{event:?}"),
+    }
+}
+
+#[test]
+fn run_reports_packets_and_applied_configuration() {
+    let nav_pvt = frame(0x01, 0x07, &[0; 92]);
+    let scenario = Scenario::new()
+        .host_sends::<CfgRate>()
+        .device_acks()
+        .host_sends::<CfgRate>()
+        .device_acks()
+        .device_sends(&nav_pvt);
+    let mut device = Device::<Proto23, _>::new(scenario);
+
+    device.send_config(&cfg_rate()).unwrap();
+    device.send_config(&cfg_rate()).unwrap();
+    let mut events = Vec::new();
+    device.run(|event| events.push(describe(&event))).unwrap();
+    assert_eq!(
+        events,
+        [
+            "other packet",
+            "acked 0x06 0x08",
+            "other packet",
+            "acked 0x06 0x08",
+            "ConfigApplied",
+            "NAV-PVT",
+        ]
+    );
+    device.transport().verify().unwrap();
+}
+
+#[test]
+fn run_reports_rejected_configuration() {
+    let scenario = Scenario::new()
+        .host_sends::<CfgRate>()
+        .device_naks()
+        .host_sends::<CfgRate>()
+        .device_acks();
+    let mut device = Device::<Proto23, _>::new(scenario);
+
+    device.send_config(&cfg_rate()).unwrap();
+    device.send_config(&cfg_rate()).unwrap();
+    let mut events = Vec::new();
+    device.run(|event| events.push(describe(&event))).unwrap();
+    // The configuration is incomplete
+    assert_eq!(
+        events,
+        [
+            "other packet",
+            "nacked 0x06 0x08",
+            "other packet",
+            "acked 0x06 0x08"
+        ]
+    );
+    assert!(device.is_unsupported::<CfgRate>());
+}
+
+#[test]
+fn run_reports_link_errors() {
+    let mut broken = frame(0x01, 0x07, &[0; 92]);
+    *broken.last_mut().unwrap() ^= 0xff;
+    let mut transport = MockTransport::new();
+    transport.push_input(b"noise");
+    transport.push_input(&broken);
+    let mut device = Device::<Proto23, _>::new(transport).with_watchdog(Duration::ZERO);
+
+    let mut events = Vec::new();
+    device.run(|event| events.push(describe(&event))).unwrap();
+    assert_eq!(events.len(), 3);
+    assert!(events[0].starts_with("ChecksumError"));
+    assert_eq!(events[1], "SyncLost { discarded: 105 }");
+    assert!(events[2].starts_with("DeviceTimeout"));
+
+    // The watchdog is reported once while the receiver is silent
+    events.clear();
+    device.run(|event| events.push(describe(&event))).unwrap();
+    assert!(events.is_empty());
+}
+
+fn cfg_valget(key: CfgKey) -> Vec<u8> {
+    let mut request = Vec::new();
+    CfgValGetRequestBuilder {
+        version: 0,
+        layers: CfgLayerGet::Ram,
+        position: 0,
+        cfg_keys: &[key],
+    }
+    .extend_to(&mut request);
+    request
+}
+
+#[test]
+fn supports_key_probes_each_key_once() {
+    let response = |key: CfgKey| {
+        let mut response = vec![1, 0, 0, 0];
+        response.extend_from_slice(&(key as u32).to_le_bytes());
+        response.push(1);
+        frame(0x06, 0x8b, &response)
+    };
+    let scenario = Scenario::new()
+        .host_sends_frame(&cfg_valget(CfgKey::SignalGpsL5Ena))
+        .device_sends(&response(CfgKey::SignalGpsL5Ena))
+        .host_sends_frame(&cfg_valget(CfgKey::SignalGalE5aEna))
+        .device_naks()
+        .host_sends_frame(&cfg_valget(CfgKey::SignalGpsL1caEna))
+        .device_sends(&response(CfgKey::SignalGpsL5Ena))
+        .device_sends(&response(CfgKey::SignalGpsL1caEna));
+    let mut device = Device::<Proto23, _>::new(scenario);
+
+    assert!(device.supports_key(CfgKey::SignalGpsL5Ena).unwrap());
+    assert!(!device.supports_key(CfgKey::SignalGalE5aEna).unwrap());
+    // The stale answer for another key is skipped
+    assert!(device.supports_key(CfgKey::SignalGpsL1caEna).unwrap());
+    // Cached, nothing is sent again
+    assert!(device.supports_key(CfgKey::SignalGpsL5Ena).unwrap());
+    assert!(!device.supports_key(CfgKey::SignalGalE5aEna).unwrap());
+    device.transport().verify().unwrap();
+}
+
+#[test]
+fn poll_sends_the_request_frame() {
+    let request = UbxPacketRequest::request_for::<MonVer>().into_packet_bytes();
+    let scenario = Scenario::new()
+        .host_sends_frame(&request)
+        .device_sends(&mon_ver());
+    let mut device = Device::<Proto23, _>::new(scenario);
+
+    let version = device.poll::<MonVer>(Duration::from_secs(1)).unwrap();
+    assert_eq!(version.software_version(), Ok("EXT 1.0"));
+    device.transport().verify().unwrap();
+}
+
+#[test]
+fn poll_stops_at_a_nak_received_after_the_timeout() {
+    let scenario = Scenario::new().host_sends::<MonVer>().device_naks();
+    let mut device = Device::<Proto23, _>::new(scenario);
+
+    // The UBX-ACK-NAK is only read once the timeout expired, before the request is resent
+    assert!(matches!(
+        device.poll::<MonVer>(Duration::ZERO),
+        Err(Error::UnsupportedByFirmware(0x0a, 0x04))
+    ));
+    device.transport().verify().unwrap();
+    assert!(device.is_unsupported::<MonVer>());
+}
+
+#[test]
+fn ack_tracker_matches_scripted_acknowledgements() {
+    let scenario = Scenario::new()
+        .host_sends::<CfgRate>()
+        .device_acks()
+        .host_sends::<CfgRate>()
+        .device_naks();
+    let mut device = Device::<Proto23, _>::new(scenario);
+    let mut tracker = AckTracker::<4>::new(Duration::from_millis(500));
+
+    let mut events = Vec::new();
+    for sent_at in [Duration::from_millis(0), Duration::from_millis(100)] {
+        let frame = cfg_rate();
+        device.write_all(&frame).unwrap();
+        assert!(tracker.record_frame(&frame, sent_at));
+        device
+            .on_data_available(|packet| {
+                events.extend(tracker.update(&packet, sent_at + Duration::from_millis(20)));
+            })
+            .unwrap();
+    }
+    assert_eq!(
+        events,
+        [
+            AckEvent::Acked {
+                class: 0x06,
+                msg_id: 0x08,
+                latency: Duration::from_millis(20),
+            },
+            AckEvent::Nacked {
+                class: 0x06,
+                msg_id: 0x08,
+                latency: Duration::from_millis(20),
+            },
+        ]
+    );
+    device.transport().verify().unwrap();
+}
+
+#[test]
+fn frame_out_of_order_fails_the_write() {
+    let scenario = Scenario::new().host_sends::<CfgRst>().device_acks();
+    let mut device = Device::<Proto23, _>::new(scenario);
+
+    assert!(device.write_all(&cfg_rate()).is_err());
+    assert_eq!(
+        device.transport().verify(),
+        Err(ScenarioError::UnexpectedFrame {
+            step: 1,
+            expected: "host sends (0x06, 0x04)".to_string(),
+            class: 0x06,
+            msg_id: 0x08,
+        })
+    );
+}
+
+#[test]
+fn frame_with_other_payload_fails_the_write() {
+    let other = CfgRateBuilder {
+        measure_rate_ms: 1000,
+        nav_rate: 1,
+        time_ref: AlignmentToReferenceTime::Gps,
+    }
+    .into_packet_bytes()
+    .to_vec();
+    let scenario = Scenario::new().host_sends_frame(&cfg_rate());
+    let mut device = Device::<Proto23, _>::new(scenario);
+
+    assert!(device.write_all(&other).is_err());
+    // Further writes fail as well
+    assert!(device.write_all(&cfg_rate()).is_err());
+    assert!(matches!(
+        device.transport().verify(),
+        Err(ScenarioError::UnexpectedFrame { step: 1, .. })
+    ));
+}
+
+#[test]
+fn unfinished_script_is_reported() {
+    let scenario = Scenario::new()
+        .host_sends::<CfgRate>()
+        .device_acks()
+        .host_sends::<CfgRst>();
+    let mut device = Device::<Proto23, _>::new(scenario);
+
+    device.write_all(&cfg_rate()).unwrap();
+    let err = device.transport().verify().unwrap_err();
+    assert_eq!(
+        err,
+        ScenarioError::Incomplete {
+            step: 3,
+            expected: "host sends (0x06, 0x04)".to_string(),
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "step 3: expected host sends (0x06, 0x04), host stopped"
+    );
+}
+
+#[test]
+fn frames_after_the_script_are_unexpected() {
+    let mut device = Device::<Proto23, _>::new(Scenario::new());
+    assert!(device.write_all(&cfg_rate()).is_err());
+    assert_eq!(
+        device.transport().verify().unwrap_err().to_string(),
+        "step 1: expected the end, host sent (0x06, 0x08)"
+    );
+}
+
+/// Warnings reported by [Device::run] after sending CFG-RATE twice to the receiver identified
+/// by `mon_ver`
+fn legacy_config_warnings(mon_ver: &[u8]) -> Vec<Warning> {
+    let scenario = Scenario::new()
+        .device_sends(mon_ver)
+        .host_sends::<CfgRate>()
+        .device_naks()
+        .host_sends::<CfgRate>()
+        .device_naks();
+    let mut device = Device::<Proto23, _>::new(scenario);
+    let mut warnings = Vec::new();
+    let mut collect = |event: Event<'_>| {
+        if let Event::Warning(warning) = event {
+            warnings.push(warning);
+        }
+    };
+
+    device.run(&mut collect).unwrap();
+    assert!(device.identity().is_some());
+    for _ in 0..2 {
+        device.send_config(&cfg_rate()).unwrap();
+        device.run(&mut collect).unwrap();
+    }
+    device.transport().verify().unwrap();
+    warnings
+}
+
+#[test]
+fn legacy_configuration_is_reported_once_to_generation_9_receivers() {
+    let warnings = legacy_config_warnings(&mon_ver_with(&["PROTVER=27.31", "MOD=ZED-F9P"]));
+    assert_eq!(
+        warnings,
+        [Warning::LegacyConfig {
+            class: 0x06,
+            msg_id: 0x08,
+            name: "CFG-RATE",
+            replacement: "CFG-RATE-*",
+        }]
+    );
+    assert_eq!(
+        warnings[0].to_string(),
+        "UBX-CFG-RATE is deprecated, set CFG-RATE-* with UBX-CFG-VALSET instead"
+    );
+
+    // An unknown product is told apart by its protocol version
+    assert_eq!(
+        legacy_config_warnings(&mon_ver_with(&["PROTVER=34.10"])).len(),
+        1
+    );
+}
+
+#[test]
+fn polling_legacy_configuration_is_not_reported() {
+    let scenario = Scenario::new()
+        .device_sends(&mon_ver_with(&["PROTVER=27.31", "MOD=ZED-F9P"]))
+        .host_sends::<CfgRate>();
+    let mut device = Device::<Proto23, _>::new(scenario);
+    let mut warnings = 0;
+
+    device.run(|_| {}).unwrap();
+    device.write_all(&frame(0x06, 0x08, &[])).unwrap();
+    device
+        .run(|event| {
+            if let Event::Warning(_) = event {
+                warnings += 1;
+            }
+        })
+        .unwrap();
+    device.transport().verify().unwrap();
+    assert_eq!(warnings, 0);
+}
+
+#[test]
+fn legacy_configuration_is_fine_for_older_receivers() {
+    let warnings = legacy_config_warnings(&mon_ver_with(&["PROTVER=18.00", "MOD=NEO-M8N-0"]));
+    assert!(warnings.is_empty());
+}
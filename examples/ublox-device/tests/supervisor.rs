@@ -0,0 +1,89 @@
+//! Reconnection of the supervisor against mock transports
+
+use std::{io, time::Duration};
+
+use ublox_device::{
+    mock::{frame, MockTransport},
+    supervisor::{Backoff, ConnectionState, Supervisor, SupervisorEvent},
+    ublox::{
+        cfg_rate::{AlignmentToReferenceTime, CfgRateBuilder},
+        proto23::Proto23,
+    },
+    Event,
+};
+
+fn cfg_rate() -> Vec<u8> {
+    CfgRateBuilder {
+        measure_rate_ms: 100,
+        nav_rate: 1,
+        time_ref: AlignmentToReferenceTime::Gps,
+    }
+    .into_packet_bytes()
+    .to_vec()
+}
+
+/// Short description of `event`, leaving out the device events but the applied configuration
+fn describe(event: SupervisorEvent<'_>) -> Option<String> {
+    match event {
+        SupervisorEvent::StateChanged(state) => Some(format!("{state:?}")),
+        SupervisorEvent::Error(e) => Some(format!("error: {e}")),
+        SupervisorEvent::Device(Event::ConfigApplied) => Some("ConfigApplied".to_string()),
+        SupervisorEvent::Device(_) => None,
+    }
+}
+
+#[test]
+fn backoff_doubles_up_to_its_maximum() {
+    let backoff = Backoff {
+        initial: Duration::from_millis(100),
+        max: Duration::from_millis(500),
+    };
+    let delays: Vec<_> = (1..=5).map(|failures| backoff.delay(failures)).collect();
+    assert_eq!(delays, [100, 200, 400, 500, 500].map(Duration::from_millis));
+    assert_eq!(backoff.delay(u32::MAX), backoff.max);
+}
+
+#[test]
+fn reconnects_and_reapplies_the_profile() {
+    let mut attempts = 0;
+    let mut supervisor = Supervisor::<Proto23, _, _>::new(|| {
+        attempts += 1;
+        if attempts == 1 {
+            return Err(io::ErrorKind::NotFound.into());
+        }
+        let mut transport = MockTransport::new();
+        transport.push_input(&frame(0x01, 0x07, &[0; 92]));
+        Ok(transport)
+    })
+    .with_profile(vec![cfg_rate()])
+    .with_backoff(Backoff {
+        initial: Duration::ZERO,
+        max: Duration::ZERO,
+    })
+    .with_watchdog(Duration::ZERO);
+
+    let mut events = Vec::new();
+    for _ in 0..4 {
+        supervisor.poll(|event| events.extend(describe(event)));
+    }
+    assert_eq!(
+        events,
+        [
+            "Connecting { failures: 0 }",
+            "error: entity not found",
+            "Disconnected { retry_in: 0ns }",
+            "Connecting { failures: 1 }",
+            "Connected",
+            // The receiver acknowledges the profile, then goes silent
+            "ConfigApplied",
+            "error: Nothing received within the watchdog timeout",
+            "Disconnected { retry_in: 0ns }",
+            "Connecting { failures: 1 }",
+            "Connected",
+        ]
+    );
+
+    let device = supervisor.device_mut().unwrap();
+    assert_eq!(device.transport().written_frames(), [(0x06, 0x08)]);
+    assert_eq!(supervisor.state(), ConnectionState::Connected);
+}
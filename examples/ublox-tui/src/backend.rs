@@ -193,8 +193,8 @@ impl SenderWrapper {
         trace!("{:?}", pkg);
         info!(
             "SW version: {} HW version: {}; Extensions: {:?}",
-            pkg.software_version(),
-            pkg.hardware_version(),
+            pkg.software_version().unwrap_or_default(),
+            pkg.hardware_version().unwrap_or_default(),
             pkg.extension().collect::<Vec<&str>>()
         );
         let mut state = MonVersionWidgetState::default();
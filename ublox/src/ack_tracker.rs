@@ -0,0 +1,196 @@
+//! Correlation of UBX-ACK-ACK and UBX-ACK-NAK with the configuration frames they answer.
+//!
+//! The receiver acknowledges every CFG message, and some commands, with the class and id of
+//! the message only. [AckTracker] remembers the frames sent along with the time they were
+//! written, matches the acknowledgements against them in the order they were sent and
+//! reports those left unanswered for longer than a timeout. Timestamps are [Duration]s since
+//! an arbitrary epoch, so that any monotonic clock can be used, and the tracker has a fixed
+//! capacity so that it can be used without an allocator.
+//!
+//! ```
+//! use core::time::Duration;
+//! use ublox::{ack_tracker::{AckEvent, AckTracker}, ParserBuilder};
+//!
+//! let mut tracker = AckTracker::<4>::new(Duration::from_millis(500));
+//! // UBX-CFG-RATE written to the receiver
+//! let cfg_rate = [0xb5, 0x62, 0x06, 0x08, 0x06, 0x00, 0x64, 0x00, 0x01, 0x00, 0x01, 0x00, 0x7a, 0x12];
+//! assert!(tracker.record_frame(&cfg_rate, Duration::from_millis(1000)));
+//!
+//! let mut parser = ParserBuilder::new().with_fixed_buffer::<1024>();
+//! let mut it = parser.consume_ubx(&[0xb5, 0x62, 0x05, 0x01, 0x02, 0x00, 0x06, 0x08, 0x16, 0x3f]);
+//! let Some(Ok(packet)) = it.next() else { panic!() };
+//!
+//! assert_eq!(
+//!     tracker.update(&packet, Duration::from_millis(1030)),
+//!     Some(AckEvent::Acked {
+//!         class: 0x06,
+//!         msg_id: 0x08,
+//!         latency: Duration::from_millis(30),
+//!     })
+//! );
+//! assert!(tracker.is_idle());
+//! ```
+
+use core::time::Duration;
+
+use crate::{
+    constants::{UBX_CLASS_OFFSET, UBX_HEADER_LEN, UBX_MSG_ID_OFFSET},
+    packet_match::match_packet,
+    UbxPacket,
+};
+
+/// A frame waiting for its acknowledgement
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SentFrame {
+    pub class: u8,
+    pub msg_id: u8,
+    /// Time the frame was written
+    pub sent_at: Duration,
+}
+
+/// Outcome of a frame recorded by an [AckTracker]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AckEvent {
+    /// The receiver accepted the frame, `latency` after it was sent
+    Acked {
+        class: u8,
+        msg_id: u8,
+        latency: Duration,
+    },
+    /// The receiver rejected the frame, `latency` after it was sent
+    Nacked {
+        class: u8,
+        msg_id: u8,
+        latency: Duration,
+    },
+    /// No acknowledgement arrived within the timeout
+    TimedOut { class: u8, msg_id: u8 },
+    /// An acknowledgement for a frame that was not recorded, or whose acknowledgement already
+    /// timed out
+    Unexpected { class: u8, msg_id: u8, ack: bool },
+}
+
+/// Matches acknowledgements against up to `N` outstanding frames, see the
+/// [module documentation](self)
+#[derive(Debug, Clone)]
+pub struct AckTracker<const N: usize = 16> {
+    timeout: Duration,
+    /// Outstanding frames, in the order they were sent
+    pending: [Option<SentFrame>; N],
+    len: usize,
+}
+
+impl<const N: usize> AckTracker<N> {
+    /// Create a tracker reporting frames not acknowledged within `timeout` as timed out
+    pub const fn new(timeout: Duration) -> Self {
+        Self {
+            timeout,
+            pending: [None; N],
+            len: 0,
+        }
+    }
+
+    /// Record that a frame of `class` and `msg_id` was sent at `now`. Returns `false` without
+    /// recording it if `N` frames are already waiting for their acknowledgement.
+    pub fn record(&mut self, class: u8, msg_id: u8, now: Duration) -> bool {
+        if self.len == N {
+            return false;
+        }
+        self.pending[self.len] = Some(SentFrame {
+            class,
+            msg_id,
+            sent_at: now,
+        });
+        self.len += 1;
+        true
+    }
+
+    /// Like [AckTracker::record], with the class and id read from the header of the UBX
+    /// `frame`, e.g. the output of a builder's `into_packet_bytes()`. Returns `false` if
+    /// `frame` is too short to be a UBX frame.
+    pub fn record_frame(&mut self, frame: &[u8], now: Duration) -> bool {
+        if frame.len() < UBX_HEADER_LEN {
+            return false;
+        }
+        self.record(frame[UBX_CLASS_OFFSET], frame[UBX_MSG_ID_OFFSET], now)
+    }
+
+    /// Match `packet`, received at `now`, against the outstanding frames. Returns the event
+    /// when `packet` is a UBX-ACK-ACK or UBX-ACK-NAK, other packets are ignored.
+    pub fn update(&mut self, packet: &UbxPacket<'_>, now: Duration) -> Option<AckEvent> {
+        let (class, msg_id, ack) = ack_report(packet)?;
+        Some(self.handle_ack(class, msg_id, ack, now))
+    }
+
+    /// Match an acknowledgement of `class` and `msg_id` received at `now`, an UBX-ACK-ACK if
+    /// `ack` is set, otherwise an UBX-ACK-NAK, against the oldest outstanding frame of that
+    /// class and id
+    pub fn handle_ack(&mut self, class: u8, msg_id: u8, ack: bool, now: Duration) -> AckEvent {
+        let Some(sent) = self.remove(|sent| (sent.class, sent.msg_id) == (class, msg_id)) else {
+            return AckEvent::Unexpected { class, msg_id, ack };
+        };
+        let latency = now.saturating_sub(sent.sent_at);
+        if ack {
+            AckEvent::Acked {
+                class,
+                msg_id,
+                latency,
+            }
+        } else {
+            AckEvent::Nacked {
+                class,
+                msg_id,
+                latency,
+            }
+        }
+    }
+
+    /// Remove the oldest frame left unacknowledged for longer than the timeout at `now`.
+    /// Call it until it returns `None`, at the latest at [AckTracker::next_deadline].
+    pub fn poll_timeout(&mut self, now: Duration) -> Option<AckEvent> {
+        let timeout = self.timeout;
+        let sent = self.remove(|sent| now.saturating_sub(sent.sent_at) >= timeout)?;
+        Some(AckEvent::TimedOut {
+            class: sent.class,
+            msg_id: sent.msg_id,
+        })
+    }
+
+    /// Time at which the oldest outstanding frame times out, if any
+    pub fn next_deadline(&self) -> Option<Duration> {
+        self.pending().map(|sent| sent.sent_at + self.timeout).min()
+    }
+
+    /// The frames waiting for their acknowledgement, oldest first
+    pub fn pending(&self) -> impl Iterator<Item = &SentFrame> {
+        self.pending[..self.len].iter().flatten()
+    }
+
+    /// Whether no frame is waiting for its acknowledgement
+    pub const fn is_idle(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Forget all outstanding frames, e.g. after a receiver reset
+    pub fn clear(&mut self) {
+        self.pending = [None; N];
+        self.len = 0;
+    }
+
+    /// Remove the oldest frame accepted by `matches`, keeping the others in order
+    fn remove(&mut self, matches: impl Fn(&SentFrame) -> bool) -> Option<SentFrame> {
+        let pos = self.pending().position(matches)?;
+        let sent = self.pending[pos].take();
+        self.pending[pos..self.len].rotate_left(1);
+        self.len -= 1;
+        sent
+    }
+}
+
+/// Class and id of the message acknowledged by `packet`, and whether it was accepted
+fn ack_report(packet: &UbxPacket<'_>) -> Option<(u8, u8, bool)> {
+    match_packet!(packet, {
+        PacketRef::AckAck(ack) => Some((ack.class(), ack.msg_id(), true)),
+        PacketRef::AckNak(nak) => Some((nak.class(), nak.msg_id(), false)),
+    } _ => None)
+}
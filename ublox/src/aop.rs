@@ -0,0 +1,147 @@
+//! AssistNow Autonomous control, enabling the orbit prediction of the receiver and following
+//! its state.
+//!
+//! With AssistNow Autonomous the receiver predicts satellite orbits from the broadcast
+//! ephemerides it has seen, for faster starts without an internet connection. u-blox 8
+//! receivers are configured with UBX-CFG-NAVX5 and report their state in UBX-NAV-AOPSTATUS,
+//! later generations use the `CFG-ANA-*` configuration keys:
+//!
+//! ```
+//! use ublox::{aop::AopControl, nav_aop_status::AopStatus, proto23::Proto23, ParserBuilder};
+//!
+//! // UBX-CFG-NAVX5 enabling AssistNow Autonomous with the default orbit error limit
+//! let enable = AopControl::navx5_packet(true, 0);
+//! assert_eq!(enable[2..4], [0x06, 0x23]);
+//!
+//! let mut aop = AopControl::new();
+//! let mut parser = ParserBuilder::new()
+//!     .with_protocol::<Proto23>()
+//!     .with_fixed_buffer::<1024>();
+//! // UBX-NAV-AOPSTATUS, enabled and computing
+//! let mut it = parser.consume_ubx(&[
+//!     0xb5, 0x62, 0x01, 0x60, 0x10, 0x00, 0xe8, 0x03, 0x00, 0x00, 0x01, 0x01, 0x00, 0x00, 0x00,
+//!     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x5e, 0x18,
+//! ]);
+//! let Some(Ok(packet)) = it.next() else { panic!() };
+//!
+//! assert_eq!(aop.update(&packet), Some(AopStatus::Running));
+//! assert_eq!(aop.is_enabled(), Some(true));
+//! ```
+
+use crate::{
+    cfg_navx5::{AopCfg, CfgNavX5Builder},
+    cfg_val::CfgVal,
+    nav_aop_status::AopStatus,
+    packet_match::match_packet,
+    UbxPacket,
+};
+
+/// Builds the AssistNow Autonomous configuration messages and tracks the state reported by
+/// the receiver
+#[derive(Debug, Clone, Default)]
+pub struct AopControl {
+    enabled: Option<bool>,
+    status: Option<AopStatus>,
+}
+
+impl AopControl {
+    pub const fn new() -> Self {
+        Self {
+            enabled: None,
+            status: None,
+        }
+    }
+
+    /// UBX-CFG-NAVX5 frame enabling or disabling AssistNow Autonomous on u-blox 8 receivers,
+    /// leaving the other navigation settings untouched. `orb_max_err` is the maximum acceptable
+    /// modeled orbit error in m, 0 for the firmware default.
+    pub fn navx5_packet(enable: bool, orb_max_err: u16) -> [u8; CfgNavX5Builder::PACKET_LEN] {
+        let aop_cfg = if enable {
+            AopCfg::USE_AOP
+        } else {
+            AopCfg::empty()
+        };
+        CfgNavX5Builder::default()
+            .set_version(2)
+            .set_aop_cfg(aop_cfg)
+            .set_aop_orb_max_err(orb_max_err)
+            .into_packet_bytes()
+    }
+
+    /// `CFG-ANA-*` values enabling or disabling AssistNow Autonomous on generation 9 and later
+    /// receivers, for a UBX-CFG-VALSET. `orb_max_err` as for [AopControl::navx5_packet].
+    pub const fn cfg_vals(enable: bool, orb_max_err: u16) -> [CfgVal; 2] {
+        [CfgVal::AnaUseAna(enable), CfgVal::AnaOrbMaxErr(orb_max_err)]
+    }
+
+    /// Update the state from `packet`, which is a UBX-NAV-AOPSTATUS, a UBX-CFG-NAVX5 poll
+    /// response or a UBX-CFG-VALGET response. Returns the state of the orbit prediction when
+    /// `packet` reports it, other packets are ignored.
+    pub fn update(&mut self, packet: &UbxPacket<'_>) -> Option<AopStatus> {
+        let report = aop_report(packet)?;
+        if report.enabled.is_some() {
+            self.enabled = report.enabled;
+        }
+        if report.status.is_some() {
+            self.status = report.status;
+        }
+        report.status
+    }
+
+    /// Whether AssistNow Autonomous is enabled, once the receiver reported its configuration
+    pub fn is_enabled(&self) -> Option<bool> {
+        self.enabled
+    }
+
+    /// The last reported state of the orbit prediction. Only u-blox 8 receivers report it.
+    pub fn status(&self) -> Option<AopStatus> {
+        self.status
+    }
+}
+
+struct Report {
+    enabled: Option<bool>,
+    status: Option<AopStatus>,
+}
+
+impl Report {
+    fn config(aop_cfg: AopCfg) -> Option<Self> {
+        Some(Self {
+            enabled: Some(aop_cfg.contains(AopCfg::USE_AOP)),
+            status: None,
+        })
+    }
+}
+
+fn aop_report(packet: &UbxPacket<'_>) -> Option<Report> {
+    match_packet!(packet, {
+        PacketRef::CfgNavX5(navx5) => Report::config(navx5.aop_cfg()),
+        PacketRef::CfgNavX5V3(navx5) => Report::config(navx5.aop_cfg()),
+    } proto23 {
+        PacketRef::NavAopStatus(status) => Some(Report {
+            enabled: Some(status.aop_cfg().contains(AopCfg::USE_AOP)),
+            status: Some(status.status()),
+        }),
+    } proto27 {
+        PacketRef::CfgValGetResponse(response) => cfg_val_report(response.cfg_data()),
+    } proto31 {
+        PacketRef::CfgValGetResponse(response) => cfg_val_report(response.cfg_data()),
+    } proto33 {
+        PacketRef::CfgValGetResponse(response) => cfg_val_report(response.cfg_data()),
+    } _ => None)
+}
+
+#[cfg(any(
+    feature = "ubx_proto27",
+    feature = "ubx_proto31",
+    feature = "ubx_proto33"
+))]
+fn cfg_val_report(mut values: impl Iterator<Item = CfgVal>) -> Option<Report> {
+    values.find_map(|value| match value {
+        CfgVal::AnaUseAna(enabled) => Some(Report {
+            enabled: Some(enabled),
+            status: None,
+        }),
+        _ => None,
+    })
+}
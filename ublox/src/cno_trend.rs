@@ -0,0 +1,184 @@
+//! Signal strength trending over UBX-NAV-SAT, e.g. to monitor antenna health in the field.
+//!
+//! [CnoTrend] keeps a C/N0 histogram and a rolling mean per constellation and reports a
+//! [CnoDrop] when the mean C/N0 of an epoch falls well below the rolling mean, as happens when
+//! the antenna is disconnected or interference sets in.
+//!
+//! ```
+//! use ublox::{cfg_gnss::GnssId, cno_trend::CnoTrend, proto23::{PacketRef, Proto23}, UbxProtocol};
+//!
+//! // UBX-NAV-SAT payloads with a single GPS satellite
+//! let nav_sat = |itow: u8, cno: u8| {
+//!     [itow, 0, 0, 0, 1, 1, 0, 0, 0, 5, cno, 30, 0, 0, 0, 0, 0, 0, 0, 0]
+//! };
+//!
+//! let mut trend = CnoTrend::new().with_drop_threshold(10.0);
+//! let mut drops = 0;
+//! for (itow, cno) in [(1, 42), (2, 44), (3, 43), (4, 12)] {
+//!     let payload = nav_sat(itow, cno);
+//!     let Ok(PacketRef::NavSat(nav_sat)) = Proto23::match_packet(0x01, 0x35, &payload) else {
+//!         panic!()
+//!     };
+//!     for drop in trend.update(&nav_sat) {
+//!         assert_eq!(drop.gnss_id, GnssId::GPS);
+//!         assert_eq!(drop.itow, 4);
+//!         assert_eq!(drop.epoch_mean, 12.0);
+//!         drops += 1;
+//!     }
+//! }
+//! assert_eq!(drops, 1);
+//! assert_eq!(trend.constellation(GnssId::GPS).histogram()[8], 3);
+//! ```
+
+use crate::{cfg_gnss::GnssId, nav_sat::NavSatRef};
+
+/// Number of constellations tracked, indexed by their UBX `gnssId`
+const GNSS_COUNT: usize = 8;
+
+/// Width of the C/N0 histogram bins in dBHz
+pub const CNO_BIN_WIDTH: u8 = 5;
+
+/// Number of C/N0 histogram bins, the last one also counts all higher values
+pub const CNO_BINS: usize = 12;
+
+/// C/N0 statistics of one constellation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConstellationCno {
+    histogram: [u32; CNO_BINS],
+    mean: Option<f32>,
+    itow: Option<u32>,
+}
+
+impl ConstellationCno {
+    const fn new() -> Self {
+        Self {
+            histogram: [0; CNO_BINS],
+            mean: None,
+            itow: None,
+        }
+    }
+
+    /// Number of satellite observations per C/N0 bin of [CNO_BIN_WIDTH] dBHz, satellites that
+    /// are not tracked (C/N0 of 0) are not counted
+    pub fn histogram(&self) -> &[u32; CNO_BINS] {
+        &self.histogram
+    }
+
+    /// Rolling mean of the per epoch mean C/N0 in dBHz
+    pub fn mean(&self) -> Option<f32> {
+        self.mean
+    }
+
+    /// GPS time of week in ms of the last epoch that included this constellation
+    pub fn itow(&self) -> Option<u32> {
+        self.itow
+    }
+}
+
+/// A sudden drop of the mean C/N0 of a constellation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CnoDrop {
+    pub gnss_id: GnssId,
+    /// GPS time of week in ms of the epoch with the drop
+    pub itow: u32,
+    /// Rolling mean C/N0 in dBHz before the epoch
+    pub rolling_mean: f32,
+    /// Mean C/N0 in dBHz of the epoch, 0 if no satellite was tracked
+    pub epoch_mean: f32,
+}
+
+/// Accumulates C/N0 statistics per constellation from UBX-NAV-SAT, see the
+/// [module documentation](self)
+#[derive(Debug, Clone)]
+pub struct CnoTrend {
+    drop_threshold: f32,
+    smoothing: f32,
+    constellations: [ConstellationCno; GNSS_COUNT],
+}
+
+impl Default for CnoTrend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CnoTrend {
+    /// Create a trend reporting drops of 6 dB, with a rolling mean over roughly 10 epochs
+    pub const fn new() -> Self {
+        Self {
+            drop_threshold: 6.0,
+            smoothing: 0.1,
+            constellations: [ConstellationCno::new(); GNSS_COUNT],
+        }
+    }
+
+    /// Report a [CnoDrop] when the epoch mean falls `db` or more below the rolling mean
+    pub const fn with_drop_threshold(mut self, db: f32) -> Self {
+        self.drop_threshold = db;
+        self
+    }
+
+    /// Weight of each epoch in the exponential rolling mean, between 0 and 1
+    pub const fn with_smoothing(mut self, smoothing: f32) -> Self {
+        self.smoothing = smoothing;
+        self
+    }
+
+    pub fn constellation(&self, gnss_id: GnssId) -> &ConstellationCno {
+        &self.constellations[gnss_id as usize]
+    }
+
+    /// Add the satellites of an epoch, returning the constellations whose C/N0 dropped.
+    /// Constellations without any satellite in `nav_sat` are left untouched.
+    pub fn update(&mut self, nav_sat: &NavSatRef<'_>) -> impl Iterator<Item = CnoDrop> {
+        let mut sums = [(0u32, 0u32, false); GNSS_COUNT];
+        for sv in nav_sat.svs() {
+            let Some(constellation) = self.constellations.get_mut(usize::from(sv.gnss_id())) else {
+                continue;
+            };
+            let (sum, tracked, seen) = &mut sums[usize::from(sv.gnss_id())];
+            *seen = true;
+            let cno = sv.cno();
+            if cno > 0 {
+                *sum += u32::from(cno);
+                *tracked += 1;
+                let bin = usize::from(cno / CNO_BIN_WIDTH).min(CNO_BINS - 1);
+                constellation.histogram[bin] += 1;
+            }
+        }
+
+        let itow = nav_sat.itow();
+        let mut drops = [None; GNSS_COUNT];
+        for (gnss, (sum, tracked, seen)) in sums.into_iter().enumerate() {
+            if !seen {
+                continue;
+            }
+            let epoch_mean = if tracked > 0 {
+                sum as f32 / tracked as f32
+            } else {
+                0.0
+            };
+            let constellation = &mut self.constellations[gnss];
+            constellation.itow = Some(itow);
+            match constellation.mean {
+                Some(rolling_mean) if rolling_mean - epoch_mean >= self.drop_threshold => {
+                    drops[gnss] = GnssId::try_from(gnss as u8).ok().map(|gnss_id| CnoDrop {
+                        gnss_id,
+                        itow,
+                        rolling_mean,
+                        epoch_mean,
+                    });
+                    // Restart from the new level, so that a lasting drop is reported once
+                    constellation.mean = Some(epoch_mean);
+                },
+                Some(rolling_mean) => {
+                    constellation.mean =
+                        Some(rolling_mean + self.smoothing * (epoch_mean - rolling_mean));
+                },
+                None if tracked > 0 => constellation.mean = Some(epoch_mean),
+                None => {},
+            }
+        }
+        drops.into_iter().flatten()
+    }
+}
@@ -0,0 +1,109 @@
+//! Compact binary encoding of UBX packets for bandwidth constrained links, e.g. LoRa or
+//! satellite backhaul.
+//!
+//! A [CompactPacket] is tagged with the UBX class and message id rather than a type name and
+//! carries the raw payload, so it is encoded by [postcard] in the payload length plus three
+//! bytes (four for payloads of 128 bytes or more). The UBX sync chars and checksum are dropped,
+//! as the link layer is expected to provide its own integrity check.
+//!
+//! ```
+//! use ublox::{compact::CompactPacket, proto23::{PacketRef, Proto23}, ParserBuilder};
+//!
+//! let mut parser = ParserBuilder::new()
+//!     .with_protocol::<Proto23>()
+//!     .with_fixed_buffer::<1024>();
+//! let mut it = parser.consume_ubx(&[0xb5, 0x62, 0x05, 0x01, 0x02, 0x00, 0x06, 0x01, 0x0f, 0x38]);
+//! let Some(Ok(packet)) = it.next() else { panic!() };
+//!
+//! let mut buf = [0u8; 16];
+//! let encoded = CompactPacket::from(&packet).encode(&mut buf).unwrap();
+//! assert_eq!(encoded, [0x05, 0x01, 0x02, 0x06, 0x01]);
+//!
+//! let decoded = CompactPacket::decode(encoded).unwrap();
+//! assert!(matches!(decoded.parse::<Proto23>(), Ok(PacketRef::AckAck(_))));
+//! ```
+//!
+//! Owned packets can be converted as well.
+//!
+//! As [CompactPacket] implements `Serialize`, it can be used with other serde formats such as
+//! CBOR as well, the payload is then encoded as a byte string.
+
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::{packet_match::for_each_protocol, ParserError, UbxPacket, UbxProtocol};
+
+/// A UBX packet reduced to its class, message id and payload
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompactPacket<'a> {
+    pub class: u8,
+    pub msg_id: u8,
+    #[serde(serialize_with = "serialize_bytes")]
+    pub payload: &'a [u8],
+}
+
+fn serialize_bytes<S: Serializer>(payload: &&[u8], serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_bytes(payload)
+}
+
+impl<'a> CompactPacket<'a> {
+    /// Encode the packet with postcard into `buf`, returning the used part of `buf`
+    pub fn encode<'b>(&self, buf: &'b mut [u8]) -> Result<&'b mut [u8], postcard::Error> {
+        postcard::to_slice(self, buf)
+    }
+
+    /// Decode a packet encoded by [CompactPacket::encode], borrowing the payload from `bytes`
+    pub fn decode(bytes: &'a [u8]) -> Result<Self, postcard::Error> {
+        postcard::from_bytes(bytes)
+    }
+
+    /// Interpret the packet for protocol version `P`, use `to_owned()` on the result to keep it
+    /// beyond the lifetime of the encoded data
+    pub fn parse<P: UbxProtocol>(&self) -> Result<P::PacketRef<'a>, ParserError> {
+        P::match_packet(self.class, self.msg_id, self.payload)
+    }
+}
+
+impl<'a> From<&UbxPacket<'a>> for CompactPacket<'a> {
+    fn from(packet: &UbxPacket<'a>) -> Self {
+        match packet {
+            #[cfg(feature = "ubx_proto14")]
+            UbxPacket::Proto14(packet) => packet.into(),
+            #[cfg(feature = "ubx_proto23")]
+            UbxPacket::Proto23(packet) => packet.into(),
+            #[cfg(feature = "ubx_proto27")]
+            UbxPacket::Proto27(packet) => packet.into(),
+            #[cfg(feature = "ubx_proto31")]
+            UbxPacket::Proto31(packet) => packet.into(),
+            #[cfg(feature = "ubx_proto33")]
+            UbxPacket::Proto33(packet) => packet.into(),
+        }
+    }
+}
+
+macro_rules! impl_from_packets {
+    ($packets:ident) => {
+        impl<'a> From<&crate::$packets::PacketRef<'a>> for CompactPacket<'a> {
+            fn from(packet: &crate::$packets::PacketRef<'a>) -> Self {
+                let (class, msg_id) = packet.class_and_msg_id();
+                Self {
+                    class,
+                    msg_id,
+                    payload: packet.payload(),
+                }
+            }
+        }
+
+        impl<'a> From<&'a crate::$packets::PacketOwned> for CompactPacket<'a> {
+            fn from(packet: &'a crate::$packets::PacketOwned) -> Self {
+                let (class, msg_id) = packet.class_and_msg_id();
+                Self {
+                    class,
+                    msg_id,
+                    payload: packet.payload(),
+                }
+            }
+        }
+    };
+}
+
+for_each_protocol!(impl_from_packets);
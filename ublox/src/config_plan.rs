@@ -0,0 +1,368 @@
+//! Application of a batch of configuration values, verified by reading them back.
+//!
+//! A [ConfigPlan] splits the values into UBX-CFG-VALSET frames of at most [MAX_CFG_KEYS]
+//! values, sends them one after the other, each once the previous one is acknowledged, then
+//! reads the accepted values back with UBX-CFG-VALGET and compares them with the values set.
+//! Each frame is applied on its own, so that a rejected frame does not prevent the others from
+//! being applied. Like [Session](crate::session::Session), the plan does no I/O itself: the
+//! driver writes the frames returned by [ConfigPlan::poll_output], passes the packets received
+//! to [ConfigPlan::handle_packet] and calls [ConfigPlan::handle_timeout] with the current time.
+//! Once [ConfigPlan::is_done], [ConfigPlan::outcomes] holds the status of every key.
+//!
+//! ```
+//! use core::time::Duration;
+//! use ublox::{
+//!     cfg_val::{CfgLayerSet, CfgVal},
+//!     config_plan::{ConfigPlan, KeyStatus},
+//! };
+//!
+//! let mut plan = ConfigPlan::new(
+//!     [CfgVal::RateMeas(200), CfgVal::Uart1Baudrate(115_200)],
+//!     CfgLayerSet::RAM,
+//! );
+//! let valset = plan.poll_output(Duration::ZERO).unwrap();
+//! assert_eq!(valset[2..4], [0x06, 0x8a]);
+//! // Nothing else is sent until the receiver answers
+//! assert_eq!(plan.poll_output(Duration::ZERO), None);
+//!
+//! // No answer within the timeout
+//! plan.handle_timeout(Duration::from_secs(1));
+//! assert!(plan.is_done());
+//! assert_eq!(plan.outcomes()[0].status, KeyStatus::TimedOut);
+//! ```
+
+use core::time::Duration;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{
+    cfg_val::{
+        CfgLayerGet, CfgLayerSet, CfgTransaction, CfgVal, CfgValGetRequest,
+        CfgValGetRequestBuilder, CfgValIter, CfgValSet, CfgValSetBuilder, MAX_CFG_KEYS,
+    },
+    packet_match::match_packet,
+    UbxPacket, UbxPacketMeta,
+};
+
+/// Time a [ConfigPlan] waits for each answer by default
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Status of a key of a [ConfigPlan]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum KeyStatus {
+    /// Not set yet
+    Pending,
+    /// Accepted by the receiver, not read back yet
+    Applied,
+    /// Read back with the value set
+    Verified,
+    /// The receiver rejected the UBX-CFG-VALSET frame with the key, so none of its values
+    /// were applied
+    Rejected,
+    /// Read back with another value
+    Mismatch(CfgVal),
+    /// Accepted by the receiver, but missing from the read back, or the read back was rejected
+    NotReadBack,
+    /// The receiver did not answer the frame with the key within the timeout
+    TimedOut,
+}
+
+/// A value of a [ConfigPlan] and what became of it
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KeyOutcome {
+    pub value: CfgVal,
+    pub status: KeyStatus,
+}
+
+/// What the plan is doing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Stage {
+    /// Setting the values of chunk `n`
+    Set(usize),
+    /// Reading back the values of chunk `n` of the accepted values
+    Verify(usize),
+    Done,
+}
+
+/// Sets and verifies configuration values, see the [module documentation](self)
+#[derive(Debug, Clone)]
+pub struct ConfigPlan {
+    layers: CfgLayerSet,
+    timeout: Duration,
+    outcomes: Vec<KeyOutcome>,
+    /// Indices into `outcomes` of the values to read back
+    verified: Vec<usize>,
+    stage: Stage,
+    /// Set once the frame of the current stage was returned by [ConfigPlan::poll_output]
+    deadline: Option<Duration>,
+}
+
+impl ConfigPlan {
+    /// Plan applying `values` to `layers`. The values are read back from the RAM layer if it
+    /// is one of `layers`, otherwise from BBR, otherwise from Flash.
+    pub fn new(values: impl IntoIterator<Item = CfgVal>, layers: CfgLayerSet) -> Self {
+        let outcomes: Vec<_> = values
+            .into_iter()
+            .map(|value| KeyOutcome {
+                value,
+                status: KeyStatus::Pending,
+            })
+            .collect();
+        let stage = if outcomes.is_empty() {
+            Stage::Done
+        } else {
+            Stage::Set(0)
+        };
+        Self {
+            layers,
+            timeout: DEFAULT_TIMEOUT,
+            outcomes,
+            verified: Vec::new(),
+            stage,
+            deadline: None,
+        }
+    }
+
+    /// Wait `timeout` for each answer of the receiver, [DEFAULT_TIMEOUT] by default
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// The next frame to write to the receiver, sent at `now`. Returns `None` while waiting
+    /// for an answer and once done.
+    pub fn poll_output(&mut self, now: Duration) -> Option<Vec<u8>> {
+        if self.deadline.is_some() {
+            return None;
+        }
+        let mut frame = Vec::new();
+        match self.stage {
+            Stage::Set(chunk) => {
+                let cfg_data: Vec<_> = self.outcomes[chunk_range(chunk, self.outcomes.len())]
+                    .iter()
+                    .map(|outcome| outcome.value)
+                    .collect();
+                CfgValSetBuilder {
+                    version: 0,
+                    layers: self.layers,
+                    transaction: CfgTransaction::None,
+                    reserved0: 0,
+                    cfg_data: &cfg_data,
+                }
+                .extend_to(&mut frame);
+            },
+            Stage::Verify(chunk) => {
+                let cfg_keys: Vec<_> = self.verified[chunk_range(chunk, self.verified.len())]
+                    .iter()
+                    .map(|&i| self.outcomes[i].value.key())
+                    .collect();
+                CfgValGetRequestBuilder {
+                    version: 0,
+                    layers: self.read_layer(),
+                    position: 0,
+                    cfg_keys: &cfg_keys,
+                }
+                .extend_to(&mut frame);
+            },
+            Stage::Done => return None,
+        }
+        self.deadline = Some(now + self.timeout);
+        Some(frame)
+    }
+
+    /// Handle a packet received from the receiver. Returns `true` if it answered the frame
+    /// sent last, other packets are ignored.
+    pub fn handle_packet(&mut self, packet: &UbxPacket<'_>) -> bool {
+        if self.deadline.is_none() {
+            return false;
+        }
+        let reply = plan_reply(packet);
+        match (self.stage, reply) {
+            (Stage::Set(chunk), Some(Reply::Ack { class, msg_id, ack }))
+                if (class, msg_id) == (CfgValSet::CLASS, CfgValSet::ID) =>
+            {
+                let status = if ack {
+                    KeyStatus::Applied
+                } else {
+                    KeyStatus::Rejected
+                };
+                self.set_chunk_status(chunk, status);
+                self.next_stage();
+                true
+            },
+            (Stage::Verify(chunk), Some(Reply::Values(values))) => {
+                for read in values {
+                    let key = read.key();
+                    let found = self.verified[chunk_range(chunk, self.verified.len())]
+                        .iter()
+                        .find(|&&i| self.outcomes[i].value.key() == key);
+                    if let Some(&i) = found {
+                        let outcome = &mut self.outcomes[i];
+                        outcome.status = if read == outcome.value {
+                            KeyStatus::Verified
+                        } else {
+                            KeyStatus::Mismatch(read)
+                        };
+                    }
+                }
+                self.verify_chunk_status(chunk, KeyStatus::NotReadBack);
+                self.next_stage();
+                true
+            },
+            // The response is all that is needed, only a rejection ends the read back
+            (
+                Stage::Verify(chunk),
+                Some(Reply::Ack {
+                    class,
+                    msg_id,
+                    ack: false,
+                }),
+            ) if (class, msg_id) == (CfgValGetRequest::CLASS, CfgValGetRequest::ID) => {
+                self.verify_chunk_status(chunk, KeyStatus::NotReadBack);
+                self.next_stage();
+                true
+            },
+            _ => false,
+        }
+    }
+
+    /// Give up on the frame sent last if it was not answered by `now`, and move on to the next
+    /// one
+    pub fn handle_timeout(&mut self, now: Duration) {
+        if self.deadline.is_none_or(|deadline| now < deadline) {
+            return;
+        }
+        match self.stage {
+            Stage::Set(chunk) => self.set_chunk_status(chunk, KeyStatus::TimedOut),
+            Stage::Verify(chunk) => self.verify_chunk_status(chunk, KeyStatus::TimedOut),
+            Stage::Done => {},
+        }
+        self.next_stage();
+    }
+
+    /// Time at which the frame sent last times out, if waiting for an answer
+    pub fn poll_timeout(&self) -> Option<Duration> {
+        self.deadline
+    }
+
+    /// Whether every value was set and read back, or failed to
+    pub fn is_done(&self) -> bool {
+        self.stage == Stage::Done
+    }
+
+    /// Whether every value was verified
+    pub fn is_success(&self) -> bool {
+        self.outcomes
+            .iter()
+            .all(|outcome| outcome.status == KeyStatus::Verified)
+    }
+
+    /// The values with their status, in the order they were given
+    pub fn outcomes(&self) -> &[KeyOutcome] {
+        &self.outcomes
+    }
+
+    /// The values that were not verified, with their status
+    pub fn failures(&self) -> impl Iterator<Item = &KeyOutcome> {
+        self.outcomes
+            .iter()
+            .filter(|outcome| outcome.status != KeyStatus::Verified)
+    }
+
+    /// The layer the values are read back from
+    fn read_layer(&self) -> CfgLayerGet {
+        if self.layers.contains(CfgLayerSet::RAM) {
+            CfgLayerGet::Ram
+        } else if self.layers.contains(CfgLayerSet::BBR) {
+            CfgLayerGet::Bbr
+        } else {
+            CfgLayerGet::Flash
+        }
+    }
+
+    fn set_chunk_status(&mut self, chunk: usize, status: KeyStatus) {
+        let range = chunk_range(chunk, self.outcomes.len());
+        for outcome in &mut self.outcomes[range] {
+            outcome.status = status;
+        }
+    }
+
+    /// Set the status of the keys of the read back `chunk` not read back yet
+    fn verify_chunk_status(&mut self, chunk: usize, status: KeyStatus) {
+        for &i in &self.verified[chunk_range(chunk, self.verified.len())] {
+            let outcome = &mut self.outcomes[i];
+            if outcome.status == KeyStatus::Applied {
+                outcome.status = status;
+            }
+        }
+    }
+
+    fn next_stage(&mut self) {
+        self.deadline = None;
+        self.stage = match self.stage {
+            Stage::Set(chunk) if (chunk + 1) * chunk_len() < self.outcomes.len() => {
+                Stage::Set(chunk + 1)
+            },
+            Stage::Set(_) => {
+                self.verified = (0..self.outcomes.len())
+                    .filter(|&i| self.outcomes[i].status == KeyStatus::Applied)
+                    .collect();
+                if self.verified.is_empty() {
+                    Stage::Done
+                } else {
+                    Stage::Verify(0)
+                }
+            },
+            Stage::Verify(chunk) if (chunk + 1) * chunk_len() < self.verified.len() => {
+                Stage::Verify(chunk + 1)
+            },
+            Stage::Verify(_) | Stage::Done => Stage::Done,
+        };
+    }
+}
+
+const fn chunk_len() -> usize {
+    MAX_CFG_KEYS as usize
+}
+
+/// Range of chunk `chunk` of a list of `len` items
+fn chunk_range(chunk: usize, len: usize) -> core::ops::Range<usize> {
+    let start = chunk * chunk_len();
+    start..len.min(start + chunk_len())
+}
+
+/// Answer of the receiver to a frame of a [ConfigPlan]
+enum Reply<'a> {
+    Ack {
+        class: u8,
+        msg_id: u8,
+        ack: bool,
+    },
+    #[allow(
+        dead_code,
+        reason = "Only protocols with UBX-CFG-VALGET read values back"
+    )]
+    Values(CfgValIter<'a>),
+}
+
+fn plan_reply<'a>(packet: &'a UbxPacket<'_>) -> Option<Reply<'a>> {
+    match_packet!(packet, {
+        PacketRef::AckAck(ack) => Some(Reply::Ack {
+            class: ack.class(),
+            msg_id: ack.msg_id(),
+            ack: true,
+        }),
+        PacketRef::AckNak(nak) => Some(Reply::Ack {
+            class: nak.class(),
+            msg_id: nak.msg_id(),
+            ack: false,
+        }),
+    } proto27 {
+        PacketRef::CfgValGetResponse(response) => Some(Reply::Values(response.cfg_data())),
+    } proto31 {
+        PacketRef::CfgValGetResponse(response) => Some(Reply::Values(response.cfg_data())),
+    } proto33 {
+        PacketRef::CfgValGetResponse(response) => Some(Reply::Values(response.cfg_data())),
+    } _ => None)
+}
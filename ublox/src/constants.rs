@@ -1,21 +1,8 @@
-/// UBX Sync Character 1 (0xB5 = 'µ')
-pub const UBX_SYNC_CHAR_1: u8 = 0xB5;
-/// UBX Sync Character 2 (0x62 = 'b')
-pub const UBX_SYNC_CHAR_2: u8 = 0x62;
-pub(crate) const UBX_SYNC_SIZE: usize = 2;
-pub(crate) const UBX_PAYLOAD_SIZE_LEN: usize = 2;
-pub(crate) const UBX_CLASS_LEN: usize = 1;
-pub(crate) const UBX_ID_LEN: usize = 1;
-pub(crate) const UBX_HEADER_LEN: usize =
-    UBX_SYNC_SIZE + UBX_CLASS_LEN + UBX_ID_LEN + UBX_PAYLOAD_SIZE_LEN;
-#[allow(dead_code, reason = "Used in tests")]
-pub(crate) const UBX_CHECKSUM_LEN: usize = 2;
-
-pub(crate) const UBX_CLASS_OFFSET: usize = UBX_SYNC_SIZE; // After SYNC_CHAR_1, SYNC_CHAR_2
-pub(crate) const UBX_MSG_ID_OFFSET: usize = UBX_CLASS_OFFSET + UBX_CLASS_LEN; // After CLASS
-pub(crate) const UBX_LENGTH_OFFSET: usize = UBX_MSG_ID_OFFSET + UBX_ID_LEN; // After MSG_ID
-
-// pub(crate) const UBX_CHECKSUM_OFFSET: usize = UBX_HEADER_LEN + UBX_PAYLOAD_SIZE_LEN;
+pub(crate) use ublox_core::constants::{
+    UBX_CHECKSUM_LEN, UBX_CLASS_OFFSET, UBX_HEADER_LEN, UBX_LENGTH_OFFSET, UBX_MSG_ID_OFFSET,
+    UBX_SYNC_SIZE,
+};
+pub use ublox_core::constants::{UBX_SYNC_CHAR_1, UBX_SYNC_CHAR_2};
 
 pub const NMEA_SYNC_CHAR: u8 = 0x24; // '$'
 pub const NMEA_END_CHAR_1: u8 = 0x0d; // '\r' (<CR>)
@@ -27,3 +14,7 @@ pub(crate) const NMEA_MAX_SENTENCE_LENGTH: usize = 82; // Maximum NMEA sentence
 pub const RTCM_SYNC_CHAR: u8 = 0xd3;
 pub(crate) const RTCM_HEADER_SIZE: usize = 3; // sync char (1) + length field (2)
 pub(crate) const RTCM_LENGTH_MASK: u16 = 0x03ff; // 10 bits for length (6 bits reserved)
+pub(crate) const RTCM_CRC_LEN: usize = 3; // CRC-24Q trailer
+
+pub const SPARTN_SYNC_CHAR: u8 = 0x73;
+pub(crate) const SPARTN_HEADER_SIZE: usize = 4; // preamble (1) + type, length, flags and frame CRC (3)
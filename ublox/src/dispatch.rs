@@ -0,0 +1,157 @@
+//! Callback based handling of UBX packets, as an alternative to matching on `PacketRef`.
+//!
+//! A [Dispatcher] owns a [Parser] and calls the handler registered for the type of each frame
+//! it finds. Frames without a handler are not decoded at all, which saves CPU time when only a
+//! few of the configured messages are of interest.
+//!
+//! ```
+//! use ublox::{ack::{AckAck, AckNak}, dispatch::Dispatcher, ParserBuilder};
+//!
+//! let mut acks = Vec::new();
+//! let mut unhandled = Vec::new();
+//! let mut dispatcher = Dispatcher::new(ParserBuilder::new().with_fixed_buffer::<1024>())
+//!     .on::<AckAck>(|ack| acks.push((ack.class(), ack.msg_id())))
+//!     .on::<AckNak>(|_| panic!("unexpected NAK"))
+//!     .on_unknown(|frame| unhandled.push((frame.class, frame.msg_id)));
+//!
+//! // UBX-ACK-ACK for UBX-CFG-PRT followed by an empty UBX-NAV-PVT poll request
+//! dispatcher.feed(&[0xb5, 0x62, 0x05, 0x01, 0x02, 0x00, 0x06, 0x00, 0x0e, 0x37]);
+//! dispatcher.feed(&[0xb5, 0x62, 0x01, 0x07, 0x00, 0x00, 0x08, 0x19]);
+//! drop(dispatcher);
+//!
+//! assert_eq!(acks, [(0x06, 0x00)]);
+//! assert_eq!(unhandled, [(0x01, 0x07)]);
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, vec::Vec};
+
+use crate::{
+    parser::DefaultProtocol, CheckedPacket, Parser, ParserError, UbxPacketRecv, UbxProtocol,
+    UbxUnknownPacketRef, UnderlyingBuffer,
+};
+
+type PayloadHandler<'h> = Box<dyn FnMut(&[u8]) -> Result<(), ParserError> + 'h>;
+type FrameHandler<'h> = Box<dyn FnMut(UbxUnknownPacketRef<'_>) + 'h>;
+type ErrorHandler<'h> = Box<dyn FnMut(ParserError) + 'h>;
+
+/// Handler of one packet type, decoding the payload before calling the user's closure
+struct Handler<'h> {
+    class: u8,
+    msg_id: u8,
+    handle: PayloadHandler<'h>,
+}
+
+/// Routes the UBX packets found in the data fed to it to per message handlers, see the
+/// [module documentation](self)
+pub struct Dispatcher<'h, T: UnderlyingBuffer, P: UbxProtocol = DefaultProtocol> {
+    parser: Parser<T, P>,
+    handlers: Vec<Handler<'h>>,
+    on_frame: Option<FrameHandler<'h>>,
+    on_unknown: Option<FrameHandler<'h>>,
+    on_handled: Option<FrameHandler<'h>>,
+    on_error: Option<ErrorHandler<'h>>,
+}
+
+impl<'h, T: UnderlyingBuffer, P: UbxProtocol> Dispatcher<'h, T, P> {
+    /// Create a dispatcher without any handlers, all packets are dropped until some are added
+    pub fn new(parser: Parser<T, P>) -> Self {
+        Self {
+            parser,
+            handlers: Vec::new(),
+            on_frame: None,
+            on_unknown: None,
+            on_handled: None,
+            on_error: None,
+        }
+    }
+
+    /// Call `f` with every received packet of type `M`. Several handlers may be registered for
+    /// the same type, they are called in the order they were added.
+    pub fn on<M: UbxPacketRecv>(mut self, mut f: impl FnMut(M::Ref<'_>) + 'h) -> Self {
+        self.handlers.push(Handler {
+            class: M::CLASS,
+            msg_id: M::ID,
+            handle: Box::new(move |payload| {
+                f(M::parse_payload(payload)?);
+                Ok(())
+            }),
+        });
+        self
+    }
+
+    /// Call `f` with every frame before its handlers, without decoding its payload, e.g. to
+    /// record it in a [LatestState](crate::latest::LatestState)
+    pub fn on_frame(mut self, f: impl FnMut(UbxUnknownPacketRef<'_>) + 'h) -> Self {
+        self.on_frame = Some(Box::new(f));
+        self
+    }
+
+    /// Call `f` with every frame no handler was registered for, without decoding its payload
+    pub fn on_unknown(mut self, f: impl FnMut(UbxUnknownPacketRef<'_>) + 'h) -> Self {
+        self.on_unknown = Some(Box::new(f));
+        self
+    }
+
+    /// Call `f` with every frame once its handlers returned, e.g. to measure the latency of
+    /// the processing with a [LatencyMeter](crate::latency::LatencyMeter)
+    pub fn on_handled(mut self, f: impl FnMut(UbxUnknownPacketRef<'_>) + 'h) -> Self {
+        self.on_handled = Some(Box::new(f));
+        self
+    }
+
+    /// Call `f` with the errors of the stream, e.g. frames with a bad checksum, and with the
+    /// errors of payloads that failed validation for a handler. They are dropped otherwise.
+    ///
+    /// Frames with a bad checksum never reach the handlers, even from a parser built with
+    /// [ParserBuilder::accept_invalid_checksums](crate::ParserBuilder::accept_invalid_checksums).
+    pub fn on_error(mut self, f: impl FnMut(ParserError) + 'h) -> Self {
+        self.on_error = Some(Box::new(f));
+        self
+    }
+
+    /// Parse `data` and pass all complete frames to their handlers
+    pub fn feed(&mut self, data: &[u8]) {
+        let mut it = self.parser.consume_ubx(data);
+        while let Some(result) = it.next_frame() {
+            // Frames accepted with an invalid checksum are reported as errors
+            let result = result
+                .and_then(CheckedPacket::into_verified)
+                .and_then(|frame| {
+                    if let Some(on_frame) = &mut self.on_frame {
+                        on_frame(frame);
+                    }
+                    let mut handled = false;
+                    for handler in &mut self.handlers {
+                        if (handler.class, handler.msg_id) == (frame.class, frame.msg_id) {
+                            handled = true;
+                            (handler.handle)(frame.payload)?;
+                        }
+                    }
+                    if let (false, Some(on_unknown)) = (handled, &mut self.on_unknown) {
+                        on_unknown(frame);
+                    }
+                    if let Some(on_handled) = &mut self.on_handled {
+                        on_handled(frame);
+                    }
+                    Ok(())
+                });
+            if let (Err(e), Some(on_error)) = (result, &mut self.on_error) {
+                on_error(e);
+            }
+        }
+    }
+
+    pub fn parser(&self) -> &Parser<T, P> {
+        &self.parser
+    }
+
+    pub fn parser_mut(&mut self) -> &mut Parser<T, P> {
+        &mut self.parser
+    }
+
+    /// Removes the handlers, returning the parser along with its buffered data
+    pub fn into_parser(self) -> Parser<T, P> {
+        self.parser
+    }
+}
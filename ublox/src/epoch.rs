@@ -0,0 +1,342 @@
+//! Aggregation of the navigation messages of an epoch, whatever messages the receiver outputs.
+//!
+//! Receivers output the solution of a navigation epoch as several messages sharing the same
+//! iTOW, e.g. UBX-NAV-PVT, UBX-NAV-DOP and UBX-NAV-SAT, optionally followed by UBX-NAV-EOE.
+//! Which of them are enabled depends on the configuration, so the [EpochAggregator] collects
+//! whatever arrives into a [NavEpoch] with `Option` components and a mask of the components
+//! present. The [EpochPolicy] decides when an epoch is emitted: once the components expected
+//! are all there, or at UBX-NAV-EOE. Either way, an epoch is emitted incomplete rather than
+//! lost when the receiver closes it with UBX-NAV-EOE or moves on to the next epoch.
+//!
+//! ```
+//! use ublox::{
+//!     epoch::{EpochAggregator, EpochComponents, EpochPolicy},
+//!     proto23::Proto23,
+//!     UbxPacket, UbxProtocol,
+//! };
+//!
+//! let mut aggregator = EpochAggregator::new(EpochPolicy::WaitForAll(
+//!     EpochComponents::DOP | EpochComponents::STATUS,
+//! ));
+//! let mut dop = [0; 18];
+//! dop[..4].copy_from_slice(&1000u32.to_le_bytes());
+//! dop[12..14].copy_from_slice(&120u16.to_le_bytes());
+//! let packet: UbxPacket = Proto23::match_packet(0x01, 0x04, &dop).unwrap().into();
+//! assert_eq!(aggregator.update(&packet).count(), 0);
+//!
+//! // UBX-NAV-STATUS is not enabled, the epoch is emitted once the next one starts
+//! dop[..4].copy_from_slice(&2000u32.to_le_bytes());
+//! let packet: UbxPacket = Proto23::match_packet(0x01, 0x04, &dop).unwrap().into();
+//! let epoch = aggregator.update(&packet).next().unwrap();
+//! assert_eq!(epoch.itow, 1000);
+//! assert_eq!(epoch.components(), EpochComponents::DOP);
+//! assert!((epoch.dop.unwrap().horizontal - 1.2).abs() < 1e-6);
+//! assert_eq!(epoch.status, None);
+//! ```
+
+use bitflags::bitflags;
+
+use crate::{
+    nav_pvt::common::NavPvtFlags, nav_status::NavStatusFlags, packet_match::match_packet,
+    GnssFixType, UbxPacket,
+};
+
+bitflags! {
+    /// Components of a [NavEpoch]
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct EpochComponents: u8 {
+        /// [NavEpoch::pvt], from UBX-NAV-PVT
+        const PVT = 0x01;
+        /// [NavEpoch::dop], from UBX-NAV-DOP
+        const DOP = 0x02;
+        /// [NavEpoch::status], from UBX-NAV-STATUS
+        const STATUS = 0x04;
+        /// [NavEpoch::clock], from UBX-NAV-CLOCK
+        const CLOCK = 0x08;
+        /// [NavEpoch::satellites], from UBX-NAV-SAT
+        const SATELLITES = 0x10;
+    }
+}
+
+/// When an [EpochAggregator] emits an epoch
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EpochPolicy {
+    /// As soon as all the components given arrived, without waiting for UBX-NAV-EOE. Later
+    /// messages of the same epoch are dropped.
+    WaitForAll(EpochComponents),
+    /// At UBX-NAV-EOE, with all the components that arrived
+    EmitOnEoe,
+}
+
+/// Position, velocity and time solution of UBX-NAV-PVT
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PvtSolution {
+    pub fix_type: GnssFixType,
+    /// Whether the fix is valid and within the DOP and accuracy masks
+    pub fix_ok: bool,
+    /// Number of satellites used in the solution
+    pub num_satellites: u8,
+    /// Latitude \[deg\]
+    pub lat: f64,
+    /// Longitude \[deg\]
+    pub lon: f64,
+    /// Height above the ellipsoid \[m\]
+    pub height: f64,
+    /// Height above mean sea level \[m\]
+    pub height_msl: f64,
+    /// Horizontal accuracy \[m\]
+    pub horizontal_accuracy: f64,
+    /// Vertical accuracy \[m\]
+    pub vertical_accuracy: f64,
+    /// Velocity north, east and down \[m/s\]
+    pub vel_ned: [f64; 3],
+    /// Ground speed \[m/s\]
+    pub ground_speed: f64,
+}
+
+/// Dilutions of precision of UBX-NAV-DOP
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Dop {
+    pub geometric: f32,
+    pub position: f32,
+    pub time: f32,
+    pub vertical: f32,
+    pub horizontal: f32,
+}
+
+/// Receiver navigation status of UBX-NAV-STATUS
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReceiverStatus {
+    pub fix_type: GnssFixType,
+    /// Whether the fix is valid and within the DOP and accuracy masks
+    pub fix_ok: bool,
+    /// Time to first fix \[ms\]
+    pub time_to_first_fix: u32,
+    /// Time since startup or reset \[ms\]
+    pub uptime: u32,
+}
+
+/// Receiver clock solution of UBX-NAV-CLOCK
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClockSolution {
+    /// Clock bias \[s\]
+    pub bias: f64,
+    /// Clock drift \[s/s\]
+    pub drift: f64,
+    /// Time accuracy \[s\]
+    pub time_accuracy: f64,
+    /// Frequency accuracy \[s/s\]
+    pub frequency_accuracy: f64,
+}
+
+/// Satellites of UBX-NAV-SAT
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SatelliteSummary {
+    /// Number of satellites reported
+    pub num_svs: u8,
+    /// Number of satellites used in the solution
+    pub num_used: u8,
+}
+
+/// The navigation messages of an epoch, each component `None` if its message was not received
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NavEpoch {
+    /// GPS time of week of the epoch \[ms\]
+    pub itow: u32,
+    pub pvt: Option<PvtSolution>,
+    pub dop: Option<Dop>,
+    pub status: Option<ReceiverStatus>,
+    pub clock: Option<ClockSolution>,
+    pub satellites: Option<SatelliteSummary>,
+    /// Whether the receiver closed the epoch with UBX-NAV-EOE
+    pub end_of_epoch: bool,
+}
+
+impl NavEpoch {
+    pub const fn new(itow: u32) -> Self {
+        Self {
+            itow,
+            pvt: None,
+            dop: None,
+            status: None,
+            clock: None,
+            satellites: None,
+            end_of_epoch: false,
+        }
+    }
+
+    /// The components present
+    pub fn components(&self) -> EpochComponents {
+        let mut components = EpochComponents::empty();
+        components.set(EpochComponents::PVT, self.pvt.is_some());
+        components.set(EpochComponents::DOP, self.dop.is_some());
+        components.set(EpochComponents::STATUS, self.status.is_some());
+        components.set(EpochComponents::CLOCK, self.clock.is_some());
+        components.set(EpochComponents::SATELLITES, self.satellites.is_some());
+        components
+    }
+
+    /// Whether all the `expected` components are present
+    pub fn is_complete(&self, expected: EpochComponents) -> bool {
+        self.components().contains(expected)
+    }
+
+    /// Fix type from UBX-NAV-PVT, or from UBX-NAV-STATUS without it
+    pub fn fix_type(&self) -> Option<GnssFixType> {
+        self.pvt
+            .map(|pvt| pvt.fix_type)
+            .or(self.status.map(|status| status.fix_type))
+    }
+
+    /// Whether the fix is valid, from UBX-NAV-PVT, or from UBX-NAV-STATUS without it
+    pub fn fix_ok(&self) -> Option<bool> {
+        self.pvt
+            .map(|pvt| pvt.fix_ok)
+            .or(self.status.map(|status| status.fix_ok))
+    }
+
+    /// Number of satellites used, from UBX-NAV-PVT, or from UBX-NAV-SAT without it
+    pub fn num_satellites(&self) -> Option<u8> {
+        self.pvt
+            .map(|pvt| pvt.num_satellites)
+            .or(self.satellites.map(|sats| sats.num_used))
+    }
+
+    fn add(&mut self, component: Component) {
+        match component {
+            Component::Pvt(pvt) => self.pvt = Some(pvt),
+            Component::Dop(dop) => self.dop = Some(dop),
+            Component::Status(status) => self.status = Some(status),
+            Component::Clock(clock) => self.clock = Some(clock),
+            Component::Satellites(satellites) => self.satellites = Some(satellites),
+            Component::EndOfEpoch => self.end_of_epoch = true,
+        }
+    }
+}
+
+/// Collects navigation messages into [NavEpoch]s, see the [module documentation](self)
+#[derive(Debug, Clone)]
+pub struct EpochAggregator {
+    policy: EpochPolicy,
+    pending: Option<NavEpoch>,
+    /// iTOW of the last epoch emitted, whose late messages are dropped
+    emitted: Option<u32>,
+}
+
+impl EpochAggregator {
+    pub const fn new(policy: EpochPolicy) -> Self {
+        Self {
+            policy,
+            pending: None,
+            emitted: None,
+        }
+    }
+
+    pub const fn policy(&self) -> EpochPolicy {
+        self.policy
+    }
+
+    /// Add `packet` to its epoch. Returns the epochs it completes: the previous epoch when
+    /// `packet` starts a new one, and the epoch of `packet` when the policy emits it. Packets
+    /// other than the navigation messages of [EpochComponents] and UBX-NAV-EOE are ignored.
+    pub fn update(&mut self, packet: &UbxPacket<'_>) -> impl Iterator<Item = NavEpoch> {
+        let mut emitted = [None, None];
+        let component = epoch_component(packet);
+        if let Some((itow, component)) = component {
+            if self.pending.is_some_and(|epoch| epoch.itow != itow) {
+                emitted[0] = self.flush();
+            }
+            if self.emitted != Some(itow) {
+                let epoch = self.pending.get_or_insert(NavEpoch::new(itow));
+                epoch.add(component);
+                let done = match self.policy {
+                    EpochPolicy::WaitForAll(expected) => {
+                        epoch.end_of_epoch || epoch.is_complete(expected)
+                    },
+                    EpochPolicy::EmitOnEoe => epoch.end_of_epoch,
+                };
+                if done {
+                    emitted[1] = self.flush();
+                }
+            }
+        }
+        emitted.into_iter().flatten()
+    }
+
+    /// Take the epoch in progress, complete or not, e.g. when the stream ends
+    pub fn flush(&mut self) -> Option<NavEpoch> {
+        let epoch = self.pending.take()?;
+        self.emitted = Some(epoch.itow);
+        Some(epoch)
+    }
+}
+
+/// A message of an epoch
+#[derive(Clone, Copy)]
+enum Component {
+    Pvt(PvtSolution),
+    Dop(Dop),
+    Status(ReceiverStatus),
+    Clock(ClockSolution),
+    Satellites(SatelliteSummary),
+    EndOfEpoch,
+}
+
+/// iTOW of the epoch and component of a navigation message
+fn epoch_component(packet: &UbxPacket<'_>) -> Option<(u32, Component)> {
+    let report = match_packet!(packet, {
+        PacketRef::NavPvt(pvt) => (
+            pvt.itow(),
+            Component::Pvt(PvtSolution {
+                fix_type: pvt.fix_type(),
+                fix_ok: pvt.flags().contains(NavPvtFlags::GPS_FIX_OK),
+                num_satellites: pvt.num_satellites(),
+                lat: pvt.latitude(),
+                lon: pvt.longitude(),
+                height: pvt.height_above_ellipsoid(),
+                height_msl: pvt.height_msl(),
+                horizontal_accuracy: pvt.horizontal_accuracy(),
+                vertical_accuracy: pvt.vertical_accuracy(),
+                vel_ned: [pvt.vel_north(), pvt.vel_east(), pvt.vel_down()],
+                ground_speed: pvt.ground_speed_2d(),
+            }),
+        ),
+        PacketRef::NavDop(dop) => (
+            dop.itow(),
+            Component::Dop(Dop {
+                geometric: dop.geometric_dop(),
+                position: dop.position_dop(),
+                time: dop.time_dop(),
+                vertical: dop.vertical_dop(),
+                horizontal: dop.horizontal_dop(),
+            }),
+        ),
+        PacketRef::NavStatus(status) => (
+            status.itow(),
+            Component::Status(ReceiverStatus {
+                fix_type: status.fix_type(),
+                fix_ok: status.flags().contains(NavStatusFlags::GPS_FIX_OK),
+                time_to_first_fix: status.time_to_first_fix(),
+                uptime: status.uptime_ms(),
+            }),
+        ),
+        PacketRef::NavClock(clock) => (
+            clock.itow_raw(),
+            Component::Clock(ClockSolution {
+                bias: clock.clk_bias(),
+                drift: clock.clk_drift(),
+                time_accuracy: clock.time_acc(),
+                frequency_accuracy: clock.freq_acc(),
+            }),
+        ),
+        PacketRef::NavSat(sat) => (
+            sat.itow(),
+            Component::Satellites(SatelliteSummary {
+                num_svs: sat.num_svs(),
+                num_used: sat.svs().filter(|sv| sv.flags().sv_used()).count() as u8,
+            }),
+        ),
+        PacketRef::NavEoe(eoe) => (eoe.itow(), Component::EndOfEpoch),
+    } _ => return None);
+    Some(report)
+}
@@ -0,0 +1,369 @@
+//! Firmware identity of a receiver, from UBX-MON-VER and UBX-MON-PATCH, to audit a fleet.
+//!
+//! UBX-MON-VER reports the firmware as free-form strings: the software version, e.g.
+//! `EXT CORE 3.01 (d189ff)`, the hardware version and extensions such as `ROM BASE 3.01
+//! (107888)`, `FWVER=ADR 4.11`, `PROTVER=19.10` and `MOD=NEO-M8L-0`. [FirmwareIdentity]
+//! keeps these in structured form, along with a digest of the patches installed as reported by
+//! UBX-MON-PATCH. Identities compare equal when the receivers run the same firmware, and
+//! [FirmwareIdentity::is_allowed] checks one against a list of [AllowedFirmware].
+//!
+//! ```
+//! use ublox::firmware::{AllowedFirmware, FirmwareIdentity, FirmwareImage, ProtocolVersion};
+//!
+//! let identity = FirmwareIdentity::new(
+//!     "EXT CORE 3.01 (d189ff)",
+//!     "00080000",
+//!     ["ROM BASE 3.01 (107888)", "FWVER=ADR 4.11", "PROTVER=19.10", "MOD=NEO-M8L-0"],
+//! );
+//! assert_eq!(identity.image(), FirmwareImage::Ext);
+//! assert_eq!(identity.fw_version(), Some("ADR 4.11"));
+//! assert_eq!(identity.protocol_version(), Some(ProtocolVersion::new(19, 10)));
+//!
+//! let allowlist = [
+//!     AllowedFirmware::any().fw_version("ADR 4.11").min_protocol(ProtocolVersion::new(19, 0)),
+//!     AllowedFirmware::any().fw_version("HPG 1.32"),
+//! ];
+//! assert!(identity.is_allowed(&allowlist));
+//! ```
+
+use core::{fmt, str::FromStr};
+
+use crate::{
+    mon_patch::MonPatchEntry,
+    mon_ver::{MonVerOwned, MonVerRef},
+};
+
+/// Length of the version strings of UBX-MON-VER
+const VERSION_LEN: usize = 30;
+
+/// UBX-MON-VER extension prefixes
+const ROM_BASE: &str = "ROM BASE ";
+const FW_VER: &str = "FWVER=";
+const PROT_VER: &str = "PROTVER=";
+const MODULE: &str = "MOD=";
+
+/// A string of at most [VERSION_LEN] bytes, stored inline
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct VersionStr {
+    bytes: [u8; VERSION_LEN],
+    len: u8,
+}
+
+impl VersionStr {
+    /// `s`, truncated to [VERSION_LEN] bytes at a character boundary
+    fn new(s: &str) -> Self {
+        let mut len = s.len().min(VERSION_LEN);
+        while !s.is_char_boundary(len) {
+            len -= 1;
+        }
+        let mut bytes = [0; VERSION_LEN];
+        bytes[..len].copy_from_slice(&s.as_bytes()[..len]);
+        Self {
+            bytes,
+            len: len as u8,
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        // Copied from a str up to a character boundary
+        core::str::from_utf8(&self.bytes[..usize::from(self.len)]).unwrap_or_default()
+    }
+}
+
+impl fmt::Debug for VersionStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+/// Version of the UBX protocol implemented by a firmware, e.g. 19.10
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ProtocolVersion {
+    pub major: u8,
+    pub minor: u8,
+}
+
+impl ProtocolVersion {
+    pub const fn new(major: u8, minor: u8) -> Self {
+        Self { major, minor }
+    }
+}
+
+impl FromStr for ProtocolVersion {
+    type Err = core::num::ParseIntError;
+
+    /// Parses `major.minor`, e.g. `19.10`, or a major version alone
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (major, minor) = s.trim().split_once('.').unwrap_or((s.trim(), "0"));
+        Ok(Self::new(major.parse()?, minor.parse()?))
+    }
+}
+
+impl fmt::Display for ProtocolVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{:02}", self.major, self.minor)
+    }
+}
+
+/// Where the running firmware is stored, from the first word of the software version
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FirmwareImage {
+    /// Firmware in ROM, e.g. `ROM CORE 3.01 (107888)`
+    Rom,
+    /// Firmware in external flash, e.g. `EXT CORE 3.01 (d189ff)`
+    Ext,
+    /// Firmware in internal flash, e.g. `FLASH CORE 1.00 (...)`
+    Flash,
+    Other,
+}
+
+/// Installed patches, as reported by UBX-MON-PATCH
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PatchSummary {
+    /// Number of patches installed
+    pub count: u16,
+    /// Number of patches activated
+    pub activated: u16,
+    /// FNV-1a digest of the patch entries, equal for the same patches in the same order
+    pub digest: u32,
+}
+
+impl PatchSummary {
+    /// Summary of the entries of a UBX-MON-PATCH
+    pub fn new(entries: impl IntoIterator<Item = MonPatchEntry>) -> Self {
+        let mut summary = Self {
+            count: 0,
+            activated: 0,
+            digest: FNV_OFFSET,
+        };
+        for entry in entries {
+            summary.count = summary.count.saturating_add(1);
+            summary.activated = summary
+                .activated
+                .saturating_add(u16::from(entry.patch_info.activated()));
+            for word in [
+                entry.patch_info.raw(),
+                entry.comparator_number,
+                entry.patch_address,
+                entry.patch_data,
+            ] {
+                summary.digest = fnv1a(summary.digest, &word.to_le_bytes());
+            }
+        }
+        summary
+    }
+}
+
+const FNV_OFFSET: u32 = 0x811c_9dc5;
+const FNV_PRIME: u32 = 0x0100_0193;
+
+fn fnv1a(hash: u32, bytes: &[u8]) -> u32 {
+    bytes.iter().fold(hash, |hash, byte| {
+        (hash ^ u32::from(*byte)).wrapping_mul(FNV_PRIME)
+    })
+}
+
+/// Structured firmware identifiers of a receiver, see the [module documentation](self)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FirmwareIdentity {
+    software_version: VersionStr,
+    hardware_version: VersionStr,
+    rom_base: Option<VersionStr>,
+    fw_version: Option<VersionStr>,
+    protocol_version: Option<ProtocolVersion>,
+    module: Option<VersionStr>,
+    patches: Option<PatchSummary>,
+}
+
+impl FirmwareIdentity {
+    /// Identity from the software version, hardware version and extension strings of a
+    /// UBX-MON-VER. Unknown extensions, e.g. the supported GNSS, are ignored.
+    pub fn new<'a>(
+        software_version: &str,
+        hardware_version: &str,
+        extensions: impl IntoIterator<Item = &'a str>,
+    ) -> Self {
+        let mut identity = Self {
+            software_version: VersionStr::new(software_version),
+            hardware_version: VersionStr::new(hardware_version),
+            rom_base: None,
+            fw_version: None,
+            protocol_version: None,
+            module: None,
+            patches: None,
+        };
+        for extension in extensions {
+            if let Some(rom_base) = extension.strip_prefix(ROM_BASE) {
+                identity.rom_base = Some(VersionStr::new(rom_base));
+            } else if let Some(fw_version) = extension.strip_prefix(FW_VER) {
+                identity.fw_version = Some(VersionStr::new(fw_version));
+            } else if let Some(protocol_version) = extension.strip_prefix(PROT_VER) {
+                identity.protocol_version = protocol_version.parse().ok();
+            } else if let Some(module) = extension.strip_prefix(MODULE) {
+                identity.module = Some(VersionStr::new(module));
+            }
+        }
+        identity
+    }
+
+    /// Add the patches reported by a UBX-MON-PATCH to the identity
+    pub fn with_patches(mut self, patches: PatchSummary) -> Self {
+        self.patches = Some(patches);
+        self
+    }
+
+    /// Software version, e.g. `EXT CORE 3.01 (d189ff)`
+    pub fn software_version(&self) -> &str {
+        self.software_version.as_str()
+    }
+
+    /// Hardware version, e.g. `00080000`
+    pub fn hardware_version(&self) -> &str {
+        self.hardware_version.as_str()
+    }
+
+    /// Where the running firmware is stored
+    pub fn image(&self) -> FirmwareImage {
+        match self.software_version().split_whitespace().next() {
+            Some("ROM") => FirmwareImage::Rom,
+            Some("EXT") => FirmwareImage::Ext,
+            Some("FLASH") => FirmwareImage::Flash,
+            _ => FirmwareImage::Other,
+        }
+    }
+
+    /// Version of the ROM the firmware in flash runs on, e.g. `3.01 (107888)`
+    pub fn rom_base(&self) -> Option<&str> {
+        self.rom_base.as_ref().map(VersionStr::as_str)
+    }
+
+    /// Firmware product and version, e.g. `ADR 4.11` or `HPG 1.32`
+    pub fn fw_version(&self) -> Option<&str> {
+        self.fw_version.as_ref().map(VersionStr::as_str)
+    }
+
+    /// Version of the UBX protocol
+    pub fn protocol_version(&self) -> Option<ProtocolVersion> {
+        self.protocol_version
+    }
+
+    /// Module name, e.g. `NEO-M8L-0`
+    pub fn module(&self) -> Option<&str> {
+        self.module.as_ref().map(VersionStr::as_str)
+    }
+
+    /// Installed patches, once added with [FirmwareIdentity::with_patches]
+    pub fn patches(&self) -> Option<PatchSummary> {
+        self.patches
+    }
+
+    /// Whether the identity matches one of the entries of `allowlist`
+    pub fn is_allowed(&self, allowlist: &[AllowedFirmware<'_>]) -> bool {
+        allowlist.iter().any(|allowed| allowed.matches(self))
+    }
+}
+
+/// Identity reported by a UBX-MON-VER
+impl From<&MonVerRef<'_>> for FirmwareIdentity {
+    fn from(mon_ver: &MonVerRef<'_>) -> Self {
+        Self::new(
+            mon_ver.software_version().unwrap_or_default(),
+            mon_ver.hardware_version().unwrap_or_default(),
+            mon_ver.extension(),
+        )
+    }
+}
+
+/// Identity reported by a UBX-MON-VER
+impl From<&MonVerOwned> for FirmwareIdentity {
+    fn from(mon_ver: &MonVerOwned) -> Self {
+        Self::new(
+            mon_ver.software_version().unwrap_or_default(),
+            mon_ver.hardware_version().unwrap_or_default(),
+            mon_ver.extension(),
+        )
+    }
+}
+
+/// Entry of a firmware allowlist: the identifiers a firmware must have, the others being free
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllowedFirmware<'a> {
+    pub software_version: Option<&'a str>,
+    pub hardware_version: Option<&'a str>,
+    pub fw_version: Option<&'a str>,
+    pub module: Option<&'a str>,
+    /// Lowest protocol version allowed
+    pub min_protocol: Option<ProtocolVersion>,
+    /// Digest of the patches, see [PatchSummary::digest]
+    pub patch_digest: Option<u32>,
+}
+
+impl<'a> AllowedFirmware<'a> {
+    /// Allow any firmware, to be narrowed down with the other methods
+    pub const fn any() -> Self {
+        Self {
+            software_version: None,
+            hardware_version: None,
+            fw_version: None,
+            module: None,
+            min_protocol: None,
+            patch_digest: None,
+        }
+    }
+
+    /// Require exactly this software version
+    pub const fn software_version(mut self, software_version: &'a str) -> Self {
+        self.software_version = Some(software_version);
+        self
+    }
+
+    /// Require exactly this hardware version
+    pub const fn hardware_version(mut self, hardware_version: &'a str) -> Self {
+        self.hardware_version = Some(hardware_version);
+        self
+    }
+
+    /// Require exactly this firmware product and version
+    pub const fn fw_version(mut self, fw_version: &'a str) -> Self {
+        self.fw_version = Some(fw_version);
+        self
+    }
+
+    /// Require exactly this module
+    pub const fn module(mut self, module: &'a str) -> Self {
+        self.module = Some(module);
+        self
+    }
+
+    /// Require this protocol version or a later one
+    pub const fn min_protocol(mut self, version: ProtocolVersion) -> Self {
+        self.min_protocol = Some(version);
+        self
+    }
+
+    /// Require exactly these patches. Identities without patches do not match.
+    pub const fn patch_digest(mut self, digest: u32) -> Self {
+        self.patch_digest = Some(digest);
+        self
+    }
+
+    /// Whether `identity` has all the identifiers required
+    pub fn matches(&self, identity: &FirmwareIdentity) -> bool {
+        fn same(required: Option<&str>, actual: Option<&str>) -> bool {
+            required.is_none_or(|required| actual == Some(required))
+        }
+
+        same(self.software_version, Some(identity.software_version()))
+            && same(self.hardware_version, Some(identity.hardware_version()))
+            && same(self.fw_version, identity.fw_version())
+            && same(self.module, identity.module())
+            && self
+                .min_protocol
+                .is_none_or(|min| identity.protocol_version().is_some_and(|v| v >= min))
+            && self.patch_digest.is_none_or(|digest| {
+                identity
+                    .patches()
+                    .is_some_and(|patches| patches.digest == digest)
+            })
+    }
+}
@@ -0,0 +1,168 @@
+//! Conversions of positions to [geo_types] and spatial indexes, with the `geo` feature.
+//!
+//! The packets store latitude and longitude as integers in 1e-7 degrees and heights in mm,
+//! which their accessors and [PositionLLA] already scale. The conversions here check the
+//! result as well: a position out of range, e.g. decoded from a corrupted field or built from
+//! raw values with a wrong scale factor, is rejected instead of landing in the middle of the
+//! ocean on a map.
+//!
+//! ```
+//! use geo_types::Point;
+//! use ublox::PositionLLA;
+//!
+//! // Raw UBX-NAV-PVT lon, lat and hMSL
+//! let position = PositionLLA::from_raw(104_074_400, 576_491_100, 12_500)?;
+//! let point = Point::try_from(position)?;
+//! assert!((point.y() - 57.64911).abs() < 1e-9);
+//!
+//! assert_eq!(position.geohash(9)?.as_str(), "u4pruydqq");
+//! # Ok::<(), ublox::geo::InvalidPosition>(())
+//! ```
+//!
+//! With the `h3` feature, [PositionLLA::h3_cell] tells the H3 cell of a position.
+
+use core::fmt;
+
+use geo_types::{Coord, Point};
+
+use crate::PositionLLA;
+
+/// Longest geohash, about 3.7 cm by 1.9 cm
+pub const MAX_GEOHASH_LEN: usize = 12;
+
+const GEOHASH_ALPHABET: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+/// A latitude or longitude out of range or not finite
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InvalidPosition {
+    pub lon: f64,
+    pub lat: f64,
+}
+
+impl fmt::Display for InvalidPosition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid position: lon {}, lat {}", self.lon, self.lat)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidPosition {}
+
+impl PositionLLA {
+    /// Position from the raw fields of a packet: longitude and latitude in 1e-7 degrees and
+    /// altitude in mm
+    pub fn from_raw(lon: i32, lat: i32, alt_mm: i32) -> Result<Self, InvalidPosition> {
+        Self {
+            lon: f64::from(lon) * 1e-7,
+            lat: f64::from(lat) * 1e-7,
+            alt: f64::from(alt_mm) * 1e-3,
+        }
+        .checked()
+    }
+
+    /// The position if its latitude is within ±90° and its longitude within ±180°
+    pub fn checked(self) -> Result<Self, InvalidPosition> {
+        if (-90.0..=90.0).contains(&self.lat) && (-180.0..=180.0).contains(&self.lon) {
+            Ok(self)
+        } else {
+            Err(InvalidPosition {
+                lon: self.lon,
+                lat: self.lat,
+            })
+        }
+    }
+
+    /// Geohash of `len` characters, at most [MAX_GEOHASH_LEN]
+    pub fn geohash(&self, len: usize) -> Result<Geohash, InvalidPosition> {
+        let position = self.checked()?;
+        let len = len.min(MAX_GEOHASH_LEN);
+        let mut lon = (-180.0, 180.0);
+        let mut lat = (-90.0, 90.0);
+        let mut bytes = [0; MAX_GEOHASH_LEN];
+        // Bits alternate between longitude and latitude, starting with longitude
+        let mut even = true;
+        for byte in &mut bytes[..len] {
+            let mut index = 0;
+            for _ in 0..5 {
+                let (range, value) = if even {
+                    (&mut lon, position.lon)
+                } else {
+                    (&mut lat, position.lat)
+                };
+                let mid = f64::midpoint(range.0, range.1);
+                index <<= 1;
+                if value >= mid {
+                    index |= 1;
+                    range.0 = mid;
+                } else {
+                    range.1 = mid;
+                }
+                even = !even;
+            }
+            *byte = GEOHASH_ALPHABET[index];
+        }
+        Ok(Geohash { bytes, len })
+    }
+
+    /// H3 cell of the position at `resolution`
+    #[cfg(feature = "h3")]
+    pub fn h3_cell(&self, resolution: h3o::Resolution) -> Result<h3o::CellIndex, InvalidPosition> {
+        let position = self.checked()?;
+        let invalid = |_| InvalidPosition {
+            lon: self.lon,
+            lat: self.lat,
+        };
+        Ok(h3o::LatLng::new(position.lat, position.lon)
+            .map_err(invalid)?
+            .to_cell(resolution))
+    }
+}
+
+/// Coordinates of the position, x being the longitude and y the latitude in degrees. The
+/// altitude is dropped.
+impl TryFrom<PositionLLA> for Coord<f64> {
+    type Error = InvalidPosition;
+
+    fn try_from(position: PositionLLA) -> Result<Self, Self::Error> {
+        let position = position.checked()?;
+        Ok(Coord {
+            x: position.lon,
+            y: position.lat,
+        })
+    }
+}
+
+/// Point at the position, like the [Coord] conversion
+impl TryFrom<PositionLLA> for Point<f64> {
+    type Error = InvalidPosition;
+
+    fn try_from(position: PositionLLA) -> Result<Self, Self::Error> {
+        Coord::try_from(position).map(Point)
+    }
+}
+
+/// Geohash of a position, see [PositionLLA::geohash]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Geohash {
+    bytes: [u8; MAX_GEOHASH_LEN],
+    len: usize,
+}
+
+impl Geohash {
+    pub fn as_str(&self) -> &str {
+        // The bytes are taken from the ASCII geohash alphabet
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap_or_default()
+    }
+}
+
+impl fmt::Debug for Geohash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Geohash").field(&self.as_str()).finish()
+    }
+}
+
+impl fmt::Display for Geohash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
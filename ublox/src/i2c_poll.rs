@@ -0,0 +1,207 @@
+//! Polling schedule for receivers connected over I2C, which only output data when the host
+//! reads it.
+//!
+//! On I2C (DDC), the host reads the number of bytes waiting in the receiver from the registers
+//! at [BYTES_AVAILABLE_REGISTER] and then the bytes from [DATA_STREAM_REGISTER]. Polling the
+//! count at a fixed, short interval wastes bus time, while a long one delays the packets. The
+//! [PollScheduler] adapts the interval to the output expected from the configured message
+//! rates, given by an [ExpectedTraffic]: it polls quickly while the burst of an epoch is being
+//! output, then sleeps until the next burst is expected, backing off when the count stays at
+//! zero. The delay it adds to a packet is never above [PollScheduler::with_max_latency].
+//!
+//! Times are [Duration]s since an arbitrary epoch, so that any monotonic clock can be used:
+//! the tick count of an RTOS converted with its tick rate, or `embassy_time::Instant` with
+//! `Duration::from_micros(Instant::now().as_micros())`. [PollScheduler::on_poll] returns the
+//! time of the next poll, to wait for with e.g. `vTaskDelayUntil` or `Timer::at`.
+//!
+//! ```
+//! use core::time::Duration;
+//! use ublox::{
+//!     i2c_poll::{bytes_available, ExpectedTraffic, PollScheduler},
+//!     msg_rate::MsgRate,
+//!     nav_pvt::proto23::NavPvt,
+//! };
+//!
+//! // UBX-NAV-PVT at 5 Hz
+//! let traffic = ExpectedTraffic::new(Duration::from_millis(200))
+//!     .with_msg_rate(&MsgRate::new::<NavPvt>(1));
+//! assert_eq!(traffic.bytes_per_epoch(), 100);
+//! let mut scheduler = PollScheduler::new(traffic).with_max_latency(Duration::from_millis(20));
+//!
+//! // The whole UBX-NAV-PVT is available, registers 0xfd and 0xfe read at 1 s
+//! let now = Duration::from_secs(1);
+//! let available = bytes_available([0x00, 0x64]);
+//! let next = scheduler.on_poll(now, available);
+//! // After reading it, the next poll is 20 ms later at most, before the next epoch
+//! assert_eq!(next, now + Duration::from_millis(20));
+//! ```
+
+use core::time::Duration;
+
+use crate::{
+    constants::{UBX_CHECKSUM_LEN, UBX_HEADER_LEN},
+    msg_rate::MsgRate,
+    packet_meta,
+};
+
+/// Address of the register pair holding the number of bytes available, high byte first
+pub const BYTES_AVAILABLE_REGISTER: u8 = 0xfd;
+
+/// Address of the register streaming the bytes available
+pub const DATA_STREAM_REGISTER: u8 = 0xff;
+
+/// Number of bytes available from the registers at [BYTES_AVAILABLE_REGISTER] and the next one
+pub const fn bytes_available(registers: [u8; 2]) -> u16 {
+    u16::from_be_bytes(registers)
+}
+
+/// Index of the I2C port in [MsgRate::rates]
+const I2C_PORT: usize = 0;
+
+/// Output of the receiver on I2C, from the message rates configured
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExpectedTraffic {
+    nav_period: Duration,
+    /// Bytes output per epoch in thousandths, averaging the messages output every few epochs
+    milli_bytes_per_epoch: u32,
+}
+
+impl ExpectedTraffic {
+    /// No messages, with a navigation solution every `nav_period`, as set by UBX-CFG-RATE
+    pub const fn new(nav_period: Duration) -> Self {
+        Self {
+            nav_period,
+            milli_bytes_per_epoch: 0,
+        }
+    }
+
+    /// Add a message output every `rate` epochs with `payload_len` bytes
+    pub const fn with_message(mut self, rate: u8, payload_len: u16) -> Self {
+        if rate > 0 {
+            let frame_len = (UBX_HEADER_LEN + UBX_CHECKSUM_LEN) as u32 + payload_len as u32;
+            self.milli_bytes_per_epoch += frame_len * 1_000 / rate as u32;
+        }
+        self
+    }
+
+    /// Add the message at its I2C rate. Its payload length is the fixed one, or the maximum
+    /// for packets of variable length, which [ExpectedTraffic::with_message] can refine. Unknown
+    /// messages are ignored.
+    pub fn with_msg_rate(self, rate: &MsgRate) -> Self {
+        match packet_meta(rate.msg_class, rate.msg_id) {
+            Some(meta) => self.with_message(
+                rate.rates[I2C_PORT],
+                meta.fixed_payload_len.unwrap_or(meta.max_payload_len),
+            ),
+            None => self,
+        }
+    }
+
+    pub fn nav_period(&self) -> Duration {
+        self.nav_period
+    }
+
+    /// Average number of bytes output per navigation epoch
+    pub fn bytes_per_epoch(&self) -> u32 {
+        self.milli_bytes_per_epoch / 1_000
+    }
+
+    /// Average number of bytes output per second
+    pub fn bytes_per_second(&self) -> u32 {
+        let period_us = self.nav_period.as_micros().max(1);
+        (u128::from(self.milli_bytes_per_epoch) * 1_000 / period_us) as u32
+    }
+}
+
+/// Times of the polls of the bytes available, see the [module documentation](self)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PollScheduler {
+    traffic: ExpectedTraffic,
+    min_interval: Duration,
+    max_latency: Duration,
+    /// Interval of the polls finding no data, doubled each time up to `max_latency`
+    backoff: Duration,
+    /// Time the first bytes of the latest burst were available
+    burst_start: Option<Duration>,
+    /// Bytes read in the current burst
+    received: u32,
+}
+
+impl PollScheduler {
+    /// Poll for the output of `traffic`, with 2 ms between the polls of a burst and at most
+    /// 50 ms otherwise
+    pub const fn new(traffic: ExpectedTraffic) -> Self {
+        Self {
+            traffic,
+            min_interval: Duration::from_millis(2),
+            max_latency: Duration::from_millis(50),
+            backoff: Duration::from_millis(2),
+            burst_start: None,
+            received: 0,
+        }
+    }
+
+    /// Interval of the polls while a burst is being output, at least the time to read it from
+    /// the bus
+    pub fn with_min_interval(mut self, interval: Duration) -> Self {
+        self.min_interval = interval;
+        self.backoff = interval;
+        self.max_latency = self.max_latency.max(interval);
+        self
+    }
+
+    /// Longest time between two polls, bounding the delay added to the packets
+    pub fn with_max_latency(mut self, latency: Duration) -> Self {
+        self.max_latency = latency.max(self.min_interval);
+        self
+    }
+
+    pub fn traffic(&self) -> &ExpectedTraffic {
+        &self.traffic
+    }
+
+    /// Record the poll at `now` finding `available` bytes, all read by the caller before the
+    /// next poll, and return the time of the next poll
+    pub fn on_poll(&mut self, now: Duration, available: u16) -> Duration {
+        if available == 0 {
+            // A burst shorter than expected ends with the first empty poll
+            self.received = 0;
+            self.backoff = (self.backoff * 2).min(self.max_latency);
+            return (now + self.backoff).min(self.next_burst(now));
+        }
+
+        // Bytes beyond the expected ones, within half a period, are still of the same epoch
+        let half_period = self.traffic.nav_period / 2;
+        if self.received == 0
+            && self
+                .burst_start
+                .is_none_or(|start| now.saturating_sub(start) >= half_period)
+        {
+            self.burst_start = Some(now);
+        }
+        self.received += u32::from(available);
+        self.backoff = self.min_interval;
+        if self.received < self.traffic.bytes_per_epoch() {
+            return now + self.min_interval;
+        }
+        // Nothing more is expected before the next epoch
+        self.received = 0;
+        self.backoff = self.max_latency;
+        self.next_burst(now)
+    }
+
+    /// Time the next burst is expected at, between `min_interval` and `max_latency` from `now`
+    fn next_burst(&self, now: Duration) -> Duration {
+        let earliest = now + self.min_interval;
+        let latest = now + self.max_latency;
+        let period = self.traffic.nav_period;
+        let Some(burst_start) = self.burst_start.filter(|_| !period.is_zero()) else {
+            return latest;
+        };
+        // The first start of a burst after `now`, a whole number of periods after the latest
+        let since_burst = now.saturating_sub(burst_start).as_nanos();
+        let periods = since_burst / period.as_nanos() + 1;
+        let expected = burst_start + Duration::from_nanos((periods * period.as_nanos()) as u64);
+        expected.clamp(earliest, latest)
+    }
+}
@@ -0,0 +1,115 @@
+//! Writing UBX frames to a serial port or any other byte sink.
+//!
+//! [WriteUbxFrame] extends every [std::io::Write] with `write_ubx`, which serializes a packet
+//! straight into the writer and flushes it. With the `embedded-io` feature,
+//! `embedded::WriteUbxFrame` does the same for `embedded_io::Write`, without an allocator.
+//!
+//! `write_ubx` takes anything implementing [UbxPacketCreator]: the builders of fixed size
+//! packets, [UbxPollRequest](crate::UbxPollRequest) and
+//! [UbxUnknownPacketRef](crate::UbxUnknownPacketRef). The builders of packets with variable size fields write their frame into a buffer first,
+//! with `extend_to_slice` or `into_packet_vec`, which `write_ubx_frame` then sends.
+//!
+//! ```
+//! # #[cfg(feature = "std")] {
+//! use ublox::{cfg_rst::{CfgRstBuilder, NavBbrPredefinedMask, ResetMode}, io::WriteUbxFrame};
+//!
+//! let mut port = Vec::new();
+//! port.write_ubx(CfgRstBuilder {
+//!     nav_bbr_mask: NavBbrPredefinedMask::HOT_START.into(),
+//!     reset_mode: ResetMode::ControlledSoftwareReset,
+//!     reserved1: 0,
+//! })?;
+//! assert_eq!(port[..4], [0xb5, 0x62, 0x06, 0x04]);
+//! # }
+//! # Ok::<(), std::io::Error>(())
+//! ```
+
+#[allow(
+    unused_imports,
+    reason = "Only used with the std or embedded-io feature"
+)]
+use crate::{MemWriter, MemWriterError, UbxPacketCreator};
+
+/// Streams the bytes of [UbxPacketCreator::create_packet] to a [std::io::Write]
+#[cfg(feature = "std")]
+struct IoWriter<'a, W: ?Sized>(&'a mut W);
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write + ?Sized> MemWriter for IoWriter<'_, W> {
+    type Error = std::io::Error;
+
+    fn reserve_allocate(&mut self, _len: usize) -> Result<(), MemWriterError<Self::Error>> {
+        Ok(())
+    }
+
+    fn write(&mut self, buf: &[u8]) -> Result<(), MemWriterError<Self::Error>> {
+        self.0.write_all(buf).map_err(MemWriterError::Custom)
+    }
+}
+
+/// Extension of [std::io::Write] sending UBX frames, see the [module documentation](self)
+#[cfg(feature = "std")]
+pub trait WriteUbxFrame: std::io::Write {
+    /// Serialize `packet` into the writer and flush it
+    fn write_ubx<P: UbxPacketCreator>(&mut self, packet: P) -> std::io::Result<()> {
+        packet
+            .create_packet(&mut IoWriter(self))
+            .map_err(|e| match e {
+                MemWriterError::Custom(e) => e,
+                MemWriterError::NotEnoughMem => std::io::ErrorKind::WriteZero.into(),
+            })?;
+        self.flush()
+    }
+
+    /// Write the serialized `frame` and flush the writer
+    fn write_ubx_frame(&mut self, frame: &[u8]) -> std::io::Result<()> {
+        self.write_all(frame)?;
+        self.flush()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write + ?Sized> WriteUbxFrame for W {}
+
+/// Sending UBX frames to an `embedded_io::Write`
+#[cfg(feature = "embedded-io")]
+pub mod embedded {
+    use crate::{MemWriter, MemWriterError, UbxPacketCreator};
+
+    /// Streams the bytes of [UbxPacketCreator::create_packet] to an [embedded_io::Write]
+    struct IoWriter<'a, W: ?Sized>(&'a mut W);
+
+    impl<W: embedded_io::Write + ?Sized> MemWriter for IoWriter<'_, W> {
+        type Error = W::Error;
+
+        fn reserve_allocate(&mut self, _len: usize) -> Result<(), MemWriterError<Self::Error>> {
+            Ok(())
+        }
+
+        fn write(&mut self, buf: &[u8]) -> Result<(), MemWriterError<Self::Error>> {
+            self.0.write_all(buf).map_err(MemWriterError::Custom)
+        }
+    }
+
+    /// Extension of [embedded_io::Write] sending UBX frames, see the
+    /// [module documentation](super)
+    pub trait WriteUbxFrame: embedded_io::Write {
+        /// Serialize `packet` into the writer and flush it. The bytes are written as they are
+        /// serialized, without buffering the frame.
+        fn write_ubx<P: UbxPacketCreator>(
+            &mut self,
+            packet: P,
+        ) -> Result<(), MemWriterError<Self::Error>> {
+            packet.create_packet(&mut IoWriter(self))?;
+            self.flush().map_err(MemWriterError::Custom)
+        }
+
+        /// Write the serialized `frame` and flush the writer
+        fn write_ubx_frame(&mut self, frame: &[u8]) -> Result<(), Self::Error> {
+            self.write_all(frame)?;
+            self.flush()
+        }
+    }
+
+    impl<W: embedded_io::Write + ?Sized> WriteUbxFrame for W {}
+}
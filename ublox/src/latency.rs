@@ -0,0 +1,367 @@
+//! End to end latency of the packets, from the navigation epoch they describe to the end of
+//! their processing on the host, e.g. as evidence of the delay budget of a control loop.
+//!
+//! The receiver outputs the solution of an epoch some time after the epoch, and the host then
+//! takes time to read, decode and act on it. To measure this, the [LatencyMeter] relates the
+//! host clock to GPS time with the time pulse: UBX-TIM-TP announces the GPS time of the next
+//! pulse, and [LatencyMeter::on_time_pulse] gives the host time the pulse was captured at,
+//! e.g. from a PPS device. The latency of each packet carrying an iTOW is then the time from
+//! its epoch to [LatencyMeter::record], called once its processing completed, such as from
+//! [Dispatcher::on_handled](crate::dispatch::Dispatcher::on_handled). Latencies are kept in
+//! a [LatencyHistogram] per packet type.
+//!
+//! The time pulse must be aligned to GPS time, as by default. The host clock can be any
+//! monotonic clock in nanoseconds, its drift being corrected at every pulse.
+//!
+//! ```
+//! use core::cell::Cell;
+//! use ublox::{
+//!     dispatch::Dispatcher, latency::LatencyMeter, nav_pvt::proto23::NavPvt, ubx_frame,
+//!     ParserBuilder,
+//! };
+//!
+//! // Host monotonic clock and latest time pulse captured with it [ns]
+//! let clock = Cell::new(5_000_000_000);
+//! let pps = Cell::new(4_400_000_000);
+//!
+//! let mut meter = LatencyMeter::new();
+//! let mut dispatcher = Dispatcher::new(ParserBuilder::new().with_fixed_buffer::<1024>())
+//!     .on_handled(|frame| {
+//!         meter.on_time_pulse(pps.get());
+//!         meter.record(frame, clock.get());
+//!     });
+//!
+//! // UBX-TIM-TP announcing the pulse of week 2300 at 100000 s
+//! let mut tim_tp = [0; 16];
+//! tim_tp[..4].copy_from_slice(&100_000_000u32.to_le_bytes());
+//! tim_tp[12..14].copy_from_slice(&2300u16.to_le_bytes());
+//! dispatcher.feed(&ubx_frame!(0x0d, 0x01, &tim_tp));
+//!
+//! // The pulse is captured, then the UBX-NAV-PVT of its epoch is processed 25 ms later
+//! pps.set(5_400_000_000);
+//! clock.set(5_425_000_000);
+//! let mut nav_pvt = [0; 92];
+//! nav_pvt[..4].copy_from_slice(&100_000_000u32.to_le_bytes());
+//! dispatcher.feed(&ubx_frame!(0x01, 0x07, &nav_pvt));
+//! drop(dispatcher);
+//!
+//! let histogram = meter.latency::<NavPvt>().unwrap();
+//! assert_eq!(histogram.count(), 1);
+//! assert_eq!(histogram.max_ns(), Some(25_000_000));
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use num_traits::float::FloatCore;
+
+use crate::{
+    esf_alg::EsfAlg,
+    esf_ins::EsfIns,
+    hnr_att::HnrAtt,
+    hnr_ins::HnrIns,
+    hnr_pvt::HnrPvt,
+    nav_att::NavAtt,
+    nav_clock::NavClock,
+    nav_cov::NavCov,
+    nav_dop::NavDop,
+    nav_hp_pos_ecef::NavHpPosEcef,
+    nav_hp_pos_llh::NavHpPosLlh,
+    nav_other::{NavEoe, NavOdo, NavVelECEF},
+    nav_pos_ecef::NavPosEcef,
+    nav_pos_llh::NavPosLlh,
+    nav_sat::NavSat,
+    nav_sol::NavSol,
+    nav_status::NavStatus,
+    nav_time_bds::NavTimeBds,
+    nav_time_gal::NavTimeGal,
+    nav_time_glo::NavTimeGlo,
+    nav_time_gps::NavTimeGps,
+    nav_time_ls::NavTimeLs,
+    nav_time_utc::NavTimeUTC,
+    nav_vel_ned::NavVelNed,
+    tim_tp::{TimTp, TimTpRefInfoTimeRefGnss, TimTpTimeBase},
+    time_mark::GpsTime,
+    UbxPacketMeta, UbxPacketRecv, UbxUnknownPacketRef,
+};
+
+/// Number of latency histogram bins, see [LatencyHistogram::bins]
+pub const LATENCY_BINS: usize = 24;
+
+const NANOS_PER_US: i64 = 1_000;
+const NANOS_PER_MS: u64 = 1_000_000;
+const NANOS_PER_HALF_WEEK: i128 = 302_400 * 1_000 * NANOS_PER_MS as i128;
+
+/// Class, message id and iTOW offset of a packet carrying the iTOW of its epoch
+macro_rules! epoch_itow {
+    ($packet:ty) => {
+        (<$packet>::CLASS, <$packet>::ID, <$packet>::OFFSET_ITOW)
+    };
+}
+
+/// Offset of the iTOW in the payload of the packets carrying one, by class and message id
+const EPOCH_ITOW: &[(u8, u8, usize)] = &[
+    epoch_itow!(NavPosEcef),
+    epoch_itow!(NavPosLlh),
+    epoch_itow!(NavStatus),
+    epoch_itow!(NavDop),
+    epoch_itow!(NavAtt),
+    epoch_itow!(NavSol),
+    #[cfg(feature = "ubx_proto14")]
+    epoch_itow!(crate::nav_pvt::proto14::NavPvt),
+    #[cfg(feature = "ubx_proto23")]
+    epoch_itow!(crate::nav_pvt::proto23::NavPvt),
+    #[cfg(feature = "ubx_proto27")]
+    epoch_itow!(crate::nav_pvt::proto27::NavPvt),
+    #[cfg(feature = "ubx_proto31")]
+    epoch_itow!(crate::nav_pvt::proto31::NavPvt),
+    #[cfg(feature = "ubx_proto33")]
+    epoch_itow!(crate::nav_pvt::proto33::NavPvt),
+    epoch_itow!(NavOdo),
+    epoch_itow!(NavVelECEF),
+    epoch_itow!(NavVelNed),
+    epoch_itow!(NavHpPosEcef),
+    epoch_itow!(NavHpPosLlh),
+    epoch_itow!(NavTimeGps),
+    epoch_itow!(NavTimeGlo),
+    epoch_itow!(NavTimeBds),
+    epoch_itow!(NavTimeGal),
+    epoch_itow!(NavTimeUTC),
+    epoch_itow!(NavClock),
+    epoch_itow!(NavTimeLs),
+    epoch_itow!(NavSat),
+    epoch_itow!(NavCov),
+    #[cfg(feature = "ubx_proto14")]
+    epoch_itow!(crate::nav_rel_pos_ned::proto14::NavRelPosNed),
+    #[cfg(feature = "ubx_proto23")]
+    epoch_itow!(crate::nav_rel_pos_ned::proto23::NavRelPosNed),
+    #[cfg(feature = "ubx_proto27")]
+    epoch_itow!(crate::nav_rel_pos_ned::proto27::NavRelPosNed),
+    #[cfg(feature = "ubx_proto31")]
+    epoch_itow!(crate::nav_rel_pos_ned::proto31::NavRelPosNed),
+    #[cfg(feature = "ubx_proto33")]
+    epoch_itow!(crate::nav_rel_pos_ned::proto33::NavRelPosNed),
+    #[cfg(any(
+        feature = "ubx_proto27",
+        feature = "ubx_proto31",
+        feature = "ubx_proto33",
+    ))]
+    epoch_itow!(crate::nav_sig::NavSig),
+    epoch_itow!(NavEoe),
+    #[cfg(any(
+        feature = "ubx_proto23",
+        feature = "ubx_proto27",
+        feature = "ubx_proto31",
+        feature = "ubx_proto33",
+    ))]
+    epoch_itow!(crate::esf_status::EsfStatus),
+    epoch_itow!(EsfAlg),
+    epoch_itow!(EsfIns),
+    epoch_itow!(HnrPvt),
+    epoch_itow!(HnrAtt),
+    epoch_itow!(HnrIns),
+];
+
+/// iTOW of the epoch of `frame`, if it carries one
+fn epoch_itow(frame: &UbxUnknownPacketRef<'_>) -> Option<u32> {
+    let &(.., offset) = EPOCH_ITOW
+        .iter()
+        .find(|&&(class, msg_id, _)| (class, msg_id) == (frame.class, frame.msg_id))?;
+    let itow = frame.payload.get(offset..offset + 4)?;
+    Some(u32::from_le_bytes(itow.try_into().ok()?))
+}
+
+/// Distribution of the latencies of a packet type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LatencyHistogram {
+    bins: [u32; LATENCY_BINS],
+    count: u32,
+    sum_ns: i64,
+    min_ns: i64,
+    max_ns: i64,
+}
+
+impl LatencyHistogram {
+    const fn new() -> Self {
+        Self {
+            bins: [0; LATENCY_BINS],
+            count: 0,
+            sum_ns: 0,
+            min_ns: i64::MAX,
+            max_ns: i64::MIN,
+        }
+    }
+
+    fn bin(latency_ns: i64) -> usize {
+        let us = latency_ns / NANOS_PER_US;
+        if us < 1 {
+            0
+        } else {
+            ((u64::BITS - (us as u64).leading_zeros()) as usize).min(LATENCY_BINS - 1)
+        }
+    }
+
+    /// Upper bound of the latencies counted in bin `i` \[ns\]
+    pub const fn bin_upper_ns(i: usize) -> i64 {
+        (1 << i) * NANOS_PER_US
+    }
+
+    fn add(&mut self, latency_ns: i64) {
+        self.bins[Self::bin(latency_ns)] += 1;
+        self.count += 1;
+        self.sum_ns = self.sum_ns.saturating_add(latency_ns);
+        self.min_ns = self.min_ns.min(latency_ns);
+        self.max_ns = self.max_ns.max(latency_ns);
+    }
+
+    /// Number of packets per latency bin. Bin 0 counts latencies below 1 µs, negative ones
+    /// included, and bin `i` those from 2^(i-1) up to 2^i µs. The last bin also counts all
+    /// higher values.
+    pub fn bins(&self) -> &[u32; LATENCY_BINS] {
+        &self.bins
+    }
+
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    pub fn min_ns(&self) -> Option<i64> {
+        (self.count > 0).then_some(self.min_ns)
+    }
+
+    pub fn max_ns(&self) -> Option<i64> {
+        (self.count > 0).then_some(self.max_ns)
+    }
+
+    pub fn mean_ns(&self) -> Option<i64> {
+        (self.count > 0).then(|| self.sum_ns / i64::from(self.count))
+    }
+
+    /// Latency that the fraction `quantile` of the packets did not exceed \[ns\], rounded up
+    /// to the upper bound of its bin but never above the maximum
+    pub fn quantile_ns(&self, quantile: f32) -> Option<i64> {
+        if self.count == 0 {
+            return None;
+        }
+        let rank = (FloatCore::ceil(quantile.clamp(0.0, 1.0) * self.count as f32) as u32).max(1);
+        let mut seen = 0;
+        let bin = self.bins.iter().position(|&count| {
+            seen += count;
+            seen >= rank
+        })?;
+        if bin == LATENCY_BINS - 1 {
+            Some(self.max_ns)
+        } else {
+            Some(Self::bin_upper_ns(bin).min(self.max_ns))
+        }
+    }
+}
+
+/// Latencies of the packets of a type, see [LatencyMeter::latencies]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PacketLatency {
+    pub class: u8,
+    pub msg_id: u8,
+    pub histogram: LatencyHistogram,
+}
+
+/// Measures the latency of the packets per type, see the [module documentation](self)
+#[derive(Debug, Default)]
+pub struct LatencyMeter {
+    /// GPS time of the next pulse announced by UBX-TIM-TP, and host time it was recorded at
+    announced: Option<(GpsTime, u64)>,
+    /// GPS time of the latest pulse and host time it was captured at
+    anchor: Option<(GpsTime, u64)>,
+    latencies: Vec<PacketLatency>,
+}
+
+impl LatencyMeter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the host time \[ns\] the time pulse announced by the latest UBX-TIM-TP was captured
+    /// at. Captures from before the UBX-TIM-TP was recorded are of the previous pulse and
+    /// ignored, as are repeated calls for the same pulse, so this can be called with the
+    /// latest capture before every [LatencyMeter::record].
+    pub fn on_time_pulse(&mut self, host_ns: u64) {
+        if let Some((pulse, recorded_ns)) = self.announced {
+            if host_ns > recorded_ns {
+                self.anchor = Some((pulse, host_ns));
+                self.announced = None;
+            }
+        }
+    }
+
+    /// Whether a time pulse relates the host clock to GPS time, so that latencies are measured
+    pub fn is_synchronized(&self) -> bool {
+        self.anchor.is_some()
+    }
+
+    /// Record `frame` as processed at host time `host_ns`, returning its latency \[ns\] if it
+    /// carries an iTOW and the meter is synchronized. UBX-TIM-TP announcing a pulse aligned to
+    /// GPS time is kept for the next [LatencyMeter::on_time_pulse].
+    pub fn record(&mut self, frame: UbxUnknownPacketRef<'_>, host_ns: u64) -> Option<i64> {
+        if (frame.class, frame.msg_id) == (TimTp::CLASS, TimTp::ID) {
+            let tim_tp = TimTp::parse_payload(frame.payload).ok()?;
+            let gps_time_base = tim_tp.flags().time_base() == TimTpTimeBase::Gnss
+                && tim_tp.ref_info().time_ref_gnss() == Some(TimTpRefInfoTimeRefGnss::Gps);
+            if gps_time_base {
+                self.announced = Some((GpsTime::of_time_pulse(&tim_tp), host_ns));
+            }
+            return None;
+        }
+
+        let itow = epoch_itow(&frame)?;
+        let (pulse, pulse_ns) = self.anchor?;
+        // The epoch is the one of the iTOW nearest to the pulse, in this week or a neighbour
+        let mut since_pulse = GpsTime {
+            week: pulse.week,
+            tow_ns: u64::from(itow) * NANOS_PER_MS,
+        }
+        .nanos_since(pulse);
+        if since_pulse > NANOS_PER_HALF_WEEK {
+            since_pulse -= 2 * NANOS_PER_HALF_WEEK;
+        } else if since_pulse < -NANOS_PER_HALF_WEEK {
+            since_pulse += 2 * NANOS_PER_HALF_WEEK;
+        }
+        let latency_ns = i128::from(host_ns) - i128::from(pulse_ns) - since_pulse;
+        let latency_ns = latency_ns.clamp(i64::MIN.into(), i64::MAX.into()) as i64;
+
+        let index = match self
+            .latencies
+            .iter()
+            .position(|latency| (latency.class, latency.msg_id) == (frame.class, frame.msg_id))
+        {
+            Some(index) => index,
+            None => {
+                self.latencies.push(PacketLatency {
+                    class: frame.class,
+                    msg_id: frame.msg_id,
+                    histogram: LatencyHistogram::new(),
+                });
+                self.latencies.len() - 1
+            },
+        };
+        self.latencies[index].histogram.add(latency_ns);
+        Some(latency_ns)
+    }
+
+    /// Latencies of the packets of type `M`, if any was recorded
+    pub fn latency<M: UbxPacketMeta>(&self) -> Option<&LatencyHistogram> {
+        self.latencies
+            .iter()
+            .find(|latency| (latency.class, latency.msg_id) == (M::CLASS, M::ID))
+            .map(|latency| &latency.histogram)
+    }
+
+    /// Latencies of all packet types recorded, in the order they were first seen
+    pub fn latencies(&self) -> &[PacketLatency] {
+        &self.latencies
+    }
+
+    /// Forget the latencies recorded, keeping the synchronization to GPS time
+    pub fn reset(&mut self) {
+        self.latencies.clear();
+    }
+}
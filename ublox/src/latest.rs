@@ -0,0 +1,142 @@
+//! Cache of the latest packet of each type, for code reading the receiver state on demand.
+//!
+//! Request/response style code, e.g. an HTTP status endpoint, needs the most recent value of
+//! some messages rather than a stream of them. [LatestState] keeps the payload of the last
+//! frame of each class and message id along with when it was received, and decodes it when
+//! asked for with [LatestState::latest]. Values older than the maximum age set for their
+//! type are reported as missing, so a receiver that stopped sending does not go unnoticed.
+//!
+//! The state is fed with every frame, e.g. from a [Dispatcher](crate::dispatch::Dispatcher):
+//!
+//! ```
+//! # #[cfg(feature = "ubx_proto23")] {
+//! use std::{cell::RefCell, time::Duration};
+//! use ublox::{
+//!     dispatch::Dispatcher, latest::LatestState, nav_pvt::proto23::NavPvt, ubx_frame,
+//!     ParserBuilder,
+//! };
+//!
+//! let state = RefCell::new(LatestState::new().with_max_age(Duration::from_secs(2)));
+//! let mut dispatcher = Dispatcher::new(ParserBuilder::new().with_fixed_buffer::<1024>())
+//!     .on_frame(|frame| state.borrow_mut().update(frame));
+//!
+//! assert!(state.borrow().latest::<NavPvt>().is_none());
+//! dispatcher.feed(&ubx_frame!(0x01, 0x07, &[0; 92]));
+//! let (_received, pvt) = state.borrow().latest::<NavPvt>().unwrap();
+//! assert_eq!(pvt.itow(), 0);
+//! # }
+//! ```
+
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+use crate::{UbxPacketMeta, UbxPacketRecv, UbxUnknownPacketRef};
+
+/// Payload of the last frame of a type
+struct Received {
+    at: Instant,
+    payload: Vec<u8>,
+}
+
+/// Latest payload of each packet type, see the [module documentation](self)
+#[derive(Default)]
+pub struct LatestState {
+    received: HashMap<(u8, u8), Received>,
+    max_ages: HashMap<(u8, u8), Duration>,
+    default_max_age: Option<Duration>,
+}
+
+impl LatestState {
+    /// Create an empty state, whose values never become stale
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Report the values of all types older than `max_age` as missing, unless set otherwise
+    /// with [LatestState::with_max_age_for]
+    pub fn with_max_age(mut self, max_age: Duration) -> Self {
+        self.default_max_age = Some(max_age);
+        self
+    }
+
+    /// Report the values of `M` older than `max_age` as missing, e.g. a longer age for
+    /// messages sent once a minute
+    pub fn with_max_age_for<M: UbxPacketMeta>(mut self, max_age: Duration) -> Self {
+        self.max_ages.insert((M::CLASS, M::ID), max_age);
+        self
+    }
+
+    /// Keep `frame` as the latest of its type, received now
+    pub fn update(&mut self, frame: UbxUnknownPacketRef<'_>) {
+        self.update_at(frame, Instant::now());
+    }
+
+    /// Keep `frame` as the latest of its type, received at `at`
+    pub fn update_at(&mut self, frame: UbxUnknownPacketRef<'_>, at: Instant) {
+        let received = self
+            .received
+            .entry((frame.class, frame.msg_id))
+            .or_insert_with(|| Received {
+                at,
+                payload: Vec::new(),
+            });
+        received.at = at;
+        received.payload.clear();
+        received.payload.extend_from_slice(frame.payload);
+    }
+
+    /// The latest packet of type `M` and when it was received, `None` if there is none, it is
+    /// stale or its payload is invalid for `M`
+    pub fn latest<M: UbxPacketRecv>(&self) -> Option<(Instant, M::Owned)> {
+        self.latest_at::<M>(Instant::now())
+    }
+
+    /// [LatestState::latest], checking staleness at `now`
+    pub fn latest_at<M: UbxPacketRecv>(&self, now: Instant) -> Option<(Instant, M::Owned)> {
+        if self.is_stale_at::<M>(now) {
+            return None;
+        }
+        let received = self.received.get(&(M::CLASS, M::ID))?;
+        let packet = M::parse_payload(&received.payload).ok()?;
+        Some((received.at, packet.into()))
+    }
+
+    /// Time since the latest packet of type `M` was received, whether it is stale or not
+    pub fn age<M: UbxPacketMeta>(&self) -> Option<Duration> {
+        self.age_at::<M>(Instant::now())
+    }
+
+    /// [LatestState::age] at `now`
+    pub fn age_at<M: UbxPacketMeta>(&self, now: Instant) -> Option<Duration> {
+        let received = self.received.get(&(M::CLASS, M::ID))?;
+        Some(now.saturating_duration_since(received.at))
+    }
+
+    /// Whether no packet of type `M` was received within its maximum age. Always false for
+    /// types without maximum age once a packet was received.
+    pub fn is_stale<M: UbxPacketMeta>(&self) -> bool {
+        self.is_stale_at::<M>(Instant::now())
+    }
+
+    /// [LatestState::is_stale] at `now`
+    pub fn is_stale_at<M: UbxPacketMeta>(&self, now: Instant) -> bool {
+        let Some(age) = self.age_at::<M>(now) else {
+            return true;
+        };
+        self.max_age::<M>().is_some_and(|max_age| age > max_age)
+    }
+
+    /// Forget all received packets, e.g. after reconnecting to the receiver
+    pub fn clear(&mut self) {
+        self.received.clear();
+    }
+
+    fn max_age<M: UbxPacketMeta>(&self) -> Option<Duration> {
+        self.max_ages
+            .get(&(M::CLASS, M::ID))
+            .copied()
+            .or(self.default_max_age)
+    }
+}
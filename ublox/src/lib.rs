@@ -8,24 +8,74 @@ extern crate core;
 extern crate serde;
 
 pub use crate::{
-    error::{DateTimeError, MemWriterError, ParserError},
+    error::{ChecksumError, DateTimeError, MemWriterError, ParserError},
     parser::{
-        AnyPacketRef, FixedBuffer, FixedLinearBuffer, NmeaPacketRef, Parser, ParserBuilder,
-        RtcmPacketRef, UbxParserIter, UnderlyingBuffer,
+        verify_frame, AnyPacketRef, CheckedPacket, ClockedParser, ClockedUbxParserIter,
+        DiscardReason, DiscardedBytes, FixedBuffer, FixedLinearBuffer, FrameDecoder, FrameEvent,
+        FrameHeader, FrameParts, FrameTimeout, NmeaPacketRef, OverflowPolicy, Parser,
+        ParserBuilder, ParserStats, ResyncPolicy, RtcmFrameRef, RtcmPacketRef, SpartnCrcType,
+        SpartnFrameRef, SpartnTimeTag, TimestampedPacket, UbxParserIter, UbxRtcmNmeaParserIter,
+        UbxRtcmParserIter, UnderlyingBuffer,
     },
     ubx_packets::*,
 };
 
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use crate::parser::{DiscardCallback, GrowableBuffer};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 mod error;
+mod packet_match;
+mod packet_set;
 mod parser;
 mod ubx_packets;
 
+pub mod ack_tracker;
+pub mod aop;
+pub mod cno_trend;
+#[cfg(feature = "postcard")]
+pub mod compact;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub mod config_plan;
 pub mod constants;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub mod dispatch;
+pub mod epoch;
+pub mod firmware;
+#[cfg(feature = "geo")]
+pub mod geo;
+pub mod i2c_poll;
+pub mod io;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub mod latency;
+#[cfg(feature = "std")]
+pub mod latest;
+pub mod link_health;
+pub mod msg_rate;
+#[cfg(feature = "nmea")]
+pub mod nmea;
+#[cfg(any(feature = "ubx_proto31", feature = "ubx_proto33"))]
+pub mod osnma;
+pub mod prelude;
+pub mod product;
+#[cfg(feature = "serde")]
+pub mod projection;
 pub mod proto14;
 pub mod proto23;
 pub mod proto27;
 pub mod proto31;
 pub mod proto33;
+pub mod recording;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub mod session;
+#[cfg(feature = "std")]
+pub mod simple;
+pub mod stationary;
+pub mod time_mark;
+pub mod trigger;
+pub mod ttff;
 
 /// Unified interface for UBX packets across different protocol versions.
 ///
@@ -33,6 +83,9 @@ pub mod proto33;
 ///
 /// Most users will only need one protocol, so enable only the relevant feature flag.
 ///
+/// With the `serde` feature, a packet serializes as its protocol's `PacketRef`, i.e. as a map
+/// of the class, message id and fields, without copying the packet first.
+///
 /// # Example
 ///
 /// ```rust,ignore
@@ -50,6 +103,8 @@ pub mod proto33;
 /// }
 /// ```
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(untagged))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum UbxPacket<'a> {
     #[cfg(feature = "ubx_proto14")]
     Proto14(proto14::PacketRef<'a>),
@@ -63,14 +118,186 @@ pub enum UbxPacket<'a> {
     Proto33(proto33::PacketRef<'a>),
 }
 
+impl<'a> UbxPacket<'a> {
+    /// Class and message id of the packet
+    pub fn class_and_msg_id(&self) -> (u8, u8) {
+        match self {
+            #[cfg(feature = "ubx_proto14")]
+            Self::Proto14(packet) => packet.class_and_msg_id(),
+            #[cfg(feature = "ubx_proto23")]
+            Self::Proto23(packet) => packet.class_and_msg_id(),
+            #[cfg(feature = "ubx_proto27")]
+            Self::Proto27(packet) => packet.class_and_msg_id(),
+            #[cfg(feature = "ubx_proto31")]
+            Self::Proto31(packet) => packet.class_and_msg_id(),
+            #[cfg(feature = "ubx_proto33")]
+            Self::Proto33(packet) => packet.class_and_msg_id(),
+        }
+    }
+
+    /// Copy the packet out of the parser's buffer, e.g. to send it to another thread or to
+    /// keep it past the next `consume_*` call
+    pub fn to_owned(&self) -> UbxPacketOwned {
+        self.into()
+    }
+
+    /// Class, id and payload of the packet, e.g. to rebuild its frame
+    pub fn as_raw(&self) -> UbxUnknownPacketRef<'a> {
+        match self {
+            #[cfg(feature = "ubx_proto14")]
+            Self::Proto14(packet) => packet.as_raw(),
+            #[cfg(feature = "ubx_proto23")]
+            Self::Proto23(packet) => packet.as_raw(),
+            #[cfg(feature = "ubx_proto27")]
+            Self::Proto27(packet) => packet.as_raw(),
+            #[cfg(feature = "ubx_proto31")]
+            Self::Proto31(packet) => packet.as_raw(),
+            #[cfg(feature = "ubx_proto33")]
+            Self::Proto33(packet) => packet.as_raw(),
+        }
+    }
+
+    /// The frame rebuilt from the packet, e.g. to forward it or compare it with a recording,
+    /// with its length and checksum computed again. Use [CheckedPacket::to_frame_vec] to
+    /// verify them against the received frame.
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn to_frame_vec(&self) -> Vec<u8> {
+        self.as_raw().to_frame_bytes()
+    }
+
+    /// Rebuild the frame at the start of `buf` like [UbxPacket::to_frame_vec], returning its
+    /// length
+    pub fn extend_to_slice(
+        &self,
+        buf: &mut [u8],
+    ) -> Result<usize, MemWriterError<core::convert::Infallible>> {
+        self.as_raw().extend_to_slice(buf)
+    }
+}
+
+/// A [UbxPacket] owning its data, see [UbxPacket::to_owned].
+///
+/// Like the per-protocol `PacketOwned` types, it holds its payload in a buffer large enough for
+/// the largest packet of its protocol, so it is best boxed when kept in large numbers.
+///
+/// ```
+/// use ublox::{ParserBuilder, UbxPacketOwned};
+///
+/// let mut parser = ParserBuilder::new().with_fixed_buffer::<1024>();
+/// let mut it = parser.consume_ubx(&[0xb5, 0x62, 0x05, 0x01, 0x02, 0x00, 0x06, 0x01, 0x0f, 0x38]);
+/// let Some(Ok(packet)) = it.next() else { panic!() };
+/// let owned: UbxPacketOwned = packet.to_owned();
+/// drop(it);
+///
+/// let ack = std::thread::spawn(move || owned.class_and_msg_id()).join().unwrap();
+/// assert_eq!(ack, (0x05, 0x01));
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(untagged))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum UbxPacketOwned {
+    #[cfg(feature = "ubx_proto14")]
+    Proto14(proto14::PacketOwned),
+    #[cfg(feature = "ubx_proto23")]
+    Proto23(proto23::PacketOwned),
+    #[cfg(feature = "ubx_proto27")]
+    Proto27(proto27::PacketOwned),
+    #[cfg(feature = "ubx_proto31")]
+    Proto31(proto31::PacketOwned),
+    #[cfg(feature = "ubx_proto33")]
+    Proto33(proto33::PacketOwned),
+}
+
+impl UbxPacketOwned {
+    /// Class and message id of the packet
+    pub fn class_and_msg_id(&self) -> (u8, u8) {
+        match self {
+            #[cfg(feature = "ubx_proto14")]
+            Self::Proto14(packet) => packet.class_and_msg_id(),
+            #[cfg(feature = "ubx_proto23")]
+            Self::Proto23(packet) => packet.class_and_msg_id(),
+            #[cfg(feature = "ubx_proto27")]
+            Self::Proto27(packet) => packet.class_and_msg_id(),
+            #[cfg(feature = "ubx_proto31")]
+            Self::Proto31(packet) => packet.class_and_msg_id(),
+            #[cfg(feature = "ubx_proto33")]
+            Self::Proto33(packet) => packet.class_and_msg_id(),
+        }
+    }
+
+    /// The raw payload, without the frame header and checksum
+    pub fn payload(&self) -> &[u8] {
+        match self {
+            #[cfg(feature = "ubx_proto14")]
+            Self::Proto14(packet) => packet.payload(),
+            #[cfg(feature = "ubx_proto23")]
+            Self::Proto23(packet) => packet.payload(),
+            #[cfg(feature = "ubx_proto27")]
+            Self::Proto27(packet) => packet.payload(),
+            #[cfg(feature = "ubx_proto31")]
+            Self::Proto31(packet) => packet.payload(),
+            #[cfg(feature = "ubx_proto33")]
+            Self::Proto33(packet) => packet.payload(),
+        }
+    }
+
+    /// Class, id and payload of the packet, e.g. to rebuild its frame
+    pub fn as_raw(&self) -> UbxUnknownPacketRef<'_> {
+        let (class, msg_id) = self.class_and_msg_id();
+        UbxUnknownPacketRef {
+            payload: self.payload(),
+            class,
+            msg_id,
+        }
+    }
+
+    /// The frame rebuilt from the packet, like [UbxPacket::to_frame_vec]
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn to_frame_vec(&self) -> Vec<u8> {
+        self.as_raw().to_frame_bytes()
+    }
+}
+
+impl From<&UbxPacket<'_>> for UbxPacketOwned {
+    fn from(packet: &UbxPacket<'_>) -> Self {
+        match packet {
+            #[cfg(feature = "ubx_proto14")]
+            UbxPacket::Proto14(packet) => Self::Proto14(packet.to_owned()),
+            #[cfg(feature = "ubx_proto23")]
+            UbxPacket::Proto23(packet) => Self::Proto23(packet.to_owned()),
+            #[cfg(feature = "ubx_proto27")]
+            UbxPacket::Proto27(packet) => Self::Proto27(packet.to_owned()),
+            #[cfg(feature = "ubx_proto31")]
+            UbxPacket::Proto31(packet) => Self::Proto31(packet.to_owned()),
+            #[cfg(feature = "ubx_proto33")]
+            UbxPacket::Proto33(packet) => Self::Proto33(packet.to_owned()),
+        }
+    }
+}
+
+mod sealed {
+    /// Keeps [UbxProtocol](super::UbxProtocol) implemented by the protocol tags of this crate
+    /// only, so items can be added to it
+    pub trait Sealed {}
+}
+
 /// Trait for parsing UBX protocol version.
-pub trait UbxProtocol: Send + Sized {
+///
+/// It is sealed, implemented by the `ProtoNN` tags of the enabled protocols only.
+pub trait UbxProtocol: sealed::Sealed + Send + Sized {
     /// The protocol-specific PacketRef type. The `'a` lifetime is tied to the input buffer.
     type PacketRef<'a>: Into<UbxPacket<'a>>;
 
+    /// Protocol version number, e.g. 23 for [proto23::Proto23].
+    const VERSION: u8;
+
     /// The maximum payload length supported by this protocol version.
     const MAX_PAYLOAD_LEN: u16;
 
+    /// The packet types parsed into [UbxProtocol::PacketRef] variants, in the order they are
+    /// matched. Other packets are parsed as `Unknown`.
+    fn supported_messages() -> &'static [PacketMeta];
+
     /// Matches a Class ID, Message ID, and payload to a specific packet type.
     fn match_packet(
         class_id: u8,
@@ -78,3 +305,40 @@ pub trait UbxProtocol: Send + Sized {
         payload: &[u8],
     ) -> Result<Self::PacketRef<'_>, ParserError>;
 }
+
+/// [PacketMeta] of the packet type with `class` and `msg_id` in the enabled protocols, the
+/// newest first, e.g. to annotate logs of raw frames. For message ids with several payload
+/// layouts, e.g. versions of UBX-CFG-NAVX5, the first one matched is returned.
+///
+/// ```
+/// # #[cfg(feature = "ubx_proto23")] {
+/// let meta = ublox::packet_meta(0x01, 0x07).unwrap();
+/// assert_eq!(meta.name, "NavPvt");
+/// assert_eq!(meta.fixed_payload_len, Some(92));
+/// assert_eq!(ublox::packet_name(0x0a, 0x04), Some("MonVer"));
+/// assert_eq!(ublox::packet_name(0xf1, 0x00), None);
+/// # }
+/// ```
+pub fn packet_meta(class: u8, msg_id: u8) -> Option<&'static PacketMeta> {
+    let protocols: &[&[PacketMeta]] = &[
+        #[cfg(feature = "ubx_proto33")]
+        proto33::Proto33::supported_messages(),
+        #[cfg(feature = "ubx_proto31")]
+        proto31::Proto31::supported_messages(),
+        #[cfg(feature = "ubx_proto27")]
+        proto27::Proto27::supported_messages(),
+        #[cfg(feature = "ubx_proto23")]
+        proto23::Proto23::supported_messages(),
+        #[cfg(feature = "ubx_proto14")]
+        proto14::Proto14::supported_messages(),
+    ];
+    protocols
+        .iter()
+        .flat_map(|messages| messages.iter())
+        .find(|meta| (meta.class, meta.msg_id) == (class, msg_id))
+}
+
+/// Name of the packet type with `class` and `msg_id`, e.g. `NavPvt`, see [packet_meta]
+pub fn packet_name(class: u8, msg_id: u8) -> Option<&'static str> {
+    packet_meta(class, msg_id).map(|meta| meta.name)
+}
@@ -0,0 +1,196 @@
+//! Health of the serial link between host and receiver, from both of its ends.
+//!
+//! A byte lost or corrupted on the wire shows up differently depending on its direction. The
+//! receiver counts the bytes it could not parse on each port in UBX-MON-MSGPP, which tells
+//! about the data sent by the host. The host's [ParserStats] count the bytes discarded and
+//! the checksum failures in the data sent by the receiver. [LinkHealth] compares both over
+//! the same interval, to tell e.g. a receiver input buffer overrun from a host UART overrun.
+//!
+//! ```
+//! use ublox::{link_health::{LinkDiagnosis, LinkHealth}, ParserStats};
+//!
+//! // Link on UART1
+//! let mut health = LinkHealth::new(1);
+//! let report = health.report(&ParserStats::new());
+//! assert_eq!(report.receiver_skipped_bytes, None);
+//! assert_eq!(report.diagnosis(), LinkDiagnosis::Healthy);
+//! ```
+//!
+//! Feed it every packet received with [LinkHealth::update], poll UBX-MON-MSGPP periodically,
+//! e.g. with `UbxPollRequest::for_packet::<MonMsgpp>()`, and call [LinkHealth::report] after
+//! each response.
+
+#[allow(unused_imports, reason = "Protocol 33 has no MON-MSGPP")]
+use crate::mon_msgpp::MonMsgppRef;
+use crate::{packet_match::match_packet, ParserStats, UbxPacket};
+
+/// Counters of one port in UBX-MON-MSGPP
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ReceiverCounters {
+    msgs: u16,
+    skipped: u32,
+}
+
+#[cfg(any(
+    feature = "ubx_proto14",
+    feature = "ubx_proto23",
+    feature = "ubx_proto27",
+    feature = "ubx_proto31"
+))]
+impl ReceiverCounters {
+    fn new(msgpp: &MonMsgppRef<'_>, port: usize) -> Option<Self> {
+        let msgs = msgpp.port_msgs(port)?;
+        Some(Self {
+            msgs: msgs.iter().fold(0, |sum, count| sum.wrapping_add(*count)),
+            skipped: msgpp.skipped_bytes()[port],
+        })
+    }
+}
+
+/// Counters of the host side, from [ParserStats]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct HostCounters {
+    bytes: u64,
+    discarded: u64,
+    checksum_failures: u64,
+    frames: u64,
+}
+
+impl HostCounters {
+    fn new(stats: &ParserStats) -> Self {
+        Self {
+            bytes: stats.bytes_consumed,
+            discarded: stats.bytes_discarded,
+            checksum_failures: stats.checksum_failures,
+            frames: stats.ubx_frames_total()
+                + stats.nmea_sentences
+                + stats.rtcm_frames
+                + stats.spartn_frames,
+        }
+    }
+}
+
+/// Link activity and errors between two calls to [LinkHealth::report]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LinkReport {
+    /// Messages of any protocol the receiver parsed on the port, `None` unless two
+    /// UBX-MON-MSGPP were received, one before the previous report and one since
+    pub receiver_msgs: Option<u16>,
+    /// Bytes the receiver skipped on the port, `None` like [LinkReport::receiver_msgs]
+    pub receiver_skipped_bytes: Option<u32>,
+    /// Bytes passed to the parser
+    pub host_bytes: u64,
+    /// Frames of any protocol the parser recognized
+    pub host_frames: u64,
+    /// Bytes the parser discarded
+    pub host_discarded_bytes: u64,
+    /// UBX checksum and RTCM3 CRC mismatches
+    pub host_checksum_failures: u64,
+}
+
+/// Which end of the link saw errors in a [LinkReport]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinkDiagnosis {
+    /// Neither end saw an error. Without a receiver report, only the host side is checked.
+    Healthy,
+    /// The receiver skipped bytes sent by the host, e.g. the host writes faster than the
+    /// receiver's baud rate or input buffer allow, or sends a protocol the port does not accept
+    ReceiverSide,
+    /// The host dropped bytes sent by the receiver, e.g. a host UART overrun or a parser
+    /// buffer too small for the messages enabled
+    HostSide,
+    /// Both ends saw errors, pointing at the wire itself or mismatched port settings
+    BothSides,
+}
+
+impl LinkReport {
+    /// Which end of the link saw errors
+    pub fn diagnosis(&self) -> LinkDiagnosis {
+        let receiver = self
+            .receiver_skipped_bytes
+            .is_some_and(|skipped| skipped > 0);
+        let host = self.host_discarded_bytes > 0 || self.host_checksum_failures > 0;
+        match (receiver, host) {
+            (false, false) => LinkDiagnosis::Healthy,
+            (true, false) => LinkDiagnosis::ReceiverSide,
+            (false, true) => LinkDiagnosis::HostSide,
+            (true, true) => LinkDiagnosis::BothSides,
+        }
+    }
+}
+
+/// Tracks UBX-MON-MSGPP and [ParserStats] to report the health of a serial link, see the
+/// [module documentation](self)
+#[derive(Debug, Clone)]
+pub struct LinkHealth {
+    port: usize,
+    /// Receiver counters of the last UBX-MON-MSGPP since the previous report
+    receiver: Option<ReceiverCounters>,
+    /// Receiver counters used by the previous report
+    receiver_reported: Option<ReceiverCounters>,
+    host_reported: HostCounters,
+}
+
+impl LinkHealth {
+    /// Track the link on the receiver's `port`, numbered as in CFG-PRT: 0 is I2C, 1 UART1, 2
+    /// UART2, 3 USB and 4 SPI
+    pub const fn new(port: u8) -> Self {
+        Self {
+            port: port as usize,
+            receiver: None,
+            receiver_reported: None,
+            host_reported: HostCounters {
+                bytes: 0,
+                discarded: 0,
+                checksum_failures: 0,
+                frames: 0,
+            },
+        }
+    }
+
+    /// Record the receiver counters of `packet` if it is a UBX-MON-MSGPP, other packets are
+    /// ignored. Returns whether `packet` was recorded.
+    pub fn update(&mut self, packet: &UbxPacket<'_>) -> bool {
+        #[allow(unused_variables, reason = "Protocol 33 has no MON-MSGPP")]
+        let port = self.port;
+        let counters = match_packet!(packet, {} proto14 {
+            PacketRef::MonMsgpp(msgpp) => ReceiverCounters::new(msgpp, port),
+        } proto23 {
+            PacketRef::MonMsgpp(msgpp) => ReceiverCounters::new(msgpp, port),
+        } proto27 {
+            PacketRef::MonMsgpp(msgpp) => ReceiverCounters::new(msgpp, port),
+        } proto31 {
+            PacketRef::MonMsgpp(msgpp) => ReceiverCounters::new(msgpp, port),
+        } _ => None);
+        self.receiver = counters.or(self.receiver);
+        counters.is_some()
+    }
+
+    /// Link activity and errors since the previous report, with the host counters read from
+    /// `stats`, the statistics of the parser reading from the link
+    pub fn report(&mut self, stats: &ParserStats) -> LinkReport {
+        let host = HostCounters::new(stats);
+        let last = self.host_reported;
+        // The receiver counters wrap around, the host ones are reset with the parser's
+        let receiver = self.receiver.take();
+        let (receiver_msgs, receiver_skipped_bytes) = match (self.receiver_reported, receiver) {
+            (Some(before), Some(now)) => (
+                Some(now.msgs.wrapping_sub(before.msgs)),
+                Some(now.skipped.wrapping_sub(before.skipped)),
+            ),
+            _ => (None, None),
+        };
+        self.receiver_reported = receiver.or(self.receiver_reported);
+        self.host_reported = host;
+        LinkReport {
+            receiver_msgs,
+            receiver_skipped_bytes,
+            host_bytes: host.bytes.saturating_sub(last.bytes),
+            host_frames: host.frames.saturating_sub(last.frames),
+            host_discarded_bytes: host.discarded.saturating_sub(last.discarded),
+            host_checksum_failures: host
+                .checksum_failures
+                .saturating_sub(last.checksum_failures),
+        }
+    }
+}
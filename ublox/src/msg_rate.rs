@@ -0,0 +1,194 @@
+//! Message output rates for every receiver generation.
+//!
+//! Receivers up to protocol 23 enable a message with UBX-CFG-MSG, later ones with the
+//! `CFG-MSGOUT-*` configuration keys of UBX-CFG-VALSET. [MsgRate] builds either from the class
+//! and message id, and [MsgRate::to_packet] picks the one understood by the protocol version
+//! the receiver is parsed with:
+//!
+//! ```
+//! # #[cfg(all(
+//! #     any(feature = "std", feature = "alloc"),
+//! #     feature = "ubx_proto23",
+//! #     feature = "ubx_proto27"
+//! # ))] {
+//! use ublox::{msg_rate::MsgRate, nav_pvt::proto23::NavPvt, proto23::Proto23, proto27::Proto27};
+//!
+//! // NAV-PVT with every navigation solution, 5 Hz once UBX-CFG-RATE set 200 ms
+//! let rate = MsgRate::new::<NavPvt>(1);
+//! assert_eq!(rate.to_packet::<Proto23>().unwrap()[2..4], [0x06, 0x01]);
+//! assert_eq!(rate.to_packet::<Proto27>().unwrap()[2..4], [0x06, 0x8a]);
+//! # }
+//! ```
+//!
+//! The rate is relative to the event the message is tied to, the navigation solution for the
+//! UBX-NAV messages: 1 sends the message with every solution, 2 with every second one and 0
+//! disables it.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::cfg_val::{CfgLayerSet, CfgTransaction, CfgValSetBuilder};
+use crate::{
+    cfg_msg::CfgMsgAllPortsBuilder,
+    cfg_val::{CfgKey, CfgVal},
+    UbxPacketMeta, UbxProtocol,
+};
+
+/// First protocol version configured with UBX-CFG-VALSET instead of UBX-CFG-MSG
+pub const FIRST_VALSET_VERSION: u8 = 27;
+
+/// Number of ports with their own rate: I2C, UART1, UART2, USB and SPI
+pub const PORT_COUNT: usize = 5;
+
+/// Output rate of a UBX message on each port of the receiver
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MsgRate {
+    pub msg_class: u8,
+    pub msg_id: u8,
+    /// Rate on the I2C, UART1, UART2, USB and SPI ports, in this order
+    pub rates: [u8; PORT_COUNT],
+}
+
+impl MsgRate {
+    /// Output message `M` at `rate` on every port
+    pub fn new<M: UbxPacketMeta>(rate: u8) -> Self {
+        Self::from_ids(M::CLASS, M::ID, [rate; PORT_COUNT])
+    }
+
+    /// Output the message with class and message id at `rates` on each port
+    pub const fn from_ids(msg_class: u8, msg_id: u8, rates: [u8; PORT_COUNT]) -> Self {
+        Self {
+            msg_class,
+            msg_id,
+            rates,
+        }
+    }
+
+    /// Disable message `M` on every port
+    pub fn disabled<M: UbxPacketMeta>() -> Self {
+        Self::new::<M>(0)
+    }
+
+    /// Whether receivers speaking protocol `P` are configured with UBX-CFG-VALSET
+    pub fn uses_cfg_valset<P: UbxProtocol>() -> bool {
+        P::VERSION >= FIRST_VALSET_VERSION
+    }
+
+    /// UBX-CFG-MSG setting the rates, for protocols before 27
+    pub fn cfg_msg(&self) -> CfgMsgAllPortsBuilder {
+        let [i2c, uart1, uart2, usb, spi] = self.rates;
+        CfgMsgAllPortsBuilder {
+            msg_class: self.msg_class,
+            msg_id: self.msg_id,
+            rates: [i2c, uart1, uart2, usb, spi, 0],
+        }
+    }
+
+    /// `CFG-MSGOUT-*` values setting the rates, for a UBX-CFG-VALSET. `None` if the message
+    /// has no output rate key.
+    pub fn cfg_vals(&self) -> Option<[CfgVal; PORT_COUNT]> {
+        let first_key = msgout_key(self.msg_class, self.msg_id)? as u32;
+        // The keys of the other ports follow the I2C one, in the order of `rates`
+        let mut port = 0;
+        Some(self.rates.map(|rate| {
+            let mut item = [0; 5];
+            item[..4].copy_from_slice(&(first_key + port).to_le_bytes());
+            item[4] = rate;
+            port += 1;
+            CfgVal::parse(&item).expect("CFG-MSGOUT keys are known")
+        }))
+    }
+
+    /// The frame setting the rates on receivers speaking protocol `P`: UBX-CFG-MSG before
+    /// protocol 27, UBX-CFG-VALSET applied to RAM from then on. `None` if the message has no
+    /// output rate key on a receiver using UBX-CFG-VALSET.
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn to_packet<P: UbxProtocol>(&self) -> Option<Vec<u8>> {
+        self.packet_for(Self::uses_cfg_valset::<P>())
+    }
+
+    /// The UBX-CFG-VALSET frame if `cfg_valset`, the UBX-CFG-MSG one otherwise
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub(crate) fn packet_for(&self, cfg_valset: bool) -> Option<Vec<u8>> {
+        if !cfg_valset {
+            return Some(self.cfg_msg().into_packet_bytes().to_vec());
+        }
+        let cfg_data = self.cfg_vals()?;
+        let mut packet = Vec::new();
+        CfgValSetBuilder {
+            version: 0,
+            layers: CfgLayerSet::RAM,
+            transaction: CfgTransaction::None,
+            reserved0: 0,
+            cfg_data: &cfg_data,
+        }
+        .extend_to(&mut packet);
+        Some(packet)
+    }
+}
+
+/// The I2C output rate key of the message with class and message id
+fn msgout_key(msg_class: u8, msg_id: u8) -> Option<CfgKey> {
+    MSGOUT_KEYS
+        .iter()
+        .find(|(class, id, _)| (*class, *id) == (msg_class, msg_id))
+        .map(|(_, _, key)| *key)
+}
+
+/// Class, message id and I2C output rate key of the UBX messages with `CFG-MSGOUT-*` keys
+const MSGOUT_KEYS: &[(u8, u8, CfgKey)] = &[
+    (0x01, 0x01, CfgKey::MsgOutUbxNavPosEcefI2c),
+    (0x01, 0x02, CfgKey::MsgOutUbxNavPosLlhI2c),
+    (0x01, 0x03, CfgKey::MsgOutUbxNavStatusI2c),
+    (0x01, 0x04, CfgKey::MsgOutUbxNavDopI2c),
+    (0x01, 0x07, CfgKey::MsgOutUbxNavPvtI2c),
+    (0x01, 0x09, CfgKey::MsgOutUbxNavOdoI2C),
+    (0x01, 0x11, CfgKey::MsgOutUbxNavVelEcefI2c),
+    (0x01, 0x12, CfgKey::MsgOutUbxNavVelNedI2c),
+    (0x01, 0x13, CfgKey::MsgOutUbxNavHpPosEcefI2c),
+    (0x01, 0x14, CfgKey::MsgOutUbxNavHpPosLlhI2c),
+    (0x01, 0x20, CfgKey::MsgOutUbxNavTimeGpsI2c),
+    (0x01, 0x21, CfgKey::MsgOutUbxNavTimeUtcI2c),
+    (0x01, 0x22, CfgKey::MsgOutUbxNavClockI2c),
+    (0x01, 0x23, CfgKey::MsgOutUbxNavTimeGloI2c),
+    (0x01, 0x24, CfgKey::MsgOutUbxNavTimeBdsI2c),
+    (0x01, 0x25, CfgKey::MsgOutUbxNavTimeGalI2c),
+    (0x01, 0x26, CfgKey::MsgOutUbxNavTimeLsI2c),
+    (0x01, 0x34, CfgKey::MsgOutUbxNavOrbI2c),
+    (0x01, 0x35, CfgKey::MsgOutUbxNavSatI2c),
+    (0x01, 0x36, CfgKey::MsgOutUbxNavCovI2c),
+    (0x01, 0x39, CfgKey::MsgOutUbxNavGeofenceI2c),
+    (0x01, 0x3b, CfgKey::MsgOutUbxNavSvinI2c),
+    (0x01, 0x3c, CfgKey::MsgOutUbxNavRelposNedI2c),
+    (0x01, 0x43, CfgKey::MsgOutUbxNavSigI2c),
+    (0x01, 0x61, CfgKey::MsgOutUbxNavEoeI2c),
+    (0x01, 0x62, CfgKey::MsgOutUbxNavPlI2c),
+    (0x02, 0x13, CfgKey::MsgOutUbxRxmSfrbxI2c),
+    (0x02, 0x14, CfgKey::MsgOutUbxRxmMeasxI2c),
+    (0x02, 0x15, CfgKey::MsgOutUbxRxmRawxI2c),
+    (0x02, 0x32, CfgKey::MsgOutUbxRxmRtcmI2c),
+    (0x02, 0x34, CfgKey::MsgOutUbxRxmCorI2c),
+    (0x02, 0x59, CfgKey::MsgOutUbxRxmRlmI2c),
+    (0x0a, 0x02, CfgKey::MsgOutUbxMonIoI2c),
+    (0x0a, 0x06, CfgKey::MsgOutUbxMonMsgppI2c),
+    (0x0a, 0x07, CfgKey::MsgOutUbxMonRxbufI2c),
+    (0x0a, 0x08, CfgKey::MsgOutUbxMonTxbufI2c),
+    (0x0a, 0x09, CfgKey::MsgOutUbxMonHwI2c),
+    (0x0a, 0x0b, CfgKey::MsgOutUbxMonHw2I2c),
+    (0x0a, 0x21, CfgKey::MsgOutUbxMonRxrI2c),
+    (0x0a, 0x36, CfgKey::MsgOutUbxMoncommsI2c),
+    (0x0a, 0x37, CfgKey::MsgOutUbxMonHw3I2c),
+    (0x0a, 0x38, CfgKey::MsgOutUbxMonRfI2c),
+    (0x0d, 0x01, CfgKey::MsgOutUbxTimTpI2c),
+    (0x0d, 0x03, CfgKey::MsgOutUbxTimTm2I2c),
+    (0x0d, 0x06, CfgKey::MsgOutUbxTimVrfyI2c),
+    (0x10, 0x02, CfgKey::MsgOutUbxEsfMeasI2c),
+    (0x10, 0x03, CfgKey::MsgOutUbxEsfRawI2c),
+    (0x10, 0x10, CfgKey::MsgOutUbxEsfStatusI2c),
+    (0x10, 0x14, CfgKey::MsgOutUbxEsfAlgI2c),
+    (0x10, 0x15, CfgKey::MsgOutUbxEsfInsI2c),
+    (0x21, 0x08, CfgKey::MsgOutUbxLogInfoI2c),
+    (0x27, 0x09, CfgKey::MsgOutUbxSecSigI2c),
+    (0x27, 0x10, CfgKey::MsgOutUbxSecSiglogI2c),
+];
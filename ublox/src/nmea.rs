@@ -0,0 +1,621 @@
+//! Typed decoding of the common NMEA 0183 sentences emitted by u-blox receivers.
+//!
+//! Sentences are obtained from the mixed parser iterator as [NmeaPacketRef] and decoded
+//! with [NmeaPacketRef::parse]:
+//!
+//! ```
+//! use ublox::{nmea::NmeaSentence, AnyPacketRef, ParserBuilder};
+//!
+//! let mut parser = ParserBuilder::new().with_fixed_buffer::<1024>();
+//! let data = b"$GNGGA,092725.00,4717.11399,N,00833.91590,E,1,08,1.01,499.6,M,48.0,M,,*45\r\n";
+//! let mut it = parser.consume_ubx_rtcm_nmea(data);
+//! if let Some(Ok(AnyPacketRef::Nmea(nmea))) = it.next() {
+//!     if let Ok(NmeaSentence::Gga(gga)) = nmea.parse() {
+//!         assert_eq!(gga.num_satellites, Some(8));
+//!     }
+//! }
+//! ```
+//!
+//! Empty fields are reported as `None`. Sentences other than GGA, RMC, GSV, GSA, VTG, ZDA and
+//! PUBX,00 are returned as [NmeaSentence::Other] with their checksum verified.
+//!
+//! [PubxRateBuilder] and [PubxConfigBuilder] assemble the u-blox proprietary PUBX,40 and
+//! PUBX,41 configuration sentences.
+
+use core::str::FromStr;
+
+use chrono::{NaiveDate, NaiveTime};
+
+use crate::{constants::NMEA_MAX_SENTENCE_LENGTH, error::ParserError, parser::NmeaPacketRef};
+
+mod pubx;
+pub use pubx::{Pubx00, PubxConfigBuilder, PubxNavStatus, PubxProtoMask, PubxRateBuilder};
+
+const NMEA: &str = "NMEA";
+
+impl<'a> NmeaPacketRef<'a> {
+    /// Verify the checksum of the sentence and decode it into a typed [NmeaSentence]
+    pub fn parse(&self) -> Result<NmeaSentence<'a>, ParserError> {
+        NmeaSentence::parse(self.data)
+    }
+}
+
+/// Talker identifier, the first two characters of the sentence address
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum NmeaTalker {
+    /// `GP`
+    Gps,
+    /// `GL`
+    Glonass,
+    /// `GA`
+    Galileo,
+    /// `GB` or `BD`
+    Beidou,
+    /// `GQ`
+    Qzss,
+    /// `GI`
+    Navic,
+    /// `GN`, solution combining several constellations
+    Gnss,
+    Other([u8; 2]),
+}
+
+impl NmeaTalker {
+    fn from_bytes(id: [u8; 2]) -> Self {
+        match &id {
+            b"GP" => Self::Gps,
+            b"GL" => Self::Glonass,
+            b"GA" => Self::Galileo,
+            b"GB" | b"BD" => Self::Beidou,
+            b"GQ" => Self::Qzss,
+            b"GI" => Self::Navic,
+            b"GN" => Self::Gnss,
+            _ => Self::Other(id),
+        }
+    }
+}
+
+/// A decoded NMEA sentence
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq)]
+pub enum NmeaSentence<'a> {
+    Gga(Gga),
+    Rmc(Rmc),
+    Gsv(Gsv),
+    Gsa(Gsa),
+    Vtg(Vtg),
+    Zda(Zda),
+    /// u-blox proprietary position data
+    Pubx00(Pubx00),
+    /// A sentence without typed support, including proprietary `$P...` sentences
+    Other {
+        /// Sentence address, e.g. `GNGLL` or `PUBX`
+        address: &'a str,
+        /// Comma separated fields following the address, without the checksum
+        fields: &'a str,
+    },
+}
+
+/// Position fix quality indicator of GGA
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum GgaFixQuality {
+    Invalid,
+    Autonomous,
+    Differential,
+    Pps,
+    RtkFixed,
+    RtkFloat,
+    DeadReckoning,
+    Manual,
+    Simulator,
+    Other(u8),
+}
+
+impl From<u8> for GgaFixQuality {
+    fn from(v: u8) -> Self {
+        match v {
+            0 => Self::Invalid,
+            1 => Self::Autonomous,
+            2 => Self::Differential,
+            3 => Self::Pps,
+            4 => Self::RtkFixed,
+            5 => Self::RtkFloat,
+            6 => Self::DeadReckoning,
+            7 => Self::Manual,
+            8 => Self::Simulator,
+            _ => Self::Other(v),
+        }
+    }
+}
+
+/// Positioning mode indicator of RMC and VTG (NMEA 2.3 and later)
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum NmeaPosMode {
+    NoFix,
+    Autonomous,
+    Differential,
+    Estimated,
+    RtkFixed,
+    RtkFloat,
+    Manual,
+    Simulator,
+    Other(u8),
+}
+
+impl From<u8> for NmeaPosMode {
+    fn from(v: u8) -> Self {
+        match v {
+            b'N' => Self::NoFix,
+            b'A' => Self::Autonomous,
+            b'D' => Self::Differential,
+            b'E' => Self::Estimated,
+            b'R' => Self::RtkFixed,
+            b'F' => Self::RtkFloat,
+            b'M' => Self::Manual,
+            b'S' => Self::Simulator,
+            _ => Self::Other(v),
+        }
+    }
+}
+
+/// Global positioning system fix data
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Gga {
+    pub talker: NmeaTalker,
+    /// UTC time of the position
+    pub time: Option<NaiveTime>,
+    /// Latitude in degrees, negative south of the equator
+    pub latitude: Option<f64>,
+    /// Longitude in degrees, negative west of Greenwich
+    pub longitude: Option<f64>,
+    pub fix_quality: GgaFixQuality,
+    pub num_satellites: Option<u8>,
+    pub hdop: Option<f32>,
+    /// Altitude above mean sea level in meters
+    pub altitude: Option<f32>,
+    /// Geoid separation in meters
+    pub geoid_separation: Option<f32>,
+    /// Age of differential corrections in seconds
+    pub diff_age: Option<f32>,
+    pub diff_station: Option<u16>,
+}
+
+/// Recommended minimum data
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Rmc {
+    pub talker: NmeaTalker,
+    pub time: Option<NaiveTime>,
+    /// `true` if the status field is `A` (data valid)
+    pub valid: bool,
+    /// Latitude in degrees, negative south of the equator
+    pub latitude: Option<f64>,
+    /// Longitude in degrees, negative west of Greenwich
+    pub longitude: Option<f64>,
+    /// Speed over ground in knots
+    pub speed_knots: Option<f32>,
+    /// Course over ground in degrees
+    pub course: Option<f32>,
+    pub date: Option<NaiveDate>,
+    /// Magnetic variation in degrees, negative to the west
+    pub magnetic_variation: Option<f32>,
+    pub mode: Option<NmeaPosMode>,
+}
+
+/// One satellite of a GSV sentence
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct GsvSatellite {
+    pub sv_id: u16,
+    /// Elevation in degrees
+    pub elevation: Option<u8>,
+    /// Azimuth in degrees
+    pub azimuth: Option<u16>,
+    /// Signal strength C/N0 in dBHz, `None` when not tracking
+    pub cno: Option<u8>,
+}
+
+/// GNSS satellites in view, one of a sequence of `num_msgs` sentences
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Gsv {
+    pub talker: NmeaTalker,
+    pub num_msgs: u8,
+    pub msg_num: u8,
+    pub num_satellites: u8,
+    pub satellites: [Option<GsvSatellite>; 4],
+    /// NMEA 4.10 signal identifier
+    pub signal_id: Option<u8>,
+}
+
+impl Gsv {
+    /// Iterate over the satellites present in this sentence
+    pub fn iter_satellites(&self) -> impl Iterator<Item = &GsvSatellite> {
+        self.satellites.iter().flatten()
+    }
+}
+
+/// Navigation fix type of GSA
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum GsaFixType {
+    NoFix,
+    Fix2D,
+    Fix3D,
+    Other(u8),
+}
+
+impl From<u8> for GsaFixType {
+    fn from(v: u8) -> Self {
+        match v {
+            1 => Self::NoFix,
+            2 => Self::Fix2D,
+            3 => Self::Fix3D,
+            _ => Self::Other(v),
+        }
+    }
+}
+
+/// GNSS DOP and active satellites
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Gsa {
+    pub talker: NmeaTalker,
+    /// `true` if the receiver switches between 2D and 3D automatically (`A`)
+    pub automatic: bool,
+    pub fix_type: GsaFixType,
+    pub sv_ids: [Option<u16>; 12],
+    pub pdop: Option<f32>,
+    pub hdop: Option<f32>,
+    pub vdop: Option<f32>,
+    /// NMEA 4.10 GNSS system identifier
+    pub system_id: Option<u8>,
+}
+
+impl Gsa {
+    /// Iterate over the satellites used in the solution
+    pub fn iter_sv_ids(&self) -> impl Iterator<Item = u16> + '_ {
+        self.sv_ids.iter().flatten().copied()
+    }
+}
+
+/// Course over ground and ground speed
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Vtg {
+    pub talker: NmeaTalker,
+    /// Course over ground (true) in degrees
+    pub course_true: Option<f32>,
+    /// Course over ground (magnetic) in degrees
+    pub course_magnetic: Option<f32>,
+    pub speed_knots: Option<f32>,
+    pub speed_kmh: Option<f32>,
+    pub mode: Option<NmeaPosMode>,
+}
+
+/// Time and date
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Zda {
+    pub talker: NmeaTalker,
+    pub time: Option<NaiveTime>,
+    pub date: Option<NaiveDate>,
+    pub local_zone_hours: Option<i8>,
+    pub local_zone_minutes: Option<u8>,
+}
+
+impl<'a> NmeaSentence<'a> {
+    /// Verify the checksum of a whole sentence (`$` up to an optional trailing `\r\n`)
+    /// and decode it
+    pub fn parse(data: &'a [u8]) -> Result<Self, ParserError> {
+        let (address, fields) = split_sentence(data)?;
+        if address == "PUBX" {
+            return Ok(match fields.split_once(',') {
+                Some(("00", pubx_fields)) => Self::Pubx00(pubx::parse_pubx00(pubx_fields)?),
+                _ => Self::Other { address, fields },
+            });
+        }
+        if address.len() != 5 || address.starts_with('P') {
+            return Ok(Self::Other { address, fields });
+        }
+        let (talker, kind) = address.split_at(2);
+        let talker = NmeaTalker::from_bytes(talker.as_bytes().try_into().unwrap());
+        let sentence = match kind {
+            "GGA" => Self::Gga(parse_gga(talker, fields)?),
+            "RMC" => Self::Rmc(parse_rmc(talker, fields)?),
+            "GSV" => Self::Gsv(parse_gsv(talker, fields)?),
+            "GSA" => Self::Gsa(parse_gsa(talker, fields)?),
+            "VTG" => Self::Vtg(parse_vtg(talker, fields)?),
+            "ZDA" => Self::Zda(parse_zda(talker, fields)?),
+            _ => Self::Other { address, fields },
+        };
+        Ok(sentence)
+    }
+}
+
+/// Check framing and checksum, returning the address and the remaining fields
+fn split_sentence(data: &[u8]) -> Result<(&str, &str), ParserError> {
+    let invalid = |field| ParserError::InvalidField {
+        packet: NMEA,
+        field,
+    };
+    let data = data.strip_suffix(b"\r\n").unwrap_or(data);
+    let data = data.strip_prefix(b"$").ok_or(invalid("sync"))?;
+    let star = data.len().checked_sub(3).ok_or(invalid("checksum"))?;
+    if data[star] != b'*' {
+        return Err(invalid("checksum"));
+    }
+    let (body, checksum) = (&data[..star], &data[star + 1..]);
+    let expect = core::str::from_utf8(checksum)
+        .ok()
+        .and_then(|s| u8::from_str_radix(s, 16).ok())
+        .ok_or(invalid("checksum"))?;
+    let got = body.iter().fold(0u8, |acc, b| acc ^ b);
+    if expect != got {
+        return Err(ParserError::InvalidChecksum {
+            expect: expect.into(),
+            got: got.into(),
+        });
+    }
+    // NMEA sentences are ASCII, which also keeps the fields safe to slice at any byte
+    if !body.is_ascii() {
+        return Err(invalid("data"));
+    }
+    let body = core::str::from_utf8(body).map_err(|_| invalid("data"))?;
+    Ok(body.split_once(',').unwrap_or((body, "")))
+}
+
+/// Cursor over the comma separated fields of a sentence
+struct Fields<'a> {
+    packet: &'static str,
+    it: core::str::Split<'a, char>,
+}
+
+impl<'a> Fields<'a> {
+    fn new(packet: &'static str, fields: &'a str) -> Self {
+        Self {
+            packet,
+            it: fields.split(','),
+        }
+    }
+
+    fn err(&self, field: &'static str) -> ParserError {
+        ParserError::InvalidField {
+            packet: self.packet,
+            field,
+        }
+    }
+
+    /// Next field, or `None` if it is empty or missing
+    fn next_str(&mut self) -> Option<&'a str> {
+        self.it.next().filter(|s| !s.is_empty())
+    }
+
+    fn opt<T: FromStr>(&mut self, field: &'static str) -> Result<Option<T>, ParserError> {
+        self.next_str()
+            .map(|s| s.parse().map_err(|_| self.err(field)))
+            .transpose()
+    }
+
+    fn req<T: FromStr>(&mut self, field: &'static str) -> Result<T, ParserError> {
+        self.opt(field)?.ok_or_else(|| self.err(field))
+    }
+
+    fn hex(&mut self, field: &'static str) -> Result<Option<u8>, ParserError> {
+        self.next_str()
+            .map(|s| u8::from_str_radix(s, 16).map_err(|_| self.err(field)))
+            .transpose()
+    }
+
+    fn char(&mut self) -> Option<u8> {
+        self.next_str().map(|s| s.as_bytes()[0])
+    }
+
+    fn skip(&mut self) {
+        self.it.next();
+    }
+
+    /// `hhmmss.ss`
+    fn time(&mut self) -> Result<Option<NaiveTime>, ParserError> {
+        let Some(s) = self.next_str() else {
+            return Ok(None);
+        };
+        let err = || self.err("time");
+        let (hms, frac) = s.split_once('.').unwrap_or((s, ""));
+        if hms.len() != 6 || frac.len() > 9 {
+            return Err(err());
+        }
+        let num = |s: &str| s.parse::<u32>().map_err(|_| err());
+        let nanos = if frac.is_empty() {
+            0
+        } else {
+            num(frac)? * 10u32.pow(9 - frac.len() as u32)
+        };
+        NaiveTime::from_hms_nano_opt(num(&hms[0..2])?, num(&hms[2..4])?, num(&hms[4..6])?, nanos)
+            .map(Some)
+            .ok_or_else(err)
+    }
+
+    /// `ddmmyy`
+    fn date(&mut self) -> Result<Option<NaiveDate>, ParserError> {
+        let Some(s) = self.next_str() else {
+            return Ok(None);
+        };
+        let err = || self.err("date");
+        if s.len() != 6 {
+            return Err(err());
+        }
+        let num = |s: &str| s.parse::<u32>().map_err(|_| err());
+        let (day, month, year) = (num(&s[0..2])?, num(&s[2..4])?, num(&s[4..6])?);
+        NaiveDate::from_ymd_opt(2000 + year as i32, month, day)
+            .map(Some)
+            .ok_or_else(err)
+    }
+
+    /// `(d)ddmm.mmmm` followed by a hemisphere field
+    fn coordinate(
+        &mut self,
+        field: &'static str,
+        negative: u8,
+    ) -> Result<Option<f64>, ParserError> {
+        let value = self.next_str();
+        let hemisphere = self.char();
+        let Some(s) = value else {
+            return Ok(None);
+        };
+        let err = || self.err(field);
+        let dot = s.find('.').unwrap_or(s.len());
+        let split = dot.checked_sub(2).ok_or_else(err)?;
+        let degrees: f64 = s[..split].parse::<u16>().map_err(|_| err())?.into();
+        let minutes: f64 = s[split..].parse().map_err(|_| err())?;
+        let value = degrees + minutes / 60.0;
+        Ok(Some(if hemisphere == Some(negative) {
+            -value
+        } else {
+            value
+        }))
+    }
+}
+
+fn parse_gga(talker: NmeaTalker, fields: &str) -> Result<Gga, ParserError> {
+    let mut f = Fields::new("GGA", fields);
+    let time = f.time()?;
+    let latitude = f.coordinate("latitude", b'S')?;
+    let longitude = f.coordinate("longitude", b'W')?;
+    let fix_quality = f.req::<u8>("fix_quality")?.into();
+    let num_satellites = f.opt("num_satellites")?;
+    let hdop = f.opt("hdop")?;
+    let altitude = f.opt("altitude")?;
+    f.skip();
+    let geoid_separation = f.opt("geoid_separation")?;
+    f.skip();
+    Ok(Gga {
+        talker,
+        time,
+        latitude,
+        longitude,
+        fix_quality,
+        num_satellites,
+        hdop,
+        altitude,
+        geoid_separation,
+        diff_age: f.opt("diff_age")?,
+        diff_station: f.opt("diff_station")?,
+    })
+}
+
+fn parse_rmc(talker: NmeaTalker, fields: &str) -> Result<Rmc, ParserError> {
+    let mut f = Fields::new("RMC", fields);
+    let time = f.time()?;
+    let valid = f.char() == Some(b'A');
+    let latitude = f.coordinate("latitude", b'S')?;
+    let longitude = f.coordinate("longitude", b'W')?;
+    let speed_knots = f.opt("speed_knots")?;
+    let course = f.opt("course")?;
+    let date = f.date()?;
+    let magnetic_variation = f.opt::<f32>("magnetic_variation")?;
+    let magnetic_variation = match f.char() {
+        Some(b'W') => magnetic_variation.map(|v| -v),
+        _ => magnetic_variation,
+    };
+    Ok(Rmc {
+        talker,
+        time,
+        valid,
+        latitude,
+        longitude,
+        speed_knots,
+        course,
+        date,
+        magnetic_variation,
+        mode: f.char().map(NmeaPosMode::from),
+    })
+}
+
+fn parse_gsv(talker: NmeaTalker, fields: &str) -> Result<Gsv, ParserError> {
+    let mut f = Fields::new("GSV", fields);
+    let num_msgs = f.req("num_msgs")?;
+    let msg_num = f.req("msg_num")?;
+    let num_satellites = f.req("num_satellites")?;
+    let mut satellites = [None; 4];
+    let num_in_msg = f.it.clone().count() / 4;
+    for sat in satellites.iter_mut().take(num_in_msg) {
+        let sv_id = f.opt("sv_id")?;
+        let elevation = f.opt("elevation")?;
+        let azimuth = f.opt("azimuth")?;
+        let cno = f.opt("cno")?;
+        *sat = sv_id.map(|sv_id| GsvSatellite {
+            sv_id,
+            elevation,
+            azimuth,
+            cno,
+        });
+    }
+    let signal_id = f.hex("signal_id")?;
+    Ok(Gsv {
+        talker,
+        num_msgs,
+        msg_num,
+        num_satellites,
+        satellites,
+        signal_id,
+    })
+}
+
+fn parse_gsa(talker: NmeaTalker, fields: &str) -> Result<Gsa, ParserError> {
+    let mut f = Fields::new("GSA", fields);
+    let automatic = f.char() == Some(b'A');
+    let fix_type = f.req::<u8>("fix_type")?.into();
+    let mut sv_ids = [None; 12];
+    for id in sv_ids.iter_mut() {
+        *id = f.opt("sv_id")?;
+    }
+    Ok(Gsa {
+        talker,
+        automatic,
+        fix_type,
+        sv_ids,
+        pdop: f.opt("pdop")?,
+        hdop: f.opt("hdop")?,
+        vdop: f.opt("vdop")?,
+        system_id: f.hex("system_id")?,
+    })
+}
+
+fn parse_vtg(talker: NmeaTalker, fields: &str) -> Result<Vtg, ParserError> {
+    let mut f = Fields::new("VTG", fields);
+    let course_true = f.opt("course_true")?;
+    f.skip();
+    let course_magnetic = f.opt("course_magnetic")?;
+    f.skip();
+    let speed_knots = f.opt("speed_knots")?;
+    f.skip();
+    let speed_kmh = f.opt("speed_kmh")?;
+    f.skip();
+    Ok(Vtg {
+        talker,
+        course_true,
+        course_magnetic,
+        speed_knots,
+        speed_kmh,
+        mode: f.char().map(NmeaPosMode::from),
+    })
+}
+
+fn parse_zda(talker: NmeaTalker, fields: &str) -> Result<Zda, ParserError> {
+    let mut f = Fields::new("ZDA", fields);
+    let time = f.time()?;
+    let day = f.opt::<u32>("day")?;
+    let month = f.opt::<u32>("month")?;
+    let year = f.opt::<i32>("year")?;
+    let date = match (year, month, day) {
+        (Some(year), Some(month), Some(day)) => {
+            Some(NaiveDate::from_ymd_opt(year, month, day).ok_or_else(|| f.err("date"))?)
+        },
+        _ => None,
+    };
+    Ok(Zda {
+        talker,
+        time,
+        date,
+        local_zone_hours: f.opt("local_zone_hours")?,
+        local_zone_minutes: f.opt("local_zone_minutes")?,
+    })
+}
@@ -0,0 +1,235 @@
+use core::fmt::{self, Write};
+
+use bitflags::bitflags;
+use chrono::NaiveTime;
+
+use super::{Fields, NMEA_MAX_SENTENCE_LENGTH};
+use crate::error::ParserError;
+
+/// Fixed capacity sentence assembly, appending the checksum and line ending on completion
+struct SentenceWriter {
+    buf: [u8; NMEA_MAX_SENTENCE_LENGTH],
+    len: usize,
+}
+
+impl SentenceWriter {
+    fn new() -> Self {
+        Self {
+            buf: [0; NMEA_MAX_SENTENCE_LENGTH],
+            len: 0,
+        }
+    }
+
+    fn finish<T>(mut self, out: &mut T) -> usize
+    where
+        T: core::iter::Extend<u8>,
+    {
+        let checksum = self.buf[1..self.len].iter().fold(0u8, |acc, b| acc ^ b);
+        write!(self, "*{checksum:02X}\r\n").expect("PUBX sentences fit in 82 characters");
+        out.extend(self.buf[..self.len].iter().copied());
+        self.len
+    }
+}
+
+impl Write for SentenceWriter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.buf
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// PUBX,40: set the output rate of a standard NMEA message on each port
+///
+/// Rates are in number of navigation solutions, 0 disables the message on that port.
+/// ```
+/// use ublox::nmea::PubxRateBuilder;
+///
+/// let mut sentence = Vec::new();
+/// PubxRateBuilder {
+///     msg_id: *b"GLL",
+///     ..Default::default()
+/// }
+/// .extend_to(&mut sentence);
+/// assert_eq!(sentence, b"$PUBX,40,GLL,0,0,0,0,0,0*5C\r\n");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PubxRateBuilder {
+    /// Sentence formatter, e.g. `*b"GGA"`
+    pub msg_id: [u8; 3],
+    pub rate_ddc: u8,
+    pub rate_uart1: u8,
+    pub rate_uart2: u8,
+    pub rate_usb: u8,
+    pub rate_spi: u8,
+}
+
+impl PubxRateBuilder {
+    /// Append the sentence, including checksum and line ending, to `out` and return its length
+    pub fn extend_to<T>(&self, out: &mut T) -> usize
+    where
+        T: core::iter::Extend<u8>,
+    {
+        let mut w = SentenceWriter::new();
+        let msg_id = core::str::from_utf8(&self.msg_id).unwrap_or("");
+        write!(
+            w,
+            "$PUBX,40,{msg_id},{},{},{},{},{},0",
+            self.rate_ddc, self.rate_uart1, self.rate_uart2, self.rate_usb, self.rate_spi
+        )
+        .expect("PUBX sentences fit in 82 characters");
+        w.finish(out)
+    }
+}
+
+bitflags! {
+    /// Protocol mask of PUBX,41
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub struct PubxProtoMask: u16 {
+        const UBX = 0x01;
+        const NMEA = 0x02;
+        const RTCM = 0x04;
+        const RTCM3 = 0x20;
+    }
+}
+
+/// PUBX,41: set the protocols and baud rate of a port
+/// ```
+/// use ublox::nmea::{PubxConfigBuilder, PubxProtoMask};
+///
+/// let mut sentence = Vec::new();
+/// PubxConfigBuilder {
+///     port_id: 1,
+///     in_proto: PubxProtoMask::UBX | PubxProtoMask::NMEA | PubxProtoMask::RTCM,
+///     out_proto: PubxProtoMask::UBX | PubxProtoMask::NMEA,
+///     baudrate: 19200,
+///     autobauding: false,
+/// }
+/// .extend_to(&mut sentence);
+/// assert_eq!(sentence, b"$PUBX,41,1,0007,0003,19200,0*25\r\n");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PubxConfigBuilder {
+    /// Port identifier, e.g. 1 for UART1
+    pub port_id: u8,
+    pub in_proto: PubxProtoMask,
+    pub out_proto: PubxProtoMask,
+    pub baudrate: u32,
+    pub autobauding: bool,
+}
+
+impl PubxConfigBuilder {
+    /// Append the sentence, including checksum and line ending, to `out` and return its length
+    pub fn extend_to<T>(&self, out: &mut T) -> usize
+    where
+        T: core::iter::Extend<u8>,
+    {
+        let mut w = SentenceWriter::new();
+        write!(
+            w,
+            "$PUBX,41,{},{:04X},{:04X},{},{}",
+            self.port_id,
+            self.in_proto.bits(),
+            self.out_proto.bits(),
+            self.baudrate,
+            u8::from(self.autobauding)
+        )
+        .expect("PUBX sentences fit in 82 characters");
+        w.finish(out)
+    }
+}
+
+/// PUBX,00 navigation status
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PubxNavStatus {
+    NoFix,
+    DeadReckoning,
+    Standalone2D,
+    Standalone3D,
+    Differential2D,
+    Differential3D,
+    /// Combined GNSS and dead reckoning solution
+    Combined,
+    TimeOnly,
+    Other([u8; 2]),
+}
+
+impl From<[u8; 2]> for PubxNavStatus {
+    fn from(v: [u8; 2]) -> Self {
+        match &v {
+            b"NF" => Self::NoFix,
+            b"DR" => Self::DeadReckoning,
+            b"G2" => Self::Standalone2D,
+            b"G3" => Self::Standalone3D,
+            b"D2" => Self::Differential2D,
+            b"D3" => Self::Differential3D,
+            b"RK" => Self::Combined,
+            b"TT" => Self::TimeOnly,
+            _ => Self::Other(v),
+        }
+    }
+}
+
+/// PUBX,00: lat/long position data, polled with `$PUBX,00*33`
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Pubx00 {
+    pub time: Option<NaiveTime>,
+    /// Latitude in degrees, negative south of the equator
+    pub latitude: Option<f64>,
+    /// Longitude in degrees, negative west of Greenwich
+    pub longitude: Option<f64>,
+    /// Altitude above the user datum ellipsoid in meters
+    pub altitude: Option<f32>,
+    pub nav_status: PubxNavStatus,
+    /// Horizontal accuracy estimate in meters
+    pub h_acc: Option<f32>,
+    /// Vertical accuracy estimate in meters
+    pub v_acc: Option<f32>,
+    /// Speed over ground in km/h
+    pub speed_kmh: Option<f32>,
+    /// Course over ground in degrees
+    pub course: Option<f32>,
+    /// Vertical velocity in m/s, positive downwards
+    pub vel_down: Option<f32>,
+    /// Age of differential corrections in seconds
+    pub diff_age: Option<f32>,
+    pub hdop: Option<f32>,
+    pub vdop: Option<f32>,
+    pub tdop: Option<f32>,
+    pub num_satellites: Option<u8>,
+}
+
+pub(super) fn parse_pubx00(fields: &str) -> Result<Pubx00, ParserError> {
+    let mut f = Fields::new("PUBX,00", fields);
+    let time = f.time()?;
+    let latitude = f.coordinate("latitude", b'S')?;
+    let longitude = f.coordinate("longitude", b'W')?;
+    let altitude = f.opt("altitude")?;
+    let nav_status = f
+        .next_str()
+        .and_then(|s| <[u8; 2]>::try_from(s.as_bytes()).ok())
+        .ok_or_else(|| f.err("nav_status"))?
+        .into();
+    Ok(Pubx00 {
+        time,
+        latitude,
+        longitude,
+        altitude,
+        nav_status,
+        h_acc: f.opt("h_acc")?,
+        v_acc: f.opt("v_acc")?,
+        speed_kmh: f.opt("speed_kmh")?,
+        course: f.opt("course")?,
+        vel_down: f.opt("vel_down")?,
+        diff_age: f.opt("diff_age")?,
+        hdop: f.opt("hdop")?,
+        vdop: f.opt("vdop")?,
+        tdop: f.opt("tdop")?,
+        num_satellites: f.opt("num_satellites")?,
+    })
+}
@@ -0,0 +1,156 @@
+//! Galileo OSNMA authentication summary, telling whether the current fix can be trusted not
+//! to be spoofed.
+//!
+//! Receivers supporting navigation message authentication report its progress in
+//! UBX-SEC-OSNMA and flag authenticated solution times in UBX-NAV-PVT. [FixAuthentication]
+//! combines both into a single verdict. Only protocols 31 and 33 define these messages.
+//!
+//! ```
+//! use ublox::osnma::{AuthStatus, FixAuthentication};
+//!
+//! let auth = FixAuthentication::new();
+//! assert_eq!(auth.status(), AuthStatus::Unknown);
+//! ```
+//!
+//! Feed it every packet received with [FixAuthentication::update], then check
+//! [FixAuthentication::status] before relying on a position or time.
+
+use crate::{
+    packet_match::match_packet,
+    sec_osnma::{NmaStatus, OsnmaFlags},
+    UbxPacket,
+};
+
+/// Minimum number of authenticated satellites for [FixAuthentication::new], enough for a fix
+/// from authenticated navigation data only
+pub const DEFAULT_MIN_AUTH_SVS: u8 = 4;
+
+/// Whether the current fix is authenticated
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthStatus {
+    /// No UBX-SEC-OSNMA received yet, e.g. the receiver does not support OSNMA
+    Unknown,
+    NotAuthenticated(NotAuthenticated),
+    /// Enough satellites are authenticated and the solution time is authenticated as well
+    Authenticated,
+}
+
+/// Reason why a fix is not authenticated, in the order the conditions are checked
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotAuthenticated {
+    /// OSNMA is disabled in the receiver configuration
+    Disabled,
+    /// The Galileo system does not announce OSNMA as operational
+    NotOperational(NmaStatus),
+    /// The public key was not verified yet
+    NoValidKey,
+    /// The receiver time is not synchronized closely enough yet
+    NoTimeSync,
+    /// Fewer satellites than required have authenticated navigation data
+    TooFewSatellites(u8),
+    /// The last UBX-NAV-PVT did not flag its time as authenticated
+    TimeNotAuthenticated,
+}
+
+/// Tracks UBX-SEC-OSNMA and UBX-NAV-PVT to report whether the current fix is authenticated
+#[derive(Debug, Clone)]
+pub struct FixAuthentication {
+    min_auth_svs: u8,
+    osnma: Option<Osnma>,
+    /// Authenticated time flag of the last UBX-NAV-PVT, if the receiver reports it
+    time_authenticated: Option<bool>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Osnma {
+    flags: OsnmaFlags,
+    nma_status: NmaStatus,
+    num_auth_svs: u8,
+}
+
+impl Default for FixAuthentication {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FixAuthentication {
+    pub const fn new() -> Self {
+        Self::with_min_auth_svs(DEFAULT_MIN_AUTH_SVS)
+    }
+
+    /// Require at least `min_auth_svs` authenticated satellites instead of
+    /// [DEFAULT_MIN_AUTH_SVS]
+    pub const fn with_min_auth_svs(min_auth_svs: u8) -> Self {
+        Self {
+            min_auth_svs,
+            osnma: None,
+            time_authenticated: None,
+        }
+    }
+
+    /// Update the state from `packet`, a UBX-SEC-OSNMA or UBX-NAV-PVT. Returns the new status
+    /// when `packet` is one of them, other packets are ignored.
+    pub fn update(&mut self, packet: &UbxPacket<'_>) -> Option<AuthStatus> {
+        let report = auth_report(packet)?;
+        match report {
+            Report::Osnma(osnma) => self.osnma = Some(osnma),
+            Report::PvtTime(authenticated) => self.time_authenticated = Some(authenticated),
+        }
+        Some(self.status())
+    }
+
+    /// Whether the current fix is authenticated, and if not, why
+    pub fn status(&self) -> AuthStatus {
+        let Some(osnma) = self.osnma else {
+            return AuthStatus::Unknown;
+        };
+        let reason = if !osnma.flags.contains(OsnmaFlags::ENABLED) {
+            NotAuthenticated::Disabled
+        } else if osnma.nma_status != NmaStatus::Operational {
+            NotAuthenticated::NotOperational(osnma.nma_status)
+        } else if !osnma.flags.contains(OsnmaFlags::KEY_VALID) {
+            NotAuthenticated::NoValidKey
+        } else if !osnma.flags.contains(OsnmaFlags::TIME_SYNC) {
+            NotAuthenticated::NoTimeSync
+        } else if osnma.num_auth_svs < self.min_auth_svs {
+            NotAuthenticated::TooFewSatellites(osnma.num_auth_svs)
+        } else if !self
+            .time_authenticated
+            .unwrap_or(osnma.flags.contains(OsnmaFlags::TIME_AUTHENTICATED))
+        {
+            NotAuthenticated::TimeNotAuthenticated
+        } else {
+            return AuthStatus::Authenticated;
+        };
+        AuthStatus::NotAuthenticated(reason)
+    }
+
+    /// Whether the current fix is authenticated
+    pub fn is_authenticated(&self) -> bool {
+        self.status() == AuthStatus::Authenticated
+    }
+}
+
+enum Report {
+    Osnma(Osnma),
+    PvtTime(bool),
+}
+
+fn auth_report(packet: &UbxPacket<'_>) -> Option<Report> {
+    match_packet!(packet, {} proto31 {
+        PacketRef::SecOsnma(osnma) => Some(Report::Osnma(Osnma {
+            flags: osnma.flags(),
+            nma_status: osnma.nma_status(),
+            num_auth_svs: osnma.num_auth_svs(),
+        })),
+        PacketRef::NavPvt(pvt) => Some(Report::PvtTime(pvt.flags3().auth_time())),
+    } proto33 {
+        PacketRef::SecOsnma(osnma) => Some(Report::Osnma(Osnma {
+            flags: osnma.flags(),
+            nma_status: osnma.nma_status(),
+            num_auth_svs: osnma.num_auth_svs(),
+        })),
+        PacketRef::NavPvt(pvt) => Some(Report::PvtTime(pvt.flags3().auth_time())),
+    } _ => None)
+}
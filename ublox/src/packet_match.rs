@@ -0,0 +1,100 @@
+//! Matching of a [UbxPacket](crate::UbxPacket) against the `PacketRef` of each enabled
+//! protocol, shared by the modules reading a few packet types whatever the protocol.
+
+/// Match a `&UbxPacket` with the same arms for every enabled protocol, `PacketRef` naming the
+/// `PacketRef` enum of the protocol of the packet. The arms of packets known to some protocols
+/// only are given in a block per protocol, after the common arms and before the final one.
+///
+/// ```ignore
+/// match_packet!(packet, {
+///     PacketRef::AckAck(ack) => Some(ack.class()),
+/// } proto27 {
+///     PacketRef::CfgValGetResponse(response) => Some(0x06),
+/// } _ => None)
+/// ```
+macro_rules! match_packet {
+    (
+        $packet:expr, { $($arms:tt)* }
+        $(proto14 { $($arms14:tt)* })?
+        $(proto23 { $($arms23:tt)* })?
+        $(proto27 { $($arms27:tt)* })?
+        $(proto31 { $($arms31:tt)* })?
+        $(proto33 { $($arms33:tt)* })?
+        _ => $default:expr $(,)?
+    ) => {
+        match $packet {
+            #[cfg(feature = "ubx_proto14")]
+            $crate::UbxPacket::Proto14(packet) => {
+                #[allow(unused_imports, reason = "Not all arms name a packet type")]
+                use $crate::proto14::PacketRef;
+                match packet {
+                    $($arms)*
+                    $($($arms14)*)?
+                    _ => $default,
+                }
+            },
+            #[cfg(feature = "ubx_proto23")]
+            $crate::UbxPacket::Proto23(packet) => {
+                #[allow(unused_imports, reason = "Not all arms name a packet type")]
+                use $crate::proto23::PacketRef;
+                match packet {
+                    $($arms)*
+                    $($($arms23)*)?
+                    _ => $default,
+                }
+            },
+            #[cfg(feature = "ubx_proto27")]
+            $crate::UbxPacket::Proto27(packet) => {
+                #[allow(unused_imports, reason = "Not all arms name a packet type")]
+                use $crate::proto27::PacketRef;
+                match packet {
+                    $($arms)*
+                    $($($arms27)*)?
+                    _ => $default,
+                }
+            },
+            #[cfg(feature = "ubx_proto31")]
+            $crate::UbxPacket::Proto31(packet) => {
+                #[allow(unused_imports, reason = "Not all arms name a packet type")]
+                use $crate::proto31::PacketRef;
+                match packet {
+                    $($arms)*
+                    $($($arms31)*)?
+                    _ => $default,
+                }
+            },
+            #[cfg(feature = "ubx_proto33")]
+            $crate::UbxPacket::Proto33(packet) => {
+                #[allow(unused_imports, reason = "Not all arms name a packet type")]
+                use $crate::proto33::PacketRef;
+                match packet {
+                    $($arms)*
+                    $($($arms33)*)?
+                    _ => $default,
+                }
+            },
+        }
+    };
+}
+
+/// Invoke `$macro!(protoNN)` for each enabled protocol, e.g. to implement a trait for the
+/// `PacketRef` and `PacketOwned` enums of every protocol
+#[cfg(feature = "postcard")]
+macro_rules! for_each_protocol {
+    ($macro:ident) => {
+        #[cfg(feature = "ubx_proto14")]
+        $macro!(proto14);
+        #[cfg(feature = "ubx_proto23")]
+        $macro!(proto23);
+        #[cfg(feature = "ubx_proto27")]
+        $macro!(proto27);
+        #[cfg(feature = "ubx_proto31")]
+        $macro!(proto31);
+        #[cfg(feature = "ubx_proto33")]
+        $macro!(proto33);
+    };
+}
+
+#[cfg(feature = "postcard")]
+pub(crate) use for_each_protocol;
+pub(crate) use match_packet;
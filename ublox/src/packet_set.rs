@@ -0,0 +1,109 @@
+/// Defines a `PacketRef`-style enum of a chosen set of packet types, with its own
+/// `match_packet`, for applications needing a few messages only
+///
+/// The protocols' `PacketRef` enums hold every packet the crate knows, and their
+/// `match_packet` pulls the validation code of all of them into the binary. A packet set
+/// matches only the types listed, each one being a [UbxPacketRecv](crate::UbxPacketRecv),
+/// including proprietary messages implementing it outside of this crate. Other frames are
+/// returned as `Unknown` without decoding their payload.
+///
+/// The frames are best read with [UbxParserIter::next_frame](crate::UbxParserIter::next_frame),
+/// which does not decode them with the parser's protocol:
+///
+/// ```
+/// # #[cfg(all(feature = "ubx_proto23", any(feature = "std", feature = "alloc")))] {
+/// use ublox::{ack::AckAck, define_ubx_packet_set, nav_pvt::proto23::NavPvt, ParserBuilder};
+///
+/// define_ubx_packet_set! {
+///     /// The packets the application handles
+///     #[derive(Debug)]
+///     pub enum AppPacket<'a> {
+///         NavPvt(NavPvt),
+///         AckAck(AckAck),
+///     }
+/// }
+///
+/// let mut parser = ParserBuilder::new().with_fixed_buffer::<1024>();
+/// let mut it = parser.consume_ubx(&[0xb5, 0x62, 0x05, 0x01, 0x02, 0x00, 0x06, 0x00, 0x0e, 0x37]);
+/// let frame = it.next_frame().unwrap().unwrap().packet;
+/// let AppPacket::AckAck(ack) = AppPacket::from_frame(frame).unwrap() else {
+///     panic!("not an ACK");
+/// };
+/// assert_eq!((ack.class(), ack.msg_id()), (0x06, 0x00));
+///
+/// assert!(matches!(AppPacket::match_packet(0x0a, 0x04, &[]), Ok(AppPacket::Unknown(_))));
+/// assert_eq!(AppPacket::MESSAGES[0].name, "NavPvt");
+/// # }
+/// ```
+#[macro_export]
+macro_rules! define_ubx_packet_set {
+    (
+        $(#[$attr:meta])*
+        $vis:vis enum $name:ident<$lt:lifetime> {
+            $($variant:ident($packet:ty)),* $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        $vis enum $name<$lt> {
+            $($variant(<$packet as $crate::UbxPacketRecv>::Ref<$lt>),)*
+            /// A packet outside of the set, with its payload undecoded
+            Unknown($crate::UbxUnknownPacketRef<$lt>),
+        }
+
+        impl<$lt> $name<$lt> {
+            /// The packet types of the set, in the order they are matched
+            pub const MESSAGES: &'static [$crate::PacketMeta] = &[$(
+                $crate::PacketMeta {
+                    class: <$packet as $crate::UbxPacketMeta>::CLASS,
+                    msg_id: <$packet as $crate::UbxPacketMeta>::ID,
+                    name: <$packet as $crate::UbxPacketMeta>::NAME,
+                    fixed_payload_len: <$packet as $crate::UbxPacketMeta>::FIXED_PAYLOAD_LEN,
+                    max_payload_len: <$packet as $crate::UbxPacketMeta>::MAX_PAYLOAD_LEN,
+                },
+            )*];
+
+            /// Validate `payload` as the packet of the set with `class` and `msg_id`, or wrap
+            /// it as `Unknown` if there is none
+            pub fn match_packet(
+                class: u8,
+                msg_id: u8,
+                payload: &$lt [u8],
+            ) -> ::core::result::Result<Self, $crate::ParserError> {
+                $(
+                    if (class, msg_id)
+                        == (
+                            <$packet as $crate::UbxPacketMeta>::CLASS,
+                            <$packet as $crate::UbxPacketMeta>::ID,
+                        )
+                    {
+                        return <$packet as $crate::UbxPacketRecv>::parse_payload(payload)
+                            .map(Self::$variant);
+                    }
+                )*
+                Ok(Self::Unknown($crate::UbxUnknownPacketRef {
+                    payload,
+                    class,
+                    msg_id,
+                }))
+            }
+
+            /// [match_packet](Self::match_packet) for a frame returned by a parser
+            pub fn from_frame(
+                frame: $crate::UbxUnknownPacketRef<$lt>,
+            ) -> ::core::result::Result<Self, $crate::ParserError> {
+                Self::match_packet(frame.class, frame.msg_id, frame.payload)
+            }
+
+            /// Class and message id of the packet
+            pub fn class_and_msg_id(&self) -> (u8, u8) {
+                match self {
+                    $(Self::$variant(_) => (
+                        <$packet as $crate::UbxPacketMeta>::CLASS,
+                        <$packet as $crate::UbxPacketMeta>::ID,
+                    ),)*
+                    Self::Unknown(frame) => (frame.class, frame.msg_id),
+                }
+            }
+        }
+    };
+}
@@ -4,12 +4,12 @@ use alloc::vec::Vec;
 use crate::{
     constants::{
         NMEA_END_CHARS_LEN, NMEA_END_CHAR_1, NMEA_END_CHAR_2, NMEA_MAX_SENTENCE_LENGTH,
-        NMEA_MIN_BUFFER_SIZE, NMEA_SYNC_CHAR, RTCM_HEADER_SIZE, RTCM_LENGTH_MASK, RTCM_SYNC_CHAR,
-        UBX_CHECKSUM_LEN, UBX_CLASS_OFFSET, UBX_HEADER_LEN, UBX_LENGTH_OFFSET, UBX_MSG_ID_OFFSET,
-        UBX_SYNC_CHAR_1, UBX_SYNC_CHAR_2, UBX_SYNC_SIZE,
+        NMEA_MIN_BUFFER_SIZE, NMEA_SYNC_CHAR, RTCM_CRC_LEN, RTCM_HEADER_SIZE, RTCM_LENGTH_MASK,
+        RTCM_SYNC_CHAR, SPARTN_SYNC_CHAR, UBX_CHECKSUM_LEN, UBX_CLASS_OFFSET, UBX_HEADER_LEN,
+        UBX_LENGTH_OFFSET, UBX_MSG_ID_OFFSET, UBX_SYNC_CHAR_1, UBX_SYNC_CHAR_2, UBX_SYNC_SIZE,
     },
     error::ParserError,
-    UbxPacket, UbxProtocol,
+    UbxPacket, UbxProtocol, UbxUnknownPacketRef,
 };
 
 use core::marker::PhantomData;
@@ -52,14 +52,30 @@ pub type DefaultProtocol = crate::proto33::Proto33;
 
 mod buffer;
 use buffer::DualBuffer;
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use buffer::GrowableBuffer;
 pub use buffer::{FixedBuffer, FixedLinearBuffer, UnderlyingBuffer};
 
 mod checksum;
+pub use ublox_core::{verify_frame, FrameParts};
+mod clock;
+pub use clock::{ClockedParser, ClockedUbxParserIter, TimestampedPacket};
+mod frame_decoder;
+pub use frame_decoder::{FrameDecoder, FrameEvent, FrameHeader};
+#[cfg(kani)]
+mod proofs;
+mod spartn;
+mod stats;
+pub use spartn::{SpartnCrcType, SpartnFrameRef, SpartnTimeTag};
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use stats::DiscardCallback;
+use stats::StreamMonitor;
+pub use stats::{DiscardReason, DiscardedBytes, ParserStats};
 
 /// A compile-time builder for constructing UBX protocol parsers with different buffer types and protocols.
 ///
-/// Unlike typical builders, `ParserBuilder` performs all configuration at compile time through
-/// the type system rather than storing configuration in fields.
+/// The buffer type and protocol are configured at compile time through the type system, only
+/// parsing options such as [ParserBuilder::accept_invalid_checksums] are stored in fields.
 ///
 /// # Examples
 ///
@@ -96,6 +112,15 @@ mod checksum;
 /// # }
 /// ```
 ///
+/// ## Parser over a caller-supplied slice
+///
+/// ```rust
+/// # use ublox::ParserBuilder;
+///
+/// let mut scratch = [0u8; 512];
+/// let mut parser = ParserBuilder::new().with_slice_buffer(&mut scratch);
+/// ```
+///
 /// ## Parser with custom buffer implementation
 ///
 /// ```rust
@@ -108,6 +133,7 @@ mod checksum;
 ///     .with_buffer(custom_buffer);
 /// ```
 pub struct ParserBuilder<P: UbxProtocol = DefaultProtocol> {
+    options: ParserOptions,
     _phantom: PhantomData<P>,
 }
 
@@ -120,6 +146,7 @@ impl Default for ParserBuilder<DefaultProtocol> {
 impl ParserBuilder<DefaultProtocol> {
     pub const fn new() -> Self {
         Self {
+            options: ParserOptions::new(),
             _phantom: PhantomData,
         }
     }
@@ -129,24 +156,286 @@ impl<P: UbxProtocol> ParserBuilder<P> {
     /// Specify a protocol version
     pub const fn with_protocol<NewP: UbxProtocol>(self) -> ParserBuilder<NewP> {
         ParserBuilder {
+            options: self.options,
             _phantom: PhantomData,
         }
     }
 
+    /// Yield UBX packets whose checksum does not match instead of dropping them, e.g. to
+    /// recover epochs with single bit errors from marginal RF captures. Such packets are
+    /// only yielded by the iterators' `next_checked()`, flagged by
+    /// [CheckedPacket::checksum_valid], while `next()` still reports them as
+    /// [ParserError::InvalidChecksum]. Packets filtered out by [ParserBuilder::with_filter]
+    /// are only reported as [ParserError::InvalidChecksum] by all of them.
+    ///
+    /// As corrupted length fields are not detected in this mode, a damaged frame may swallow
+    /// the frames following it, so this is meant for post-processing rather than live use.
+    pub const fn accept_invalid_checksums(mut self, accept: bool) -> Self {
+        self.options.accept_invalid_checksums = accept;
+        self
+    }
+
+    /// Only yield UBX packets whose `(class, msg_id)` is in `filter`. Other frames are still
+    /// checksum verified and counted in [ParserStats], but dropped without decoding the
+    /// payload, which saves CPU time on small targets receiving many message types.
+    ///
+    /// ```
+    /// use ublox::ParserBuilder;
+    ///
+    /// let mut parser = ParserBuilder::new()
+    ///     .with_filter(&[(0x01, 0x07)])
+    ///     .with_fixed_buffer::<1024>();
+    /// let mut it = parser.consume_ubx(&[0xb5, 0x62, 0x05, 0x01, 0x02, 0x00, 0x06, 0x01, 0x0f, 0x38]);
+    /// assert!(it.next().is_none());
+    /// drop(it);
+    /// assert_eq!(parser.stats().ubx_frames(0x05), 1);
+    /// ```
+    pub const fn with_filter(mut self, filter: &'static [(u8, u8)]) -> Self {
+        self.options.filter = Some(filter);
+        self
+    }
+
+    /// Drop UBX frames announcing a payload longer than `len` as soon as their header is
+    /// received, reported as [DiscardReason::InvalidLength]. Without this, a corrupted or
+    /// hostile length field up to the protocol's largest message makes a heap buffer grow to
+    /// hold it, or a fixed buffer wait for bytes that never come.
+    ///
+    /// ```
+    /// use ublox::ParserBuilder;
+    ///
+    /// // UBX-NAV-TIMEGPS, 16 byte payload
+    /// let mut frame = vec![0xb5, 0x62, 0x01, 0x20, 16, 0];
+    /// frame.extend_from_slice(&[0; 16]);
+    /// let (ck_a, ck_b) = ublox::ubx_checksum(&frame[2..]);
+    /// frame.extend_from_slice(&[ck_a, ck_b]);
+    ///
+    /// let mut parser = ParserBuilder::new()
+    ///     .with_max_payload_len(8)
+    ///     .with_vec_buffer();
+    /// assert!(parser.consume_ubx(&frame).next().is_none());
+    /// assert_eq!(parser.stats().bytes_discarded, frame.len() as u64);
+    /// ```
+    pub const fn with_max_payload_len(mut self, len: u16) -> Self {
+        self.options.max_payload_len = len;
+        self
+    }
+
+    /// Override [ParserBuilder::with_max_payload_len] for the classes in `limits`, given as
+    /// `(class, max_payload_len)`, e.g. to accept large UBX-RXM raw data while keeping the
+    /// other classes tightly capped. Lengths beyond the protocol's largest message are dropped
+    /// regardless.
+    ///
+    /// ```
+    /// use ublox::ParserBuilder;
+    ///
+    /// let parser = ParserBuilder::new()
+    ///     .with_max_payload_len(100)
+    ///     .with_class_payload_limits(&[(0x02, 1024)])
+    ///     .with_fixed_buffer::<1100>();
+    /// # drop(parser);
+    /// ```
+    pub const fn with_class_payload_limits(mut self, limits: &'static [(u8, u16)]) -> Self {
+        self.options.class_payload_limits = Some(limits);
+        self
+    }
+
+    /// Choose what happens to frames too large for the parser's buffer, see [OverflowPolicy]
+    ///
+    /// ```
+    /// use ublox::{OverflowPolicy, ParserBuilder};
+    ///
+    /// let mut parser = ParserBuilder::new()
+    ///     .with_overflow_policy(OverflowPolicy::Grow)
+    ///     .with_growable_buffer(16);
+    /// // UBX-NAV-TIMEGPS, 24 bytes in total
+    /// let mut frame = vec![0xb5, 0x62, 0x01, 0x20, 16, 0];
+    /// frame.extend_from_slice(&[0; 16]);
+    /// let (ck_a, ck_b) = ublox::ubx_checksum(&frame[2..]);
+    /// frame.extend_from_slice(&[ck_a, ck_b]);
+    ///
+    /// // The buffer grows to keep the start of the frame until the rest arrives
+    /// let mut packets = 0;
+    /// for chunk in frame.chunks(20) {
+    ///     let mut it = parser.consume_ubx(chunk);
+    ///     while let Some(packet) = it.next() {
+    ///         assert!(packet.is_ok());
+    ///         packets += 1;
+    ///     }
+    /// }
+    /// assert_eq!(packets, 1);
+    /// assert_eq!(parser.buffer_capacity(), frame.len());
+    /// ```
+    pub const fn with_overflow_policy(mut self, policy: OverflowPolicy) -> Self {
+        self.options.overflow_policy = policy;
+        self
+    }
+
+    /// Abandon UBX frames that do not complete within `timeout`, returning
+    /// [ParserError::PartialFrameTimeout] and searching for the next frame from the byte after
+    /// their sync chars. On lossy links, e.g. radio modems, a frame whose end was lost otherwise
+    /// holds back the frames following it until its announced length is reached, or forever if
+    /// the stream stops.
+    ///
+    /// ```
+    /// use ublox::{FrameTimeout, ParserBuilder, ParserError};
+    ///
+    /// let mut parser = ParserBuilder::new()
+    ///     .with_partial_frame_timeout(FrameTimeout::Reads(1))
+    ///     .with_fixed_buffer::<1024>();
+    /// // The start of a UBX-NAV-TIMEGPS frame, the rest of it is lost
+    /// assert!(parser.consume_ubx(&[0xb5, 0x62, 0x01, 0x20, 16, 0, 0, 0]).next().is_none());
+    ///
+    /// // Nothing arrives until the next read times out
+    /// let mut it = parser.consume_ubx(&[]);
+    /// assert!(matches!(
+    ///     it.next(),
+    ///     Some(Err(ParserError::PartialFrameTimeout { received: 8 }))
+    /// ));
+    /// assert!(it.next().is_none());
+    /// ```
+    pub const fn with_partial_frame_timeout(mut self, timeout: FrameTimeout) -> Self {
+        self.options.partial_frame_timeout = Some(timeout);
+        self
+    }
+
+    /// Choose where the search for the next UBX frame resumes after one failed its checksum,
+    /// see [ResyncPolicy]
+    pub const fn with_resync_policy(mut self, policy: ResyncPolicy) -> Self {
+        self.options.resync_policy = policy;
+        self
+    }
+
     /// Build a parser with a `Vec<u8>` buffer
     #[cfg(any(feature = "std", feature = "alloc"))]
     pub fn with_vec_buffer(self) -> Parser<Vec<u8>, P> {
-        Parser::new(Vec::new())
+        Parser::with_options(Vec::new(), self.options)
+    }
+
+    /// Build a parser with a heap allocated buffer of `capacity` bytes, which grows for larger
+    /// frames with [OverflowPolicy::Grow]
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn with_growable_buffer(self, capacity: usize) -> Parser<GrowableBuffer, P> {
+        Parser::with_options(GrowableBuffer::with_capacity(capacity), self.options)
     }
 
     /// Build a parser with a fixed-size buffer (for no_std or when you want bounded memory usage)
     pub const fn with_fixed_buffer<const N: usize>(self) -> Parser<FixedBuffer<N>, P> {
-        Parser::with_fixed_buffer()
+        Parser::with_options(FixedBuffer::new(), self.options)
+    }
+
+    /// Build a parser over an externally owned slice, e.g. one placed in a specific RAM section
+    /// such as CCM or DMA capable memory on embedded targets
+    ///
+    /// ```
+    /// use ublox::ParserBuilder;
+    ///
+    /// let mut scratch = [0u8; 512];
+    /// let mut parser = ParserBuilder::new().with_slice_buffer(&mut scratch);
+    /// assert_eq!(parser.buffer_capacity(), 512);
+    /// ```
+    pub const fn with_slice_buffer(self, buffer: &mut [u8]) -> Parser<FixedLinearBuffer<'_>, P> {
+        Parser::with_options(FixedLinearBuffer::new(buffer), self.options)
     }
 
     /// Build a parser with a custom buffer implementation
     pub const fn with_buffer<T: UnderlyingBuffer>(self, buffer: T) -> Parser<T, P> {
-        Parser::new(buffer)
+        Parser::with_options(buffer, self.options)
+    }
+}
+
+/// What the parser does with a frame that does not fit into its buffer, set with
+/// [ParserBuilder::with_overflow_policy]
+///
+/// A frame overflows once more of it arrived than the buffer can hold, so this is decided
+/// before the frame is complete. The parser resynchronizes after the sync chars of a dropped
+/// frame, which are reported to the discard callback with [DiscardReason::OutOfMemory].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverflowPolicy {
+    /// Drop the frame and return [ParserError::OutOfMemory]
+    #[default]
+    Error,
+    /// Drop the partial frame at the start of the buffer without returning an error, e.g. when
+    /// large frames are expected but not needed
+    DropOldest,
+    /// Grow the buffer to fit the frame, for buffers implementing [UnderlyingBuffer::grow]
+    /// such as `GrowableBuffer`. Buffers of a fixed size cannot grow and reject the frame like
+    /// [OverflowPolicy::Error].
+    Grow,
+}
+
+/// Where the parser resumes searching for UBX frames after one failed its checksum, set with
+/// [ParserBuilder::with_resync_policy]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ResyncPolicy {
+    /// From the byte after the sync chars of the failed frame, so that no frame starting within
+    /// it is lost, e.g. when its length field was corrupted or its sync chars were a false match
+    /// in other data. Sync chars within the payload of a corrupted frame are tried as frames as
+    /// well, which only costs further checksum failures.
+    #[default]
+    AfterSync,
+    /// From the byte after the whole frame, as announced by its length field. Sync chars in the
+    /// payload of a corrupted frame are not tried, but a frame whose length field was corrupted
+    /// makes the parser skip the frames following it.
+    AfterFrame,
+}
+
+/// How long the parser waits for the rest of a UBX frame, set with
+/// [ParserBuilder::with_partial_frame_timeout]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameTimeout {
+    /// Abandon a frame once more than this many bytes, counted from its first sync char, were
+    /// received without completing it
+    Bytes(usize),
+    /// Abandon a frame still incomplete this many `consume_*` calls after the one it was first
+    /// seen in. Empty calls count as well, so calling the parser with no data whenever a read
+    /// times out makes this a time limit of roughly this many read timeouts.
+    Reads(u32),
+}
+
+/// Parsing options set through the [ParserBuilder]
+#[derive(Debug, Clone, Copy)]
+struct ParserOptions {
+    accept_invalid_checksums: bool,
+    filter: Option<&'static [(u8, u8)]>,
+    overflow_policy: OverflowPolicy,
+    max_payload_len: u16,
+    class_payload_limits: Option<&'static [(u8, u16)]>,
+    partial_frame_timeout: Option<FrameTimeout>,
+    resync_policy: ResyncPolicy,
+}
+
+impl ParserOptions {
+    const fn new() -> Self {
+        Self {
+            accept_invalid_checksums: false,
+            filter: None,
+            overflow_policy: OverflowPolicy::Error,
+            max_payload_len: u16::MAX,
+            class_payload_limits: None,
+            partial_frame_timeout: None,
+            resync_policy: ResyncPolicy::AfterSync,
+        }
+    }
+
+    /// Whether a UBX frame of `class` announcing `pack_len` bytes of payload is longer than
+    /// the protocol `P` or the limits set in the [ParserBuilder] allow
+    fn exceeds_payload_limit<P: UbxProtocol>(self, class: u8, pack_len: u16) -> bool {
+        let limit = self
+            .class_payload_limits
+            .and_then(|limits| {
+                limits
+                    .iter()
+                    .find(|&&(limit_class, _)| limit_class == class)
+            })
+            .map_or(self.max_payload_len, |&(_, limit)| limit);
+        pack_len > P::MAX_PAYLOAD_LEN || pack_len > limit
+    }
+
+    /// Whether UBX frames of `class` and `msg_id` are dropped by the
+    /// [ParserBuilder::with_filter] option
+    fn filters_out(self, class: u8, msg_id: u8) -> bool {
+        self.filter
+            .is_some_and(|filter| !filter.contains(&(class, msg_id)))
     }
 }
 
@@ -165,6 +454,8 @@ where
     T: UnderlyingBuffer,
 {
     buf: T,
+    monitor: StreamMonitor,
+    options: ParserOptions,
     _phantom: PhantomData<P>,
 }
 
@@ -190,6 +481,8 @@ impl Parser<Vec<u8>, DefaultProtocol> {
     pub fn default_proto() -> Self {
         Self {
             buf: Vec::new(),
+            monitor: StreamMonitor::new(),
+            options: ParserOptions::new(),
             _phantom: PhantomData,
         }
     }
@@ -197,8 +490,14 @@ impl Parser<Vec<u8>, DefaultProtocol> {
 
 impl<T: UnderlyingBuffer, P: UbxProtocol> Parser<T, P> {
     pub const fn new(underlying: T) -> Self {
+        Self::with_options(underlying, ParserOptions::new())
+    }
+
+    const fn with_options(underlying: T, options: ParserOptions) -> Self {
         Self {
             buf: underlying,
+            monitor: StreamMonitor::new(),
+            options,
             _phantom: PhantomData,
         }
     }
@@ -217,38 +516,175 @@ impl<T: UnderlyingBuffer, P: UbxProtocol> Parser<T, P> {
         self.buf.max_capacity()
     }
 
+    /// Returns how many more bytes are needed to complete the frame at the start of the buffer,
+    /// or the length of the smallest UBX frame (8 bytes) if the buffer is empty. This allows
+    /// sizing reads precisely instead of waking up for every byte on a slow UART.
+    ///
+    /// NMEA sentences carry no length, so at least one byte is requested while assembling one.
+    /// Zero is returned if the buffer already holds a complete frame, i.e. the iterator of the
+    /// last `consume_*` call was not exhausted.
+    ///
+    /// ```
+    /// use ublox::ParserBuilder;
+    ///
+    /// let mut parser = ParserBuilder::new().with_fixed_buffer::<1024>();
+    /// assert_eq!(parser.bytes_needed(), 8);
+    ///
+    /// let mut it = parser.consume_ubx(&[0xb5, 0x62, 0x05, 0x01, 0x02, 0x00, 0x06]);
+    /// assert!(it.next().is_none());
+    /// drop(it);
+    /// assert_eq!(parser.bytes_needed(), 3);
+    /// ```
+    pub fn bytes_needed(&self) -> usize {
+        let len = self.buf.len();
+        if len == 0 {
+            return UBX_HEADER_LEN + UBX_CHECKSUM_LEN;
+        }
+        let frame_len = match self.buf[0] {
+            UBX_SYNC_CHAR_1 if len < UBX_HEADER_LEN => UBX_HEADER_LEN + UBX_CHECKSUM_LEN,
+            UBX_SYNC_CHAR_1 => {
+                let pack_len = u16::from_le_bytes([
+                    self.buf[UBX_LENGTH_OFFSET],
+                    self.buf[UBX_LENGTH_OFFSET + 1],
+                ]);
+                UBX_HEADER_LEN + usize::from(pack_len) + UBX_CHECKSUM_LEN
+            },
+            RTCM_SYNC_CHAR if len < RTCM_HEADER_SIZE => RTCM_HEADER_SIZE + RTCM_CRC_LEN,
+            RTCM_SYNC_CHAR => {
+                let pack_len = u16::from_be_bytes([self.buf[1], self.buf[2]]) & RTCM_LENGTH_MASK;
+                RTCM_HEADER_SIZE + usize::from(pack_len) + RTCM_CRC_LEN
+            },
+            SPARTN_SYNC_CHAR => {
+                let header = &self.buf[0..core::cmp::min(len, spartn::SPARTN_MAX_HEADER_SIZE)];
+                match spartn::frame_len(header) {
+                    spartn::SpartnFrameLen::Complete(frame_len) => frame_len,
+                    spartn::SpartnFrameLen::Incomplete
+                    | spartn::SpartnFrameLen::Invalid
+                    | spartn::SpartnFrameLen::InvalidFrameCrc { .. } => {
+                        return 1;
+                    },
+                }
+            },
+            NMEA_SYNC_CHAR => {
+                return NMEA_MIN_BUFFER_SIZE.saturating_sub(len).max(1);
+            },
+            _ => return 1,
+        };
+        frame_len.saturating_sub(len)
+    }
+
+    /// Returns the counters accumulated over all data consumed so far
+    pub fn stats(&self) -> &ParserStats {
+        &self.monitor.stats
+    }
+
+    /// Resets all counters returned by [Parser::stats] to zero
+    pub fn reset_stats(&mut self) {
+        self.monitor.stats = ParserStats::new();
+    }
+
+    /// Sets a callback invoked with the bytes dropped while searching for the next frame, e.g.
+    /// to log corrupted data. Frames that fail validation are reported along with the reason
+    /// before the parser resynchronizes. `None` removes the callback.
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn set_discard_callback(&mut self, callback: Option<DiscardCallback>) {
+        self.monitor.on_discard = callback;
+    }
+
     /// Appends `new_data` to the internal buffer and returns and iterator over the buffer
     /// that will yield [UbxPackets](UbxPacket) on demand.
     pub fn consume_ubx<'a>(&'a mut self, new_data: &'a [u8]) -> UbxParserIter<'a, T, P> {
         let mut buf = DualBuffer::new(&mut self.buf, new_data);
+        let monitor = &mut self.monitor;
+        monitor.feed(new_data.len());
 
         for i in 0..buf.len() {
             if buf[i] == UBX_SYNC_CHAR_1 {
-                buf.drain(i);
+                monitor.discard(&mut buf, i, DiscardReason::Garbage);
                 break;
             }
         }
 
         UbxParserIter {
             buf,
+            monitor,
+            options: self.options,
             _phantom: PhantomData,
         }
     }
 
+    /// Parses data arriving in several segments, e.g. the two halves of a ring buffer or
+    /// scatter-gather DMA descriptors, passing each [UbxPacket] to `f`. Only frames spanning
+    /// segments are copied into the internal buffer.
+    ///
+    /// ```
+    /// use std::io::IoSlice;
+    /// use ublox::ParserBuilder;
+    ///
+    /// let mut parser = ParserBuilder::new().with_fixed_buffer::<1024>();
+    /// let ack_ack = [0xb5, 0x62, 0x05, 0x01, 0x02, 0x00, 0x06, 0x01, 0x0f, 0x38];
+    /// let slices = [IoSlice::new(&ack_ack[..4]), IoSlice::new(&ack_ack[4..])];
+    ///
+    /// let mut count = 0;
+    /// parser.consume_ubx_segments(slices.iter().map(|slice| &slice[..]), |packet| {
+    ///     assert!(packet.is_ok());
+    ///     count += 1;
+    /// });
+    /// assert_eq!(count, 1);
+    /// ```
+    pub fn consume_ubx_segments<'s, F>(
+        &mut self,
+        segments: impl IntoIterator<Item = &'s [u8]>,
+        mut f: F,
+    ) where
+        F: FnMut(Result<UbxPacket<'_>, ParserError>),
+    {
+        for segment in segments {
+            let mut it = self.consume_ubx(segment);
+            while let Some(packet) = it.next() {
+                f(packet);
+            }
+        }
+    }
+
+    /// Like [Parser::consume_ubx_segments], for data held in a [bytes::Buf]. All of `data` is
+    /// consumed.
+    #[cfg(feature = "bytes")]
+    pub fn consume_ubx_buf<B, F>(&mut self, mut data: B, mut f: F)
+    where
+        B: bytes::Buf,
+        F: FnMut(Result<UbxPacket<'_>, ParserError>),
+    {
+        while data.has_remaining() {
+            let chunk = data.chunk();
+            let len = chunk.len();
+            let mut it = self.consume_ubx(chunk);
+            while let Some(packet) = it.next() {
+                f(packet);
+            }
+            drop(it);
+            data.advance(len);
+        }
+    }
+
     /// Appends `new_data` to the internal buffer and returns and iterator over the buffer
     /// that will yield [UbxPackets or RtcmPackets](AnyPacketRef) on demand.
     pub fn consume_ubx_rtcm<'a>(&'a mut self, new_data: &'a [u8]) -> UbxRtcmParserIter<'a, T, P> {
         let mut buf = DualBuffer::new(&mut self.buf, new_data);
+        let monitor = &mut self.monitor;
+        monitor.feed(new_data.len());
 
         for i in 0..buf.len() {
             if buf[i] == UBX_SYNC_CHAR_1 || buf[i] == RTCM_SYNC_CHAR {
-                buf.drain(i);
+                monitor.discard(&mut buf, i, DiscardReason::Garbage);
                 break;
             }
         }
 
         UbxRtcmParserIter {
             buf,
+            monitor,
+            options: self.options,
             _phantom: PhantomData,
         }
     }
@@ -260,16 +696,52 @@ impl<T: UnderlyingBuffer, P: UbxProtocol> Parser<T, P> {
         new_data: &'a [u8],
     ) -> UbxRtcmNmeaParserIter<'a, T, P> {
         let mut buf = DualBuffer::new(&mut self.buf, new_data);
+        let monitor = &mut self.monitor;
+        monitor.feed(new_data.len());
 
         for i in 0..buf.len() {
             if buf[i] == UBX_SYNC_CHAR_1 || buf[i] == RTCM_SYNC_CHAR || buf[i] == NMEA_SYNC_CHAR {
-                buf.drain(i);
+                monitor.discard(&mut buf, i, DiscardReason::Garbage);
+                break;
+            }
+        }
+
+        UbxRtcmNmeaParserIter {
+            buf,
+            monitor,
+            options: self.options,
+            spartn: false,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Appends `new_data` to the internal buffer and returns and iterator over the buffer
+    /// that will yield [UbxPackets, RtcmPackets, NmeaPackets, or SpartnFrames](AnyPacketRef) on
+    /// demand, for receivers that get SPARTN corrections (e.g. PointPerfect) on the same port.
+    pub fn consume_ubx_rtcm_nmea_spartn<'a>(
+        &'a mut self,
+        new_data: &'a [u8],
+    ) -> UbxRtcmNmeaParserIter<'a, T, P> {
+        let mut buf = DualBuffer::new(&mut self.buf, new_data);
+        let monitor = &mut self.monitor;
+        monitor.feed(new_data.len());
+
+        for i in 0..buf.len() {
+            if buf[i] == UBX_SYNC_CHAR_1
+                || buf[i] == RTCM_SYNC_CHAR
+                || buf[i] == NMEA_SYNC_CHAR
+                || buf[i] == SPARTN_SYNC_CHAR
+            {
+                monitor.discard(&mut buf, i, DiscardReason::Garbage);
                 break;
             }
         }
 
         UbxRtcmNmeaParserIter {
             buf,
+            monitor,
+            options: self.options,
+            spartn: true,
             _phantom: PhantomData,
         }
     }
@@ -280,43 +752,235 @@ enum NextSync {
     Ubx(usize),
     Rtcm(usize),
     Nmea(usize),
+    Spartn(usize),
     None,
 }
 
+/// A frame extracted from a byte stream carrying interleaved UBX, RTCM3 and NMEA data.
+///
+/// Yielded by the iterators returned from [Parser::consume_ubx_rtcm] and
+/// [Parser::consume_ubx_rtcm_nmea], which is useful for receivers (such as the F9P) that emit
+/// all three protocols on the same port. SPARTN frames are only yielded by
+/// [Parser::consume_ubx_rtcm_nmea_spartn].
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum AnyPacketRef<'a> {
+    /// A UBX packet, decoded for the parser's protocol version
     Ubx(UbxPacket<'a>),
+    /// A complete RTCM3 frame
     Rtcm(RtcmPacketRef<'a>),
+    /// A complete NMEA sentence
     Nmea(NmeaPacketRef<'a>),
+    /// A complete SPARTN frame
+    Spartn(SpartnFrameRef<'a>),
+}
+
+/// A packet along with whether its checksum matched, returned by the iterators'
+/// `next_checked()`
+///
+/// The checksum can only mismatch for UBX packets from parsers built with
+/// [ParserBuilder::accept_invalid_checksums].
+#[derive(Debug)]
+pub struct CheckedPacket<T> {
+    pub packet: T,
+    pub checksum_valid: bool,
+    /// Received and calculated checksum of a packet whose checksum did not match
+    mismatch: Option<(u16, u16)>,
+}
+
+impl<T> CheckedPacket<T> {
+    pub(crate) fn verified(packet: T) -> Self {
+        Self {
+            packet,
+            checksum_valid: true,
+            mismatch: None,
+        }
+    }
+
+    /// The packet if its checksum matched, [ParserError::InvalidChecksum] otherwise, as
+    /// returned by the iterators' `next()`
+    pub fn into_verified(self) -> Result<T, ParserError> {
+        self.verify()?;
+        Ok(self.packet)
+    }
+
+    fn verify(&self) -> Result<(), ParserError> {
+        match self.mismatch {
+            None => Ok(()),
+            Some((expect, got)) => Err(ParserError::InvalidChecksum { expect, got }),
+        }
+    }
+
+    pub(crate) fn map<U>(self, f: impl FnOnce(T) -> U) -> CheckedPacket<U> {
+        CheckedPacket {
+            packet: f(self.packet),
+            checksum_valid: self.checksum_valid,
+            mismatch: self.mismatch,
+        }
+    }
+}
+
+impl CheckedPacket<UbxPacket<'_>> {
+    /// The frame rebuilt from the packet like [UbxPacket::to_frame_vec], verified against the
+    /// received one. The length always matches, as the payload is the one announced by the
+    /// received header, so this fails with [ParserError::InvalidChecksum] exactly if the
+    /// received checksum did not match the rebuilt one.
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn to_frame_vec(&self) -> Result<Vec<u8>, ParserError> {
+        self.verify()?;
+        Ok(self.packet.to_frame_vec())
+    }
+}
+
+impl CheckedPacket<UbxUnknownPacketRef<'_>> {
+    /// The frame rebuilt like [UbxUnknownPacketRef::to_frame_bytes], verified against the
+    /// received one like the `to_frame_vec()` of a `CheckedPacket<UbxPacket>`
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn to_frame_vec(&self) -> Result<Vec<u8>, ParserError> {
+        self.verify()?;
+        Ok(self.packet.to_frame_bytes())
+    }
 }
 
 /// Iterator over data stored in `Parser` buffer
 pub struct UbxParserIter<'a, T: UnderlyingBuffer, P: UbxProtocol = DefaultProtocol> {
     buf: DualBuffer<'a, T>,
+    monitor: &'a mut StreamMonitor,
+    options: ParserOptions,
     _phantom: PhantomData<P>,
 }
 
+/// Drop the UBX frame at the start of `buf` if it is complete, valid and filtered out by the
+/// [ParserBuilder::with_filter] option, returns whether the frame was dropped
+fn skip_filtered_ubx<T: UnderlyingBuffer>(
+    buf: &mut DualBuffer<'_, T>,
+    monitor: &mut StreamMonitor,
+    options: ParserOptions,
+    pack_len: u16,
+) -> bool {
+    let class_id = buf[UBX_CLASS_OFFSET];
+    let frame_len = UBX_HEADER_LEN + usize::from(pack_len) + UBX_CHECKSUM_LEN;
+    if !options.filters_out(class_id, buf[UBX_MSG_ID_OFFSET]) || buf.len() < frame_len {
+        return false;
+    }
+    // Frames with a bad checksum are left to `extract_frame_ubx` to be reported
+    if checksum::UbxChecksumCalc::validate_buffer(buf, pack_len).is_err() {
+        return false;
+    }
+    monitor.stats.count_ubx_frame(class_id);
+    buf.drain(frame_len);
+    true
+}
+
+/// Apply the [OverflowPolicy] to the frame at the start of `buf` if the buffer cannot hold it,
+/// returns whether the frame was dropped. `sync_len` bytes are dropped to resynchronize, frames
+/// that are kept are left to extraction, which reports [ParserError::OutOfMemory].
+fn skip_overflowing_frame<T: UnderlyingBuffer>(
+    buf: &mut DualBuffer<'_, T>,
+    monitor: &mut StreamMonitor,
+    options: ParserOptions,
+    drain: usize,
+    take: usize,
+    sync_len: usize,
+) -> bool {
+    if buf.can_drain_and_take(drain, take) || buf.potential_lost_bytes() == 0 {
+        return false;
+    }
+    match options.overflow_policy {
+        OverflowPolicy::Error => false,
+        OverflowPolicy::DropOldest => {
+            monitor.discard(buf, sync_len, DiscardReason::OutOfMemory);
+            true
+        },
+        OverflowPolicy::Grow => {
+            buf.grow(drain + take);
+            false
+        },
+    }
+}
+
 fn extract_packet_ubx<'b, T: UnderlyingBuffer, P: UbxProtocol>(
     buf: &'b mut DualBuffer<'_, T>,
+    monitor: &mut StreamMonitor,
+    options: ParserOptions,
+    pack_len: u16,
+) -> Option<Result<CheckedPacket<UbxPacket<'b>>, ParserError>> {
+    let result = extract_frame_ubx(buf, monitor, options, pack_len)?;
+    Some(result.and_then(decode_frame_ubx::<P>))
+}
+
+/// Decode a frame returned by [extract_frame_ubx] with the packet definitions of `P`
+pub(crate) fn decode_frame_ubx<P: UbxProtocol>(
+    frame: CheckedPacket<UbxUnknownPacketRef<'_>>,
+) -> Result<CheckedPacket<UbxPacket<'_>>, ParserError> {
+    let packet = P::match_packet(
+        frame.packet.class,
+        frame.packet.msg_id,
+        frame.packet.payload,
+    )?;
+    Ok(frame.map(|_| packet.into()))
+}
+
+/// Drop the incomplete UBX frame at the start of `buf` if it waited longer than the
+/// [ParserBuilder::with_partial_frame_timeout] option allows
+fn expire_partial_frame<T: UnderlyingBuffer>(
+    buf: &mut DualBuffer<'_, T>,
+    monitor: &mut StreamMonitor,
+    options: ParserOptions,
+) -> Option<ParserError> {
+    let timeout = options.partial_frame_timeout?;
+    if !monitor.partial_frame_expired(buf.len(), timeout) {
+        return None;
+    }
+    let received = buf.len();
+    monitor.discard(buf, UBX_SYNC_SIZE, DiscardReason::PartialFrameTimeout);
+    Some(ParserError::PartialFrameTimeout { received })
+}
+
+/// Like [extract_packet_ubx], but returns the frame without decoding its payload
+fn extract_frame_ubx<'b, T: UnderlyingBuffer>(
+    buf: &'b mut DualBuffer<'_, T>,
+    monitor: &mut StreamMonitor,
+    options: ParserOptions,
     pack_len: u16,
-) -> Option<Result<UbxPacket<'b>, ParserError>> {
+) -> Option<Result<CheckedPacket<UbxUnknownPacketRef<'b>>, ParserError>> {
     if !buf.can_drain_and_take(UBX_HEADER_LEN, usize::from(pack_len) + UBX_CHECKSUM_LEN) {
         if buf.potential_lost_bytes() > 0 {
             // We ran out of space, drop this packet and move on
-            buf.drain(UBX_SYNC_SIZE);
+            monitor.discard(buf, UBX_SYNC_SIZE, DiscardReason::OutOfMemory);
             return Some(Err(ParserError::OutOfMemory {
                 required_size: usize::from(pack_len) + UBX_CHECKSUM_LEN,
             }));
         }
-        return None;
-    }
-    if let Err(checksum_error) = checksum::UbxChecksumCalc::validate_buffer(buf, pack_len) {
-        buf.drain(UBX_SYNC_SIZE);
-        return Some(Err(checksum_error));
+        return expire_partial_frame(buf, monitor, options).map(Err);
     }
+    let mismatch = match checksum::UbxChecksumCalc::validate_buffer(buf, pack_len) {
+        Ok(()) => None,
+        Err(ParserError::InvalidChecksum { expect, got }) if options.accept_invalid_checksums => {
+            monitor.stats.checksum_failures += 1;
+            // Filtered out frames are only reported, like with a valid checksum they are not
+            // yielded
+            if options.filters_out(buf[UBX_CLASS_OFFSET], buf[UBX_MSG_ID_OFFSET]) {
+                monitor.stats.count_ubx_frame(buf[UBX_CLASS_OFFSET]);
+                buf.drain(UBX_HEADER_LEN + usize::from(pack_len) + UBX_CHECKSUM_LEN);
+                return Some(Err(ParserError::InvalidChecksum { expect, got }));
+            }
+            Some((expect, got))
+        },
+        Err(checksum_error) => {
+            let frame_len = UBX_HEADER_LEN + usize::from(pack_len) + UBX_CHECKSUM_LEN;
+            let resync_len = match options.resync_policy {
+                ResyncPolicy::AfterSync => UBX_SYNC_SIZE,
+                ResyncPolicy::AfterFrame => frame_len,
+            };
+            monitor.discard_frame(buf, resync_len, frame_len, DiscardReason::InvalidChecksum);
+            return Some(Err(checksum_error));
+        },
+    };
 
     let class_id = buf[UBX_CLASS_OFFSET];
     let msg_id = buf[UBX_MSG_ID_OFFSET];
+    monitor.stats.count_ubx_frame(class_id);
     buf.drain(UBX_HEADER_LEN);
     let msg_data = match buf.take(usize::from(pack_len) + UBX_CHECKSUM_LEN) {
         Ok(x) => x,
@@ -324,19 +988,18 @@ fn extract_packet_ubx<'b, T: UnderlyingBuffer, P: UbxProtocol>(
             return Some(Err(e));
         },
     };
-    let specific_packet_result = P::match_packet(
-        class_id,
-        msg_id,
-        &msg_data[..msg_data.len() - UBX_CHECKSUM_LEN],
-    );
-    Some(specific_packet_result.map(|p| p.into()))
+    Some(Ok(CheckedPacket {
+        packet: UbxUnknownPacketRef {
+            payload: &msg_data[..msg_data.len() - UBX_CHECKSUM_LEN],
+            class: class_id,
+            msg_id,
+        },
+        checksum_valid: mismatch.is_none(),
+        mismatch,
+    }))
 }
 
 impl<T: UnderlyingBuffer, P: UbxProtocol> UbxParserIter<'_, T, P> {
-    fn find_sync(&self) -> Option<usize> {
-        (0..self.buf.len()).find(|&i| self.buf[i] == UBX_SYNC_CHAR_1)
-    }
-
     #[allow(
         clippy::should_implement_trait,
         reason = "This is a lending iterator, which is not in std"
@@ -344,71 +1007,161 @@ impl<T: UnderlyingBuffer, P: UbxProtocol> UbxParserIter<'_, T, P> {
     /// Parse and return the next [UbxPacket] in the buffer, or `None` if the buffer cannot yield
     /// another full [UbxPacket]
     pub fn next(&mut self) -> Option<Result<UbxPacket<'_>, ParserError>> {
-        while self.buf.len() > 0 {
-            let pos = match self.find_sync() {
-                Some(x) => x,
-                None => {
-                    self.buf.clear();
-                    return None;
-                },
-            };
-            self.buf.drain(pos);
+        self.next_checked()
+            .map(|result| result.and_then(CheckedPacket::into_verified))
+    }
 
-            if self.buf.len() < UBX_SYNC_SIZE {
-                return None;
-            }
-            if self.buf[1] != UBX_SYNC_CHAR_2 {
-                self.buf.drain(1);
-                continue;
-            }
+    /// Like [UbxParserIter::next], but also reports whether the packet's checksum matched
+    pub fn next_checked(&mut self) -> Option<Result<CheckedPacket<UbxPacket<'_>>, ParserError>> {
+        next_ubx::<T, P>(&mut self.buf, self.monitor, self.options)
+    }
+
+    /// Like [UbxParserIter::next_checked], but returns the frame without decoding its payload,
+    /// e.g. to match it against a [packet set](crate::define_ubx_packet_set)
+    pub fn next_frame(
+        &mut self,
+    ) -> Option<Result<CheckedPacket<UbxUnknownPacketRef<'_>>, ParserError>> {
+        next_frame_ubx::<T, P>(&mut self.buf, self.monitor, self.options)
+    }
+}
 
-            if self.buf.len() < UBX_HEADER_LEN {
+/// Body of [UbxParserIter::next_checked], borrowing only the buffer for the returned packet so
+/// that the [StreamMonitor] can still be inspected afterwards
+fn next_ubx<'b, T: UnderlyingBuffer, P: UbxProtocol>(
+    buf: &'b mut DualBuffer<'_, T>,
+    monitor: &mut StreamMonitor,
+    options: ParserOptions,
+) -> Option<Result<CheckedPacket<UbxPacket<'b>>, ParserError>> {
+    let result = next_frame_ubx::<T, P>(buf, monitor, options)?;
+    Some(result.and_then(decode_frame_ubx::<P>))
+}
+
+/// Finds the next complete UBX frame in `buf`, dropping garbage and invalid frames on the way
+fn next_frame_ubx<'b, T: UnderlyingBuffer, P: UbxProtocol>(
+    buf: &'b mut DualBuffer<'_, T>,
+    monitor: &mut StreamMonitor,
+    options: ParserOptions,
+) -> Option<Result<CheckedPacket<UbxUnknownPacketRef<'b>>, ParserError>> {
+    while buf.len() > 0 {
+        let pos = match (0..buf.len()).find(|&i| buf[i] == UBX_SYNC_CHAR_1) {
+            Some(x) => x,
+            None => {
+                monitor.discard_all(buf, DiscardReason::Garbage);
                 return None;
-            }
+            },
+        };
+        monitor.discard(buf, pos, DiscardReason::Garbage);
 
-            let pack_len =
-                u16::from_le_bytes([self.buf[UBX_LENGTH_OFFSET], self.buf[UBX_LENGTH_OFFSET + 1]]);
-            if pack_len > P::MAX_PAYLOAD_LEN {
-                self.buf.drain(UBX_SYNC_SIZE);
-                continue;
-            }
-            return extract_packet_ubx::<T, P>(&mut self.buf, pack_len);
+        if buf.len() < UBX_SYNC_SIZE {
+            return None;
         }
-        None
+        if buf[1] != UBX_SYNC_CHAR_2 {
+            monitor.discard(buf, 1, DiscardReason::InvalidHeader);
+            continue;
+        }
+
+        if buf.len() < UBX_HEADER_LEN {
+            return None;
+        }
+
+        let pack_len = u16::from_le_bytes([buf[UBX_LENGTH_OFFSET], buf[UBX_LENGTH_OFFSET + 1]]);
+        if options.exceeds_payload_limit::<P>(buf[UBX_CLASS_OFFSET], pack_len) {
+            monitor.discard(buf, UBX_SYNC_SIZE, DiscardReason::InvalidLength);
+            continue;
+        }
+        if skip_filtered_ubx(buf, monitor, options, pack_len) {
+            continue;
+        }
+        let payload_len = usize::from(pack_len) + UBX_CHECKSUM_LEN;
+        if skip_overflowing_frame(
+            buf,
+            monitor,
+            options,
+            UBX_HEADER_LEN,
+            payload_len,
+            UBX_SYNC_SIZE,
+        ) {
+            continue;
+        }
+        monitor.frame_start = monitor.bytes_fed - buf.len() as u64;
+        return extract_frame_ubx(buf, monitor, options, pack_len);
     }
+    None
 }
 
 /// Iterator over data stored in `Parser` buffer
 pub struct UbxRtcmParserIter<'a, T: UnderlyingBuffer, P: UbxProtocol = DefaultProtocol> {
     buf: DualBuffer<'a, T>,
+    monitor: &'a mut StreamMonitor,
+    options: ParserOptions,
     _phantom: PhantomData<P>,
 }
 
+/// A raw RTCM3 frame, with its CRC-24Q already verified by the parser
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct RtcmPacketRef<'a> {
+    /// The whole frame: preamble, length, message and CRC-24Q trailer.
+    /// Forward this as-is to pass the corrections on to another receiver.
     pub data: &'a [u8],
 }
 
+impl<'a> RtcmPacketRef<'a> {
+    /// The RTCM3 message, without the frame header and CRC
+    pub fn payload(&self) -> &'a [u8] {
+        &self.data[RTCM_HEADER_SIZE..self.data.len() - RTCM_CRC_LEN]
+    }
+
+    /// The message number and payload, or `None` for a frame with a payload too short to
+    /// hold the 12-bit message number
+    pub fn frame(&self) -> Option<RtcmFrameRef<'a>> {
+        let payload = self.payload();
+        let msg_type = u16::from_be_bytes([*payload.first()?, *payload.get(1)?]) >> 4;
+        Some(RtcmFrameRef { msg_type, payload })
+    }
+}
+
+/// A decoded view of a [RtcmPacketRef]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct RtcmFrameRef<'a> {
+    /// RTCM3 message number, e.g. 1005 or 1077
+    pub msg_type: u16,
+    /// The message, starting with the message number, without the frame header and CRC
+    pub payload: &'a [u8],
+}
+
 fn extract_packet_rtcm<'a, 'b, T: UnderlyingBuffer>(
     buf: &'b mut DualBuffer<'a, T>,
+    monitor: &mut StreamMonitor,
     pack_len: u16,
 ) -> Option<Result<AnyPacketRef<'b>, ParserError>> {
     let pack_len = pack_len as usize; // `usize` is needed for indexing but constraining the input to `u16` is still important
-    if !buf.can_drain_and_take(0, pack_len + RTCM_HEADER_SIZE) {
+    let frame_len = RTCM_HEADER_SIZE + pack_len + RTCM_CRC_LEN;
+    if !buf.can_drain_and_take(0, frame_len) {
         if buf.potential_lost_bytes() > 0 {
             // We ran out of space, drop this packet and move on
             // Drain only the RTCM sync char to allow for finding another RTCM packet
-            buf.drain(1);
+            monitor.discard(buf, 1, DiscardReason::OutOfMemory);
             return Some(Err(ParserError::OutOfMemory {
-                required_size: pack_len + RTCM_HEADER_SIZE,
+                required_size: frame_len,
             }));
         }
         return None;
     }
 
-    let maybe_data = buf.take(pack_len + RTCM_HEADER_SIZE);
+    if let Err(crc_error) = checksum::RtcmCrc24Calc::validate_buffer(buf, frame_len) {
+        // Drain only the RTCM sync char, the preamble may have been a false match
+        monitor.discard_frame(buf, 1, frame_len, DiscardReason::InvalidChecksum);
+        return Some(Err(crc_error));
+    }
+
+    let maybe_data = buf.take(frame_len);
     match maybe_data {
-        Ok(data) => Some(Ok(AnyPacketRef::Rtcm(RtcmPacketRef::<'b> { data }))),
+        Ok(data) => {
+            monitor.stats.rtcm_frames += 1;
+            Some(Ok(AnyPacketRef::Rtcm(RtcmPacketRef::<'b> { data })))
+        },
         Err(e) => Some(Err(e)),
     }
 }
@@ -433,16 +1186,24 @@ impl<T: UnderlyingBuffer, P: UbxProtocol> UbxRtcmParserIter<'_, T, P> {
     /// Parse and return the next [UbxPacket or RtcmPacket](AnyPacketRef) in the buffer, or `None` if the buffer cannot yield
     /// another full packet
     pub fn next(&mut self) -> Option<Result<AnyPacketRef<'_>, ParserError>> {
+        self.next_checked()
+            .map(|result| result.and_then(CheckedPacket::into_verified))
+    }
+
+    /// Like [UbxRtcmParserIter::next], but also reports whether the packet's checksum matched
+    pub fn next_checked(&mut self) -> Option<Result<CheckedPacket<AnyPacketRef<'_>>, ParserError>> {
         while self.buf.len() > 0 {
             match self.find_sync() {
                 NextSync::Ubx(pos) => {
-                    self.buf.drain(pos);
+                    self.monitor
+                        .discard(&mut self.buf, pos, DiscardReason::Garbage);
 
                     if self.buf.len() < UBX_SYNC_SIZE {
                         return None;
                     }
                     if self.buf[1] != UBX_SYNC_CHAR_2 {
-                        self.buf.drain(1);
+                        self.monitor
+                            .discard(&mut self.buf, 1, DiscardReason::InvalidHeader);
                         continue;
                     }
 
@@ -454,19 +1215,45 @@ impl<T: UnderlyingBuffer, P: UbxProtocol> UbxRtcmParserIter<'_, T, P> {
                         self.buf[UBX_LENGTH_OFFSET],
                         self.buf[UBX_LENGTH_OFFSET + 1],
                     ]);
-                    if pack_len > P::MAX_PAYLOAD_LEN {
-                        self.buf.drain(UBX_SYNC_SIZE);
+                    if self
+                        .options
+                        .exceeds_payload_limit::<P>(self.buf[UBX_CLASS_OFFSET], pack_len)
+                    {
+                        self.monitor.discard(
+                            &mut self.buf,
+                            UBX_SYNC_SIZE,
+                            DiscardReason::InvalidLength,
+                        );
                         continue;
                     }
-                    let maybe_packet = extract_packet_ubx::<T, P>(&mut self.buf, pack_len);
+                    if skip_filtered_ubx(&mut self.buf, self.monitor, self.options, pack_len) {
+                        continue;
+                    }
+                    if skip_overflowing_frame(
+                        &mut self.buf,
+                        self.monitor,
+                        self.options,
+                        UBX_HEADER_LEN,
+                        usize::from(pack_len) + UBX_CHECKSUM_LEN,
+                        UBX_SYNC_SIZE,
+                    ) {
+                        continue;
+                    }
+                    let maybe_packet = extract_packet_ubx::<T, P>(
+                        &mut self.buf,
+                        self.monitor,
+                        self.options,
+                        pack_len,
+                    );
                     match maybe_packet {
-                        Some(Ok(packet)) => return Some(Ok(AnyPacketRef::Ubx(packet))),
+                        Some(Ok(checked)) => return Some(Ok(checked.map(AnyPacketRef::Ubx))),
                         Some(Err(e)) => return Some(Err(e)),
                         None => return None,
                     }
                 },
                 NextSync::Rtcm(pos) => {
-                    self.buf.drain(pos);
+                    self.monitor
+                        .discard(&mut self.buf, pos, DiscardReason::Garbage);
 
                     if self.buf.len() < RTCM_HEADER_SIZE {
                         return None;
@@ -474,11 +1261,24 @@ impl<T: UnderlyingBuffer, P: UbxProtocol> UbxRtcmParserIter<'_, T, P> {
                     // next 2 bytes contain 6 bits reserved + 10 bits length, big endian
                     let pack_len =
                         u16::from_be_bytes([self.buf[1], self.buf[2]]) & RTCM_LENGTH_MASK;
+                    let frame_len = RTCM_HEADER_SIZE + usize::from(pack_len) + RTCM_CRC_LEN;
+                    if skip_overflowing_frame(
+                        &mut self.buf,
+                        self.monitor,
+                        self.options,
+                        0,
+                        frame_len,
+                        1,
+                    ) {
+                        continue;
+                    }
 
-                    return extract_packet_rtcm(&mut self.buf, pack_len);
+                    return extract_packet_rtcm(&mut self.buf, self.monitor, pack_len)
+                        .map(|result| result.map(CheckedPacket::verified));
                 },
-                NextSync::Nmea(_) | NextSync::None => {
-                    self.buf.clear();
+                NextSync::Nmea(_) | NextSync::Spartn(_) | NextSync::None => {
+                    self.monitor
+                        .discard_all(&mut self.buf, DiscardReason::Garbage);
                     return None;
                 },
             };
@@ -490,16 +1290,40 @@ impl<T: UnderlyingBuffer, P: UbxProtocol> UbxRtcmParserIter<'_, T, P> {
 /// Iterator over data stored in `Parser` buffer
 pub struct UbxRtcmNmeaParserIter<'a, T: UnderlyingBuffer, P: UbxProtocol = DefaultProtocol> {
     buf: DualBuffer<'a, T>,
+    monitor: &'a mut StreamMonitor,
+    options: ParserOptions,
+    spartn: bool,
     _phantom: PhantomData<P>,
 }
 
+/// A raw NMEA sentence
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub struct NmeaPacketRef<'a> {
+    /// The whole sentence, from the leading `$` up to and including the trailing `\r\n`
     pub data: &'a [u8],
 }
 
+/// Serializes the sentence as a string where possible, as NMEA is a text protocol
+#[cfg(feature = "serde")]
+impl serde::Serialize for NmeaPacketRef<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("NmeaPacketRef", 1)?;
+        match core::str::from_utf8(self.data) {
+            Ok(sentence) => state.serialize_field("data", sentence)?,
+            Err(_) => state.serialize_field("data", self.data)?,
+        }
+        state.end()
+    }
+}
+
 fn extract_packet_nmea<'a, 'b, T: UnderlyingBuffer>(
     buf: &'b mut DualBuffer<'a, T>,
+    monitor: &mut StreamMonitor,
     pack_len: u16,
 ) -> Option<Result<AnyPacketRef<'b>, ParserError>> {
     let pack_len = pack_len as usize; // `usize` is needed for indexing but constraining the input to `u16` is still important
@@ -507,7 +1331,7 @@ fn extract_packet_nmea<'a, 'b, T: UnderlyingBuffer>(
         if buf.potential_lost_bytes() > 0 {
             // We ran out of space, drop this packet and move on
             // Drain only the NMEA sync char to allow for finding another NMEA sentence
-            buf.drain(1);
+            monitor.discard(buf, 1, DiscardReason::OutOfMemory);
             return Some(Err(ParserError::OutOfMemory {
                 required_size: pack_len,
             }));
@@ -517,19 +1341,68 @@ fn extract_packet_nmea<'a, 'b, T: UnderlyingBuffer>(
 
     let maybe_data = buf.take(pack_len);
     match maybe_data {
-        Ok(data) => Some(Ok(AnyPacketRef::Nmea(NmeaPacketRef::<'b> { data }))),
+        Ok(data) => {
+            monitor.stats.nmea_sentences += 1;
+            Some(Ok(AnyPacketRef::Nmea(NmeaPacketRef::<'b> { data })))
+        },
+        Err(e) => Some(Err(e)),
+    }
+}
+
+fn extract_packet_spartn<'a, 'b, T: UnderlyingBuffer>(
+    buf: &'b mut DualBuffer<'a, T>,
+    monitor: &mut StreamMonitor,
+    frame_len: usize,
+) -> Option<Result<AnyPacketRef<'b>, ParserError>> {
+    if !buf.can_drain_and_take(0, frame_len) {
+        if buf.potential_lost_bytes() > 0 {
+            // We ran out of space, drop this packet and move on
+            // Drain only the SPARTN sync char to allow for finding another SPARTN frame
+            monitor.discard(buf, 1, DiscardReason::OutOfMemory);
+            return Some(Err(ParserError::OutOfMemory {
+                required_size: frame_len,
+            }));
+        }
+        return None;
+    }
+
+    let mut header = [0; spartn::SPARTN_MAX_HEADER_SIZE];
+    for (i, byte) in header.iter_mut().enumerate().take(frame_len) {
+        *byte = buf[i];
+    }
+    let crc_type = SpartnCrcType::from_header(&header);
+    let crc_offset = frame_len - crc_type.byte_len();
+    let mut calc = spartn::SpartnCrcCalc::new(crc_type);
+    let (head, tail) = buf.peek_raw(1..crc_offset);
+    calc.update(head);
+    calc.update(tail);
+    let expect = (crc_offset..frame_len).fold(0, |crc, i| (crc << 8) | u32::from(buf[i]));
+    let got = calc.result();
+    if expect != got {
+        // Drain only the SPARTN sync char, the preamble may have been a false match
+        monitor.discard_frame(buf, 1, frame_len, DiscardReason::InvalidChecksum);
+        return Some(Err(ParserError::InvalidSpartnCrc { expect, got }));
+    }
+
+    let maybe_data = buf.take(frame_len);
+    match maybe_data {
+        Ok(data) => {
+            monitor.stats.spartn_frames += 1;
+            Some(Ok(AnyPacketRef::Spartn(SpartnFrameRef::<'b> { data })))
+        },
         Err(e) => Some(Err(e)),
     }
 }
 
 impl<T: UnderlyingBuffer, P: UbxProtocol> UbxRtcmNmeaParserIter<'_, T, P> {
     /// Find the next sync char in the buffer, starting at `min_idx`
-    fn find_sync(&self, min_idx: usize) -> NextSync {
+    fn find_sync(&self, min_idx: usize, spartn: bool) -> NextSync {
         for i in min_idx..self.buf.len() {
             match self.buf[i] {
                 UBX_SYNC_CHAR_1 => return NextSync::Ubx(i),
                 RTCM_SYNC_CHAR => return NextSync::Rtcm(i),
                 NMEA_SYNC_CHAR => return NextSync::Nmea(i),
+                SPARTN_SYNC_CHAR if spartn => return NextSync::Spartn(i),
                 _ => (),
             }
         }
@@ -543,16 +1416,24 @@ impl<T: UnderlyingBuffer, P: UbxProtocol> UbxRtcmNmeaParserIter<'_, T, P> {
     /// Parse and return the next [UbxPacket, RtcmPacket, or NmeaPacket](AnyPacketRef) in the buffer, or `None` if the buffer cannot yield
     /// another full packet
     pub fn next(&mut self) -> Option<Result<AnyPacketRef<'_>, ParserError>> {
+        self.next_checked()
+            .map(|result| result.and_then(CheckedPacket::into_verified))
+    }
+
+    /// Like [UbxRtcmNmeaParserIter::next], but also reports whether the packet's checksum matched
+    pub fn next_checked(&mut self) -> Option<Result<CheckedPacket<AnyPacketRef<'_>>, ParserError>> {
         while self.buf.len() > 0 {
-            match self.find_sync(0) {
+            match self.find_sync(0, self.spartn) {
                 NextSync::Ubx(pos) => {
-                    self.buf.drain(pos);
+                    self.monitor
+                        .discard(&mut self.buf, pos, DiscardReason::Garbage);
 
                     if self.buf.len() < UBX_SYNC_SIZE {
                         return None;
                     }
                     if self.buf[1] != UBX_SYNC_CHAR_2 {
-                        self.buf.drain(1);
+                        self.monitor
+                            .discard(&mut self.buf, 1, DiscardReason::InvalidHeader);
                         continue;
                     }
 
@@ -564,19 +1445,45 @@ impl<T: UnderlyingBuffer, P: UbxProtocol> UbxRtcmNmeaParserIter<'_, T, P> {
                         self.buf[UBX_LENGTH_OFFSET],
                         self.buf[UBX_LENGTH_OFFSET + 1],
                     ]);
-                    if pack_len > P::MAX_PAYLOAD_LEN {
-                        self.buf.drain(UBX_SYNC_SIZE);
+                    if self
+                        .options
+                        .exceeds_payload_limit::<P>(self.buf[UBX_CLASS_OFFSET], pack_len)
+                    {
+                        self.monitor.discard(
+                            &mut self.buf,
+                            UBX_SYNC_SIZE,
+                            DiscardReason::InvalidLength,
+                        );
+                        continue;
+                    }
+                    if skip_filtered_ubx(&mut self.buf, self.monitor, self.options, pack_len) {
+                        continue;
+                    }
+                    if skip_overflowing_frame(
+                        &mut self.buf,
+                        self.monitor,
+                        self.options,
+                        UBX_HEADER_LEN,
+                        usize::from(pack_len) + UBX_CHECKSUM_LEN,
+                        UBX_SYNC_SIZE,
+                    ) {
                         continue;
                     }
-                    let maybe_packet = extract_packet_ubx::<T, P>(&mut self.buf, pack_len);
+                    let maybe_packet = extract_packet_ubx::<T, P>(
+                        &mut self.buf,
+                        self.monitor,
+                        self.options,
+                        pack_len,
+                    );
                     match maybe_packet {
-                        Some(Ok(packet)) => return Some(Ok(AnyPacketRef::Ubx(packet))),
+                        Some(Ok(checked)) => return Some(Ok(checked.map(AnyPacketRef::Ubx))),
                         Some(Err(e)) => return Some(Err(e)),
                         None => return None,
                     }
                 },
                 NextSync::Rtcm(pos) => {
-                    self.buf.drain(pos);
+                    self.monitor
+                        .discard(&mut self.buf, pos, DiscardReason::Garbage);
 
                     if self.buf.len() < RTCM_HEADER_SIZE {
                         return None;
@@ -584,11 +1491,24 @@ impl<T: UnderlyingBuffer, P: UbxProtocol> UbxRtcmNmeaParserIter<'_, T, P> {
                     // next 2 bytes contain 6 bits reserved + 10 bits length, big endian
                     let pack_len =
                         u16::from_be_bytes([self.buf[1], self.buf[2]]) & RTCM_LENGTH_MASK;
+                    let frame_len = RTCM_HEADER_SIZE + usize::from(pack_len) + RTCM_CRC_LEN;
+                    if skip_overflowing_frame(
+                        &mut self.buf,
+                        self.monitor,
+                        self.options,
+                        0,
+                        frame_len,
+                        1,
+                    ) {
+                        continue;
+                    }
 
-                    return extract_packet_rtcm(&mut self.buf, pack_len);
+                    return extract_packet_rtcm(&mut self.buf, self.monitor, pack_len)
+                        .map(|result| result.map(CheckedPacket::verified));
                 },
                 NextSync::Nmea(pos) => {
-                    self.buf.drain(pos);
+                    self.monitor
+                        .discard(&mut self.buf, pos, DiscardReason::Garbage);
 
                     if self.buf.len() < NMEA_MIN_BUFFER_SIZE {
                         return None;
@@ -605,22 +1525,75 @@ impl<T: UnderlyingBuffer, P: UbxProtocol> UbxRtcmNmeaParserIter<'_, T, P> {
 
                     // try to extract the packet if its length was found,
                     // otherwise check if NMEA string has to be discarded
+                    if let Some(len) = pack_len {
+                        if skip_overflowing_frame(
+                            &mut self.buf,
+                            self.monitor,
+                            self.options,
+                            0,
+                            usize::from(len),
+                            1,
+                        ) {
+                            continue;
+                        }
+                    }
                     return if let Some(len) = pack_len {
-                        extract_packet_nmea(&mut self.buf, len)
+                        extract_packet_nmea(&mut self.buf, self.monitor, len)
+                            .map(|result| result.map(CheckedPacket::verified))
                     } else {
-                        if self.find_sync(1) != NextSync::None {
+                        // SPARTN's preamble is a printable character, so it is ignored here
+                        if self.find_sync(1, false) != NextSync::None {
                             // found another packet before the end of the NMEA sentence,
                             // drain NMEA sync char
-                            self.buf.drain(1);
+                            self.monitor
+                                .discard(&mut self.buf, 1, DiscardReason::Truncated);
                         } else if self.buf.len() > NMEA_MAX_SENTENCE_LENGTH {
                             // maximum NMEA length exceeded, clear buffer
-                            self.buf.clear();
+                            self.monitor
+                                .discard_all(&mut self.buf, DiscardReason::InvalidLength);
                         }
                         None
                     };
                 },
+                NextSync::Spartn(pos) => {
+                    self.monitor
+                        .discard(&mut self.buf, pos, DiscardReason::Garbage);
+
+                    let mut header = [0; spartn::SPARTN_MAX_HEADER_SIZE];
+                    let header_len = core::cmp::min(self.buf.len(), header.len());
+                    for (i, byte) in header[..header_len].iter_mut().enumerate() {
+                        *byte = self.buf[i];
+                    }
+                    match spartn::frame_len(&header[..header_len]) {
+                        spartn::SpartnFrameLen::Incomplete => return None,
+                        spartn::SpartnFrameLen::Invalid => {
+                            self.monitor
+                                .discard(&mut self.buf, 1, DiscardReason::InvalidHeader)
+                        },
+                        spartn::SpartnFrameLen::InvalidFrameCrc { expect, got } => {
+                            self.monitor
+                                .discard(&mut self.buf, 1, DiscardReason::InvalidChecksum);
+                            return Some(Err(ParserError::InvalidSpartnFrameCrc { expect, got }));
+                        },
+                        spartn::SpartnFrameLen::Complete(frame_len) => {
+                            if skip_overflowing_frame(
+                                &mut self.buf,
+                                self.monitor,
+                                self.options,
+                                0,
+                                frame_len,
+                                1,
+                            ) {
+                                continue;
+                            }
+                            return extract_packet_spartn(&mut self.buf, self.monitor, frame_len)
+                                .map(|result| result.map(CheckedPacket::verified));
+                        },
+                    }
+                },
                 NextSync::None => {
-                    self.buf.clear();
+                    self.monitor
+                        .discard_all(&mut self.buf, DiscardReason::Garbage);
                     return None;
                 },
             };
@@ -664,6 +1637,62 @@ mod test {
         assert_eq!(parser.buffer_len(), 0);
     }
 
+    #[test]
+    fn parser_drops_frames_above_payload_limits() {
+        // UBX-RXM-SFRBX and UBX-NAV-PVT headers announcing 1000 bytes, then an ACK-ACK
+        let mut data = [0u8; 22];
+        data[..6].copy_from_slice(&[0xb5, 0x62, 0x02, 0x13, 0xe8, 0x03]);
+        data[6..12].copy_from_slice(&[0xb5, 0x62, 0x01, 0x07, 0xe8, 0x03]);
+        data[12..].copy_from_slice(&[0xb5, 0x62, 0x05, 0x01, 0x02, 0x00, 0x06, 0x01, 0x0f, 0x38]);
+
+        // Both frames are dropped at their header, without waiting for their payload
+        let mut parser = ParserBuilder::new()
+            .with_max_payload_len(100)
+            .with_fixed_buffer::<1024>();
+        let mut it = parser.consume_ubx(&data);
+        assert!(matches!(it.next(), Some(Ok(_))));
+        assert!(it.next().is_none());
+        drop(it);
+        assert_eq!(parser.stats().bytes_discarded, 12);
+
+        // The RXM frame is allowed and the parser waits for the rest of it
+        let mut parser = ParserBuilder::new()
+            .with_max_payload_len(100)
+            .with_class_payload_limits(&[(0x02, 1000)])
+            .with_fixed_buffer::<1024>();
+        assert!(parser.consume_ubx(&data).next().is_none());
+        assert_eq!(parser.buffer_len(), data.len());
+    }
+
+    #[test]
+    fn parser_abandons_partial_frame_after_byte_timeout() {
+        const ACK_ACK: [u8; 10] = [0xb5, 0x62, 0x05, 0x01, 0x02, 0x00, 0x06, 0x01, 0x0f, 0x38];
+        // A frame announcing 100 bytes, whose end was lost
+        let truncated = [0xb5, 0x62, 0x01, 0x07, 100, 0, 0, 0];
+
+        let mut parser = ParserBuilder::new()
+            .with_partial_frame_timeout(FrameTimeout::Bytes(20))
+            .with_fixed_buffer::<1024>();
+        let mut it = parser.consume_ubx(&truncated);
+        assert!(it.next().is_none());
+        drop(it);
+        // 18 bytes since the sync chars, the ACK-ACK is still taken as payload
+        let mut it = parser.consume_ubx(&ACK_ACK);
+        assert!(it.next().is_none());
+        drop(it);
+
+        let mut it = parser.consume_ubx(&ACK_ACK);
+        assert!(matches!(
+            it.next(),
+            Some(Err(ParserError::PartialFrameTimeout { received: 28 }))
+        ));
+        assert!(matches!(it.next(), Some(Ok(_))));
+        assert!(matches!(it.next(), Some(Ok(_))));
+        assert!(it.next().is_none());
+        drop(it);
+        assert_eq!(parser.stats().bytes_discarded, 8);
+    }
+
     #[cfg(feature = "alloc")]
     use alloc::vec;
 
@@ -893,6 +1922,97 @@ mod test {
         }
     }
 
+    #[cfg(feature = "ubx_proto23")]
+    #[test]
+    fn parser_oom_drop_oldest() {
+        use crate::proto23::{PacketRef, Proto23};
+        let bytes = test_util_cfg_nav5_bytes();
+        let ack_ack = [0xb5, 0x62, 0x5, 0x1, 0x2, 0x0, 0x4, 0x5, 0x11, 0x38];
+
+        let mut buffer = [0; 12];
+        let mut parser = ParserBuilder::new()
+            .with_protocol::<Proto23>()
+            .with_overflow_policy(OverflowPolicy::DropOldest)
+            .with_buffer(FixedLinearBuffer::new(&mut buffer));
+
+        {
+            let mut it = parser.consume_ubx(&bytes[0..8]);
+            assert!(it.next().is_none());
+        }
+
+        {
+            // The rest of the frame is dropped as garbage after the sync chars
+            let data = [&bytes[8..], &ack_ack[..]].concat();
+            let mut it = parser.consume_ubx(&data);
+            assert!(matches!(
+                it.next(),
+                Some(Ok(UbxPacket::Proto23(PacketRef::AckAck(_))))
+            ));
+            assert!(it.next().is_none());
+        }
+        assert_eq!(parser.stats().bytes_discarded, bytes.len() as u64);
+    }
+
+    #[cfg(feature = "ubx_proto23")]
+    #[test]
+    fn parser_oom_grow_fixed_buffer() {
+        use crate::proto23::Proto23;
+        let bytes = test_util_cfg_nav5_bytes();
+
+        let mut parser = ParserBuilder::new()
+            .with_protocol::<Proto23>()
+            .with_overflow_policy(OverflowPolicy::Grow)
+            .with_fixed_buffer::<12>();
+
+        {
+            let mut it = parser.consume_ubx(&bytes[0..8]);
+            assert!(it.next().is_none());
+        }
+
+        {
+            let mut it = parser.consume_ubx(&bytes[8..]);
+            assert!(matches!(
+                it.next(),
+                Some(Err(ParserError::OutOfMemory { .. }))
+            ));
+        }
+        assert_eq!(parser.buffer_capacity(), 12);
+    }
+
+    #[cfg(all(feature = "alloc", feature = "ubx_proto23"))]
+    #[test]
+    fn parser_oom_grow() {
+        use crate::proto23::{PacketRef, Proto23};
+        let bytes = test_util_cfg_nav5_bytes();
+
+        let mut parser = ParserBuilder::new()
+            .with_protocol::<Proto23>()
+            .with_overflow_policy(OverflowPolicy::Grow)
+            .with_growable_buffer(12);
+
+        {
+            let mut it = parser.consume_ubx(&bytes[0..8]);
+            assert!(it.next().is_none());
+        }
+
+        {
+            // The partial frame is kept until the rest arrives
+            let mut it = parser.consume_ubx(&bytes[8..20]);
+            assert!(it.next().is_none());
+        }
+        assert_eq!(parser.buffer_len(), 20);
+
+        {
+            let mut it = parser.consume_ubx(&bytes[20..]);
+            assert!(matches!(
+                it.next(),
+                Some(Ok(UbxPacket::Proto23(PacketRef::CfgNav5(_))))
+            ));
+            assert!(it.next().is_none());
+        }
+        assert_eq!(parser.buffer_capacity(), bytes.len());
+    }
+
     #[cfg(feature = "ubx_proto27")]
     #[test]
     fn parser_oom_clears_buffer_proto27() {
@@ -47,6 +47,16 @@ pub trait UnderlyingBuffer:
     fn is_empty(&self) -> bool {
         self.len() == 0
     }
+
+    /// Raises `max_capacity` to at least `capacity`, returning whether the buffer could grow.
+    /// Called by the parser for frames that do not fit with [OverflowPolicy::Grow], buffers of
+    /// a fixed size keep the default implementation, which never grows.
+    ///
+    /// [OverflowPolicy::Grow]: crate::OverflowPolicy::Grow
+    fn grow(&mut self, capacity: usize) -> bool {
+        let _ = capacity;
+        false
+    }
 }
 
 #[cfg(any(feature = "std", feature = "alloc"))]
@@ -77,6 +87,85 @@ impl UnderlyingBuffer for Vec<u8> {
     }
 }
 
+/// A heap allocated buffer with an initial capacity, which only grows when a frame does not
+/// fit and the parser was built with [OverflowPolicy::Grow]
+///
+/// This keeps memory use low while still handling the odd large frame, e.g. UBX-RXM-RAWX with
+/// many satellites in view, whereas a `Vec<u8>` buffer grows as far as the data demands.
+///
+/// [OverflowPolicy::Grow]: crate::OverflowPolicy::Grow
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[derive(Debug, Clone, Default)]
+pub struct GrowableBuffer {
+    buffer: Vec<u8>,
+    capacity: usize,
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl GrowableBuffer {
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            buffer: Vec::with_capacity(capacity),
+            capacity,
+        }
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl core::ops::Index<core::ops::Range<usize>> for GrowableBuffer {
+    type Output = [u8];
+
+    fn index(&self, index: core::ops::Range<usize>) -> &Self::Output {
+        &self.buffer[index]
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl core::ops::Index<usize> for GrowableBuffer {
+    type Output = u8;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.buffer[index]
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl UnderlyingBuffer for GrowableBuffer {
+    fn clear(&mut self) {
+        self.buffer.clear();
+    }
+
+    fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    fn max_capacity(&self) -> usize {
+        self.capacity
+    }
+
+    fn extend_from_slice(&mut self, other: &[u8]) -> usize {
+        let to_copy = min(other.len(), self.capacity - self.buffer.len());
+        self.buffer.extend_from_slice(&other[..to_copy]);
+        other.len() - to_copy
+    }
+
+    fn drain(&mut self, count: usize) {
+        self.buffer.drain(0..min(count, self.buffer.len()));
+    }
+
+    fn find(&self, value: u8) -> Option<usize> {
+        self.buffer.iter().position(|elem| *elem == value)
+    }
+
+    fn grow(&mut self, capacity: usize) -> bool {
+        if capacity > self.capacity {
+            self.buffer.reserve(capacity - self.buffer.len());
+            self.capacity = capacity;
+        }
+        true
+    }
+}
+
 /// Holds a mutable reference to a fixed byte array
 pub struct FixedLinearBuffer<'a> {
     buffer: &'a mut [u8],
@@ -84,7 +173,7 @@ pub struct FixedLinearBuffer<'a> {
 }
 
 impl<'a> FixedLinearBuffer<'a> {
-    pub fn new(buf: &'a mut [u8]) -> Self {
+    pub const fn new(buf: &'a mut [u8]) -> Self {
         Self {
             buffer: buf,
             len: 0,
@@ -309,6 +398,11 @@ impl<'a, T: UnderlyingBuffer> DualBuffer<'a, T> {
         }
     }
 
+    /// Raises the capacity of the underlying buffer, see [UnderlyingBuffer::grow]
+    pub(crate) fn grow(&mut self, capacity: usize) -> bool {
+        self.buf.grow(capacity)
+    }
+
     pub(crate) fn can_drain_and_take(&self, drain: usize, take: usize) -> bool {
         let underlying_bytes = core::cmp::min(self.buf.len() - self.off, drain);
         let new_bytes = drain.saturating_sub(underlying_bytes);
@@ -696,6 +790,23 @@ mod test {
         assert_eq!(buf.find(5), Some(4));
     }
 
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn growable_buf_grow() {
+        let mut buf = GrowableBuffer::with_capacity(4);
+        assert_eq!(buf.extend_from_slice(&[1, 2, 3, 4, 5, 6]), 2);
+        assert_eq!(buf.max_capacity(), 4);
+        assert!(buf.grow(8));
+        assert_eq!(buf.extend_from_slice(&[5, 6, 7, 8, 9]), 1);
+        assert_eq!(&buf[0..buf.len()], &[1, 2, 3, 4, 5, 6, 7, 8]);
+
+        // Never shrinks
+        assert!(buf.grow(2));
+        assert_eq!(buf.max_capacity(), 8);
+        buf.drain(3);
+        assert_eq!(&buf[0..buf.len()], &[4, 5, 6, 7, 8]);
+    }
+
     #[test]
     fn fixed_buf_clear() {
         let mut buf = FixedBuffer::<16>::new();
@@ -1,11 +1,13 @@
 use crate::{
-    constants::{UBX_CHECKSUM_LEN, UBX_CLASS_OFFSET, UBX_HEADER_LEN, UBX_LENGTH_OFFSET},
+    constants::{
+        RTCM_CRC_LEN, UBX_CHECKSUM_LEN, UBX_CLASS_OFFSET, UBX_HEADER_LEN, UBX_LENGTH_OFFSET,
+    },
     parser::buffer::DualBuffer,
     ParserError, UnderlyingBuffer,
 };
 
 /// UBX [Fletcher-16 checksum](https://en.wikipedia.org/wiki/Fletcher%27s_checksum) calculator supporting both streaming and single-shot validation
-#[derive(Default)]
+#[derive(Debug, Default, Clone, Copy)]
 pub(crate) struct UbxChecksumCalc {
     ck_a: u8,
     ck_b: u8,
@@ -80,11 +82,94 @@ impl UbxChecksumCalc {
     }
 }
 
+const CRC24Q_POLY: u32 = 0x0186_4CFB;
+
+const CRC24Q_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = (i as u32) << 16;
+        let mut bit = 0;
+        while bit < 8 {
+            crc <<= 1;
+            if crc & 0x0100_0000 != 0 {
+                crc ^= CRC24Q_POLY;
+            }
+            bit += 1;
+        }
+        table[i] = crc & 0x00FF_FFFF;
+        i += 1;
+    }
+    table
+};
+
+/// RTCM3 [CRC-24Q](https://en.wikipedia.org/wiki/Cyclic_redundancy_check) calculator supporting both streaming and single-shot validation
+#[derive(Default)]
+pub(crate) struct RtcmCrc24Calc {
+    crc: u32,
+}
+
+impl RtcmCrc24Calc {
+    pub(crate) const fn new() -> Self {
+        Self { crc: 0 }
+    }
+
+    /// Update CRC with new bytes
+    pub(crate) const fn update(&mut self, bytes: &[u8]) {
+        let mut i = 0;
+        while i < bytes.len() {
+            self.update_byte(bytes[i]);
+            i += 1;
+        }
+    }
+
+    /// Update CRC with a single byte
+    pub(crate) const fn update_byte(&mut self, byte: u8) {
+        let idx = ((self.crc >> 16) as u8 ^ byte) as usize;
+        self.crc = ((self.crc << 8) ^ CRC24Q_TABLE[idx]) & 0x00FF_FFFF;
+    }
+
+    /// Get the current CRC result
+    pub(crate) const fn result(self) -> u32 {
+        self.crc
+    }
+
+    /// Validate CRC against the received big-endian 24-bit trailer
+    pub(crate) const fn validate_result(self, received: u32) -> Result<(), ParserError> {
+        let calculated = self.result();
+        if calculated == received {
+            Ok(())
+        } else {
+            Err(ParserError::InvalidRtcmCrc {
+                expect: received,
+                got: calculated,
+            })
+        }
+    }
+
+    /// Single-shot validation of a whole frame (preamble up to and including the CRC) at the
+    /// start of the buffer
+    pub(crate) fn validate_buffer<T: UnderlyingBuffer>(
+        buf: &DualBuffer<'_, T>,
+        frame_len: usize,
+    ) -> Result<(), ParserError> {
+        let crc_offset = frame_len - RTCM_CRC_LEN;
+        let mut calc = Self::new();
+        let (head, tail) = buf.peek_raw(0..crc_offset);
+        calc.update(head);
+        calc.update(tail);
+
+        let received =
+            u32::from_be_bytes([0, buf[crc_offset], buf[crc_offset + 1], buf[crc_offset + 2]]);
+        calc.validate_result(received)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
         constants::{UBX_SYNC_CHAR_1, UBX_SYNC_CHAR_2},
-        FixedBuffer,
+        verify_frame, ChecksumError, FixedBuffer,
     };
 
     use super::*;
@@ -120,6 +205,33 @@ mod tests {
         (pack_len, packet)
     }
 
+    #[test]
+    fn test_verify_frame() {
+        let (_, packet) = create_valid_ubx_packet();
+        let parts = verify_frame(&packet).unwrap();
+        assert_eq!(parts.class, 0x05);
+        assert_eq!(parts.msg_id, 0x01);
+        assert_eq!(parts.payload, &[0x04, 0x05]);
+
+        let (_, packet) = create_invalid_ubx_packet();
+        assert!(matches!(
+            verify_frame(&packet),
+            Err(ChecksumError::Mismatch { .. })
+        ));
+        assert_eq!(
+            verify_frame(&VALID_UBX_PACKET[..9]),
+            Err(ChecksumError::InvalidLength { expect: 10, got: 9 })
+        );
+        assert_eq!(
+            verify_frame(&VALID_UBX_PACKET[..4]),
+            Err(ChecksumError::InvalidLength { expect: 8, got: 4 })
+        );
+        assert_eq!(
+            verify_frame(&VALID_UBX_PACKET[1..]),
+            Err(ChecksumError::InvalidSync)
+        );
+    }
+
     #[test]
     fn test_streaming_checksum_valid() {
         let (_, packet) = create_valid_ubx_packet();
@@ -288,4 +400,45 @@ mod tests {
             ));
         };
     }
+
+    // RTCM 1005 frame from the u-blox ZED-F9P integration manual
+    const VALID_RTCM_FRAME: [u8; 25] = [
+        0xd3, 0x00, 0x13, 0x3e, 0xd7, 0xd3, 0x02, 0x02, 0x98, 0x0e, 0xde, 0xef, 0x34, 0xb4, 0xbd,
+        0x62, 0xac, 0x09, 0x41, 0x98, 0x6f, 0x33, 0x36, 0x0b, 0x98,
+    ];
+
+    #[test]
+    fn test_rtcm_crc_valid() {
+        let mut calc = RtcmCrc24Calc::new();
+        calc.update(&VALID_RTCM_FRAME[..22]);
+        assert_eq!(calc.result(), 0x360b98);
+
+        let mut calc = RtcmCrc24Calc::new();
+        for byte in &VALID_RTCM_FRAME[..22] {
+            calc.update_byte(*byte);
+        }
+        assert_eq!(calc.validate_result(0x360b98), Ok(()));
+    }
+
+    #[test]
+    fn test_rtcm_crc_buffer_validation() {
+        let mut buf = FixedBuffer::<128>::new();
+        let dual_buffer = DualBuffer::new(&mut buf, &VALID_RTCM_FRAME);
+        assert_eq!(
+            RtcmCrc24Calc::validate_buffer(&dual_buffer, VALID_RTCM_FRAME.len()),
+            Ok(())
+        );
+
+        let mut corrupted = VALID_RTCM_FRAME;
+        corrupted[10] ^= 0x01;
+        let mut buf = FixedBuffer::<128>::new();
+        let dual_buffer = DualBuffer::new(&mut buf, &corrupted);
+        assert!(matches!(
+            RtcmCrc24Calc::validate_buffer(&dual_buffer, corrupted.len()),
+            Err(ParserError::InvalidRtcmCrc {
+                expect: 0x360b98,
+                ..
+            })
+        ));
+    }
 }
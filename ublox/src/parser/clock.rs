@@ -0,0 +1,145 @@
+use super::{next_ubx, CheckedPacket, Parser, UbxParserIter};
+use crate::{ParserError, UbxPacket, UbxProtocol, UnderlyingBuffer};
+
+/// A packet along with the time its first byte was passed to the parser
+#[derive(Debug)]
+pub struct TimestampedPacket<T, Ts> {
+    pub packet: T,
+    pub timestamp: Ts,
+}
+
+/// A [Parser] reading a clock whenever data is consumed, built with [Parser::with_clock]
+///
+/// Packets are stamped with the time of the `consume_ubx` call that passed in their first sync
+/// byte, so frames split across several reads keep the time they started arriving at. The
+/// resolution is therefore that of the reads, feed the data as it arrives, e.g. from the UART
+/// interrupt or right after each `read()`, for precise latency measurements or PPS alignment.
+///
+/// ```
+/// use ublox::ParserBuilder;
+///
+/// let mut ticks = 0;
+/// let mut parser = ParserBuilder::new()
+///     .with_fixed_buffer::<1024>()
+///     .with_clock(move || {
+///         ticks += 1;
+///         ticks
+///     });
+/// let ack_ack = [0xb5, 0x62, 0x05, 0x01, 0x02, 0x00, 0x06, 0x01, 0x0f, 0x38];
+///
+/// // Tick 1: the first half of a frame arrives
+/// let mut it = parser.consume_ubx(&ack_ack[..4]);
+/// assert!(it.next().is_none());
+/// drop(it);
+///
+/// // Tick 2: the rest of it, along with another frame
+/// let data = [&ack_ack[4..], &ack_ack[..]].concat();
+/// let mut it = parser.consume_ubx(&data);
+/// assert_eq!(it.next().unwrap().unwrap().timestamp, 1);
+/// assert_eq!(it.next().unwrap().unwrap().timestamp, 2);
+/// assert!(it.next().is_none());
+/// ```
+pub struct ClockedParser<T, P, C, Ts>
+where
+    T: UnderlyingBuffer,
+    P: UbxProtocol,
+{
+    parser: Parser<T, P>,
+    clock: C,
+    /// Time the data left in the buffer started arriving
+    buffered: Option<Ts>,
+}
+
+impl<T: UnderlyingBuffer, P: UbxProtocol> Parser<T, P> {
+    /// Stamp every packet with the value of `clock` when its first sync byte arrived, see
+    /// [ClockedParser]
+    pub fn with_clock<C, Ts>(self, clock: C) -> ClockedParser<T, P, C, Ts>
+    where
+        C: FnMut() -> Ts,
+        Ts: Clone,
+    {
+        ClockedParser {
+            parser: self,
+            clock,
+            buffered: None,
+        }
+    }
+}
+
+impl<T, P, C, Ts> ClockedParser<T, P, C, Ts>
+where
+    T: UnderlyingBuffer,
+    P: UbxProtocol,
+    C: FnMut() -> Ts,
+    Ts: Clone,
+{
+    /// Reads the clock, appends `new_data` to the internal buffer and returns an iterator
+    /// yielding [TimestampedPackets](TimestampedPacket)
+    pub fn consume_ubx<'a>(&'a mut self, new_data: &'a [u8]) -> ClockedUbxParserIter<'a, T, P, Ts> {
+        let now = (self.clock)();
+        let new_data_start = self.parser.monitor.bytes_fed;
+        ClockedUbxParserIter {
+            inner: self.parser.consume_ubx(new_data),
+            now,
+            buffered: &mut self.buffered,
+            new_data_start,
+        }
+    }
+
+    pub fn parser(&self) -> &Parser<T, P> {
+        &self.parser
+    }
+
+    pub fn parser_mut(&mut self) -> &mut Parser<T, P> {
+        &mut self.parser
+    }
+
+    /// Removes the clock, returning the parser along with its buffered data
+    pub fn into_parser(self) -> Parser<T, P> {
+        self.parser
+    }
+}
+
+/// Iterator over data stored in the buffer of a [ClockedParser]
+pub struct ClockedUbxParserIter<'a, T: UnderlyingBuffer, P: UbxProtocol, Ts: Clone> {
+    inner: UbxParserIter<'a, T, P>,
+    now: Ts,
+    buffered: &'a mut Option<Ts>,
+    /// Stream offset of the first byte passed to this `consume_ubx` call
+    new_data_start: u64,
+}
+
+impl<T: UnderlyingBuffer, P: UbxProtocol, Ts: Clone> ClockedUbxParserIter<'_, T, P, Ts> {
+    #[allow(
+        clippy::should_implement_trait,
+        reason = "This is a lending iterator, which is not in std"
+    )]
+    /// Parse and return the next [UbxPacket] in the buffer along with its timestamp, or `None`
+    /// if the buffer cannot yield another full [UbxPacket]
+    pub fn next(&mut self) -> Option<Result<TimestampedPacket<UbxPacket<'_>, Ts>, ParserError>> {
+        let inner = &mut self.inner;
+        let result = next_ubx::<T, P>(&mut inner.buf, inner.monitor, inner.options)?;
+        let timestamp = match self.buffered {
+            Some(buffered) if inner.monitor.frame_start < self.new_data_start => buffered.clone(),
+            _ => self.now.clone(),
+        };
+        Some(
+            result
+                .and_then(CheckedPacket::into_verified)
+                .map(|packet| TimestampedPacket { packet, timestamp }),
+        )
+    }
+}
+
+impl<T: UnderlyingBuffer, P: UbxProtocol, Ts: Clone> Drop for ClockedUbxParserIter<'_, T, P, Ts> {
+    fn drop(&mut self) {
+        let remaining = self.inner.buf.len() as u64;
+        let remaining_start = self.inner.monitor.bytes_fed - remaining;
+        if remaining == 0 {
+            *self.buffered = None;
+        } else if remaining_start >= self.new_data_start || self.buffered.is_none() {
+            // The partial frame left over started arriving with this call
+            *self.buffered = Some(self.now.clone());
+        }
+    }
+}
@@ -0,0 +1,296 @@
+use super::checksum::UbxChecksumCalc;
+use crate::{
+    constants::{UBX_CHECKSUM_LEN, UBX_HEADER_LEN, UBX_SYNC_CHAR_1, UBX_SYNC_CHAR_2},
+    error::ChecksumError,
+};
+
+/// Class, message id and payload length of a UBX frame, as read from its header
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FrameHeader {
+    pub class: u8,
+    pub msg_id: u8,
+    pub payload_len: u16,
+}
+
+impl FrameHeader {
+    /// Length of the whole frame, from the sync chars up to and including the checksum
+    pub const fn frame_len(&self) -> usize {
+        UBX_HEADER_LEN + self.payload_len as usize + UBX_CHECKSUM_LEN
+    }
+}
+
+/// What a byte passed to [FrameDecoder::push] completed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameEvent {
+    /// Nothing yet, the byte is part of a frame header, payload or of the data between frames
+    NeedMore,
+    /// The header of a frame is complete, its payload and checksum follow
+    FrameStart(FrameHeader),
+    /// The last byte of a frame with a valid checksum
+    FrameComplete(FrameHeader),
+    /// The frame was dropped, either because its checksum does not match or because its payload
+    /// is longer than [FrameDecoder::with_max_payload_len] allows
+    Error(ChecksumError),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum State {
+    Sync1,
+    Sync2,
+    Class,
+    MsgId { class: u8 },
+    Len1 { class: u8, msg_id: u8 },
+    Len2 { class: u8, msg_id: u8, len_lo: u8 },
+    Payload { header: FrameHeader, remaining: u16 },
+    ChecksumA { header: FrameHeader },
+    ChecksumB { header: FrameHeader, ck_a: u8 },
+}
+
+/// Byte-at-a-time UBX framing state machine, without a buffer and without decoding packets
+///
+/// For integrations where the bytes are already stored elsewhere, e.g. by a DMA engine or in
+/// an interrupt handler, that only need to know where frames start and end. Feed every byte
+/// received to [push](FrameDecoder::push): a [FrameEvent::FrameStart] announces how many
+/// bytes the frame has, a [FrameEvent::FrameComplete] that they were all received with a
+/// valid checksum. The frame can then be passed to a [Parser](crate::Parser) or
+/// [verify_frame](crate::verify_frame).
+///
+/// Unlike the [Parser](crate::Parser), the decoder does not look back: after a dropped frame
+/// it searches for the next sync chars in the bytes that follow, not in the dropped ones.
+///
+/// ```
+/// use ublox::{FrameDecoder, FrameEvent, FrameHeader};
+///
+/// let ack_ack = [0xb5, 0x62, 0x05, 0x01, 0x02, 0x00, 0x06, 0x01, 0x0f, 0x38];
+/// let header = FrameHeader { class: 0x05, msg_id: 0x01, payload_len: 2 };
+///
+/// let mut decoder = FrameDecoder::new();
+/// let events: Vec<_> = ack_ack.iter().map(|&byte| decoder.push(byte)).collect();
+/// assert_eq!(events[5], FrameEvent::FrameStart(header));
+/// assert_eq!(events[9], FrameEvent::FrameComplete(header));
+/// assert_eq!(header.frame_len(), ack_ack.len());
+/// ```
+#[derive(Debug, Clone)]
+pub struct FrameDecoder {
+    state: State,
+    checksum: UbxChecksumCalc,
+    max_payload_len: u16,
+}
+
+impl Default for FrameDecoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FrameDecoder {
+    pub const fn new() -> Self {
+        Self {
+            state: State::Sync1,
+            checksum: UbxChecksumCalc::new(),
+            max_payload_len: u16::MAX,
+        }
+    }
+
+    /// Drop frames with a payload longer than `len` as soon as their header is complete, e.g.
+    /// the size of the receive buffer, instead of waiting for a bogus length to pass
+    pub const fn with_max_payload_len(mut self, len: u16) -> Self {
+        self.max_payload_len = len;
+        self
+    }
+
+    /// Whether the decoder is inside a frame, i.e. has seen sync chars and no end of frame yet
+    pub const fn in_frame(&self) -> bool {
+        !matches!(self.state, State::Sync1)
+    }
+
+    /// Drop the frame being decoded, if any, and search for the next sync chars
+    pub fn reset(&mut self) {
+        self.state = State::Sync1;
+    }
+
+    /// Advance the state machine by one received byte
+    pub fn push(&mut self, byte: u8) -> FrameEvent {
+        let (state, event) = match self.state {
+            State::Sync1 if byte == UBX_SYNC_CHAR_1 => (State::Sync2, FrameEvent::NeedMore),
+            State::Sync1 => (State::Sync1, FrameEvent::NeedMore),
+            State::Sync2 if byte == UBX_SYNC_CHAR_2 => {
+                self.checksum = UbxChecksumCalc::new();
+                (State::Class, FrameEvent::NeedMore)
+            },
+            // A repeated first sync char may still start a frame
+            State::Sync2 if byte == UBX_SYNC_CHAR_1 => (State::Sync2, FrameEvent::NeedMore),
+            State::Sync2 => (State::Sync1, FrameEvent::NeedMore),
+            State::Class => {
+                self.checksum.update_byte(byte);
+                (State::MsgId { class: byte }, FrameEvent::NeedMore)
+            },
+            State::MsgId { class } => {
+                self.checksum.update_byte(byte);
+                (
+                    State::Len1 {
+                        class,
+                        msg_id: byte,
+                    },
+                    FrameEvent::NeedMore,
+                )
+            },
+            State::Len1 { class, msg_id } => {
+                self.checksum.update_byte(byte);
+                (
+                    State::Len2 {
+                        class,
+                        msg_id,
+                        len_lo: byte,
+                    },
+                    FrameEvent::NeedMore,
+                )
+            },
+            State::Len2 {
+                class,
+                msg_id,
+                len_lo,
+            } => {
+                self.checksum.update_byte(byte);
+                let header = FrameHeader {
+                    class,
+                    msg_id,
+                    payload_len: u16::from_le_bytes([len_lo, byte]),
+                };
+                if header.payload_len > self.max_payload_len {
+                    let max = FrameHeader {
+                        payload_len: self.max_payload_len,
+                        ..header
+                    };
+                    let error = ChecksumError::InvalidLength {
+                        expect: max.frame_len(),
+                        got: header.frame_len(),
+                    };
+                    (State::Sync1, FrameEvent::Error(error))
+                } else if header.payload_len == 0 {
+                    (State::ChecksumA { header }, FrameEvent::FrameStart(header))
+                } else {
+                    let remaining = header.payload_len;
+                    (
+                        State::Payload { header, remaining },
+                        FrameEvent::FrameStart(header),
+                    )
+                }
+            },
+            State::Payload { header, remaining } => {
+                self.checksum.update_byte(byte);
+                let state = match remaining - 1 {
+                    0 => State::ChecksumA { header },
+                    remaining => State::Payload { header, remaining },
+                };
+                (state, FrameEvent::NeedMore)
+            },
+            State::ChecksumA { header } => (
+                State::ChecksumB { header, ck_a: byte },
+                FrameEvent::NeedMore,
+            ),
+            State::ChecksumB { header, ck_a } => {
+                let (calc_a, calc_b) = self.checksum.result();
+                let event = if (calc_a, calc_b) == (ck_a, byte) {
+                    FrameEvent::FrameComplete(header)
+                } else {
+                    FrameEvent::Error(ChecksumError::Mismatch {
+                        expect: u16::from_le_bytes([ck_a, byte]),
+                        got: u16::from_le_bytes([calc_a, calc_b]),
+                    })
+                };
+                (State::Sync1, event)
+            },
+        };
+        self.state = state;
+        event
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const ACK_ACK: [u8; 10] = [0xb5, 0x62, 0x05, 0x01, 0x02, 0x00, 0x06, 0x01, 0x0f, 0x38];
+
+    fn completed(decoder: &mut FrameDecoder, data: &[u8]) -> [usize; 3] {
+        let mut counts = [0; 3];
+        for &byte in data {
+            match decoder.push(byte) {
+                FrameEvent::NeedMore => {},
+                FrameEvent::FrameStart(_) => counts[0] += 1,
+                FrameEvent::FrameComplete(_) => counts[1] += 1,
+                FrameEvent::Error(_) => counts[2] += 1,
+            }
+        }
+        counts
+    }
+
+    #[test]
+    fn skips_noise_between_frames() {
+        let mut data = vec![0x00, 0xb5, 0xb5];
+        data.extend_from_slice(&ACK_ACK[1..]);
+        data.extend_from_slice(&[0x62, 0xff]);
+        data.extend_from_slice(&ACK_ACK);
+
+        let mut decoder = FrameDecoder::new();
+        assert_eq!(completed(&mut decoder, &data), [2, 2, 0]);
+        assert!(!decoder.in_frame());
+    }
+
+    #[test]
+    fn reports_checksum_mismatch_and_recovers() {
+        let mut data = ACK_ACK.to_vec();
+        data[9] = 0x39;
+        data.extend_from_slice(&ACK_ACK);
+
+        let mut decoder = FrameDecoder::new();
+        let events: Vec<_> = data.iter().map(|&byte| decoder.push(byte)).collect();
+        assert_eq!(
+            events[9],
+            FrameEvent::Error(ChecksumError::Mismatch {
+                expect: 0x390f,
+                got: 0x380f
+            })
+        );
+        assert!(matches!(events[19], FrameEvent::FrameComplete(_)));
+    }
+
+    #[test]
+    fn decodes_empty_payload() {
+        let poll = [0xb5, 0x62, 0x0a, 0x04, 0x00, 0x00, 0x0e, 0x34];
+        let mut decoder = FrameDecoder::new();
+        let events: Vec<_> = poll.iter().map(|&byte| decoder.push(byte)).collect();
+        let header = FrameHeader {
+            class: 0x0a,
+            msg_id: 0x04,
+            payload_len: 0,
+        };
+        assert_eq!(events[5], FrameEvent::FrameStart(header));
+        assert_eq!(events[7], FrameEvent::FrameComplete(header));
+    }
+
+    #[test]
+    fn drops_oversized_frames_at_the_header() {
+        let mut decoder = FrameDecoder::new().with_max_payload_len(1);
+        let events: Vec<_> = ACK_ACK[..6]
+            .iter()
+            .map(|&byte| decoder.push(byte))
+            .collect();
+        assert_eq!(
+            events[5],
+            FrameEvent::Error(ChecksumError::InvalidLength { expect: 9, got: 10 })
+        );
+        assert!(!decoder.in_frame());
+    }
+
+    #[test]
+    fn reset_drops_partial_frame() {
+        let mut decoder = FrameDecoder::new();
+        assert_eq!(completed(&mut decoder, &ACK_ACK[..7]), [1, 0, 0]);
+        assert!(decoder.in_frame());
+        decoder.reset();
+        assert_eq!(completed(&mut decoder, &ACK_ACK[7..]), [0, 0, 0]);
+        assert_eq!(completed(&mut decoder, &ACK_ACK), [1, 1, 0]);
+    }
+}
@@ -0,0 +1,76 @@
+//! [Kani](https://model-checking.github.io/kani/) proof harnesses for the framing code, run
+//! with `just kani`
+//!
+//! Unlike the proptest based tests, which sample random inputs, each harness proves for every
+//! possible input of the given size that the framing code neither panics nor accesses memory
+//! out of bounds. The sizes are kept small to bound the verification time, but large enough
+//! to hold a complete frame, a partial one and a buffer overflow.
+
+use super::{
+    spartn::{self, SPARTN_MAX_HEADER_SIZE},
+    verify_frame, FrameDecoder, FrameEvent, ParserBuilder,
+};
+use crate::constants::{UBX_CHECKSUM_LEN, UBX_HEADER_LEN};
+
+const INPUT_LEN: usize = 12;
+
+#[kani::proof]
+#[kani::unwind(13)]
+fn frame_decoder_never_panics() {
+    let data: [u8; INPUT_LEN] = kani::any();
+    let mut decoder = FrameDecoder::new().with_max_payload_len(kani::any());
+    for byte in data {
+        if let FrameEvent::FrameComplete(header) = decoder.push(byte) {
+            // A frame can only complete once all its bytes were pushed
+            assert!(header.frame_len() <= INPUT_LEN);
+        }
+    }
+}
+
+#[kani::proof]
+#[kani::unwind(13)]
+fn verify_frame_never_panics() {
+    let data: [u8; INPUT_LEN] = kani::any();
+    let len = kani::any_where(|&len: &usize| len <= INPUT_LEN);
+    if let Ok(parts) = verify_frame(&data[..len]) {
+        assert_eq!(UBX_HEADER_LEN + parts.payload.len() + UBX_CHECKSUM_LEN, len);
+    }
+}
+
+#[kani::proof]
+#[kani::unwind(80)]
+fn spartn_frame_len_never_panics() {
+    let header: [u8; SPARTN_MAX_HEADER_SIZE] = kani::any();
+    let len = kani::any_where(|&len: &usize| len <= SPARTN_MAX_HEADER_SIZE);
+    let _ = spartn::frame_len(&header[..len]);
+}
+
+#[kani::proof]
+#[kani::unwind(20)]
+fn ubx_parser_never_panics() {
+    let first: [u8; 8] = kani::any();
+    let second: [u8; 8] = kani::any();
+    // An empty filter drops valid frames without decoding their payload, which keeps the
+    // proof to the framing code. The buffer is too small for both inputs to exercise overflows.
+    let mut parser = ParserBuilder::new()
+        .with_filter(&[])
+        .with_fixed_buffer::<12>();
+    for data in [&first[..], &second[..]] {
+        let mut it = parser.consume_ubx(data);
+        while it.next().is_some() {}
+    }
+}
+
+#[kani::proof]
+#[kani::unwind(20)]
+fn multi_protocol_parser_never_panics() {
+    let first: [u8; 8] = kani::any();
+    let second: [u8; 8] = kani::any();
+    let mut parser = ParserBuilder::new()
+        .with_filter(&[])
+        .with_fixed_buffer::<12>();
+    for data in [&first[..], &second[..]] {
+        let mut it = parser.consume_ubx_rtcm_nmea_spartn(data);
+        while it.next().is_some() {}
+    }
+}
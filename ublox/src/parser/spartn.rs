@@ -0,0 +1,301 @@
+use crate::constants::SPARTN_HEADER_SIZE;
+
+/// SPARTN message types defined by SPARTN v2: OCB, HPAC, GAD, BPAC, EAS and proprietary.
+/// Other values are treated as false preambles, which keeps the framer from stalling on
+/// random 0x73 bytes.
+const KNOWN_MSG_TYPES: [u8; 6] = [0, 1, 2, 3, 4, 120];
+
+/// Length of the embedded authentication data in bytes, indexed by its length indicator
+const EMBEDDED_AUTH_LEN: [usize; 5] = [8, 12, 16, 32, 64];
+
+/// Worst case size of the fixed header plus the payload description block
+pub(crate) const SPARTN_MAX_HEADER_SIZE: usize = SPARTN_HEADER_SIZE + 8;
+
+/// Read `len` bits starting at bit `offset`, MSB first
+fn bits(data: &[u8], offset: usize, len: usize) -> u32 {
+    (offset..offset + len).fold(0, |acc, i| {
+        (acc << 1) | u32::from((data[i / 8] >> (7 - i % 8)) & 1)
+    })
+}
+
+/// Update `crc` with `len` bits of `data` starting at bit `offset`, MSB first, for a
+/// non-reflected generator `poly` of degree `width`
+fn crc_bits(crc: u32, data: &[u8], offset: usize, len: usize, poly: u32, width: u32) -> u32 {
+    let top = 1 << (width - 1);
+    let mask = (top << 1) - 1;
+    (offset..offset + len).fold(crc, |crc, i| {
+        let bit = (data[i / 8] >> (7 - i % 8)) & 1 == 1;
+        let crc = if (crc & top != 0) ^ bit {
+            (crc << 1) ^ poly
+        } else {
+            crc << 1
+        };
+        crc & mask
+    })
+}
+
+/// Frame CRC of a SPARTN header: CRC-4-ITU (x^4 + x + 1) over the message type, payload
+/// length, EAF and CRC type fields
+fn frame_crc(header: &[u8]) -> u8 {
+    crc_bits(0, header, 8, 20, 0x3, 4) as u8
+}
+
+pub(crate) enum SpartnFrameLen {
+    /// More bytes are needed to determine the frame length
+    Incomplete,
+    /// The preamble does not start a SPARTN frame
+    Invalid,
+    /// The frame CRC of the header does not match, `expect` being the received one
+    InvalidFrameCrc {
+        expect: u8,
+        got: u8,
+    },
+    Complete(usize),
+}
+
+/// Determine the total length of the SPARTN frame starting with `header`, which holds up to
+/// [SPARTN_MAX_HEADER_SIZE] bytes from the preamble on
+pub(crate) fn frame_len(header: &[u8]) -> SpartnFrameLen {
+    if header.len() < SPARTN_HEADER_SIZE + 1 {
+        return SpartnFrameLen::Incomplete;
+    }
+    let msg_type = bits(header, 8, 7) as u8;
+    if !KNOWN_MSG_TYPES.contains(&msg_type) {
+        return SpartnFrameLen::Invalid;
+    }
+    let expect = bits(header, 28, 4) as u8;
+    let got = frame_crc(header);
+    if expect != got {
+        return SpartnFrameLen::InvalidFrameCrc { expect, got };
+    }
+    let payload_len = bits(header, 15, 10) as usize;
+    let encrypted = bits(header, 25, 1) == 1;
+    let crc_len = bits(header, 26, 2) as usize + 1;
+    let full_time_tag = bits(header, 36, 1) == 1;
+
+    let desc_len = match (full_time_tag, encrypted) {
+        (false, false) => 4,
+        (true, false) | (false, true) => 6,
+        (true, true) => 8,
+    };
+    let auth_len = if encrypted {
+        if header.len() < SPARTN_HEADER_SIZE + desc_len {
+            return SpartnFrameLen::Incomplete;
+        }
+        let auth_offset = (SPARTN_HEADER_SIZE + desc_len) * 8 - 6;
+        let auth_indicator = bits(header, auth_offset, 3);
+        let auth_len_indicator = bits(header, auth_offset + 3, 3) as usize;
+        if auth_indicator <= 1 {
+            0
+        } else if let Some(len) = EMBEDDED_AUTH_LEN.get(auth_len_indicator) {
+            *len
+        } else {
+            return SpartnFrameLen::Invalid;
+        }
+    } else {
+        0
+    };
+    SpartnFrameLen::Complete(SPARTN_HEADER_SIZE + desc_len + payload_len + auth_len + crc_len)
+}
+
+/// Message CRC calculator of a SPARTN frame, over the bytes from the one following the
+/// preamble up to the CRC
+pub(crate) struct SpartnCrcCalc {
+    crc_type: SpartnCrcType,
+    crc: u32,
+}
+
+impl SpartnCrcCalc {
+    pub(crate) fn new(crc_type: SpartnCrcType) -> Self {
+        let crc = match crc_type {
+            SpartnCrcType::Crc32 => u32::MAX,
+            _ => 0,
+        };
+        Self { crc_type, crc }
+    }
+
+    pub(crate) fn update(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.crc = match self.crc_type {
+                // CRC-8-CCITT, CRC-16-CCITT (XMODEM) and CRC-24 (as RTCM3): MSB first
+                SpartnCrcType::Crc8 => crc_bits(self.crc, &[byte], 0, 8, 0x07, 8),
+                SpartnCrcType::Crc16 => crc_bits(self.crc, &[byte], 0, 8, 0x1021, 16),
+                SpartnCrcType::Crc24 => crc_bits(self.crc, &[byte], 0, 8, 0x86_4cfb, 24),
+                // CRC-32 (IEEE 802.3): reflected
+                SpartnCrcType::Crc32 => (0..8).fold(self.crc ^ u32::from(byte), |crc, _| {
+                    if crc & 1 == 1 {
+                        (crc >> 1) ^ 0xedb8_8320
+                    } else {
+                        crc >> 1
+                    }
+                }),
+            };
+        }
+    }
+
+    pub(crate) fn result(&self) -> u32 {
+        match self.crc_type {
+            SpartnCrcType::Crc32 => !self.crc,
+            _ => self.crc,
+        }
+    }
+}
+
+/// Time tag of a SPARTN message
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SpartnTimeTag {
+    /// 16-bit time tag, seconds modulo one hour or half a day depending on the message
+    Truncated(u16),
+    /// 32-bit time tag, GPS seconds since 2010-01-01
+    Full(u32),
+}
+
+/// Size of the message CRC that ends a SPARTN frame
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SpartnCrcType {
+    Crc8,
+    Crc16,
+    Crc24,
+    Crc32,
+}
+
+impl SpartnCrcType {
+    pub(crate) fn from_header(header: &[u8]) -> Self {
+        match bits(header, 26, 2) {
+            0 => SpartnCrcType::Crc8,
+            1 => SpartnCrcType::Crc16,
+            2 => SpartnCrcType::Crc24,
+            _ => SpartnCrcType::Crc32,
+        }
+    }
+
+    /// Size of the CRC in bytes
+    pub(crate) fn byte_len(self) -> usize {
+        match self {
+            SpartnCrcType::Crc8 => 1,
+            SpartnCrcType::Crc16 => 2,
+            SpartnCrcType::Crc24 => 3,
+            SpartnCrcType::Crc32 => 4,
+        }
+    }
+}
+
+/// A raw SPARTN frame, e.g. PointPerfect corrections
+///
+/// The frame is passed through as-is, typically to be forwarded to the receiver through
+/// UBX-RXM-PMP or a second port. Header fields are decoded on demand, the payload itself
+/// may be encrypted and is left opaque.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SpartnFrameRef<'a> {
+    /// The whole frame: preamble, header, payload description block, payload, embedded
+    /// authentication data and message CRC
+    pub data: &'a [u8],
+}
+
+impl<'a> SpartnFrameRef<'a> {
+    /// Message type, e.g. 0 for orbit, clock and bias (OCB) messages
+    pub fn msg_type(&self) -> u8 {
+        bits(self.data, 8, 7) as u8
+    }
+
+    /// Message subtype, e.g. the GNSS of an OCB message
+    pub fn msg_subtype(&self) -> u8 {
+        bits(self.data, 32, 4) as u8
+    }
+
+    /// Length of the payload in bytes
+    pub fn payload_len(&self) -> u16 {
+        bits(self.data, 15, 10) as u16
+    }
+
+    /// Whether the payload is encrypted and/or authenticated (EAF)
+    pub fn encrypted(&self) -> bool {
+        bits(self.data, 25, 1) == 1
+    }
+
+    pub fn crc_type(&self) -> SpartnCrcType {
+        SpartnCrcType::from_header(self.data)
+    }
+
+    pub fn time_tag(&self) -> SpartnTimeTag {
+        if self.full_time_tag() {
+            SpartnTimeTag::Full(bits(self.data, 37, 32))
+        } else {
+            SpartnTimeTag::Truncated(bits(self.data, 37, 16) as u16)
+        }
+    }
+
+    /// Solution identifier, i.e. the correction area
+    pub fn solution_id(&self) -> u8 {
+        bits(self.data, self.solution_offset(), 7) as u8
+    }
+
+    pub fn solution_processor_id(&self) -> u8 {
+        bits(self.data, self.solution_offset() + 7, 4) as u8
+    }
+
+    /// Identifier of the key the payload is encrypted with, if encrypted
+    pub fn encryption_id(&self) -> Option<u8> {
+        self.encrypted()
+            .then(|| bits(self.data, self.solution_offset() + 11, 4) as u8)
+    }
+
+    /// Encryption sequence number, if encrypted
+    pub fn encryption_sequence(&self) -> Option<u8> {
+        self.encrypted()
+            .then(|| bits(self.data, self.solution_offset() + 15, 6) as u8)
+    }
+
+    /// The (possibly encrypted) payload
+    pub fn payload(&self) -> &'a [u8] {
+        let start = (self.solution_offset() + 11) / 8 + if self.encrypted() { 2 } else { 0 };
+        &self.data[start..start + usize::from(self.payload_len())]
+    }
+
+    fn full_time_tag(&self) -> bool {
+        bits(self.data, 36, 1) == 1
+    }
+
+    fn solution_offset(&self) -> usize {
+        if self.full_time_tag() {
+            69
+        } else {
+            53
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spartn_crc_check_values() {
+        for (crc_type, check) in [
+            (SpartnCrcType::Crc8, 0xf4),
+            (SpartnCrcType::Crc16, 0x31c3),
+            (SpartnCrcType::Crc24, 0xcd_e703),
+            (SpartnCrcType::Crc32, 0xcbf4_3926),
+        ] {
+            let mut calc = SpartnCrcCalc::new(crc_type);
+            calc.update(b"1234");
+            calc.update(b"56789");
+            assert_eq!(calc.result(), check, "{crc_type:?}");
+        }
+    }
+
+    #[test]
+    fn test_spartn_frame_crc() {
+        // CRC-4-ITU of 0x12345, computed as the remainder of 0x123450 by x^4 + x + 1
+        let header = [0x73, 0x12, 0x34, 0x50];
+        let expect = (0..24).rev().fold(0x12_3450u32, |rem, i| {
+            if i >= 4 && rem & (1 << i) != 0 {
+                rem ^ (0x13 << (i - 4))
+            } else {
+                rem
+            }
+        });
+        assert_eq!(u32::from(frame_crc(&header)), expect);
+    }
+}
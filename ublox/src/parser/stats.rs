@@ -0,0 +1,269 @@
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+
+use super::buffer::DualBuffer;
+use super::FrameTimeout;
+use crate::UnderlyingBuffer;
+
+/// UBX classes up to and including HNR (0x28) are counted individually
+const UBX_CLASS_COUNT: usize = 0x29;
+
+/// Counters describing the health of the byte stream fed into a [Parser](crate::Parser),
+/// e.g. to diagnose a flaky serial link in the field.
+///
+/// ```
+/// use ublox::ParserBuilder;
+///
+/// let mut parser = ParserBuilder::new().with_fixed_buffer::<1024>();
+/// let data = [0x00, 0xb5, 0x62, 0x05, 0x01, 0x02, 0x00, 0x04, 0x05, 0x11, 0x38];
+/// let mut it = parser.consume_ubx(&data);
+/// while let Some(_packet) = it.next() {}
+/// drop(it);
+///
+/// let stats = parser.stats();
+/// assert_eq!(stats.bytes_consumed, 11);
+/// assert_eq!(stats.bytes_discarded, 1);
+/// assert_eq!(stats.ubx_frames(0x05), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParserStats {
+    /// Bytes passed to the `consume_*` methods
+    pub bytes_consumed: u64,
+    /// Bytes dropped without being part of a yielded frame, e.g. line noise or broken frames
+    pub bytes_discarded: u64,
+    /// UBX checksum and RTCM3 CRC mismatches
+    pub checksum_failures: u64,
+    /// Frame candidates that were abandoned to search for the next sync character
+    pub resyncs: u64,
+    pub rtcm_frames: u64,
+    pub nmea_sentences: u64,
+    pub spartn_frames: u64,
+    ubx_frames: [u64; UBX_CLASS_COUNT],
+    ubx_frames_other: u64,
+}
+
+impl Default for ParserStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ParserStats {
+    pub const fn new() -> Self {
+        Self {
+            bytes_consumed: 0,
+            bytes_discarded: 0,
+            checksum_failures: 0,
+            resyncs: 0,
+            rtcm_frames: 0,
+            nmea_sentences: 0,
+            spartn_frames: 0,
+            ubx_frames: [0; UBX_CLASS_COUNT],
+            ubx_frames_other: 0,
+        }
+    }
+
+    /// Number of valid UBX frames of the given class, whether or not the message is known to
+    /// the parser's protocol version
+    pub fn ubx_frames(&self, class: u8) -> u64 {
+        match self.ubx_frames.get(usize::from(class)) {
+            Some(count) => *count,
+            // Not a class defined by u-blox, all such frames are counted together
+            None => self.ubx_frames_other,
+        }
+    }
+
+    /// Number of valid UBX frames of any class
+    pub fn ubx_frames_total(&self) -> u64 {
+        self.ubx_frames.iter().sum::<u64>() + self.ubx_frames_other
+    }
+
+    pub(crate) fn count_ubx_frame(&mut self, class: u8) {
+        match self.ubx_frames.get_mut(usize::from(class)) {
+            Some(count) => *count += 1,
+            None => self.ubx_frames_other += 1,
+        }
+    }
+}
+
+/// Why the parser dropped bytes from its buffer
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiscardReason {
+    /// Bytes in front of the next sync char
+    Garbage,
+    /// A sync char that is not followed by a valid frame header
+    InvalidHeader,
+    /// A frame announcing a length beyond the protocol's maximum
+    InvalidLength,
+    /// A UBX frame with a bad checksum or an RTCM3 or SPARTN frame with a bad CRC
+    InvalidChecksum,
+    /// An NMEA sentence interrupted by the start of another frame
+    Truncated,
+    /// A frame too large for the parser's buffer
+    OutOfMemory,
+    /// A frame that did not complete within the
+    /// [partial frame timeout](crate::ParserBuilder::with_partial_frame_timeout)
+    PartialFrameTimeout,
+}
+
+/// Bytes dropped by the parser, passed to the callback set with
+/// `Parser::set_discard_callback` (`std` or `alloc`)
+///
+/// A frame failing its checksum is reported in full. Unless the parser
+/// [resyncs after the frame](crate::ResyncPolicy::AfterFrame), only its sync chars are dropped
+/// though, and the bytes after them are reported again when they are dropped while searching
+/// for the next frame.
+#[derive(Debug, Clone, Copy)]
+pub struct DiscardedBytes<'a> {
+    reason: DiscardReason,
+    head: &'a [u8],
+    tail: &'a [u8],
+}
+
+impl<'a> DiscardedBytes<'a> {
+    pub fn reason(&self) -> DiscardReason {
+        self.reason
+    }
+
+    pub fn len(&self) -> usize {
+        self.head.len() + self.tail.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The dropped bytes, which may be split between the parser's buffer and the data passed
+    /// to the `consume_*` call
+    pub fn as_slices(&self) -> (&'a [u8], &'a [u8]) {
+        (self.head, self.tail)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = u8> + 'a {
+        self.head.iter().chain(self.tail).copied()
+    }
+}
+
+/// Callback invoked for every run of bytes dropped by the parser
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub type DiscardCallback = Box<dyn FnMut(&DiscardedBytes<'_>) + Send>;
+
+/// Keeps the [ParserStats] and notifies the discard callback while consuming data
+pub(crate) struct StreamMonitor {
+    pub(crate) stats: ParserStats,
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub(crate) on_discard: Option<DiscardCallback>,
+    /// Bytes passed to the parser, unlike [ParserStats::bytes_consumed] never reset
+    pub(crate) bytes_fed: u64,
+    /// Stream offset of the last UBX frame handed to extraction
+    pub(crate) frame_start: u64,
+    /// Number of `consume_*` calls, wrapping
+    reads: u32,
+    /// Stream offset of the incomplete UBX frame waiting for more data, along with `reads` when
+    /// it was first seen
+    partial_frame: Option<(u64, u32)>,
+}
+
+impl StreamMonitor {
+    pub(crate) const fn new() -> Self {
+        Self {
+            stats: ParserStats::new(),
+            #[cfg(any(feature = "std", feature = "alloc"))]
+            on_discard: None,
+            bytes_fed: 0,
+            frame_start: 0,
+            reads: 0,
+            partial_frame: None,
+        }
+    }
+
+    /// Account for `len` new bytes passed to a `consume_*` method
+    pub(crate) fn feed(&mut self, len: usize) {
+        self.stats.bytes_consumed += len as u64;
+        self.bytes_fed += len as u64;
+        self.reads = self.reads.wrapping_add(1);
+    }
+
+    /// Whether the incomplete frame making up the `buffered` bytes waiting for more data has
+    /// waited longer than `timeout`
+    pub(crate) fn partial_frame_expired(&mut self, buffered: usize, timeout: FrameTimeout) -> bool {
+        let offset = self.bytes_fed - buffered as u64;
+        let first_read = match self.partial_frame {
+            Some((partial_offset, read)) if partial_offset == offset => read,
+            _ => {
+                self.partial_frame = Some((offset, self.reads));
+                self.reads
+            },
+        };
+        match timeout {
+            FrameTimeout::Bytes(bytes) => buffered > bytes,
+            FrameTimeout::Reads(reads) => self.reads.wrapping_sub(first_read) >= reads,
+        }
+    }
+
+    /// Drop the first `count` bytes of `buf`
+    pub(crate) fn discard<T: UnderlyingBuffer>(
+        &mut self,
+        buf: &mut DualBuffer<'_, T>,
+        count: usize,
+        reason: DiscardReason,
+    ) {
+        self.discard_frame(buf, count, count, reason);
+    }
+
+    /// Drop the first `count` bytes of `buf`, reporting the first `frame_len` bytes to the
+    /// callback, e.g. a whole frame failing its checksum of which only the sync chars are
+    /// dropped
+    pub(crate) fn discard_frame<T: UnderlyingBuffer>(
+        &mut self,
+        buf: &mut DualBuffer<'_, T>,
+        count: usize,
+        frame_len: usize,
+        reason: DiscardReason,
+    ) {
+        if count > 0 {
+            self.record(buf, count, frame_len.max(count), reason);
+            buf.drain(count);
+        }
+    }
+
+    /// Drop all buffered bytes
+    pub(crate) fn discard_all<T: UnderlyingBuffer>(
+        &mut self,
+        buf: &mut DualBuffer<'_, T>,
+        reason: DiscardReason,
+    ) {
+        if buf.len() > 0 {
+            self.record(buf, buf.len(), buf.len(), reason);
+            buf.clear();
+        }
+    }
+
+    #[cfg_attr(
+        not(any(feature = "std", feature = "alloc")),
+        allow(unused_variables, reason = "Only reported to the discard callback")
+    )]
+    fn record<T: UnderlyingBuffer>(
+        &mut self,
+        buf: &DualBuffer<'_, T>,
+        count: usize,
+        reported: usize,
+        reason: DiscardReason,
+    ) {
+        #[cfg(any(feature = "std", feature = "alloc"))]
+        if let Some(callback) = self.on_discard.as_mut() {
+            let (head, tail) = buf.peek_raw(0..reported);
+            callback(&DiscardedBytes { reason, head, tail });
+        }
+        self.stats.bytes_discarded += count as u64;
+        match reason {
+            DiscardReason::Garbage => {},
+            DiscardReason::InvalidChecksum => {
+                self.stats.checksum_failures += 1;
+                self.stats.resyncs += 1;
+            },
+            _ => self.stats.resyncs += 1,
+        }
+    }
+}
@@ -0,0 +1,66 @@
+//! The types most programs need, imported at once with `use ublox::prelude::*;`.
+//!
+//! It holds the parser and its builder, the packet enums and traits, the tags of the enabled
+//! protocols, the errors, and the packets and types commonly used to configure a receiver and
+//! read its position. What it exports follows the enabled features, e.g. the `std` writer
+//! extension and [read_position](crate::simple::read_position) are only there with `std`.
+//!
+//! ```
+//! use ublox::prelude::*;
+//!
+//! let mut parser = ParserBuilder::new()
+//!     .with_protocol::<Proto23>()
+//!     .with_fixed_buffer::<1024>();
+//! let mut it = parser.consume_ubx(&[0xb5, 0x62, 0x05, 0x01, 0x02, 0x00, 0x06, 0x01, 0x0f, 0x38]);
+//! let Some(Ok(packet)) = it.next() else { panic!() };
+//! assert_eq!(packet.class_and_msg_id(), (0x05, 0x01));
+//! ```
+
+#[doc(no_inline)]
+pub use crate::{
+    cfg_msg::{CfgMsgAllPortsBuilder, CfgMsgSinglePortBuilder},
+    cfg_rate::{AlignmentToReferenceTime, CfgRateBuilder},
+    cfg_rst::{CfgRstBuilder, NavBbrPredefinedMask, ResetMode},
+    cfg_val::{CfgLayerSet, CfgVal},
+    mon_ver::MonVer,
+    nav_pvt::common::NavPvtFlags,
+    ChecksumError, DateTimeError, FixedBuffer, GnssFixType, Heading, HeadingSource, MemWriterError,
+    Parser, ParserBuilder, ParserError, PositionECEF, PositionLLA, UbxPacket, UbxPacketCreator,
+    UbxPacketMeta, UbxPacketOwned, UbxPacketRecv, UbxPollRequest, UbxProtocol, Velocity,
+    VelocityECEF, VelocityNED,
+};
+
+#[cfg(feature = "ubx_proto14")]
+#[doc(no_inline)]
+pub use crate::proto14::Proto14;
+#[cfg(feature = "ubx_proto23")]
+#[doc(no_inline)]
+pub use crate::proto23::Proto23;
+#[cfg(feature = "ubx_proto27")]
+#[doc(no_inline)]
+pub use crate::proto27::Proto27;
+#[cfg(feature = "ubx_proto31")]
+#[doc(no_inline)]
+pub use crate::proto31::Proto31;
+#[cfg(feature = "ubx_proto33")]
+#[doc(no_inline)]
+pub use crate::proto33::Proto33;
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[doc(no_inline)]
+pub use crate::{
+    cfg_val::CfgValSetBuilder,
+    session::{Session, SessionEvent},
+    GrowableBuffer,
+};
+
+#[cfg(feature = "std")]
+#[doc(no_inline)]
+pub use crate::{
+    io::WriteUbxFrame,
+    simple::{positions, read_position},
+};
+
+// Both writer extensions have the same name, only their methods are brought into scope
+#[cfg(feature = "embedded-io")]
+pub use crate::io::embedded::WriteUbxFrame as _;
@@ -0,0 +1,236 @@
+//! Presets for the u-blox product families, to tailor the configuration to the receiver.
+//!
+//! A [ProductFamily] is detected from the [FirmwareIdentity] reported by UBX-MON-VER, from the
+//! module name when the receiver reports one and from the firmware product otherwise. Each
+//! family has the protocol version of its latest firmware, its [Limitations] and a default set
+//! of messages, which [ProductFamily::message_profile] turns into the frames enabling them:
+//!
+//! ```
+//! use ublox::{
+//!     firmware::{FirmwareIdentity, ProtocolVersion},
+//!     product::ProductFamily,
+//! };
+//!
+//! let identity = FirmwareIdentity::new(
+//!     "EXT CORE 1.00 (61b2dd)",
+//!     "00190000",
+//!     ["ROM BASE 0x118B2060", "FWVER=HPG 1.32", "PROTVER=27.31", "MOD=ZED-F9P"],
+//! );
+//! let family = ProductFamily::from_identity(&identity).unwrap();
+//! assert_eq!(family, ProductFamily::F9P);
+//! assert_eq!(family.protocol_version(), ProtocolVersion::new(27, 31));
+//! assert!(family.limitations().has_rawx);
+//! assert!(family.uses_cfg_valset());
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{
+    firmware::{FirmwareIdentity, ProtocolVersion},
+    msg_rate::{MsgRate, PORT_COUNT},
+};
+
+/// Output with every navigation solution on every port
+const EVERY: [u8; PORT_COUNT] = [1; PORT_COUNT];
+
+const NAV_PVT: MsgRate = MsgRate::from_ids(0x01, 0x07, EVERY);
+const NAV_DOP: MsgRate = MsgRate::from_ids(0x01, 0x04, EVERY);
+const NAV_SAT: MsgRate = MsgRate::from_ids(0x01, 0x35, EVERY);
+const NAV_RELPOSNED: MsgRate = MsgRate::from_ids(0x01, 0x3c, EVERY);
+const RXM_RAWX: MsgRate = MsgRate::from_ids(0x02, 0x15, EVERY);
+const RXM_SFRBX: MsgRate = MsgRate::from_ids(0x02, 0x13, EVERY);
+const TIM_TP: MsgRate = MsgRate::from_ids(0x0d, 0x01, EVERY);
+const ESF_STATUS: MsgRate = MsgRate::from_ids(0x10, 0x10, EVERY);
+const ESF_INS: MsgRate = MsgRate::from_ids(0x10, 0x15, EVERY);
+
+/// u-blox product family, see the [module documentation](self)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ProductFamily {
+    /// Standard precision u-blox M8, e.g. NEO-M8N or SAM-M8Q
+    M8N,
+    /// u-blox M8 timing, e.g. LEA-M8T
+    M8T,
+    /// u-blox M8 untethered dead reckoning, e.g. NEO-M8U
+    M8U,
+    /// u-blox M8 automotive dead reckoning, e.g. NEO-M8L
+    M8L,
+    /// u-blox M8 high precision, e.g. NEO-M8P
+    M8P,
+    /// Standard precision u-blox M9, e.g. NEO-M9N
+    M9N,
+    /// u-blox F9 high precision, e.g. ZED-F9P
+    F9P,
+    /// u-blox F9 high precision dead reckoning, e.g. ZED-F9R
+    F9R,
+    /// u-blox F9 timing, e.g. ZED-F9T
+    F9T,
+    /// Standard precision u-blox M10, e.g. MAX-M10S
+    M10S,
+    /// L-band correction receiver, e.g. NEO-D9S
+    D9S,
+}
+
+/// Known limitations of a [ProductFamily]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Limitations {
+    /// Highest navigation rate with several GNSS, in Hz. `None` for receivers without
+    /// navigation solution.
+    pub max_nav_rate_hz: Option<u8>,
+    /// Whether UBX-RXM-RAWX raw measurements are available
+    pub has_rawx: bool,
+    /// Whether the navigation solution fuses external sensors, reported by UBX-ESF-*
+    pub has_sensor_fusion: bool,
+}
+
+impl ProductFamily {
+    pub const ALL: [Self; 11] = [
+        Self::M8N,
+        Self::M8T,
+        Self::M8U,
+        Self::M8L,
+        Self::M8P,
+        Self::M9N,
+        Self::F9P,
+        Self::F9R,
+        Self::F9T,
+        Self::M10S,
+        Self::D9S,
+    ];
+
+    /// Family of the module name reported by UBX-MON-VER, e.g. `NEO-M8N-0` or `ZED-F9P-04B`
+    pub fn from_module(module: &str) -> Option<Self> {
+        let chip = module.split('-').nth(1)?;
+        Some(match chip {
+            "M8N" | "M8Q" | "M8M" | "M8C" | "M8J" | "M8W" => Self::M8N,
+            "M8T" => Self::M8T,
+            "M8U" => Self::M8U,
+            "M8L" => Self::M8L,
+            "M8P" => Self::M8P,
+            "M9N" => Self::M9N,
+            "F9P" => Self::F9P,
+            "F9R" => Self::F9R,
+            "F9T" => Self::F9T,
+            "M10S" | "M10Q" | "M10M" => Self::M10S,
+            "D9S" => Self::D9S,
+            _ => return None,
+        })
+    }
+
+    /// Family of a receiver, from its module name or else its firmware product. High precision
+    /// firmware without module name is told apart by its protocol version.
+    pub fn from_identity(identity: &FirmwareIdentity) -> Option<Self> {
+        if let Some(family) = identity.module().and_then(Self::from_module) {
+            return Some(family);
+        }
+        let (product, version) = identity.fw_version()?.split_once(' ')?;
+        let major = version.split('.').next()?;
+        Some(match (product, major) {
+            ("SPG", "3") => Self::M8N,
+            ("SPG", "4") => Self::M9N,
+            ("SPG", "5") => Self::M10S,
+            ("TIM", "1") => Self::M8T,
+            ("TIM", "2") => Self::F9T,
+            ("UDR", _) => Self::M8U,
+            ("ADR", _) => Self::M8L,
+            ("HPS", _) => Self::F9R,
+            ("PMP", _) => Self::D9S,
+            ("HPG", _) => {
+                if identity.protocol_version()? >= ProtocolVersion::new(27, 0) {
+                    Self::F9P
+                } else {
+                    Self::M8P
+                }
+            },
+            _ => return None,
+        })
+    }
+
+    /// Protocol version of the latest firmware of the family
+    pub fn protocol_version(self) -> ProtocolVersion {
+        match self {
+            Self::M8N => ProtocolVersion::new(18, 0),
+            Self::M8T => ProtocolVersion::new(22, 0),
+            Self::M8U => ProtocolVersion::new(19, 20),
+            Self::M8L => ProtocolVersion::new(19, 10),
+            Self::M8P => ProtocolVersion::new(20, 30),
+            Self::M9N => ProtocolVersion::new(32, 1),
+            Self::F9P => ProtocolVersion::new(27, 31),
+            Self::F9R => ProtocolVersion::new(33, 30),
+            Self::F9T => ProtocolVersion::new(29, 20),
+            Self::M10S => ProtocolVersion::new(34, 10),
+            Self::D9S => ProtocolVersion::new(24, 0),
+        }
+    }
+
+    /// Whether the receiver is configured with UBX-CFG-VALSET rather than UBX-CFG-MSG. True
+    /// for every family after u-blox M8, including the NEO-D9S despite its protocol 24.
+    pub fn uses_cfg_valset(self) -> bool {
+        !matches!(
+            self,
+            Self::M8N | Self::M8T | Self::M8U | Self::M8L | Self::M8P
+        )
+    }
+
+    pub fn limitations(self) -> Limitations {
+        let (max_nav_rate_hz, has_rawx, has_sensor_fusion) = match self {
+            Self::M8N => (Some(10), false, false),
+            Self::M8T => (Some(10), true, false),
+            Self::M8U => (Some(30), false, true),
+            Self::M8L => (Some(30), false, true),
+            Self::M8P => (Some(8), true, false),
+            Self::M9N => (Some(25), false, false),
+            Self::F9P => (Some(20), true, false),
+            Self::F9R => (Some(30), true, true),
+            Self::F9T => (Some(2), true, false),
+            Self::M10S => (Some(10), false, false),
+            Self::D9S => (None, false, false),
+        };
+        Limitations {
+            max_nav_rate_hz,
+            has_rawx,
+            has_sensor_fusion,
+        }
+    }
+
+    /// Messages worth enabling on the family, with every navigation solution. Empty for the
+    /// NEO-D9S, whose UBX-RXM-PMP correction output is not parsed by this crate.
+    pub fn default_messages(self) -> &'static [MsgRate] {
+        match self {
+            Self::M8N | Self::M9N | Self::M10S => &[NAV_PVT, NAV_DOP, NAV_SAT],
+            Self::M8T | Self::F9T => &[NAV_PVT, NAV_SAT, TIM_TP, RXM_RAWX, RXM_SFRBX],
+            Self::M8U | Self::M8L => &[NAV_PVT, NAV_DOP, NAV_SAT, ESF_STATUS],
+            Self::M8P => &[
+                NAV_PVT,
+                NAV_DOP,
+                NAV_SAT,
+                NAV_RELPOSNED,
+                RXM_RAWX,
+                RXM_SFRBX,
+            ],
+            Self::F9P => &[
+                NAV_PVT,
+                NAV_DOP,
+                NAV_SAT,
+                NAV_RELPOSNED,
+                RXM_RAWX,
+                RXM_SFRBX,
+            ],
+            Self::F9R => &[NAV_PVT, NAV_DOP, NAV_SAT, ESF_STATUS, ESF_INS, RXM_RAWX],
+            Self::D9S => &[],
+        }
+    }
+
+    /// Frames enabling the [default messages](ProductFamily::default_messages), with
+    /// UBX-CFG-MSG or UBX-CFG-VALSET depending on the family
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn message_profile(self) -> Vec<Vec<u8>> {
+        let cfg_valset = self.uses_cfg_valset();
+        self.default_messages()
+            .iter()
+            .filter_map(|rate| rate.packet_for(cfg_valset))
+            .collect()
+    }
+}
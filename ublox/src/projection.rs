@@ -0,0 +1,374 @@
+//! Serialization of a subset of a packet's fields, e.g. for trackers that only report a few
+//! values of UBX-NAV-PVT over a bandwidth constrained link.
+//!
+//! Fields are selected with the [Field] constants generated for each packet, e.g.
+//! `EsfMeas::FIELD_ITOW`, plus [Field::CLASS] and [Field::MSG_ID] for the
+//! [PacketRef](crate::proto23::PacketRef) enums and [UbxPacket](crate::UbxPacket).
+//!
+//! ```
+//! use ublox::{
+//!     esf_meas::EsfMeas,
+//!     projection::{Field, Projection},
+//!     proto23::Proto23,
+//!     ParserBuilder,
+//! };
+//!
+//! const TRACKER: Projection =
+//!     Projection::new(&[Field::MSG_ID, EsfMeas::FIELD_ITOW, EsfMeas::FIELD_CALIB_TAG]);
+//!
+//! let mut parser = ParserBuilder::new()
+//!     .with_protocol::<Proto23>()
+//!     .with_fixed_buffer::<1024>();
+//! let mut it = parser.consume_ubx(&[
+//!     0xb5, 0x62, 0x10, 0x02, 0x10, 0x00, 0xf3, 0x79, 0x81, 0x01, 0x18, 0x08, 0x00, 0x00, 0x4d,
+//!     0x64, 0x00, 0x0b, 0xd3, 0x94, 0x81, 0x01, 0xd5, 0xc6,
+//! ]);
+//! let Some(Ok(packet)) = it.next() else { panic!() };
+//!
+//! let json = serde_json::to_string(&TRACKER.apply(&packet)).unwrap();
+//! assert_eq!(json, r#"{"msg_id":2,"itow":25262579,"calib_tag":25269459}"#);
+//! ```
+//!
+//! Only the top level fields of a packet can be selected, the selected fields are serialized
+//! as a map in the order of the packet definition.
+
+use core::marker::PhantomData;
+
+use serde::ser::{
+    self, Error as _, Impossible, Serialize, SerializeMap, SerializeStruct, Serializer,
+};
+
+/// A top level field of a packet, as it is serialized
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Field(&'static str);
+
+impl Field {
+    /// Class of the [PacketRef](crate::proto23::PacketRef) enums, [UbxPacket](crate::UbxPacket)
+    /// and [UbxUnknownPacketRef](crate::UbxUnknownPacketRef)
+    pub const CLASS: Self = Self("class");
+    /// Message id of the [PacketRef](crate::proto23::PacketRef) enums,
+    /// [UbxPacket](crate::UbxPacket) and [UbxUnknownPacketRef](crate::UbxUnknownPacketRef)
+    pub const MSG_ID: Self = Self("msg_id");
+    /// Raw payload of an [UbxUnknownPacketRef](crate::UbxUnknownPacketRef)
+    pub const PAYLOAD: Self = Self("payload");
+
+    /// Field named `name` in the packet definition, used by the generated constants
+    pub(crate) const fn new(name: &'static str) -> Self {
+        Self(name)
+    }
+
+    /// The key the field is serialized with
+    pub const fn name(&self) -> &'static str {
+        self.0
+    }
+}
+
+/// A selection of packet fields, see the [module documentation](self)
+#[derive(Debug, Clone, Copy)]
+pub struct Projection<'p> {
+    fields: &'p [Field],
+}
+
+impl<'p> Projection<'p> {
+    pub const fn new(fields: &'p [Field]) -> Self {
+        Self { fields }
+    }
+
+    /// The selected fields
+    pub const fn fields(&self) -> &'p [Field] {
+        self.fields
+    }
+
+    /// Wrap `packet` to serialize only the selected fields
+    pub fn apply<'a, T: Serialize + ?Sized>(&'a self, packet: &'a T) -> Projected<'a, T> {
+        Projected {
+            fields: self.fields,
+            packet,
+        }
+    }
+}
+
+/// A packet serialized through a [Projection]
+#[derive(Debug, Clone, Copy)]
+pub struct Projected<'a, T: ?Sized> {
+    fields: &'a [Field],
+    packet: &'a T,
+}
+
+impl<T: Serialize + ?Sized> Serialize for Projected<'_, T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.packet.serialize(ProjectingSerializer {
+            inner: serializer,
+            fields: self.fields,
+        })
+    }
+}
+
+const NOT_A_PACKET: &str = "projections only apply to packets serialized as maps";
+const NOT_A_FIELD_NAME: &str = "projections require string field names";
+
+/// Rejects everything but the methods implemented by the serializer
+macro_rules! unsupported {
+    ($msg:expr; $($method:ident($($arg:ty),*);)*) => {
+        $(
+            fn $method(self, $(_: $arg),*) -> Result<Self::Ok, Self::Error> {
+                Err(Self::Error::custom($msg))
+            }
+        )*
+
+        fn collect_str<V: core::fmt::Display + ?Sized>(
+            self,
+            _: &V,
+        ) -> Result<Self::Ok, Self::Error> {
+            Err(Self::Error::custom($msg))
+        }
+
+        fn serialize_some<V: Serialize + ?Sized>(self, _: &V) -> Result<Self::Ok, Self::Error> {
+            Err(Self::Error::custom($msg))
+        }
+
+        fn serialize_newtype_struct<V: Serialize + ?Sized>(
+            self,
+            _: &'static str,
+            _: &V,
+        ) -> Result<Self::Ok, Self::Error> {
+            Err(Self::Error::custom($msg))
+        }
+
+        fn serialize_newtype_variant<V: Serialize + ?Sized>(
+            self,
+            _: &'static str,
+            _: u32,
+            _: &'static str,
+            _: &V,
+        ) -> Result<Self::Ok, Self::Error> {
+            Err(Self::Error::custom($msg))
+        }
+
+        fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+            Err(Self::Error::custom($msg))
+        }
+
+        fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, Self::Error> {
+            Err(Self::Error::custom($msg))
+        }
+
+        fn serialize_tuple_struct(
+            self,
+            _: &'static str,
+            _: usize,
+        ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+            Err(Self::Error::custom($msg))
+        }
+
+        fn serialize_tuple_variant(
+            self,
+            _: &'static str,
+            _: u32,
+            _: &'static str,
+            _: usize,
+        ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+            Err(Self::Error::custom($msg))
+        }
+
+        fn serialize_struct_variant(
+            self,
+            _: &'static str,
+            _: u32,
+            _: &'static str,
+            _: usize,
+        ) -> Result<Self::SerializeStructVariant, Self::Error> {
+            Err(Self::Error::custom($msg))
+        }
+    };
+}
+
+/// Passes the packet's map on to the inner serializer, filtering its entries
+struct ProjectingSerializer<'a, S> {
+    inner: S,
+    fields: &'a [Field],
+}
+
+impl<'a, S: Serializer> Serializer for ProjectingSerializer<'a, S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+    type SerializeSeq = Impossible<S::Ok, S::Error>;
+    type SerializeTuple = Impossible<S::Ok, S::Error>;
+    type SerializeTupleStruct = Impossible<S::Ok, S::Error>;
+    type SerializeTupleVariant = Impossible<S::Ok, S::Error>;
+    type SerializeMap = ProjectedMap<'a, S::SerializeMap>;
+    type SerializeStruct = ProjectedMap<'a, S::SerializeMap>;
+    type SerializeStructVariant = Impossible<S::Ok, S::Error>;
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Ok(ProjectedMap {
+            inner: self.inner.serialize_map(None)?,
+            fields: self.fields,
+            skip_value: false,
+        })
+    }
+
+    /// Structs such as [UbxUnknownPacketRef](crate::UbxUnknownPacketRef) are projected to maps,
+    /// as the number of selected fields is not known up front
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        self.serialize_map(None)
+    }
+
+    unsupported! {
+        NOT_A_PACKET;
+        serialize_bool(bool);
+        serialize_i8(i8);
+        serialize_i16(i16);
+        serialize_i32(i32);
+        serialize_i64(i64);
+        serialize_u8(u8);
+        serialize_u16(u16);
+        serialize_u32(u32);
+        serialize_u64(u64);
+        serialize_f32(f32);
+        serialize_f64(f64);
+        serialize_char(char);
+        serialize_str(&str);
+        serialize_bytes(&[u8]);
+        serialize_none();
+        serialize_unit();
+        serialize_unit_struct(&'static str);
+        serialize_unit_variant(&'static str, u32, &'static str);
+    }
+}
+
+struct ProjectedMap<'a, M> {
+    inner: M,
+    fields: &'a [Field],
+    skip_value: bool,
+}
+
+impl<M: SerializeMap> ProjectedMap<'_, M> {
+    fn is_selected<K: Serialize + ?Sized>(&self, key: &K) -> Result<bool, M::Error> {
+        key.serialize(FieldNameMatcher {
+            fields: self.fields,
+            _error: PhantomData,
+        })
+    }
+}
+
+impl<M: SerializeMap> SerializeMap for ProjectedMap<'_, M> {
+    type Ok = M::Ok;
+    type Error = M::Error;
+
+    fn serialize_key<K: Serialize + ?Sized>(&mut self, key: &K) -> Result<(), Self::Error> {
+        self.skip_value = !self.is_selected(key)?;
+        if self.skip_value {
+            Ok(())
+        } else {
+            self.inner.serialize_key(key)
+        }
+    }
+
+    fn serialize_value<V: Serialize + ?Sized>(&mut self, value: &V) -> Result<(), Self::Error> {
+        if self.skip_value {
+            Ok(())
+        } else {
+            self.inner.serialize_value(value)
+        }
+    }
+
+    fn serialize_entry<K, V>(&mut self, key: &K, value: &V) -> Result<(), Self::Error>
+    where
+        K: Serialize + ?Sized,
+        V: Serialize + ?Sized,
+    {
+        if self.is_selected(key)? {
+            self.inner.serialize_entry(key, value)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.end()
+    }
+}
+
+impl<M: SerializeMap> SerializeStruct for ProjectedMap<'_, M> {
+    type Ok = M::Ok;
+    type Error = M::Error;
+
+    fn serialize_field<V: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &V,
+    ) -> Result<(), Self::Error> {
+        if self.fields.contains(&Field(key)) {
+            self.inner.serialize_entry(key, value)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.end()
+    }
+}
+
+/// Checks whether a map key is one of the selected field names
+struct FieldNameMatcher<'a, E> {
+    fields: &'a [Field],
+    _error: PhantomData<E>,
+}
+
+impl<E: ser::Error> Serializer for FieldNameMatcher<'_, E> {
+    type Ok = bool;
+    type Error = E;
+    type SerializeSeq = Impossible<bool, E>;
+    type SerializeTuple = Impossible<bool, E>;
+    type SerializeTupleStruct = Impossible<bool, E>;
+    type SerializeTupleVariant = Impossible<bool, E>;
+    type SerializeMap = Impossible<bool, E>;
+    type SerializeStruct = Impossible<bool, E>;
+    type SerializeStructVariant = Impossible<bool, E>;
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, E> {
+        Err(E::custom(NOT_A_FIELD_NAME))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<bool, E> {
+        Ok(self.fields.iter().any(|field| field.0 == v))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, E> {
+        Err(E::custom(NOT_A_FIELD_NAME))
+    }
+
+    unsupported! {
+        NOT_A_FIELD_NAME;
+        serialize_bool(bool);
+        serialize_i8(i8);
+        serialize_i16(i16);
+        serialize_i32(i32);
+        serialize_i64(i64);
+        serialize_u8(u8);
+        serialize_u16(u16);
+        serialize_u32(u32);
+        serialize_u64(u64);
+        serialize_f32(f32);
+        serialize_f64(f64);
+        serialize_char(char);
+        serialize_bytes(&[u8]);
+        serialize_none();
+        serialize_unit();
+        serialize_unit_struct(&'static str);
+        serialize_unit_variant(&'static str, u32, &'static str);
+    }
+}
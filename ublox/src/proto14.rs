@@ -6,7 +6,7 @@ use crate::ubx_packets::packetref_proto14;
 use alloc::vec::Vec;
 
 #[doc(inline)]
-pub use crate::ubx_packets::packetref_proto14::PacketRef;
+pub use crate::ubx_packets::packetref_proto14::{PacketOwned, PacketRef};
 
 impl<'a> From<PacketRef<'a>> for crate::UbxPacket<'a> {
     fn from(packet: PacketRef<'a>) -> Self {
@@ -14,13 +14,34 @@ impl<'a> From<PacketRef<'a>> for crate::UbxPacket<'a> {
     }
 }
 
+impl From<PacketOwned> for crate::UbxPacketOwned {
+    fn from(packet: PacketOwned) -> Self {
+        crate::UbxPacketOwned::Proto14(packet)
+    }
+}
+
 /// Tag for protocol 14 packets
 pub struct Proto14;
 
+impl Proto14 {
+    /// The packet types of protocol 14 compiled in, see
+    /// [UbxProtocol::supported_messages](crate::UbxProtocol::supported_messages)
+    pub const fn supported_messages() -> &'static [crate::PacketMeta] {
+        packetref_proto14::SUPPORTED_MESSAGES
+    }
+}
+
+impl crate::sealed::Sealed for Proto14 {}
+
 impl crate::UbxProtocol for Proto14 {
     type PacketRef<'a> = PacketRef<'a>;
+    const VERSION: u8 = 14;
     const MAX_PAYLOAD_LEN: u16 = packetref_proto14::MAX_PAYLOAD_LEN;
 
+    fn supported_messages() -> &'static [crate::PacketMeta] {
+        Self::supported_messages()
+    }
+
     fn match_packet(
         class_id: u8,
         msg_id: u8,
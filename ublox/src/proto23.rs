@@ -6,7 +6,7 @@ use crate::ubx_packets::packetref_proto23;
 use alloc::vec::Vec;
 
 #[doc(inline)]
-pub use crate::ubx_packets::packetref_proto23::PacketRef;
+pub use crate::ubx_packets::packetref_proto23::{PacketOwned, PacketRef};
 
 impl<'a> From<PacketRef<'a>> for crate::UbxPacket<'a> {
     fn from(packet: PacketRef<'a>) -> Self {
@@ -14,14 +14,36 @@ impl<'a> From<PacketRef<'a>> for crate::UbxPacket<'a> {
     }
 }
 
+impl From<PacketOwned> for crate::UbxPacketOwned {
+    fn from(packet: PacketOwned) -> Self {
+        crate::UbxPacketOwned::Proto23(packet)
+    }
+}
+
 /// Tag for protocol 23 packets
 pub struct Proto23;
 
+impl Proto23 {
+    /// The packet types of protocol 23 compiled in, see
+    /// [UbxProtocol::supported_messages](crate::UbxProtocol::supported_messages)
+    pub const fn supported_messages() -> &'static [crate::PacketMeta] {
+        packetref_proto23::SUPPORTED_MESSAGES
+    }
+}
+
+impl crate::sealed::Sealed for Proto23 {}
+
 impl crate::UbxProtocol for Proto23 {
     type PacketRef<'a> = PacketRef<'a>;
 
+    const VERSION: u8 = 23;
+
     const MAX_PAYLOAD_LEN: u16 = packetref_proto23::MAX_PAYLOAD_LEN;
 
+    fn supported_messages() -> &'static [crate::PacketMeta] {
+        Self::supported_messages()
+    }
+
     fn match_packet(
         class_id: u8,
         msg_id: u8,
@@ -6,7 +6,7 @@ use crate::ubx_packets::packetref_proto31;
 use alloc::vec::Vec;
 
 #[doc(inline)]
-pub use crate::ubx_packets::packetref_proto31::PacketRef;
+pub use crate::ubx_packets::packetref_proto31::{PacketOwned, PacketRef};
 
 impl<'a> From<PacketRef<'a>> for crate::UbxPacket<'a> {
     fn from(packet: PacketRef<'a>) -> Self {
@@ -14,13 +14,34 @@ impl<'a> From<PacketRef<'a>> for crate::UbxPacket<'a> {
     }
 }
 
+impl From<PacketOwned> for crate::UbxPacketOwned {
+    fn from(packet: PacketOwned) -> Self {
+        crate::UbxPacketOwned::Proto31(packet)
+    }
+}
+
 /// Tag for protocol 31 packets
 pub struct Proto31;
 
+impl Proto31 {
+    /// The packet types of protocol 31 compiled in, see
+    /// [UbxProtocol::supported_messages](crate::UbxProtocol::supported_messages)
+    pub const fn supported_messages() -> &'static [crate::PacketMeta] {
+        packetref_proto31::SUPPORTED_MESSAGES
+    }
+}
+
+impl crate::sealed::Sealed for Proto31 {}
+
 impl crate::UbxProtocol for Proto31 {
     type PacketRef<'a> = PacketRef<'a>;
+    const VERSION: u8 = 31;
     const MAX_PAYLOAD_LEN: u16 = packetref_proto31::MAX_PAYLOAD_LEN;
 
+    fn supported_messages() -> &'static [crate::PacketMeta] {
+        Self::supported_messages()
+    }
+
     fn match_packet(
         class_id: u8,
         msg_id: u8,
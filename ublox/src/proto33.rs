@@ -6,7 +6,7 @@ use crate::ubx_packets::packetref_proto33;
 use alloc::vec::Vec;
 
 #[doc(inline)]
-pub use crate::ubx_packets::packetref_proto33::PacketRef;
+pub use crate::ubx_packets::packetref_proto33::{PacketOwned, PacketRef};
 
 impl<'a> From<PacketRef<'a>> for crate::UbxPacket<'a> {
     fn from(packet: PacketRef<'a>) -> Self {
@@ -14,13 +14,34 @@ impl<'a> From<PacketRef<'a>> for crate::UbxPacket<'a> {
     }
 }
 
+impl From<PacketOwned> for crate::UbxPacketOwned {
+    fn from(packet: PacketOwned) -> Self {
+        crate::UbxPacketOwned::Proto33(packet)
+    }
+}
+
 /// Tag for protocol 33 packets
 pub struct Proto33;
 
+impl Proto33 {
+    /// The packet types of protocol 33 compiled in, see
+    /// [UbxProtocol::supported_messages](crate::UbxProtocol::supported_messages)
+    pub const fn supported_messages() -> &'static [crate::PacketMeta] {
+        packetref_proto33::SUPPORTED_MESSAGES
+    }
+}
+
+impl crate::sealed::Sealed for Proto33 {}
+
 impl crate::UbxProtocol for Proto33 {
     type PacketRef<'a> = PacketRef<'a>;
+    const VERSION: u8 = 33;
     const MAX_PAYLOAD_LEN: u16 = packetref_proto33::MAX_PAYLOAD_LEN;
 
+    fn supported_messages() -> &'static [crate::PacketMeta] {
+        Self::supported_messages()
+    }
+
     fn match_packet(
         class_id: u8,
         msg_id: u8,
@@ -0,0 +1,182 @@
+//! Recording of both directions of a receiver connection, to replay and debug configuration
+//! sequences.
+//!
+//! A recording starts with [MAGIC] and holds [Record]s in the order they happened: the bytes
+//! written to the receiver tagged [Direction::Tx], and the bytes read from it tagged
+//! [Direction::Rx], each with its timestamp. The bytes are stored as they were written or
+//! read, so that a recording also keeps partial frames and data of other protocols. Like
+//! [Session](crate::session::Session), the recording does no I/O itself: [Record::header] and
+//! the record data are written to any sink, and [RecordReader] reads them back from a slice.
+//!
+//! ```
+//! # #[cfg(feature = "std")] {
+//! use core::time::Duration;
+//! use ublox::{recording::{Direction, Record, RecordReader, MAGIC}, ParserBuilder};
+//!
+//! let mut file = MAGIC.to_vec();
+//! let poll = [0xb5, 0x62, 0x0a, 0x04, 0x00, 0x00, 0x0e, 0x34];
+//! Record::new(Direction::Tx, Duration::from_millis(10), &poll).write_to(&mut file)?;
+//! let nak = [0xb5, 0x62, 0x05, 0x00, 0x02, 0x00, 0x0a, 0x04, 0x15, 0x3e];
+//! Record::new(Direction::Rx, Duration::from_millis(42), &nak).write_to(&mut file)?;
+//!
+//! // Replay the receiver side through a parser
+//! let mut parser = ParserBuilder::new().with_fixed_buffer::<1024>();
+//! for record in RecordReader::new(&file).unwrap() {
+//!     let record = record.unwrap();
+//!     if record.direction == Direction::Rx {
+//!         let mut it = parser.consume_ubx(record.data);
+//!         assert!(it.next().unwrap().is_ok());
+//!     }
+//! }
+//! # }
+//! # Ok::<(), std::io::Error>(())
+//! ```
+
+use core::{fmt, time::Duration};
+
+/// Start of a recording, followed by the format version
+pub const MAGIC: [u8; 8] = *b"UBXREC\x00\x01";
+
+/// Length of [Record::header]: direction, timestamp in µs and data length
+pub const RECORD_HEADER_LEN: usize = 1 + 8 + 4;
+
+/// Direction of the bytes of a [Record]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Direction {
+    /// Written by the host to the receiver
+    Tx = b'T',
+    /// Read by the host from the receiver
+    Rx = b'R',
+}
+
+/// Bytes written to or read from the receiver at once
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Record<'a> {
+    pub direction: Direction,
+    /// Time the bytes were written or read, since an arbitrary epoch, with µs resolution
+    pub timestamp: Duration,
+    pub data: &'a [u8],
+}
+
+impl<'a> Record<'a> {
+    pub const fn new(direction: Direction, timestamp: Duration, data: &'a [u8]) -> Self {
+        Self {
+            direction,
+            timestamp,
+            data,
+        }
+    }
+
+    /// The bytes preceding [Record::data] in a recording. Timestamps past about 584,000 years
+    /// and data longer than 4 GiB are saturated.
+    pub fn header(&self) -> [u8; RECORD_HEADER_LEN] {
+        let micros = u64::try_from(self.timestamp.as_micros()).unwrap_or(u64::MAX);
+        let len = u32::try_from(self.data.len()).unwrap_or(u32::MAX);
+        let mut header = [0; RECORD_HEADER_LEN];
+        header[0] = self.direction as u8;
+        header[1..9].copy_from_slice(&micros.to_le_bytes());
+        header[9..].copy_from_slice(&len.to_le_bytes());
+        header
+    }
+
+    /// Length of the record in a recording, header included
+    pub const fn encoded_len(&self) -> usize {
+        RECORD_HEADER_LEN + self.data.len()
+    }
+
+    /// Append the record to `out`, e.g. a recording file opened after writing [MAGIC]
+    #[cfg(feature = "std")]
+    pub fn write_to<W: std::io::Write + ?Sized>(&self, out: &mut W) -> std::io::Result<()> {
+        out.write_all(&self.header())?;
+        out.write_all(self.data)
+    }
+}
+
+/// Invalid recording read by [RecordReader]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordingError {
+    /// The data does not start with [MAGIC]
+    BadMagic,
+    /// A record has an unknown direction tag, at the given offset
+    InvalidDirection { offset: usize, tag: u8 },
+    /// The last record at the given offset is cut short, e.g. the recording was still being
+    /// written
+    Truncated { offset: usize },
+}
+
+impl fmt::Display for RecordingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BadMagic => f.write_str("not a UBX recording"),
+            Self::InvalidDirection { offset, tag } => {
+                write!(f, "invalid direction {tag:#04x} at offset {offset}")
+            },
+            Self::Truncated { offset } => write!(f, "truncated record at offset {offset}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RecordingError {}
+
+/// Iterates over the [Record]s of a recording, in the order they were written. Stops after
+/// the first error.
+#[derive(Debug, Clone)]
+pub struct RecordReader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> RecordReader<'a> {
+    /// Read the recording in `data`, which must start with [MAGIC]
+    pub fn new(data: &'a [u8]) -> Result<Self, RecordingError> {
+        if !data.starts_with(&MAGIC) {
+            return Err(RecordingError::BadMagic);
+        }
+        Ok(Self {
+            data,
+            offset: MAGIC.len(),
+        })
+    }
+
+    /// Offset of the next record in the recording
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    fn read(&self) -> Result<Record<'a>, RecordingError> {
+        let offset = self.offset;
+        let rest = &self.data[offset..];
+        let header = rest
+            .get(..RECORD_HEADER_LEN)
+            .ok_or(RecordingError::Truncated { offset })?;
+        let direction = match header[0] {
+            tag if tag == Direction::Tx as u8 => Direction::Tx,
+            tag if tag == Direction::Rx as u8 => Direction::Rx,
+            tag => return Err(RecordingError::InvalidDirection { offset, tag }),
+        };
+        let micros = u64::from_le_bytes(header[1..9].try_into().unwrap());
+        let len = u32::from_le_bytes(header[9..].try_into().unwrap()) as usize;
+        let data = rest[RECORD_HEADER_LEN..]
+            .get(..len)
+            .ok_or(RecordingError::Truncated { offset })?;
+        Ok(Record::new(direction, Duration::from_micros(micros), data))
+    }
+}
+
+impl<'a> Iterator for RecordReader<'a> {
+    type Item = Result<Record<'a>, RecordingError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset >= self.data.len() {
+            return None;
+        }
+        let record = self.read();
+        self.offset = match record {
+            Ok(record) => self.offset + record.encoded_len(),
+            Err(_) => self.data.len(),
+        };
+        Some(record)
+    }
+}
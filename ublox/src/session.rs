@@ -0,0 +1,363 @@
+//! Request tracking for a receiver connection, without doing any I/O.
+//!
+//! A [Session] owns a [Parser] and keeps track of the polls and configuration messages sent to
+//! the receiver. It never reads or writes anything itself, the driver of the connection, e.g. a
+//! tokio task, a blocking serial loop or an embedded main loop, passes the received bytes to
+//! [Session::handle_input], writes the frames returned by [Session::poll_output] and calls
+//! [Session::handle_timeout] with the current time, at the latest when
+//! [Session::poll_timeout] expires. Timestamps are [Duration]s since an arbitrary epoch, so
+//! that any monotonic clock can be used.
+//!
+//! ```
+//! use core::time::Duration;
+//! use ublox::{mon_ver::MonVer, session::{Session, SessionEvent}, ParserBuilder};
+//!
+//! let mut session = Session::new(ParserBuilder::new().with_fixed_buffer::<1024>())
+//!     .with_timeout(Duration::from_millis(500));
+//! session.handle_timeout(Duration::ZERO);
+//! session.poll::<MonVer>();
+//!
+//! // Write this to the receiver
+//! let request = session.poll_output().unwrap();
+//! assert_eq!(request, [0xb5, 0x62, 0x0a, 0x04, 0x00, 0x00, 0x0e, 0x34]);
+//!
+//! // No answer, the poll is sent again
+//! assert_eq!(session.poll_timeout(), Some(Duration::from_millis(500)));
+//! session.handle_timeout(Duration::from_millis(500));
+//! assert_eq!(session.poll_output().unwrap(), request);
+//!
+//! // The receiver rejects it
+//! let mut it = session.handle_input(&[0xb5, 0x62, 0x05, 0x00, 0x02, 0x00, 0x0a, 0x04, 0x15, 0x3e]);
+//! while let Some(_packet) = it.next() {}
+//! drop(it);
+//! assert_eq!(
+//!     session.poll_event(),
+//!     Some(SessionEvent::Nacked { class: 0x0a, msg_id: 0x04 })
+//! );
+//! ```
+
+use core::time::Duration;
+
+#[cfg(feature = "alloc")]
+use alloc::{collections::VecDeque, vec::Vec};
+#[cfg(not(feature = "alloc"))]
+use std::collections::VecDeque;
+
+use crate::{
+    ack::{AckAck, AckNak},
+    constants::{UBX_CLASS_OFFSET, UBX_MSG_ID_OFFSET},
+    parser::{decode_frame_ubx, CheckedPacket, DefaultProtocol},
+    Parser, ParserError, UbxPacket, UbxPacketMeta, UbxParserIter, UbxPollRequest, UbxProtocol,
+    UbxUnknownPacketRef, UnderlyingBuffer,
+};
+
+/// Outcome of a request, returned by [Session::poll_event]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionEvent {
+    /// The receiver acknowledged a configuration message
+    Acked { class: u8, msg_id: u8 },
+    /// The receiver rejected a configuration message or a poll
+    Nacked { class: u8, msg_id: u8 },
+    /// The polled message was received
+    Responded { class: u8, msg_id: u8 },
+    /// The receiver did not answer any of the attempts
+    TimedOut { class: u8, msg_id: u8 },
+}
+
+/// What completes a request
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Expect {
+    Ack,
+    Response,
+}
+
+struct Request {
+    id: u32,
+    class: u8,
+    msg_id: u8,
+    expect: Expect,
+    frame: Vec<u8>,
+    attempts: u32,
+    /// Set once the frame was returned by [Session::poll_output]
+    deadline: Option<Duration>,
+}
+
+struct Schedule {
+    class: u8,
+    msg_id: u8,
+    period: Duration,
+    next: Duration,
+}
+
+/// State of a [Session] updated by the frames passing through [SessionIter]
+struct SessionState {
+    now: Duration,
+    timeout: Duration,
+    attempts: u32,
+    next_id: u32,
+    requests: Vec<Request>,
+    schedules: Vec<Schedule>,
+    /// Frames to send, along with the id of their request
+    output: VecDeque<(Vec<u8>, Option<u32>)>,
+    events: VecDeque<SessionEvent>,
+}
+
+impl SessionState {
+    fn queue(&mut self, frame: Vec<u8>, expect: Option<Expect>) {
+        let Some(expect) = expect else {
+            self.output.push_back((frame, None));
+            return;
+        };
+        let id = self.next_id;
+        self.next_id = self.next_id.wrapping_add(1);
+        self.requests.push(Request {
+            id,
+            class: frame[UBX_CLASS_OFFSET],
+            msg_id: frame[UBX_MSG_ID_OFFSET],
+            expect,
+            frame: frame.clone(),
+            attempts: 1,
+            deadline: None,
+        });
+        self.output.push_back((frame, Some(id)));
+    }
+
+    /// Completes the oldest sent request for `(class, msg_id)` accepted by `matches`
+    fn complete(
+        &mut self,
+        class: u8,
+        msg_id: u8,
+        matches: impl Fn(Expect) -> bool,
+        event: SessionEvent,
+    ) {
+        let pos = self.requests.iter().position(|r| {
+            r.deadline.is_some() && (r.class, r.msg_id) == (class, msg_id) && matches(r.expect)
+        });
+        if let Some(pos) = pos {
+            self.requests.remove(pos);
+            self.events.push_back(event);
+        }
+    }
+
+    fn on_frame(&mut self, frame: &UbxUnknownPacketRef<'_>) {
+        match (frame.class, frame.msg_id, frame.payload) {
+            (AckAck::CLASS, AckAck::ID, &[class, msg_id]) => self.complete(
+                class,
+                msg_id,
+                |expect| expect == Expect::Ack,
+                SessionEvent::Acked { class, msg_id },
+            ),
+            (AckNak::CLASS, AckNak::ID, &[class, msg_id]) => self.complete(
+                class,
+                msg_id,
+                |_| true,
+                SessionEvent::Nacked { class, msg_id },
+            ),
+            (class, msg_id, _) => self.complete(
+                class,
+                msg_id,
+                |expect| expect == Expect::Response,
+                SessionEvent::Responded { class, msg_id },
+            ),
+        }
+    }
+}
+
+/// Sans-IO state machine for a receiver connection, see the [module documentation](self)
+pub struct Session<T: UnderlyingBuffer, P: UbxProtocol = DefaultProtocol> {
+    parser: Parser<T, P>,
+    state: SessionState,
+}
+
+impl<T: UnderlyingBuffer, P: UbxProtocol> Session<T, P> {
+    /// Create a session waiting 1 s for each answer, sending requests up to 3 times
+    pub fn new(parser: Parser<T, P>) -> Self {
+        Self {
+            parser,
+            state: SessionState {
+                now: Duration::ZERO,
+                timeout: Duration::from_secs(1),
+                attempts: 3,
+                next_id: 0,
+                requests: Vec::new(),
+                schedules: Vec::new(),
+                output: VecDeque::new(),
+                events: VecDeque::new(),
+            },
+        }
+    }
+
+    /// Time to wait for the answer to a request before sending it again
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.state.timeout = timeout;
+        self
+    }
+
+    /// How often a request is sent before reporting [SessionEvent::TimedOut], at least once
+    pub fn with_attempts(mut self, attempts: u32) -> Self {
+        self.state.attempts = attempts.max(1);
+        self
+    }
+
+    /// Queue a frame which the receiver does not answer, e.g. UBX-CFG-RST
+    pub fn send(&mut self, frame: &[u8]) {
+        self.state.queue(frame.to_vec(), None);
+    }
+
+    /// Queue a configuration frame, completed by the receiver's UBX-ACK-ACK or UBX-ACK-NAK.
+    /// `frame` is a complete UBX frame, e.g. from a builder's `into_packet_bytes()`.
+    pub fn send_cfg(&mut self, frame: &[u8]) {
+        self.state.queue(frame.to_vec(), Some(Expect::Ack));
+    }
+
+    /// Queue a poll request for `M`, completed by the next `M` received or a UBX-ACK-NAK
+    pub fn poll<M: UbxPacketMeta>(&mut self) {
+        self.poll_class(M::CLASS, M::ID);
+    }
+
+    /// Poll `M` every `period`, starting with the next call to [Session::handle_timeout].
+    /// No poll is sent while the previous one is still outstanding.
+    pub fn schedule<M: UbxPacketMeta>(&mut self, period: Duration) {
+        self.unschedule::<M>();
+        self.state.schedules.push(Schedule {
+            class: M::CLASS,
+            msg_id: M::ID,
+            period,
+            next: self.state.now,
+        });
+    }
+
+    /// Stop the periodic polls of `M`
+    pub fn unschedule<M: UbxPacketMeta>(&mut self) {
+        self.state
+            .schedules
+            .retain(|s| (s.class, s.msg_id) != (M::CLASS, M::ID));
+    }
+
+    fn poll_class(&mut self, class: u8, msg_id: u8) {
+        let frame = UbxPollRequest::new(class, msg_id).into_packet_bytes();
+        self.state.queue(frame.to_vec(), Some(Expect::Response));
+    }
+
+    /// Appends `data` to the parser's buffer and returns an iterator yielding the received
+    /// [UbxPackets](UbxPacket), updating the outstanding requests as frames pass through it
+    pub fn handle_input<'a>(&'a mut self, data: &'a [u8]) -> SessionIter<'a, T, P> {
+        SessionIter {
+            inner: self.parser.consume_ubx(data),
+            state: &mut self.state,
+        }
+    }
+
+    /// Advance the session's clock to `now`, resending unanswered requests and queueing the
+    /// scheduled polls that are due
+    pub fn handle_timeout(&mut self, now: Duration) {
+        let state = &mut self.state;
+        state.now = now;
+
+        let mut i = 0;
+        while i < state.requests.len() {
+            let request = &mut state.requests[i];
+            match request.deadline {
+                Some(deadline) if deadline <= now && request.attempts < state.attempts => {
+                    request.attempts += 1;
+                    request.deadline = None;
+                    state
+                        .output
+                        .push_back((request.frame.clone(), Some(request.id)));
+                },
+                Some(deadline) if deadline <= now => {
+                    let request = state.requests.remove(i);
+                    state.events.push_back(SessionEvent::TimedOut {
+                        class: request.class,
+                        msg_id: request.msg_id,
+                    });
+                    continue;
+                },
+                _ => {},
+            }
+            i += 1;
+        }
+
+        let mut due = Vec::new();
+        for schedule in &mut state.schedules {
+            if schedule.next > now {
+                continue;
+            }
+            // Skip missed polls instead of sending them in a burst
+            while schedule.next <= now {
+                schedule.next += schedule.period.max(Duration::from_millis(1));
+            }
+            let outstanding = state
+                .requests
+                .iter()
+                .any(|r| (r.class, r.msg_id) == (schedule.class, schedule.msg_id));
+            if !outstanding {
+                due.push((schedule.class, schedule.msg_id));
+            }
+        }
+        for (class, msg_id) in due {
+            self.poll_class(class, msg_id);
+        }
+    }
+
+    /// Time at which [Session::handle_timeout] needs to be called next, if anything is pending
+    pub fn poll_timeout(&self) -> Option<Duration> {
+        let deadlines = self.state.requests.iter().filter_map(|r| r.deadline);
+        let schedules = self.state.schedules.iter().map(|s| s.next);
+        deadlines.chain(schedules).min()
+    }
+
+    /// Next frame to write to the receiver. The answer is awaited from the time of the last
+    /// [Session::handle_timeout] call on.
+    pub fn poll_output(&mut self) -> Option<Vec<u8>> {
+        let (frame, id) = self.state.output.pop_front()?;
+        if let Some(request) = self.state.requests.iter_mut().find(|r| Some(r.id) == id) {
+            request.deadline = Some(self.state.now + self.state.timeout);
+        }
+        Some(frame)
+    }
+
+    /// Next completed or failed request
+    pub fn poll_event(&mut self) -> Option<SessionEvent> {
+        self.state.events.pop_front()
+    }
+
+    /// Whether no request is outstanding and nothing is left to send
+    pub fn is_idle(&self) -> bool {
+        self.state.requests.is_empty() && self.state.output.is_empty()
+    }
+
+    pub fn parser(&self) -> &Parser<T, P> {
+        &self.parser
+    }
+
+    pub fn parser_mut(&mut self) -> &mut Parser<T, P> {
+        &mut self.parser
+    }
+}
+
+/// Iterator over the packets passed to [Session::handle_input]
+pub struct SessionIter<'a, T: UnderlyingBuffer, P: UbxProtocol> {
+    inner: UbxParserIter<'a, T, P>,
+    state: &'a mut SessionState,
+}
+
+impl<T: UnderlyingBuffer, P: UbxProtocol> SessionIter<'_, T, P> {
+    #[allow(
+        clippy::should_implement_trait,
+        reason = "This is a lending iterator, which is not in std"
+    )]
+    /// Parse and return the next [UbxPacket] in the buffer, or `None` if the buffer cannot yield
+    /// another full [UbxPacket]
+    pub fn next(&mut self) -> Option<Result<UbxPacket<'_>, ParserError>> {
+        let frame = match self.inner.next_frame()? {
+            Ok(frame) => frame,
+            Err(e) => return Some(Err(e)),
+        };
+        // A corrupted frame must not complete a request
+        if frame.checksum_valid {
+            self.state.on_frame(&frame.packet);
+        }
+        Some(decode_frame_ubx::<P>(frame).and_then(CheckedPacket::into_verified))
+    }
+}
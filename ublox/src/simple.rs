@@ -0,0 +1,169 @@
+//! Blocking reading of positions from a serial port, the shortest way to get a fix.
+//!
+//! [read_position] configures the receiver to output UBX-NAV-PVT once per second, with
+//! UBX-CFG-RATE and UBX-CFG-MSG or with UBX-CFG-VALSET depending on the protocol version, and
+//! returns the first valid position. [positions] does the same, but
+//! keeps returning positions as they arrive. Any [std::io::Read] and [std::io::Write]
+//! implementation will do as port, e.g. the `Box<dyn SerialPort>` of the `serialport` crate:
+//!
+//! ```no_run
+//! # fn open_port() -> std::io::Cursor<Vec<u8>> { unimplemented!() }
+//! use std::time::Duration;
+//!
+//! let mut port = open_port();
+//! let position = ublox::simple::read_position(&mut port, Duration::from_secs(60))?;
+//! println!("{} {}", position.lat, position.lon);
+//! # Ok::<(), std::io::Error>(())
+//! ```
+//!
+//! Both are thin wrappers around the [Parser] and [EpochAggregator], use these directly for
+//! anything more than positions.
+
+use std::{
+    collections::VecDeque,
+    io::{self, Read, Write},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    cfg_rate::{AlignmentToReferenceTime, CfgRateBuilder},
+    cfg_val::{CfgLayerSet, CfgTransaction, CfgVal, CfgValSetBuilder},
+    epoch::{EpochAggregator, EpochComponents, EpochPolicy, PvtSolution},
+    io::WriteUbxFrame,
+    msg_rate::{MsgRate, PORT_COUNT},
+    parser::DefaultProtocol,
+    GnssFixType, Parser, UbxProtocol,
+};
+
+/// UBX-NAV-PVT with every navigation solution
+const NAV_PVT: MsgRate = MsgRate::from_ids(0x01, 0x07, [1; PORT_COUNT]);
+
+/// Configures the receiver behind `port` and waits up to `timeout` for a valid position
+///
+/// Fails with [io::ErrorKind::TimedOut] if none arrived in time, or with
+/// [io::ErrorKind::UnexpectedEof] if the port was closed.
+pub fn read_position<T: Read + Write>(port: T, timeout: Duration) -> io::Result<PvtSolution> {
+    positions(port)?.next_within(timeout)
+}
+
+/// Configures the receiver behind `port` and returns an iterator over its valid positions
+pub fn positions<T: Read + Write>(port: T) -> io::Result<Positions<T>> {
+    Positions::new(port)
+}
+
+/// Iterator over the valid positions of UBX-NAV-PVT read from a port, see [positions]
+///
+/// Invalid and incomplete packets are skipped, the iterator ends when the port is closed.
+pub struct Positions<T, P: UbxProtocol = DefaultProtocol> {
+    port: T,
+    parser: Parser<Vec<u8>, P>,
+    aggregator: EpochAggregator,
+    positions: VecDeque<PvtSolution>,
+    buf: [u8; 1024],
+}
+
+impl<T: Read + Write, P: UbxProtocol> Positions<T, P> {
+    /// Enables UBX-NAV-PVT at 1 Hz, with UBX-CFG-RATE and UBX-CFG-MSG before protocol 27 and
+    /// with UBX-CFG-VALSET from then on
+    pub fn new(mut port: T) -> io::Result<Self> {
+        if MsgRate::uses_cfg_valset::<P>() {
+            let mut packet = Vec::new();
+            CfgValSetBuilder {
+                version: 0,
+                layers: CfgLayerSet::RAM,
+                transaction: CfgTransaction::None,
+                reserved0: 0,
+                cfg_data: &[CfgVal::RateMeas(1000), CfgVal::RateNav(1)],
+            }
+            .extend_to(&mut packet);
+            port.write_ubx_frame(&packet)?;
+        } else {
+            port.write_ubx(CfgRateBuilder {
+                measure_rate_ms: 1000,
+                nav_rate: 1,
+                time_ref: AlignmentToReferenceTime::Gps,
+            })?;
+        }
+        let nav_pvt = NAV_PVT
+            .to_packet::<P>()
+            .expect("UBX-NAV-PVT has output rate keys");
+        port.write_ubx_frame(&nav_pvt)?;
+        Ok(Self {
+            port,
+            parser: Parser::new(Vec::new()),
+            aggregator: EpochAggregator::new(EpochPolicy::WaitForAll(EpochComponents::PVT)),
+            positions: VecDeque::new(),
+            buf: [0; 1024],
+        })
+    }
+
+    /// Waits up to `timeout` for the next valid position
+    ///
+    /// Fails with [io::ErrorKind::TimedOut] if none arrived in time, or with
+    /// [io::ErrorKind::UnexpectedEof] if the port was closed.
+    pub fn next_within(&mut self, timeout: Duration) -> io::Result<PvtSolution> {
+        self.next_before(Some(Instant::now() + timeout))?
+            .ok_or_else(|| io::ErrorKind::UnexpectedEof.into())
+    }
+
+    /// Returns the port, e.g. to send other messages
+    pub fn port_mut(&mut self) -> &mut T {
+        &mut self.port
+    }
+
+    fn next_before(&mut self, deadline: Option<Instant>) -> io::Result<Option<PvtSolution>> {
+        loop {
+            if let Some(position) = self.positions.pop_front() {
+                return Ok(Some(position));
+            }
+            if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+                return Err(io::ErrorKind::TimedOut.into());
+            }
+
+            let len = match self.port.read(&mut self.buf) {
+                Ok(0) => return Ok(None),
+                Ok(len) => len,
+                // Serial ports report their read timeout as an error
+                Err(e)
+                    if matches!(
+                        e.kind(),
+                        io::ErrorKind::Interrupted
+                            | io::ErrorKind::WouldBlock
+                            | io::ErrorKind::TimedOut
+                    ) =>
+                {
+                    continue
+                },
+                Err(e) => return Err(e),
+            };
+            let mut it = self.parser.consume_ubx(&self.buf[..len]);
+            while let Some(packet) = it.next() {
+                let Ok(packet) = packet else {
+                    continue;
+                };
+                self.positions.extend(
+                    self.aggregator
+                        .update(&packet)
+                        .filter_map(|epoch| epoch.pvt)
+                        .filter(is_valid),
+                );
+            }
+        }
+    }
+}
+
+impl<T: Read + Write, P: UbxProtocol> Iterator for Positions<T, P> {
+    type Item = io::Result<PvtSolution>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_before(None).transpose()
+    }
+}
+
+fn is_valid(pvt: &PvtSolution) -> bool {
+    pvt.fix_ok
+        && matches!(
+            pvt.fix_type,
+            GnssFixType::Fix2D | GnssFixType::Fix3D | GnssFixType::GPSPlusDeadReckoning
+        )
+}
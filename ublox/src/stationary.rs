@@ -0,0 +1,263 @@
+//! Static hold configuration and host-side detection of a stationary receiver.
+//!
+//! A parked receiver sees its position wander by a few meters as the satellite geometry and
+//! multipath change. The receiver's static hold clamps the position once the speed drops below
+//! a threshold, [StaticHold] configures it with UBX-CFG-NAV5 on older receivers and with the
+//! CFG-MOT-* keys on generation 9 and later ones. [StationaryDetector] makes the same decision
+//! on the host from UBX-NAV-PVT, so that an asset tracker can report a single position while
+//! parked whatever the receiver configuration.
+//!
+//! ```
+//! use ublox::{cfg_nav5::CfgNav5Params, stationary::StaticHold};
+//!
+//! let hold = StaticHold::new(0.5, 10);
+//! let builder = hold.cfg_nav5();
+//! assert_eq!(builder.mask, CfgNav5Params::STATIC_HOLD_MASK);
+//! assert_eq!(builder.static_hold_max_dist, 10);
+//! ```
+//!
+//! Feed the detector every packet received with [StationaryDetector::update], and use
+//! [StationaryDetector::position] instead of the position of each fix.
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+use num_traits::float::FloatCore;
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::cfg_val::{keys, CfgItems};
+use crate::{
+    cfg_nav5::CfgNav5Builder, nav_pvt::common::NavPvtFlags, packet_match::match_packet,
+    PositionLLA, UbxPacket,
+};
+
+/// Speed below which [StationaryDetector::new] considers the receiver still \[m/s\]
+pub const DEFAULT_SPEED_THRESHOLD: f64 = 0.5;
+
+/// Horizontal accuracy above which [StationaryDetector::new] ignores a fix \[m\]
+pub const DEFAULT_MAX_HORIZONTAL_ACCURACY: f64 = 20.0;
+
+/// Consecutive still fixes after which [StationaryDetector::new] reports the receiver as
+/// stationary
+pub const DEFAULT_MIN_STILL_EPOCHS: u8 = 3;
+
+/// Static hold thresholds of the receiver's navigation engine
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StaticHold {
+    /// Speed below which the receiver enters static hold \[m/s\], 0 disables static hold
+    pub speed_threshold: f32,
+    /// Distance from the held position after which the receiver leaves static hold \[m\]
+    pub max_distance: u16,
+}
+
+impl StaticHold {
+    /// Hold the position below `speed_threshold` \[m/s\] until the receiver moved more than
+    /// `max_distance` \[m\]. The threshold is rounded to cm/s and saturates at 2.55 m/s.
+    pub const fn new(speed_threshold: f32, max_distance: u16) -> Self {
+        Self {
+            speed_threshold,
+            max_distance,
+        }
+    }
+
+    /// Disable static hold
+    pub const fn disabled() -> Self {
+        Self::new(0.0, 0)
+    }
+
+    /// UBX-CFG-NAV5 applying the static hold settings only, for protocols before 27
+    pub fn cfg_nav5(self) -> CfgNav5Builder {
+        CfgNav5Builder::default()
+            .set_static_hold_thresh(self.speed_threshold)
+            .set_static_hold_max_dist(self.max_distance)
+    }
+
+    /// CFG-MOT-GNSSSPEED_THRS and CFG-MOT-GNSSDIST_THRS, for receivers configured with
+    /// UBX-CFG-VALSET
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn cfg_items(self) -> CfgItems {
+        self.add_to(CfgItems::new())
+    }
+
+    /// Add the static hold keys to `items`, like [StaticHold::cfg_items]
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn add_to(self, items: CfgItems) -> CfgItems {
+        items
+            .set(keys::MotGnssSpeedThrs, self.speed_threshold_cm_s())
+            .set(keys::MotGnssDistThrs, self.max_distance)
+    }
+
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        reason = "Clamped to the range of u8"
+    )]
+    fn speed_threshold_cm_s(self) -> u8 {
+        FloatCore::round(self.speed_threshold * 100.0).clamp(0.0, 255.0) as u8
+    }
+}
+
+/// Motion state reported by [StationaryDetector::update]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MotionState {
+    /// Not enough usable fixes yet to tell
+    Unknown,
+    Moving,
+    Stationary,
+}
+
+/// Detects a stationary receiver from UBX-NAV-PVT, see the [module documentation](self)
+///
+/// A fix is still when its ground speed is below the speed threshold with a speed accuracy
+/// no worse than the threshold, or when the receiver reports zero velocity, which is how its
+/// own static hold shows. Fixes without GNSS fix OK or with a horizontal accuracy above the
+/// limit are ignored. The receiver becomes stationary after a number of consecutive still
+/// fixes and moving again at the first fix above the speed threshold.
+#[derive(Debug, Clone)]
+pub struct StationaryDetector {
+    speed_threshold: f64,
+    max_horizontal_accuracy: f64,
+    min_still_epochs: u8,
+    still_epochs: u8,
+    state: MotionState,
+    /// Most accurate position of the still fixes, and its accuracy
+    anchor: Option<(PositionLLA, f64)>,
+    /// Position of the last fix used
+    last: Option<PositionLLA>,
+}
+
+impl Default for StationaryDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StationaryDetector {
+    pub const fn new() -> Self {
+        Self::with_thresholds(
+            DEFAULT_SPEED_THRESHOLD,
+            DEFAULT_MAX_HORIZONTAL_ACCURACY,
+            DEFAULT_MIN_STILL_EPOCHS,
+        )
+    }
+
+    /// Use `speed_threshold` \[m/s\], `max_horizontal_accuracy` \[m\] and `min_still_epochs`
+    /// instead of the defaults
+    pub const fn with_thresholds(
+        speed_threshold: f64,
+        max_horizontal_accuracy: f64,
+        min_still_epochs: u8,
+    ) -> Self {
+        Self {
+            speed_threshold,
+            max_horizontal_accuracy,
+            min_still_epochs,
+            still_epochs: 0,
+            state: MotionState::Unknown,
+            anchor: None,
+            last: None,
+        }
+    }
+
+    /// Update the state from `packet` if it is a UBX-NAV-PVT. Returns the new state when
+    /// `packet` is one, other packets are ignored.
+    pub fn update(&mut self, packet: &UbxPacket<'_>) -> Option<MotionState> {
+        let fix = motion_fix(packet)?;
+        self.handle_fix(&fix);
+        Some(self.state)
+    }
+
+    fn handle_fix(&mut self, fix: &MotionFix) {
+        if !fix.fix_ok || fix.horizontal_accuracy > self.max_horizontal_accuracy {
+            self.break_still_run();
+            return;
+        }
+        self.last = Some(fix.position);
+        let still = fix.zero_velocity
+            || (fix.speed <= self.speed_threshold && fix.speed_accuracy <= self.speed_threshold);
+        if !still {
+            if fix.speed > self.speed_threshold {
+                self.state = MotionState::Moving;
+                self.anchor = None;
+            }
+            self.break_still_run();
+            return;
+        }
+        self.still_epochs = self.still_epochs.saturating_add(1);
+        if self
+            .anchor
+            .is_none_or(|(_, accuracy)| fix.horizontal_accuracy < accuracy)
+        {
+            self.anchor = Some((fix.position, fix.horizontal_accuracy));
+        }
+        if self.still_epochs >= self.min_still_epochs {
+            self.state = MotionState::Stationary;
+        }
+    }
+
+    /// A fix that is not still ends the run of still fixes, and forgets the positions of the
+    /// run unless the receiver is already stationary
+    fn break_still_run(&mut self) {
+        self.still_epochs = 0;
+        if self.state != MotionState::Stationary {
+            self.anchor = None;
+        }
+    }
+
+    /// The current motion state
+    pub const fn state(&self) -> MotionState {
+        self.state
+    }
+
+    /// Whether the receiver is stationary
+    pub fn is_stationary(&self) -> bool {
+        self.state == MotionState::Stationary
+    }
+
+    /// Position to report: the most accurate one of the still fixes while stationary,
+    /// otherwise the last usable fix
+    pub fn position(&self) -> Option<PositionLLA> {
+        match (self.state, self.anchor) {
+            (MotionState::Stationary, Some((position, _))) => Some(position),
+            _ => self.last,
+        }
+    }
+
+    /// Forget the state, e.g. after a receiver reset
+    pub fn reset(&mut self) {
+        *self = Self::with_thresholds(
+            self.speed_threshold,
+            self.max_horizontal_accuracy,
+            self.min_still_epochs,
+        );
+    }
+}
+
+/// The UBX-NAV-PVT fields used by [StationaryDetector]
+struct MotionFix {
+    fix_ok: bool,
+    position: PositionLLA,
+    horizontal_accuracy: f64,
+    speed: f64,
+    speed_accuracy: f64,
+    zero_velocity: bool,
+}
+
+/// Whether a velocity component in mm/s resolution is 0
+fn is_zero(velocity: f64) -> bool {
+    velocity.abs() < 0.5e-3
+}
+
+fn motion_fix(packet: &UbxPacket<'_>) -> Option<MotionFix> {
+    match_packet!(packet, {
+        PacketRef::NavPvt(pvt) => Some(MotionFix {
+            fix_ok: pvt.flags().contains(NavPvtFlags::GPS_FIX_OK),
+            position: PositionLLA::from(pvt),
+            horizontal_accuracy: pvt.horizontal_accuracy(),
+            speed: pvt.ground_speed_2d(),
+            speed_accuracy: pvt.speed_accuracy(),
+            zero_velocity: is_zero(pvt.vel_north())
+                && is_zero(pvt.vel_east())
+                && is_zero(pvt.vel_down()),
+        }),
+    } _ => None)
+}
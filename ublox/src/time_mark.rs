@@ -0,0 +1,298 @@
+//! GPS time of events captured on the EXTINT inputs, e.g. the shutter of a camera for
+//! photogrammetry or mapping.
+//!
+//! The receiver reports the time of the edges on its EXTINT pins with UBX-TIM-TM2.
+//! [EventTimestamper::update] returns a [TimeMark] for each new edge, with its GPS week and
+//! time of week to the nanosecond:
+//!
+//! ```
+//! # #[cfg(all(feature = "ubx_proto23", any(feature = "std", feature = "alloc")))] {
+//! use ublox::{time_mark::{Edge, EventTimestamper}, ubx_frame, ParserBuilder};
+//!
+//! let mut timestamper = EventTimestamper::new();
+//! let mut parser = ParserBuilder::new().with_fixed_buffer::<1024>();
+//! // UBX-TIM-TM2 of the first rising edge on EXTINT0, in week 2300 at 100000.500250 s
+//! let frame = ubx_frame!(
+//!     0x0d,
+//!     0x03,
+//!     "00 c8 0100 fc08 0000 f4e2f505 90d00300 00000000 00000000 14000000"
+//! );
+//! let mut it = parser.consume_ubx(&frame);
+//! let Some(Ok(packet)) = it.next() else { panic!() };
+//!
+//! let mark = timestamper.update(&packet).next().unwrap();
+//! assert_eq!((mark.channel, mark.edge, mark.count), (0, Edge::Rising, 1));
+//! assert_eq!(mark.time.week, 2300);
+//! assert_eq!(mark.time.tow_ns, 100_000_500_250_000);
+//! assert_eq!(mark.accuracy_ns, 20);
+//! # }
+//! ```
+//!
+//! Sensor data sent to the receiver with UBX-ESF-MEAS can come with a time mark on an EXTINT
+//! input, whose receiver local time is then output as the calibTtag of UBX-ESF-MEAS. The
+//! timestamper pairs this time mark with the UBX-TIM-TM2 of the same edge, in the order they
+//! are received, to relate the local time to GPS time. [EventTimestamper::esf_gps_time] then
+//! converts the calibTtag of any UBX-ESF-MEAS to GPS time.
+
+#[cfg(any(
+    feature = "ubx_proto23",
+    feature = "ubx_proto27",
+    feature = "ubx_proto31",
+    feature = "ubx_proto33",
+))]
+use crate::esf_meas::EsfMeasRef;
+use crate::{
+    packet_match::match_packet,
+    tim_tm2::{TimTm2Ref, TimTm2TimeBase},
+    tim_tp::TimTpRef,
+    UbxPacket,
+};
+
+const NANOS_PER_MS: u64 = 1_000_000;
+const NANOS_PER_WEEK: u64 = 7 * 24 * 3600 * 1_000 * NANOS_PER_MS;
+
+/// Number of EXTINT inputs
+const CHANNELS: usize = 2;
+
+/// Time in the GPS week
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct GpsTime {
+    pub week: u16,
+    /// Time of week \[ns\]
+    pub tow_ns: u64,
+}
+
+impl GpsTime {
+    /// The time `nanos` later, or earlier when negative, carrying into the week number
+    pub fn add_nanos(self, nanos: i64) -> Self {
+        let tow_ns = i128::from(self.tow_ns) + i128::from(nanos);
+        let week = i128::from(self.week) + tow_ns.div_euclid(NANOS_PER_WEEK.into());
+        Self {
+            week: week as u16,
+            tow_ns: tow_ns.rem_euclid(NANOS_PER_WEEK.into()) as u64,
+        }
+    }
+
+    /// Time of the time pulse announced by UBX-TIM-TP, in its time base
+    pub(crate) fn of_time_pulse(tim_tp: &TimTpRef<'_>) -> Self {
+        // towSubMS is in 2^-32 ms
+        let sub_ms_ns = (u64::from(tim_tp.tow_sub_ms()) * NANOS_PER_MS) >> 32;
+        Self {
+            week: tim_tp.week(),
+            tow_ns: u64::from(tim_tp.tow_ms()) * NANOS_PER_MS + sub_ms_ns,
+        }
+    }
+
+    /// Signed time from `earlier` to this time \[ns\]
+    pub fn nanos_since(self, earlier: Self) -> i128 {
+        let nanos = |time: Self| {
+            i128::from(time.week) * i128::from(NANOS_PER_WEEK) + i128::from(time.tow_ns)
+        };
+        nanos(self) - nanos(earlier)
+    }
+}
+
+/// Edge of the signal on an EXTINT input
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Edge {
+    Rising,
+    Falling,
+}
+
+/// An edge captured on an EXTINT input, returned by [EventTimestamper::update]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TimeMark {
+    /// EXTINT input, 0 or 1
+    pub channel: u8,
+    pub edge: Edge,
+    /// Rising edge counter of the input, wrapping at 65536
+    pub count: u16,
+    pub time: GpsTime,
+    /// Accuracy estimate of the time \[ns\]
+    pub accuracy_ns: u32,
+    /// Time base of the receiver the time is in, GPS time unless configured otherwise
+    pub time_base: TimTm2TimeBase,
+}
+
+/// Time mark of a UBX-ESF-MEAS, in receiver local time
+#[cfg(any(
+    feature = "ubx_proto23",
+    feature = "ubx_proto27",
+    feature = "ubx_proto31",
+    feature = "ubx_proto33",
+))]
+#[derive(Debug, Clone, Copy)]
+struct EsfTimeMark {
+    channel: usize,
+    edge: Edge,
+    calib_tag: u32,
+}
+
+/// Input read from a packet
+enum Input {
+    TimeMarks([Option<TimeMark>; 2]),
+    #[cfg(any(
+        feature = "ubx_proto23",
+        feature = "ubx_proto27",
+        feature = "ubx_proto31",
+        feature = "ubx_proto33",
+    ))]
+    EsfTimeMark(EsfTimeMark),
+}
+
+/// GPS time of EXTINT edges and UBX-ESF-MEAS, see the [module documentation](self)
+#[derive(Debug, Default)]
+pub struct EventTimestamper {
+    /// Latest edges reported by UBX-TIM-TM2 not paired with a UBX-ESF-MEAS yet, per input and
+    /// edge
+    unpaired_marks: [[Option<GpsTime>; 2]; CHANNELS],
+    /// Latest time marks of UBX-ESF-MEAS not paired with a UBX-TIM-TM2 yet
+    unpaired_esf: [[Option<u32>; 2]; CHANNELS],
+    /// calibTtag and GPS time of the latest paired time mark
+    esf_anchor: Option<(u32, GpsTime)>,
+}
+
+impl EventTimestamper {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Read the edges reported by UBX-TIM-TM2 and the time marks of UBX-ESF-MEAS, returning
+    /// the new edges with a valid time. Other packets are ignored.
+    pub fn update(&mut self, packet: &UbxPacket<'_>) -> impl Iterator<Item = TimeMark> {
+        let input = time_mark_input(packet);
+        let marks = match input {
+            Some(Input::TimeMarks(marks)) => {
+                for mark in marks.iter().flatten() {
+                    self.on_time_mark(mark);
+                }
+                marks
+            },
+            #[cfg(any(
+                feature = "ubx_proto23",
+                feature = "ubx_proto27",
+                feature = "ubx_proto31",
+                feature = "ubx_proto33",
+            ))]
+            Some(Input::EsfTimeMark(esf)) => {
+                self.on_esf_time_mark(esf);
+                [None, None]
+            },
+            None => [None, None],
+        };
+        marks.into_iter().flatten()
+    }
+
+    /// GPS time of the calibTtag of a UBX-ESF-MEAS, once a time mark was paired. Tags up to
+    /// 24 days away from the paired one are converted, the local time wrapping every 49 days.
+    pub fn esf_gps_time(&self, calib_tag: u32) -> Option<GpsTime> {
+        let (anchor_tag, anchor_time) = self.esf_anchor?;
+        let delta_ms = calib_tag.wrapping_sub(anchor_tag) as i32;
+        Some(anchor_time.add_nanos(i64::from(delta_ms) * NANOS_PER_MS as i64))
+    }
+
+    /// Whether a UBX-ESF-MEAS time mark was paired with its UBX-TIM-TM2 edge, so that
+    /// [EventTimestamper::esf_gps_time] converts calibTtags
+    pub fn is_esf_calibrated(&self) -> bool {
+        self.esf_anchor.is_some()
+    }
+
+    fn on_time_mark(&mut self, mark: &TimeMark) {
+        let Some(channel) = self.unpaired_marks.get_mut(usize::from(mark.channel)) else {
+            return;
+        };
+        let edge = mark.edge as usize;
+        match self.unpaired_esf[usize::from(mark.channel)][edge].take() {
+            Some(calib_tag) => self.esf_anchor = Some((calib_tag, mark.time)),
+            None => channel[edge] = Some(mark.time),
+        }
+    }
+
+    #[cfg(any(
+        feature = "ubx_proto23",
+        feature = "ubx_proto27",
+        feature = "ubx_proto31",
+        feature = "ubx_proto33",
+    ))]
+    fn on_esf_time_mark(&mut self, esf: EsfTimeMark) {
+        let edge = esf.edge as usize;
+        match self.unpaired_marks[esf.channel][edge].take() {
+            Some(time) => self.esf_anchor = Some((esf.calib_tag, time)),
+            None => self.unpaired_esf[esf.channel][edge] = Some(esf.calib_tag),
+        }
+    }
+}
+
+fn time_marks(tm2: &TimTm2Ref<'_>) -> [Option<TimeMark>; 2] {
+    let flags = tm2.flags();
+    if !flags.time_valid() {
+        return [None, None];
+    }
+    let mark = |edge, week, tow_ms: u32, tow_sub_ms_ns: u32| TimeMark {
+        channel: tm2.ch(),
+        edge,
+        count: tm2.count(),
+        time: GpsTime {
+            week,
+            tow_ns: u64::from(tow_ms) * NANOS_PER_MS + u64::from(tow_sub_ms_ns),
+        },
+        accuracy_ns: tm2.acc_est(),
+        time_base: flags.time_base(),
+    };
+    [
+        flags
+            .new_rising_edge()
+            .then(|| mark(Edge::Rising, tm2.wn_r(), tm2.tow_ms_r(), tm2.tow_sub_ms_r())),
+        flags.new_falling_edge().then(|| {
+            mark(
+                Edge::Falling,
+                tm2.wn_f(),
+                tm2.tow_ms_f(),
+                tm2.tow_sub_ms_f(),
+            )
+        }),
+    ]
+}
+
+#[cfg(any(
+    feature = "ubx_proto23",
+    feature = "ubx_proto27",
+    feature = "ubx_proto31",
+    feature = "ubx_proto33",
+))]
+fn esf_time_mark(meas: &EsfMeasRef<'_>) -> Option<EsfTimeMark> {
+    let flags = meas.flags();
+    let channel = match flags.time_mark_sent() {
+        1 => 0,
+        2 => 1,
+        _ => return None,
+    };
+    Some(EsfTimeMark {
+        channel,
+        edge: if flags.time_mark_edge() {
+            Edge::Falling
+        } else {
+            Edge::Rising
+        },
+        calib_tag: meas.calib_tag()?,
+    })
+}
+
+fn time_mark_input(packet: &UbxPacket<'_>) -> Option<Input> {
+    match_packet!(packet, {
+        PacketRef::TimTm2(tm2) => Some(Input::TimeMarks(time_marks(tm2))),
+    } proto23 {
+        PacketRef::EsfMeas(meas) => esf_time_mark(meas).map(Input::EsfTimeMark),
+    } proto27 {
+        PacketRef::EsfMeas(meas) => esf_time_mark(meas).map(Input::EsfTimeMark),
+    } proto31 {
+        PacketRef::EsfMeas(meas) => esf_time_mark(meas).map(Input::EsfTimeMark),
+    } proto33 {
+        PacketRef::EsfMeas(meas) => esf_time_mark(meas).map(Input::EsfTimeMark),
+    } _ => None)
+}
@@ -0,0 +1,214 @@
+//! Camera triggers on the time pulse output, aligned to the top of the GNSS second, for survey
+//! payloads triggering their sensors from the receiver.
+//!
+//! A [TriggerPlan] configures the time pulse with UBX-CFG-TP5 to pulse every period, a
+//! fraction of a second, once the receiver is locked to GNSS time. Nothing is output before,
+//! so that no trigger is ever misaligned. The UBX-TIM-TP announcing a time pulse gives the
+//! GPS time of the triggers of the second starting with it, and captured edges, e.g. the
+//! flash sync of the camera reported by UBX-TIM-TM2, are checked against the plan:
+//!
+//! ```
+//! use core::time::Duration;
+//! use ublox::{
+//!     time_mark::GpsTime,
+//!     trigger::TriggerPlan,
+//!     tim_tp::TimTp,
+//!     UbxPacketRecv,
+//! };
+//!
+//! let plan = TriggerPlan::new(Duration::from_millis(200))
+//!     .unwrap()
+//!     .with_pulse_len(Duration::from_millis(1))
+//!     .unwrap();
+//! let _frame = plan.cfg_tp5().into_packet_bytes();
+//!
+//! // UBX-TIM-TP announcing the pulse of week 2300 at 100000 s
+//! let mut payload = [0; 16];
+//! payload[..4].copy_from_slice(&100_000_000u32.to_le_bytes());
+//! payload[12..14].copy_from_slice(&2300u16.to_le_bytes());
+//! let tim_tp = TimTp::parse_payload(&payload).unwrap();
+//!
+//! let schedule = plan.schedule(&tim_tp);
+//! let triggers: Vec<_> = schedule.triggers().map(|time| time.tow_ns).collect();
+//! assert_eq!(triggers[..2], [100_000_000_000_000, 100_000_200_000_000]);
+//! assert_eq!(triggers.len(), 5);
+//!
+//! let flash = GpsTime { week: 2300, tow_ns: 100_000_400_000_150 };
+//! assert_eq!(plan.deviation_ns(flash), 150);
+//! ```
+
+use core::{fmt, time::Duration};
+
+use crate::{
+    cfg_tp5::{CfgTp5Builder, CfgTp5Flags, CfgTp5TimePulseMode},
+    tim_tp::{TimTpRef, TimTpTimeBase},
+    time_mark::{Edge, GpsTime},
+};
+
+/// Invalid [TriggerPlan]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TriggerPlanError {
+    /// The period is not a whole number of microseconds dividing a second, the only periods
+    /// the receiver aligns to the top of second
+    PeriodNotFractionOfSecond,
+    /// The pulse is empty or not shorter than the period
+    InvalidPulseLen,
+}
+
+impl fmt::Display for TriggerPlanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PeriodNotFractionOfSecond => {
+                f.write_str("trigger period is not a fraction of a second")
+            },
+            Self::InvalidPulseLen => f.write_str("trigger pulse length out of the period"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TriggerPlanError {}
+
+/// Periodic triggers on a time pulse output, see the [module documentation](self)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TriggerPlan {
+    period_us: u32,
+    pulse_len_us: u32,
+    user_delay_ns: i32,
+    edge: Edge,
+    output: CfgTp5TimePulseMode,
+}
+
+impl TriggerPlan {
+    /// Trigger every `period`, which must divide a second, with rising edges of 1 µs pulses
+    /// on the first time pulse output
+    pub fn new(period: Duration) -> Result<Self, TriggerPlanError> {
+        let period_us = u32::try_from(period.as_micros())
+            .ok()
+            .filter(|&us| {
+                us > 0
+                    && Duration::from_micros(us.into()) == period
+                    && 1_000_000u32.is_multiple_of(us)
+            })
+            .ok_or(TriggerPlanError::PeriodNotFractionOfSecond)?;
+        Ok(Self {
+            period_us,
+            pulse_len_us: 1,
+            user_delay_ns: 0,
+            edge: Edge::Rising,
+            output: CfgTp5TimePulseMode::TimePulse,
+        })
+    }
+
+    /// Length of the pulses, rounded down to the microsecond, shorter than the period
+    pub fn with_pulse_len(mut self, len: Duration) -> Result<Self, TriggerPlanError> {
+        self.pulse_len_us = u32::try_from(len.as_micros())
+            .ok()
+            .filter(|&us| us > 0 && us < self.period_us)
+            .ok_or(TriggerPlanError::InvalidPulseLen)?;
+        Ok(self)
+    }
+
+    /// Delay of the pulses after the triggers' times \[ns\], e.g. negative to compensate the
+    /// shutter lag of the camera
+    pub fn with_user_delay_ns(mut self, delay_ns: i32) -> Self {
+        self.user_delay_ns = delay_ns;
+        self
+    }
+
+    /// Edge of the pulse at the triggers' times, the other one ending the pulse
+    pub fn with_edge(mut self, edge: Edge) -> Self {
+        self.edge = edge;
+        self
+    }
+
+    /// Time pulse output of the triggers
+    pub fn with_output(mut self, output: CfgTp5TimePulseMode) -> Self {
+        self.output = output;
+        self
+    }
+
+    pub fn period(&self) -> Duration {
+        Duration::from_micros(self.period_us.into())
+    }
+
+    pub fn triggers_per_second(&self) -> u32 {
+        1_000_000 / self.period_us
+    }
+
+    /// UBX-CFG-TP5 setting up the time pulse, silent until the receiver is locked to GNSS time
+    pub fn cfg_tp5(&self) -> CfgTp5Builder {
+        let mut flags = CfgTp5Flags::ACTIVE
+            | CfgTp5Flags::LOCK_GNSS_FREQ
+            | CfgTp5Flags::LOCKED_OTHER_SET
+            | CfgTp5Flags::IS_LENGTH
+            | CfgTp5Flags::ALIGN_TO_TOW;
+        if self.edge == Edge::Rising {
+            flags |= CfgTp5Flags::POLARITY;
+        }
+        CfgTp5Builder {
+            tp_idx: self.output,
+            version: 1,
+            freq_period: self.period_us.into(),
+            freq_period_lock: self.period_us.into(),
+            pulse_len_ratio: 0.0,
+            pulse_len_ratio_lock: self.pulse_len_us.into(),
+            user_delay: self.user_delay_ns.into(),
+            flags,
+            ..Default::default()
+        }
+    }
+
+    /// The triggers of the second starting at the time pulse announced by `tim_tp`
+    pub fn schedule(&self, tim_tp: &TimTpRef<'_>) -> TriggerSchedule {
+        let flags = tim_tp.flags();
+        TriggerSchedule {
+            pulse: GpsTime::of_time_pulse(tim_tp),
+            time_base: flags.time_base(),
+            q_err_ps: flags.q_err_valid().then(|| tim_tp.q_err()),
+            plan: *self,
+        }
+    }
+
+    /// Signed time from the nearest trigger to `time`, e.g. of the flash sync of a camera
+    /// captured with UBX-TIM-TM2, to check the triggers are taken
+    pub fn deviation_ns(&self, time: GpsTime) -> i64 {
+        let period_ns = u64::from(self.period_us) * 1_000;
+        let since_trigger = time.add_nanos(-i64::from(self.user_delay_ns)).tow_ns % period_ns;
+        if since_trigger <= period_ns / 2 {
+            since_trigger as i64
+        } else {
+            since_trigger as i64 - period_ns as i64
+        }
+    }
+}
+
+/// Triggers of a second, returned by [TriggerPlan::schedule]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TriggerSchedule {
+    /// Time of the time pulse announced by UBX-TIM-TP, without user delay
+    pub pulse: GpsTime,
+    /// Time base of the time pulse, UTC for triggers aligned to UTC seconds
+    pub time_base: TimTpTimeBase,
+    /// Quantization error of the time pulse \[ps\], if valid
+    pub q_err_ps: Option<i32>,
+    plan: TriggerPlan,
+}
+
+impl TriggerSchedule {
+    /// Times of the triggers of the second, the user delay included
+    pub fn triggers(&self) -> impl Iterator<Item = GpsTime> + '_ {
+        let period_ns = u64::from(self.plan.period_us) * 1_000;
+        let first = self.pulse.add_nanos(self.plan.user_delay_ns.into());
+        (0..u64::from(self.plan.triggers_per_second()))
+            .map(move |i| first.add_nanos((i * period_ns) as i64))
+    }
+
+    /// Whether the pulse lies on the grid of the plan's periods, as when the receiver applied
+    /// the plan
+    pub fn is_aligned(&self) -> bool {
+        self.pulse
+            .tow_ns
+            .is_multiple_of(u64::from(self.plan.period_us) * 1_000)
+    }
+}
@@ -0,0 +1,216 @@
+//! Time-to-first-fix measurement from UBX-NAV-STATUS and UBX-NAV-PVT, e.g. for comparing
+//! receivers or start modes.
+//!
+//! The timer is started by the caller when the receiver is reset or the device is opened,
+//! timestamps are passed in as [Duration]s since an arbitrary epoch so that any monotonic clock
+//! can be used:
+//!
+//! ```
+//! use core::time::Duration;
+//! use ublox::{proto23::Proto23, ttff::{TtffSource, TtffTimer}, GnssFixType, ParserBuilder};
+//!
+//! let mut timer = TtffTimer::new();
+//! timer.start(Duration::from_secs(100));
+//!
+//! let mut parser = ParserBuilder::new()
+//!     .with_protocol::<Proto23>()
+//!     .with_fixed_buffer::<1024>();
+//! // UBX-NAV-STATUS reporting a 3D fix, 26.5 s after the receiver started
+//! let mut it = parser.consume_ubx(&[
+//!     0xb5, 0x62, 0x01, 0x03, 0x10, 0x00, 0xe8, 0x03, 0x00, 0x00, 0x03, 0x0d, 0x00, 0x00, 0x84,
+//!     0x67, 0x00, 0x00, 0x20, 0x75, 0x00, 0x00, 0x8f, 0x9d,
+//! ]);
+//! let Some(Ok(packet)) = it.next() else { panic!() };
+//!
+//! let ttff = timer.update(&packet, Duration::from_secs(130)).unwrap();
+//! assert_eq!(ttff.duration, Duration::from_millis(26_500));
+//! assert_eq!(ttff.fix_type, GnssFixType::Fix3D);
+//! assert_eq!(ttff.source, TtffSource::Receiver);
+//! ```
+//!
+//! The receiver's own measurement from UBX-NAV-STATUS is preferred, as it does not include
+//! the transport delay. With only UBX-NAV-PVT enabled the time is measured between
+//! [TtffTimer::start] and the arrival of the first packet with a valid 2D or 3D fix.
+//!
+//! For hardware qualification, [StartType] provides the UBX-CFG-RST frames for hot, warm and
+//! cold starts and [TtffStats] accumulates the results of repeated starts into a report.
+
+use core::time::Duration;
+
+pub use crate::cfg_rst::StartType;
+use crate::{
+    nav_pvt::common::NavPvtFlags, nav_status::NavStatusFlags, packet_match::match_packet,
+    GnssFixType, UbxPacket,
+};
+
+/// How the time to first fix was obtained
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TtffSource {
+    /// Reported by the receiver in UBX-NAV-STATUS
+    Receiver,
+    /// Measured from [TtffTimer::start] to the arrival of the first fix
+    Host,
+}
+
+/// A time to first fix measured by a [TtffTimer]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ttff {
+    pub duration: Duration,
+    /// Type of the first fix, one of `Fix2D`, `Fix3D` or `GPSPlusDeadReckoning`
+    pub fix_type: GnssFixType,
+    pub source: TtffSource,
+}
+
+/// Watches the packets received after a reset for the first 2D or 3D fix
+#[derive(Debug, Clone, Default)]
+pub struct TtffTimer {
+    started_at: Option<Duration>,
+    ttff: Option<Ttff>,
+}
+
+impl TtffTimer {
+    pub const fn new() -> Self {
+        Self {
+            started_at: None,
+            ttff: None,
+        }
+    }
+
+    /// Start measuring at `now`, discarding a previous measurement. Call this when the
+    /// receiver is reset or the device is opened.
+    pub fn start(&mut self, now: Duration) {
+        self.started_at = Some(now);
+        self.ttff = None;
+    }
+
+    /// Check `packet` for the first fix, received at `now`. Returns the time to first fix
+    /// once, on the packet completing the measurement. Packets are ignored before
+    /// [TtffTimer::start] is called.
+    pub fn update(&mut self, packet: &UbxPacket<'_>, now: Duration) -> Option<Ttff> {
+        let started_at = self.started_at?;
+        if self.ttff.is_some() {
+            return None;
+        }
+        let fix = first_fix(packet)?;
+        let ttff = match fix.receiver_ttff {
+            Some(duration) => Ttff {
+                duration,
+                fix_type: fix.fix_type,
+                source: TtffSource::Receiver,
+            },
+            None => Ttff {
+                duration: now.saturating_sub(started_at),
+                fix_type: fix.fix_type,
+                source: TtffSource::Host,
+            },
+        };
+        self.ttff = Some(ttff);
+        self.ttff
+    }
+
+    /// The time to first fix, once measured
+    pub fn ttff(&self) -> Option<Ttff> {
+        self.ttff
+    }
+
+    /// Whether the timer was started and is still waiting for the first fix
+    pub fn is_running(&self) -> bool {
+        self.started_at.is_some() && self.ttff.is_none()
+    }
+}
+
+/// Statistics of the times to first fix of repeated starts of one [StartType]
+///
+/// ```
+/// use core::time::Duration;
+/// use ublox::ttff::{StartType, TtffStats};
+///
+/// let mut stats = TtffStats::new(StartType::Hot);
+/// stats.add(Some(Duration::from_secs(2)));
+/// stats.add(Some(Duration::from_secs(4)));
+/// stats.add(None);
+/// assert_eq!(stats.fixes, 2);
+/// assert_eq!(stats.failures, 1);
+/// assert_eq!(stats.mean(), Some(Duration::from_secs(3)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TtffStats {
+    pub start_type: StartType,
+    /// Starts that reached a fix
+    pub fixes: u32,
+    /// Starts without a fix before the timeout
+    pub failures: u32,
+    pub min: Option<Duration>,
+    pub max: Option<Duration>,
+    total: Duration,
+}
+
+impl TtffStats {
+    pub const fn new(start_type: StartType) -> Self {
+        Self {
+            start_type,
+            fixes: 0,
+            failures: 0,
+            min: None,
+            max: None,
+            total: Duration::ZERO,
+        }
+    }
+
+    /// Add the result of one start, `None` if it timed out without a fix
+    pub fn add(&mut self, ttff: Option<Duration>) {
+        let Some(ttff) = ttff else {
+            self.failures += 1;
+            return;
+        };
+        self.fixes += 1;
+        self.total += ttff;
+        self.min = Some(self.min.map_or(ttff, |min| min.min(ttff)));
+        self.max = Some(self.max.map_or(ttff, |max| max.max(ttff)));
+    }
+
+    /// Mean time to first fix of the starts that reached a fix
+    pub fn mean(&self) -> Option<Duration> {
+        (self.fixes > 0).then(|| self.total / self.fixes)
+    }
+}
+
+/// A valid fix reported by a navigation packet
+struct Fix {
+    fix_type: GnssFixType,
+    receiver_ttff: Option<Duration>,
+}
+
+const fn is_position_fix(fix_type: GnssFixType) -> bool {
+    matches!(
+        fix_type,
+        GnssFixType::Fix2D | GnssFixType::Fix3D | GnssFixType::GPSPlusDeadReckoning
+    )
+}
+
+fn first_fix(packet: &UbxPacket<'_>) -> Option<Fix> {
+    match_packet!(packet, {
+        PacketRef::NavStatus(status)
+            if is_position_fix(status.fix_type())
+                && status.flags().contains(NavStatusFlags::GPS_FIX_OK) =>
+        {
+            Some(Fix {
+                fix_type: status.fix_type(),
+                // Zero until the receiver has measured it
+                receiver_ttff: Some(status.time_to_first_fix())
+                    .filter(|&ms| ms > 0)
+                    .map(|ms| Duration::from_millis(ms.into())),
+            })
+        },
+        PacketRef::NavPvt(pvt)
+            if is_position_fix(pvt.fix_type())
+                && pvt.flags().contains(NavPvtFlags::GPS_FIX_OK) =>
+        {
+            Some(Fix {
+                fix_type: pvt.fix_type(),
+                receiver_ttff: None,
+            })
+        },
+    } _ => None)
+}
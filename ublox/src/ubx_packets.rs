@@ -2,30 +2,110 @@ pub mod cfg_val;
 pub mod packets;
 mod types;
 
-use crate::{constants::UBX_HEADER_LEN, constants::UBX_SYNC_SIZE, error::MemWriterError};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use core::{convert::Infallible, marker::PhantomData};
+
+use crate::{
+    constants::UBX_HEADER_LEN,
+    constants::UBX_SYNC_SIZE,
+    constants::{UBX_CHECKSUM_LEN, UBX_SYNC_CHAR_1, UBX_SYNC_CHAR_2},
+    error::{MemWriterError, ParserError},
+};
 pub use packets::*;
 pub use types::*;
+pub use ublox_core::{
+    ubx_checksum, MemWriter, PacketMeta, PayloadField, PayloadFieldKind, UbxPacketCreator,
+    UbxPacketMeta,
+};
+
+/// Decoding of a received packet type on its own, without going through a protocol's
+/// `PacketRef` enum
+pub trait UbxPacketRecv: UbxPacketMeta {
+    /// Zero-copy view of the payload, e.g. `NavPvtRef`
+    type Ref<'a>;
+
+    /// Copy of the payload owning its buffer, e.g. `NavPvtOwned`
+    type Owned: for<'a> From<Self::Ref<'a>>;
+
+    /// Validate `payload` the same way the parser does and wrap it
+    fn parse_payload(payload: &[u8]) -> Result<Self::Ref<'_>, ParserError>;
+
+    /// The frame polling this message, an empty payload frame of the same class and id, see
+    /// [UbxPollRequest]
+    ///
+    /// ```
+    /// use ublox::{mon_ver::MonVer, UbxPacketRecv, UbxPollRequest};
+    ///
+    /// assert_eq!(MonVer::poll_frame(), UbxPollRequest::new(0x0a, 0x04).into_packet_bytes());
+    /// ```
+    fn poll_frame() -> [u8; UbxPollRequest::PACKET_LEN] {
+        UbxPollRequest::for_packet::<Self>().into_packet_bytes()
+    }
+}
 
-/// Information about concrete UBX protocol's packet
-pub trait UbxPacketMeta {
-    const CLASS: u8;
-    const ID: u8;
-    const FIXED_PAYLOAD_LEN: Option<u16>;
-    const MAX_PAYLOAD_LEN: u16;
+/// Iterator over the blocks of a repeated group, e.g. the satellites of UBX-NAV-SAT
+///
+/// Returned by the accessors of fields declared as `#[ubx(repeat = count)] blocks: [Block]`,
+/// the payload is checked to hold exactly `count` valid blocks when the packet is parsed.
+pub struct RepeatedIter<'a, B> {
+    data: &'a [u8],
+    _block: PhantomData<B>,
 }
 
-/// The checksum is calculated over the packet, starting and including
-/// the CLASS field, up until, but excluding, the checksum field.
-/// So slice should starts with class id.
-/// Return ck_a and ck_b
-pub(crate) fn ubx_checksum(data: &[u8]) -> (u8, u8) {
-    let mut ck_a = 0_u8;
-    let mut ck_b = 0_u8;
-    for byte in data {
-        ck_a = ck_a.overflowing_add(*byte).0;
-        ck_b = ck_b.overflowing_add(ck_a).0;
+impl<'a, B: UbxPacketRecv> RepeatedIter<'a, B> {
+    /// Size of a block in bytes
+    pub const BLOCK_SIZE: usize = match B::FIXED_PAYLOAD_LEN {
+        Some(len) => len as usize,
+        None => panic!("Blocks of repeated groups have a fixed size"),
+    };
+
+    pub(crate) fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            _block: PhantomData,
+        }
+    }
+}
+
+impl<'a, B: UbxPacketRecv> Iterator for RepeatedIter<'a, B> {
+    type Item = B::Ref<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let block = self.data.get(..Self::BLOCK_SIZE)?;
+        self.data = &self.data[Self::BLOCK_SIZE..];
+        B::parse_payload(block).ok()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<B: UbxPacketRecv> ExactSizeIterator for RepeatedIter<'_, B> {
+    fn len(&self) -> usize {
+        self.data.len() / Self::BLOCK_SIZE
+    }
+}
+
+impl<B> Clone for RepeatedIter<'_, B> {
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data,
+            _block: PhantomData,
+        }
+    }
+}
+
+impl<'a, B: UbxPacketRecv> core::fmt::Debug for RepeatedIter<'a, B>
+where
+    B::Ref<'a>: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
     }
-    (ck_a, ck_b)
 }
 
 /// For ubx checksum on the fly
@@ -47,47 +127,225 @@ impl UbxChecksumCalc {
     }
 }
 
-/// Abstraction for buffer creation/reallocation
-/// to storing packet
-pub trait MemWriter {
-    type Error;
-    /// make sure that we have at least `len` bytes for writing
-    fn reserve_allocate(&mut self, len: usize) -> Result<(), MemWriterError<Self::Error>>;
-    fn write(&mut self, buf: &[u8]) -> Result<(), MemWriterError<Self::Error>>;
+/// Discards the bytes written by the generated builders, to measure the variable size fields
+pub(crate) struct ByteSink;
+
+impl Extend<u8> for ByteSink {
+    fn extend<I: IntoIterator<Item = u8>>(&mut self, _iter: I) {}
 }
 
-#[cfg(feature = "std")]
-impl MemWriter for Vec<u8> {
-    type Error = std::io::Error;
+/// Writes the frames of the generated builders with variable size fields into a slice, which
+/// must be at least as long as the frame
+pub(crate) struct SliceWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
 
-    fn reserve_allocate(&mut self, len: usize) -> Result<(), MemWriterError<Self::Error>> {
-        self.reserve(len);
-        Ok(())
+impl<'a> SliceWriter<'a> {
+    pub(crate) fn new(buf: &'a mut [u8]) -> Self {
+        Self { buf, len: 0 }
     }
-    fn write(&mut self, buf: &[u8]) -> Result<(), MemWriterError<Self::Error>> {
-        let ret = <dyn std::io::Write>::write(self, buf).map_err(MemWriterError::Custom)?;
-        if ret == buf.len() {
-            Ok(())
-        } else {
-            Err(MemWriterError::NotEnoughMem)
+}
+
+impl Extend<u8> for SliceWriter<'_> {
+    fn extend<I: IntoIterator<Item = u8>>(&mut self, iter: I) {
+        for byte in iter {
+            self.buf[self.len] = byte;
+            self.len += 1;
         }
     }
 }
 
-pub trait UbxPacketCreator {
-    /// Create packet and store bytes sequence to somewhere using `out`
-    fn create_packet<T: MemWriter>(self, out: &mut T) -> Result<(), MemWriterError<T::Error>>;
+impl core::ops::Deref for SliceWriter<'_> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+}
+
+impl core::ops::DerefMut for SliceWriter<'_> {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        &mut self.buf[..self.len]
+    }
 }
 
 /// Packet not supported yet by this crate
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct UbxUnknownPacketRef<'a> {
     pub payload: &'a [u8],
     pub class: u8,
     pub msg_id: u8,
 }
 
+impl UbxUnknownPacketRef<'_> {
+    /// Length of the whole frame, from the sync characters to the checksum
+    pub fn frame_len(&self) -> usize {
+        UBX_HEADER_LEN + self.payload.len() + UBX_CHECKSUM_LEN
+    }
+
+    fn header(&self) -> [u8; UBX_HEADER_LEN] {
+        let [len_lo, len_hi] = (self.payload.len() as u16).to_le_bytes();
+        [
+            UBX_SYNC_CHAR_1,
+            UBX_SYNC_CHAR_2,
+            self.class,
+            self.msg_id,
+            len_lo,
+            len_hi,
+        ]
+    }
+
+    /// Checksum of the frame, `ck_a` and `ck_b`. The parser only yields frames carrying this
+    /// checksum, except for those accepted with an invalid checksum, which are returned by
+    /// `next_checked()` and `next_frame()` in a [CheckedPacket](crate::CheckedPacket) flagging
+    /// them.
+    pub fn checksum(&self) -> (u8, u8) {
+        let mut checksum = UbxChecksumCalc::default();
+        checksum.update(&self.header()[UBX_SYNC_SIZE..]);
+        checksum.update(self.payload);
+        checksum.result()
+    }
+
+    /// The frame rebuilt from its parts, e.g. for logging or forwarding messages unknown to
+    /// this crate, with its length and checksum computed again. Use
+    /// [CheckedPacket::to_frame_vec](crate::CheckedPacket::to_frame_vec) to verify them
+    /// against the received frame.
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub fn to_frame_bytes(&self) -> Vec<u8> {
+        let (ck_a, ck_b) = self.checksum();
+        let mut frame = Vec::with_capacity(self.frame_len());
+        frame.extend_from_slice(&self.header());
+        frame.extend_from_slice(self.payload);
+        frame.extend_from_slice(&[ck_a, ck_b]);
+        frame
+    }
+
+    /// Write the frame to the start of `buf` like [UbxUnknownPacketRef::to_frame_bytes],
+    /// returning its length
+    pub fn extend_to_slice(&self, buf: &mut [u8]) -> Result<usize, MemWriterError<Infallible>> {
+        let len = self.frame_len();
+        let out = buf.get_mut(..len).ok_or(MemWriterError::NotEnoughMem)?;
+        let (header, rest) = out.split_at_mut(UBX_HEADER_LEN);
+        let (payload, checksum) = rest.split_at_mut(self.payload.len());
+        header.copy_from_slice(&self.header());
+        payload.copy_from_slice(self.payload);
+        let (ck_a, ck_b) = self.checksum();
+        checksum.copy_from_slice(&[ck_a, ck_b]);
+        Ok(len)
+    }
+}
+
+impl UbxPacketCreator for UbxUnknownPacketRef<'_> {
+    fn create_packet<T: MemWriter>(self, out: &mut T) -> Result<(), MemWriterError<T::Error>> {
+        let (ck_a, ck_b) = self.checksum();
+        out.reserve_allocate(self.frame_len())?;
+        out.write(&self.header())?;
+        out.write(self.payload)?;
+        out.write(&[ck_a, ck_b])
+    }
+}
+
+/// Builds a UBX frame, with its length and checksum, as a `Vec<u8>`, for tests and examples
+///
+/// The payload is given as bytes, e.g. an array, a byte string or a `Vec`, as a string of hex
+/// digits, whitespace being ignored, or left out for an empty payload.
+///
+/// ```
+/// use ublox::ubx_frame;
+///
+/// let poll = ubx_frame!(0x0a, 0x04);
+/// assert_eq!(poll, [0xb5, 0x62, 0x0a, 0x04, 0x00, 0x00, 0x0e, 0x34]);
+///
+/// let ack = ubx_frame!(0x05, 0x01, [0x06, 0x08]);
+/// assert_eq!(ack, ubx_frame!(0x05, 0x01, "06 08"));
+/// assert_eq!(ack, ubx_frame!(0x05, 0x01, b"\x06\x08"));
+/// assert_eq!(ack, [0xb5, 0x62, 0x05, 0x01, 0x02, 0x00, 0x06, 0x08, 0x16, 0x3f]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the hex string has an odd number of digits or characters other than hex digits
+/// and whitespace.
+#[cfg(feature = "test-utils")]
+#[macro_export]
+macro_rules! ubx_frame {
+    ($class:expr, $msg_id:expr) => {
+        $crate::ubx_frame!($class, $msg_id, [])
+    };
+    ($class:expr, $msg_id:expr, $payload:expr) => {
+        $crate::UbxUnknownPacketRef {
+            payload: &$crate::FramePayload::frame_payload(&$payload),
+            class: $class,
+            msg_id: $msg_id,
+        }
+        .to_frame_bytes()
+    };
+}
+
+/// Payload of a frame built by [ubx_frame]: bytes as they are, strings as hex digits
+#[doc(hidden)]
+#[cfg(feature = "test-utils")]
+pub trait FramePayload {
+    fn frame_payload(&self) -> Vec<u8>;
+}
+
+#[cfg(feature = "test-utils")]
+impl<T: FramePayload + ?Sized> FramePayload for &T {
+    fn frame_payload(&self) -> Vec<u8> {
+        (**self).frame_payload()
+    }
+}
+
+#[cfg(feature = "test-utils")]
+impl FramePayload for [u8] {
+    fn frame_payload(&self) -> Vec<u8> {
+        self.to_vec()
+    }
+}
+
+#[cfg(feature = "test-utils")]
+impl<const N: usize> FramePayload for [u8; N] {
+    fn frame_payload(&self) -> Vec<u8> {
+        self.to_vec()
+    }
+}
+
+#[cfg(feature = "test-utils")]
+impl FramePayload for Vec<u8> {
+    fn frame_payload(&self) -> Vec<u8> {
+        self.clone()
+    }
+}
+
+#[cfg(feature = "test-utils")]
+impl FramePayload for str {
+    fn frame_payload(&self) -> Vec<u8> {
+        let digits: Vec<u8> = self
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| match c.to_digit(16) {
+                Some(digit) => digit as u8,
+                None => panic!("invalid hex digit {c:?}"),
+            })
+            .collect();
+        assert!(digits.len().is_multiple_of(2), "odd number of hex digits");
+        digits
+            .chunks(2)
+            .map(|pair| pair[0] << 4 | pair[1])
+            .collect()
+    }
+}
+
+impl UbxPacketCreator for UbxPollRequest {
+    fn create_packet<T: MemWriter>(self, out: &mut T) -> Result<(), MemWriterError<T::Error>> {
+        out.reserve_allocate(Self::PACKET_LEN)?;
+        out.write(&self.into_packet_bytes())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct UbxUnknownPacketOwned<const MAX_PAYLOAD_LEN: usize> {
     pub payload: [u8; MAX_PAYLOAD_LEN],
@@ -96,25 +354,63 @@ pub struct UbxUnknownPacketOwned<const MAX_PAYLOAD_LEN: usize> {
     pub msg_id: u8,
 }
 
-/// Request specific packet
-pub struct UbxPacketRequest {
+#[cfg(feature = "defmt")]
+impl<const MAX_PAYLOAD_LEN: usize> defmt::Format for UbxUnknownPacketOwned<MAX_PAYLOAD_LEN> {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(
+            f,
+            "UbxUnknownPacketOwned {{ payload: {=[u8]}, class: {=u8}, msg_id: {=u8} }}",
+            &self.payload[..self.payload_len],
+            self.class,
+            self.msg_id
+        )
+    }
+}
+
+/// Poll request for a message: a frame of the message's class and id with an empty payload,
+/// which most receivable messages answer with their current value.
+///
+/// Messages whose poll carries a payload, e.g. UBX-CFG-VALGET which takes the keys to read,
+/// are requested with the builder of their request message instead.
+///
+/// ```
+/// use ublox::{tim_svin::TimSvin, UbxPollRequest};
+///
+/// let frame = UbxPollRequest::for_packet::<TimSvin>().into_packet_bytes();
+/// assert_eq!(frame, UbxPollRequest::new(0x0d, 0x04).into_packet_bytes());
+/// assert_eq!(frame[..6], [0xb5, 0x62, 0x0d, 0x04, 0x00, 0x00]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UbxPollRequest {
     req_class: u8,
     req_id: u8,
 }
 
-impl UbxPacketRequest {
+/// Former name of [UbxPollRequest]
+pub type UbxPacketRequest = UbxPollRequest;
+
+impl UbxPollRequest {
     pub const PACKET_LEN: usize = 8;
 
+    /// Poll the message with the given class and id
+    #[inline]
+    pub const fn new(req_class: u8, req_id: u8) -> Self {
+        Self { req_class, req_id }
+    }
+
+    /// Poll the message `T`
+    #[inline]
+    pub const fn for_packet<T: UbxPacketMeta + ?Sized>() -> Self {
+        Self::new(T::CLASS, T::ID)
+    }
+
     #[inline]
     pub fn request_for<T: UbxPacketMeta>() -> Self {
-        Self {
-            req_class: T::CLASS,
-            req_id: T::ID,
-        }
+        Self::for_packet::<T>()
     }
     #[inline]
     pub fn request_for_unknown(req_class: u8, req_id: u8) -> Self {
-        Self { req_class, req_id }
+        Self::new(req_class, req_id)
     }
 
     #[inline]
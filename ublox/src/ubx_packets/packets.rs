@@ -17,9 +17,11 @@ pub mod ack;
 pub mod aid_ini;
 
 pub mod cfg_ant;
+pub mod cfg_cfg;
 pub mod cfg_esf_alg;
 pub mod cfg_esf_wt;
 pub mod cfg_gnss;
+pub mod cfg_hnr;
 pub mod cfg_inf;
 pub mod cfg_itfm;
 pub mod cfg_msg;
@@ -53,15 +55,20 @@ pub mod inf_test;
 pub mod inf_warning;
 
 pub mod mga_ack;
+pub mod mga_bds_alm;
 pub mod mga_bds_eph;
 pub mod mga_bds_iono;
 pub mod mga_bds_utc;
+pub mod mga_gal_alm;
 pub mod mga_gal_eph;
 pub mod mga_gal_time;
+pub mod mga_glo_alm;
 pub mod mga_glo_eph;
+pub mod mga_gps_alm;
 pub mod mga_gps_eph;
 pub mod mga_gps_iono;
 pub mod mga_gps_utc;
+pub mod mga_ini;
 
 pub mod mon_comms;
 pub mod mon_gnss;
@@ -78,6 +85,7 @@ pub mod mon_span;
 pub mod mon_txbuf;
 pub mod mon_ver;
 
+pub mod nav_aop_status;
 pub mod nav_att;
 pub mod nav_clock;
 pub mod nav_cov;
@@ -94,6 +102,9 @@ pub mod nav_sat;
 pub mod nav_sig;
 pub mod nav_sol;
 pub mod nav_status;
+pub mod nav_time_bds;
+pub mod nav_time_gal;
+pub mod nav_time_glo;
 pub mod nav_time_gps;
 pub mod nav_time_ls;
 pub mod nav_time_utc;
@@ -105,6 +116,7 @@ pub mod rxm_rawx;
 pub mod rxm_rtcm;
 pub mod rxm_sfrbx;
 
+pub mod sec_osnma;
 pub mod sec_sig;
 pub mod sec_siglog;
 pub mod sec_uniq_id;
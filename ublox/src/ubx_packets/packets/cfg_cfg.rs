@@ -0,0 +1,110 @@
+use bitflags::bitflags;
+
+use crate::{ubx_checksum, MemWriter, MemWriterError, UbxPacketCreator, UbxPacketMeta};
+use ublox_derive::{ubx_extend_bitflags, ubx_packet_send};
+
+/// Clear, save and load configurations: saves the current configuration to non-volatile
+/// memory, loads it back or reverts to the default configuration. The receiver clears, then
+/// saves, then loads the masked sections, and acknowledges the message once done.
+#[ubx_packet_send]
+#[ubx(
+    class = 0x06,
+    id = 0x09,
+    fixed_payload_len = 13,
+    flags = "default_for_builder"
+)]
+struct CfgCfg {
+    /// Sections to reset to the default configuration in the permanent configuration
+    #[ubx(map_type = CfgSections)]
+    clear_mask: u32,
+
+    /// Sections to save from the current configuration to the permanent configuration
+    #[ubx(map_type = CfgSections)]
+    save_mask: u32,
+
+    /// Sections to load from the permanent configuration into the current configuration
+    #[ubx(map_type = CfgSections)]
+    load_mask: u32,
+
+    /// Memories the permanent configuration is cleared in, saved to or loaded from
+    #[ubx(map_type = CfgDevices)]
+    device_mask: u8,
+}
+
+#[ubx_extend_bitflags]
+#[ubx(into_raw, rest_reserved)]
+bitflags! {
+    /// Configuration sections of a [CfgCfg] mask
+    #[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct CfgSections: u32 {
+        /// Communication port settings
+        const IO_PORT = 0x01;
+        /// Message settings, enabled messages and their rates
+        const MSG_CONF = 0x02;
+        /// UBX-INF message settings
+        const INF_MSG = 0x04;
+        /// Navigation settings
+        const NAV_CONF = 0x08;
+        /// Receiver manager settings
+        const RXM_CONF = 0x10;
+        /// Sensor interface settings, from protocol 19 on
+        const SEN_CONF = 0x100;
+        /// Remote inventory settings
+        const RINV_CONF = 0x200;
+        /// Antenna settings
+        const ANT_CONF = 0x400;
+        /// Logging settings
+        const LOG_CONF = 0x800;
+        /// FTS settings, for FTS product variants only
+        const FTS_CONF = 0x1000;
+    }
+}
+
+#[ubx_extend_bitflags]
+#[ubx(into_raw, rest_reserved)]
+bitflags! {
+    /// Non-volatile memories of a [CfgCfg] device mask
+    #[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct CfgDevices: u8 {
+        /// Battery backed RAM
+        const BBR = 0x01;
+        /// Flash
+        const FLASH = 0x02;
+        /// EEPROM
+        const EEPROM = 0x04;
+        /// SPI flash
+        const SPI_FLASH = 0x10;
+    }
+}
+
+impl CfgCfgBuilder {
+    /// Save the whole current configuration to `devices`, so that it survives a power cycle
+    pub fn save(devices: CfgDevices) -> Self {
+        Self {
+            save_mask: CfgSections::all(),
+            device_mask: devices,
+            ..Self::default()
+        }
+    }
+
+    /// Load the configuration saved in `devices` into the current configuration, discarding
+    /// the changes made since
+    pub fn load(devices: CfgDevices) -> Self {
+        Self {
+            load_mask: CfgSections::all(),
+            device_mask: devices,
+            ..Self::default()
+        }
+    }
+
+    /// Clear the configuration saved in `devices` and load the defaults into the current
+    /// configuration
+    pub fn revert_to_defaults(devices: CfgDevices) -> Self {
+        Self {
+            clear_mask: CfgSections::all(),
+            load_mask: CfgSections::all(),
+            device_mask: devices,
+            ..Self::default()
+        }
+    }
+}
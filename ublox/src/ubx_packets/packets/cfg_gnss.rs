@@ -485,6 +485,28 @@ impl<'a> CfgGnssBuilder<'a> {
     }
 }
 
+/// Builder with the fields and blocks of a polled configuration, to modify it and send it back.
+/// The blocks are read from the received packet.
+impl<'a> From<&CfgGnssRef<'a>> for CfgGnssBuilder<'a> {
+    fn from(packet: &CfgGnssRef<'a>) -> Self {
+        const BLOCKS_OFFSET: usize = CfgGnss::OFFSET_NUM_CONFIG_BLOCKS + 1;
+        let blocks = &packet.0[BLOCKS_OFFSET..BLOCKS_OFFSET + packet.data_len()];
+        Self {
+            msg_version: packet.msg_version(),
+            num_trk_ch_hw: packet.num_trk_ch_hw(),
+            num_trk_ch_use: packet.num_trk_ch_use(),
+            num_config_blocks: packet.num_config_blocks(),
+            blocks: GnssConfigBlockIter::new(blocks),
+        }
+    }
+}
+
+impl<'a> From<&'a CfgGnssOwned> for CfgGnssBuilder<'a> {
+    fn from(packet: &'a CfgGnssOwned) -> Self {
+        Self::from(&CfgGnssRef(packet.as_bytes()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[allow(unused_imports, reason = "unused in some feature sets")]
@@ -534,6 +556,40 @@ mod tests {
         assert_eq!(parsed[1].gnss_id as u8, blocks[1].gnss_id as u8);
     }
 
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn builder_from_received_packet() {
+        let blocks = [
+            GnssConfigBlock {
+                gnss_id: GnssId::GPS,
+                res_trk_ch: 8,
+                max_trk_ch: 16,
+                reserved1: 0,
+                flags: 0x0001_0001,
+            },
+            GnssConfigBlock {
+                gnss_id: GnssId::GLONASS,
+                res_trk_ch: 8,
+                max_trk_ch: 12,
+                reserved1: 0,
+                flags: 0x0001_0000,
+            },
+        ];
+        let mut sent = Vec::new();
+        CfgGnssBuilder {
+            num_trk_ch_hw: 32,
+            num_trk_ch_use: 32,
+            ..Default::default()
+        }
+        .with_blocks(&blocks)
+        .extend_to(&mut sent);
+
+        let payload = &sent[6..sent.len() - 2];
+        let mut resent = Vec::new();
+        CfgGnssBuilder::from(&CfgGnssRef(payload)).extend_to(&mut resent);
+        assert_eq!(resent, sent);
+    }
+
     #[test]
     fn gnss_id_try_from_navic() {
         assert_eq!(GnssId::try_from(7u8), Ok(GnssId::NAVIC));
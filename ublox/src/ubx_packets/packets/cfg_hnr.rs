@@ -0,0 +1,25 @@
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use crate::{
+    error::ParserError, ubx_checksum, MemWriter, MemWriterError, UbxPacketCreator, UbxPacketMeta,
+};
+use ublox_derive::ubx_packet_recv_send;
+
+/// High Navigation Rate Settings
+/// Only available for ADR/UDR products, see `RateNavPrio` in
+/// [CfgVal](crate::cfg_val::CfgVal) for the generation 9 equivalent
+#[ubx_packet_recv_send]
+#[ubx(
+    class = 0x06,
+    id = 0x5C,
+    fixed_payload_len = 4,
+    flags = "default_for_builder"
+)]
+struct CfgHnr {
+    /// Rate of the HNR-* navigation solution output in Hz
+    high_nav_rate: u8,
+    reserved1: [u8; 3],
+}
@@ -17,66 +17,74 @@ use ublox_derive::{ubx_extend, ubx_extend_bitflags, ubx_packet_recv_send};
     class = 0x06,
     id = 0x24,
     fixed_payload_len = 36,
-    flags = "default_for_builder"
+    flags = "default_for_builder, setters"
 )]
 struct CfgNav5 {
     /// Only the masked parameters will be applied
     #[ubx(map_type = CfgNav5Params)]
     mask: u16,
-    #[ubx(map_type = NavDynamicModel, may_fail)]
+    #[ubx(map_type = NavDynamicModel, may_fail, apply_mask = CfgNav5Params::DYN)]
     dyn_model: u8,
-    #[ubx(map_type = NavFixMode, may_fail)]
+    #[ubx(map_type = NavFixMode, may_fail, apply_mask = CfgNav5Params::POS_FIX_MODE)]
     fix_mode: u8,
 
     /// Fixed altitude (mean sea level) for 2D fixmode [m]
-    #[ubx(map_type = f64, scale = 0.01)]
+    #[ubx(map_type = f64, scale = 0.01, apply_mask = CfgNav5Params::POS_FIX_MODE)]
     fixed_alt: i32,
 
     /// Fixed altitude variance for 2D mode (m^2)
-    #[ubx(map_type = f64, scale = 0.0001)]
+    #[ubx(map_type = f64, scale = 0.0001, apply_mask = CfgNav5Params::POS_FIX_MODE)]
     fixed_alt_var: u32,
 
     /// Minimum Elevation for a GNSS satellite to be used in NAV (deg)
+    #[ubx(apply_mask = CfgNav5Params::MIN_EL)]
     min_elev_degrees: i8,
 
     /// Reserved
+    #[ubx(apply_mask = CfgNav5Params::DR_LIM)]
     dr_limit: u8,
 
     /// Position DOP Mask to use
-    #[ubx(map_type = f32, scale = 0.1)]
+    #[ubx(map_type = f32, scale = 0.1, apply_mask = CfgNav5Params::POS_MASK_APPLY)]
     pdop: u16,
 
     /// Time DOP Mask to use
-    #[ubx(map_type = f32, scale = 0.1)]
+    #[ubx(map_type = f32, scale = 0.1, apply_mask = CfgNav5Params::TIME_MASK)]
     tdop: u16,
 
     /// Position Accuracy Mask [m]
+    #[ubx(apply_mask = CfgNav5Params::POS_MASK_APPLY)]
     pacc: u16,
 
     /// Time Accuracy Mask
     /// according to manual unit is "m", but this looks like typo
+    #[ubx(apply_mask = CfgNav5Params::TIME_MASK)]
     tacc: u16,
 
     /// Static hold threshold
-    #[ubx(map_type = f32, scale = 0.01)]
+    #[ubx(map_type = f32, scale = 0.01, apply_mask = CfgNav5Params::STATIC_HOLD_MASK)]
     static_hold_thresh: u8,
 
     /// DGNSS timeout (seconds)
+    #[ubx(apply_mask = CfgNav5Params::DGPS_MASK)]
     dgps_time_out: u8,
 
     /// Number of satellites required to have
     /// C/N0 above `cno_thresh` for a fix to be attempted
+    #[ubx(apply_mask = CfgNav5Params::CNO_THRESHOLD)]
     cno_thresh_num_svs: u8,
 
     /// C/N0 threshold for deciding whether toattempt a fix (dBHz)
+    #[ubx(apply_mask = CfgNav5Params::CNO_THRESHOLD)]
     cno_thresh: u8,
     reserved1: [u8; 2],
 
     /// Static hold distance threshold (beforequitting static hold)
+    #[ubx(apply_mask = CfgNav5Params::STATIC_HOLD_MASK)]
     static_hold_max_dist: u16,
 
     /// UTC standard to be used
-    #[ubx(map_type = UtcStandardIdentifier, may_fail)]
+    #[ubx(map_type = UtcStandardIdentifier, may_fail, apply_mask = CfgNav5Params::UTC)]
     utc_standard: u8,
     reserved2: [u8; 5],
 }
@@ -10,16 +10,20 @@ use crate::{
 };
 use ublox_derive::{ubx_extend_bitflags, ubx_packet_recv_send};
 
-/// Navigation Engine Expert Settings
+/// Navigation Engine Expert Settings, message versions 0 and 2
+///
+/// Version 0 is used up to protocol version 17 and has no `sig_atten_comp_mode` or
+/// `CfgNavX5Params2::USE_SIG_ATTEN_COMP`, the field is reserved there. Polling the message
+/// returns the version of the receiver's firmware, which is [CfgNavX5V3] on some products.
 #[ubx_packet_recv_send]
 #[ubx(
     class = 0x06,
     id = 0x23,
     fixed_payload_len = 40,
-    flags = "default_for_builder"
+    flags = "default_for_builder, setters"
 )]
 struct CfgNavX5 {
-    /// Only version 2 supported
+    /// Message version, 0 or 2
     version: u16,
 
     /// Only the masked parameters will be applied
@@ -33,30 +37,50 @@ struct CfgNavX5 {
     reserved1: [u8; 2],
 
     /// Minimum number of satellites for navigation
+    #[ubx(apply_mask = CfgNavX5Params1::MIN_MAX)]
     min_svs: u8,
 
     ///Maximum number of satellites for navigation
+    #[ubx(apply_mask = CfgNavX5Params1::MIN_MAX)]
     max_svs: u8,
 
     /// Minimum satellite signal level for navigation
+    #[ubx(apply_mask = CfgNavX5Params1::MIN_CNO)]
     min_cno_dbhz: u8,
 
     /// Reserved
     reserved2: u8,
 
     /// initial fix must be 3D
+    #[ubx(
+        map_type = bool,
+        from = CfgNavX5::flag,
+        into = CfgNavX5::flag_raw,
+        apply_mask = CfgNavX5Params1::INITIAL_3D_FIX
+    )]
     ini_fix_3d: u8,
 
     /// Reserved
     reserved3: [u8; 2],
 
     /// issue acknowledgements for assistance message input
+    #[ubx(
+        map_type = bool,
+        from = CfgNavX5::flag,
+        into = CfgNavX5::flag_raw,
+        apply_mask = CfgNavX5Params1::AID_ACK
+    )]
     ack_aiding: u8,
 
     /// GPS week rollover number
+    #[ubx(apply_mask = CfgNavX5Params1::WKN_ROLL)]
     wkn_rollover: u16,
 
     /// Permanently attenuated signal compensation
+    #[ubx(
+        map_type = SigAttenCompMode,
+        apply_mask = CfgNavX5Params2::USE_SIG_ATTEN_COMP
+    )]
     sig_atten_comp_mode: u8,
 
     /// Reserved
@@ -65,15 +89,24 @@ struct CfgNavX5 {
     reserved6: [u8; 2],
 
     /// Use Precise Point Positioning (only available with the PPP product variant)
+    #[ubx(
+        map_type = bool,
+        from = CfgNavX5::flag,
+        into = CfgNavX5::flag_raw,
+        apply_mask = CfgNavX5Params1::USE_PPP
+    )]
     use_ppp: u8,
 
     /// AssistNow Autonomous configuration
+    #[ubx(map_type = AopCfg, apply_mask = CfgNavX5Params1::AOP_CFG)]
     aop_cfg: u8,
 
     /// Reserved
     reserved7: [u8; 2],
 
-    /// Maximum acceptable (modeled) AssistNow Autonomous orbit error
+    /// Maximum acceptable (modeled) AssistNow Autonomous orbit error in m, 0 for the
+    /// firmware default
+    #[ubx(apply_mask = CfgNavX5Params1::AOP_CFG)]
     aop_orb_max_err: u16,
 
     /// Reserved
@@ -81,9 +114,139 @@ struct CfgNavX5 {
     reserved9: [u8; 3],
 
     /// Enable/disable ADR/UDR sensor fusion
+    #[ubx(
+        map_type = bool,
+        from = CfgNavX5::flag,
+        into = CfgNavX5::flag_raw,
+        apply_mask = CfgNavX5Params2::USE_ADR
+    )]
     use_adr: u8,
 }
 
+/// Navigation Engine Expert Settings, message version 3
+///
+/// Same as [CfgNavX5] with 4 more reserved bytes at the end.
+#[ubx_packet_recv_send]
+#[ubx(
+    class = 0x06,
+    id = 0x23,
+    fixed_payload_len = 44,
+    flags = "default_for_builder, setters"
+)]
+struct CfgNavX5V3 {
+    /// Message version, 3
+    version: u16,
+
+    /// Only the masked parameters will be applied
+    #[ubx(map_type = CfgNavX5Params1)]
+    mask1: u16,
+
+    #[ubx(map_type = CfgNavX5Params2)]
+    mask2: u32,
+
+    /// Reserved
+    reserved1: [u8; 2],
+
+    /// Minimum number of satellites for navigation
+    #[ubx(apply_mask = CfgNavX5Params1::MIN_MAX)]
+    min_svs: u8,
+
+    ///Maximum number of satellites for navigation
+    #[ubx(apply_mask = CfgNavX5Params1::MIN_MAX)]
+    max_svs: u8,
+
+    /// Minimum satellite signal level for navigation
+    #[ubx(apply_mask = CfgNavX5Params1::MIN_CNO)]
+    min_cno_dbhz: u8,
+
+    /// Reserved
+    reserved2: u8,
+
+    /// initial fix must be 3D
+    #[ubx(
+        map_type = bool,
+        from = CfgNavX5::flag,
+        into = CfgNavX5::flag_raw,
+        apply_mask = CfgNavX5Params1::INITIAL_3D_FIX
+    )]
+    ini_fix_3d: u8,
+
+    /// Reserved
+    reserved3: [u8; 2],
+
+    /// issue acknowledgements for assistance message input
+    #[ubx(
+        map_type = bool,
+        from = CfgNavX5::flag,
+        into = CfgNavX5::flag_raw,
+        apply_mask = CfgNavX5Params1::AID_ACK
+    )]
+    ack_aiding: u8,
+
+    /// GPS week rollover number
+    #[ubx(apply_mask = CfgNavX5Params1::WKN_ROLL)]
+    wkn_rollover: u16,
+
+    /// Permanently attenuated signal compensation
+    #[ubx(
+        map_type = SigAttenCompMode,
+        apply_mask = CfgNavX5Params2::USE_SIG_ATTEN_COMP
+    )]
+    sig_atten_comp_mode: u8,
+
+    /// Reserved
+    reserved4: u8,
+    reserved5: [u8; 2],
+    reserved6: [u8; 2],
+
+    /// Use Precise Point Positioning (only available with the PPP product variant)
+    #[ubx(
+        map_type = bool,
+        from = CfgNavX5::flag,
+        into = CfgNavX5::flag_raw,
+        apply_mask = CfgNavX5Params1::USE_PPP
+    )]
+    use_ppp: u8,
+
+    /// AssistNow Autonomous configuration
+    #[ubx(map_type = AopCfg, apply_mask = CfgNavX5Params1::AOP_CFG)]
+    aop_cfg: u8,
+
+    /// Reserved
+    reserved7: [u8; 2],
+
+    /// Maximum acceptable (modeled) AssistNow Autonomous orbit error in m, 0 for the
+    /// firmware default
+    #[ubx(apply_mask = CfgNavX5Params1::AOP_CFG)]
+    aop_orb_max_err: u16,
+
+    /// Reserved
+    reserved8: [u8; 4],
+    reserved9: [u8; 3],
+
+    /// Enable/disable ADR/UDR sensor fusion
+    #[ubx(
+        map_type = bool,
+        from = CfgNavX5::flag,
+        into = CfgNavX5::flag_raw,
+        apply_mask = CfgNavX5Params2::USE_ADR
+    )]
+    use_adr: u8,
+
+    /// Reserved
+    reserved10: [u8; 4],
+}
+
+impl CfgNavX5 {
+    const fn flag(raw: u8) -> bool {
+        raw != 0
+    }
+
+    const fn flag_raw(enabled: bool) -> u8 {
+        enabled as u8
+    }
+}
+
 #[ubx_extend_bitflags]
 #[ubx(from, into_raw, rest_reserved)]
 bitflags! {
@@ -119,3 +282,46 @@ bitflags! {
         const USE_SIG_ATTEN_COMP = 0x80;
     }
 }
+
+#[ubx_extend_bitflags]
+#[ubx(from, into_raw, rest_reserved)]
+bitflags! {
+    /// AssistNow Autonomous configuration
+    #[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct AopCfg: u8 {
+        /// Use AssistNow Autonomous
+        const USE_AOP = 0x01;
+    }
+}
+
+/// Compensation of permanently attenuated signals, e.g. from an antenna behind a windshield
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SigAttenCompMode {
+    #[default]
+    Disabled,
+    /// The receiver determines the maximum C/N0 of the signals
+    Automatic,
+    /// Maximum C/N0 of the signals in dBHz, from 1 to 63
+    MaxCno(u8),
+}
+
+impl SigAttenCompMode {
+    pub const fn into_raw(self) -> u8 {
+        match self {
+            Self::Disabled => 0,
+            Self::Automatic => 255,
+            Self::MaxCno(cno) => cno,
+        }
+    }
+}
+
+impl From<u8> for SigAttenCompMode {
+    fn from(raw: u8) -> Self {
+        match raw {
+            0 => Self::Disabled,
+            255 => Self::Automatic,
+            cno => Self::MaxCno(cno),
+        }
+    }
+}
@@ -16,7 +16,7 @@ use ublox_derive::{ubx_extend, ubx_extend_bitflags, ubx_packet_recv_send};
     class = 0x06,
     id = 0x00,
     fixed_payload_len = 20,
-    flags = "default_for_builder"
+    flags = "default_for_builder, setters"
 )]
 struct CfgPrtI2c {
     #[ubx(map_type = I2cPortId, may_fail)]
@@ -48,7 +48,7 @@ pub enum I2cPortId {
 
 /// Port Configuration for UART
 #[ubx_packet_recv_send]
-#[ubx(class = 0x06, id = 0x00, fixed_payload_len = 20)]
+#[ubx(class = 0x06, id = 0x00, fixed_payload_len = 20, flags = "setters")]
 struct CfgPrtUart {
     #[ubx(map_type = UartPortId, may_fail)]
     portid: u8,
@@ -65,6 +65,24 @@ struct CfgPrtUart {
     reserved5: u16,
 }
 
+/// Factory default configuration of a UART: 9600 baud 8N1, UBX, NMEA and RTCM3 input, UBX
+/// and NMEA output
+impl Default for CfgPrtUartBuilder {
+    fn default() -> Self {
+        Self {
+            portid: UartPortId::Uart1,
+            reserved0: 0,
+            tx_ready: 0,
+            mode: UartMode::new(DataBits::Eight, Parity::None, StopBits::One),
+            baud_rate: 9600,
+            in_proto_mask: InProtoMask::UBLOX | InProtoMask::NMEA | InProtoMask::RTCM3,
+            out_proto_mask: OutProtoMask::UBLOX | OutProtoMask::NMEA,
+            flags: 0,
+            reserved5: 0,
+        }
+    }
+}
+
 /// Port Identifier Number (= 1 or 2 for UART ports)
 #[ubx_extend]
 #[ubx(from_unchecked, into_raw, rest_error)]
@@ -217,7 +235,7 @@ impl From<u32> for StopBits {
     class = 0x06,
     id = 0x00,
     fixed_payload_len = 20,
-    flags = "default_for_builder"
+    flags = "default_for_builder, setters"
 )]
 struct CfgPrtSpi {
     #[ubx(map_type = SpiPortId, may_fail)]
@@ -17,9 +17,59 @@ struct CfgRst {
     reserved1: u8,
 }
 
+impl CfgRstBuilder {
+    /// Reset in `reset_mode`, clearing the battery backed RAM sections of `nav_bbr_mask` first
+    pub fn new(nav_bbr_mask: impl Into<NavBbrMask>, reset_mode: ResetMode) -> Self {
+        Self {
+            nav_bbr_mask: nav_bbr_mask.into(),
+            reset_mode,
+            reserved1: 0,
+        }
+    }
+
+    /// Restart the GNSS part of the receiver with a hot, warm or cold start. The configuration
+    /// and the connection to the host are kept.
+    pub fn gnss_restart(start: StartType) -> Self {
+        Self::new(
+            start.nav_bbr_mask(),
+            ResetMode::ControlledSoftwareResetGpsOnly,
+        )
+    }
+
+    /// Restart the whole receiver with a hot, warm or cold start. The configuration not saved
+    /// with UBX-CFG-CFG is lost, and USB connections are dropped.
+    pub fn software_reset(start: StartType) -> Self {
+        Self::new(start.nav_bbr_mask(), ResetMode::ControlledSoftwareReset)
+    }
+
+    /// Watchdog reset of the receiver right away, keeping the battery backed RAM
+    pub fn hardware_reset() -> Self {
+        Self::new(
+            NavBbrPredefinedMask::HOT_START,
+            ResetMode::HardwareResetImmediately,
+        )
+    }
+
+    /// Stop the GNSS part of the receiver, e.g. to save power while the host is idle
+    pub fn gnss_stop() -> Self {
+        Self::new(
+            NavBbrPredefinedMask::HOT_START,
+            ResetMode::ControlledGpsStop,
+        )
+    }
+
+    /// Start the GNSS part of the receiver again after [CfgRstBuilder::gnss_stop]
+    pub fn gnss_start() -> Self {
+        Self::new(
+            NavBbrPredefinedMask::HOT_START,
+            ResetMode::ControlledGpsStart,
+        )
+    }
+}
+
 /// Reset Type
 #[repr(u8)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ResetMode {
     /// Hardware reset (Watchdog) immediately
@@ -42,6 +92,7 @@ impl ResetMode {
 #[ubx(into_raw, rest_reserved)]
 bitflags! {
     /// Battery backed RAM sections to clear
+    #[derive(Debug, PartialEq, Eq)]
     pub struct NavBbrMask: u16 {
         const EPHEMERIS = 1;
         const ALMANACH = 2;
@@ -60,7 +111,7 @@ bitflags! {
 }
 
 /// Predefined values for `NavBbrMask`
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[repr(transparent)]
 pub struct NavBbrPredefinedMask(u16);
 
@@ -75,3 +126,31 @@ impl NavBbrPredefinedMask {
     pub const WARM_START: NavBbrPredefinedMask = NavBbrPredefinedMask(1);
     pub const COLD_START: NavBbrPredefinedMask = NavBbrPredefinedMask(0xFFFF);
 }
+
+/// Receiver start type, selecting the battery backed data cleared before the restart
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StartType {
+    /// Keep all navigation data
+    Hot,
+    /// Clear the ephemeris
+    Warm,
+    /// Clear all navigation data
+    Cold,
+}
+
+impl StartType {
+    pub const fn nav_bbr_mask(self) -> NavBbrPredefinedMask {
+        match self {
+            StartType::Hot => NavBbrPredefinedMask::HOT_START,
+            StartType::Warm => NavBbrPredefinedMask::WARM_START,
+            StartType::Cold => NavBbrPredefinedMask::COLD_START,
+        }
+    }
+
+    /// UBX-CFG-RST frame restarting the GNSS part of the receiver with this start type. The
+    /// receiver does not acknowledge it.
+    pub fn reset_packet(self) -> [u8; CfgRstBuilder::PACKET_LEN] {
+        CfgRstBuilder::gnss_restart(self).into_packet_bytes()
+    }
+}
@@ -17,7 +17,7 @@ use ublox_derive::{ubx_extend, ubx_extend_bitflags, ubx_packet_recv_send};
     class = 0x06,
     id = 0x31,
     fixed_payload_len = 32,
-    flags = "default_for_builder"
+    flags = "default_for_builder, setters"
 )]
 struct CfgTp5 {
     #[ubx(map_type = CfgTp5TimePulseMode, may_fail)]
@@ -61,7 +61,7 @@ struct CfgTp5 {
 #[ubx_extend]
 #[ubx(from_unchecked, into_raw, rest_error)]
 #[repr(u8)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum CfgTp5TimePulseMode {
     #[default]
     TimePulse = 0,
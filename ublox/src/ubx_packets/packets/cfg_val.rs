@@ -1,5 +1,7 @@
 use bitflags::bitflags;
 
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::cfg_val::{CfgItem, CfgScaledValue, ScaledCfgItem};
 #[allow(unused_imports, reason = "It is only unused in some feature sets")]
 use crate::FieldIter;
 #[cfg(feature = "alloc")]
@@ -8,7 +10,7 @@ use alloc::vec::Vec;
 use {super::SerializeUbxPacketFields, crate::serde::ser::SerializeMap};
 
 use crate::{
-    cfg_val::{CfgKey, CfgVal},
+    cfg_val::{CfgKey, CfgVal, CfgValue, CfgValueType},
     error::ParserError,
     ubx_checksum, UbxPacketMeta,
 };
@@ -20,13 +22,19 @@ use ublox_derive::{ubx_extend, ubx_extend_bitflags, ubx_packet_recv, ubx_packet_
   id = 0x8a,
   max_payload_len = 772, // 4 + (4 + 8) * 64
 )]
+/// Sets configuration items in the selected layers.
+///
+/// This message is limited to containing a maximum of 64 key-value pairs. Version 1 requests
+/// are part of a transaction, see [CfgValSetTransaction] to set more values at once.
 struct CfgValSet<'a> {
-    /// Message version
+    /// Message version, 0 for a request applied on its own, 1 for a request with `transaction`
     version: u8,
-    /// The layers from which the configuration items should be retrieved
+    /// The layers in which the configuration items should be set
     #[ubx(map_type = CfgLayerSet)]
     layers: u8,
-    reserved1: u16,
+    #[ubx(map_type = CfgTransaction)]
+    transaction: u8,
+    reserved0: u8,
     cfg_data: &'a [CfgVal],
 }
 
@@ -55,6 +63,47 @@ struct CfgValGetRequest<'a> {
     cfg_keys: &'a [CfgKey],
 }
 
+#[ubx_packet_send]
+#[ubx(
+  class = 0x06,
+  id = 0x8c,
+  max_payload_len = 260, // 4 + sizeof(u32) * MAX_CFG_KEYS
+)]
+/// Deletes configuration items from the BBR and Flash layers, so that the value of the layer
+/// below, eventually the default, applies again from the next restart on. The RAM layer
+/// cannot be deleted from.
+///
+/// This message is limited to containing a maximum of 64 key IDs. Version 1 requests are
+/// part of a transaction: the deletions of all requests from [CfgTransaction::Start] to
+/// [CfgTransaction::Apply] are applied together, or none of them if one is rejected.
+struct CfgValDel<'a> {
+    /// Message version, 0 for a request applied on its own, 1 for a request with `transaction`
+    version: u8,
+    /// The layers from which the configuration items should be deleted
+    #[ubx(map_type = CfgLayerSet)]
+    layers: u8,
+    #[ubx(map_type = CfgTransaction)]
+    transaction: u8,
+    reserved0: u8,
+    cfg_keys: &'a [CfgKey],
+}
+
+/// Transaction action of a version 1 UBX-CFG-VALSET or UBX-CFG-VALDEL request
+#[ubx_extend]
+#[ubx(from, into_raw, rest_reserved)]
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CfgTransaction {
+    /// Apply the request on its own, dropping any transaction in progress
+    None = 0,
+    /// Start a transaction, dropping any transaction in progress
+    Start = 1,
+    /// Add the request to the transaction in progress
+    Ongoing = 2,
+    /// Add the request to the transaction in progress and apply all of it
+    Apply = 3,
+}
+
 #[ubx_packet_recv]
 #[ubx(
   class = 0x06,
@@ -110,6 +159,14 @@ impl<'a> CfgValIter<'a> {
     }
 }
 
+impl<'a> CfgValIter<'a> {
+    /// Decode the remaining key/value pairs into [CfgValue]s typed from the key database,
+    /// continuing past keys this crate does not know
+    pub fn typed(&self) -> CfgValueIter<'a> {
+        CfgValueIter { data: self.data }
+    }
+}
+
 impl core::iter::Iterator for CfgValIter<'_> {
     type Item = CfgVal;
 
@@ -126,6 +183,44 @@ impl core::iter::Iterator for CfgValIter<'_> {
     }
 }
 
+/// A key/value pair of a UBX-CFG-VALGET response, see [CfgValIter::typed]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CfgKeyValue {
+    pub key_id: u32,
+    /// `None` for keys this crate does not know, their value is decoded as a boolean or a
+    /// bitfield of the size given by the key id
+    pub key: Option<CfgKey>,
+    pub value: CfgValue,
+}
+
+/// Iterator over the typed key/value pairs of a UBX-CFG-VALGET response, ending at the first
+/// truncated pair or key id without a valid size
+#[derive(Debug, Clone)]
+pub struct CfgValueIter<'a> {
+    data: &'a [u8],
+}
+
+impl core::iter::Iterator for CfgValueIter<'_> {
+    type Item = CfgKeyValue;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let key_bytes = self.data.get(..4)?;
+        let key_id = u32::from_le_bytes([key_bytes[0], key_bytes[1], key_bytes[2], key_bytes[3]]);
+        let raw_type = CfgValueType::raw_for_key(key_id)?;
+        let key = CfgKey::from_id(key_id);
+        // The size encoded in the key id is authoritative, fall back to it should the key
+        // database disagree
+        let value_type = key
+            .and_then(CfgKey::value_type)
+            .filter(|value_type| value_type.size() == raw_type.size())
+            .unwrap_or(raw_type);
+        let value = self.data.get(4..4 + value_type.size())?;
+        let value = value_type.decode(value);
+        self.data = &self.data[4 + value_type.size()..];
+        Some(CfgKeyValue { key_id, key, value })
+    }
+}
+
 /// The `CfgLayerSet` defines the configuration layer used to set configuration values to.
 /// The definition of the Layers for updating the configuration values is different than
 /// the definition of the Layers for reading values, see [CfgLayerGet]
@@ -133,6 +228,7 @@ impl core::iter::Iterator for CfgValIter<'_> {
 #[ubx(from, into_raw, rest_reserved)]
 bitflags! {
     /// A mask describing where configuration is applied.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
     pub struct CfgLayerSet: u8 {
         const RAM = 0b001;
         const BBR = 0b010;
@@ -145,3 +241,329 @@ impl Default for CfgLayerSet {
         Self::RAM | Self::BBR | Self::FLASH
     }
 }
+
+/// Typed list of configuration values, turned into UBX-CFG-VALSET frames without encoding key ids
+/// and values by hand
+///
+/// ```
+/// use ublox::cfg_val::{keys, CfgItems, CfgLayerSet, CfgVal};
+///
+/// let items = CfgItems::new()
+///     .set(keys::RateMeas, 100)
+///     .set(keys::Uart1Baudrate, 115_200)
+///     .set_scaled(keys::RateMeas, 0.2);
+/// assert_eq!(items.values(), [CfgVal::RateMeas(200), CfgVal::Uart1Baudrate(115_200)]);
+///
+/// let frames = items.to_packets(CfgLayerSet::RAM).unwrap();
+/// assert_eq!(frames.len(), 1);
+/// assert_eq!(frames[0][2..4], [0x06, 0x8a]);
+/// ```
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct CfgItems {
+    values: Vec<CfgVal>,
+    /// The [CfgItem::LAYERS] of each value
+    layers: Vec<CfgLayerSet>,
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl Default for CfgItems {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl CfgItems {
+    pub const fn new() -> Self {
+        Self {
+            values: Vec::new(),
+            layers: Vec::new(),
+        }
+    }
+
+    /// Set the key `K` to `value`, replacing the value set earlier for the same key
+    pub fn set<K: CfgItem>(mut self, _key: K, value: K::Value) -> Self {
+        let value = K::val(value);
+        match self.values.iter().position(|v| v.key() == K::KEY) {
+            Some(i) => {
+                self.values[i] = value;
+                self.layers[i] = K::LAYERS;
+            },
+            None => {
+                self.values.push(value);
+                self.layers.push(K::LAYERS);
+            },
+        }
+        self
+    }
+
+    /// Set the key `K` to the raw value of the physical `value`, e.g. seconds for
+    /// [keys::RateMeas](crate::cfg_val::keys::RateMeas)
+    pub fn set_scaled<K: ScaledCfgItem>(self, key: K, value: f64) -> Self {
+        self.set(key, K::Value::from_scaled(value, K::SCALE))
+    }
+
+    /// The values in the order their keys were first set
+    pub fn values(&self) -> &[CfgVal] {
+        &self.values
+    }
+
+    /// Check that the keys of all values can be set in `layers`
+    pub fn check_layers(&self, layers: CfgLayerSet) -> Result<(), CfgLayerError> {
+        let unsupported = self
+            .values
+            .iter()
+            .zip(&self.layers)
+            .find(|(_, supported)| !supported.contains(layers));
+        match unsupported {
+            Some((value, supported)) => Err(CfgLayerError {
+                key: value.key(),
+                layers: layers.difference(*supported),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// UBX-CFG-VALSET frames applying the values to `layers`, one per [MAX_CFG_KEYS] values.
+    /// Each frame is applied on its own by the receiver.
+    ///
+    /// Fails if a key cannot be set in one of the `layers`.
+    pub fn to_packets(&self, layers: CfgLayerSet) -> Result<Vec<Vec<u8>>, CfgLayerError> {
+        self.check_layers(layers)?;
+        let packets = self
+            .values
+            .chunks(usize::from(MAX_CFG_KEYS))
+            .map(|cfg_data| {
+                let mut packet = Vec::new();
+                CfgValSetBuilder {
+                    version: 0,
+                    layers,
+                    transaction: CfgTransaction::None,
+                    reserved0: 0,
+                    cfg_data,
+                }
+                .extend_to(&mut packet);
+                packet
+            })
+            .collect();
+        Ok(packets)
+    }
+
+    /// UBX-CFG-VALSET frames applying all values to `layers` at once, as one transaction, see
+    /// [CfgValSetTransaction]
+    ///
+    /// Fails if a key cannot be set in one of the `layers`.
+    pub fn to_transaction(&self, layers: CfgLayerSet) -> Result<Vec<Vec<u8>>, CfgLayerError> {
+        self.check_layers(layers)?;
+        let packets = CfgValSetTransaction::new(&self.values, layers)
+            .map(|builder| {
+                let mut packet = Vec::new();
+                builder.extend_to(&mut packet);
+                packet
+            })
+            .collect();
+        Ok(packets)
+    }
+}
+
+/// A configuration key of [CfgItems] that cannot be set in some of the requested layers
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CfgLayerError {
+    pub key: CfgKey,
+    /// The requested layers the key cannot be set in
+    pub layers: CfgLayerSet,
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl core::fmt::Display for CfgLayerError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "configuration key {:?} cannot be set in the layers {:?}",
+            self.key, self.layers
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CfgLayerError {}
+
+/// Splits any number of values into UBX-CFG-VALSET requests of at most [MAX_CFG_KEYS] values
+/// each, sequenced as a single transaction: the receiver applies the values once the last
+/// request is received, or none of them if one is rejected. Each request is acknowledged on
+/// its own, send the next one only after the UBX-ACK-ACK of the previous one.
+///
+/// Values fitting into a single request are sent without a transaction.
+///
+/// ```
+/// use ublox::cfg_val::{CfgLayerSet, CfgTransaction, CfgVal, CfgValSetTransaction};
+///
+/// let values: Vec<_> = (0..100).map(|_| CfgVal::RateMeas(1000)).collect();
+/// let requests: Vec<_> = CfgValSetTransaction::new(&values, CfgLayerSet::RAM)
+///     .map(|builder| (builder.transaction, builder.cfg_data.len()))
+///     .collect();
+/// assert_eq!(requests, [(CfgTransaction::Start, 64), (CfgTransaction::Apply, 36)]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct CfgValSetTransaction<'a> {
+    chunks: core::slice::Chunks<'a, CfgVal>,
+    layers: CfgLayerSet,
+    started: bool,
+}
+
+impl<'a> CfgValSetTransaction<'a> {
+    pub fn new(values: &'a [CfgVal], layers: CfgLayerSet) -> Self {
+        Self {
+            chunks: values.chunks(usize::from(MAX_CFG_KEYS)),
+            layers,
+            started: false,
+        }
+    }
+}
+
+impl<'a> Iterator for CfgValSetTransaction<'a> {
+    type Item = CfgValSetBuilder<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let cfg_data = self.chunks.next()?;
+        let last = self.chunks.len() == 0;
+        let transaction = match (self.started, last) {
+            (false, true) => CfgTransaction::None,
+            (false, false) => CfgTransaction::Start,
+            (true, false) => CfgTransaction::Ongoing,
+            (true, true) => CfgTransaction::Apply,
+        };
+        self.started = true;
+        Some(CfgValSetBuilder {
+            version: u8::from(transaction != CfgTransaction::None),
+            layers: self.layers,
+            transaction,
+            reserved0: 0,
+            cfg_data,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chunks.size_hint()
+    }
+}
+
+impl ExactSizeIterator for CfgValSetTransaction<'_> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn typed_values_include_unknown_keys() {
+        let data = [
+            0x01, 0x00, 0x21, 0x30, 0xe8, 0x03, // CFG-RATE-MEAS, 1000 ms
+            0x99, 0x00, 0xfe, 0x20, 0x07, // Unknown one byte key
+            0x02, 0x00, 0x52, 0x20, 0x09, // CFG-UART1-STOPBITS, out of range
+            0x05, 0x00, 0x52, 0x10, 0x01, // CFG-UART1-ENABLED
+        ];
+        let values: Vec<_> = CfgValIter::new(&data).typed().collect();
+        assert_eq!(
+            values,
+            [
+                CfgKeyValue {
+                    key_id: 0x30210001,
+                    key: Some(CfgKey::RateMeas),
+                    value: CfgValue::U2(1000),
+                },
+                CfgKeyValue {
+                    key_id: 0x20fe0099,
+                    key: None,
+                    value: CfgValue::X1(7),
+                },
+                CfgKeyValue {
+                    key_id: 0x20520002,
+                    key: Some(CfgKey::Uart1StopBits),
+                    value: CfgValue::E1(9),
+                },
+                CfgKeyValue {
+                    key_id: 0x10520005,
+                    key: Some(CfgKey::Uart1Enabled),
+                    value: CfgValue::L(true),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn typed_values_stop_at_truncated_pairs() {
+        let data = [
+            0x01, 0x00, 0x21, 0x30, 0xe8, 0x03, // CFG-RATE-MEAS, 1000 ms
+            0x01, 0x00, 0x52, 0x40, 0x00, 0xc2, // CFG-UART1-BAUDRATE, missing a byte
+        ];
+        assert_eq!(CfgValIter::new(&data).typed().count(), 1);
+
+        // Size bits 0 are not valid
+        let data = [0x01, 0x00, 0x00, 0x00, 0x00];
+        assert_eq!(CfgValIter::new(&data).typed().count(), 0);
+    }
+
+    #[test]
+    fn transaction_sequences_requests() {
+        let values = [CfgVal::RateMeas(1000); 130];
+        let requests: Vec<_> = CfgValSetTransaction::new(&values, CfgLayerSet::RAM)
+            .map(|builder| (builder.version, builder.transaction, builder.cfg_data.len()))
+            .collect();
+        assert_eq!(
+            requests,
+            [
+                (1, CfgTransaction::Start, 64),
+                (1, CfgTransaction::Ongoing, 64),
+                (1, CfgTransaction::Apply, 2),
+            ]
+        );
+
+        let single: Vec<_> = CfgValSetTransaction::new(&values[..64], CfgLayerSet::RAM)
+            .map(|builder| (builder.version, builder.transaction))
+            .collect();
+        assert_eq!(single, [(0, CfgTransaction::None)]);
+        assert_eq!(CfgValSetTransaction::new(&[], CfgLayerSet::RAM).count(), 0);
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    fn values_are_rejected_in_unsupported_layers() {
+        /// CFG-RATE-MEAS restricted to the RAM layer
+        #[derive(Debug, Clone, Copy)]
+        struct RamOnlyRateMeas;
+
+        impl CfgItem for RamOnlyRateMeas {
+            type Value = u16;
+            const KEY: CfgKey = CfgKey::RateMeas;
+            const LAYERS: CfgLayerSet = CfgLayerSet::RAM;
+
+            fn val(value: u16) -> CfgVal {
+                CfgVal::RateMeas(value)
+            }
+        }
+
+        assert_eq!(CfgKey::Uart1Baudrate.layers(), CfgLayerSet::default());
+        let items = CfgItems::new()
+            .set(crate::cfg_val::keys::Uart1Baudrate, 115_200)
+            .set(RamOnlyRateMeas, 100);
+        assert!(items.to_packets(CfgLayerSet::RAM).is_ok());
+        let error = CfgLayerError {
+            key: CfgKey::RateMeas,
+            layers: CfgLayerSet::BBR | CfgLayerSet::FLASH,
+        };
+        assert_eq!(items.to_packets(CfgLayerSet::default()), Err(error));
+        assert_eq!(
+            items
+                .to_transaction(CfgLayerSet::FLASH)
+                .err()
+                .map(|e| e.layers),
+            Some(CfgLayerSet::FLASH)
+        );
+
+        // Setting the key again takes the layers of the new value
+        let items = items.set(crate::cfg_val::keys::RateMeas, 100);
+        assert!(items.to_packets(CfgLayerSet::default()).is_ok());
+    }
+}
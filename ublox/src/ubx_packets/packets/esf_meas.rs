@@ -310,8 +310,10 @@ impl EsfMeasFlags {
     pub const fn into_raw(self) -> u16 {
         self.0
     }
+    /// Input of the time mark sent with the measurements: 0 for none, 1 for EXTINT0 and 2 for
+    /// EXTINT1
     pub fn time_mark_sent(self) -> u8 {
-        ((self.0) & 0x2) as u8
+        (self.0 & 0x3) as u8
     }
 
     pub fn time_mark_edge(self) -> bool {
@@ -405,7 +407,7 @@ impl EsfMeasFlagsBuilder {
 
     pub fn build(self) -> EsfMeasFlags {
         let mut flags = 0u16;
-        flags |= (self.time_mark_sent as u16) << 1; // bits 1-2
+        flags |= self.time_mark_sent as u16; // bits 0-1
         flags |= (self.time_mark_edge as u16) << 2; // bit 2
         flags |= (self.calib_tag_valid as u16) << 3; // bit 3
         flags |= (self.num_meas as u16) << 11; // bits 11-15
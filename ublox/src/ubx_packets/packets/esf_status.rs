@@ -247,7 +247,7 @@ impl EsfSensorStatus {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct EsfSensorStatusIter<'a>(core::slice::ChunksExact<'a, u8>);
 
 impl<'a> EsfSensorStatusIter<'a> {
@@ -261,6 +261,12 @@ impl<'a> EsfSensorStatusIter<'a> {
     }
 }
 
+impl fmt::Debug for EsfSensorStatusIter<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
 impl core::iter::Iterator for EsfSensorStatusIter<'_> {
     type Item = EsfSensorStatus;
 
@@ -428,6 +434,116 @@ pub struct EsfSensorFaults: u8 {
 
 impl From<u8> for EsfSensorFaults {
     fn from(s: u8) -> Self {
-        Self::from_bits(s).unwrap_or(EsfSensorFaults::empty())
+        Self::from_bits_truncate(s)
+    }
+}
+
+impl EsfSensorFaults {
+    /// Iterate over the individual faults set in this block
+    pub fn iter_faults(self) -> impl Iterator<Item = EsfSensorFault> {
+        [
+            (Self::BAD_MEASUREMENT, EsfSensorFault::BadMeasurement),
+            (Self::BAD_TIME_TAG, EsfSensorFault::BadTimeTag),
+            (
+                Self::MISSING_MEASUREMENT,
+                EsfSensorFault::MissingMeasurement,
+            ),
+            (Self::NOISY_MEASUREMENT, EsfSensorFault::NoisyMeasurement),
+        ]
+        .into_iter()
+        .filter(move |(flag, _)| self.contains(*flag))
+        .map(|(_, fault)| fault)
+    }
+}
+
+/// A single fault reported for a sensor in UBX-ESF-STATUS
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum EsfSensorFault {
+    /// Bad measurements detected
+    BadMeasurement,
+    /// Bad measurement time-tags detected
+    BadTimeTag,
+    /// Missing or time-misaligned measurements detected
+    MissingMeasurement,
+    /// High measurement noise-level detected
+    NoisyMeasurement,
+}
+
+impl EsfSensorFault {
+    /// Short human-readable description of the fault
+    pub fn description(self) -> &'static str {
+        match self {
+            Self::BadMeasurement => "bad measurement",
+            Self::BadTimeTag => "bad time tag",
+            Self::MissingMeasurement => "missing measurement",
+            Self::NoisyMeasurement => "noisy measurement",
+        }
+    }
+}
+
+impl fmt::Display for EsfSensorFault {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.description())
+    }
+}
+
+/// Sensor type and fault set of a sensor reporting at least one fault,
+/// as returned by [EsfStatusRef::faulty_sensors]
+#[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct EsfSensorFaultReport {
+    sensor_type: EsfSensorType,
+    faults: EsfSensorFaults,
+}
+
+impl EsfSensorFaultReport {
+    pub fn sensor_type(&self) -> EsfSensorType {
+        self.sensor_type
+    }
+
+    pub fn faults(&self) -> EsfSensorFaults {
+        self.faults
+    }
+
+    pub fn iter_faults(&self) -> impl Iterator<Item = EsfSensorFault> {
+        self.faults.iter_faults()
+    }
+}
+
+impl fmt::Display for EsfSensorFaultReport {
+    /// Formats as e.g. `GyroZ: missing measurement, noisy measurement`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}:", self.sensor_type)?;
+        for (i, fault) in self.iter_faults().enumerate() {
+            let sep = if i == 0 { " " } else { ", " };
+            write!(f, "{sep}{fault}")?;
+        }
+        Ok(())
+    }
+}
+
+fn faulty_sensors(
+    iter: EsfSensorStatusIter<'_>,
+) -> impl Iterator<Item = EsfSensorFaultReport> + '_ {
+    iter.filter(|s| !s.faults().is_empty())
+        .map(|s| EsfSensorFaultReport {
+            sensor_type: s.sensor_type(),
+            faults: s.faults(),
+        })
+}
+
+impl EsfStatusRef<'_> {
+    /// Sensors that report at least one fault, for ADR troubleshooting
+    pub fn faulty_sensors(&self) -> impl Iterator<Item = EsfSensorFaultReport> + '_ {
+        faulty_sensors(self.data())
+    }
+}
+
+impl EsfStatusOwned {
+    /// Sensors that report at least one fault, for ADR troubleshooting
+    pub fn faulty_sensors(&self) -> impl Iterator<Item = EsfSensorFaultReport> + '_ {
+        faulty_sensors(self.data())
     }
 }
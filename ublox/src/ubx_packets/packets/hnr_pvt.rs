@@ -5,6 +5,7 @@ use super::SerializeUbxPacketFields;
 #[cfg(feature = "serde")]
 use crate::serde::ser::SerializeMap;
 
+use crate::ubx_packets::types::{Heading, HeadingSource, ToHeading};
 use crate::{error::ParserError, GnssFixType, UbxPacketMeta};
 use ublox_derive::{ubx_extend_bitflags, ubx_packet_recv};
 
@@ -82,6 +83,34 @@ struct HnrPvt {
     reserved2: [u8; 4],
 }
 
+macro_rules! impl_to_heading {
+    ($type:ty) => {
+        impl ToHeading for $type {
+            fn to_heading(&self) -> Heading {
+                let flags = self.flags();
+                let (heading, source, valid) = if flags.contains(HnrPvtFlags::HEAD_VEH_VALID) {
+                    (self.heading_vehicle(), HeadingSource::Vehicle, true)
+                } else {
+                    (
+                        self.heading_motion(),
+                        HeadingSource::Motion,
+                        flags.contains(HnrPvtFlags::GPS_FIX_OK),
+                    )
+                };
+                Heading {
+                    heading,
+                    accuracy: self.heading_accuracy(),
+                    source,
+                    valid,
+                }
+            }
+        }
+    };
+}
+
+impl_to_heading!(HnrPvtRef<'_>);
+impl_to_heading!(HnrPvtOwned);
+
 #[ubx_extend_bitflags]
 #[ubx(from, rest_reserved)]
 bitflags! {
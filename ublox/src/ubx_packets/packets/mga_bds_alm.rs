@@ -0,0 +1,78 @@
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use crate::{
+    error::ParserError, ubx_checksum, MemWriter, MemWriterError, UbxPacketCreator, UbxPacketMeta,
+};
+
+use ublox_derive::ubx_packet_recv_send;
+
+/// UBX-MGA-BDS ALM frame, the almanac of a BeiDou satellite.
+///
+/// Like [MgaGpsAlm](super::mga_gps_alm::MgaGpsAlm), the fields are kept in the units of the
+/// navigation message.
+#[ubx_packet_recv_send]
+#[ubx(
+    class = 0x13,
+    id = 0x03,
+    fixed_payload_len = 40,
+    flags = "default_for_builder"
+)]
+struct MgaBdsAlm {
+    /// Message type (0x02 for this type)
+    msg_type: u8,
+
+    /// Message version (0x00 for this version)
+    version: u8,
+
+    /// BeiDou satellite identifier
+    sv_id: u8,
+
+    /// Reserved
+    reserved1: u8,
+
+    /// Almanac week number
+    wn_a: u8,
+
+    /// Almanac reference time \[2^12 s\]
+    toa: u8,
+
+    /// Almanac correction of orbit reference inclination at reference time
+    /// \[2^-19 semi-circles\]
+    delta_i: i16,
+
+    /// Almanac square root of semi-major axis \[2^-11 m^1/2\]
+    sqrt_a: u32,
+
+    /// Almanac eccentricity \[2^-21\]
+    e: u32,
+
+    /// Almanac argument of perigee \[2^-23 semi-circles\]
+    omega: i32,
+
+    /// Almanac mean anomaly at reference time \[2^-23 semi-circles\]
+    m0: i32,
+
+    /// Almanac longitude of ascending node of orbital plane computed according to reference
+    /// time \[2^-23 semi-circles\]
+    omega0: i32,
+
+    /// Almanac rate of right ascension \[2^-38 semi-circles/s\]
+    omega_dot: i32,
+
+    /// Almanac satellite clock bias \[2^-20 s\]
+    a0: i16,
+
+    /// Almanac satellite clock rate \[2^-38 s/s\]
+    a1: i16,
+
+    /// Reserved
+    reserved2: [u8; 4],
+}
+
+impl MgaBdsAlmBuilder {
+    /// Message type of a UBX-MGA-BDS ALM frame
+    pub const MSG_TYPE: u8 = 0x02;
+}
@@ -0,0 +1,89 @@
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use crate::{
+    error::ParserError, ubx_checksum, MemWriter, MemWriterError, UbxPacketCreator, UbxPacketMeta,
+};
+
+use ublox_derive::ubx_packet_recv_send;
+
+/// UBX-MGA-GAL ALM frame, the almanac of a Galileo satellite.
+///
+/// Like [MgaGpsAlm](super::mga_gps_alm::MgaGpsAlm), the fields are kept in the units of the
+/// navigation message.
+#[ubx_packet_recv_send]
+#[ubx(
+    class = 0x13,
+    id = 0x02,
+    fixed_payload_len = 32,
+    flags = "default_for_builder"
+)]
+struct MgaGalAlm {
+    /// Message type (0x02 for this type)
+    msg_type: u8,
+
+    /// Message version (0x00 for this version)
+    version: u8,
+
+    /// Galileo satellite identifier
+    sv_id: u8,
+
+    /// Reserved
+    reserved1: u8,
+
+    /// Almanac issue of data
+    ioda: u8,
+
+    /// Almanac reference week number, modulo 4
+    alm_wn_a: u8,
+
+    /// Almanac reference time \[600 s\]
+    toa: u16,
+
+    /// Difference with respect to the square root of the nominal semi-major axis
+    /// \[2^-9 m^1/2\]
+    delta_sqrt_a: i16,
+
+    /// Eccentricity \[2^-16\]
+    e: u16,
+
+    /// Inclination at reference time relative to 56° \[2^-14 semi-circles\]
+    delta_i: i16,
+
+    /// Longitude of ascending node of orbital plane at weekly epoch \[2^-15 semi-circles\]
+    omega0: i16,
+
+    /// Rate of change of right ascension \[2^-33 semi-circles/s\]
+    omega_dot: i16,
+
+    /// Argument of perigee \[2^-15 semi-circles\]
+    omega: i16,
+
+    /// Satellite mean anomaly at reference time \[2^-15 semi-circles\]
+    m0: i16,
+
+    /// Satellite clock correction bias \[2^-19 s\]
+    af0: i16,
+
+    /// Satellite clock correction linear \[2^-38 s/s\]
+    af1: i16,
+
+    /// Satellite E1-B signal health status
+    health_e1b: u8,
+
+    /// Satellite E5b signal health status
+    health_e5b: u8,
+
+    /// Satellite E5a signal health status
+    health_e5a: u8,
+
+    /// Reserved
+    reserved2: [u8; 3],
+}
+
+impl MgaGalAlmBuilder {
+    /// Message type of a UBX-MGA-GAL ALM frame
+    pub const MSG_TYPE: u8 = 0x02;
+}
@@ -0,0 +1,79 @@
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use crate::{
+    error::ParserError, ubx_checksum, MemWriter, MemWriterError, UbxPacketCreator, UbxPacketMeta,
+};
+
+use ublox_derive::ubx_packet_recv_send;
+
+/// UBX-MGA-GLO ALM frame, the almanac of a GLONASS satellite.
+///
+/// Like [MgaGpsAlm](super::mga_gps_alm::MgaGpsAlm), the fields are kept in the units of the
+/// navigation message.
+#[ubx_packet_recv_send]
+#[ubx(
+    class = 0x13,
+    id = 0x06,
+    fixed_payload_len = 36,
+    flags = "default_for_builder"
+)]
+struct MgaGloAlm {
+    /// Message type (0x02 for this type)
+    msg_type: u8,
+
+    /// Message version (0x00 for this version)
+    version: u8,
+
+    /// GLONASS satellite identifier
+    sv_id: u8,
+
+    /// Reserved
+    reserved1: u8,
+
+    /// Reference calendar day number of the almanac within the four-year period \[days\]
+    n: u16,
+
+    /// Type of GLONASS satellite, 1 for GLONASS-M
+    m: u8,
+
+    /// Unhealthy flag at instant of almanac upload, 1 if the satellite is operational
+    c: u8,
+
+    /// Coarse time correction to GLONASS time \[2^-18 s\]
+    tau: i16,
+
+    /// Eccentricity \[2^-20\]
+    epsilon: u16,
+
+    /// Longitude of the first ascending node of the orbit \[2^-20 semi-circles\]
+    lambda: i32,
+
+    /// Correction to the mean value of inclination \[2^-20 semi-circles\]
+    delta_i: i32,
+
+    /// Time of the first ascending node passage \[2^-5 s\]
+    t_lambda: u32,
+
+    /// Correction to the mean value of the Draconian period \[2^-9 s/orbit period\]
+    delta_t: i32,
+
+    /// Rate of change of the Draconian period \[2^-14 s/orbit period^2\]
+    delta_dt: i8,
+
+    /// Carrier frequency number of the navigation RF signal
+    h: i8,
+
+    /// Argument of perigee \[2^-15 semi-circles\]
+    omega: i16,
+
+    /// Reserved
+    reserved2: [u8; 4],
+}
+
+impl MgaGloAlmBuilder {
+    /// Message type of a UBX-MGA-GLO ALM frame
+    pub const MSG_TYPE: u8 = 0x02;
+}
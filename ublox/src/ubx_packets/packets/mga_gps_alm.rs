@@ -0,0 +1,77 @@
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use crate::{
+    error::ParserError, ubx_checksum, MemWriter, MemWriterError, UbxPacketCreator, UbxPacketMeta,
+};
+
+use ublox_derive::ubx_packet_recv_send;
+
+/// UBX-MGA-GPS ALM frame, the almanac of a GPS satellite.
+///
+/// The orbital parameters are kept in the units of the broadcast navigation message, given
+/// with each field, so that almanacs decoded from AssistNow data or from the navigation
+/// message are forwarded without loss.
+#[ubx_packet_recv_send]
+#[ubx(
+    class = 0x13,
+    id = 0x00,
+    fixed_payload_len = 36,
+    flags = "default_for_builder"
+)]
+struct MgaGpsAlm {
+    /// Message type (0x02 for this type)
+    msg_type: u8,
+
+    /// Message version (0x00 for this version)
+    version: u8,
+
+    /// GPS satellite identifier
+    sv_id: u8,
+
+    /// Satellite health
+    sv_health: u8,
+
+    /// Eccentricity \[2^-21\]
+    e: u16,
+
+    /// Reference week number of the almanac, modulo 256
+    alm_wn_a: u8,
+
+    /// Reference time of the almanac \[2^12 s\]
+    toa: u8,
+
+    /// Inclination correction relative to 0.3 semi-circles \[2^-19 semi-circles\]
+    delta_i: i16,
+
+    /// Rate of right ascension \[2^-38 semi-circles/s\]
+    omega_dot: i16,
+
+    /// Square root of the semi-major axis \[2^-11 m^1/2\]
+    sqrt_a: u32,
+
+    /// Longitude of ascending node of orbit plane \[2^-23 semi-circles\]
+    omega0: i32,
+
+    /// Argument of perigee \[2^-23 semi-circles\]
+    omega: i32,
+
+    /// Mean anomaly at reference time \[2^-23 semi-circles\]
+    m0: i32,
+
+    /// Time polynomial coefficient 0 \[2^-20 s\]
+    af0: i16,
+
+    /// Time polynomial coefficient 1 \[2^-38 s/s\]
+    af1: i16,
+
+    /// Reserved
+    reserved1: [u8; 4],
+}
+
+impl MgaGpsAlmBuilder {
+    /// Message type of a UBX-MGA-GPS ALM frame
+    pub const MSG_TYPE: u8 = 0x02;
+}
@@ -0,0 +1,149 @@
+use chrono::prelude::*;
+use core::time::Duration;
+
+use crate::{
+    ubx_checksum, ubx_packets::packets::ScaleBack, MemWriter, MemWriterError, PositionLLA,
+    UbxPacketCreator, UbxPacketMeta,
+};
+use ublox_derive::ubx_packet_send;
+
+/// UBX-MGA-INI POS_LLH frame: initial position assistance, replacing UBX-AID-INI on receivers
+/// supporting UBX-MGA messages
+#[ubx_packet_send]
+#[ubx(
+    class = 0x13,
+    id = 0x40,
+    fixed_payload_len = 20,
+    flags = "default_for_builder"
+)]
+struct MgaIniPosLlh {
+    /// Message type (0x01 for this type)
+    msg_type: u8,
+
+    /// Message version (0x00 for this version)
+    version: u8,
+
+    /// Reserved
+    reserved1: [u8; 2],
+
+    /// Latitude \[deg\]
+    #[ubx(map_type = f64, scale = 1e-7)]
+    lat: i32,
+
+    /// Longitude \[deg\]
+    #[ubx(map_type = f64, scale = 1e-7)]
+    lon: i32,
+
+    /// Altitude above the WGS84 ellipsoid \[m\]
+    #[ubx(map_type = f64, scale = 1e-2)]
+    alt: i32,
+
+    /// Position accuracy, standard deviation \[m\]
+    #[ubx(map_type = f64, scale = 1e-2)]
+    pos_acc: u32,
+}
+
+impl MgaIniPosLlhBuilder {
+    /// Message type of a UBX-MGA-INI POS_LLH frame
+    pub const MSG_TYPE: u8 = 0x01;
+
+    /// Initial `position`, known to `accuracy` \[m\]. The altitude of `position` is taken as
+    /// above the ellipsoid.
+    pub fn new(position: PositionLLA, accuracy: f64) -> Self {
+        Self {
+            msg_type: Self::MSG_TYPE,
+            lat: position.lat,
+            lon: position.lon,
+            alt: position.alt,
+            pos_acc: accuracy,
+            ..Self::default()
+        }
+    }
+}
+
+/// UBX-MGA-INI TIME_UTC frame: initial time assistance, replacing UBX-AID-INI on receivers
+/// supporting UBX-MGA messages
+#[ubx_packet_send]
+#[ubx(
+    class = 0x13,
+    id = 0x40,
+    fixed_payload_len = 24,
+    flags = "default_for_builder"
+)]
+struct MgaIniTimeUtc {
+    /// Message type (0x10 for this type)
+    msg_type: u8,
+
+    /// Message version (0x00 for this version)
+    version: u8,
+
+    /// Time reference: bits 0-3 are the source, 0 for the receipt of the message and 1 or 2
+    /// for the last or next EXTINT0/1 pulse, bit 4 is set for a falling edge and bit 5 for the
+    /// last pulse instead of the next one
+    reference: u8,
+
+    /// Number of leap seconds since 1980, -128 if unknown
+    leap_secs: i8,
+
+    /// Year
+    year: u16,
+
+    /// Month, 1..12
+    month: u8,
+
+    /// Day, 1..31
+    day: u8,
+
+    /// Hour, 0..23
+    hour: u8,
+
+    /// Minute, 0..59
+    minute: u8,
+
+    /// Second, 0..60
+    second: u8,
+
+    /// Reserved
+    reserved1: u8,
+
+    /// Nanoseconds, 0..999,999,999
+    ns: u32,
+
+    /// Seconds part of the time accuracy
+    t_acc_s: u16,
+
+    /// Reserved
+    reserved2: [u8; 2],
+
+    /// Nanoseconds part of the time accuracy, 0..999,999,999
+    t_acc_ns: u32,
+}
+
+impl MgaIniTimeUtcBuilder {
+    /// Message type of a UBX-MGA-INI TIME_UTC frame
+    pub const MSG_TYPE: u8 = 0x10;
+
+    /// Initial `time`, valid on receipt of the message and known to `accuracy`, with an unknown
+    /// number of leap seconds
+    pub fn new(time: DateTime<Utc>, accuracy: Duration) -> Self {
+        // chrono represents a leap second with the nanoseconds of the previous second
+        let (second, ns) = match time.nanosecond() {
+            ns @ 0..1_000_000_000 => (time.second(), ns),
+            ns => (time.second() + 1, ns - 1_000_000_000),
+        };
+        Self {
+            msg_type: Self::MSG_TYPE,
+            leap_secs: -128,
+            year: u16::try_from(time.year()).unwrap_or_default(),
+            month: time.month() as u8,
+            day: time.day() as u8,
+            hour: time.hour() as u8,
+            minute: time.minute() as u8,
+            second: second as u8,
+            ns,
+            t_acc_s: u16::try_from(accuracy.as_secs()).unwrap_or(u16::MAX),
+            t_acc_ns: accuracy.subsec_nanos(),
+            ..Self::default()
+        }
+    }
+}
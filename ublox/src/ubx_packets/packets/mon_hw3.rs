@@ -10,7 +10,7 @@ use super::SerializeUbxPacketFields;
 use crate::serde::ser::SerializeMap;
 #[allow(unused_imports, reason = "It is only unused in some feature sets")]
 use crate::FieldIter;
-use crate::{error::ParserError, mon_ver, UbxPacketMeta};
+use crate::{error::ParserError, UbxPacketMeta};
 use ublox_derive::ubx_packet_recv;
 
 /// I/O pin status
@@ -29,8 +29,7 @@ pub struct MonHw3 {
     #[ubx(map_type = Flags)]
     flags: u8,
     /// Zero-terminated hardware version string (same as that returned in the UBX-MON-VER message)
-    #[ubx(map_type = &str, may_fail, from = mon_ver::convert_to_str_unchecked,
-          is_valid = mon_ver::is_cstr_valid, get_as_ref)]
+    #[ubx(chars)]
     hw_version: [u8; 10],
     /// Reserved bytes
     reserved0: [u8; 9],
@@ -48,6 +48,30 @@ struct MonMsgpp {
     skipped: [u8; 24],
 }
 
+impl MonMsgppRef<'_> {
+    /// Messages parsed and processed on `port`, one count per protocol, or `None` if `port`
+    /// is not below [NUM_PORTS]. Ports are numbered as in CFG-PRT: 0 is I2C, 1 UART1, 2
+    /// UART2, 3 USB and 4 SPI.
+    pub fn port_msgs(&self, port: usize) -> Option<[u16; NUM_PROTOCOLS]> {
+        let msgs = match port {
+            0 => self.msg1(),
+            1 => self.msg2(),
+            2 => self.msg3(),
+            3 => self.msg4(),
+            4 => self.msg5(),
+            5 => self.msg6(),
+            _ => return None,
+        };
+        Some(parse_port_msg(&msgs))
+    }
+
+    /// Bytes the receiver skipped on each port because they did not belong to a message of an
+    /// enabled protocol
+    pub fn skipped_bytes(&self) -> [u32; NUM_PORTS] {
+        parse_skipped(&self.skipped())
+    }
+}
+
 /// Helper function to convert raw bytes to u16 array
 pub fn parse_port_msg(bytes: &[u8; 16]) -> [u16; NUM_PROTOCOLS] {
     let mut result = [0u16; NUM_PROTOCOLS];
@@ -32,6 +32,18 @@ pub struct MonRf {
     blocks: [u8; 0],
 }
 
+/// RF band served by an RF block
+#[ubx_extend]
+#[ubx(from, rest_reserved)]
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RfBand {
+    /// L1 band
+    L1 = 0,
+    /// L2 or L5 band, depending on the product configuration
+    L2OrL5 = 1,
+}
+
 /// Jamming/Interference Monitor State
 #[ubx_extend]
 #[ubx(from, rest_reserved)]
@@ -131,6 +143,31 @@ pub struct RfBlock {
     pub reserved2: [u8; 3],
 }
 
+impl RfBlock {
+    /// Full scale of the AGC monitor counter
+    pub const AGC_CNT_MAX: u16 = 8191;
+
+    /// RF band this block reports on
+    pub fn band(&self) -> RfBand {
+        RfBand::from(self.block_id)
+    }
+
+    /// Output from the Jamming/Interference Monitor
+    pub fn jamming_state(&self) -> JammingState {
+        self.flags.jamming_state
+    }
+
+    /// AGC monitor as a percentage of its full scale (0 to 100 %)
+    pub fn agc_percent(&self) -> f32 {
+        f32::from(self.agc_cnt.min(Self::AGC_CNT_MAX)) * 100.0 / f32::from(Self::AGC_CNT_MAX)
+    }
+
+    /// CW jamming indicator as a percentage (0 = no CW jamming, 100 = strong CW jamming)
+    pub fn cw_jamming_percent(&self) -> f32 {
+        f32::from(self.jam_ind) * 100.0 / f32::from(u8::MAX)
+    }
+}
+
 /// Iterator for RF block information
 #[derive(Debug, Clone)]
 pub struct RfBlockIter<'a> {
@@ -177,4 +214,11 @@ impl core::iter::Iterator for RfBlockIter<'_> {
         self.offset += 24;
         Some(block)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.data.len() - self.offset) / 24;
+        (remaining, Some(remaining))
+    }
 }
+
+impl core::iter::ExactSizeIterator for RfBlockIter<'_> {}
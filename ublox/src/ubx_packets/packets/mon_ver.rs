@@ -10,11 +10,12 @@ use ublox_derive::ubx_packet_recv;
 #[ubx_packet_recv]
 #[ubx(class = 0x0a, id = 0x04, max_payload_len = 1240)]
 pub struct MonVer {
-    #[ubx(map_type = &str, may_fail, from = convert_to_str_unchecked,
-          is_valid = is_cstr_valid, get_as_ref)]
+    /// Software version
+    #[ubx(chars)]
     software_version: [u8; 30],
-    #[ubx(map_type = &str, may_fail, from = convert_to_str_unchecked,
-          is_valid = is_cstr_valid, get_as_ref)]
+
+    /// Hardware version
+    #[ubx(chars)]
     hardware_version: [u8; 10],
 
     /// Extended software information strings
@@ -99,8 +100,8 @@ mod test {
         ];
         assert_eq!(Ok(()), <MonVerRef>::validate(&payload));
         let ver = MonVerRef(&payload);
-        assert_eq!("ROM CORE 3.01 (107888)", ver.software_version());
-        assert_eq!("00080000", ver.hardware_version());
+        assert_eq!(Ok("ROM CORE 3.01 (107888)"), ver.software_version());
+        assert_eq!(Ok("00080000"), ver.hardware_version());
         let mut it = ver.extension();
         assert_eq!("FWVER=SPG 3.01", it.next().unwrap());
         assert_eq!("PROTVER=18.00", it.next().unwrap());
@@ -126,8 +127,8 @@ mod test {
         ];
         assert_eq!(Ok(()), <MonVerRef>::validate(&payload));
         let ver = MonVerRef(&payload);
-        assert_eq!("EXT CORE 3.01 (d189ff)", ver.software_version());
-        assert_eq!("00080000", ver.hardware_version());
+        assert_eq!(Ok("EXT CORE 3.01 (d189ff)"), ver.software_version());
+        assert_eq!(Ok("00080000"), ver.hardware_version());
         let mut it = ver.extension();
         assert_eq!("ROM BASE 3.01 (107888)", it.next().unwrap());
         assert_eq!("FWVER=ADR 4.11", it.next().unwrap());
@@ -139,6 +140,20 @@ mod test {
         assert_eq!(None, it.next());
     }
 
+    #[test]
+    fn mon_ver_unterminated_chars() {
+        let mut payload = [0u8; 40];
+        payload[..30].copy_from_slice(&[b'A'; 30]);
+        payload[30..].copy_from_slice(b"0008000012");
+        assert_eq!(Ok(()), <MonVerRef>::validate(&payload));
+        let ver = MonVerRef(&payload);
+        assert_eq!(Ok("A".repeat(30).as_str()), ver.software_version());
+        assert_eq!(Ok("0008000012"), ver.hardware_version());
+
+        payload[0] = 0xff;
+        assert!(MonVerRef(&payload).software_version().is_err());
+    }
+
     #[test]
     #[cfg(feature = "std")]
     fn mon_ver_to_owned() {
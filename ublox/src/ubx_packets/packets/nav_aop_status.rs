@@ -0,0 +1,45 @@
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use super::cfg_navx5::AopCfg;
+use crate::{error::ParserError, UbxPacketMeta};
+use ublox_derive::ubx_packet_recv;
+
+/// AssistNow Autonomous status
+#[ubx_packet_recv]
+#[ubx(class = 0x01, id = 0x60, fixed_payload_len = 16)]
+struct NavAopStatus {
+    /// GPS Millisecond Time of Week
+    itow: u32,
+
+    /// AssistNow Autonomous configuration
+    #[ubx(map_type = AopCfg)]
+    aop_cfg: u8,
+
+    /// AssistNow Autonomous subsystem status
+    #[ubx(map_type = AopStatus)]
+    status: u8,
+
+    reserved1: [u8; 10],
+}
+
+/// State of the AssistNow Autonomous orbit prediction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AopStatus {
+    /// Not computing, either disabled or all orbits are up to date
+    Idle,
+    /// Computing orbit predictions from the broadcast ephemerides
+    Running,
+}
+
+impl From<u8> for AopStatus {
+    fn from(raw: u8) -> Self {
+        match raw {
+            0 => Self::Idle,
+            _ => Self::Running,
+        }
+    }
+}
@@ -3,6 +3,7 @@ use super::SerializeUbxPacketFields;
 #[cfg(feature = "serde")]
 use crate::serde::ser::SerializeMap;
 
+use crate::ubx_packets::types::{Heading, HeadingSource, ToHeading};
 use crate::{error::ParserError, UbxPacketMeta};
 use ublox_derive::ubx_packet_recv;
 
@@ -25,3 +26,22 @@ struct NavAtt {
     #[ubx(map_type = f64, scale = 1e-5, alias = vehicle_heading_accuracy)]
     acc_heading: u32,
 }
+
+macro_rules! impl_to_heading {
+    ($type:ty) => {
+        impl ToHeading for $type {
+            fn to_heading(&self) -> Heading {
+                // NAV-ATT has no validity flag, it is only output with an attitude solution
+                Heading {
+                    heading: self.vehicle_heading(),
+                    accuracy: self.vehicle_heading_accuracy(),
+                    source: HeadingSource::Vehicle,
+                    valid: true,
+                }
+            }
+        }
+    };
+}
+
+impl_to_heading!(NavAttRef<'_>);
+impl_to_heading!(NavAttOwned);
@@ -23,11 +23,11 @@ struct NavHpPosLlh {
     itow: u32,
 
     /// Longitude (deg)
-    #[ubx(map_type = f64, scale = 1e-7, alias = lon_degrees)]
+    #[ubx(scale = 1e-7, unit = "deg")]
     lon: i32,
 
     /// Latitude (deg)
-    #[ubx(map_type = f64, scale = 1e-7, alias = lat_degrees)]
+    #[ubx(scale = 1e-7, unit = "deg")]
     lat: i32,
 
     /// Height above Ellipsoid [m]
@@ -41,13 +41,13 @@ struct NavHpPosLlh {
     /// High precision component of longitude
     /// Must be in the range -99..+99
     /// Precise longitude in deg * 1e-7 = lon + (lonHp * 1e-2)
-    #[ubx(map_type = f64, scale = 1e-9, alias = lon_hp_degrees)]
+    #[ubx(scale = 1e-9, unit = "deg")]
     lon_hp: i8,
 
     /// High precision component of latitude
     /// Must be in the range -99..+99
     /// Precise latitude in deg * 1e-7 = lat + (latHp * 1e-2)
-    #[ubx(map_type = f64, scale = 1e-9, alias = lat_hp_degrees)]
+    #[ubx(scale = 1e-9, unit = "deg")]
     lat_hp: i8,
 
     /// High precision component of height above ellipsoid
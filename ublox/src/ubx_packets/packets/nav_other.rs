@@ -3,6 +3,7 @@ use super::SerializeUbxPacketFields;
 #[cfg(feature = "serde")]
 use crate::serde::ser::SerializeMap;
 
+use crate::ubx_packets::types::{ToVelocityECEF, VelocityECEF};
 use crate::{
     error::ParserError, ubx_checksum, MemWriter, MemWriterError, UbxPacketCreator, UbxPacketMeta,
 };
@@ -33,12 +34,43 @@ struct NavEoe {
 #[ubx(class = 0x01, id = 0x10, fixed_payload_len = 0)]
 struct NavResetOdo {}
 
+/// Velocity Solution in ECEF
 #[ubx_packet_recv]
 #[ubx(class = 0x01, id = 0x11, fixed_payload_len = 20)]
 struct NavVelECEF {
+    /// GPS Millisecond Time of Week
     itow: u32,
+
+    /// ECEF X velocity
+    #[ubx(scale = 1e-2, unit = "m/s")]
     ecef_vx: i32,
+
+    /// ECEF Y velocity
+    #[ubx(scale = 1e-2, unit = "m/s")]
     ecef_vy: i32,
+
+    /// ECEF Z velocity
+    #[ubx(scale = 1e-2, unit = "m/s")]
     ecef_vz: i32,
-    s_acc: u32,
+
+    /// Speed Accuracy Estimate [m/s]
+    #[ubx(map_type = f64, scale = 1e-2)]
+    speed_accuracy_estimate: u32,
 }
+
+macro_rules! impl_to_velocity_ecef {
+    ($type:ty) => {
+        impl ToVelocityECEF for $type {
+            fn to_velocity_ecef(&self) -> VelocityECEF {
+                VelocityECEF {
+                    x: self.ecef_vx_mps(),
+                    y: self.ecef_vy_mps(),
+                    z: self.ecef_vz_mps(),
+                }
+            }
+        }
+    };
+}
+
+impl_to_velocity_ecef!(NavVelECEFRef<'_>);
+impl_to_velocity_ecef!(NavVelECEFOwned);
@@ -4,7 +4,10 @@ use super::super::SerializeUbxPacketFields;
 use crate::serde::ser::SerializeMap;
 
 use crate::error::DateTimeError;
-use crate::ubx_packets::types::{PositionLLA, ToDateTime, ToLLA, ToVelocity, Velocity};
+use crate::ubx_packets::types::{
+    Heading, HeadingSource, PositionLLA, ToDateTime, ToHeading, ToLLA, ToVelocity, ToVelocityNED,
+    Velocity, VelocityNED,
+};
 use chrono::{DateTime, Utc};
 use core::convert::TryFrom;
 
@@ -13,8 +16,11 @@ use crate::{error::ParserError, GnssFixType, UbxPacketMeta};
 use ublox_derive::ubx_packet_recv;
 
 /// Navigation Position Velocity Time Solution
+///
+/// u-blox 8 and later receivers append the heading of vehicle and the magnetic declination,
+/// which are `None` in the 84 byte payload of earlier receivers.
 #[ubx_packet_recv]
-#[ubx(class = 1, id = 0x07, fixed_payload_len = 84)]
+#[ubx(class = 1, id = 0x07, max_payload_len = 92, min_payload_len = 84)]
 struct NavPvt {
     /// GPS Millisecond time of week of the navigation epoch.
     ///
@@ -120,6 +126,19 @@ struct NavPvt {
 
     reserved2: [u8; 2],
     reserved3: [u8; 4],
+
+    /// Heading of vehicle (2-D), this is only valid when [HEAD_VEH_VALID](NavPvtFlags::HEAD_VEH_VALID) is set,
+    /// otherwise the output is set to the heading of motion
+    #[ubx(map_type = f64, scale = 1e-5, alias = heading_vehicle)]
+    head_vehicle: i32,
+
+    /// Magnetic declination. Only supported in ADR 4.10 and later.
+    #[ubx(map_type = f64, scale = 1e-2, alias = magnetic_declination)]
+    magnetic_declination: i16,
+
+    /// Magnetic declination accuracy. Only supported in ADR 4.10 and later.
+    #[ubx(map_type = f64, scale = 1e-2, alias = magnetic_declination_accuracy)]
+    magnetic_declination_accuracy: u16,
 }
 
 macro_rules! impl_to_lla {
@@ -149,12 +168,53 @@ macro_rules! impl_to_velocity {
                 }
             }
         }
+
+        impl ToVelocityNED for $type {
+            fn to_velocity_ned(&self) -> VelocityNED {
+                VelocityNED {
+                    north: self.vel_north(),
+                    east: self.vel_east(),
+                    down: self.vel_down(),
+                }
+            }
+        }
     };
 }
 
 impl_to_velocity!(NavPvtRef<'_>);
 impl_to_velocity!(NavPvtOwned);
 
+macro_rules! impl_to_heading {
+    ($type:ty) => {
+        impl ToHeading for $type {
+            fn to_heading(&self) -> Heading {
+                let flags = self.flags();
+                // Receivers older than u-blox 8 have no heading of vehicle
+                let heading_vehicle = self
+                    .heading_vehicle()
+                    .filter(|_| flags.contains(NavPvtFlags::HEAD_VEH_VALID));
+                let (heading, source, valid) = match heading_vehicle {
+                    Some(heading) => (heading, HeadingSource::Vehicle, true),
+                    None => (
+                        self.heading_motion(),
+                        HeadingSource::Motion,
+                        flags.contains(NavPvtFlags::GPS_FIX_OK),
+                    ),
+                };
+                Heading {
+                    heading,
+                    accuracy: self.heading_accuracy(),
+                    source,
+                    valid,
+                }
+            }
+        }
+    };
+}
+
+impl_to_heading!(NavPvtRef<'_>);
+impl_to_heading!(NavPvtOwned);
+
 macro_rules! impl_to_date_time {
     ($type:ty) => {
         impl ToDateTime for $type {
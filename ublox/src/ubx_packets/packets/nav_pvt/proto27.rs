@@ -4,7 +4,10 @@ use super::super::SerializeUbxPacketFields;
 use crate::serde::ser::SerializeMap;
 
 use crate::error::DateTimeError;
-use crate::ubx_packets::types::{PositionLLA, ToDateTime, ToLLA, ToVelocity, Velocity};
+use crate::ubx_packets::types::{
+    Heading, HeadingSource, PositionLLA, ToDateTime, ToHeading, ToLLA, ToVelocity, ToVelocityNED,
+    Velocity, VelocityNED,
+};
 use chrono::{DateTime, Utc};
 use core::convert::TryFrom;
 
@@ -202,12 +205,50 @@ macro_rules! impl_to_velocity {
                 }
             }
         }
+
+        impl ToVelocityNED for $type {
+            fn to_velocity_ned(&self) -> VelocityNED {
+                VelocityNED {
+                    north: self.vel_north(),
+                    east: self.vel_east(),
+                    down: self.vel_down(),
+                }
+            }
+        }
     };
 }
 
 impl_to_velocity!(NavPvtRef<'_>);
 impl_to_velocity!(NavPvtOwned);
 
+macro_rules! impl_to_heading {
+    ($type:ty) => {
+        impl ToHeading for $type {
+            fn to_heading(&self) -> Heading {
+                let flags = self.flags();
+                let (heading, source, valid) = if flags.contains(NavPvtFlags::HEAD_VEH_VALID) {
+                    (self.heading_vehicle(), HeadingSource::Vehicle, true)
+                } else {
+                    (
+                        self.heading_motion(),
+                        HeadingSource::Motion,
+                        flags.contains(NavPvtFlags::GPS_FIX_OK),
+                    )
+                };
+                Heading {
+                    heading,
+                    accuracy: self.heading_accuracy(),
+                    source,
+                    valid,
+                }
+            }
+        }
+    };
+}
+
+impl_to_heading!(NavPvtRef<'_>);
+impl_to_heading!(NavPvtOwned);
+
 macro_rules! impl_to_date_time {
     ($type:ty) => {
         impl ToDateTime for $type {
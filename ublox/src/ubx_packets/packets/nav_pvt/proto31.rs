@@ -4,7 +4,10 @@ use super::super::SerializeUbxPacketFields;
 use crate::serde::ser::SerializeMap;
 
 use crate::error::DateTimeError;
-use crate::ubx_packets::types::{PositionLLA, ToDateTime, ToLLA, ToVelocity, Velocity};
+use crate::ubx_packets::types::{
+    Heading, HeadingSource, PositionLLA, ToDateTime, ToHeading, ToLLA, ToVelocity, ToVelocityNED,
+    Velocity, VelocityNED,
+};
 use chrono::{DateTime, Utc};
 use core::convert::TryFrom;
 
@@ -122,9 +125,9 @@ struct NavPvt {
 
     /// Additional flags
     #[ubx(map_type = flags::NavPvtFlags3)]
-    flags3: u8,
+    flags3: u16,
 
-    reserved1: [u8; 5],
+    reserved1: [u8; 4],
 
     /// Heading of vehicle (2-D), this is only valid when [HEAD_VEH_VALID](NavPvtFlags::HEAD_VEH_VALID) is set,
     /// otherwise the output is set to the heading of motion
@@ -146,6 +149,7 @@ pub(crate) mod flags {
     pub struct NavPvtFlags3 {
         invalid_llh: bool,
         age_differential_correction: u8,
+        auth_time: bool,
     }
 
     impl NavPvtFlags3 {
@@ -157,18 +161,26 @@ pub(crate) mod flags {
         pub fn age_differential_correction(&self) -> u8 {
             self.age_differential_correction
         }
+
+        /// 1 = The time of the solution was authenticated, e.g. with Galileo OSNMA. Only set
+        /// by firmware supporting navigation message authentication.
+        pub fn auth_time(&self) -> bool {
+            self.auth_time
+        }
     }
 
-    impl From<u8> for NavPvtFlags3 {
-        fn from(val: u8) -> Self {
-            const AGE_DIFFERENTIAL_CORRECTION_MASK: u8 = 0b11110;
+    impl From<u16> for NavPvtFlags3 {
+        fn from(val: u16) -> Self {
+            const AGE_DIFFERENTIAL_CORRECTION_MASK: u16 = 0b11110;
+            const AUTH_TIME: u16 = 1 << 13;
             let invalid = val & 0x01 == 1;
             // F9R interface description document specifies that this byte is unused
             // We can read it ... but we don't expose it
-            let age_differential_correction = val & AGE_DIFFERENTIAL_CORRECTION_MASK;
+            let age_differential_correction = (val & AGE_DIFFERENTIAL_CORRECTION_MASK) as u8;
             Self {
                 invalid_llh: invalid,
                 age_differential_correction,
+                auth_time: val & AUTH_TIME != 0,
             }
         }
     }
@@ -201,12 +213,50 @@ macro_rules! impl_to_velocity {
                 }
             }
         }
+
+        impl ToVelocityNED for $type {
+            fn to_velocity_ned(&self) -> VelocityNED {
+                VelocityNED {
+                    north: self.vel_north(),
+                    east: self.vel_east(),
+                    down: self.vel_down(),
+                }
+            }
+        }
     };
 }
 
 impl_to_velocity!(NavPvtRef<'_>);
 impl_to_velocity!(NavPvtOwned);
 
+macro_rules! impl_to_heading {
+    ($type:ty) => {
+        impl ToHeading for $type {
+            fn to_heading(&self) -> Heading {
+                let flags = self.flags();
+                let (heading, source, valid) = if flags.contains(NavPvtFlags::HEAD_VEH_VALID) {
+                    (self.heading_vehicle(), HeadingSource::Vehicle, true)
+                } else {
+                    (
+                        self.heading_motion(),
+                        HeadingSource::Motion,
+                        flags.contains(NavPvtFlags::GPS_FIX_OK),
+                    )
+                };
+                Heading {
+                    heading,
+                    accuracy: self.heading_accuracy(),
+                    source,
+                    valid,
+                }
+            }
+        }
+    };
+}
+
+impl_to_heading!(NavPvtRef<'_>);
+impl_to_heading!(NavPvtOwned);
+
 macro_rules! impl_to_date_time {
     ($type:ty) => {
         impl ToDateTime for $type {
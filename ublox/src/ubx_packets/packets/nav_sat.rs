@@ -4,7 +4,7 @@ use core::fmt;
 #[cfg(feature = "serde")]
 use {super::SerializeUbxPacketFields, crate::serde::ser::SerializeMap};
 
-use crate::{error::ParserError, UbxPacketMeta};
+use crate::{error::ParserError, RepeatedIter, UbxPacketMeta};
 use ublox_derive::ubx_packet_recv;
 
 #[ubx_packet_recv]
@@ -20,46 +20,14 @@ struct NavSat {
 
     reserved: [u8; 2],
 
-    #[ubx(
-        map_type = NavSatIter,
-        from = NavSatIter::new,
-        is_valid = NavSatIter::is_valid,
-        may_fail,
-        get_as_ref,
-    )]
-    svs: [u8; 0],
+    #[ubx(repeat = num_svs)]
+    svs: [NavSatSvInfo],
 }
 
-#[derive(Debug, Clone)]
-pub struct NavSatIter<'a> {
-    data: &'a [u8],
-    offset: usize,
-}
-
-impl<'a> NavSatIter<'a> {
-    fn new(data: &'a [u8]) -> Self {
-        Self { data, offset: 0 }
-    }
-
-    fn is_valid(bytes: &[u8]) -> bool {
-        bytes.len().is_multiple_of(12)
-    }
-}
-
-impl<'a> core::iter::Iterator for NavSatIter<'a> {
-    type Item = NavSatSvInfoRef<'a>;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.offset < self.data.len() {
-            let data = &self.data[self.offset..self.offset + 12];
-            self.offset += 12;
-            Some(NavSatSvInfoRef(data))
-        } else {
-            None
-        }
-    }
-}
+/// Iterator over the satellites of UBX-NAV-SAT
+pub type NavSatIter<'a> = RepeatedIter<'a, NavSatSvInfo>;
 
+/// This packet is not actually received as such, it is a block of the `NavSat` message
 #[ubx_packet_recv]
 #[ubx(class = 0x01, id = 0x35, fixed_payload_len = 12)]
 struct NavSatSvInfo {
@@ -11,7 +11,7 @@ use {super::SerializeUbxPacketFields, crate::serde::ser::SerializeMap};
 use crate::nav_sat::NavSatSvHealth;
 #[allow(unused_imports, reason = "It is only unused in some feature sets")]
 use crate::FieldIter;
-use crate::{error::ParserError, UbxPacketMeta};
+use crate::{error::ParserError, RepeatedIter, UbxPacketMeta};
 use ublox_derive::ubx_packet_recv;
 
 #[ubx_packet_recv]
@@ -27,55 +27,18 @@ struct NavSig {
 
     reserved: u16,
 
-    #[ubx(map_type = NavSigIter,
-        may_fail,
-        is_valid = NavSigIter::is_valid,
-        from = NavSigIter::new,
-        get_as_ref)]
-    sigs: [u8; 0],
+    #[ubx(repeat = num_sigs)]
+    sigs: [NavSigInfo],
 }
 
-impl fmt::Debug for NavSigIter<'_> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("NavSigIter").finish()
-    }
-}
-
-#[derive(Clone)]
-pub struct NavSigIter<'a> {
-    data: &'a [u8],
-    offset: usize,
-}
-
-impl<'a> NavSigIter<'a> {
-    fn new(data: &'a [u8]) -> Self {
-        Self { data, offset: 0 }
-    }
-
-    fn is_valid(bytes: &[u8]) -> bool {
-        bytes.len().is_multiple_of(16)
-    }
-}
-
-impl<'a> core::iter::Iterator for NavSigIter<'a> {
-    type Item = NavSigInfoRef<'a>;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.offset < self.data.len() {
-            let data = &self.data[self.offset..self.offset + 16];
-            self.offset += 16;
-            Some(NavSigInfoRef(data))
-        } else {
-            None
-        }
-    }
-}
+/// Iterator over the signals of UBX-NAV-SIG
+pub type NavSigIter<'a> = RepeatedIter<'a, NavSigInfo>;
 
 /// This packet is not actually received as such, it is a block of the `NavSig` message
 /// The `ubx_packet_recv` macro is used here as a shortcut to generate the needed code required for the repeated block.
 #[ubx_packet_recv]
 #[ubx(class = 0x01, id = 0x35, fixed_payload_len = 16)]
-pub(crate) struct NavSigInfo {
+struct NavSigInfo {
     gnss_id: u8,
     sv_id: u8,
     sig_id: u8,
@@ -5,6 +5,7 @@ use super::SerializeUbxPacketFields;
 #[cfg(feature = "serde")]
 use crate::serde::ser::SerializeMap;
 
+use crate::ubx_packets::types::{ToVelocityECEF, VelocityECEF};
 use crate::{error::ParserError, GnssFixType, UbxPacketMeta};
 use ublox_derive::{ubx_extend_bitflags, ubx_packet_recv};
 
@@ -87,3 +88,20 @@ bitflags! {
         const TOW_SET = 8;
     }
 }
+
+macro_rules! impl_to_velocity_ecef {
+    ($type:ty) => {
+        impl ToVelocityECEF for $type {
+            fn to_velocity_ecef(&self) -> VelocityECEF {
+                VelocityECEF {
+                    x: self.ecef_vx(),
+                    y: self.ecef_vy(),
+                    z: self.ecef_vz(),
+                }
+            }
+        }
+    };
+}
+
+impl_to_velocity_ecef!(NavSolRef<'_>);
+impl_to_velocity_ecef!(NavSolOwned);
@@ -0,0 +1,53 @@
+// Generated by ublox_codegen from ublox/interface/packets.json, do not edit
+
+use bitflags::bitflags;
+
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use crate::{error::ParserError, UbxPacketMeta};
+use ublox_derive::{ubx_extend_bitflags, ubx_packet_recv};
+
+/// BeiDou time solution (UBX-NAV-TIMEBDS)
+#[ubx_packet_recv]
+#[ubx(class = 0x01, id = 0x24, fixed_payload_len = 20)]
+struct NavTimeBds {
+    /// GPS time of week of the navigation epoch (ms).
+    itow: u32,
+
+    /// BeiDou time of week, rounded to seconds (s).
+    sow: u32,
+
+    /// Fractional part of SOW (range: +/- 500000000) (ns).
+    fsow: i32,
+
+    /// BeiDou week number of the navigation epoch.
+    week: i16,
+
+    /// BeiDou leap seconds (BDS-UTC) (s).
+    leap_s: i8,
+
+    /// Validity flags.
+    #[ubx(map_type = NavTimeBdsFlags)]
+    valid: u8,
+
+    /// Time accuracy estimate (ns).
+    t_acc: u32,
+}
+
+#[ubx_extend_bitflags]
+#[ubx(from, rest_reserved)]
+bitflags! {
+    /// Validity flags of `NavTimeBds`
+    #[derive(Default, Debug)]
+    pub struct NavTimeBdsFlags: u8 {
+        /// Valid BeiDou time of week (sow + fsow).
+        const VALID_SOW = 0x1;
+        /// Valid BeiDou week number.
+        const VALID_WEEK = 0x2;
+        /// Valid BeiDou leap seconds.
+        const VALID_LEAP_S = 0x4;
+    }
+}
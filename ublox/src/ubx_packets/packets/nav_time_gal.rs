@@ -0,0 +1,53 @@
+// Generated by ublox_codegen from ublox/interface/packets.json, do not edit
+
+use bitflags::bitflags;
+
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use crate::{error::ParserError, UbxPacketMeta};
+use ublox_derive::{ubx_extend_bitflags, ubx_packet_recv};
+
+/// Galileo time solution (UBX-NAV-TIMEGAL)
+#[ubx_packet_recv]
+#[ubx(class = 0x01, id = 0x25, fixed_payload_len = 20)]
+struct NavTimeGal {
+    /// GPS time of week of the navigation epoch (ms).
+    itow: u32,
+
+    /// Galileo time of week, rounded to seconds (s).
+    gal_tow: u32,
+
+    /// Fractional part of the Galileo time of week (range: +/- 500000000) (ns).
+    f_gal_tow: i32,
+
+    /// Galileo week number.
+    gal_wno: i16,
+
+    /// Galileo leap seconds (Galileo-UTC) (s).
+    leap_s: i8,
+
+    /// Validity flags.
+    #[ubx(map_type = NavTimeGalFlags)]
+    valid: u8,
+
+    /// Time accuracy estimate (ns).
+    t_acc: u32,
+}
+
+#[ubx_extend_bitflags]
+#[ubx(from, rest_reserved)]
+bitflags! {
+    /// Validity flags of `NavTimeGal`
+    #[derive(Default, Debug)]
+    pub struct NavTimeGalFlags: u8 {
+        /// Valid Galileo time of week (gal_tow + f_gal_tow).
+        const VALID_TOW = 0x1;
+        /// Valid Galileo week number.
+        const VALID_WNO = 0x2;
+        /// Valid Galileo leap seconds.
+        const VALID_LEAP_S = 0x4;
+    }
+}
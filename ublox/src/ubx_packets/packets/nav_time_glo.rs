@@ -0,0 +1,52 @@
+// Generated by ublox_codegen from ublox/interface/packets.json, do not edit
+
+use bitflags::bitflags;
+
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use crate::{error::ParserError, UbxPacketMeta};
+use ublox_derive::{ubx_extend_bitflags, ubx_packet_recv};
+
+/// GLONASS time solution (UBX-NAV-TIMEGLO)
+#[ubx_packet_recv]
+#[ubx(class = 0x01, id = 0x23, fixed_payload_len = 20)]
+struct NavTimeGlo {
+    /// GPS time of week of the navigation epoch (ms).
+    itow: u32,
+
+    /// GLONASS time of day, rounded to integer seconds (s).
+    tod: u32,
+
+    /// Fractional part of TOD (range: +/- 500000000) (ns).
+    ftod: i32,
+
+    /// Current date (range: 1-1461), starting at 1 from the 1st Jan of the year indicated by N4
+    /// and ending at 1461 at the 31st Dec of the third year after that indicated by N4 (days).
+    nt: u16,
+
+    /// Four-year interval number starting from 1996 (1=1996, 2=2000, 3=2004...).
+    n4: u8,
+
+    /// Validity flags.
+    #[ubx(map_type = NavTimeGloFlags)]
+    valid: u8,
+
+    /// Time accuracy estimate (ns).
+    t_acc: u32,
+}
+
+#[ubx_extend_bitflags]
+#[ubx(from, rest_reserved)]
+bitflags! {
+    /// Validity flags of `NavTimeGlo`
+    #[derive(Default, Debug)]
+    pub struct NavTimeGloFlags: u8 {
+        /// Valid GLONASS time of day (tod + ftod).
+        const VALID_TOD = 0x1;
+        /// Valid GLONASS date (nt + n4).
+        const VALID_DATE = 0x2;
+    }
+}
@@ -3,7 +3,7 @@ use super::SerializeUbxPacketFields;
 #[cfg(feature = "serde")]
 use crate::serde::ser::SerializeMap;
 
-use crate::ubx_packets::types::{ToVelocity, Velocity};
+use crate::ubx_packets::types::{ToVelocity, ToVelocityNED, Velocity, VelocityNED};
 use crate::{error::ParserError, UbxPacketMeta};
 use ublox_derive::ubx_packet_recv;
 
@@ -57,6 +57,16 @@ macro_rules! impl_to_velocity {
                 }
             }
         }
+
+        impl ToVelocityNED for $type {
+            fn to_velocity_ned(&self) -> VelocityNED {
+                VelocityNED {
+                    north: self.vel_north(),
+                    east: self.vel_east(),
+                    down: self.vel_down(),
+                }
+            }
+        }
     };
 }
 
@@ -12,6 +12,7 @@ use crate::ubx_packets::packets::{
     cfg_gnss::{CfgGnss, CfgGnssOwned, CfgGnssRef},
     cfg_itfm::{CfgItfm, CfgItfmOwned, CfgItfmRef},
     cfg_nav5::{CfgNav5, CfgNav5Owned, CfgNav5Ref},
+    cfg_navx5::{CfgNavX5, CfgNavX5Owned, CfgNavX5Ref, CfgNavX5V3, CfgNavX5V3Owned, CfgNavX5V3Ref},
     cfg_odo::{CfgOdo, CfgOdoOwned, CfgOdoRef},
     cfg_prt::{
         CfgPrtI2c, CfgPrtI2cOwned, CfgPrtI2cRef, CfgPrtSpi, CfgPrtSpiOwned, CfgPrtSpiRef,
@@ -28,11 +29,15 @@ use crate::ubx_packets::packets::{
     inf_test::{InfTest, InfTestOwned, InfTestRef},
     inf_warning::{InfWarning, InfWarningOwned, InfWarningRef},
     mga_ack::{MgaAck, MgaAckOwned, MgaAckRef},
+    mga_bds_alm::{MgaBdsAlm, MgaBdsAlmOwned, MgaBdsAlmRef},
     mga_bds_eph::{MgaBdsEph, MgaBdsEphOwned, MgaBdsEphRef},
     mga_bds_utc::{MgaBdsUtc, MgaBdsUtcOwned, MgaBdsUtcRef},
+    mga_gal_alm::{MgaGalAlm, MgaGalAlmOwned, MgaGalAlmRef},
     mga_gal_eph::{MgaGalEph, MgaGalEphOwned, MgaGalEphRef},
     mga_gal_time::{MgaGalTime, MgaGalTimeOwned, MgaGalTimeRef},
+    mga_glo_alm::{MgaGloAlm, MgaGloAlmOwned, MgaGloAlmRef},
     mga_glo_eph::{MgaGloEph, MgaGloEphOwned, MgaGloEphRef},
+    mga_gps_alm::{MgaGpsAlm, MgaGpsAlmOwned, MgaGpsAlmRef},
     mga_gps_eph::{MgaGpsEph, MgaGpsEphOwned, MgaGpsEphRef},
     mga_gps_iono::{MgaGpsIono, MgaGpsIonoOwned, MgaGpsIonoRef},
     mga_gps_utc::{MgaGpsUtc, MgaGpsUtcOwned, MgaGpsUtcRef},
@@ -84,6 +89,8 @@ define_recv_packets!(
         CfgGnss,
         CfgItfm,
         CfgNav5,
+        CfgNavX5,
+        CfgNavX5V3,
         CfgOdo,
         CfgPrtI2c,
         CfgPrtSpi,
@@ -110,12 +117,16 @@ define_recv_packets!(
         MgaAck,
         MgaGpsIono,
         MgaGpsEph,
+        MgaGpsAlm,
         MgaGpsUtc,
         MgaGalEph,
+        MgaGalAlm,
         MgaGalTime,
         MgaBdsEph,
+        MgaBdsAlm,
         MgaBdsUtc,
         MgaGloEph,
+        MgaGloAlm,
         NavAtt,
         NavClock,
         NavDop,
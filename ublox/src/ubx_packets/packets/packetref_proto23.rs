@@ -9,9 +9,11 @@ use crate::ubx_packets::packets::{
     cfg_esf_alg::{CfgEsfAlg, CfgEsfAlgOwned, CfgEsfAlgRef},
     cfg_esf_wt::{CfgEsfWt, CfgEsfWtOwned, CfgEsfWtRef},
     cfg_gnss::{CfgGnss, CfgGnssOwned, CfgGnssRef},
+    cfg_hnr::{CfgHnr, CfgHnrOwned, CfgHnrRef},
     cfg_inf::{CfgInf, CfgInfOwned, CfgInfRef},
     cfg_itfm::{CfgItfm, CfgItfmOwned, CfgItfmRef},
     cfg_nav5::{CfgNav5, CfgNav5Owned, CfgNav5Ref},
+    cfg_navx5::{CfgNavX5, CfgNavX5Owned, CfgNavX5Ref, CfgNavX5V3, CfgNavX5V3Owned, CfgNavX5V3Ref},
     cfg_odo::{CfgOdo, CfgOdoOwned, CfgOdoRef},
     cfg_prt::{
         CfgPrtI2c, CfgPrtI2cOwned, CfgPrtI2cRef, CfgPrtSpi, CfgPrtSpiOwned, CfgPrtSpiRef,
@@ -35,11 +37,15 @@ use crate::ubx_packets::packets::{
     inf_test::{InfTest, InfTestOwned, InfTestRef},
     inf_warning::{InfWarning, InfWarningOwned, InfWarningRef},
     mga_ack::{MgaAck, MgaAckOwned, MgaAckRef},
+    mga_bds_alm::{MgaBdsAlm, MgaBdsAlmOwned, MgaBdsAlmRef},
     mga_bds_eph::{MgaBdsEph, MgaBdsEphOwned, MgaBdsEphRef},
     mga_bds_utc::{MgaBdsUtc, MgaBdsUtcOwned, MgaBdsUtcRef},
+    mga_gal_alm::{MgaGalAlm, MgaGalAlmOwned, MgaGalAlmRef},
     mga_gal_eph::{MgaGalEph, MgaGalEphOwned, MgaGalEphRef},
     mga_gal_time::{MgaGalTime, MgaGalTimeOwned, MgaGalTimeRef},
+    mga_glo_alm::{MgaGloAlm, MgaGloAlmOwned, MgaGloAlmRef},
     mga_glo_eph::{MgaGloEph, MgaGloEphOwned, MgaGloEphRef},
+    mga_gps_alm::{MgaGpsAlm, MgaGpsAlmOwned, MgaGpsAlmRef},
     mga_gps_eph::{MgaGpsEph, MgaGpsEphOwned, MgaGpsEphRef},
     mga_gps_iono::{MgaGpsIono, MgaGpsIonoOwned, MgaGpsIonoRef},
     mga_gps_utc::{MgaGpsUtc, MgaGpsUtcOwned, MgaGpsUtcRef},
@@ -53,6 +59,7 @@ use crate::ubx_packets::packets::{
     mon_rxr::{MonRxr, MonRxrOwned, MonRxrRef},
     mon_txbuf::{MonTxbuf, MonTxbufOwned, MonTxbufRef},
     mon_ver::{MonVer, MonVerOwned, MonVerRef},
+    nav_aop_status::{NavAopStatus, NavAopStatusOwned, NavAopStatusRef},
     nav_att::{NavAtt, NavAttOwned, NavAttRef},
     nav_clock::{NavClock, NavClockOwned, NavClockRef},
     nav_dop::{NavDop, NavDopOwned, NavDopRef},
@@ -68,6 +75,9 @@ use crate::ubx_packets::packets::{
     nav_sat::{NavSat, NavSatOwned, NavSatRef},
     nav_sol::{NavSol, NavSolOwned, NavSolRef},
     nav_status::{NavStatus, NavStatusOwned, NavStatusRef},
+    nav_time_bds::{NavTimeBds, NavTimeBdsOwned, NavTimeBdsRef},
+    nav_time_gal::{NavTimeGal, NavTimeGalOwned, NavTimeGalRef},
+    nav_time_glo::{NavTimeGlo, NavTimeGloOwned, NavTimeGloRef},
     nav_time_gps::{NavTimeGps, NavTimeGpsOwned, NavTimeGpsRef},
     nav_time_ls::{NavTimeLs, NavTimeLsOwned, NavTimeLsRef},
     nav_time_utc::{NavTimeUTC, NavTimeUTCOwned, NavTimeUTCRef},
@@ -93,9 +103,12 @@ define_recv_packets!(
         CfgEsfAlg,
         CfgEsfWt,
         CfgGnss,
+        CfgHnr,
         CfgInf,
         CfgItfm,
         CfgNav5,
+        CfgNavX5,
+        CfgNavX5V3,
         CfgOdo,
         CfgPrtI2c,
         CfgPrtSpi,
@@ -130,12 +143,17 @@ define_recv_packets!(
         MgaAck,
         MgaGpsIono,
         MgaGpsEph,
+        MgaGpsAlm,
         MgaGpsUtc,
         MgaBdsEph,
+        MgaBdsAlm,
         MgaBdsUtc,
         MgaGalEph,
+        MgaGalAlm,
         MgaGalTime,
         MgaGloEph,
+        MgaGloAlm,
+        NavAopStatus,
         NavAtt,
         NavClock,
         NavDop,
@@ -151,6 +169,9 @@ define_recv_packets!(
         NavStatus,
         NavVelNed,
         NavTimeGps,
+        NavTimeBds,
+        NavTimeGal,
+        NavTimeGlo,
         NavTimeUTC,
         NavTimeLs,
         NavVelECEF,
@@ -12,6 +12,7 @@ use crate::ubx_packets::packets::{
     cfg_inf::{CfgInf, CfgInfOwned, CfgInfRef},
     cfg_itfm::{CfgItfm, CfgItfmOwned, CfgItfmRef},
     cfg_nav5::{CfgNav5, CfgNav5Owned, CfgNav5Ref},
+    cfg_navx5::{CfgNavX5, CfgNavX5Owned, CfgNavX5Ref, CfgNavX5V3, CfgNavX5V3Owned, CfgNavX5V3Ref},
     cfg_odo::{CfgOdo, CfgOdoOwned, CfgOdoRef},
     cfg_prt::{
         CfgPrtI2c, CfgPrtI2cOwned, CfgPrtI2cRef, CfgPrtSpi, CfgPrtSpiOwned, CfgPrtSpiRef,
@@ -32,11 +33,15 @@ use crate::ubx_packets::packets::{
     inf_test::{InfTest, InfTestOwned, InfTestRef},
     inf_warning::{InfWarning, InfWarningOwned, InfWarningRef},
     mga_ack::{MgaAck, MgaAckOwned, MgaAckRef},
+    mga_bds_alm::{MgaBdsAlm, MgaBdsAlmOwned, MgaBdsAlmRef},
     mga_bds_eph::{MgaBdsEph, MgaBdsEphOwned, MgaBdsEphRef},
     mga_bds_utc::{MgaBdsUtc, MgaBdsUtcOwned, MgaBdsUtcRef},
+    mga_gal_alm::{MgaGalAlm, MgaGalAlmOwned, MgaGalAlmRef},
     mga_gal_eph::{MgaGalEph, MgaGalEphOwned, MgaGalEphRef},
     mga_gal_time::{MgaGalTime, MgaGalTimeOwned, MgaGalTimeRef},
+    mga_glo_alm::{MgaGloAlm, MgaGloAlmOwned, MgaGloAlmRef},
     mga_glo_eph::{MgaGloEph, MgaGloEphOwned, MgaGloEphRef},
+    mga_gps_alm::{MgaGpsAlm, MgaGpsAlmOwned, MgaGpsAlmRef},
     mga_gps_eph::{MgaGpsEph, MgaGpsEphOwned, MgaGpsEphRef},
     mga_gps_iono::{MgaGpsIono, MgaGpsIonoOwned, MgaGpsIonoRef},
     mga_gps_utc::{MgaGpsUtc, MgaGpsUtcOwned, MgaGpsUtcRef},
@@ -67,6 +72,9 @@ use crate::ubx_packets::packets::{
     nav_sig::{NavSig, NavSigOwned, NavSigRef},
     nav_sol::{NavSol, NavSolOwned, NavSolRef},
     nav_status::{NavStatus, NavStatusOwned, NavStatusRef},
+    nav_time_bds::{NavTimeBds, NavTimeBdsOwned, NavTimeBdsRef},
+    nav_time_gal::{NavTimeGal, NavTimeGalOwned, NavTimeGalRef},
+    nav_time_glo::{NavTimeGlo, NavTimeGloOwned, NavTimeGloRef},
     nav_time_gps::{NavTimeGps, NavTimeGpsOwned, NavTimeGpsRef},
     nav_time_ls::{NavTimeLs, NavTimeLsOwned, NavTimeLsRef},
     nav_time_utc::{NavTimeUTC, NavTimeUTCOwned, NavTimeUTCRef},
@@ -75,6 +83,7 @@ use crate::ubx_packets::packets::{
     rxm_rawx::{RxmRawx, RxmRawxOwned, RxmRawxRef},
     rxm_rtcm::{RxmRtcm, RxmRtcmOwned, RxmRtcmRef},
     rxm_sfrbx::{RxmSfrbx, RxmSfrbxOwned, RxmSfrbxRef},
+    sec_osnma::{SecOsnma, SecOsnmaOwned, SecOsnmaRef},
     sec_sig::{SecSig, SecSigOwned, SecSigRef},
     sec_siglog::{SecSiglog, SecSiglogOwned, SecSiglogRef},
     sec_uniq_id::{SecUniqId, SecUniqIdOwned, SecUniqIdRef},
@@ -97,6 +106,8 @@ define_recv_packets!(
         CfgInf,
         CfgItfm,
         CfgNav5,
+        CfgNavX5,
+        CfgNavX5V3,
         CfgOdo,
         CfgPrtI2c,
         CfgPrtSpi,
@@ -126,12 +137,16 @@ define_recv_packets!(
         MgaAck,
         MgaGpsIono,
         MgaGpsEph,
+        MgaGpsAlm,
         MgaGpsUtc,
         MgaBdsEph,
+        MgaBdsAlm,
         MgaBdsUtc,
         MgaGalEph,
+        MgaGalAlm,
         MgaGalTime,
         MgaGloEph,
+        MgaGloAlm,
         NavAtt,
         NavClock,
         NavCov,
@@ -151,6 +166,9 @@ define_recv_packets!(
         NavStatus,
         NavVelNed,
         NavTimeGps,
+        NavTimeBds,
+        NavTimeGal,
+        NavTimeGlo,
         NavTimeUTC,
         NavTimeLs,
         NavVelECEF,
@@ -158,6 +176,7 @@ define_recv_packets!(
         RxmRawx,
         RxmRtcm,
         RxmSfrbx,
+        SecOsnma,
         SecSig,
         SecSiglog,
         SecUniqId,
@@ -4,7 +4,7 @@ use bitflags::bitflags;
 #[cfg(feature = "serde")]
 use {super::SerializeUbxPacketFields, crate::serde::ser::SerializeMap};
 
-use crate::{error::ParserError, UbxPacketMeta};
+use crate::{error::ParserError, RepeatedIter, UbxPacketMeta};
 use ublox_derive::{ubx_extend_bitflags, ubx_packet_recv};
 
 #[ubx_packet_recv]
@@ -32,14 +32,9 @@ struct RxmRawx {
     /// Reserved
     reserved1: [u8; 2],
 
-    /// Extended software information strings
-    #[ubx(
-        map_type = RxmRawxInfoIter,
-        from = RxmRawxInfoIter::new,
-        may_fail,
-        is_valid = RxmRawxInfoIter::is_valid,
-    )]
-    measurements: [u8; 0],
+    /// Measurements of the signals tracked, `num_meas` blocks
+    #[ubx(repeat = num_meas)]
+    measurements: [RxmRawxInfo],
 }
 
 #[ubx_extend_bitflags]
@@ -55,27 +50,10 @@ bitflags! {
     }
 }
 
-#[derive(Debug, Clone)]
-pub struct RxmRawxInfoIter<'a>(core::slice::ChunksExact<'a, u8>);
-
-impl<'a> RxmRawxInfoIter<'a> {
-    fn new(data: &'a [u8]) -> Self {
-        Self(data.chunks_exact(32))
-    }
-
-    fn is_valid(bytes: &[u8]) -> bool {
-        bytes.len().is_multiple_of(32)
-    }
-}
-
-impl<'a> core::iter::Iterator for RxmRawxInfoIter<'a> {
-    type Item = RxmRawxInfoRef<'a>;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        self.0.next().map(RxmRawxInfoRef)
-    }
-}
+/// Iterator over the measurements of UBX-RXM-RAWX
+pub type RxmRawxInfoIter<'a> = RepeatedIter<'a, RxmRawxInfo>;
 
+/// This packet is not actually received as such, it is a block of the `RxmRawx` message
 #[ubx_packet_recv]
 #[ubx(class = 0x02, id = 0x15, fixed_payload_len = 32)]
 #[derive(Debug)]
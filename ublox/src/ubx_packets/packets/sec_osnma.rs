@@ -0,0 +1,73 @@
+//! SEC-OSNMA: Galileo OSNMA authentication status
+//!
+//! Reports whether the Galileo Open Service Navigation Message Authentication (OSNMA) is
+//! running and how many satellites have their navigation data authenticated, on receiver
+//! firmware supporting OSNMA.
+
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use bitflags::bitflags;
+
+use crate::{error::ParserError, UbxPacketMeta};
+use ublox_derive::{ubx_extend, ubx_extend_bitflags, ubx_packet_recv};
+
+/// Galileo OSNMA authentication status
+#[ubx_packet_recv]
+#[ubx(class = 0x27, id = 0x0a, fixed_payload_len = 12)]
+struct SecOsnma {
+    /// Message version
+    version: u8,
+
+    /// Authentication state flags
+    #[ubx(map_type = OsnmaFlags)]
+    flags: u8,
+
+    /// Status of the navigation message authentication announced in the OSNMA header
+    #[ubx(map_type = NmaStatus)]
+    nma_status: u8,
+
+    /// Number of Galileo satellites whose navigation data was authenticated
+    num_auth_svs: u8,
+
+    /// GPS time of week of the navigation epoch, in ms
+    itow: u32,
+
+    reserved0: [u8; 4],
+}
+
+#[ubx_extend_bitflags]
+#[ubx(from, rest_reserved)]
+bitflags! {
+    /// Authentication state flags of [SecOsnma]
+    #[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct OsnmaFlags: u8 {
+        /// OSNMA processing is enabled
+        const ENABLED = 0x01;
+        /// The receiver time is synchronized closely enough for the authentication, a
+        /// prerequisite for authenticated navigation data
+        const TIME_SYNC = 0x02;
+        /// The public key was verified against the Merkle tree root
+        const KEY_VALID = 0x04;
+        /// The time of the navigation solution was derived from authenticated data
+        const TIME_AUTHENTICATED = 0x08;
+    }
+}
+
+/// Navigation message authentication status of the OSNMA header
+#[ubx_extend]
+#[ubx(from, rest_reserved)]
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NmaStatus {
+    /// No OSNMA header received yet
+    Unknown = 0,
+    /// OSNMA is in a test phase, its authentications are not to be relied upon
+    Test = 1,
+    /// OSNMA is operational
+    Operational = 2,
+    /// The authentication must not be used
+    DontUse = 3,
+}
@@ -65,6 +65,152 @@ pub(crate) trait ToVelocity {
     fn to_velocity(&self) -> Velocity;
 }
 
+/// Velocity in the local north, east and down frame, in meters per second
+///
+/// Provided by UBX-NAV-VELNED and UBX-NAV-PVT, and by UBX-NAV-VELECEF through
+/// [VelocityECEF::to_ned], so that consumers don't depend on the message the receiver outputs.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VelocityNED {
+    pub north: f64,
+    pub east: f64,
+    pub down: f64,
+}
+
+/// Velocity in the ECEF (Earth-Centered, Earth-Fixed) frame, in meters per second
+///
+/// Provided by UBX-NAV-VELECEF.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VelocityECEF {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+/// A trait for types that can provide a velocity in the local north, east and down frame,
+/// allowing them to be converted to a [`VelocityNED`] struct.
+pub(crate) trait ToVelocityNED {
+    fn to_velocity_ned(&self) -> VelocityNED;
+}
+
+/// A trait for types that can provide a velocity in the ECEF frame, allowing them to be
+/// converted to a [`VelocityECEF`] struct.
+pub(crate) trait ToVelocityECEF {
+    fn to_velocity_ecef(&self) -> VelocityECEF;
+}
+
+/// Sines and cosines of the latitude and longitude of `at`
+#[cfg(feature = "std")]
+fn ned_rotation(at: &PositionLLA) -> (f64, f64, f64, f64) {
+    let (sin_lat, cos_lat) = at.lat.to_radians().sin_cos();
+    let (sin_lon, cos_lon) = at.lon.to_radians().sin_cos();
+    (sin_lat, cos_lat, sin_lon, cos_lon)
+}
+
+#[cfg(feature = "std")]
+impl VelocityECEF {
+    /// The velocity in the north, east and down frame of the position `at`, usually the
+    /// position of the same epoch
+    ///
+    /// ```
+    /// use ublox::{PositionLLA, VelocityECEF};
+    ///
+    /// // Moving up at the equator and the prime meridian, where up is along the X axis
+    /// let at = PositionLLA { lon: 0.0, lat: 0.0, alt: 0.0 };
+    /// let ned = VelocityECEF { x: 2.0, y: 0.0, z: 0.0 }.to_ned(&at);
+    /// assert!((ned.down + 2.0).abs() < 1e-9);
+    /// ```
+    pub fn to_ned(&self, at: &PositionLLA) -> VelocityNED {
+        let (sin_lat, cos_lat, sin_lon, cos_lon) = ned_rotation(at);
+        VelocityNED {
+            north: -sin_lat * cos_lon * self.x - sin_lat * sin_lon * self.y + cos_lat * self.z,
+            east: -sin_lon * self.x + cos_lon * self.y,
+            down: -cos_lat * cos_lon * self.x - cos_lat * sin_lon * self.y - sin_lat * self.z,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl VelocityNED {
+    /// The velocity in the ECEF frame, given the position `at` of the north, east and down
+    /// frame
+    pub fn to_ecef(&self, at: &PositionLLA) -> VelocityECEF {
+        let (sin_lat, cos_lat, sin_lon, cos_lon) = ned_rotation(at);
+        VelocityECEF {
+            x: -sin_lat * cos_lon * self.north
+                - sin_lon * self.east
+                - cos_lat * cos_lon * self.down,
+            y: -sin_lat * sin_lon * self.north + cos_lon * self.east
+                - cos_lat * sin_lon * self.down,
+            z: cos_lat * self.north - sin_lat * self.down,
+        }
+    }
+}
+
+/// Speed over the ground and heading of motion, in degrees from north in `0..360`
+#[cfg(feature = "std")]
+impl From<VelocityNED> for Velocity {
+    fn from(velocity: VelocityNED) -> Self {
+        Self {
+            speed: velocity.north.hypot(velocity.east),
+            heading: velocity
+                .east
+                .atan2(velocity.north)
+                .to_degrees()
+                .rem_euclid(360.0),
+        }
+    }
+}
+
+/// What a [Heading] describes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HeadingSource {
+    /// Direction of travel over ground (`headMot`). It is undefined while stationary, where
+    /// the receiver keeps its last value or lets it drift, and points backwards when reversing.
+    Motion,
+    /// Direction the vehicle points to (`headVeh`, or the UBX-NAV-ATT heading), from sensor
+    /// fusion, also valid while stationary or reversing
+    Vehicle,
+}
+
+/// A heading together with what it describes and whether the receiver considers it valid.
+///
+/// Receivers without sensor fusion only report the heading of motion, which is not the
+/// orientation of the vehicle: check [Heading::source] before using it as such.
+///
+/// ```
+/// use ublox::{Heading, HeadingSource};
+///
+/// fn orientation(heading: Heading) -> Option<f64> {
+///     (heading.valid && heading.source == HeadingSource::Vehicle).then_some(heading.heading)
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Heading {
+    /// Heading in degrees
+    pub heading: f64,
+
+    /// Heading accuracy estimate in degrees
+    pub accuracy: f64,
+
+    pub source: HeadingSource,
+
+    /// Whether the receiver flags the heading as valid: the heading of vehicle flag for
+    /// [HeadingSource::Vehicle], a valid fix for [HeadingSource::Motion]
+    pub valid: bool,
+}
+
+/// A trait for types that can provide heading information.
+///
+/// This trait is implemented by uBlox packets that contain a heading, allowing them to be
+/// converted to a [`Heading`] struct. The heading of vehicle is preferred when valid.
+pub(crate) trait ToHeading {
+    fn to_heading(&self) -> Heading;
+}
+
 impl<T> From<&T> for PositionLLA
 where
     T: ToLLA,
@@ -83,6 +229,33 @@ where
     }
 }
 
+impl<T> From<&T> for VelocityNED
+where
+    T: ToVelocityNED,
+{
+    fn from(packet: &T) -> Self {
+        packet.to_velocity_ned()
+    }
+}
+
+impl<T> From<&T> for VelocityECEF
+where
+    T: ToVelocityECEF,
+{
+    fn from(packet: &T) -> Self {
+        packet.to_velocity_ecef()
+    }
+}
+
+impl<T> From<&T> for Heading
+where
+    T: ToHeading,
+{
+    fn from(packet: &T) -> Self {
+        packet.to_heading()
+    }
+}
+
 impl<T> From<&T> for PositionECEF
 where
     T: ToECEF,
@@ -126,6 +299,12 @@ pub(crate) fn datetime_from_components(
     Ok(DateTime::from_naive_utc_and_offset(dt, Utc))
 }
 
+/// Text of a fixed size character field, up to the NUL padding
+pub(crate) fn str_from_chars(bytes: &[u8]) -> Result<&str, core::str::Utf8Error> {
+    let len = bytes.iter().position(|b| *b == 0).unwrap_or(bytes.len());
+    core::str::from_utf8(&bytes[..len])
+}
+
 #[allow(dead_code, reason = "It is only dead code in some feature sets")]
 pub(crate) struct FieldIter<I>(pub(crate) I);
 
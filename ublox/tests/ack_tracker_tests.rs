@@ -0,0 +1,84 @@
+#![cfg(feature = "ubx_proto23")]
+
+use core::time::Duration;
+use ublox::{
+    ack_tracker::{AckEvent, AckTracker},
+    proto23::Proto23,
+};
+
+#[path = "common/mod.rs"]
+mod common;
+use common::packet;
+
+fn ms(ms: u64) -> Duration {
+    Duration::from_millis(ms)
+}
+
+#[test]
+fn test_ack_tracker_matches_in_send_order() {
+    let mut tracker = AckTracker::<4>::new(ms(100));
+    assert!(tracker.record(0x06, 0x8a, ms(0)));
+    assert!(tracker.record(0x06, 0x01, ms(10)));
+    assert!(tracker.record(0x06, 0x8a, ms(20)));
+
+    // Other packets are ignored
+    assert_eq!(
+        tracker.update(&packet::<Proto23>(0x01, 0x03, &[0; 16]), ms(25)),
+        None
+    );
+
+    assert_eq!(
+        tracker.update(&packet::<Proto23>(0x05, 0x01, &[0x06, 0x8a]), ms(30)),
+        Some(AckEvent::Acked {
+            class: 0x06,
+            msg_id: 0x8a,
+            latency: ms(30),
+        })
+    );
+    assert_eq!(
+        tracker.update(&packet::<Proto23>(0x05, 0x00, &[0x06, 0x8a]), ms(40)),
+        Some(AckEvent::Nacked {
+            class: 0x06,
+            msg_id: 0x8a,
+            latency: ms(20),
+        })
+    );
+    assert_eq!(
+        tracker.update(&packet::<Proto23>(0x05, 0x01, &[0x06, 0x8a]), ms(50)),
+        Some(AckEvent::Unexpected {
+            class: 0x06,
+            msg_id: 0x8a,
+            ack: true,
+        })
+    );
+
+    let pending: Vec<_> = tracker.pending().map(|sent| sent.msg_id).collect();
+    assert_eq!(pending, [0x01]);
+}
+
+#[test]
+fn test_ack_tracker_times_out() {
+    let mut tracker = AckTracker::<2>::new(ms(100));
+    assert_eq!(tracker.next_deadline(), None);
+    assert!(tracker.record_frame(&[0xb5, 0x62, 0x06, 0x08, 0x06, 0x00], ms(0)));
+    assert!(tracker.record(0x06, 0x01, ms(50)));
+    // Full
+    assert!(!tracker.record(0x06, 0x02, ms(60)));
+    assert!(!tracker.record_frame(&[0xb5, 0x62], ms(60)));
+
+    assert_eq!(tracker.next_deadline(), Some(ms(100)));
+    assert_eq!(tracker.poll_timeout(ms(99)), None);
+    assert_eq!(
+        tracker.poll_timeout(ms(120)),
+        Some(AckEvent::TimedOut {
+            class: 0x06,
+            msg_id: 0x08,
+        })
+    );
+    assert_eq!(tracker.poll_timeout(ms(120)), None);
+    assert_eq!(tracker.next_deadline(), Some(ms(150)));
+
+    tracker.clear();
+    assert!(tracker.is_idle());
+    assert_eq!(tracker.poll_timeout(ms(1000)), None);
+}
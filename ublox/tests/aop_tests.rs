@@ -0,0 +1,65 @@
+#![cfg(feature = "ubx_proto23")]
+
+use ublox::{
+    aop::AopControl,
+    cfg_navx5::{AopCfg, CfgNavX5Builder, CfgNavX5Params1},
+    nav_aop_status::AopStatus,
+    proto23::Proto23,
+};
+
+#[path = "common/mod.rs"]
+mod common;
+use common::packet;
+
+fn nav_aop_status(aop_cfg: u8, status: u8) -> [u8; 16] {
+    let mut payload = [0; 16];
+    payload[4] = aop_cfg;
+    payload[5] = status;
+    payload
+}
+
+#[test]
+fn navx5_packet_only_applies_aop_settings() {
+    let packet = AopControl::navx5_packet(true, 60);
+    assert_eq!(
+        packet,
+        CfgNavX5Builder {
+            version: 2,
+            mask1: CfgNavX5Params1::AOP_CFG,
+            aop_cfg: AopCfg::USE_AOP,
+            aop_orb_max_err: 60,
+            ..Default::default()
+        }
+        .into_packet_bytes()
+    );
+}
+
+#[test]
+fn tracks_status_and_configuration() {
+    let mut aop = AopControl::new();
+    assert_eq!(aop.is_enabled(), None);
+    assert_eq!(aop.status(), None);
+
+    let running = nav_aop_status(1, 1);
+    assert_eq!(
+        aop.update(&packet::<Proto23>(0x01, 0x60, &running)),
+        Some(AopStatus::Running)
+    );
+    assert_eq!(aop.is_enabled(), Some(true));
+
+    let idle = nav_aop_status(1, 0);
+    assert_eq!(
+        aop.update(&packet::<Proto23>(0x01, 0x60, &idle)),
+        Some(AopStatus::Idle)
+    );
+    assert_eq!(aop.status(), Some(AopStatus::Idle));
+
+    // A CFG-NAVX5 poll response only changes the configuration
+    let disabled = AopControl::navx5_packet(false, 0);
+    assert_eq!(
+        aop.update(&packet::<Proto23>(0x06, 0x23, &disabled[6..46])),
+        None
+    );
+    assert_eq!(aop.is_enabled(), Some(false));
+    assert_eq!(aop.status(), Some(AopStatus::Idle));
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "ubx_proto23")]
+
+use ublox::{
+    cfg_gnss::GnssId,
+    cno_trend::{CnoDrop, CnoTrend},
+    proto23::{PacketRef, Proto23},
+    UbxProtocol,
+};
+
+/// UBX-NAV-SAT payload with one satellite per `(gnss_id, cno)`
+fn nav_sat_payload(itow: u32, svs: &[(u8, u8)]) -> Vec<u8> {
+    let mut payload = itow.to_le_bytes().to_vec();
+    payload.extend_from_slice(&[1, svs.len() as u8, 0, 0]);
+    for (i, &(gnss_id, cno)) in svs.iter().enumerate() {
+        payload.extend_from_slice(&[gnss_id, i as u8 + 1, cno, 30, 0, 0, 0, 0, 0, 0, 0, 0]);
+    }
+    payload
+}
+
+fn update(trend: &mut CnoTrend, itow: u32, svs: &[(u8, u8)]) -> Vec<CnoDrop> {
+    let payload = nav_sat_payload(itow, svs);
+    let Ok(PacketRef::NavSat(nav_sat)) = Proto23::match_packet(0x01, 0x35, &payload) else {
+        panic!("Expected a UBX-NAV-SAT packet");
+    };
+    trend.update(&nav_sat).collect()
+}
+
+#[test]
+fn test_cno_trend_antenna_disconnect() {
+    let mut trend = CnoTrend::new();
+    for itow in 0..5 {
+        let drops = update(&mut trend, itow, &[(0, 40), (0, 44), (6, 35), (9, 50)]);
+        assert!(drops.is_empty());
+    }
+    let gps = trend.constellation(GnssId::GPS);
+    assert_eq!(gps.mean(), Some(42.0));
+    assert_eq!(gps.itow(), Some(4));
+    assert_eq!(gps.histogram()[8], 10);
+    assert_eq!(trend.constellation(GnssId::GLONASS).histogram()[7], 5);
+
+    // GPS loses all signals, GLONASS is not reported in this epoch
+    let drops = update(&mut trend, 5, &[(0, 0), (0, 0)]);
+    assert_eq!(
+        drops,
+        [CnoDrop {
+            gnss_id: GnssId::GPS,
+            itow: 5,
+            rolling_mean: 42.0,
+            epoch_mean: 0.0,
+        }]
+    );
+    assert_eq!(trend.constellation(GnssId::GLONASS).itow(), Some(4));
+
+    // A lasting drop is only reported once
+    assert!(update(&mut trend, 6, &[(0, 0), (0, 0)]).is_empty());
+}
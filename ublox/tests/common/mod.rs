@@ -1,54 +1,19 @@
-//! Shared helpers for the UBX packet fuzz tests.
+//! Shared helpers of the integration tests.
 //!
-//! Keep this module minimal: only protocol-level primitives (checksum, frame
-//! envelope) and generic proptest strategies belong here. Per-packet payload
+//! Keep this module minimal: only generic helpers and proptest strategies belong here, frames
+//! are built with `ublox::ubx_frame!`. Per-packet payload
 //! structs and their `*_payload_strategy()` / `to_bytes()` helpers stay in
 //! their own test files so that each packet's correctness remains
 //! independently auditable.
 
 #![allow(dead_code)] // each test binary uses only a subset of these helpers
 
-use byteorder::{LittleEndian, WriteBytesExt};
 use proptest::prelude::*;
-use ublox::constants::{UBX_SYNC_CHAR_1, UBX_SYNC_CHAR_2};
+use ublox::{PayloadField, UbxPacket, UbxPacketMeta, UbxProtocol};
 
-/// Calculates the 8-bit Fletcher-16 checksum used by U-Blox.
-///
-/// `data` is the checksummed region of the frame: class, id, length and
-/// payload (everything between the sync chars and the checksum bytes).
-pub fn calculate_checksum(data: &[u8]) -> (u8, u8) {
-    let mut ck_a: u8 = 0;
-    let mut ck_b: u8 = 0;
-    for byte in data {
-        ck_a = ck_a.wrapping_add(*byte);
-        ck_b = ck_b.wrapping_add(ck_a);
-    }
-    (ck_a, ck_b)
-}
-
-/// Assembles a complete, valid UBX frame from a class/id and payload:
-/// `[sync1, sync2, class, id, len_lo, len_hi, payload..., ck_a, ck_b]`.
-///
-/// The length field is the payload length as a little-endian `u16`, and the
-/// checksum is computed over `class..=payload` per [`calculate_checksum`].
-pub fn build_ubx_frame(class: u8, id: u8, payload: &[u8]) -> Vec<u8> {
-    let mut frame_core = Vec::with_capacity(4 + payload.len());
-    frame_core.push(class);
-    frame_core.push(id);
-    frame_core
-        .write_u16::<LittleEndian>(payload.len() as u16)
-        .unwrap();
-    frame_core.extend_from_slice(payload);
-
-    let (ck_a, ck_b) = calculate_checksum(&frame_core);
-
-    let mut frame = Vec::with_capacity(8 + payload.len());
-    frame.push(UBX_SYNC_CHAR_1);
-    frame.push(UBX_SYNC_CHAR_2);
-    frame.extend_from_slice(&frame_core);
-    frame.push(ck_a);
-    frame.push(ck_b);
-    frame
+/// `payload` decoded as the packet `class`, `msg_id` of protocol `P`
+pub fn packet<P: UbxProtocol>(class: u8, msg_id: u8, payload: &[u8]) -> UbxPacket<'_> {
+    P::match_packet(class, msg_id, payload).unwrap().into()
 }
 
 /// A proptest strategy that generates only finite `f32` values (no NaN/inf).
@@ -62,3 +27,40 @@ pub fn finite_f32() -> impl Strategy<Value = f32> {
         }
     })
 }
+
+/// A proptest strategy for payloads of packet `P` closer to real traffic than random bytes:
+/// the fields [UbxPacketMeta::PAYLOAD_FIELDS] lists as reserved are zero and the `version`
+/// field is one of `versions`, the other fields are random.
+///
+/// Variable-length packets get only the [UbxPacketMeta::PAYLOAD_FIELDS_LEN] bytes of the fields
+/// before their first variable-length field, the repeated blocks are for the caller to append.
+pub fn fuzz_payload_realistic_strategy<P: UbxPacketMeta>(
+    versions: &'static [u8],
+) -> impl Strategy<Value = Vec<u8>> {
+    let version = if versions.is_empty() {
+        Just(None).boxed()
+    } else {
+        proptest::sample::select(versions).prop_map(Some).boxed()
+    };
+    (
+        proptest::collection::vec(any::<u8>(), usize::from(P::PAYLOAD_FIELDS_LEN)),
+        version,
+    )
+        .prop_map(|(mut payload, version)| {
+            for field in P::PAYLOAD_FIELDS {
+                let bytes = &mut payload[field_range(field)];
+                if field.is_reserved() {
+                    bytes.fill(0);
+                } else if let (true, Some(version)) = (field.is_version(), version) {
+                    bytes.fill(0);
+                    bytes[0] = version;
+                }
+            }
+            payload
+        })
+}
+
+/// Range of `field` in a payload
+pub fn field_range(field: &PayloadField) -> core::ops::Range<usize> {
+    usize::from(field.offset)..usize::from(field.offset + field.size)
+}
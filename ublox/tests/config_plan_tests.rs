@@ -0,0 +1,137 @@
+#![cfg(feature = "ubx_proto27")]
+
+use core::time::Duration;
+use ublox::{
+    cfg_val::{CfgLayerSet, CfgVal},
+    config_plan::{ConfigPlan, KeyStatus},
+    proto27::Proto27,
+};
+
+#[path = "common/mod.rs"]
+mod common;
+use common::packet;
+
+fn ms(ms: u64) -> Duration {
+    Duration::from_millis(ms)
+}
+
+/// Payload of a UBX-CFG-VALGET response from the RAM layer
+fn valget_response(cfg_data: &[u8]) -> Vec<u8> {
+    let mut payload = vec![0x01, 0x00, 0x00, 0x00];
+    payload.extend_from_slice(cfg_data);
+    payload
+}
+
+const RATE_MEAS_200: [u8; 6] = [0x01, 0x00, 0x21, 0x30, 0xc8, 0x00];
+const RATE_MEAS_1000: [u8; 6] = [0x01, 0x00, 0x21, 0x30, 0xe8, 0x03];
+const UART1_BAUDRATE_115200: [u8; 8] = [0x01, 0x00, 0x52, 0x40, 0x00, 0xc2, 0x01, 0x00];
+
+#[test]
+fn test_config_plan_sets_and_verifies() {
+    let mut plan = ConfigPlan::new(
+        [CfgVal::RateMeas(200), CfgVal::Uart1Baudrate(115_200)],
+        CfgLayerSet::RAM | CfgLayerSet::BBR,
+    )
+    .with_timeout(ms(100));
+
+    let valset = plan.poll_output(ms(0)).unwrap();
+    assert_eq!(valset[2..4], [0x06, 0x8a]);
+    assert_eq!(plan.poll_timeout(), Some(ms(100)));
+
+    // Acknowledgements of other messages are ignored
+    assert!(!plan.handle_packet(&packet::<Proto27>(0x05, 0x01, &[0x06, 0x08])));
+    assert!(plan.handle_packet(&packet::<Proto27>(0x05, 0x01, &[0x06, 0x8a])));
+    assert_eq!(plan.outcomes()[0].status, KeyStatus::Applied);
+
+    // Read back from RAM
+    let valget = plan.poll_output(ms(20)).unwrap();
+    assert_eq!(
+        valget[2..18],
+        [
+            0x06, 0x8b, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x21, 0x30, 0x01, 0x00,
+            0x52, 0x40
+        ]
+    );
+    let mut cfg_data = RATE_MEAS_200.to_vec();
+    cfg_data.extend_from_slice(&UART1_BAUDRATE_115200);
+    assert!(plan.handle_packet(&packet::<Proto27>(0x06, 0x8b, &valget_response(&cfg_data))));
+
+    assert!(plan.is_done());
+    assert!(plan.is_success());
+    assert_eq!(plan.poll_output(ms(30)), None);
+    assert_eq!(plan.failures().count(), 0);
+}
+
+#[test]
+fn test_config_plan_reports_mismatches_and_missing_keys() {
+    let mut plan = ConfigPlan::new(
+        [CfgVal::RateMeas(200), CfgVal::Uart1Baudrate(115_200)],
+        CfgLayerSet::RAM,
+    );
+    plan.poll_output(ms(0)).unwrap();
+    assert!(plan.handle_packet(&packet::<Proto27>(0x05, 0x01, &[0x06, 0x8a])));
+    plan.poll_output(ms(10)).unwrap();
+    assert!(plan.handle_packet(&packet::<Proto27>(
+        0x06,
+        0x8b,
+        &valget_response(&RATE_MEAS_1000)
+    )));
+
+    assert!(plan.is_done());
+    assert!(!plan.is_success());
+    let statuses: Vec<_> = plan.failures().map(|outcome| outcome.status).collect();
+    assert_eq!(
+        statuses,
+        [
+            KeyStatus::Mismatch(CfgVal::RateMeas(1000)),
+            KeyStatus::NotReadBack
+        ]
+    );
+}
+
+#[test]
+fn test_config_plan_skips_rejected_chunks() {
+    let values: Vec<_> = (0..100).map(|_| CfgVal::RateMeas(200)).collect();
+    let mut plan = ConfigPlan::new(values, CfgLayerSet::BBR | CfgLayerSet::FLASH);
+
+    // A first frame of 64 values, rejected
+    let valset = plan.poll_output(ms(0)).unwrap();
+    assert_eq!(valset[4..6], (4 + 64 * 6u16).to_le_bytes());
+    assert!(plan.handle_packet(&packet::<Proto27>(0x05, 0x00, &[0x06, 0x8a])));
+
+    // The second one is still sent
+    let valset = plan.poll_output(ms(10)).unwrap();
+    assert_eq!(valset[4..6], (4 + 36 * 6u16).to_le_bytes());
+    assert!(plan.handle_packet(&packet::<Proto27>(0x05, 0x01, &[0x06, 0x8a])));
+
+    // Only the accepted values are read back, from BBR
+    let valget = plan.poll_output(ms(20)).unwrap();
+    assert_eq!(valget[4..6], (4 + 36 * 4u16).to_le_bytes());
+    assert_eq!(valget[7], 1);
+    assert!(plan.handle_packet(&packet::<Proto27>(0x05, 0x00, &[0x06, 0x8b])));
+
+    assert!(plan.is_done());
+    let outcomes = plan.outcomes();
+    assert!(outcomes[..64]
+        .iter()
+        .all(|outcome| outcome.status == KeyStatus::Rejected));
+    assert!(outcomes[64..]
+        .iter()
+        .all(|outcome| outcome.status == KeyStatus::NotReadBack));
+}
+
+#[test]
+fn test_config_plan_times_out() {
+    let mut plan = ConfigPlan::new([CfgVal::RateMeas(200)], CfgLayerSet::RAM).with_timeout(ms(50));
+    assert!(!plan.handle_packet(&packet::<Proto27>(0x05, 0x01, &[0x06, 0x8a])));
+    plan.poll_output(ms(0)).unwrap();
+    plan.handle_packet(&packet::<Proto27>(0x05, 0x01, &[0x06, 0x8a]));
+
+    plan.poll_output(ms(10)).unwrap();
+    plan.handle_timeout(ms(59));
+    assert!(!plan.is_done());
+    plan.handle_timeout(ms(60));
+    assert!(plan.is_done());
+    assert_eq!(plan.outcomes()[0].status, KeyStatus::TimedOut);
+    assert_eq!(plan.poll_timeout(), None);
+}
@@ -0,0 +1,27 @@
+#![cfg(all(feature = "defmt", feature = "ubx_proto23"))]
+
+use ublox::{
+    nav_pvt::{
+        common::NavPvtFlags,
+        proto23::{NavPvtOwned, NavPvtRef},
+    },
+    proto23::{PacketOwned, PacketRef},
+    ChecksumError, GnssFixType, ParserError, UbxPacket, UbxPacketOwned, UbxUnknownPacketRef,
+};
+
+fn assert_format<T: defmt::Format + ?Sized>() {}
+
+#[test]
+fn test_packets_enums_and_errors_implement_defmt_format() {
+    assert_format::<NavPvtRef<'_>>();
+    assert_format::<NavPvtOwned>();
+    assert_format::<PacketRef<'_>>();
+    assert_format::<PacketOwned>();
+    assert_format::<UbxPacket<'_>>();
+    assert_format::<UbxPacketOwned>();
+    assert_format::<UbxUnknownPacketRef<'_>>();
+    assert_format::<GnssFixType>();
+    assert_format::<NavPvtFlags>();
+    assert_format::<ParserError>();
+    assert_format::<ChecksumError>();
+}
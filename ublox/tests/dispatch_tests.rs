@@ -0,0 +1,116 @@
+#![cfg(feature = "ubx_proto23")]
+
+use std::cell::RefCell;
+
+use ublox::{
+    ack::AckAck, dispatch::Dispatcher, nav_pvt::proto23::NavPvt, proto23::Proto23, ubx_frame,
+    GnssFixType, ParserBuilder, ParserError,
+};
+
+fn nav_pvt(itow: u32, fix_type: u8) -> Vec<u8> {
+    let mut payload = [0; 92];
+    payload[..4].copy_from_slice(&itow.to_le_bytes());
+    payload[NavPvt::OFFSET_FIX_TYPE] = fix_type;
+    ubx_frame!(0x01, 0x07, &payload)
+}
+
+#[test]
+fn test_dispatch_to_handlers() {
+    let fixes = RefCell::new(Vec::new());
+    let acks = RefCell::new(0);
+    let unknown = RefCell::new(Vec::new());
+    let handled = RefCell::new(Vec::new());
+    let mut dispatcher = Dispatcher::new(
+        ParserBuilder::new()
+            .with_protocol::<Proto23>()
+            .with_fixed_buffer::<1024>(),
+    )
+    .on::<NavPvt>(|pvt| fixes.borrow_mut().push((pvt.itow(), pvt.fix_type())))
+    .on::<AckAck>(|_| *acks.borrow_mut() += 1)
+    .on::<AckAck>(|ack| assert_eq!(*acks.borrow(), 1, "{ack:?}"))
+    .on_unknown(|frame| unknown.borrow_mut().push((frame.class, frame.msg_id)))
+    .on_handled(|frame| {
+        // After the handlers of the frame
+        if frame.class == 0x05 {
+            assert_eq!(*acks.borrow(), 1);
+        }
+        handled.borrow_mut().push(frame.msg_id);
+    });
+
+    let mut data = nav_pvt(1000, 3);
+    data.extend(ubx_frame!(0x05, 0x01, &[0x06, 0x00]));
+    data.extend(ubx_frame!(0x0a, 0x04, &[]));
+    data.extend(nav_pvt(2000, 0));
+    // Split frames across calls
+    for chunk in data.chunks(7) {
+        dispatcher.feed(chunk);
+    }
+    drop(dispatcher);
+
+    assert_eq!(
+        *fixes.borrow(),
+        [(1000, GnssFixType::Fix3D), (2000, GnssFixType::NoFix)]
+    );
+    assert_eq!(*acks.borrow(), 1);
+    assert_eq!(*unknown.borrow(), [(0x0a, 0x04)]);
+    assert_eq!(*handled.borrow(), [0x07, 0x01, 0x04, 0x07]);
+}
+
+#[test]
+fn test_dispatch_errors() {
+    let mut errors = Vec::new();
+    let mut handled = 0;
+    let mut dispatcher = Dispatcher::new(
+        ParserBuilder::new()
+            .with_protocol::<Proto23>()
+            .with_fixed_buffer::<1024>(),
+    )
+    .on::<AckAck>(|_| handled += 1)
+    .on_error(|e| errors.push(e));
+
+    // Payload too short for UBX-ACK-ACK
+    dispatcher.feed(&ubx_frame!(0x05, 0x01, &[0x06]));
+    // Bad checksum
+    let mut corrupted = ubx_frame!(0x05, 0x01, &[0x06, 0x00]);
+    corrupted[7] ^= 0xff;
+    dispatcher.feed(&corrupted);
+    // No handler, so an invalid payload is not noticed
+    dispatcher.feed(&ubx_frame!(0x01, 0x07, &[0; 3]));
+    dispatcher.feed(&ubx_frame!(0x05, 0x01, &[0x06, 0x00]));
+    drop(dispatcher);
+
+    assert_eq!(handled, 1);
+    assert_eq!(errors.len(), 2);
+    assert!(matches!(
+        errors[0],
+        ParserError::InvalidPacketLen {
+            packet: "AckAck",
+            expect: 2,
+            got: 1
+        }
+    ));
+    assert!(matches!(errors[1], ParserError::InvalidChecksum { .. }));
+}
+
+#[test]
+fn test_dispatch_rejects_accepted_invalid_checksums() {
+    let mut errors = Vec::new();
+    let mut frames = 0;
+    let mut dispatcher = Dispatcher::new(
+        ParserBuilder::new()
+            .with_protocol::<Proto23>()
+            .accept_invalid_checksums(true)
+            .with_fixed_buffer::<1024>(),
+    )
+    .on_frame(|_| frames += 1)
+    .on_unknown(|frame| panic!("Corrupted frame passed on: {frame:?}"))
+    .on_error(|e| errors.push(e));
+
+    let mut corrupted = ubx_frame!(0x0a, 0x04);
+    corrupted[7] ^= 0xff;
+    dispatcher.feed(&corrupted);
+    drop(dispatcher);
+
+    assert_eq!(frames, 0);
+    assert!(matches!(errors[..], [ParserError::InvalidChecksum { .. }]));
+}
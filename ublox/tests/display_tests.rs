@@ -0,0 +1,62 @@
+#![cfg(feature = "display")]
+
+use ublox::UbxProtocol;
+
+#[test]
+#[cfg(feature = "ubx_proto23")]
+fn test_nav_pvt_and_nav_sat_display_list_their_fields() {
+    use ublox::proto23::{PacketRef, Proto23};
+
+    let mut payload = [0u8; 92];
+    payload[0..4].copy_from_slice(&1000u32.to_le_bytes());
+    payload[23] = 12;
+    let packet = Proto23::match_packet(0x01, 0x07, &payload).unwrap();
+    let PacketRef::NavPvt(pvt) = packet else {
+        panic!("Not a UBX-NAV-PVT");
+    };
+    let text = pvt.to_string();
+    assert_eq!(text.lines().next(), Some("NavPvt"));
+    assert!(text.lines().any(|line| line == "  itow: 1000"));
+    assert!(text.lines().any(|line| line == "  num_satellites: 12"));
+    assert_eq!(pvt.to_owned().to_string(), text);
+
+    let mut payload = [0u8; 20];
+    payload[5] = 1;
+    payload[9] = 12;
+    let packet = Proto23::match_packet(0x01, 0x35, &payload).unwrap();
+    let PacketRef::NavSat(sat) = packet else {
+        panic!("Not a UBX-NAV-SAT");
+    };
+    let text = sat.to_string();
+    assert_eq!(text.lines().next(), Some("NavSat"));
+    assert!(text.lines().any(|line| line == "  num_svs: 1"));
+    assert!(text.contains("sv_id: 12"));
+}
+
+#[test]
+#[cfg(feature = "ubx_proto27")]
+fn test_nav_hp_pos_llh_display_lists_scaled_fields_with_units() {
+    use ublox::proto27::{PacketRef, Proto27};
+
+    let mut payload = [0u8; 36];
+    payload[4..8].copy_from_slice(&1000u32.to_le_bytes());
+    payload[8..12].copy_from_slice(&115_000_000i32.to_le_bytes());
+    payload[12..16].copy_from_slice(&480_000_000i32.to_le_bytes());
+    payload[16..20].copy_from_slice(&520_000i32.to_le_bytes());
+
+    let packet = Proto27::match_packet(0x01, 0x14, &payload).unwrap();
+    let PacketRef::NavHpPosLlh(pos) = packet else {
+        panic!("Not a UBX-NAV-HPPOSLLH");
+    };
+    let text = pos.to_string();
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(lines[0], "NavHpPosLlh");
+    assert!(lines.contains(&"  itow: 1000"));
+    assert!(lines.contains(&"  lon: 11.5 deg"));
+    assert!(lines.contains(&"  lat: 48.0 deg"));
+    assert!(lines.contains(&"  height_meters: 520.0"));
+    // Reserved fields are left out
+    assert!(!text.contains("reserved"));
+
+    assert_eq!(pos.to_owned().to_string(), text);
+}
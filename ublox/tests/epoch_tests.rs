@@ -0,0 +1,126 @@
+#![cfg(feature = "ubx_proto23")]
+
+use ublox::{
+    epoch::{EpochAggregator, EpochComponents, EpochPolicy, NavEpoch},
+    nav_pvt::proto23::NavPvt,
+    proto23::Proto23,
+    GnssFixType,
+};
+
+#[path = "common/mod.rs"]
+mod common;
+use common::packet;
+
+fn with_itow<const N: usize>(itow: u32) -> [u8; N] {
+    let mut payload = [0; N];
+    payload[..4].copy_from_slice(&itow.to_le_bytes());
+    payload
+}
+
+fn nav_pvt(itow: u32) -> [u8; 92] {
+    let mut payload = with_itow(itow);
+    payload[NavPvt::OFFSET_FIX_TYPE] = 3; // 3D fix
+    payload[NavPvt::OFFSET_FLAGS] = 0x01; // gnssFixOK
+    payload[NavPvt::OFFSET_NUM_SATELLITES] = 9;
+    payload
+}
+
+fn nav_status(itow: u32) -> [u8; 16] {
+    let mut payload = with_itow(itow);
+    payload[4] = 2; // 2D fix
+    payload
+}
+
+fn feed(aggregator: &mut EpochAggregator, class: u8, msg_id: u8, payload: &[u8]) -> Vec<NavEpoch> {
+    aggregator
+        .update(&packet::<Proto23>(class, msg_id, payload))
+        .collect()
+}
+
+#[test]
+fn test_wait_for_all_emits_once_complete() {
+    let mut aggregator = EpochAggregator::new(EpochPolicy::WaitForAll(
+        EpochComponents::PVT | EpochComponents::DOP,
+    ));
+    assert!(feed(&mut aggregator, 0x01, 0x07, &nav_pvt(1000)).is_empty());
+    // Other packets are ignored
+    assert!(feed(&mut aggregator, 0x05, 0x01, &[0x06, 0x8a]).is_empty());
+
+    let epochs = feed(&mut aggregator, 0x01, 0x04, &with_itow::<18>(1000));
+    assert_eq!(epochs.len(), 1);
+    let epoch = epochs[0];
+    assert_eq!(epoch.itow, 1000);
+    assert_eq!(
+        epoch.components(),
+        EpochComponents::PVT | EpochComponents::DOP
+    );
+    assert_eq!(epoch.fix_type(), Some(GnssFixType::Fix3D));
+    assert_eq!(epoch.fix_ok(), Some(true));
+    assert_eq!(epoch.num_satellites(), Some(9));
+    assert!(!epoch.end_of_epoch);
+
+    // Late messages of the emitted epoch are dropped
+    assert!(feed(&mut aggregator, 0x01, 0x03, &nav_status(1000)).is_empty());
+    assert!(feed(&mut aggregator, 0x01, 0x61, &with_itow::<4>(1000)).is_empty());
+    assert_eq!(aggregator.flush(), None);
+}
+
+#[test]
+fn test_wait_for_all_emits_incomplete_epochs() {
+    let mut aggregator = EpochAggregator::new(EpochPolicy::WaitForAll(EpochComponents::all()));
+
+    // Closed by UBX-NAV-EOE
+    assert!(feed(&mut aggregator, 0x01, 0x03, &nav_status(1000)).is_empty());
+    let epochs = feed(&mut aggregator, 0x01, 0x61, &with_itow::<4>(1000));
+    assert_eq!(epochs.len(), 1);
+    assert!(epochs[0].end_of_epoch);
+    assert_eq!(epochs[0].components(), EpochComponents::STATUS);
+    assert!(!epochs[0].is_complete(EpochComponents::all()));
+    // Degrades to UBX-NAV-STATUS without UBX-NAV-PVT
+    assert_eq!(epochs[0].fix_type(), Some(GnssFixType::Fix2D));
+    assert_eq!(epochs[0].fix_ok(), Some(false));
+    assert_eq!(epochs[0].num_satellites(), None);
+
+    // Closed by the next epoch
+    assert!(feed(&mut aggregator, 0x01, 0x07, &nav_pvt(2000)).is_empty());
+    let epochs = feed(&mut aggregator, 0x01, 0x07, &nav_pvt(3000));
+    assert_eq!(epochs.len(), 1);
+    assert_eq!(epochs[0].itow, 2000);
+    assert!(!epochs[0].end_of_epoch);
+    assert_eq!(aggregator.flush().unwrap().itow, 3000);
+}
+
+#[test]
+fn test_emit_on_eoe_collects_all_components() {
+    let mut aggregator = EpochAggregator::new(EpochPolicy::EmitOnEoe);
+    assert!(feed(&mut aggregator, 0x01, 0x07, &nav_pvt(1000)).is_empty());
+    assert!(feed(&mut aggregator, 0x01, 0x04, &with_itow::<18>(1000)).is_empty());
+    assert!(feed(&mut aggregator, 0x01, 0x22, &with_itow::<20>(1000)).is_empty());
+    let mut nav_sat = with_itow::<20>(1000);
+    nav_sat[5] = 1; // numSvs
+    nav_sat[16] = 0x08; // svUsed
+    assert!(feed(&mut aggregator, 0x01, 0x35, &nav_sat).is_empty());
+
+    let epochs = feed(&mut aggregator, 0x01, 0x61, &with_itow::<4>(1000));
+    assert_eq!(epochs.len(), 1);
+    let epoch = epochs[0];
+    assert!(epoch.end_of_epoch);
+    assert_eq!(
+        epoch.components(),
+        EpochComponents::all() - EpochComponents::STATUS
+    );
+    assert_eq!(epoch.satellites.unwrap().num_svs, 1);
+    assert_eq!(epoch.satellites.unwrap().num_used, 1);
+}
+
+#[test]
+fn test_new_epoch_flushes_and_completes_at_once() {
+    let mut aggregator = EpochAggregator::new(EpochPolicy::WaitForAll(EpochComponents::STATUS));
+    assert!(feed(&mut aggregator, 0x01, 0x07, &nav_pvt(1000)).is_empty());
+
+    let epochs = feed(&mut aggregator, 0x01, 0x03, &nav_status(2000));
+    let itows: Vec<_> = epochs.iter().map(|epoch| epoch.itow).collect();
+    assert_eq!(itows, [1000, 2000]);
+    assert_eq!(epochs[0].components(), EpochComponents::PVT);
+    assert_eq!(epochs[1].components(), EpochComponents::STATUS);
+}
@@ -0,0 +1,140 @@
+#![cfg(feature = "ubx_proto23")]
+
+use ublox::{
+    firmware::{AllowedFirmware, FirmwareIdentity, FirmwareImage, PatchSummary, ProtocolVersion},
+    proto23::{PacketRef, Proto23},
+    UbxProtocol,
+};
+
+/// A UBX-MON-VER payload with the given strings, padded to 30 and 10 bytes
+fn mon_ver(software: &str, hardware: &str, extensions: &[&str]) -> Vec<u8> {
+    let mut payload = vec![0; 40 + 30 * extensions.len()];
+    payload[..software.len()].copy_from_slice(software.as_bytes());
+    payload[30..30 + hardware.len()].copy_from_slice(hardware.as_bytes());
+    for (i, extension) in extensions.iter().enumerate() {
+        let start = 40 + 30 * i;
+        payload[start..start + extension.len()].copy_from_slice(extension.as_bytes());
+    }
+    payload
+}
+
+fn identity(payload: &[u8]) -> FirmwareIdentity {
+    let PacketRef::MonVer(ver) = Proto23::match_packet(0x0a, 0x04, payload).unwrap() else {
+        panic!("not a UBX-MON-VER");
+    };
+    FirmwareIdentity::from(&ver)
+}
+
+fn m8l() -> Vec<u8> {
+    mon_ver(
+        "EXT CORE 3.01 (d189ff)",
+        "00080000",
+        &[
+            "ROM BASE 3.01 (107888)",
+            "FWVER=ADR 4.11",
+            "PROTVER=19.10",
+            "MOD=NEO-M8L-0",
+            "FIS=0xEF4015 (100111)",
+            "GPS;GLO;GAL;BDS",
+        ],
+    )
+}
+
+#[test]
+fn mon_ver_identifiers_are_parsed() {
+    let identity = identity(&m8l());
+    assert_eq!(identity.software_version(), "EXT CORE 3.01 (d189ff)");
+    assert_eq!(identity.hardware_version(), "00080000");
+    assert_eq!(identity.image(), FirmwareImage::Ext);
+    assert_eq!(identity.rom_base(), Some("3.01 (107888)"));
+    assert_eq!(identity.fw_version(), Some("ADR 4.11"));
+    assert_eq!(
+        identity.protocol_version(),
+        Some(ProtocolVersion::new(19, 10))
+    );
+    assert_eq!(identity.module(), Some("NEO-M8L-0"));
+    assert_eq!(identity.patches(), None);
+
+    let rom = self::identity(&mon_ver(
+        "ROM CORE 3.01 (107888)",
+        "00080000",
+        &["FWVER=SPG 3.01", "PROTVER=18.00"],
+    ));
+    assert_eq!(rom.image(), FirmwareImage::Rom);
+    assert_eq!(rom.rom_base(), None);
+    assert_eq!(rom.module(), None);
+    assert_eq!(rom.protocol_version(), Some(ProtocolVersion::new(18, 0)));
+}
+
+#[test]
+fn protocol_versions_order_and_display() {
+    let v = "27.31".parse::<ProtocolVersion>().unwrap();
+    assert!(v > ProtocolVersion::new(27, 0));
+    assert!(v < ProtocolVersion::new(34, 10));
+    assert_eq!(v.to_string(), "27.31");
+    assert_eq!("18".parse(), Ok(ProtocolVersion::new(18, 0)));
+    assert!("x.10".parse::<ProtocolVersion>().is_err());
+}
+
+#[test]
+fn same_firmware_compares_equal() {
+    assert_eq!(identity(&m8l()), identity(&m8l()));
+    let other = identity(&mon_ver(
+        "EXT CORE 3.01 (d189ff)",
+        "00080000",
+        &["ROM BASE 3.01 (107888)", "FWVER=ADR 4.10", "PROTVER=19.10"],
+    ));
+    assert_ne!(identity(&m8l()), other);
+}
+
+#[test]
+fn allowlist_requires_every_identifier_of_an_entry() {
+    let identity = identity(&m8l());
+    assert!(identity.is_allowed(&[AllowedFirmware::any()]));
+    assert!(!identity.is_allowed(&[]));
+
+    let adr = AllowedFirmware::any()
+        .fw_version("ADR 4.11")
+        .module("NEO-M8L-0");
+    assert!(adr.matches(&identity));
+    assert!(!adr.module("NEO-M8U-0").matches(&identity));
+    assert!(!AllowedFirmware::any()
+        .min_protocol(ProtocolVersion::new(27, 0))
+        .matches(&identity));
+    assert!(AllowedFirmware::any()
+        .software_version("EXT CORE 3.01 (d189ff)")
+        .hardware_version("00080000")
+        .min_protocol(ProtocolVersion::new(19, 10))
+        .matches(&identity));
+
+    let allowlist = [
+        AllowedFirmware::any().fw_version("HPG 1.32"),
+        AllowedFirmware::any().fw_version("ADR 4.11"),
+    ];
+    assert!(identity.is_allowed(&allowlist));
+}
+
+#[test]
+fn patches_are_part_of_the_identity() {
+    // Two activated patches from BBR
+    let mut payload = vec![0x01, 0x00, 0x02, 0x00];
+    for address in [0x0010_0000_u32, 0x0010_0040] {
+        payload.extend_from_slice(&0x05_u32.to_le_bytes());
+        payload.extend_from_slice(&1_u32.to_le_bytes());
+        payload.extend_from_slice(&address.to_le_bytes());
+        payload.extend_from_slice(&0xdead_beef_u32.to_le_bytes());
+    }
+    let PacketRef::MonPatch(patch) = Proto23::match_packet(0x0a, 0x27, &payload).unwrap() else {
+        panic!("not a UBX-MON-PATCH");
+    };
+    let patches = PatchSummary::new(patch.patches());
+    assert_eq!(patches.count, 2);
+    assert_eq!(patches.activated, 2);
+    assert_ne!(patches.digest, PatchSummary::new([]).digest);
+
+    let patched = identity(&m8l()).with_patches(patches);
+    assert_ne!(patched, identity(&m8l()));
+    let allowed = AllowedFirmware::any().patch_digest(patches.digest);
+    assert!(allowed.matches(&patched));
+    assert!(!allowed.matches(&identity(&m8l())));
+}
@@ -0,0 +1,94 @@
+#![cfg(feature = "ubx_proto23")]
+
+use ublox::{
+    cfg_nav5::{CfgNav5Builder, NavDynamicModel},
+    proto23::Proto23,
+    ubx_frame, MemWriterError, ParserBuilder, ParserError, UbxPacketOwned, UbxProtocol,
+};
+
+/// Frames of a known fixed size packet, a known variable size packet, a packet unknown to
+/// protocol 23 and a known packet with an empty payload
+fn recording() -> Vec<Vec<u8>> {
+    let mut mon_ver = [0; 70];
+    mon_ver[..8].copy_from_slice(b"ROM CORE");
+    mon_ver[40..52].copy_from_slice(b"PROTVER=23.0");
+    vec![
+        CfgNav5Builder::default()
+            .set_dyn_model(NavDynamicModel::Automotive)
+            .into_packet_bytes()
+            .to_vec(),
+        ubx_frame!(0x0a, 0x04, &mon_ver),
+        ubx_frame!(0x7f, 0x01, &[1, 2, 3]),
+        ubx_frame!(0x05, 0x01, &[0x06, 0x24]),
+    ]
+}
+
+#[test]
+fn received_packets_rebuild_their_frames() {
+    let frames = recording();
+    let data = frames.concat();
+    let mut parser = ParserBuilder::new().with_fixed_buffer::<1024>();
+    let mut it = parser.consume_ubx(&data);
+    let mut buf = [0; 128];
+    for original in &frames {
+        let packet = it.next().unwrap().unwrap();
+        assert_eq!(&packet.to_frame_vec(), original);
+        assert_eq!(packet.extend_to_slice(&mut buf).unwrap(), original.len());
+        assert_eq!(&buf[..original.len()], original);
+
+        let owned: UbxPacketOwned = packet.to_owned();
+        assert_eq!(&owned.to_frame_vec(), original);
+
+        let raw = packet.as_raw();
+        let packet = Proto23::match_packet(raw.class, raw.msg_id, raw.payload).unwrap();
+        assert_eq!(packet.frame_len(), original.len());
+        assert_eq!(&packet.to_frame_vec(), original);
+    }
+    assert!(it.next().is_none());
+}
+
+#[test]
+fn extend_to_slice_checks_the_buffer_length() {
+    let original = ubx_frame!(0x05, 0x01, &[0x06, 0x24]);
+    let mut parser = ParserBuilder::new().with_fixed_buffer::<1024>();
+    let mut it = parser.consume_ubx(&original);
+    let packet = it.next().unwrap().unwrap();
+
+    let mut buf = [0xff; 10];
+    assert!(matches!(
+        packet.extend_to_slice(&mut buf[..9]),
+        Err(MemWriterError::NotEnoughMem)
+    ));
+    assert_eq!(buf, [0xff; 10]);
+    assert_eq!(packet.extend_to_slice(&mut buf).unwrap(), 10);
+    assert_eq!(buf[..], original);
+}
+
+#[test]
+fn checked_packets_verify_the_rebuilt_frame() {
+    let original = ubx_frame!(0x05, 0x01, &[0x06, 0x24]);
+    let mut corrupted = original.clone();
+    corrupted[9] ^= 0x01;
+    let data = [corrupted.as_slice(), &original].concat();
+    let mut parser = ParserBuilder::new()
+        .accept_invalid_checksums(true)
+        .with_fixed_buffer::<1024>();
+
+    let mut it = parser.consume_ubx(&data);
+    let checked = it.next_checked().unwrap().unwrap();
+    assert!(matches!(
+        checked.to_frame_vec(),
+        Err(ParserError::InvalidChecksum { .. })
+    ));
+    assert_eq!(checked.packet.to_frame_vec(), original);
+    let checked = it.next_checked().unwrap().unwrap();
+    assert_eq!(checked.to_frame_vec().unwrap(), original);
+    drop(it);
+
+    let mut it = parser.consume_ubx(&corrupted);
+    let frame = it.next_frame().unwrap().unwrap();
+    assert!(matches!(
+        frame.to_frame_vec(),
+        Err(ParserError::InvalidChecksum { .. })
+    ));
+}
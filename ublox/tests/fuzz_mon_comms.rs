@@ -13,10 +13,7 @@
 
 use byteorder::{LittleEndian, WriteBytesExt};
 use proptest::prelude::*;
-use ublox::{ParserBuilder, UbxPacket};
-
-mod common;
-use common::build_ubx_frame;
+use ublox::{ubx_frame, ParserBuilder, UbxPacket};
 
 /// Represents a single 40-byte port block within a MON-COMMS message.
 #[derive(Debug, Clone)]
@@ -185,7 +182,7 @@ fn mon_comms_payload_strategy() -> impl Strategy<Value = MonCommsPayload> {
 pub fn ubx_mon_comms_frame_strategy() -> impl Strategy<Value = (MonCommsPayload, Vec<u8>)> {
     mon_comms_payload_strategy().prop_map(|payload_struct| {
         let payload = payload_struct.to_bytes();
-        let final_frame = build_ubx_frame(0x0A, 0x36, &payload);
+        let final_frame = ubx_frame!(0x0A, 0x36, payload);
 
         (payload_struct, final_frame)
     })
@@ -14,10 +14,7 @@
 
 use byteorder::{LittleEndian, WriteBytesExt};
 use proptest::prelude::*;
-use ublox::{ParserBuilder, UbxPacket};
-
-mod common;
-use common::build_ubx_frame;
+use ublox::{mon_hw2::ConfigSource, ubx_frame, ParserBuilder, UbxPacket};
 
 /// Represents the payload of a UBX-MON-HW2 message.
 ///
@@ -61,22 +58,16 @@ impl MonHw2 {
 /// A proptest strategy for generating a `MonHw2` payload struct.
 pub fn mon_hw2_payload_strategy() -> impl Strategy<Value = MonHw2> {
     (
-        any::<i8>(), // ofs_i
-        any::<u8>(), // mag_i
-        any::<i8>(), // ofs_q
-        any::<u8>(), // mag_q
-        prop_oneof![
-            // cfg_source must be one of the specified values
-            Just(114u8), // ROM
-            Just(111u8), // OTP
-            Just(112u8), // config pins
-            Just(102u8), // flash image
-        ],
-        Just([0u8; 3]), // reserved0
-        any::<u32>(),   // low_lev_cfg
-        Just([0u8; 8]), // reserved1
-        any::<u32>(),   // post_status
-        Just([0u8; 4]), // reserved2
+        any::<i8>(),                                          // ofs_i
+        any::<u8>(),                                          // mag_i
+        any::<i8>(),                                          // ofs_q
+        any::<u8>(),                                          // mag_q
+        proptest::sample::select(ConfigSource::KNOWN_VALUES), // cfg_source
+        Just([0u8; 3]),                                       // reserved0
+        any::<u32>(),                                         // low_lev_cfg
+        Just([0u8; 8]),                                       // reserved1
+        any::<u32>(),                                         // post_status
+        Just([0u8; 4]),                                       // reserved2
     )
         .prop_map(
             |(
@@ -115,7 +106,7 @@ pub fn mon_hw2_payload_strategy() -> impl Strategy<Value = MonHw2> {
 pub fn ubx_mon_hw2_frame_strategy() -> impl Strategy<Value = (MonHw2, Vec<u8>)> {
     mon_hw2_payload_strategy().prop_map(|mon_hw2| {
         let payload = mon_hw2.to_bytes();
-        let final_frame = build_ubx_frame(0x0A, 0x0B, &payload);
+        let final_frame = ubx_frame!(0x0A, 0x0B, payload);
 
         (mon_hw2, final_frame)
     })
@@ -13,10 +13,7 @@
 
 use byteorder::{LittleEndian, WriteBytesExt};
 use proptest::prelude::*;
-use ublox::{ParserBuilder, UbxPacket};
-
-mod common;
-use common::build_ubx_frame;
+use ublox::{ubx_frame, ParserBuilder, UbxPacket};
 
 /// Represents a single pin in the UBX-MON-HW3 message.
 #[derive(Debug, Clone)]
@@ -115,7 +112,7 @@ pub fn mon_hw3_payload_strategy() -> impl Strategy<Value = MonHw3> {
 pub fn ubx_mon_hw3_frame_strategy() -> impl Strategy<Value = (MonHw3, Vec<u8>)> {
     mon_hw3_payload_strategy().prop_map(|mon_hw3| {
         let payload = mon_hw3.to_bytes();
-        let final_frame = build_ubx_frame(0x0A, 0x37, &payload);
+        let final_frame = ubx_frame!(0x0A, 0x37, payload);
 
         (mon_hw3, final_frame)
     })
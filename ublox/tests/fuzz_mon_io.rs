@@ -7,8 +7,7 @@
 
 use byteorder::{LittleEndian, WriteBytesExt};
 use proptest::prelude::*;
-mod common;
-use common::build_ubx_frame;
+use ublox::ubx_frame;
 
 /// Represents a single I/O port block in a MON-IO message (20 bytes).
 #[derive(Debug, Clone)]
@@ -89,7 +88,7 @@ pub fn ubx_mon_io_frame_strategy() -> impl Strategy<Value = (Vec<MonIoPort>, Vec
             payload.extend_from_slice(&port.to_bytes());
         }
 
-        let final_frame = build_ubx_frame(0x0a, 0x02, &payload);
+        let final_frame = ubx_frame!(0x0a, 0x02, payload);
 
         (ports, final_frame)
     })
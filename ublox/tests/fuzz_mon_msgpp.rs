@@ -7,10 +7,7 @@
 
 use byteorder::{LittleEndian, WriteBytesExt};
 use proptest::prelude::*;
-use ublox::{ParserBuilder, UbxPacket};
-
-mod common;
-use common::build_ubx_frame;
+use ublox::{ubx_frame, ParserBuilder, UbxPacket};
 
 /// Number of I/O ports
 const NUM_PORTS: usize = 6;
@@ -56,7 +53,7 @@ fn mon_msgpp_payload_strategy() -> impl Strategy<Value = MonMsgppPayload> {
 pub fn ubx_mon_msgpp_frame_strategy() -> impl Strategy<Value = (MonMsgppPayload, Vec<u8>)> {
     mon_msgpp_payload_strategy().prop_map(|payload_data| {
         let payload = payload_data.to_bytes();
-        let final_frame = build_ubx_frame(0x0a, 0x06, &payload);
+        let final_frame = ubx_frame!(0x0a, 0x06, payload);
 
         (payload_data, final_frame)
     })
@@ -14,10 +14,10 @@
 
 use byteorder::{LittleEndian, WriteBytesExt};
 use proptest::prelude::*;
-use ublox::{ParserBuilder, UbxPacket};
-
-mod common;
-use common::build_ubx_frame;
+use ublox::{
+    mon_rf::{AntennaPowerStatus, AntennaStatus},
+    ubx_frame, ParserBuilder, UbxPacket,
+};
 
 /// Represents a single RF block within a MON-RF message payload.
 ///
@@ -92,8 +92,8 @@ pub fn rf_block_strategy() -> impl Strategy<Value = RfBlock> {
         (
             any::<u8>(),                                                // block_id
             (0..=3u8), // flags (only jammingState bits 0-1 are used)
-            prop_oneof![Just(0u8), Just(1), Just(2), Just(3), Just(4)], // ant_status
-            prop_oneof![Just(0u8), Just(1), Just(2)], // ant_power
+            proptest::sample::select(AntennaStatus::KNOWN_VALUES), // ant_status
+            proptest::sample::select(AntennaPowerStatus::KNOWN_VALUES), // ant_power
             any::<u32>(), // post_status
             Just([0u8; 4]), // reserved1
             any::<u16>(), // noise_per_ms
@@ -154,7 +154,7 @@ pub fn ubx_mon_rf_frame_strategy() -> impl Strategy<Value = (MonRf, Vec<u8>)> {
     mon_rf_payload_strategy().prop_map(|mon_rf| {
         let payload = mon_rf.to_bytes();
 
-        let final_frame = build_ubx_frame(0x0A, 0x38, &payload);
+        let final_frame = ubx_frame!(0x0A, 0x38, payload);
 
         (mon_rf, final_frame)
     })
@@ -178,6 +178,7 @@ proptest! {
         prop_assert_eq!(p.version(), expected_mon_rf.version);
         prop_assert_eq!(p.n_blocks(), expected_mon_rf.blocks.len() as u8);
         prop_assert_eq!(p.blocks().count(), expected_mon_rf.blocks.len());
+        prop_assert_eq!(p.blocks().len(), expected_mon_rf.blocks.len());
 
         let mut parsed_blocks = p.blocks();
         for expected_block in &expected_mon_rf.blocks {
@@ -195,6 +196,11 @@ proptest! {
             prop_assert_eq!(parsed_block.mag_i, expected_block.mag_i);
             prop_assert_eq!(parsed_block.ofs_q, expected_block.ofs_q);
             prop_assert_eq!(parsed_block.mag_q, expected_block.mag_q);
+
+            prop_assert_eq!(parsed_block.band() as u8, expected_block.block_id);
+            prop_assert_eq!(parsed_block.jamming_state() as u8, expected_block.flags & 0x03);
+            prop_assert!((0.0..=100.0).contains(&parsed_block.agc_percent()));
+            prop_assert!((0.0..=100.0).contains(&parsed_block.cw_jamming_percent()));
         }
     }
 }
@@ -217,6 +223,7 @@ proptest! {
         prop_assert_eq!(p.version(), expected_mon_rf.version);
         prop_assert_eq!(p.n_blocks(), expected_mon_rf.blocks.len() as u8);
         prop_assert_eq!(p.blocks().count(), expected_mon_rf.blocks.len());
+        prop_assert_eq!(p.blocks().len(), expected_mon_rf.blocks.len());
 
         let mut parsed_blocks = p.blocks();
         for expected_block in &expected_mon_rf.blocks {
@@ -234,6 +241,11 @@ proptest! {
             prop_assert_eq!(parsed_block.mag_i, expected_block.mag_i);
             prop_assert_eq!(parsed_block.ofs_q, expected_block.ofs_q);
             prop_assert_eq!(parsed_block.mag_q, expected_block.mag_q);
+
+            prop_assert_eq!(parsed_block.band() as u8, expected_block.block_id);
+            prop_assert_eq!(parsed_block.jamming_state() as u8, expected_block.flags & 0x03);
+            prop_assert!((0.0..=100.0).contains(&parsed_block.agc_percent()));
+            prop_assert!((0.0..=100.0).contains(&parsed_block.cw_jamming_percent()));
         }
     }
 }
@@ -256,6 +268,7 @@ proptest! {
         prop_assert_eq!(p.version(), expected_mon_rf.version);
         prop_assert_eq!(p.n_blocks(), expected_mon_rf.blocks.len() as u8);
         prop_assert_eq!(p.blocks().count(), expected_mon_rf.blocks.len());
+        prop_assert_eq!(p.blocks().len(), expected_mon_rf.blocks.len());
 
         let mut parsed_blocks = p.blocks();
         for expected_block in &expected_mon_rf.blocks {
@@ -273,6 +286,11 @@ proptest! {
             prop_assert_eq!(parsed_block.mag_i, expected_block.mag_i);
             prop_assert_eq!(parsed_block.ofs_q, expected_block.ofs_q);
             prop_assert_eq!(parsed_block.mag_q, expected_block.mag_q);
+
+            prop_assert_eq!(parsed_block.band() as u8, expected_block.block_id);
+            prop_assert_eq!(parsed_block.jamming_state() as u8, expected_block.flags & 0x03);
+            prop_assert!((0.0..=100.0).contains(&parsed_block.agc_percent()));
+            prop_assert!((0.0..=100.0).contains(&parsed_block.cw_jamming_percent()));
         }
     }
 }
@@ -2,10 +2,7 @@
 
 use byteorder::{LittleEndian, WriteBytesExt};
 use proptest::prelude::*;
-use ublox::{ParserBuilder, UbxPacket};
-
-mod common;
-use common::build_ubx_frame;
+use ublox::{ubx_frame, ParserBuilder, UbxPacket};
 
 const NUM_TARGETS: usize = 6;
 
@@ -45,7 +42,7 @@ pub fn ubx_mon_rxbuf_frame_strategy() -> impl Strategy<Value = (MonRxbufPayload,
     mon_rxbuf_payload_strategy().prop_map(|payload_data| {
         let payload = payload_data.to_bytes();
 
-        let final_frame = build_ubx_frame(0x0a, 0x07, &payload);
+        let final_frame = ubx_frame!(0x0a, 0x07, payload);
 
         (payload_data, final_frame)
     })
@@ -4,10 +4,7 @@
 //! UBX frames containing a MON-RXR message.
 
 use proptest::prelude::*;
-use ublox::{ParserBuilder, UbxPacket};
-
-mod common;
-use common::build_ubx_frame;
+use ublox::{ubx_frame, ParserBuilder, UbxPacket};
 
 /// Represents the MON-RXR payload (1 byte).
 #[derive(Debug, Clone)]
@@ -27,7 +24,7 @@ pub fn ubx_mon_rxr_frame_strategy() -> impl Strategy<Value = (MonRxrPayload, Vec
     any::<u8>().prop_map(|flags| {
         let payload_data = MonRxrPayload { flags };
 
-        let final_frame = build_ubx_frame(0x0a, 0x21, &[flags]);
+        let final_frame = ubx_frame!(0x0a, 0x21, [flags]);
 
         (payload_data, final_frame)
     })
@@ -2,10 +2,7 @@
 
 use byteorder::{LittleEndian, WriteBytesExt};
 use proptest::prelude::*;
-use ublox::{ParserBuilder, UbxPacket};
-
-mod common;
-use common::build_ubx_frame;
+use ublox::{ubx_frame, ParserBuilder, UbxPacket};
 
 const SPECTRUM_SIZE: usize = 256;
 
@@ -101,7 +98,7 @@ pub fn ubx_mon_span_frame_strategy() -> impl Strategy<Value = (MonSpanPayload, V
     mon_span_payload_strategy().prop_map(|payload_data| {
         let payload = payload_data.to_bytes();
 
-        let final_frame = build_ubx_frame(0x0a, 0x31, &payload);
+        let final_frame = ubx_frame!(0x0a, 0x31, payload);
 
         (payload_data, final_frame)
     })
@@ -130,11 +127,11 @@ proptest! {
         prop_assert_eq!(parsed_blocks.len(), expected.rf_blocks.len());
 
         for (parsed, expected_block) in parsed_blocks.iter().zip(expected.rf_blocks.iter()) {
-            prop_assert_eq!(parsed.spectrum_raw(), &expected_block.spectrum);
-            prop_assert_eq!(parsed.span, expected_block.span);
-            prop_assert_eq!(parsed.res, expected_block.res);
-            prop_assert_eq!(parsed.center, expected_block.center);
-            prop_assert_eq!(parsed.pga, expected_block.pga);
+            prop_assert_eq!(parsed.spectrum(), &expected_block.spectrum[..]);
+            prop_assert_eq!(parsed.span(), expected_block.span);
+            prop_assert_eq!(parsed.res(), expected_block.res);
+            prop_assert_eq!(parsed.center(), expected_block.center);
+            prop_assert_eq!(parsed.pga(), expected_block.pga);
         }
     }
 }
@@ -162,11 +159,11 @@ proptest! {
         prop_assert_eq!(parsed_blocks.len(), expected.rf_blocks.len());
 
         for (parsed, expected_block) in parsed_blocks.iter().zip(expected.rf_blocks.iter()) {
-            prop_assert_eq!(parsed.spectrum_raw(), &expected_block.spectrum);
-            prop_assert_eq!(parsed.span, expected_block.span);
-            prop_assert_eq!(parsed.res, expected_block.res);
-            prop_assert_eq!(parsed.center, expected_block.center);
-            prop_assert_eq!(parsed.pga, expected_block.pga);
+            prop_assert_eq!(parsed.spectrum(), &expected_block.spectrum[..]);
+            prop_assert_eq!(parsed.span(), expected_block.span);
+            prop_assert_eq!(parsed.res(), expected_block.res);
+            prop_assert_eq!(parsed.center(), expected_block.center);
+            prop_assert_eq!(parsed.pga(), expected_block.pga);
         }
     }
 }
@@ -2,10 +2,7 @@
 
 use byteorder::{LittleEndian, WriteBytesExt};
 use proptest::prelude::*;
-use ublox::{ParserBuilder, UbxPacket};
-
-mod common;
-use common::build_ubx_frame;
+use ublox::{ubx_frame, ParserBuilder, UbxPacket};
 
 const NUM_TARGETS: usize = 6;
 
@@ -65,7 +62,7 @@ pub fn ubx_mon_txbuf_frame_strategy() -> impl Strategy<Value = (MonTxbufPayload,
     mon_txbuf_payload_strategy().prop_map(|payload_data| {
         let payload = payload_data.to_bytes();
 
-        let final_frame = build_ubx_frame(0x0a, 0x08, &payload);
+        let final_frame = ubx_frame!(0x0a, 0x08, payload);
 
         (payload_data, final_frame)
     })
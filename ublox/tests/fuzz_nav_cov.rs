@@ -13,10 +13,10 @@
 
 use byteorder::{LittleEndian, WriteBytesExt};
 use proptest::prelude::*;
-use ublox::{ParserBuilder, UbxPacket};
+use ublox::{ubx_frame, ParserBuilder, UbxPacket};
 
 mod common;
-use common::{build_ubx_frame, finite_f32};
+use common::finite_f32;
 
 /// Represents the payload of a UBX-NAV-COV message.
 ///
@@ -138,7 +138,7 @@ pub fn ubx_nav_cov_frame_strategy() -> impl Strategy<Value = (NavCovPayload, Vec
     nav_cov_payload_strategy().prop_map(|payload_struct| {
         let payload = payload_struct.to_bytes();
 
-        let final_frame = build_ubx_frame(0x01, 0x36, &payload);
+        let final_frame = ubx_frame!(0x01, 0x36, payload);
 
         (payload_struct, final_frame)
     })
@@ -7,10 +7,7 @@
 
 use byteorder::{LittleEndian, WriteBytesExt};
 use proptest::prelude::*;
-use ublox::{ParserBuilder, UbxPacket};
-
-mod common;
-use common::build_ubx_frame;
+use ublox::{ubx_frame, ParserBuilder, UbxPacket};
 
 #[allow(dead_code, reason = "dead variants for SOME feature flag combination")]
 #[derive(Debug, Clone, Copy)]
@@ -148,7 +145,7 @@ pub fn ubx_nav_hpposllh_frame_strategy(
     nav_hpposllh_payload_strategy(version).prop_map(move |nav_hpposllh| {
         let payload = nav_hpposllh.to_bytes(version);
 
-        let final_frame = build_ubx_frame(0x01, 0x14, &payload);
+        let final_frame = ubx_frame!(0x01, 0x14, payload);
 
         (nav_hpposllh, final_frame)
     })
@@ -174,12 +171,12 @@ proptest! {
         prop_assert_eq!(p.version(), expected_hpposllh.version);
         prop_assert_eq!(p.itow(), expected_hpposllh.itow);
         prop_assert_eq!(p.flags_raw(), expected_hpposllh.flags);
-        prop_assert_eq!(p.lon_degrees_raw(), expected_hpposllh.lon);
-        prop_assert_eq!(p.lat_degrees_raw(), expected_hpposllh.lat);
+        prop_assert_eq!(p.lon_raw(), expected_hpposllh.lon);
+        prop_assert_eq!(p.lat_raw(), expected_hpposllh.lat);
         prop_assert_eq!(p.height_meters_raw(), expected_hpposllh.height);
         prop_assert_eq!(p.height_msl_raw(), expected_hpposllh.h_msl);
-        prop_assert_eq!(p.lon_hp_degrees_raw(), expected_hpposllh.lon_hp);
-        prop_assert_eq!(p.lat_hp_degrees_raw(), expected_hpposllh.lat_hp);
+        prop_assert_eq!(p.lon_hp_raw(), expected_hpposllh.lon_hp);
+        prop_assert_eq!(p.lat_hp_raw(), expected_hpposllh.lat_hp);
         prop_assert_eq!(p.height_hp_meters_raw(), expected_hpposllh.height_hp);
         prop_assert_eq!(p.height_hp_msl_raw(), expected_hpposllh.h_msl_hp);
         prop_assert_eq!(p.horizontal_accuracy_raw(), expected_hpposllh.h_acc);
@@ -206,12 +203,12 @@ proptest! {
         prop_assert_eq!(p.version(), expected_hpposllh.version);
         prop_assert_eq!(p.itow(), expected_hpposllh.itow);
         prop_assert_eq!(p.flags_raw(), expected_hpposllh.flags);
-        prop_assert_eq!(p.lon_degrees_raw(), expected_hpposllh.lon);
-        prop_assert_eq!(p.lat_degrees_raw(), expected_hpposllh.lat);
+        prop_assert_eq!(p.lon_raw(), expected_hpposllh.lon);
+        prop_assert_eq!(p.lat_raw(), expected_hpposllh.lat);
         prop_assert_eq!(p.height_meters_raw(), expected_hpposllh.height);
         prop_assert_eq!(p.height_msl_raw(), expected_hpposllh.h_msl);
-        prop_assert_eq!(p.lon_hp_degrees_raw(), expected_hpposllh.lon_hp);
-        prop_assert_eq!(p.lat_hp_degrees_raw(), expected_hpposllh.lat_hp);
+        prop_assert_eq!(p.lon_hp_raw(), expected_hpposllh.lon_hp);
+        prop_assert_eq!(p.lat_hp_raw(), expected_hpposllh.lat_hp);
         prop_assert_eq!(p.height_hp_meters_raw(), expected_hpposllh.height_hp);
         prop_assert_eq!(p.height_hp_msl_raw(), expected_hpposllh.h_msl_hp);
         prop_assert_eq!(p.horizontal_accuracy_raw(), expected_hpposllh.h_acc);
@@ -238,12 +235,12 @@ proptest! {
         prop_assert_eq!(p.version(), expected_hpposllh.version);
         prop_assert_eq!(p.itow(), expected_hpposllh.itow);
         prop_assert_eq!(p.flags_raw(), expected_hpposllh.flags);
-        prop_assert_eq!(p.lon_degrees_raw(), expected_hpposllh.lon);
-        prop_assert_eq!(p.lat_degrees_raw(), expected_hpposllh.lat);
+        prop_assert_eq!(p.lon_raw(), expected_hpposllh.lon);
+        prop_assert_eq!(p.lat_raw(), expected_hpposllh.lat);
         prop_assert_eq!(p.height_meters_raw(), expected_hpposllh.height);
         prop_assert_eq!(p.height_msl_raw(), expected_hpposllh.h_msl);
-        prop_assert_eq!(p.lon_hp_degrees_raw(), expected_hpposllh.lon_hp);
-        prop_assert_eq!(p.lat_hp_degrees_raw(), expected_hpposllh.lat_hp);
+        prop_assert_eq!(p.lon_hp_raw(), expected_hpposllh.lon_hp);
+        prop_assert_eq!(p.lat_hp_raw(), expected_hpposllh.lat_hp);
         prop_assert_eq!(p.height_hp_meters_raw(), expected_hpposllh.height_hp);
         prop_assert_eq!(p.height_hp_msl_raw(), expected_hpposllh.h_msl_hp);
         prop_assert_eq!(p.horizontal_accuracy_raw(), expected_hpposllh.h_acc);
@@ -270,12 +267,12 @@ proptest! {
         prop_assert_eq!(p.version(), expected_hpposllh.version);
         prop_assert_eq!(p.itow(), expected_hpposllh.itow);
         prop_assert_eq!(p.flags_raw(), expected_hpposllh.flags);
-        prop_assert_eq!(p.lon_degrees_raw(), expected_hpposllh.lon);
-        prop_assert_eq!(p.lat_degrees_raw(), expected_hpposllh.lat);
+        prop_assert_eq!(p.lon_raw(), expected_hpposllh.lon);
+        prop_assert_eq!(p.lat_raw(), expected_hpposllh.lat);
         prop_assert_eq!(p.height_meters_raw(), expected_hpposllh.height);
         prop_assert_eq!(p.height_msl_raw(), expected_hpposllh.h_msl);
-        prop_assert_eq!(p.lon_hp_degrees_raw(), expected_hpposllh.lon_hp);
-        prop_assert_eq!(p.lat_hp_degrees_raw(), expected_hpposllh.lat_hp);
+        prop_assert_eq!(p.lon_hp_raw(), expected_hpposllh.lon_hp);
+        prop_assert_eq!(p.lat_hp_raw(), expected_hpposllh.lat_hp);
         prop_assert_eq!(p.height_hp_meters_raw(), expected_hpposllh.height_hp);
         prop_assert_eq!(p.height_hp_msl_raw(), expected_hpposllh.h_msl_hp);
         prop_assert_eq!(p.horizontal_accuracy_raw(), expected_hpposllh.h_acc);
@@ -302,12 +299,12 @@ proptest! {
         prop_assert_eq!(p.version(), expected_hpposllh.version);
         prop_assert_eq!(p.itow(), expected_hpposllh.itow);
         prop_assert_eq!(p.flags_raw(), expected_hpposllh.flags);
-        prop_assert_eq!(p.lon_degrees_raw(), expected_hpposllh.lon);
-        prop_assert_eq!(p.lat_degrees_raw(), expected_hpposllh.lat);
+        prop_assert_eq!(p.lon_raw(), expected_hpposllh.lon);
+        prop_assert_eq!(p.lat_raw(), expected_hpposllh.lat);
         prop_assert_eq!(p.height_meters_raw(), expected_hpposllh.height);
         prop_assert_eq!(p.height_msl_raw(), expected_hpposllh.h_msl);
-        prop_assert_eq!(p.lon_hp_degrees_raw(), expected_hpposllh.lon_hp);
-        prop_assert_eq!(p.lat_hp_degrees_raw(), expected_hpposllh.lat_hp);
+        prop_assert_eq!(p.lon_hp_raw(), expected_hpposllh.lon_hp);
+        prop_assert_eq!(p.lat_hp_raw(), expected_hpposllh.lat_hp);
         prop_assert_eq!(p.height_hp_meters_raw(), expected_hpposllh.height_hp);
         prop_assert_eq!(p.height_hp_msl_raw(), expected_hpposllh.h_msl_hp);
         prop_assert_eq!(p.horizontal_accuracy_raw(), expected_hpposllh.h_acc);
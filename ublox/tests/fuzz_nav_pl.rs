@@ -13,10 +13,7 @@
 
 use byteorder::{LittleEndian, WriteBytesExt};
 use proptest::prelude::*;
-use ublox::{ParserBuilder, UbxPacket};
-
-mod common;
-use common::build_ubx_frame;
+use ublox::{ubx_frame, ParserBuilder, UbxPacket};
 
 /// Represents the payload of a UBX-NAV-PL message.
 ///
@@ -186,7 +183,7 @@ pub fn ubx_nav_pl_frame_strategy() -> impl Strategy<Value = (NavPlPayload, Vec<u
     nav_pl_payload_strategy().prop_map(|payload_struct| {
         let payload = payload_struct.to_bytes();
 
-        let final_frame = build_ubx_frame(0x01, 0x62, &payload);
+        let final_frame = ubx_frame!(0x01, 0x62, payload);
 
         (payload_struct, final_frame)
     })
@@ -7,10 +7,7 @@
 
 use byteorder::{LittleEndian, WriteBytesExt};
 use proptest::prelude::*;
-use ublox::{ParserBuilder, UbxPacket};
-
-mod common;
-use common::build_ubx_frame;
+use ublox::{ubx_frame, ParserBuilder, UbxPacket};
 
 #[allow(dead_code, reason = "dead variants for SOME feature flag combination")]
 #[derive(Debug, Clone, Copy)]
@@ -114,9 +111,17 @@ impl NavPvt {
                 wtr.write_i16::<LittleEndian>(self.mag_dec).unwrap();
                 wtr.write_u16::<LittleEndian>(self.mag_acc).unwrap();
             },
-            ProtocolVersion::V27 | ProtocolVersion::V31 | ProtocolVersion::V33 => {
-                // TODO: Proto 27/33 have 2-byte flags3
-                // Proto 27/31/33 have 1-byte flags3, then 5 bytes reserved1 (total 92 bytes)
+            ProtocolVersion::V31 | ProtocolVersion::V33 => {
+                // Proto 31/33 have 2-byte flags3, then 4 bytes reserved1 (total 92 bytes)
+                wtr.write_u16::<LittleEndian>(self.flags3).unwrap();
+                wtr.extend_from_slice(&self.reserved1[..4]);
+                wtr.write_i32::<LittleEndian>(self.head_veh).unwrap();
+                wtr.write_i16::<LittleEndian>(self.mag_dec).unwrap();
+                wtr.write_u16::<LittleEndian>(self.mag_acc).unwrap();
+            },
+            ProtocolVersion::V27 => {
+                // TODO: Proto 27 has 2-byte flags3
+                // Proto 27 has 1-byte flags3, then 5 bytes reserved1 (total 92 bytes)
                 wtr.write_u8(self.flags3 as u8).unwrap();
                 wtr.extend_from_slice(&self.reserved1); // All 5 bytes of reserved1
                 wtr.write_i32::<LittleEndian>(self.head_veh).unwrap();
@@ -270,7 +275,7 @@ pub fn ubx_nav_pvt_frame_strategy(
 ) -> impl Strategy<Value = (NavPvt, Vec<u8>)> {
     nav_pvt_payload_strategy(version).prop_map(move |nav_pvt| {
         let payload = nav_pvt.to_bytes(version);
-        let final_frame = build_ubx_frame(0x01, 0x07, &payload);
+        let final_frame = ubx_frame!(0x01, 0x07, payload);
 
         (nav_pvt, final_frame)
     })
@@ -424,7 +429,7 @@ proptest! {
         prop_assert_eq!(p.fix_type_raw(), expected_pvt.fix_type);
         prop_assert_eq!(p.flags_raw(), expected_pvt.flags);
         prop_assert_eq!(p.flags2_raw(), expected_pvt.flags2);
-        prop_assert_eq!(p.flags3_raw(), expected_pvt.flags3 as u8);
+        prop_assert_eq!(p.flags3_raw(), expected_pvt.flags3);
     }
 }
 
@@ -462,6 +467,6 @@ proptest! {
         prop_assert_eq!(p.fix_type_raw(), expected_pvt.fix_type);
         prop_assert_eq!(p.flags_raw(), expected_pvt.flags);
         prop_assert_eq!(p.flags2_raw(), expected_pvt.flags2);
-        prop_assert_eq!(p.flags3_raw(), expected_pvt.flags3 as u8);
+        prop_assert_eq!(p.flags3_raw(), expected_pvt.flags3);
     }
 }
@@ -0,0 +1,104 @@
+//! Parser tests with payloads from `fuzz_payload_realistic_strategy()`, which zeroes the
+//! reserved fields and pins the version fields like real receivers do.
+
+#![cfg(feature = "ubx_proto23")]
+
+use proptest::prelude::*;
+use ublox::{
+    nav_dop::NavDop,
+    nav_pvt::proto23::NavPvt,
+    nav_sat::NavSat,
+    nav_status::NavStatus,
+    proto23::{PacketRef, Proto23},
+    ubx_frame, ParserBuilder, PayloadFieldKind, UbxPacket, UbxPacketMeta,
+};
+
+mod common;
+use common::{field_range, fuzz_payload_realistic_strategy};
+
+/// Parse a single frame of `payload` as packet `P`
+fn parse<P: UbxPacketMeta>(payload: &[u8], check: impl FnOnce(&PacketRef<'_>)) {
+    let frame = ubx_frame!(P::CLASS, P::ID, payload);
+    let mut parser = ParserBuilder::new()
+        .with_protocol::<Proto23>()
+        .with_fixed_buffer::<2048>();
+    let mut it = parser.consume_ubx(&frame);
+    match it.next() {
+        Some(Ok(UbxPacket::Proto23(packet))) => check(&packet),
+        other => panic!("expected a packet, got {other:?}"),
+    }
+    assert!(it.next().is_none());
+}
+
+#[test]
+fn test_payload_fields_layout() {
+    let names: Vec<_> = NavStatus::PAYLOAD_FIELDS
+        .iter()
+        .map(|field| (field.name, field.offset, field.size))
+        .collect();
+    assert_eq!(
+        names,
+        [
+            ("itow", 0, 4),
+            ("fix_type", 4, 1),
+            ("flags", 5, 1),
+            ("fix_stat", 6, 1),
+            ("flags2", 7, 1),
+            ("time_to_first_fix", 8, 4),
+            ("uptime_ms", 12, 4),
+        ]
+    );
+    // Fields up to the repeated blocks
+    let last = NavSat::PAYLOAD_FIELDS.last().unwrap();
+    assert_eq!(
+        (last.name, last.kind),
+        ("reserved", PayloadFieldKind::Reserved)
+    );
+    assert_eq!(NavSat::PAYLOAD_FIELDS_LEN, 8);
+    assert_eq!(NavPvt::PAYLOAD_FIELDS_LEN, 92);
+}
+
+#[test]
+fn test_payload_offset_consts() {
+    assert_eq!(NavStatus::OFFSET_ITOW, 0);
+    assert_eq!(NavStatus::OFFSET_UPTIME_MS, 12);
+    assert_eq!(NavPvt::OFFSET_FIX_TYPE, 20);
+    assert_eq!(NavPvt::OFFSET_LAT, 28);
+    let lat = NavPvt::PAYLOAD_FIELDS
+        .iter()
+        .find(|field| field.name == "lat");
+    assert_eq!(
+        lat.map(|field| usize::from(field.offset)),
+        Some(NavPvt::OFFSET_LAT)
+    );
+}
+
+proptest! {
+    #[test]
+    fn test_realistic_nav_pvt(payload in fuzz_payload_realistic_strategy::<NavPvt>(&[])) {
+        prop_assert_eq!(payload.len(), 92);
+        let reserved = NavPvt::PAYLOAD_FIELDS.iter().filter(|field| field.is_reserved());
+        for field in reserved {
+            prop_assert!(payload[field_range(field)].iter().all(|b| *b == 0));
+        }
+        parse::<NavPvt>(&payload, |packet| assert!(matches!(packet, PacketRef::NavPvt(_))));
+    }
+
+    #[test]
+    fn test_realistic_nav_sat(mut payload in fuzz_payload_realistic_strategy::<NavSat>(&[1])) {
+        prop_assert_eq!(payload.len(), 8);
+        prop_assert_eq!(payload[4], 1);
+        prop_assert_eq!(&payload[6..8], &[0, 0]);
+        // Without satellite blocks
+        payload[5] = 0;
+        parse::<NavSat>(&payload, |packet| {
+            let PacketRef::NavSat(nav_sat) = packet else { panic!() };
+            assert_eq!(nav_sat.version(), 1);
+        });
+    }
+
+    #[test]
+    fn test_realistic_nav_dop(payload in fuzz_payload_realistic_strategy::<NavDop>(&[])) {
+        parse::<NavDop>(&payload, |packet| assert!(matches!(packet, PacketRef::NavDop(_))));
+    }
+}
@@ -13,10 +13,7 @@
 
 use byteorder::{LittleEndian, WriteBytesExt};
 use proptest::prelude::*;
-use ublox::{ParserBuilder, UbxPacket};
-
-mod common;
-use common::build_ubx_frame;
+use ublox::{ubx_frame, ParserBuilder, UbxPacket};
 
 /// Represents the payload of a UBX-RXM-COR message.
 ///
@@ -72,7 +69,7 @@ fn rxm_cor_payload_strategy() -> impl Strategy<Value = RxmCorPayload> {
 pub fn ubx_rxm_cor_frame_strategy() -> impl Strategy<Value = (RxmCorPayload, Vec<u8>)> {
     rxm_cor_payload_strategy().prop_map(|payload_struct| {
         let payload = payload_struct.to_bytes();
-        let final_frame = build_ubx_frame(0x02, 0x34, &payload);
+        let final_frame = ubx_frame!(0x02, 0x34, payload);
 
         (payload_struct, final_frame)
     })
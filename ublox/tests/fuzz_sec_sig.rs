@@ -13,10 +13,7 @@
 
 use byteorder::{LittleEndian, WriteBytesExt};
 use proptest::prelude::*;
-use ublox::{ParserBuilder, UbxPacket};
-
-mod common;
-use common::build_ubx_frame;
+use ublox::{ubx_frame, ParserBuilder, UbxPacket};
 
 /// Represents the payload of a UBX-SEC-SIG message.
 ///
@@ -82,7 +79,7 @@ fn sec_sig_payload_strategy() -> impl Strategy<Value = SecSigPayload> {
 pub fn ubx_sec_sig_frame_strategy() -> impl Strategy<Value = (SecSigPayload, Vec<u8>)> {
     sec_sig_payload_strategy().prop_map(|payload_struct| {
         let payload = payload_struct.to_bytes();
-        let final_frame = build_ubx_frame(0x27, 0x09, &payload);
+        let final_frame = ubx_frame!(0x27, 0x09, payload);
 
         (payload_struct, final_frame)
     })
@@ -13,10 +13,7 @@
 
 use byteorder::{LittleEndian, WriteBytesExt};
 use proptest::prelude::*;
-use ublox::{ParserBuilder, UbxPacket};
-
-mod common;
-use common::build_ubx_frame;
+use ublox::{ubx_frame, ParserBuilder, UbxPacket};
 
 /// Represents a single 8-byte event entry within a SEC-SIGLOG message.
 #[derive(Debug, Clone)]
@@ -94,7 +91,7 @@ fn sec_siglog_payload_strategy() -> impl Strategy<Value = SecSiglogPayload> {
 pub fn ubx_sec_siglog_frame_strategy() -> impl Strategy<Value = (SecSiglogPayload, Vec<u8>)> {
     sec_siglog_payload_strategy().prop_map(|payload_struct| {
         let payload = payload_struct.to_bytes();
-        let final_frame = build_ubx_frame(0x27, 0x10, &payload);
+        let final_frame = ubx_frame!(0x27, 0x10, payload);
 
         (payload_struct, final_frame)
     })
@@ -1,4 +1,20 @@
-use ublox::{cfg_msg::CfgMsgSinglePortBuilder, nav_pos_llh::NavPosLlh, nav_status::NavStatus};
+use ublox::{
+    cfg_cfg::{CfgCfgBuilder, CfgDevices, CfgSections},
+    cfg_hnr::CfgHnrBuilder,
+    cfg_msg::CfgMsgSinglePortBuilder,
+    cfg_nav5::{CfgNav5, CfgNav5Builder, CfgNav5Owned, CfgNav5Params, NavDynamicModel},
+    cfg_navx5::{CfgNavX5Builder, CfgNavX5Params1, CfgNavX5Params2},
+    cfg_prt::CfgPrtUartBuilder,
+    cfg_rst::{CfgRstBuilder, NavBbrMask, NavBbrPredefinedMask, ResetMode},
+    cfg_val::{CfgKey, CfgLayerSet, CfgTransaction, CfgVal, CfgValDelBuilder, CfgValSetBuilder},
+    esf_meas::{EsfMeasBuilder, EsfMeasData},
+    esf_status::EsfSensorType,
+    mga_gps_alm::MgaGpsAlmBuilder,
+    mga_ini::{MgaIniPosLlhBuilder, MgaIniTimeUtcBuilder},
+    nav_pos_llh::NavPosLlh,
+    nav_status::NavStatus,
+    ubx_checksum, PositionLLA, UbxPacketRecv,
+};
 
 #[test]
 fn test_cfg_msg_simple() {
@@ -12,3 +28,327 @@ fn test_cfg_msg_simple() {
         CfgMsgSinglePortBuilder::set_rate_for::<NavStatus>(1).into_packet_bytes()
     );
 }
+
+#[test]
+fn test_cfg_hnr() {
+    assert_eq!(
+        [0xb5, 0x62, 0x06, 0x5C, 0x04, 0x00, 0x0A, 0x00, 0x00, 0x00, 0x70, 0xF4],
+        CfgHnrBuilder {
+            high_nav_rate: 10,
+            ..Default::default()
+        }
+        .into_packet_bytes()
+    );
+}
+
+#[test]
+fn test_cfg_nav5_setters_apply_mask() {
+    let packet = CfgNav5Builder::default()
+        .set_dyn_model(NavDynamicModel::Automotive)
+        .set_pdop(2.5)
+        .into_packet_bytes();
+    assert_eq!(packet[6..8], [0x11, 0x00]);
+    assert_eq!(
+        packet,
+        CfgNav5Builder {
+            mask: CfgNav5Params::DYN | CfgNav5Params::POS_MASK_APPLY,
+            dyn_model: NavDynamicModel::Automotive,
+            pdop: 2.5,
+            ..Default::default()
+        }
+        .into_packet_bytes()
+    );
+}
+
+#[test]
+fn test_cfg_nav5_builder_from_received_packet() {
+    let polled = CfgNav5Builder {
+        mask: CfgNav5Params::all(),
+        dyn_model: NavDynamicModel::Portable,
+        pdop: 25.0,
+        static_hold_thresh: 0.5,
+        ..Default::default()
+    }
+    .into_packet_bytes();
+    let received = CfgNav5::parse_payload(&polled[6..polled.len() - 2]).unwrap();
+
+    let modified = CfgNav5Builder::from(&received)
+        .set_dyn_model(NavDynamicModel::Automotive)
+        .into_packet_bytes();
+    let expected = CfgNav5Builder {
+        mask: CfgNav5Params::all(),
+        dyn_model: NavDynamicModel::Automotive,
+        pdop: 25.0,
+        static_hold_thresh: 0.5,
+        ..Default::default()
+    }
+    .into_packet_bytes();
+    assert_eq!(modified, expected);
+
+    let owned = CfgNav5Owned::from(received);
+    assert_eq!(CfgNav5Builder::from(&owned).into_packet_bytes(), polled);
+}
+
+#[test]
+fn test_cfg_navx5_setters_apply_masks() {
+    let packet = CfgNavX5Builder::default()
+        .set_version(2)
+        .set_min_svs(4)
+        .set_max_svs(20)
+        .set_use_adr(true)
+        .into_packet_bytes();
+    // mask1 and mask2
+    assert_eq!(packet[8..10], [0x04, 0x00]);
+    assert_eq!(packet[10..14], [0x40, 0x00, 0x00, 0x00]);
+    assert_eq!(
+        packet,
+        CfgNavX5Builder {
+            version: 2,
+            mask1: CfgNavX5Params1::MIN_MAX,
+            mask2: CfgNavX5Params2::USE_ADR,
+            min_svs: 4,
+            max_svs: 20,
+            use_adr: true,
+            ..Default::default()
+        }
+        .into_packet_bytes()
+    );
+}
+
+#[test]
+fn test_cfg_prt_uart_defaults() {
+    let packet = CfgPrtUartBuilder::default()
+        .set_baud_rate(115_200)
+        .into_packet_bytes();
+    // UART1, 8N1
+    assert_eq!(packet[6], 1);
+    assert_eq!(packet[10..14], [0xc0, 0x08, 0x00, 0x00]);
+    assert_eq!(packet[14..18], 115_200u32.to_le_bytes());
+    // UBX, NMEA and RTCM3 in, UBX and NMEA out
+    assert_eq!(packet[18..22], [0x23, 0x00, 0x03, 0x00]);
+}
+
+#[test]
+fn test_cfg_val_del() {
+    let mut packet = Vec::new();
+    CfgValDelBuilder {
+        version: 1,
+        layers: CfgLayerSet::BBR | CfgLayerSet::FLASH,
+        transaction: CfgTransaction::Apply,
+        reserved0: 0,
+        cfg_keys: &[CfgKey::Uart1Baudrate, CfgKey::RateMeas],
+    }
+    .extend_to(&mut packet);
+
+    let mut expected = vec![
+        0xb5, 0x62, 0x06, 0x8c, 0x0c, 0x00, // Header
+        0x01, 0x06, 0x03, 0x00, // Version, layers, transaction, reserved
+        0x01, 0x00, 0x52, 0x40, // CFG-UART1-BAUDRATE
+        0x01, 0x00, 0x21, 0x30, // CFG-RATE-MEAS
+    ];
+    let (ck_a, ck_b) = ubx_checksum(&expected[2..]);
+    expected.extend([ck_a, ck_b]);
+    assert_eq!(packet, expected);
+}
+
+#[test]
+fn test_extend_to_slice() {
+    let mut buf = [0u8; 64];
+
+    let cfg_msg = || CfgMsgSinglePortBuilder::set_rate_for::<NavPosLlh>(1);
+    assert_eq!(cfg_msg().frame_len(), 11);
+    assert_eq!(cfg_msg().extend_to_slice(&mut buf).unwrap(), 11);
+    assert_eq!(buf[..11], cfg_msg().into_packet_bytes());
+    assert!(cfg_msg().extend_to_slice(&mut buf[..10]).is_err());
+
+    let cfg_val_set = || CfgValSetBuilder {
+        version: 0,
+        layers: CfgLayerSet::RAM,
+        transaction: CfgTransaction::None,
+        reserved0: 0,
+        cfg_data: &[CfgVal::Uart1Baudrate(115_200), CfgVal::RateMeas(100)],
+    };
+    let mut expected = Vec::new();
+    cfg_val_set().extend_to(&mut expected);
+    assert_eq!(cfg_val_set().frame_len(), expected.len());
+    assert_eq!(
+        cfg_val_set().extend_to_slice(&mut buf).unwrap(),
+        expected.len()
+    );
+    assert_eq!(buf[..expected.len()], expected);
+    assert!(cfg_val_set()
+        .extend_to_slice(&mut buf[..expected.len() - 1])
+        .is_err());
+
+    let data = [EsfMeasData {
+        data_type: EsfSensorType::Speed,
+        data_field: 1000,
+    }];
+    let esf_meas = || {
+        EsfMeasBuilder::default()
+            .with_measurement_data(&data)
+            .with_calib_tag(Some(0x1234_5678))
+    };
+    let mut expected = Vec::new();
+    esf_meas().extend_to(&mut expected);
+    assert_eq!(esf_meas().frame_len(), expected.len());
+    assert_eq!(
+        esf_meas().extend_to_slice(&mut buf).unwrap(),
+        expected.len()
+    );
+    assert_eq!(buf[..expected.len()], expected);
+}
+
+#[test]
+fn test_mga_ini_pos_llh() {
+    let position = PositionLLA {
+        lon: 8.5,
+        lat: 47.25,
+        alt: 500.0,
+    };
+    let packet = MgaIniPosLlhBuilder::new(position, 100.0).into_packet_bytes();
+    assert_eq!(packet[..6], [0xb5, 0x62, 0x13, 0x40, 20, 0]);
+    assert_eq!(packet[6..10], [0x01, 0x00, 0x00, 0x00]);
+    assert_eq!(packet[10..14], 472_500_000_i32.to_le_bytes());
+    assert_eq!(packet[14..18], 85_000_000_i32.to_le_bytes());
+    assert_eq!(packet[18..22], 50_000_i32.to_le_bytes());
+    assert_eq!(packet[22..26], 10_000_u32.to_le_bytes());
+    assert_eq!(
+        (packet[26], packet[27]),
+        ubx_checksum(&packet[2..packet.len() - 2])
+    );
+}
+
+#[test]
+fn test_mga_ini_time_utc() {
+    use chrono::{NaiveDate, TimeZone, Utc};
+    use core::time::Duration;
+
+    let time = Utc
+        .with_ymd_and_hms(2024, 3, 1, 12, 30, 45)
+        .unwrap()
+        .checked_add_signed(chrono::Duration::milliseconds(250))
+        .unwrap();
+    let packet = MgaIniTimeUtcBuilder::new(time, Duration::from_millis(1_500)).into_packet_bytes();
+    assert_eq!(packet[..6], [0xb5, 0x62, 0x13, 0x40, 24, 0]);
+    let payload = &packet[6..30];
+    // Type, version, reference and unknown leap seconds
+    assert_eq!(payload[..4], [0x10, 0x00, 0x00, 0x80]);
+    assert_eq!(payload[4..6], 2024_u16.to_le_bytes());
+    assert_eq!(payload[6..12], [3, 1, 12, 30, 45, 0]);
+    assert_eq!(payload[12..16], 250_000_000_u32.to_le_bytes());
+    assert_eq!(payload[16..18], 1_u16.to_le_bytes());
+    assert_eq!(payload[20..24], 500_000_000_u32.to_le_bytes());
+
+    let leap_second = NaiveDate::from_ymd_opt(2016, 12, 31)
+        .unwrap()
+        .and_hms_nano_opt(23, 59, 59, 1_500_000_000)
+        .unwrap()
+        .and_utc();
+    let packet = MgaIniTimeUtcBuilder::new(leap_second, Duration::ZERO).into_packet_bytes();
+    assert_eq!(packet[6 + 10], 60);
+    assert_eq!(packet[6 + 12..6 + 16], 500_000_000_u32.to_le_bytes());
+}
+
+#[cfg(feature = "ubx_proto23")]
+#[test]
+fn test_mga_gps_alm_round_trip() {
+    use ublox::{
+        proto23::{PacketRef, Proto23},
+        UbxProtocol,
+    };
+
+    let packet = MgaGpsAlmBuilder {
+        msg_type: MgaGpsAlmBuilder::MSG_TYPE,
+        sv_id: 12,
+        e: 0x1234,
+        toa: 144,
+        sqrt_a: 10_560_000,
+        m0: -4_000_000,
+        af1: -2,
+        ..Default::default()
+    }
+    .into_packet_bytes();
+    assert_eq!(packet[..6], [0xb5, 0x62, 0x13, 0x00, 36, 0]);
+
+    // Same class and id as UBX-MGA-GPS EPH, told apart by the payload length
+    let PacketRef::MgaGpsAlm(alm) = Proto23::match_packet(0x13, 0x00, &packet[6..42]).unwrap()
+    else {
+        panic!("Not a UBX-MGA-GPS ALM");
+    };
+    assert_eq!(alm.msg_type(), 0x02);
+    assert_eq!(alm.sv_id(), 12);
+    assert_eq!(alm.e(), 0x1234);
+    assert_eq!(alm.toa(), 144);
+    assert_eq!(alm.sqrt_a(), 10_560_000);
+    assert_eq!(alm.m0(), -4_000_000);
+    assert_eq!(alm.af1(), -2);
+}
+
+#[test]
+fn test_cfg_cfg_save_load_revert() {
+    let save = CfgCfgBuilder::save(CfgDevices::BBR | CfgDevices::FLASH).into_packet_bytes();
+    assert_eq!(save[..6], [0xb5, 0x62, 0x06, 0x09, 13, 0]);
+    assert_eq!(
+        save[6..19],
+        [0, 0, 0, 0, 0xff, 0xff, 0xff, 0xff, 0, 0, 0, 0, 0x03]
+    );
+    assert_eq!(ubx_checksum(&save[2..19]), (save[19], save[20]));
+
+    let load = CfgCfgBuilder::load(CfgDevices::FLASH).into_packet_bytes();
+    assert_eq!(
+        load[6..19],
+        [0, 0, 0, 0, 0, 0, 0, 0, 0xff, 0xff, 0xff, 0xff, 0x02]
+    );
+
+    let revert = CfgCfgBuilder::revert_to_defaults(CfgDevices::BBR).into_packet_bytes();
+    assert_eq!(
+        revert[6..19],
+        [0xff, 0xff, 0xff, 0xff, 0, 0, 0, 0, 0xff, 0xff, 0xff, 0xff, 0x01]
+    );
+
+    // Only save the port and message settings
+    let partial = CfgCfgBuilder {
+        save_mask: CfgSections::IO_PORT | CfgSections::MSG_CONF,
+        device_mask: CfgDevices::SPI_FLASH,
+        ..Default::default()
+    }
+    .into_packet_bytes();
+    assert_eq!(partial[10..14], [0x03, 0, 0, 0]);
+    assert_eq!(partial[18], 0x10);
+}
+
+#[test]
+fn test_cfg_rst_builders() {
+    use ublox::{ttff::StartType, verify_frame};
+
+    let payload = |builder: CfgRstBuilder| {
+        verify_frame(&builder.into_packet_bytes())
+            .unwrap()
+            .payload
+            .to_vec()
+    };
+
+    assert_eq!(
+        payload(CfgRstBuilder::gnss_restart(StartType::Hot)),
+        [0, 0, 0x02, 0]
+    );
+    assert_eq!(
+        payload(CfgRstBuilder::software_reset(StartType::Cold)),
+        [0xff, 0xff, 0x01, 0]
+    );
+    assert_eq!(payload(CfgRstBuilder::hardware_reset()), [0, 0, 0x00, 0]);
+    assert_eq!(payload(CfgRstBuilder::gnss_stop()), [0, 0, 0x08, 0]);
+    assert_eq!(payload(CfgRstBuilder::gnss_start()), [0, 0, 0x09, 0]);
+
+    let custom = CfgRstBuilder::new(
+        NavBbrMask::EPHEMERIS | NavBbrMask::ALMANACH,
+        ResetMode::HardwareResetAfterShutdown,
+    );
+    assert_eq!(custom.reset_mode, ResetMode::HardwareResetAfterShutdown);
+    assert_eq!(payload(custom), [0x03, 0, 0x04, 0]);
+    assert_eq!(
+        NavBbrMask::from(NavBbrPredefinedMask::WARM_START),
+        NavBbrMask::EPHEMERIS
+    );
+}
@@ -0,0 +1,102 @@
+#![cfg(feature = "geo")]
+
+use geo_types::{Coord, Point};
+use ublox::{geo::InvalidPosition, PositionLLA};
+
+#[test]
+fn from_raw_scales_and_checks() {
+    let position = PositionLLA::from_raw(-1_224_188_000, 377_752_700, -1_500).unwrap();
+    assert!((position.lon + 122.4188).abs() < 1e-9);
+    assert!((position.lat - 37.77527).abs() < 1e-9);
+    assert!((position.alt + 1.5).abs() < 1e-9);
+
+    // Latitude already in degrees instead of 1e-7 degrees, scaled once more
+    assert!(PositionLLA::from_raw(0, i32::MAX, 0).is_err());
+}
+
+#[test]
+fn geo_types_conversions() {
+    let position = PositionLLA {
+        lon: 10.40744,
+        lat: 57.64911,
+        alt: 12.0,
+    };
+    assert_eq!(
+        Coord::try_from(position),
+        Ok(Coord {
+            x: 10.40744,
+            y: 57.64911
+        })
+    );
+    assert_eq!(
+        Point::try_from(position),
+        Ok(Point::new(10.40744, 57.64911))
+    );
+
+    let invalid = PositionLLA {
+        lon: 181.0,
+        lat: 0.0,
+        alt: 0.0,
+    };
+    assert_eq!(
+        Point::try_from(invalid),
+        Err(InvalidPosition {
+            lon: 181.0,
+            lat: 0.0
+        })
+    );
+    let nan = PositionLLA {
+        lon: 0.0,
+        lat: f64::NAN,
+        alt: 0.0,
+    };
+    assert!(Coord::try_from(nan).is_err());
+}
+
+#[test]
+fn geohash() {
+    let position = PositionLLA {
+        lon: 10.40744,
+        lat: 57.64911,
+        alt: 0.0,
+    };
+    assert_eq!(position.geohash(11).unwrap().as_str(), "u4pruydqqvj");
+    assert_eq!(position.geohash(1).unwrap().to_string(), "u");
+    assert_eq!(position.geohash(20).unwrap().as_str().len(), 12);
+    assert_eq!(position.geohash(0).unwrap().as_str(), "");
+
+    let corner = PositionLLA {
+        lon: -180.0,
+        lat: -90.0,
+        alt: 0.0,
+    };
+    assert_eq!(corner.geohash(5).unwrap().as_str(), "00000");
+}
+
+#[cfg(feature = "h3")]
+#[test]
+fn h3_cell() {
+    use h3o::{LatLng, Resolution};
+
+    let position = PositionLLA {
+        lon: -122.418307270836,
+        lat: 37.7752702151959,
+        alt: 0.0,
+    };
+    let cell = position.h3_cell(Resolution::Nine).unwrap();
+    assert_eq!(cell.resolution(), Resolution::Nine);
+    assert_eq!(
+        cell,
+        LatLng::new(position.lat, position.lon)
+            .unwrap()
+            .to_cell(Resolution::Nine)
+    );
+    assert_eq!(u64::from(cell), 0x0892_8308_280f_ffff);
+
+    let invalid = PositionLLA {
+        lon: 0.0,
+        lat: 91.0,
+        alt: 0.0,
+    };
+    assert!(invalid.h3_cell(Resolution::Nine).is_err());
+}
@@ -0,0 +1,73 @@
+#![cfg(feature = "ubx_proto23")]
+
+use ublox::{
+    proto23::{PacketRef, Proto23},
+    Heading, HeadingSource, UbxPacket, UbxProtocol,
+};
+
+/// UBX-NAV-PVT payload with the given fix status flags, heading of motion 90° and heading of
+/// vehicle 180°
+fn nav_pvt(flags: u8) -> [u8; 92] {
+    let mut payload = [0; 92];
+    payload[21] = flags;
+    payload[64..68].copy_from_slice(&9_000_000_i32.to_le_bytes());
+    payload[72..76].copy_from_slice(&150_000_u32.to_le_bytes());
+    payload[84..88].copy_from_slice(&18_000_000_i32.to_le_bytes());
+    payload
+}
+
+fn heading(payload: &[u8]) -> Heading {
+    let packet: UbxPacket = Proto23::match_packet(0x01, 0x07, payload).unwrap().into();
+    let UbxPacket::Proto23(PacketRef::NavPvt(pvt)) = packet else {
+        panic!("Not a UBX-NAV-PVT");
+    };
+    Heading::from(&pvt)
+}
+
+#[test]
+fn nav_pvt_prefers_valid_heading_of_vehicle() {
+    // GPS_FIX_OK and HEAD_VEH_VALID
+    let heading = heading(&nav_pvt(0x21));
+    assert_eq!(heading.source, HeadingSource::Vehicle);
+    assert!(heading.valid);
+    assert!((heading.heading - 180.0).abs() < 1e-9);
+    assert!((heading.accuracy - 1.5).abs() < 1e-9);
+}
+
+#[test]
+fn nav_pvt_falls_back_to_heading_of_motion() {
+    let motion = heading(&nav_pvt(0x01));
+    assert_eq!(motion.source, HeadingSource::Motion);
+    assert!(motion.valid);
+    assert!((motion.heading - 90.0).abs() < 1e-9);
+
+    let no_fix = heading(&nav_pvt(0x00));
+    assert_eq!(no_fix.source, HeadingSource::Motion);
+    assert!(!no_fix.valid);
+}
+
+#[cfg(feature = "ubx_proto14")]
+#[test]
+fn nav_pvt_proto14_heading_of_vehicle_past_84_bytes() {
+    use ublox::{nav_pvt::proto14::NavPvt, ParserError, UbxPacketRecv};
+
+    let payload = nav_pvt(0x21);
+    let pvt = NavPvt::parse_payload(&payload[..84]).unwrap();
+    assert_eq!(pvt.heading_vehicle(), None);
+    assert_eq!(Heading::from(&pvt).source, HeadingSource::Motion);
+
+    let pvt = NavPvt::parse_payload(&payload).unwrap();
+    assert_eq!(pvt.heading_vehicle_raw(), Some(18_000_000));
+    assert_eq!(pvt.magnetic_declination(), Some(0.0));
+    assert_eq!(Heading::from(&pvt).source, HeadingSource::Vehicle);
+    assert!((pvt.to_owned().heading_vehicle().unwrap() - 180.0).abs() < 1e-9);
+
+    assert!(matches!(
+        NavPvt::parse_payload(&payload[..80]),
+        Err(ParserError::InvalidPacketLen { expect: 84, .. })
+    ));
+    assert!(matches!(
+        NavPvt::parse_payload(&[0; 96]),
+        Err(ParserError::InvalidPacketLen { expect: 92, .. })
+    ));
+}
@@ -0,0 +1,76 @@
+#![cfg(feature = "ubx_proto23")]
+
+use core::time::Duration;
+
+use ublox::{
+    i2c_poll::{bytes_available, ExpectedTraffic, PollScheduler},
+    msg_rate::MsgRate,
+    nav_dop::NavDop,
+    nav_pvt::proto23::NavPvt,
+};
+
+fn ms(ms: u64) -> Duration {
+    Duration::from_millis(ms)
+}
+
+/// UBX-NAV-PVT and UBX-NAV-DOP with every solution at 5 Hz, 126 bytes per epoch
+fn traffic() -> ExpectedTraffic {
+    ExpectedTraffic::new(ms(200))
+        .with_msg_rate(&MsgRate::new::<NavPvt>(1))
+        .with_msg_rate(&MsgRate::new::<NavDop>(1))
+}
+
+#[test]
+fn expected_traffic_from_message_rates() {
+    let traffic = traffic();
+    assert_eq!(traffic.bytes_per_epoch(), 126);
+    assert_eq!(traffic.bytes_per_second(), 630);
+
+    // Messages disabled on I2C or unknown are not output
+    let uart_only = MsgRate::from_ids(0x01, 0x07, [0, 1, 0, 0, 0]);
+    let unknown = MsgRate::from_ids(0xf0, 0x00, [1; 5]);
+    assert_eq!(
+        traffic.with_msg_rate(&uart_only).with_msg_rate(&unknown),
+        traffic
+    );
+
+    // A 92 byte message every 4 epochs adds 25 bytes per epoch
+    assert_eq!(traffic.with_message(4, 92).bytes_per_epoch(), 151);
+    assert_eq!(bytes_available([0x01, 0x02]), 0x0102);
+}
+
+#[test]
+fn polls_follow_the_bursts() {
+    let mut scheduler = PollScheduler::new(traffic());
+
+    // UBX-NAV-PVT is available first, then UBX-NAV-DOP
+    assert_eq!(scheduler.on_poll(ms(1_000), 100), ms(1_002));
+    // The epoch is complete, the next one is expected in 200 ms
+    assert_eq!(scheduler.on_poll(ms(1_002), 26), ms(1_052));
+    assert_eq!(scheduler.on_poll(ms(1_052), 0), ms(1_102));
+    assert_eq!(scheduler.on_poll(ms(1_102), 0), ms(1_152));
+    assert_eq!(scheduler.on_poll(ms(1_152), 0), ms(1_200));
+
+    // A burst shorter than expected, then back off doubling the interval
+    assert_eq!(scheduler.on_poll(ms(1_200), 100), ms(1_202));
+    assert_eq!(scheduler.on_poll(ms(1_202), 0), ms(1_206));
+    assert_eq!(scheduler.on_poll(ms(1_206), 0), ms(1_214));
+    assert_eq!(scheduler.on_poll(ms(1_214), 0), ms(1_230));
+    assert_eq!(scheduler.on_poll(ms(1_230), 0), ms(1_262));
+    assert_eq!(scheduler.on_poll(ms(1_262), 0), ms(1_312));
+}
+
+#[test]
+fn added_latency_is_bounded() {
+    let mut scheduler = PollScheduler::new(traffic())
+        .with_min_interval(ms(1))
+        .with_max_latency(ms(30));
+    let mut now = ms(0);
+    for i in 0..500u16 {
+        let available = if i % 7 < 2 { i % 130 } else { 0 };
+        let next = scheduler.on_poll(now, available);
+        assert!(next >= now + ms(1), "{next:?} at {now:?}");
+        assert!(next <= now + ms(30), "{next:?} at {now:?}");
+        now = next;
+    }
+}
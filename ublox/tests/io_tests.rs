@@ -0,0 +1,44 @@
+use ublox::{
+    cfg_msg::CfgMsgSinglePortBuilder, io::WriteUbxFrame, nav_pos_llh::NavPosLlh, UbxPollRequest,
+};
+
+#[test]
+fn write_ubx_serializes_packets() {
+    let mut port = Vec::new();
+    port.write_ubx(CfgMsgSinglePortBuilder::set_rate_for::<NavPosLlh>(1))
+        .unwrap();
+    port.write_ubx(UbxPollRequest::new(0x0a, 0x04)).unwrap();
+
+    let mut expected = CfgMsgSinglePortBuilder::set_rate_for::<NavPosLlh>(1)
+        .into_packet_bytes()
+        .to_vec();
+    expected.extend_from_slice(&UbxPollRequest::new(0x0a, 0x04).into_packet_bytes());
+    assert_eq!(port, expected);
+}
+
+#[test]
+fn write_ubx_reports_short_writes() {
+    let mut buf = [0u8; 8];
+    let mut port = &mut buf[..];
+    let err = port
+        .write_ubx(CfgMsgSinglePortBuilder::set_rate_for::<NavPosLlh>(1))
+        .unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::WriteZero);
+}
+
+#[cfg(feature = "embedded-io")]
+#[test]
+fn embedded_write_ubx_serializes_packets() {
+    use ublox::{io::embedded, MemWriterError};
+
+    let poll = UbxPollRequest::new(0x0a, 0x04);
+    let mut buf = [0u8; 12];
+    let mut port = &mut buf[..];
+    // `&mut [u8]` is a `std::io::Write` as well, hence the qualified calls
+    embedded::WriteUbxFrame::write_ubx(&mut port, poll).unwrap();
+    assert!(matches!(
+        embedded::WriteUbxFrame::write_ubx(&mut port, poll),
+        Err(MemWriterError::Custom(embedded_io::SliceWriteError::Full))
+    ));
+    assert_eq!(buf[..8], poll.into_packet_bytes());
+}
@@ -0,0 +1,158 @@
+#![cfg(feature = "ubx_proto23")]
+
+use ublox::{
+    latency::{LatencyHistogram, LatencyMeter, LATENCY_BINS},
+    nav_dop::NavDop,
+    nav_pvt::proto23::NavPvt,
+    UbxUnknownPacketRef,
+};
+
+const MS: u64 = 1_000_000;
+
+/// UBX-TIM-TP payload announcing a pulse aligned to GPS time, or UTC
+fn tim_tp(week: u16, tow_ms: u32, utc: bool) -> [u8; 16] {
+    let mut payload = [0; 16];
+    payload[..4].copy_from_slice(&tow_ms.to_le_bytes());
+    payload[12..14].copy_from_slice(&week.to_le_bytes());
+    payload[14] = u8::from(utc);
+    payload
+}
+
+fn itow_payload<const N: usize>(itow: u32) -> [u8; N] {
+    let mut payload = [0; N];
+    payload[..4].copy_from_slice(&itow.to_le_bytes());
+    payload
+}
+
+fn frame(class: u8, msg_id: u8, payload: &[u8]) -> UbxUnknownPacketRef<'_> {
+    UbxUnknownPacketRef {
+        payload,
+        class,
+        msg_id,
+    }
+}
+
+/// Meter synchronized to the pulse of `week` at `tow_ms`, captured at host time `pulse_ns`
+fn synchronized(week: u16, tow_ms: u32, pulse_ns: u64) -> LatencyMeter {
+    let mut meter = LatencyMeter::new();
+    meter.record(
+        frame(0x0d, 0x01, &tim_tp(week, tow_ms, false)),
+        pulse_ns - 500 * MS,
+    );
+    meter.on_time_pulse(pulse_ns);
+    assert!(meter.is_synchronized());
+    meter
+}
+
+#[test]
+fn latency_per_packet_type() {
+    let mut meter = synchronized(2300, 10_000, 1_000 * MS);
+    let pvt = itow_payload::<92>(10_000);
+    let dop = itow_payload::<18>(10_000);
+
+    assert_eq!(
+        meter.record(frame(0x01, 0x07, &pvt), 1_030 * MS),
+        Some(30_000_000)
+    );
+    assert_eq!(
+        meter.record(frame(0x01, 0x04, &dop), 1_032 * MS),
+        Some(32_000_000)
+    );
+    // The next epoch, 200 ms after the pulse
+    let pvt = itow_payload::<92>(10_200);
+    assert_eq!(
+        meter.record(frame(0x01, 0x07, &pvt), 1_250 * MS),
+        Some(50_000_000)
+    );
+    // Packets without an iTOW are not measured
+    assert_eq!(meter.record(frame(0x0a, 0x04, &[]), 1_260 * MS), None);
+
+    let pvt = meter.latency::<NavPvt>().unwrap();
+    assert_eq!(pvt.count(), 2);
+    assert_eq!(pvt.min_ns(), Some(30_000_000));
+    assert_eq!(pvt.max_ns(), Some(50_000_000));
+    assert_eq!(pvt.mean_ns(), Some(40_000_000));
+    assert_eq!(meter.latency::<NavDop>().unwrap().count(), 1);
+    let types: Vec<_> = meter
+        .latencies()
+        .iter()
+        .map(|latency| (latency.class, latency.msg_id))
+        .collect();
+    assert_eq!(types, [(0x01, 0x07), (0x01, 0x04)]);
+
+    meter.reset();
+    assert!(meter.latencies().is_empty());
+    assert!(meter.is_synchronized());
+}
+
+#[test]
+fn time_pulse_pairing() {
+    let mut meter = LatencyMeter::new();
+    let pvt = itow_payload::<92>(10_000);
+    assert_eq!(meter.record(frame(0x01, 0x07, &pvt), 1_030 * MS), None);
+
+    // Time pulses aligned to UTC are not used
+    meter.record(frame(0x0d, 0x01, &tim_tp(2300, 10_000, true)), 500 * MS);
+    meter.on_time_pulse(1_000 * MS);
+    assert!(!meter.is_synchronized());
+
+    // A capture from before the UBX-TIM-TP is of the previous pulse
+    meter.record(frame(0x0d, 0x01, &tim_tp(2300, 10_000, false)), 500 * MS);
+    meter.on_time_pulse(400 * MS);
+    assert!(!meter.is_synchronized());
+    meter.on_time_pulse(1_000 * MS);
+    // Calling again with a later capture does not pair the same announcement twice
+    meter.on_time_pulse(2_000 * MS);
+    assert_eq!(
+        meter.record(frame(0x01, 0x07, &pvt), 1_030 * MS),
+        Some(30_000_000)
+    );
+}
+
+#[test]
+fn epoch_across_week_rollover() {
+    // Pulse of the last second of week 2300, then the first epoch of week 2301
+    let mut meter = synchronized(2300, 604_799_000, 1_000 * MS);
+    let pvt = itow_payload::<92>(0);
+    assert_eq!(
+        meter.record(frame(0x01, 0x07, &pvt), 2_010 * MS),
+        Some(10_000_000)
+    );
+
+    // And the other way around
+    let mut meter = synchronized(2301, 0, 1_000 * MS);
+    let pvt = itow_payload::<92>(604_799_500);
+    assert_eq!(
+        meter.record(frame(0x01, 0x07, &pvt), 520 * MS),
+        Some(20_000_000)
+    );
+}
+
+#[test]
+fn histogram_bins_and_quantiles() {
+    let mut meter = synchronized(2300, 10_000, 1_000 * MS);
+    let pvt = itow_payload::<92>(10_000);
+    // 1 µs early, then 1.5 µs, 3 ms and 100 s late
+    for latency_ns in [-1_000, 1_500, 3_000_000, 100_000_000_000] {
+        let host_ns = (1_000 * MS as i64 + latency_ns) as u64;
+        meter.record(frame(0x01, 0x07, &pvt), host_ns);
+    }
+
+    let histogram = meter.latency::<NavPvt>().unwrap();
+    let bins = histogram.bins();
+    assert_eq!(bins[0], 1);
+    assert_eq!(bins[1], 1);
+    // 3000 µs is between 2^11 and 2^12 µs
+    assert_eq!(bins[12], 1);
+    assert_eq!(bins[LATENCY_BINS - 1], 1);
+    assert_eq!(bins.iter().sum::<u32>(), 4);
+
+    assert_eq!(histogram.quantile_ns(0.0), Some(1_000));
+    assert_eq!(histogram.quantile_ns(0.5), Some(2_000));
+    assert_eq!(
+        histogram.quantile_ns(0.75),
+        Some(LatencyHistogram::bin_upper_ns(12))
+    );
+    assert_eq!(histogram.quantile_ns(1.0), Some(100_000_000_000));
+    assert_eq!(histogram.min_ns(), Some(-1_000));
+}
@@ -0,0 +1,91 @@
+#![cfg(all(feature = "std", feature = "ubx_proto23"))]
+
+use std::{
+    cell::RefCell,
+    time::{Duration, Instant},
+};
+
+use ublox::{
+    dispatch::Dispatcher, latest::LatestState, nav_dop::NavDop, nav_pvt::proto23::NavPvt,
+    ubx_frame, ParserBuilder, UbxPacketMeta, UbxUnknownPacketRef,
+};
+
+fn frame<M: UbxPacketMeta>(payload: &[u8]) -> UbxUnknownPacketRef<'_> {
+    UbxUnknownPacketRef {
+        payload,
+        class: M::CLASS,
+        msg_id: M::ID,
+    }
+}
+
+fn nav_pvt(itow: u32) -> [u8; 92] {
+    let mut payload = [0; 92];
+    payload[NavPvt::OFFSET_ITOW..][..4].copy_from_slice(&itow.to_le_bytes());
+    payload
+}
+
+#[test]
+fn keeps_the_latest_packet_of_each_type() {
+    let start = Instant::now();
+    let mut state = LatestState::new();
+    assert!(state.latest_at::<NavPvt>(start).is_none());
+    assert!(state.is_stale_at::<NavPvt>(start));
+
+    state.update_at(frame::<NavPvt>(&nav_pvt(1000)), start);
+    let later = start + Duration::from_secs(1);
+    state.update_at(frame::<NavPvt>(&nav_pvt(2000)), later);
+    state.update_at(frame::<NavDop>(&[0; 18]), start);
+
+    let (received, pvt) = state.latest_at::<NavPvt>(later).unwrap();
+    assert_eq!((received, pvt.itow()), (later, 2000));
+    assert_eq!(state.latest_at::<NavDop>(later).unwrap().0, start);
+    assert_eq!(state.age_at::<NavDop>(later), Some(Duration::from_secs(1)));
+
+    state.clear();
+    assert!(state.latest_at::<NavPvt>(later).is_none());
+}
+
+#[test]
+fn stale_values_are_missing() {
+    let start = Instant::now();
+    let mut state = LatestState::new()
+        .with_max_age(Duration::from_secs(2))
+        .with_max_age_for::<NavDop>(Duration::from_secs(10));
+    state.update_at(frame::<NavPvt>(&nav_pvt(1000)), start);
+    state.update_at(frame::<NavDop>(&[0; 18]), start);
+
+    let now = start + Duration::from_secs(2);
+    assert!(state.latest_at::<NavPvt>(now).is_some());
+    let now = start + Duration::from_secs(3);
+    assert!(state.is_stale_at::<NavPvt>(now));
+    assert!(state.latest_at::<NavPvt>(now).is_none());
+    // Still known, only stale
+    assert_eq!(state.age_at::<NavPvt>(now), Some(Duration::from_secs(3)));
+    assert!(state.latest_at::<NavDop>(now).is_some());
+}
+
+#[test]
+fn invalid_payloads_are_missing() {
+    let mut state = LatestState::new();
+    state.update(frame::<NavPvt>(&[0; 10]));
+    assert!(!state.is_stale::<NavPvt>());
+    assert!(state.latest::<NavPvt>().is_none());
+}
+
+#[test]
+fn fed_by_the_dispatcher() {
+    let state = RefCell::new(LatestState::new());
+    let handled = RefCell::new(0);
+    let mut dispatcher = Dispatcher::new(ParserBuilder::new().with_fixed_buffer::<1024>())
+        .on_frame(|frame| state.borrow_mut().update(frame))
+        .on::<NavPvt>(|_| *handled.borrow_mut() += 1);
+
+    dispatcher.feed(&ubx_frame!(0x01, 0x07, &nav_pvt(1000)));
+    dispatcher.feed(&ubx_frame!(0x01, 0x04, &[0; 18]));
+    drop(dispatcher);
+
+    assert_eq!(*handled.borrow(), 1);
+    let state = state.borrow();
+    assert_eq!(state.latest::<NavPvt>().unwrap().1.itow(), 1000);
+    assert!(state.latest::<NavDop>().is_some());
+}
@@ -1,6 +1,45 @@
+#![allow(
+    clippy::duplicate_mod,
+    reason = "The test files are also their own binaries, each declaring the `common` helpers"
+)]
+
+mod ack_tracker_tests;
+mod aop_tests;
+mod cno_trend_tests;
+mod config_plan_tests;
+mod defmt_tests;
+mod dispatch_tests;
+mod display_tests;
+mod epoch_tests;
+mod firmware_tests;
+mod frame_tests;
 mod generator_test;
+mod geo_tests;
+mod heading_tests;
+mod i2c_poll_tests;
+mod io_tests;
+mod latency_tests;
+mod latest_tests;
+mod link_health_tests;
+mod meta_tests;
+mod msg_rate_tests;
+mod nav_time_tests;
+mod nmea_tests;
+mod osnma_tests;
+mod owned_tests;
+mod packet_set_tests;
 mod parser_binary_dump_test;
 mod parser_tests;
+mod product_tests;
+mod recording_tests;
+mod regressions;
 mod rxm_sfrbx;
+mod session_tests;
+mod simple_tests;
+mod stationary_tests;
+mod time_mark_tests;
+mod trigger_tests;
+mod ttff_tests;
+mod velocity_tests;
 
 // `fuzz_*` tests are their own auto-discovered binaries; don't declare them here.
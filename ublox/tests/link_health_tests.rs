@@ -0,0 +1,76 @@
+#![cfg(feature = "ubx_proto23")]
+
+use ublox::{
+    link_health::{LinkDiagnosis, LinkHealth},
+    proto23::{PacketRef, Proto23},
+    ParserBuilder, UbxPacket, UbxProtocol,
+};
+
+/// UBX-MON-MSGPP payload with `msgs` UBX and NMEA messages and `skipped` bytes on UART1
+fn mon_msgpp(msgs: [u16; 2], skipped: u32) -> [u8; 120] {
+    let mut payload = [0; 120];
+    payload[16..18].copy_from_slice(&msgs[0].to_le_bytes());
+    payload[18..20].copy_from_slice(&msgs[1].to_le_bytes());
+    payload[100..104].copy_from_slice(&skipped.to_le_bytes());
+    payload
+}
+
+fn packet(payload: &[u8]) -> UbxPacket<'_> {
+    Proto23::match_packet(0x0a, 0x06, payload).unwrap().into()
+}
+
+#[test]
+fn mon_msgpp_port_counters() {
+    let payload = mon_msgpp([3, 4], 17);
+    let UbxPacket::Proto23(PacketRef::MonMsgpp(msgpp)) = packet(&payload) else {
+        panic!("Not a UBX-MON-MSGPP");
+    };
+    assert_eq!(msgpp.port_msgs(1), Some([3, 4, 0, 0, 0, 0, 0, 0]));
+    assert_eq!(msgpp.port_msgs(0), Some([0; 8]));
+    assert_eq!(msgpp.port_msgs(6), None);
+    assert_eq!(msgpp.skipped_bytes(), [0, 17, 0, 0, 0, 0]);
+}
+
+#[test]
+fn link_health_separates_receiver_and_host_errors() {
+    let mut health = LinkHealth::new(1);
+    let mut parser = ParserBuilder::new().with_fixed_buffer::<256>();
+
+    // The first UBX-MON-MSGPP is the baseline of the receiver counters
+    let payload = mon_msgpp([10, 5], 100);
+    assert!(health.update(&packet(&payload)));
+    let report = health.report(parser.stats());
+    assert_eq!(report.receiver_skipped_bytes, None);
+    assert_eq!(report.diagnosis(), LinkDiagnosis::Healthy);
+
+    // The receiver skipped bytes, the host received a clean UBX-ACK-ACK
+    let payload = mon_msgpp([12, 6], 120);
+    assert!(health.update(&packet(&payload)));
+    let ack_ack = [0xb5, 0x62, 0x05, 0x01, 0x02, 0x00, 0x06, 0x01, 0x0f, 0x38];
+    let mut it = parser.consume_ubx(&ack_ack);
+    while it.next().is_some() {}
+    drop(it);
+    let report = health.report(parser.stats());
+    assert_eq!(report.receiver_msgs, Some(3));
+    assert_eq!(report.receiver_skipped_bytes, Some(20));
+    assert_eq!(report.host_bytes, 10);
+    assert_eq!(report.host_frames, 1);
+    assert_eq!(report.diagnosis(), LinkDiagnosis::ReceiverSide);
+
+    // No new UBX-MON-MSGPP, the host received noise
+    let mut it = parser.consume_ubx(&[0x00, 0x11]);
+    while it.next().is_some() {}
+    drop(it);
+    let report = health.report(parser.stats());
+    assert_eq!(report.receiver_skipped_bytes, None);
+    assert_eq!(report.host_discarded_bytes, 2);
+    assert_eq!(report.diagnosis(), LinkDiagnosis::HostSide);
+
+    // Counters wrap around on the receiver
+    let payload = mon_msgpp([1, 6], 130);
+    assert!(health.update(&packet(&payload)));
+    let report = health.report(parser.stats());
+    assert_eq!(report.receiver_msgs, Some(u16::MAX - 10));
+    assert_eq!(report.receiver_skipped_bytes, Some(10));
+    assert_eq!(report.host_bytes, 0);
+}
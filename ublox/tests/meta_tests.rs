@@ -0,0 +1,28 @@
+#![cfg(feature = "ubx_proto23")]
+
+use ublox::{
+    mon_ver::MonVer, nav_pvt::proto23::NavPvt, packet_meta, packet_name, proto23::Proto23,
+    UbxPacketMeta,
+};
+
+#[test]
+fn packet_constants() {
+    assert_eq!(
+        (NavPvt::CLASS, NavPvt::ID, NavPvt::NAME),
+        (0x01, 0x07, "NavPvt")
+    );
+    assert_eq!(MonVer::NAME, "MonVer");
+}
+
+#[test]
+fn every_supported_message_is_registered() {
+    for meta in Proto23::supported_messages() {
+        let registered = packet_meta(meta.class, meta.msg_id).unwrap();
+        assert_eq!(
+            (registered.class, registered.msg_id),
+            (meta.class, meta.msg_id)
+        );
+        assert_eq!(packet_name(meta.class, meta.msg_id), Some(registered.name));
+    }
+    assert_eq!(packet_meta(0xf1, 0x00), None);
+}
@@ -0,0 +1,70 @@
+#![cfg(all(feature = "ubx_proto23", feature = "ubx_proto27"))]
+
+use ublox::{
+    cfg_msg::CfgMsgAllPortsBuilder,
+    cfg_val::{CfgLayerSet, CfgVal},
+    msg_rate::MsgRate,
+    nav_pvt::proto23::NavPvt,
+    proto23::Proto23,
+    proto27::Proto27,
+    UbxProtocol,
+};
+
+#[test]
+fn protocol_versions_choose_the_configuration_message() {
+    assert!(!MsgRate::uses_cfg_valset::<Proto23>());
+    assert!(MsgRate::uses_cfg_valset::<Proto27>());
+    assert_eq!(Proto23::VERSION, 23);
+}
+
+#[test]
+fn legacy_frame_sets_all_ports() {
+    let rate = MsgRate::new::<NavPvt>(1);
+    let expected = CfgMsgAllPortsBuilder::set_rate_for::<NavPvt>([1, 1, 1, 1, 1, 0]);
+    assert_eq!(
+        rate.to_packet::<Proto23>().unwrap(),
+        expected.into_packet_bytes()
+    );
+}
+
+#[test]
+fn msgout_keys_follow_the_ports() {
+    let rate = MsgRate::from_ids(0x01, 0x07, [0, 5, 0, 1, 0]);
+    assert_eq!(
+        rate.cfg_vals().unwrap(),
+        [
+            CfgVal::MsgOutUbxNavPvtI2c(0),
+            CfgVal::MsgOutUbxNavPvtUart1(5),
+            CfgVal::MsgOutUbxNavPvtUart2(0),
+            CfgVal::MsgOutUbxNavPvtUsb(1),
+            CfgVal::MsgOutUbxNavPvtSpi(0),
+        ]
+    );
+
+    let odo = MsgRate::from_ids(0x01, 0x09, [1; 5]).cfg_vals().unwrap();
+    assert_eq!(odo[0], CfgVal::MsgOutUbxNavOdoI2C(1));
+    let sfrbx = MsgRate::from_ids(0x02, 0x13, [2; 5]).cfg_vals().unwrap();
+    assert_eq!(sfrbx[4], CfgVal::MsgOutUbxRxmSfrbxSpi(2));
+}
+
+#[test]
+fn valset_frame_applies_to_ram() {
+    let packet = MsgRate::new::<NavPvt>(1).to_packet::<Proto27>().unwrap();
+    assert_eq!(packet[2..4], [0x06, 0x8a]);
+    // Version, layers, transaction and reserved byte, then 5 keys with 1 byte values
+    assert_eq!(
+        usize::from(u16::from_le_bytes([packet[4], packet[5]])),
+        4 + 5 * 5
+    );
+    assert_eq!(packet[7], CfgLayerSet::RAM.bits());
+    assert_eq!(packet[10..15], [0x06, 0x00, 0x91, 0x20, 1]);
+}
+
+#[test]
+fn messages_without_msgout_keys() {
+    // UBX-NAV-EELL has no CFG-MSGOUT key
+    let rate = MsgRate::from_ids(0x01, 0x3d, [1; 5]);
+    assert!(rate.cfg_vals().is_none());
+    assert!(rate.to_packet::<Proto27>().is_none());
+    assert!(rate.to_packet::<Proto23>().is_some());
+}
@@ -0,0 +1,74 @@
+#![cfg(feature = "ubx_proto23")]
+
+use ublox::{
+    nav_time_bds::NavTimeBdsFlags,
+    nav_time_gal::NavTimeGalFlags,
+    nav_time_glo::NavTimeGloFlags,
+    proto23::{PacketRef, Proto23},
+    UbxProtocol,
+};
+
+/// Payload shared by the GNSS time solutions: iTOW, time of week or day, its fraction, the week
+/// or day number, a byte, the validity flags and the time accuracy
+fn time_payload(tow: u32, ftow: i32, week: u16, byte: u8, valid: u8) -> [u8; 20] {
+    let mut payload = [0; 20];
+    payload[0..4].copy_from_slice(&345_600_000u32.to_le_bytes());
+    payload[4..8].copy_from_slice(&tow.to_le_bytes());
+    payload[8..12].copy_from_slice(&ftow.to_le_bytes());
+    payload[12..14].copy_from_slice(&week.to_le_bytes());
+    payload[14] = byte;
+    payload[15] = valid;
+    payload[16..20].copy_from_slice(&25u32.to_le_bytes());
+    payload
+}
+
+#[test]
+fn nav_time_glo_is_parsed() {
+    let payload = time_payload(10_800, -1_500, 732, 8, 0b11);
+    let Ok(PacketRef::NavTimeGlo(time)) = Proto23::match_packet(0x01, 0x23, &payload) else {
+        panic!("Not a UBX-NAV-TIMEGLO");
+    };
+    assert_eq!(time.itow(), 345_600_000);
+    assert_eq!(time.tod(), 10_800);
+    assert_eq!(time.ftod(), -1_500);
+    assert_eq!(time.nt(), 732);
+    assert_eq!(time.n4(), 8);
+    assert_eq!(
+        time.valid().bits(),
+        (NavTimeGloFlags::VALID_TOD | NavTimeGloFlags::VALID_DATE).bits()
+    );
+    assert_eq!(time.t_acc(), 25);
+}
+
+#[test]
+fn nav_time_bds_is_parsed() {
+    let payload = time_payload(345_586, 250_000_000, 1_000, 4, 0b011);
+    let Ok(PacketRef::NavTimeBds(time)) = Proto23::match_packet(0x01, 0x24, &payload) else {
+        panic!("Not a UBX-NAV-TIMEBDS");
+    };
+    assert_eq!(time.sow(), 345_586);
+    assert_eq!(time.fsow(), 250_000_000);
+    assert_eq!(time.week(), 1_000);
+    assert_eq!(time.leap_s(), 4);
+    assert_eq!(
+        time.valid().bits(),
+        (NavTimeBdsFlags::VALID_SOW | NavTimeBdsFlags::VALID_WEEK).bits()
+    );
+}
+
+#[test]
+fn nav_time_gal_is_parsed() {
+    let payload = time_payload(345_618, -3, 1_300, 18, 0b100);
+    let Ok(PacketRef::NavTimeGal(time)) = Proto23::match_packet(0x01, 0x25, &payload) else {
+        panic!("Not a UBX-NAV-TIMEGAL");
+    };
+    assert_eq!(time.gal_tow(), 345_618);
+    assert_eq!(time.f_gal_tow(), -3);
+    assert_eq!(time.gal_wno(), 1_300);
+    assert_eq!(time.leap_s(), 18);
+    assert_eq!(time.valid().bits(), NavTimeGalFlags::VALID_LEAP_S.bits());
+    assert!(matches!(
+        Proto23::match_packet(0x01, 0x25, &payload[..19]),
+        Ok(PacketRef::Unknown(_))
+    ));
+}
@@ -0,0 +1,257 @@
+#![cfg(feature = "nmea")]
+
+use chrono::{NaiveDate, NaiveTime};
+use ublox::{
+    nmea::{
+        GgaFixQuality, GsaFixType, GsvSatellite, NmeaPosMode, NmeaSentence, NmeaTalker,
+        PubxConfigBuilder, PubxNavStatus, PubxProtoMask, PubxRateBuilder,
+    },
+    AnyPacketRef, ParserBuilder, ParserError,
+};
+
+fn parse(sentence: &[u8]) -> Result<NmeaSentence<'_>, ParserError> {
+    NmeaSentence::parse(sentence)
+}
+
+fn assert_close(value: Option<f64>, expected: f64) {
+    let value = value.expect("value missing");
+    assert!((value - expected).abs() < 1e-9, "{value} != {expected}");
+}
+
+#[test]
+fn test_nmea_gga() {
+    let Ok(NmeaSentence::Gga(gga)) =
+        parse(b"$GNGGA,092725.00,4717.11399,N,00833.91590,E,1,08,1.01,499.6,M,48.0,M,,*45\r\n")
+    else {
+        panic!("Expected GGA");
+    };
+    assert_eq!(gga.talker, NmeaTalker::Gnss);
+    assert_eq!(gga.time, NaiveTime::from_hms_opt(9, 27, 25));
+    assert_close(gga.latitude, 47.0 + 17.11399 / 60.0);
+    assert_close(gga.longitude, 8.0 + 33.91590 / 60.0);
+    assert_eq!(gga.fix_quality, GgaFixQuality::Autonomous);
+    assert_eq!(gga.num_satellites, Some(8));
+    assert_eq!(gga.hdop, Some(1.01));
+    assert_eq!(gga.altitude, Some(499.6));
+    assert_eq!(gga.geoid_separation, Some(48.0));
+    assert_eq!(gga.diff_age, None);
+    assert_eq!(gga.diff_station, None);
+}
+
+#[test]
+fn test_nmea_gga_southern_western_hemisphere() {
+    let Ok(NmeaSentence::Gga(gga)) =
+        parse(b"$GNGGA,092725.00,4717.11399,S,00833.91590,W,1,08,1.01,499.6,M,48.0,M,,*4A\r\n")
+    else {
+        panic!("Expected GGA");
+    };
+    assert_close(gga.latitude, -(47.0 + 17.11399 / 60.0));
+    assert_close(gga.longitude, -(8.0 + 33.91590 / 60.0));
+}
+
+#[test]
+fn test_nmea_rmc() {
+    let Ok(NmeaSentence::Rmc(rmc)) =
+        parse(b"$GNRMC,083559.00,A,4717.11437,N,00833.91522,E,0.004,77.52,091202,,,A*49\r\n")
+    else {
+        panic!("Expected RMC");
+    };
+    assert!(rmc.valid);
+    assert_eq!(rmc.time, NaiveTime::from_hms_opt(8, 35, 59));
+    assert_eq!(rmc.date, NaiveDate::from_ymd_opt(2002, 12, 9));
+    assert_eq!(rmc.speed_knots, Some(0.004));
+    assert_eq!(rmc.course, Some(77.52));
+    assert_eq!(rmc.magnetic_variation, None);
+    assert_eq!(rmc.mode, Some(NmeaPosMode::Autonomous));
+}
+
+#[test]
+fn test_nmea_gsv() {
+    let Ok(NmeaSentence::Gsv(gsv)) =
+        parse(b"$GPGSV,3,1,10,23,38,230,44,29,71,156,47,07,29,116,41,08,09,081,36*7F\r\n")
+    else {
+        panic!("Expected GSV");
+    };
+    assert_eq!(gsv.talker, NmeaTalker::Gps);
+    assert_eq!((gsv.num_msgs, gsv.msg_num, gsv.num_satellites), (3, 1, 10));
+    assert_eq!(gsv.iter_satellites().count(), 4);
+    assert_eq!(
+        gsv.satellites[0],
+        Some(GsvSatellite {
+            sv_id: 23,
+            elevation: Some(38),
+            azimuth: Some(230),
+            cno: Some(44),
+        })
+    );
+    assert_eq!(gsv.signal_id, None);
+
+    let Ok(NmeaSentence::Gsv(gsv)) = parse(b"$GPGSV,3,3,10,09,,,,30,12,310,,1*5E\r\n") else {
+        panic!("Expected GSV");
+    };
+    assert_eq!(gsv.iter_satellites().count(), 2);
+    assert_eq!(
+        gsv.satellites[0],
+        Some(GsvSatellite {
+            sv_id: 9,
+            elevation: None,
+            azimuth: None,
+            cno: None,
+        })
+    );
+    assert_eq!(gsv.signal_id, Some(1));
+}
+
+#[test]
+fn test_nmea_gsa() {
+    let Ok(NmeaSentence::Gsa(gsa)) =
+        parse(b"$GNGSA,A,3,80,71,73,79,69,,,,,,,,1.83,1.09,1.47,4*0F\r\n")
+    else {
+        panic!("Expected GSA");
+    };
+    assert!(gsa.automatic);
+    assert_eq!(gsa.fix_type, GsaFixType::Fix3D);
+    assert_eq!(gsa.iter_sv_ids().collect::<Vec<_>>(), [80, 71, 73, 79, 69]);
+    assert_eq!(
+        (gsa.pdop, gsa.hdop, gsa.vdop),
+        (Some(1.83), Some(1.09), Some(1.47))
+    );
+    assert_eq!(gsa.system_id, Some(4));
+}
+
+#[test]
+fn test_nmea_vtg() {
+    let Ok(NmeaSentence::Vtg(vtg)) = parse(b"$GNVTG,77.52,T,,M,0.004,N,0.008,K,A*18\r\n") else {
+        panic!("Expected VTG");
+    };
+    assert_eq!(vtg.course_true, Some(77.52));
+    assert_eq!(vtg.course_magnetic, None);
+    assert_eq!(vtg.speed_knots, Some(0.004));
+    assert_eq!(vtg.speed_kmh, Some(0.008));
+    assert_eq!(vtg.mode, Some(NmeaPosMode::Autonomous));
+}
+
+#[test]
+fn test_nmea_zda() {
+    let Ok(NmeaSentence::Zda(zda)) = parse(b"$GNZDA,082710.00,16,09,2002,00,00*7A\r\n") else {
+        panic!("Expected ZDA");
+    };
+    assert_eq!(zda.time, NaiveTime::from_hms_opt(8, 27, 10));
+    assert_eq!(zda.date, NaiveDate::from_ymd_opt(2002, 9, 16));
+    assert_eq!(zda.local_zone_hours, Some(0));
+    assert_eq!(zda.local_zone_minutes, Some(0));
+}
+
+#[test]
+fn test_nmea_other_sentence() {
+    assert_eq!(
+        parse(b"$GNTXT,01,01,02,u-blox AG - www.u-blox.com*4E\r\n"),
+        Ok(NmeaSentence::Other {
+            address: "GNTXT",
+            fields: "01,01,02,u-blox AG - www.u-blox.com",
+        })
+    );
+}
+
+#[test]
+fn test_nmea_invalid_checksum() {
+    assert_eq!(
+        parse(b"$GNVTG,77.52,T,,M,0.004,N,0.008,K,A*19\r\n"),
+        Err(ParserError::InvalidChecksum {
+            expect: 0x19,
+            got: 0x18
+        })
+    );
+    assert!(matches!(
+        parse(b"$GNVTG,77.52,T,,M,0.004,N,0.008,K,A\r\n"),
+        Err(ParserError::InvalidField { .. })
+    ));
+}
+
+#[test]
+fn test_nmea_non_ascii() {
+    fn with_checksum(body: &str) -> Vec<u8> {
+        let checksum = body.bytes().fold(0u8, |acc, b| acc ^ b);
+        format!("${body}*{checksum:02X}\r\n").into_bytes()
+    }
+    for body in [
+        "aéGA,",
+        "GPGGA,1é345.00,,,,,0,,,,,,,,",
+        "GPRMC,,A,4é7.1,N,,,,,01012é,,,A",
+    ] {
+        assert!(matches!(
+            parse(&with_checksum(body)),
+            Err(ParserError::InvalidField { field: "data", .. })
+        ));
+    }
+}
+
+#[test]
+fn test_nmea_from_mixed_parser() {
+    let mut parser = ParserBuilder::new().with_fixed_buffer::<1024>();
+    let mut it = parser.consume_ubx_rtcm_nmea(
+        b"$GNZDA,082710.00,16,09,2002,00,00*7A\r\n$GNVTG,77.52,T,,M,0.004,N,0.008,K,A*18\r\n",
+    );
+    let Some(Ok(AnyPacketRef::Nmea(nmea))) = it.next() else {
+        panic!("Expected an NMEA sentence");
+    };
+    assert!(matches!(nmea.parse(), Ok(NmeaSentence::Zda(_))));
+    let Some(Ok(AnyPacketRef::Nmea(nmea))) = it.next() else {
+        panic!("Expected an NMEA sentence");
+    };
+    assert!(matches!(nmea.parse(), Ok(NmeaSentence::Vtg(_))));
+}
+
+#[test]
+fn test_pubx_00() {
+    let Ok(NmeaSentence::Pubx00(pubx)) = parse(
+        b"$PUBX,00,081350.00,4717.113210,N,00833.915187,E,546.589,G3,2.1,2.0,0.007,77.52,0.007,,0.92,1.19,0.77,9,0,0*5F\r\n",
+    ) else {
+        panic!("Expected PUBX,00");
+    };
+    assert_eq!(pubx.time, NaiveTime::from_hms_opt(8, 13, 50));
+    assert_close(pubx.latitude, 47.0 + 17.113210 / 60.0);
+    assert_close(pubx.longitude, 8.0 + 33.915187 / 60.0);
+    assert_eq!(pubx.altitude, Some(546.589));
+    assert_eq!(pubx.nav_status, PubxNavStatus::Standalone3D);
+    assert_eq!((pubx.h_acc, pubx.v_acc), (Some(2.1), Some(2.0)));
+    assert_eq!(pubx.course, Some(77.52));
+    assert_eq!(pubx.diff_age, None);
+    assert_eq!(
+        (pubx.hdop, pubx.vdop, pubx.tdop),
+        (Some(0.92), Some(1.19), Some(0.77))
+    );
+    assert_eq!(pubx.num_satellites, Some(9));
+}
+
+#[test]
+fn test_pubx_builders_round_trip() {
+    let mut sentence = Vec::new();
+    let len = PubxRateBuilder {
+        msg_id: *b"GSV",
+        rate_uart1: 1,
+        rate_usb: 5,
+        ..Default::default()
+    }
+    .extend_to(&mut sentence);
+    assert_eq!(len, sentence.len());
+    assert_eq!(
+        parse(&sentence),
+        Ok(NmeaSentence::Other {
+            address: "PUBX",
+            fields: "40,GSV,0,1,0,5,0,0",
+        })
+    );
+
+    let mut sentence = Vec::new();
+    PubxConfigBuilder {
+        port_id: 1,
+        in_proto: PubxProtoMask::UBX | PubxProtoMask::RTCM3,
+        out_proto: PubxProtoMask::UBX,
+        baudrate: 115200,
+        autobauding: true,
+    }
+    .extend_to(&mut sentence);
+    assert_eq!(sentence, b"$PUBX,41,1,0021,0001,115200,1*1F\r\n");
+    assert!(parse(&sentence).is_ok());
+}
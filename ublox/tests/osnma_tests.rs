@@ -0,0 +1,122 @@
+#![cfg(feature = "ubx_proto33")]
+
+use ublox::{
+    osnma::{AuthStatus, FixAuthentication, NotAuthenticated},
+    proto33::{PacketRef, Proto33},
+    sec_osnma::{NmaStatus, OsnmaFlags},
+    UbxPacket,
+};
+
+#[path = "common/mod.rs"]
+mod common;
+use common::packet;
+
+fn sec_osnma(flags: OsnmaFlags, nma_status: u8, num_auth_svs: u8) -> [u8; 12] {
+    let mut payload = [0; 12];
+    payload[0] = 1;
+    payload[1] = flags.bits();
+    payload[2] = nma_status;
+    payload[3] = num_auth_svs;
+    payload[4..8].copy_from_slice(&1_000_u32.to_le_bytes());
+    payload
+}
+
+fn nav_pvt(auth_time: bool) -> [u8; 92] {
+    let mut payload = [0; 92];
+    if auth_time {
+        payload[79] = 0x20;
+    }
+    payload
+}
+
+const READY: OsnmaFlags = OsnmaFlags::ENABLED
+    .union(OsnmaFlags::KEY_VALID)
+    .union(OsnmaFlags::TIME_SYNC);
+
+#[test]
+fn sec_osnma_fields_are_parsed() {
+    let payload = sec_osnma(READY, 2, 7);
+    let UbxPacket::Proto33(PacketRef::SecOsnma(osnma)) = packet::<Proto33>(0x27, 0x0a, &payload)
+    else {
+        panic!("Not a UBX-SEC-OSNMA");
+    };
+    assert_eq!(osnma.version(), 1);
+    assert_eq!(osnma.flags(), READY);
+    assert_eq!(osnma.nma_status(), NmaStatus::Operational);
+    assert_eq!(osnma.num_auth_svs(), 7);
+    assert_eq!(osnma.itow(), 1_000);
+}
+
+#[test]
+fn nav_pvt_auth_time_flag() {
+    for auth_time in [false, true] {
+        let payload = nav_pvt(auth_time);
+        let UbxPacket::Proto33(PacketRef::NavPvt(pvt)) = packet::<Proto33>(0x01, 0x07, &payload)
+        else {
+            panic!("Not a UBX-NAV-PVT");
+        };
+        assert_eq!(pvt.flags3().auth_time(), auth_time);
+    }
+}
+
+#[test]
+fn fix_is_authenticated_once_all_conditions_hold() {
+    let mut auth = FixAuthentication::new();
+    assert_eq!(auth.status(), AuthStatus::Unknown);
+
+    let steps = [
+        (
+            sec_osnma(OsnmaFlags::empty(), 2, 0),
+            NotAuthenticated::Disabled,
+        ),
+        (
+            sec_osnma(READY, 1, 8),
+            NotAuthenticated::NotOperational(NmaStatus::Test),
+        ),
+        (
+            sec_osnma(OsnmaFlags::ENABLED | OsnmaFlags::TIME_SYNC, 2, 8),
+            NotAuthenticated::NoValidKey,
+        ),
+        (
+            sec_osnma(OsnmaFlags::ENABLED | OsnmaFlags::KEY_VALID, 2, 8),
+            NotAuthenticated::NoTimeSync,
+        ),
+        (
+            sec_osnma(READY, 2, 3),
+            NotAuthenticated::TooFewSatellites(3),
+        ),
+        (
+            sec_osnma(READY, 2, 8),
+            NotAuthenticated::TimeNotAuthenticated,
+        ),
+    ];
+    for (payload, reason) in steps {
+        assert_eq!(
+            auth.update(&packet::<Proto33>(0x27, 0x0a, &payload)),
+            Some(AuthStatus::NotAuthenticated(reason))
+        );
+    }
+
+    assert_eq!(
+        auth.update(&packet::<Proto33>(0x01, 0x07, &nav_pvt(true))),
+        Some(AuthStatus::Authenticated)
+    );
+    assert!(auth.is_authenticated());
+
+    // Losing the authenticated time on the next epoch drops the verdict
+    auth.update(&packet::<Proto33>(0x01, 0x07, &nav_pvt(false)));
+    assert_eq!(
+        auth.status(),
+        AuthStatus::NotAuthenticated(NotAuthenticated::TimeNotAuthenticated)
+    );
+}
+
+#[test]
+fn sec_osnma_time_flag_is_used_without_nav_pvt() {
+    let mut auth = FixAuthentication::with_min_auth_svs(1);
+    let payload = sec_osnma(READY | OsnmaFlags::TIME_AUTHENTICATED, 2, 1);
+    assert_eq!(
+        auth.update(&packet::<Proto33>(0x27, 0x0a, &payload)),
+        Some(AuthStatus::Authenticated)
+    );
+}
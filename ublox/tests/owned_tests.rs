@@ -0,0 +1,112 @@
+#![cfg(feature = "ubx_proto23")]
+
+use ublox::{
+    mon_ver::MonVerRef,
+    nav_sat::NavSatRef,
+    proto23::{PacketOwned, PacketRef, Proto23},
+    tim_tm2::TimTm2Ref,
+    ParserBuilder, ParserError, UbxPacket, UbxPacketOwned, UbxProtocol,
+};
+
+/// UBX-MON-VER payload with a single extension string, far shorter than its maximum length
+fn mon_ver() -> [u8; 70] {
+    let mut payload = [0; 70];
+    payload[..8].copy_from_slice(b"ROM CORE");
+    payload[30..38].copy_from_slice(b"00080000");
+    payload[40..52].copy_from_slice(b"PROTVER=23.0");
+    payload
+}
+
+#[test]
+fn owned_variable_size_packet_keeps_its_length() {
+    let payload = mon_ver();
+    let packet = Proto23::match_packet(0x0a, 0x04, &payload).unwrap();
+    let PacketRef::MonVer(ver) = &packet else {
+        panic!("Not a UBX-MON-VER");
+    };
+    let owned = ver.to_owned();
+    assert_eq!(owned.payload_len(), payload.len());
+    assert_eq!(owned.as_bytes(), payload);
+    assert_eq!(owned.software_version(), Ok("ROM CORE"));
+    assert!(owned.extension().eq(["PROTVER=23.0"]));
+
+    let PacketOwned::MonVer(owned) = packet.to_owned() else {
+        panic!("Not a UBX-MON-VER");
+    };
+    assert_eq!(owned.as_bytes(), payload);
+}
+
+#[test]
+fn owned_packets_outlive_the_parser_buffer() {
+    fn assert_send<T: Send + 'static>() {}
+    assert_send::<UbxPacketOwned>();
+
+    let mut frame = vec![0xb5, 0x62, 0x0a, 0x04, 70, 0];
+    frame.extend_from_slice(&mon_ver());
+    let (ck_a, ck_b) = ublox::ubx_checksum(&frame[2..]);
+    frame.extend_from_slice(&[ck_a, ck_b]);
+
+    let mut parser = ParserBuilder::new()
+        .with_protocol::<Proto23>()
+        .with_fixed_buffer::<256>();
+    let mut it = parser.consume_ubx(&frame);
+    let Some(Ok(packet @ UbxPacket::Proto23(_))) = it.next() else {
+        panic!("No packet");
+    };
+    let owned = packet.to_owned();
+    drop(it);
+
+    let handle = std::thread::spawn(move || owned);
+    let owned = handle.join().unwrap();
+    assert_eq!(owned.class_and_msg_id(), (0x0a, 0x04));
+    assert_eq!(owned.payload(), mon_ver());
+}
+
+#[test]
+fn ref_from_bare_payload() {
+    let payload = mon_ver();
+    let ver = MonVerRef::try_from(&payload[..]).unwrap();
+    assert_eq!(ver.hardware_version(), Ok("00080000"));
+
+    assert_eq!(
+        TimTm2Ref::try_from(&payload[..27]).unwrap_err(),
+        ParserError::InvalidPacketLen {
+            packet: "TimTm2",
+            expect: 28,
+            got: 27,
+        }
+    );
+}
+
+#[test]
+fn payload_longer_than_the_owned_buffer_is_rejected() {
+    // UBX-NAV-SAT with 200 satellites: a valid repeat count, but 2408 bytes of payload where
+    // the owned packet holds at most 1240
+    let mut payload = vec![0; 8 + 200 * 12];
+    payload[4] = 1;
+    payload[5] = 200;
+    assert_eq!(
+        NavSatRef::try_from(&payload[..]).unwrap_err(),
+        ParserError::InvalidPacketLen {
+            packet: "NavSat",
+            expect: 1240,
+            got: 2408,
+        }
+    );
+
+    let mut frame = vec![0xb5, 0x62, 0x01, 0x35];
+    frame.extend_from_slice(&(payload.len() as u16).to_le_bytes());
+    frame.extend_from_slice(&payload);
+    let (ck_a, ck_b) = ublox::ubx_checksum(&frame[2..]);
+    frame.extend_from_slice(&[ck_a, ck_b]);
+    let mut parser = ParserBuilder::new()
+        .with_protocol::<Proto23>()
+        .with_vec_buffer();
+    let mut it = parser.consume_ubx(&frame);
+    let Some(Ok(UbxPacket::Proto23(PacketRef::Unknown(unknown)))) = it.next() else {
+        panic!("Not an unknown packet");
+    };
+    assert_eq!(unknown.payload.len(), 2408);
+    let owned = UbxPacket::Proto23(PacketRef::Unknown(unknown)).to_owned();
+    assert_eq!(owned.payload().len(), 2408);
+}
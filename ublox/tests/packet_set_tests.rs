@@ -0,0 +1,108 @@
+#![cfg(all(feature = "ubx_proto23", feature = "std"))]
+
+use ublox::{
+    ack::AckAck, define_ubx_packet_set, nav_pvt::proto23::NavPvt, ubx_frame, ParserBuilder,
+    ParserError, PayloadField, UbxPacketMeta, UbxPacketRecv,
+};
+
+/// A proprietary message of class 0xf1, holding a little-endian counter
+struct VendorCounter;
+
+#[derive(Debug)]
+struct VendorCounterRef<'a>(&'a [u8]);
+
+impl VendorCounterRef<'_> {
+    fn counter(&self) -> u32 {
+        u32::from_le_bytes(self.0.try_into().unwrap())
+    }
+}
+
+struct VendorCounterOwned(u32);
+
+impl From<VendorCounterRef<'_>> for VendorCounterOwned {
+    fn from(packet: VendorCounterRef<'_>) -> Self {
+        Self(packet.counter())
+    }
+}
+
+impl UbxPacketMeta for VendorCounter {
+    const CLASS: u8 = 0xf1;
+    const ID: u8 = 0x01;
+    const NAME: &'static str = "VendorCounter";
+    const FIXED_PAYLOAD_LEN: Option<u16> = Some(4);
+    const MAX_PAYLOAD_LEN: u16 = 4;
+    const PAYLOAD_FIELDS: &'static [PayloadField] = &[];
+}
+
+impl UbxPacketRecv for VendorCounter {
+    type Ref<'a> = VendorCounterRef<'a>;
+    type Owned = VendorCounterOwned;
+
+    fn parse_payload(payload: &[u8]) -> Result<VendorCounterRef<'_>, ParserError> {
+        if payload.len() != 4 {
+            return Err(ParserError::InvalidPacketLen {
+                packet: "VendorCounter",
+                expect: 4,
+                got: payload.len(),
+            });
+        }
+        Ok(VendorCounterRef(payload))
+    }
+}
+
+define_ubx_packet_set! {
+    #[derive(Debug)]
+    enum AppPacket<'a> {
+        NavPvt(NavPvt),
+        AckAck(AckAck),
+        Counter(VendorCounter),
+    }
+}
+
+#[test]
+fn packet_set_matches_listed_packets_only() {
+    let mut stream = ubx_frame!(0xf1, 0x01, 7u32.to_le_bytes());
+    stream.extend(ubx_frame!(0x05, 0x01, [0x06, 0x00]));
+    stream.extend(ubx_frame!(0x0a, 0x04));
+    stream.extend(ubx_frame!(0xf1, 0x01, [0; 2]));
+
+    let mut parser = ParserBuilder::new().with_vec_buffer();
+    let mut it = parser.consume_ubx(&stream);
+    let mut packets = Vec::new();
+    while let Some(frame) = it.next_frame() {
+        let packet = AppPacket::from_frame(frame.unwrap().packet);
+        packets.push(match packet {
+            Ok(AppPacket::Counter(counter)) => {
+                format!("counter {}", VendorCounterOwned::from(counter).0)
+            },
+            Ok(AppPacket::AckAck(ack)) => format!("ack {:#04x}", ack.class()),
+            Ok(packet) => format!("other {:02x?}", packet.class_and_msg_id()),
+            Err(err) => format!("{err:?}"),
+        });
+    }
+    assert_eq!(
+        packets,
+        [
+            "counter 7",
+            "ack 0x06",
+            "other (0a, 04)",
+            "InvalidPacketLen { packet: \"VendorCounter\", expect: 4, got: 2 }",
+        ]
+    );
+}
+
+#[test]
+fn packet_set_lists_its_messages() {
+    let messages: Vec<_> = AppPacket::MESSAGES
+        .iter()
+        .map(|meta| (meta.name, meta.class, meta.msg_id, meta.fixed_payload_len))
+        .collect();
+    assert_eq!(
+        messages,
+        [
+            ("NavPvt", 0x01, 0x07, Some(92)),
+            ("AckAck", 0x05, 0x01, Some(2)),
+            ("VendorCounter", 0xf1, 0x01, Some(4)),
+        ]
+    );
+}
@@ -676,6 +676,48 @@ fn test_parse_cfg_nav5_proto33() {
     assert!(found);
 }
 
+#[cfg(feature = "ubx_proto23")]
+#[test]
+fn test_parse_cfg_navx5_versions_proto23() {
+    use ublox::cfg_navx5::{AopCfg, CfgNavX5Builder, CfgNavX5V3Builder, SigAttenCompMode};
+    use ublox::proto23::{PacketRef, Proto23};
+
+    let v2 = CfgNavX5Builder::default()
+        .set_version(2)
+        .set_ack_aiding(true)
+        .set_sig_atten_comp_mode(SigAttenCompMode::MaxCno(35))
+        .into_packet_bytes();
+    let v3 = CfgNavX5V3Builder::default()
+        .set_version(3)
+        .set_aop_cfg(AopCfg::USE_AOP)
+        .set_aop_orb_max_err(60)
+        .set_sig_atten_comp_mode(SigAttenCompMode::Automatic)
+        .into_packet_bytes();
+    let bytes = [&v2[..], &v3[..]].concat();
+
+    let mut parser = Parser::<_, Proto23>::default();
+    let mut it = parser.consume_ubx(&bytes);
+    match it.next() {
+        Some(Ok(UbxPacket::Proto23(PacketRef::CfgNavX5(pack)))) => {
+            assert_eq!(2, pack.version());
+            assert!(pack.ack_aiding());
+            assert!(!pack.ini_fix_3d());
+            assert_eq!(SigAttenCompMode::MaxCno(35), pack.sig_atten_comp_mode());
+        },
+        _ => panic!(),
+    }
+    match it.next() {
+        Some(Ok(UbxPacket::Proto23(PacketRef::CfgNavX5V3(pack)))) => {
+            assert_eq!(3, pack.version());
+            assert_eq!(AopCfg::USE_AOP, pack.aop_cfg());
+            assert_eq!(60, pack.aop_orb_max_err());
+            assert_eq!(SigAttenCompMode::Automatic, pack.sig_atten_comp_mode());
+        },
+        _ => panic!(),
+    }
+    assert!(it.next().is_none());
+}
+
 #[cfg(feature = "serde")]
 const RET_ESF_MEAS_SERIALIZE: [u8; 24] = [
     181, 98, 16, 2, 16, 0, 243, 121, 129, 1, 24, 8, 0, 0, 77, 100, 0, 11, 211, 148, 129, 1, 213,
@@ -782,6 +824,50 @@ fn test_esf_meas_serialize_proto23() {
     }
     assert!(found);
 }
+#[test]
+#[cfg(feature = "ubx_proto23")]
+#[cfg(feature = "serde")]
+fn test_owned_packets_serialize_like_refs() {
+    use ublox::proto23::{PacketOwned, PacketRef, Proto23};
+
+    let mut unknown = vec![0xb5, 0x62, 0x7f, 0x01, 0x02, 0x00, 0xaa, 0xbb];
+    let (ck_a, ck_b) = ublox::ubx_checksum(&unknown[2..]);
+    unknown.extend_from_slice(&[ck_a, ck_b]);
+
+    let mut parser = Parser::<_, Proto23>::default();
+    let data = [&RET_ESF_MEAS_SERIALIZE[..], &unknown].concat();
+    let mut it = parser.consume_ubx(&data);
+    let mut count = 0;
+    while let Some(pack) = it.next() {
+        let Ok(packet @ UbxPacket::Proto23(_)) = pack else {
+            panic!();
+        };
+        let owned = packet.to_owned();
+        let expected = serde_json::to_value(&packet).unwrap();
+        assert_eq!(serde_json::to_value(&owned).unwrap(), expected);
+
+        let (UbxPacket::Proto23(packet_ref), ublox::UbxPacketOwned::Proto23(packet_owned)) =
+            (packet, owned)
+        else {
+            panic!();
+        };
+        match (packet_ref, packet_owned) {
+            (PacketRef::EsfMeas(esf_meas_ref), PacketOwned::EsfMeas(esf_meas_owned)) => {
+                assert_eq!(
+                    serde_json::to_value(&esf_meas_owned).unwrap(),
+                    serde_json::to_value(esf_meas_ref).unwrap()
+                );
+            },
+            (PacketRef::Unknown(_), PacketOwned::Unknown(_)) => {
+                assert_eq!(expected["payload"], serde_json::json!([0xaa, 0xbb]));
+            },
+            _ => panic!(),
+        }
+        count += 1;
+    }
+    assert_eq!(count, 2);
+}
+
 #[test]
 #[cfg(feature = "ubx_proto27")]
 #[cfg(feature = "serde")]
@@ -1346,3 +1432,1024 @@ fn test_ack_ack_to_owned_can_be_moved_proto33() {
         _ => panic!(),
     };
 }
+
+// RTCM3 1005 (stationary RTK reference station ARP), including its CRC-24Q trailer
+const RTCM_1005_BYTES: [u8; 25] = [
+    0xd3, 0x00, 0x13, 0x3e, 0xd7, 0xd3, 0x02, 0x02, 0x98, 0x0e, 0xde, 0xef, 0x34, 0xb4, 0xbd, 0x62,
+    0xac, 0x09, 0x41, 0x98, 0x6f, 0x33, 0x36, 0x0b, 0x98,
+];
+
+const NMEA_GGA_BYTES: &[u8] =
+    b"$GNGGA,092725.00,4717.11399,N,00833.91590,E,1,08,1.01,499.6,M,48.0,M,,*45\r\n";
+
+fn test_util_mixed_stream() -> Vec<u8> {
+    let mut data = vec![];
+    data.extend_from_slice(NMEA_GGA_BYTES);
+    data.extend_from_slice(&ACK_ACK_BYTES);
+    data.extend_from_slice(&RTCM_1005_BYTES);
+    data.extend_from_slice(NMEA_GGA_BYTES);
+    data.extend_from_slice(&ACK_ACK_BYTES);
+    data
+}
+
+#[cfg(feature = "ubx_proto23")]
+fn extract_any_frames_proto23<T: ublox::UnderlyingBuffer>(
+    mut it: ublox::UbxRtcmNmeaParserIter<T, ublox::proto23::Proto23>,
+    frames: &mut Vec<(&'static str, Vec<u8>)>,
+) {
+    use ublox::AnyPacketRef;
+
+    while let Some(frame) = it.next() {
+        match frame {
+            Ok(AnyPacketRef::Ubx(packet)) => {
+                assert!(matches!(
+                    packet,
+                    UbxPacket::Proto23(ublox::proto23::PacketRef::AckAck(_))
+                ));
+                frames.push(("ubx", ACK_ACK_BYTES.to_vec()));
+            },
+            Ok(AnyPacketRef::Rtcm(rtcm)) => frames.push(("rtcm", rtcm.data.to_vec())),
+            Ok(AnyPacketRef::Nmea(nmea)) => frames.push(("nmea", nmea.data.to_vec())),
+            Ok(AnyPacketRef::Spartn(_)) => panic!("SPARTN frames were not requested"),
+            Err(err) => panic!("Unexpected error {err:?}"),
+        }
+    }
+}
+
+#[cfg(feature = "ubx_proto23")]
+fn expected_mixed_frames() -> Vec<(&'static str, Vec<u8>)> {
+    vec![
+        ("nmea", NMEA_GGA_BYTES.to_vec()),
+        ("ubx", ACK_ACK_BYTES.to_vec()),
+        ("rtcm", RTCM_1005_BYTES.to_vec()),
+        ("nmea", NMEA_GGA_BYTES.to_vec()),
+        ("ubx", ACK_ACK_BYTES.to_vec()),
+    ]
+}
+
+#[cfg(feature = "ubx_proto23")]
+#[test]
+fn test_parse_mixed_ubx_rtcm_nmea_in_one_go_proto23() {
+    let data = test_util_mixed_stream();
+    let mut parser = Parser::<_, ublox::proto23::Proto23>::default();
+
+    let mut frames = vec![];
+    extract_any_frames_proto23(parser.consume_ubx_rtcm_nmea(&data), &mut frames);
+
+    assert_eq!(frames, expected_mixed_frames());
+    assert!(parser.is_buffer_empty());
+}
+
+#[cfg(feature = "ubx_proto23")]
+#[test]
+fn test_parse_mixed_ubx_rtcm_nmea_byte_by_byte_proto23() {
+    let data = test_util_mixed_stream();
+    let mut parser = Parser::<_, ublox::proto23::Proto23>::default();
+
+    let mut frames = vec![];
+    for byte in &data {
+        extract_any_frames_proto23(
+            parser.consume_ubx_rtcm_nmea(core::slice::from_ref(byte)),
+            &mut frames,
+        );
+    }
+
+    assert_eq!(frames, expected_mixed_frames());
+}
+
+#[cfg(feature = "ubx_proto23")]
+#[test]
+fn test_parse_rtcm_frame_proto23() {
+    use ublox::{AnyPacketRef, RtcmFrameRef};
+
+    let mut parser = Parser::<_, ublox::proto23::Proto23>::default();
+    let mut it = parser.consume_ubx_rtcm(&RTCM_1005_BYTES);
+    let Some(Ok(AnyPacketRef::Rtcm(rtcm))) = it.next() else {
+        panic!("Expected an RTCM3 frame");
+    };
+    assert_eq!(
+        rtcm.frame(),
+        Some(RtcmFrameRef {
+            msg_type: 1005,
+            payload: &RTCM_1005_BYTES[3..22],
+        })
+    );
+}
+
+#[cfg(feature = "ubx_proto23")]
+#[test]
+fn test_parse_rtcm_invalid_crc_proto23() {
+    use ublox::AnyPacketRef;
+
+    let mut corrupted = RTCM_1005_BYTES;
+    corrupted[10] ^= 0x01;
+    let mut data = corrupted.to_vec();
+    // The corrupted frame contains a 0xd3 byte announcing a 514 byte message, provide enough
+    // data for that false preamble to be rejected as well
+    data.extend_from_slice(&[0; 520]);
+    data.extend_from_slice(&RTCM_1005_BYTES);
+
+    let mut parser = Parser::<_, ublox::proto23::Proto23>::default();
+    let mut it = parser.consume_ubx_rtcm_nmea(&data);
+    assert!(matches!(
+        it.next(),
+        Some(Err(ParserError::InvalidRtcmCrc {
+            expect: 0x360b98,
+            ..
+        }))
+    ));
+    // The parser resynchronizes on the following valid frame
+    loop {
+        match it.next() {
+            Some(Ok(AnyPacketRef::Rtcm(rtcm))) => {
+                assert_eq!(rtcm.data, RTCM_1005_BYTES);
+                break;
+            },
+            Some(_) => {},
+            None => panic!("Valid RTCM3 frame not found"),
+        }
+    }
+}
+
+// SPARTN HPAC frame: 16-bit time tag 0x1234, solution 11/2, 5 byte payload and CRC-8
+const SPARTN_HPAC_BYTES: [u8; 14] = [
+    0x73, 0x02, 0x02, 0x85, 0x00, 0x91, 0xa0, 0xb2, 0x01, 0x02, 0x03, 0x04, 0x05, 0xd0,
+];
+
+// Encrypted SPARTN OCB frame: 32-bit time tag, 3 byte payload, 8 bytes of embedded
+// authentication data and CRC-24
+const SPARTN_OCB_ENCRYPTED_BYTES: [u8; 26] = [
+    0x73, 0x00, 0x01, 0xe4, 0x28, 0xd1, 0x59, 0xe2, 0x69, 0x51, 0x58, 0x58, 0x09, 0x08, 0x07, 0x55,
+    0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x55, 0x5f, 0x36, 0x6f,
+];
+
+#[cfg(feature = "ubx_proto23")]
+fn test_util_spartn_stream() -> Vec<u8> {
+    let mut data = vec![];
+    data.extend_from_slice(&ACK_ACK_BYTES);
+    data.extend_from_slice(&SPARTN_HPAC_BYTES);
+    data.extend_from_slice(NMEA_GGA_BYTES);
+    data.extend_from_slice(&SPARTN_OCB_ENCRYPTED_BYTES);
+    data.extend_from_slice(&RTCM_1005_BYTES);
+    data
+}
+
+#[cfg(feature = "ubx_proto23")]
+fn extract_spartn_frames_proto23<T: ublox::UnderlyingBuffer>(
+    mut it: ublox::UbxRtcmNmeaParserIter<T, ublox::proto23::Proto23>,
+    frames: &mut Vec<Vec<u8>>,
+    others: &mut usize,
+) {
+    use ublox::AnyPacketRef;
+
+    while let Some(frame) = it.next() {
+        match frame {
+            Ok(AnyPacketRef::Spartn(spartn)) => frames.push(spartn.data.to_vec()),
+            Ok(_) => *others += 1,
+            Err(err) => panic!("Unexpected error {err:?}"),
+        }
+    }
+}
+
+#[cfg(feature = "ubx_proto23")]
+#[test]
+fn test_parse_spartn_in_one_go_proto23() {
+    let data = test_util_spartn_stream();
+    let mut parser = Parser::<_, ublox::proto23::Proto23>::default();
+
+    let (mut frames, mut others) = (vec![], 0);
+    extract_spartn_frames_proto23(
+        parser.consume_ubx_rtcm_nmea_spartn(&data),
+        &mut frames,
+        &mut others,
+    );
+
+    assert_eq!(
+        frames,
+        [
+            SPARTN_HPAC_BYTES.to_vec(),
+            SPARTN_OCB_ENCRYPTED_BYTES.to_vec()
+        ]
+    );
+    assert_eq!(others, 3);
+    assert!(parser.is_buffer_empty());
+}
+
+#[cfg(feature = "ubx_proto23")]
+#[test]
+fn test_parse_spartn_byte_by_byte_proto23() {
+    let data = test_util_spartn_stream();
+    let mut parser = Parser::<_, ublox::proto23::Proto23>::default();
+
+    let (mut frames, mut others) = (vec![], 0);
+    for byte in &data {
+        extract_spartn_frames_proto23(
+            parser.consume_ubx_rtcm_nmea_spartn(core::slice::from_ref(byte)),
+            &mut frames,
+            &mut others,
+        );
+    }
+
+    assert_eq!(
+        frames,
+        [
+            SPARTN_HPAC_BYTES.to_vec(),
+            SPARTN_OCB_ENCRYPTED_BYTES.to_vec()
+        ]
+    );
+    assert_eq!(others, 3);
+}
+
+#[cfg(feature = "ubx_proto23")]
+#[test]
+fn test_parse_spartn_ignored_without_opt_in_proto23() {
+    let data = test_util_spartn_stream();
+    let mut parser = Parser::<_, ublox::proto23::Proto23>::default();
+
+    let (mut frames, mut others) = (vec![], 0);
+    extract_spartn_frames_proto23(
+        parser.consume_ubx_rtcm_nmea(&data),
+        &mut frames,
+        &mut others,
+    );
+
+    assert!(frames.is_empty());
+    assert_eq!(others, 3);
+}
+
+#[cfg(feature = "ubx_proto23")]
+#[test]
+fn test_parse_spartn_invalid_crc_proto23() {
+    use ublox::AnyPacketRef;
+
+    let mut bad_frame_crc = SPARTN_HPAC_BYTES;
+    bad_frame_crc[3] ^= 0x01;
+    let mut bad_crc = SPARTN_OCB_ENCRYPTED_BYTES;
+    bad_crc[10] ^= 0x01;
+    let mut data = vec![];
+    data.extend_from_slice(&bad_frame_crc);
+    data.extend_from_slice(&bad_crc);
+    data.extend_from_slice(&SPARTN_HPAC_BYTES);
+
+    let mut parser = Parser::<_, ublox::proto23::Proto23>::default();
+    let mut it = parser.consume_ubx_rtcm_nmea_spartn(&data);
+    assert!(matches!(
+        it.next(),
+        Some(Err(ParserError::InvalidSpartnFrameCrc {
+            expect: 4,
+            got: 5
+        }))
+    ));
+    assert!(matches!(
+        it.next(),
+        Some(Err(ParserError::InvalidSpartnCrc { .. }))
+    ));
+    // The parser resynchronizes on the following valid frame
+    loop {
+        match it.next() {
+            Some(Ok(AnyPacketRef::Spartn(spartn))) => {
+                assert_eq!(spartn.data, SPARTN_HPAC_BYTES);
+                break;
+            },
+            Some(_) => {},
+            None => panic!("Valid SPARTN frame not found"),
+        }
+    }
+}
+
+#[test]
+fn test_spartn_frame_header() {
+    use ublox::{SpartnCrcType, SpartnFrameRef, SpartnTimeTag};
+
+    let hpac = SpartnFrameRef {
+        data: &SPARTN_HPAC_BYTES,
+    };
+    assert_eq!(hpac.msg_type(), 1);
+    assert_eq!(hpac.msg_subtype(), 0);
+    assert_eq!(hpac.payload_len(), 5);
+    assert!(!hpac.encrypted());
+    assert_eq!(hpac.crc_type(), SpartnCrcType::Crc8);
+    assert_eq!(hpac.time_tag(), SpartnTimeTag::Truncated(0x1234));
+    assert_eq!(hpac.solution_id(), 11);
+    assert_eq!(hpac.solution_processor_id(), 2);
+    assert_eq!(hpac.encryption_id(), None);
+    assert_eq!(hpac.payload(), [1, 2, 3, 4, 5]);
+
+    let ocb = SpartnFrameRef {
+        data: &SPARTN_OCB_ENCRYPTED_BYTES,
+    };
+    assert_eq!(ocb.msg_type(), 0);
+    assert_eq!(ocb.msg_subtype(), 2);
+    assert!(ocb.encrypted());
+    assert_eq!(ocb.crc_type(), SpartnCrcType::Crc24);
+    assert_eq!(ocb.time_tag(), SpartnTimeTag::Full(0x1A2B3C4D));
+    assert_eq!(ocb.solution_id(), 21);
+    assert_eq!(ocb.solution_processor_id(), 1);
+    assert_eq!(ocb.encryption_id(), Some(5));
+    assert_eq!(ocb.encryption_sequence(), Some(33));
+    assert_eq!(ocb.payload(), [9, 8, 7]);
+}
+
+#[cfg(feature = "ubx_proto27")]
+#[test]
+fn test_esf_status_faulty_sensors_proto27() {
+    use ublox::esf_status::{EsfSensorFault, EsfSensorType};
+
+    #[rustfmt::skip]
+    let payload: [u8; 28] = [
+        0x10, 0x27, 0x00, 0x00, // itow
+        0x02, // version
+        0x00, 0x00, // init_status1, init_status2
+        0x00, 0x00, 0x00, 0x00, 0x00, // reserved1
+        0x01, // fusion_mode
+        0x00, 0x00, // reserved2
+        0x03, // num_sens
+        0xC5, 0x00, 0x64, 0x0C, // GyroZ: missing + noisy
+        0xD0, 0x00, 0x64, 0x00, // AccX: no faults
+        0xCB, 0x00, 0x0A, 0x12, // Speed: bad time tag + reserved bit
+    ];
+    let mut frame = vec![UBX_SYNC_CHAR_1, UBX_SYNC_CHAR_2, 0x10, 0x10, 28, 0];
+    frame.extend_from_slice(&payload);
+    let (mut ck_a, mut ck_b) = (0u8, 0u8);
+    for b in &frame[2..] {
+        ck_a = ck_a.wrapping_add(*b);
+        ck_b = ck_b.wrapping_add(ck_a);
+    }
+    frame.extend_from_slice(&[ck_a, ck_b]);
+
+    let mut parser = Parser::<_, ublox::proto27::Proto27>::default();
+    let mut it = parser.consume_ubx(&frame);
+    let Some(Ok(UbxPacket::Proto27(ublox::proto27::PacketRef::EsfStatus(status)))) = it.next()
+    else {
+        panic!("Expected a UBX-ESF-STATUS packet");
+    };
+
+    let reports: Vec<_> = status.faulty_sensors().collect();
+    assert_eq!(reports.len(), 2);
+    assert!(matches!(reports[0].sensor_type(), EsfSensorType::GyroZ));
+    assert_eq!(
+        reports[0].iter_faults().collect::<Vec<_>>(),
+        [
+            EsfSensorFault::MissingMeasurement,
+            EsfSensorFault::NoisyMeasurement
+        ]
+    );
+    assert_eq!(
+        reports[0].to_string(),
+        "GyroZ: missing measurement, noisy measurement"
+    );
+    assert!(matches!(reports[1].sensor_type(), EsfSensorType::Speed));
+    assert_eq!(
+        reports[1].iter_faults().collect::<Vec<_>>(),
+        [EsfSensorFault::BadTimeTag]
+    );
+}
+
+#[cfg(feature = "ubx_proto23")]
+#[test]
+fn test_parser_stats_proto23() {
+    let mut bad_pack = FULL_ACK_ACK_PACK;
+    bad_pack[bad_pack.len() - 3] = 5;
+    let mut data = vec![0x00, 0x11];
+    data.extend_from_slice(&test_util_mixed_stream());
+    data.extend_from_slice(&bad_pack);
+
+    let mut parser = Parser::<_, ublox::proto23::Proto23>::default();
+    let mut it = parser.consume_ubx_rtcm_nmea(&data);
+    let mut errors = 0;
+    while let Some(frame) = it.next() {
+        errors += usize::from(frame.is_err());
+    }
+    assert_eq!(errors, 1);
+    drop(it);
+
+    let stats = parser.stats();
+    assert_eq!(stats.bytes_consumed, data.len() as u64);
+    assert_eq!(stats.ubx_frames(0x05), 2);
+    assert_eq!(stats.ubx_frames(0x01), 0);
+    assert_eq!(stats.ubx_frames_total(), 2);
+    assert_eq!(stats.rtcm_frames, 1);
+    assert_eq!(stats.nmea_sentences, 2);
+    assert_eq!(stats.spartn_frames, 0);
+    assert_eq!(stats.checksum_failures, 1);
+    assert_eq!(stats.resyncs, 1);
+    // Leading garbage plus the whole corrupted packet
+    assert_eq!(stats.bytes_discarded, 2 + bad_pack.len() as u64);
+
+    parser.reset_stats();
+    assert_eq!(*parser.stats(), ublox::ParserStats::default());
+}
+
+#[cfg(feature = "ubx_proto23")]
+#[test]
+fn test_parser_stats_byte_by_byte_proto23() {
+    let data = test_util_spartn_stream();
+    let mut parser = Parser::<_, ublox::proto23::Proto23>::default();
+
+    let (mut frames, mut others) = (vec![], 0);
+    for byte in &data {
+        extract_spartn_frames_proto23(
+            parser.consume_ubx_rtcm_nmea_spartn(core::slice::from_ref(byte)),
+            &mut frames,
+            &mut others,
+        );
+    }
+
+    let stats = parser.stats();
+    assert_eq!(stats.bytes_consumed, data.len() as u64);
+    assert_eq!(stats.spartn_frames, 2);
+    assert_eq!(
+        stats.ubx_frames_total() + stats.rtcm_frames + stats.nmea_sentences,
+        3
+    );
+    assert_eq!(stats.bytes_discarded, 0);
+    assert_eq!(stats.resyncs, 0);
+}
+
+#[cfg(all(feature = "ubx_proto23", feature = "serde"))]
+#[test]
+fn test_any_packet_serialize_proto23() {
+    let mut data = RET_ESF_MEAS_SERIALIZE.to_vec();
+    data.extend_from_slice(&RTCM_1005_BYTES);
+    data.extend_from_slice(NMEA_GGA_BYTES);
+    data.extend_from_slice(&SPARTN_HPAC_BYTES);
+
+    let mut parser = Parser::<_, ublox::proto23::Proto23>::default();
+    let mut it = parser.consume_ubx_rtcm_nmea_spartn(&data);
+    let mut values = vec![];
+    while let Some(frame) = it.next() {
+        values.push(serde_json::to_value(frame.unwrap()).unwrap());
+    }
+
+    assert_eq!(values.len(), 4);
+    assert_eq!(values[0]["Ubx"]["class"], 0x10);
+    assert_eq!(values[0]["Ubx"]["msg_id"], 0x02);
+    assert_eq!(values[0]["Ubx"]["calib_tag"], 25269459);
+    assert_eq!(
+        values[1],
+        serde_json::json!({ "Rtcm": { "data": RTCM_1005_BYTES.to_vec() } })
+    );
+    assert_eq!(
+        values[2],
+        serde_json::json!({ "Nmea": { "data": core::str::from_utf8(NMEA_GGA_BYTES).unwrap() } })
+    );
+    assert_eq!(
+        values[3],
+        serde_json::json!({ "Spartn": { "data": SPARTN_HPAC_BYTES.to_vec() } })
+    );
+}
+
+#[cfg(all(feature = "ubx_proto23", feature = "postcard"))]
+#[test]
+fn test_compact_owned_round_trip_proto23() {
+    use ublox::{compact::CompactPacket, proto23::Proto23};
+
+    let mut parser = Parser::<_, Proto23>::default();
+    let mut it = parser.consume_ubx(&RET_ESF_MEAS_SERIALIZE);
+    let Some(Ok(UbxPacket::Proto23(packet))) = it.next() else {
+        panic!("Expected a UBX-ESF-MEAS packet");
+    };
+    let owned = packet.to_owned();
+
+    let mut buf = [0u8; 64];
+    let encoded = CompactPacket::from(&packet).encode(&mut buf).unwrap();
+    // class, msg_id, payload length and the 16 byte payload
+    assert_eq!(encoded.len(), 3 + 16);
+    assert_eq!(encoded[..3], [0x10, 0x02, 16]);
+
+    let decoded = CompactPacket::decode(encoded).unwrap();
+    assert_eq!(decoded, CompactPacket::from(&packet));
+    let ublox::proto23::PacketRef::EsfMeas(meas) = decoded.parse::<Proto23>().unwrap() else {
+        panic!("Expected a UBX-ESF-MEAS packet");
+    };
+    assert_eq!(meas.itow(), 25262579);
+
+    let from_owned = CompactPacket::from(&owned);
+    assert_eq!((from_owned.class, from_owned.msg_id), (0x10, 0x02));
+    assert_eq!(from_owned.payload[..16], *packet.payload());
+}
+
+#[cfg(feature = "ubx_proto23")]
+#[test]
+fn test_discard_callback_proto23() {
+    use std::sync::{Arc, Mutex};
+    use ublox::{DiscardReason, DiscardedBytes};
+
+    let discarded = Arc::new(Mutex::new(Vec::new()));
+    let record = {
+        let discarded = Arc::clone(&discarded);
+        move |bytes: &DiscardedBytes| {
+            let bytes = (bytes.reason(), bytes.iter().collect::<Vec<_>>());
+            discarded.lock().unwrap().push(bytes);
+        }
+    };
+
+    let mut bad_pack = FULL_ACK_ACK_PACK;
+    bad_pack[bad_pack.len() - 3] = 5;
+    let mut parser = Parser::<_, ublox::proto23::Proto23>::default();
+    parser.set_discard_callback(Some(Box::new(record)));
+
+    // Split the corrupted packet across two calls, so the discarded bytes are split between
+    // the parser's buffer and the new data
+    let mut first = vec![0x00, 0x11];
+    first.extend_from_slice(&bad_pack[..4]);
+    for data in [&first[..], &bad_pack[4..], &FULL_ACK_ACK_PACK[..]] {
+        let mut it = parser.consume_ubx(data);
+        while it.next().is_some() {}
+    }
+
+    let discarded = discarded.lock().unwrap();
+    assert_eq!(
+        *discarded,
+        [
+            (DiscardReason::Garbage, vec![0x00, 0x11]),
+            // The whole frame is reported, but only its sync chars are dropped
+            (DiscardReason::InvalidChecksum, bad_pack.to_vec()),
+            (DiscardReason::Garbage, bad_pack[2..].to_vec()),
+        ]
+    );
+    assert_eq!(parser.stats().checksum_failures, 1);
+    assert_eq!(parser.stats().ubx_frames(0x05), 1);
+}
+
+#[cfg(all(feature = "ubx_proto23", feature = "serde"))]
+#[test]
+fn test_projection_proto23() {
+    use ublox::{
+        esf_meas::EsfMeas,
+        nav_pvt::proto23::NavPvt,
+        projection::{Field, Projection},
+        UbxUnknownPacketRef,
+    };
+
+    let projection = Projection::new(&[Field::CLASS, EsfMeas::FIELD_DATA, NavPvt::FIELD_LAT]);
+
+    let mut parser = Parser::<_, ublox::proto23::Proto23>::default();
+    let mut it = parser.consume_ubx(&RET_ESF_MEAS_SERIALIZE);
+    let Some(Ok(packet)) = it.next() else {
+        panic!("Expected a UBX-ESF-MEAS packet");
+    };
+    assert_eq!(
+        serde_json::to_value(projection.apply(&packet)).unwrap(),
+        serde_json::json!({
+            "class": 16,
+            "data": [{ "data_type": "Speed", "data_field": 25677 }]
+        })
+    );
+
+    let unknown = UbxUnknownPacketRef {
+        payload: &[1, 2],
+        class: 0x99,
+        msg_id: 0x01,
+    };
+    assert_eq!(
+        serde_json::to_value(projection.apply(&unknown)).unwrap(),
+        serde_json::json!({ "class": 0x99 })
+    );
+
+    assert!(serde_json::to_value(projection.apply(&42u8)).is_err());
+}
+
+#[cfg(feature = "ubx_proto23")]
+#[test]
+fn test_unknown_packet_frame_reconstruction_proto23() {
+    use ublox::{proto23::PacketRef, ubx_checksum, UbxPacketCreator};
+
+    let mut frame = vec![
+        UBX_SYNC_CHAR_1,
+        UBX_SYNC_CHAR_2,
+        0x99,
+        0x01,
+        0x03,
+        0x00,
+        1,
+        2,
+        3,
+    ];
+    let (ck_a, ck_b) = ubx_checksum(&frame[2..]);
+    frame.extend_from_slice(&[ck_a, ck_b]);
+
+    let mut parser = Parser::<_, ublox::proto23::Proto23>::default();
+    let mut it = parser.consume_ubx(&frame);
+    let Some(Ok(UbxPacket::Proto23(PacketRef::Unknown(unknown)))) = it.next() else {
+        panic!("Expected an unknown packet");
+    };
+    assert_eq!((unknown.class, unknown.msg_id), (0x99, 0x01));
+    assert_eq!(unknown.payload, [1, 2, 3]);
+    assert_eq!(unknown.checksum(), (ck_a, ck_b));
+    assert_eq!(unknown.frame_len(), frame.len());
+    assert_eq!(unknown.to_frame_bytes(), frame);
+
+    let mut written = Vec::new();
+    unknown.create_packet(&mut written).unwrap();
+    assert_eq!(written, frame);
+}
+
+#[cfg(feature = "ubx_proto23")]
+#[test]
+fn test_accept_invalid_checksums_proto23() {
+    use ublox::{proto23::Proto23, ParserBuilder};
+
+    let mut bad_pack = FULL_ACK_ACK_PACK;
+    bad_pack[bad_pack.len() - 3] = 5;
+    let mut data = bad_pack.to_vec();
+    data.extend_from_slice(&FULL_ACK_ACK_PACK);
+
+    let mut parser = ParserBuilder::new()
+        .with_protocol::<Proto23>()
+        .accept_invalid_checksums(true)
+        .with_vec_buffer();
+    let mut it = parser.consume_ubx(&data);
+    let mut frames = vec![];
+    while let Some(Ok(checked)) = it.next_checked() {
+        let UbxPacket::Proto23(ublox::proto23::PacketRef::AckAck(ack)) = checked.packet else {
+            panic!("Expected a UBX-ACK-ACK packet");
+        };
+        frames.push((ack.class(), ack.msg_id(), checked.checksum_valid));
+    }
+    assert_eq!(frames, [(6, 5, false), (6, 1, true)]);
+    drop(it);
+    assert_eq!(parser.stats().checksum_failures, 1);
+    assert_eq!(parser.stats().bytes_discarded, 0);
+
+    // Only flagged by `next_checked()`, `next()` stays strict
+    let mut it = parser.consume_ubx(&data);
+    assert!(matches!(
+        it.next(),
+        Some(Err(ParserError::InvalidChecksum { .. }))
+    ));
+    assert!(matches!(it.next(), Some(Ok(_))));
+    assert!(it.next().is_none());
+
+    // Rejected by default
+    let mut parser = ParserBuilder::new()
+        .with_protocol::<Proto23>()
+        .with_vec_buffer();
+    let mut it = parser.consume_ubx_rtcm_nmea(&data);
+    assert!(matches!(
+        it.next_checked(),
+        Some(Err(ParserError::InvalidChecksum { .. }))
+    ));
+    assert!(matches!(
+        it.next_checked(),
+        Some(Ok(ublox::CheckedPacket {
+            packet: ublox::AnyPacketRef::Ubx(_),
+            checksum_valid: true,
+            ..
+        }))
+    ));
+}
+
+#[cfg(feature = "ubx_proto23")]
+#[test]
+fn test_filter_proto23() {
+    use ublox::{proto23::Proto23, AnyPacketRef, ParserBuilder};
+
+    let esf_meas = [
+        0xb5, 0x62, 0x10, 0x02, 0x10, 0x00, 0xf3, 0x79, 0x81, 0x01, 0x18, 0x08, 0x00, 0x00, 0x4d,
+        0x64, 0x00, 0x0b, 0xd3, 0x94, 0x81, 0x01, 0xd5, 0xc6,
+    ];
+    let nmea = b"$GNGLL,,,,,,V,N*7A\r\n";
+    let mut data = esf_meas.to_vec();
+    data.extend_from_slice(&FULL_ACK_ACK_PACK);
+    data.extend_from_slice(nmea);
+
+    // The filtered out frame does not even have to fit into the buffer
+    let mut parser = ParserBuilder::new()
+        .with_protocol::<Proto23>()
+        .with_filter(&[(0x05, 0x01)])
+        .with_fixed_buffer::<16>();
+    let mut it = parser.consume_ubx_rtcm_nmea(&data);
+    assert!(matches!(
+        it.next(),
+        Some(Ok(AnyPacketRef::Ubx(UbxPacket::Proto23(
+            ublox::proto23::PacketRef::AckAck(_)
+        ))))
+    ));
+    assert!(matches!(it.next(), Some(Ok(AnyPacketRef::Nmea(_)))));
+    assert!(it.next().is_none());
+    drop(it);
+
+    let stats = parser.stats();
+    assert_eq!(stats.ubx_frames(0x10), 1);
+    assert_eq!(stats.ubx_frames(0x05), 1);
+    assert_eq!(stats.bytes_discarded, 0);
+}
+
+#[cfg(feature = "ubx_proto23")]
+#[test]
+fn test_filter_invalid_checksum_proto23() {
+    use ublox::{proto23::Proto23, ParserBuilder};
+
+    let mut esf_meas = [
+        0xb5, 0x62, 0x10, 0x02, 0x10, 0x00, 0xf3, 0x79, 0x81, 0x01, 0x18, 0x08, 0x00, 0x00, 0x4d,
+        0x64, 0x00, 0x0b, 0xd3, 0x94, 0x81, 0x01, 0xd5, 0xc6,
+    ];
+    esf_meas[23] ^= 0xff;
+    let mut data = esf_meas.to_vec();
+    data.extend_from_slice(&FULL_ACK_ACK_PACK);
+
+    let mut parser = ParserBuilder::new()
+        .with_protocol::<Proto23>()
+        .with_filter(&[(0x05, 0x01)])
+        .accept_invalid_checksums(true)
+        .with_fixed_buffer::<64>();
+    // The corrupted frame is reported but, filtered out, not yielded
+    let mut it = parser.consume_ubx(&data);
+    assert!(matches!(
+        it.next_checked(),
+        Some(Err(ParserError::InvalidChecksum { .. }))
+    ));
+    let Some(Ok(ack)) = it.next_checked() else {
+        panic!("No UBX-ACK-ACK");
+    };
+    assert!(ack.checksum_valid);
+    assert!(it.next_checked().is_none());
+    drop(it);
+
+    let mut it = parser.consume_ubx(&data);
+    assert!(matches!(
+        it.next_frame(),
+        Some(Err(ParserError::InvalidChecksum { .. }))
+    ));
+    assert!(matches!(
+        it.next_frame(),
+        Some(Ok(frame)) if frame.packet.class == 0x05 && frame.checksum_valid
+    ));
+    assert!(it.next_frame().is_none());
+    drop(it);
+
+    let stats = parser.stats();
+    assert_eq!(stats.checksum_failures, 2);
+    assert_eq!(stats.ubx_frames(0x10), 2);
+    assert_eq!(stats.bytes_discarded, 0);
+}
+
+#[cfg(feature = "ubx_proto23")]
+#[test]
+fn test_slice_buffer_proto23() {
+    use ublox::{proto23::Proto23, ParserBuilder};
+
+    let mut scratch = [0u8; 16];
+    let mut parser = ParserBuilder::new()
+        .with_protocol::<Proto23>()
+        .with_slice_buffer(&mut scratch);
+    assert_eq!(parser.buffer_capacity(), 16);
+
+    let mut found = 0;
+    for byte in FULL_ACK_ACK_PACK.chunks(1) {
+        let mut it = parser.consume_ubx(byte);
+        while let Some(packet) = it.next() {
+            assert!(matches!(
+                packet,
+                Ok(UbxPacket::Proto23(ublox::proto23::PacketRef::AckAck(_)))
+            ));
+            found += 1;
+        }
+    }
+    assert_eq!(found, 1);
+    assert!(parser.is_buffer_empty());
+}
+
+#[cfg(all(feature = "bytes", feature = "ubx_proto23"))]
+#[test]
+fn test_consume_buf_proto23() {
+    use bytes::Buf;
+    use ublox::{proto23::Proto23, ParserBuilder};
+
+    let mut data = FULL_ACK_ACK_PACK.to_vec();
+    data.extend_from_slice(&FULL_ACK_ACK_PACK);
+    let (head, tail) = data.split_at(13);
+
+    let mut parser = ParserBuilder::new()
+        .with_protocol::<Proto23>()
+        .with_fixed_buffer::<16>();
+    let mut acks = 0;
+    parser.consume_ubx_buf(head.chain(tail), |packet| {
+        assert!(matches!(
+            packet,
+            Ok(UbxPacket::Proto23(ublox::proto23::PacketRef::AckAck(_)))
+        ));
+        acks += 1;
+    });
+    assert_eq!(acks, 2);
+    assert!(parser.is_buffer_empty());
+    assert_eq!(parser.stats().bytes_consumed, 20);
+}
+
+#[test]
+fn test_bytes_needed() {
+    let mut parser = ublox::ParserBuilder::new().with_fixed_buffer::<64>();
+    assert_eq!(parser.bytes_needed(), 8);
+    for (i, byte) in FULL_ACK_ACK_PACK.chunks(1).enumerate() {
+        let mut it = parser.consume_ubx(byte);
+        while it.next().is_some() {}
+        drop(it);
+        let consumed = i + 1;
+        let expected = match consumed {
+            0..6 => 8 - consumed,
+            6..10 => 10 - consumed,
+            _ => 8,
+        };
+        assert_eq!(parser.bytes_needed(), expected, "after {consumed} bytes");
+    }
+
+    // RTCM3 frame header announcing a 19 byte payload, followed by the 24 bit CRC
+    let mut it = parser.consume_ubx_rtcm(&[0xd3, 0x00, 0x13, 0x3e]);
+    assert!(it.next().is_none());
+    drop(it);
+    assert_eq!(parser.bytes_needed(), 3 + 19 + 3 - 4);
+}
+
+#[test]
+fn test_clock_timestamps() {
+    let mut now = 0u32;
+    let mut parser = ublox::ParserBuilder::new()
+        .with_fixed_buffer::<64>()
+        .with_clock(move || {
+            now += 10;
+            now
+        });
+
+    // Garbage, then a frame split over three reads
+    let mut it = parser.consume_ubx(&[0x00, 0x01]);
+    assert!(it.next().is_none());
+    drop(it);
+    let mut it = parser.consume_ubx(&FULL_ACK_ACK_PACK[..3]);
+    assert!(it.next().is_none());
+    drop(it);
+    let mut it = parser.consume_ubx(&FULL_ACK_ACK_PACK[3..7]);
+    assert!(it.next().is_none());
+    drop(it);
+
+    // The rest of the frame, a complete frame and the start of another one
+    let data = [
+        &FULL_ACK_ACK_PACK[7..],
+        &FULL_ACK_ACK_PACK[..],
+        &FULL_ACK_ACK_PACK[..5],
+    ]
+    .concat();
+    let mut it = parser.consume_ubx(&data);
+    assert_eq!(it.next().unwrap().unwrap().timestamp, 20);
+    assert_eq!(it.next().unwrap().unwrap().timestamp, 40);
+    assert!(it.next().is_none());
+    drop(it);
+
+    let mut it = parser.consume_ubx(&FULL_ACK_ACK_PACK[5..]);
+    assert_eq!(it.next().unwrap().unwrap().timestamp, 40);
+    assert!(it.next().is_none());
+    drop(it);
+    assert_eq!(parser.parser().stats().ubx_frames(0x05), 3);
+}
+
+#[cfg(feature = "ubx_proto23")]
+#[test]
+fn test_supported_messages_proto23() {
+    use ublox::{proto23::Proto23, UbxProtocol};
+
+    let messages = Proto23::supported_messages();
+    let nav_pvt = messages.iter().find(|m| m.name == "NavPvt").unwrap();
+    assert_eq!((nav_pvt.class, nav_pvt.msg_id), (0x01, 0x07));
+    assert_eq!(nav_pvt.fixed_payload_len, Some(92));
+    assert!(messages.iter().any(|m| m.name == "MonVer"));
+
+    // One entry per packet type, with consistent lengths
+    for meta in messages {
+        if let Some(len) = meta.fixed_payload_len {
+            assert!(len <= meta.max_payload_len, "{}", meta.name);
+        }
+        let duplicates = messages.iter().filter(|m| m.name == meta.name).count();
+        assert_eq!(duplicates, 1, "{}", meta.name);
+    }
+
+    fn count<P: UbxProtocol>() -> usize {
+        P::supported_messages().len()
+    }
+    assert_eq!(count::<Proto23>(), messages.len());
+}
+
+#[cfg(all(feature = "ubx_proto14", feature = "ubx_proto31"))]
+#[test]
+fn test_supported_messages_differ_per_protocol() {
+    use ublox::{proto14::Proto14, proto31::Proto31};
+
+    let has = |messages: &[ublox::PacketMeta], name| messages.iter().any(|m| m.name == name);
+    assert!(!has(Proto14::supported_messages(), "NavSig"));
+    assert!(has(Proto31::supported_messages(), "NavSig"));
+}
+
+#[cfg(feature = "ubx_proto23")]
+#[test]
+fn test_nav_sat_repeated_blocks() {
+    use ublox::{nav_sat::NavSat, UbxPacketRecv};
+
+    let mut payload = vec![0xe8, 0x03, 0x00, 0x00, 0x01, 0x02, 0x00, 0x00];
+    // GPS 5 and Galileo 11, the latter used in the solution
+    payload.extend([
+        0x00, 0x05, 0x28, 0x1e, 0x5a, 0x00, 0x00, 0x00, 0x07, 0x00, 0x00, 0x00,
+    ]);
+    payload.extend([
+        0x02, 0x0b, 0x2d, 0x3c, 0xb4, 0x00, 0x00, 0x00, 0x0f, 0x00, 0x00, 0x00,
+    ]);
+
+    let nav_sat = NavSat::parse_payload(&payload).unwrap();
+    let svs = nav_sat.svs();
+    assert_eq!(svs.len(), 2);
+    let ids: Vec<_> = svs.map(|sv| (sv.gnss_id(), sv.sv_id())).collect();
+    assert_eq!(ids, [(0, 5), (2, 11)]);
+    assert!(nav_sat.svs().last().unwrap().flags().sv_used());
+    assert_eq!(nav_sat.to_owned().svs().count(), 2);
+
+    // The number of blocks has to match `num_svs`
+    assert_eq!(
+        NavSat::parse_payload(&payload[..20]).err(),
+        Some(ParserError::InvalidPacketLen {
+            packet: "NavSat",
+            expect: 32,
+            got: 20
+        })
+    );
+    payload[5] = 1;
+    assert!(NavSat::parse_payload(&payload).is_err());
+}
+
+/// 100 UBX-NAV-TIMEGPS frames: the length field of every 10th one is corrupted so that it
+/// announces the next frame as part of its payload, and a payload byte of every 10th + 5 one
+/// is flipped. Returns the number of packets parsed with `policy`.
+fn recovered_frames(policy: ublox::ResyncPolicy) -> usize {
+    let mut stream = Vec::new();
+    for i in 0..100u32 {
+        let mut payload = [0; 16];
+        payload[..4].copy_from_slice(&i.to_le_bytes());
+        let mut frame = ublox::ubx_frame!(0x01, 0x20, &payload);
+        match i % 10 {
+            0 => frame[4] = 40,
+            5 => frame[10] ^= 0x01,
+            _ => (),
+        }
+        stream.extend_from_slice(&frame);
+    }
+
+    let mut parser = ublox::ParserBuilder::new()
+        .with_resync_policy(policy)
+        .with_vec_buffer();
+    let mut it = parser.consume_ubx(&stream);
+    let mut recovered = 0;
+    while let Some(result) = it.next() {
+        match result {
+            Ok(_) => recovered += 1,
+            Err(err) => assert!(matches!(err, ParserError::InvalidChecksum { .. })),
+        }
+    }
+    recovered
+}
+
+#[test]
+fn test_resync_after_sync_recovers_frames_behind_corrupted_length() {
+    // Only the 20 corrupted frames are lost
+    assert_eq!(recovered_frames(ublox::ResyncPolicy::AfterSync), 80);
+    // The 10 frames following the corrupted length fields are skipped as well
+    assert_eq!(recovered_frames(ublox::ResyncPolicy::AfterFrame), 70);
+}
+
+#[test]
+fn test_rxm_rawx_and_mon_span_repeated_blocks() {
+    use ublox::{mon_span::MonSpan, rxm_rawx::RxmRawx, UbxPacketRecv};
+
+    // Two measurements, of GPS 5 and Galileo 11
+    let mut payload = vec![0; 16 + 2 * 32];
+    payload[11] = 2;
+    payload[16 + 20..16 + 22].copy_from_slice(&[0, 5]);
+    payload[48 + 20..48 + 22].copy_from_slice(&[2, 11]);
+    let rawx = RxmRawx::parse_payload(&payload).unwrap();
+    assert_eq!(rawx.measurements().len(), 2);
+    let ids: Vec<_> = rawx
+        .measurements()
+        .map(|meas| (meas.gnss_id(), meas.sv_id()))
+        .collect();
+    assert_eq!(ids, [(0, 5), (2, 11)]);
+    // The number of blocks has to match `num_meas`
+    payload[11] = 3;
+    assert_eq!(
+        RxmRawx::parse_payload(&payload).err(),
+        Some(ParserError::InvalidPacketLen {
+            packet: "RxmRawx",
+            expect: 112,
+            got: 80
+        })
+    );
+
+    // One RF block centered on L1
+    let mut payload = vec![0; 4 + 272];
+    payload[1] = 1;
+    payload[4 + 10] = 160;
+    payload[4 + 264..4 + 268].copy_from_slice(&1_575_420_000u32.to_le_bytes());
+    let span = MonSpan::parse_payload(&payload).unwrap();
+    let block = span.rf_blocks().next().unwrap();
+    assert_eq!(block.center(), 1_575_420_000);
+    assert_eq!(block.spectrum().len(), 256);
+    assert_eq!(block.spectrum_db(10), Some(40.0));
+    // A block beyond `num_rf_blocks` is not ignored
+    payload[1] = 0;
+    assert!(MonSpan::parse_payload(&payload).is_err());
+}
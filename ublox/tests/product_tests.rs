@@ -0,0 +1,107 @@
+#![cfg(any(feature = "std", feature = "alloc"))]
+
+use ublox::{
+    firmware::{FirmwareIdentity, ProtocolVersion},
+    product::ProductFamily,
+};
+
+#[test]
+fn family_from_module_name() {
+    assert_eq!(
+        ProductFamily::from_module("NEO-M8N-0"),
+        Some(ProductFamily::M8N)
+    );
+    assert_eq!(
+        ProductFamily::from_module("SAM-M8Q"),
+        Some(ProductFamily::M8N)
+    );
+    assert_eq!(
+        ProductFamily::from_module("ZED-F9R-02B"),
+        Some(ProductFamily::F9R)
+    );
+    assert_eq!(
+        ProductFamily::from_module("MAX-M10S-00B"),
+        Some(ProductFamily::M10S)
+    );
+    assert_eq!(ProductFamily::from_module("NEO-6M"), None);
+    assert_eq!(ProductFamily::from_module("F9P"), None);
+}
+
+#[test]
+fn family_from_firmware_without_module() {
+    let family = |fw_version: &str, protver: &str| {
+        let extensions = [fw_version, protver];
+        ProductFamily::from_identity(&FirmwareIdentity::new("EXT CORE", "0", extensions))
+    };
+    assert_eq!(
+        family("FWVER=ADR 4.11", "PROTVER=19.10"),
+        Some(ProductFamily::M8L)
+    );
+    assert_eq!(
+        family("FWVER=SPG 4.04", "PROTVER=32.01"),
+        Some(ProductFamily::M9N)
+    );
+    assert_eq!(
+        family("FWVER=TIM 2.20", "PROTVER=29.20"),
+        Some(ProductFamily::F9T)
+    );
+    // High precision firmware of both generations
+    assert_eq!(
+        family("FWVER=HPG 1.40", "PROTVER=20.30"),
+        Some(ProductFamily::M8P)
+    );
+    assert_eq!(
+        family("FWVER=HPG 1.32", "PROTVER=27.31"),
+        Some(ProductFamily::F9P)
+    );
+    assert_eq!(family("FWVER=HPG 1.32", ""), None);
+    assert_eq!(family("FWVER=XYZ 1.00", "PROTVER=27.31"), None);
+}
+
+#[test]
+fn module_name_takes_precedence() {
+    let identity = FirmwareIdentity::new(
+        "EXT CORE 3.01 (d189ff)",
+        "00080000",
+        ["FWVER=SPG 3.01", "PROTVER=18.00", "MOD=NEO-M8T-0"],
+    );
+    assert_eq!(
+        ProductFamily::from_identity(&identity),
+        Some(ProductFamily::M8T)
+    );
+}
+
+#[test]
+fn presets_are_consistent() {
+    for family in ProductFamily::ALL {
+        let limitations = family.limitations();
+        let messages = family.default_messages();
+        // RXM-RAWX is only enabled where available
+        let rawx = messages
+            .iter()
+            .any(|rate| (rate.msg_class, rate.msg_id) == (0x02, 0x15));
+        assert!(!rawx || limitations.has_rawx, "{family:?}");
+        assert_eq!(
+            limitations.max_nav_rate_hz.is_some(),
+            !messages.is_empty(),
+            "{family:?}"
+        );
+        if family.protocol_version() >= ProtocolVersion::new(27, 0) {
+            assert!(family.uses_cfg_valset(), "{family:?}");
+        }
+        // Every default message can be enabled
+        assert_eq!(family.message_profile().len(), messages.len(), "{family:?}");
+    }
+}
+
+#[test]
+fn profile_uses_the_configuration_message_of_the_family() {
+    let m8 = ProductFamily::M8U.message_profile();
+    assert!(m8.iter().all(|frame| frame[2..4] == [0x06, 0x01]));
+    // ESF-STATUS is enabled
+    assert!(m8.iter().any(|frame| frame[6..8] == [0x10, 0x10]));
+
+    let f9 = ProductFamily::F9P.message_profile();
+    assert!(f9.iter().all(|frame| frame[2..4] == [0x06, 0x8a]));
+    assert!(ProductFamily::D9S.message_profile().is_empty());
+}
@@ -0,0 +1,110 @@
+#![cfg(feature = "ubx_proto23")]
+
+use core::time::Duration;
+use ublox::{
+    mon_ver::MonVer,
+    recording::{Direction, Record, RecordReader, RecordingError, MAGIC, RECORD_HEADER_LEN},
+    session::{Session, SessionEvent},
+    ParserBuilder,
+};
+
+/// UBX-ACK-NAK of a poll of UBX-MON-VER
+const MON_VER_NAK: [u8; 10] = [0xb5, 0x62, 0x05, 0x00, 0x02, 0x00, 0x0a, 0x04, 0x15, 0x3e];
+
+#[test]
+fn records_round_trip() {
+    let mut file = MAGIC.to_vec();
+    let records = [
+        Record::new(Direction::Tx, Duration::from_micros(1_500), &[1, 2, 3]),
+        Record::new(Direction::Rx, Duration::from_secs(3), &[]),
+        Record::new(Direction::Rx, Duration::from_secs(4), &MON_VER_NAK),
+    ];
+    for record in &records {
+        record.write_to(&mut file).unwrap();
+    }
+    assert_eq!(
+        file.len(),
+        MAGIC.len() + 3 * RECORD_HEADER_LEN + 3 + MON_VER_NAK.len()
+    );
+    assert_eq!(file[8..9], *b"T");
+
+    let read: Vec<_> = RecordReader::new(&file)
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(read, records);
+}
+
+#[test]
+fn invalid_recordings() {
+    assert_eq!(
+        RecordReader::new(b"UBXREC").unwrap_err(),
+        RecordingError::BadMagic
+    );
+
+    let mut file = MAGIC.to_vec();
+    Record::new(Direction::Tx, Duration::ZERO, &[1, 2])
+        .write_to(&mut file)
+        .unwrap();
+    Record::new(Direction::Rx, Duration::ZERO, &[3, 4])
+        .write_to(&mut file)
+        .unwrap();
+
+    // Cut in the middle of the second record's data
+    let mut reader = RecordReader::new(&file[..file.len() - 1]).unwrap();
+    assert!(reader.next().unwrap().is_ok());
+    let offset = reader.offset();
+    assert_eq!(offset, MAGIC.len() + RECORD_HEADER_LEN + 2);
+    assert_eq!(
+        reader.next(),
+        Some(Err(RecordingError::Truncated { offset }))
+    );
+    assert_eq!(reader.next(), None);
+
+    file[offset] = b'X';
+    let mut reader = RecordReader::new(&file).unwrap();
+    reader.next();
+    assert_eq!(
+        reader.next(),
+        Some(Err(RecordingError::InvalidDirection { offset, tag: b'X' }))
+    );
+}
+
+#[test]
+fn replays_both_sides_of_a_session() {
+    // Record a poll the receiver rejects
+    let mut file = MAGIC.to_vec();
+    let mut session = Session::new(ParserBuilder::new().with_fixed_buffer::<1024>());
+    session.handle_timeout(Duration::ZERO);
+    session.poll::<MonVer>();
+    let request = session.poll_output().unwrap();
+    Record::new(Direction::Tx, Duration::from_millis(1), &request)
+        .write_to(&mut file)
+        .unwrap();
+    Record::new(Direction::Rx, Duration::from_millis(30), &MON_VER_NAK)
+        .write_to(&mut file)
+        .unwrap();
+
+    // Replay it into a new session, checking the frames it sends against the recorded ones
+    let mut replay = Session::new(ParserBuilder::new().with_fixed_buffer::<1024>());
+    replay.handle_timeout(Duration::ZERO);
+    replay.poll::<MonVer>();
+    for record in RecordReader::new(&file).unwrap() {
+        let record = record.unwrap();
+        replay.handle_timeout(record.timestamp);
+        match record.direction {
+            Direction::Tx => assert_eq!(replay.poll_output().unwrap(), record.data),
+            Direction::Rx => {
+                let mut it = replay.handle_input(record.data);
+                while it.next().is_some() {}
+            },
+        }
+    }
+    assert_eq!(
+        replay.poll_event(),
+        Some(SessionEvent::Nacked {
+            class: 0x0a,
+            msg_id: 0x04
+        })
+    );
+}
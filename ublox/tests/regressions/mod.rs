@@ -0,0 +1,181 @@
+//! Frames that crashed or were mis-parsed in the field, kept as permanent tests.
+//!
+//! Each `*.hex` file of this directory holds a dump of the bytes received, as hex digits
+//! separated by any whitespace. Lines starting with `#` are comments describing the issue,
+//! except for a `# frames: N` line giving the number of frames with a valid checksum the
+//! parser must find, and `# expect: <packet>` lines giving, in order, the `Debug` of each
+//! packet that must be decoded, without its protocol variant. Every dump is parsed with each
+//! enabled protocol, fed at once and one byte at a time, and each packet decoded is formatted
+//! with `Debug`, calling all its accessors, and converted to its owned form. The expected
+//! packets are checked with each enabled protocol supporting all the frames of the dump.
+//!
+//! To add a regression, drop the dump of the offending bytes in a new file here, e.g. from
+//! `xxd -p capture.ubx`, describing the issue in its comments and the packets it must decode
+//! to.
+#![cfg(feature = "std")]
+
+use std::{
+    fs,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+};
+
+use ublox::{FramePayload, ParserBuilder, UbxPacket, UbxProtocol};
+
+struct Dump {
+    bytes: Vec<u8>,
+    expected_frames: Option<usize>,
+    expected_packets: Vec<String>,
+}
+
+impl Dump {
+    fn read(path: &Path) -> Self {
+        let text = fs::read_to_string(path).unwrap();
+        let mut expected_frames = None;
+        let mut expected_packets = Vec::new();
+        let mut hex = String::new();
+        for line in text.lines() {
+            let Some(comment) = line.trim_start().strip_prefix('#') else {
+                hex.push_str(line);
+                hex.push('\n');
+                continue;
+            };
+            if let Some(frames) = comment.trim().strip_prefix("frames:") {
+                expected_frames = Some(frames.trim().parse().expect("invalid frame count"));
+            } else if let Some(packet) = comment.trim().strip_prefix("expect:") {
+                expected_packets.push(packet.trim().to_string());
+            }
+        }
+        Self {
+            bytes: hex.as_str().frame_payload(),
+            expected_frames,
+            expected_packets,
+        }
+    }
+}
+
+fn dumps() -> Vec<PathBuf> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/regressions");
+    let mut paths: Vec<PathBuf> = fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "hex"))
+        .collect();
+    paths.sort();
+    paths
+}
+
+/// Class and message id of the frames with a valid checksum, whatever their payload
+fn frame_ids(bytes: &[u8]) -> Vec<(u8, u8)> {
+    let mut parser = ParserBuilder::new().with_vec_buffer();
+    let mut it = parser.consume_ubx(bytes);
+    let mut ids = Vec::new();
+    while let Some(result) = it.next_frame() {
+        if let Ok(frame) = result {
+            ids.push((frame.packet.class, frame.packet.msg_id));
+        }
+    }
+    ids
+}
+
+/// `Debug` of the packet without its protocol variant
+fn packet_debug(packet: &UbxPacket) -> String {
+    match packet {
+        #[cfg(feature = "ubx_proto14")]
+        UbxPacket::Proto14(packet) => format!("{packet:?}"),
+        #[cfg(feature = "ubx_proto23")]
+        UbxPacket::Proto23(packet) => format!("{packet:?}"),
+        #[cfg(feature = "ubx_proto27")]
+        UbxPacket::Proto27(packet) => format!("{packet:?}"),
+        #[cfg(feature = "ubx_proto31")]
+        UbxPacket::Proto31(packet) => format!("{packet:?}"),
+        #[cfg(feature = "ubx_proto33")]
+        UbxPacket::Proto33(packet) => format!("{packet:?}"),
+    }
+}
+
+/// `Debug` of every packet decoded with protocol `P`
+fn decoded_packets<P: UbxProtocol>(bytes: &[u8]) -> Vec<String> {
+    let mut parser = ParserBuilder::new().with_protocol::<P>().with_vec_buffer();
+    let mut it = parser.consume_ubx(bytes);
+    let mut packets = Vec::new();
+    while let Some(result) = it.next() {
+        if let Ok(packet) = result {
+            packets.push(packet_debug(&packet));
+        }
+    }
+    packets
+}
+
+/// Decode every packet with protocol `P`, fed in chunks of `chunk_len` bytes, returning a
+/// summary of each packet or error to compare the feeding strategies
+fn decode_all<P: UbxProtocol>(bytes: &[u8], chunk_len: usize) -> Vec<String> {
+    let mut parser = ParserBuilder::new().with_protocol::<P>().with_vec_buffer();
+    let mut decoded = Vec::new();
+    for chunk in bytes.chunks(chunk_len) {
+        let mut it = parser.consume_ubx(chunk);
+        while let Some(result) = it.next() {
+            decoded.push(match result {
+                Ok(packet) => {
+                    let owned = packet.to_owned();
+                    format!("{packet:?} {owned:?}")
+                },
+                Err(e) => format!("{e:?}"),
+            });
+        }
+    }
+    decoded
+}
+
+fn check_protocol<P: UbxProtocol>(dump: &Dump, frame_ids: &[(u8, u8)]) {
+    let at_once = decode_all::<P>(&dump.bytes, dump.bytes.len().max(1));
+    let byte_by_byte = decode_all::<P>(&dump.bytes, 1);
+    assert_eq!(at_once, byte_by_byte, "fed at once and one byte at a time");
+
+    let supported = frame_ids.iter().all(|&(class, msg_id)| {
+        P::supported_messages()
+            .iter()
+            .any(|meta| meta.class == class && meta.msg_id == msg_id)
+    });
+    if supported && !dump.expected_packets.is_empty() {
+        assert_eq!(
+            decoded_packets::<P>(&dump.bytes),
+            dump.expected_packets,
+            "packets decoded with protocol {}",
+            P::VERSION
+        );
+    }
+}
+
+fn check(dump: &Dump) {
+    let frame_ids = frame_ids(&dump.bytes);
+    if let Some(expected) = dump.expected_frames {
+        assert_eq!(frame_ids.len(), expected, "frames found");
+    }
+    #[cfg(feature = "ubx_proto14")]
+    check_protocol::<ublox::proto14::Proto14>(dump, &frame_ids);
+    #[cfg(feature = "ubx_proto23")]
+    check_protocol::<ublox::proto23::Proto23>(dump, &frame_ids);
+    #[cfg(feature = "ubx_proto27")]
+    check_protocol::<ublox::proto27::Proto27>(dump, &frame_ids);
+    #[cfg(feature = "ubx_proto31")]
+    check_protocol::<ublox::proto31::Proto31>(dump, &frame_ids);
+    #[cfg(feature = "ubx_proto33")]
+    check_protocol::<ublox::proto33::Proto33>(dump, &frame_ids);
+}
+
+#[test]
+fn regression_dumps() {
+    let paths = dumps();
+    assert!(!paths.is_empty(), "no regression dumps found");
+
+    let failed: Vec<_> = paths
+        .iter()
+        .filter(|path| {
+            let dump = Dump::read(path);
+            panic::catch_unwind(AssertUnwindSafe(|| check(&dump))).is_err()
+        })
+        .map(|path| path.file_name().unwrap().to_string_lossy().into_owned())
+        .collect();
+    assert!(failed.is_empty(), "regression dumps failed: {failed:?}");
+}
@@ -0,0 +1,180 @@
+#![cfg(feature = "ubx_proto23")]
+
+use core::time::Duration;
+use ublox::{
+    cfg_rate::{AlignmentToReferenceTime, CfgRateBuilder},
+    mon_hw::MonHw,
+    nav_status::NavStatus,
+    proto23::{PacketRef, Proto23},
+    session::{Session, SessionEvent},
+    ubx_frame, FixedBuffer, ParserBuilder, ParserError, UbxPacket,
+};
+
+fn session() -> Session<FixedBuffer<1024>, Proto23> {
+    Session::new(
+        ParserBuilder::new()
+            .with_protocol::<Proto23>()
+            .with_fixed_buffer::<1024>(),
+    )
+    .with_timeout(Duration::from_millis(100))
+    .with_attempts(2)
+}
+
+/// Feeds `data` to the session, returning the number of packets received
+fn input(session: &mut Session<FixedBuffer<1024>, Proto23>, data: &[u8]) -> usize {
+    let mut packets = 0;
+    let mut it = session.handle_input(data);
+    while let Some(packet) = it.next() {
+        assert!(packet.is_ok());
+        packets += 1;
+    }
+    packets
+}
+
+#[test]
+fn test_session_cfg_acked() {
+    let mut session = session();
+    let cfg_rate = CfgRateBuilder {
+        measure_rate_ms: 200,
+        nav_rate: 1,
+        time_ref: AlignmentToReferenceTime::Gps,
+    }
+    .into_packet_bytes();
+    session.send_cfg(&cfg_rate);
+    assert_eq!(session.poll_output().unwrap(), cfg_rate);
+    assert!(session.poll_output().is_none());
+    assert!(!session.is_idle());
+
+    // An ACK for another message does not complete the request
+    assert_eq!(
+        input(&mut session, &ubx_frame!(0x05, 0x01, &[0x06, 0x01])),
+        1
+    );
+    assert!(session.poll_event().is_none());
+
+    assert_eq!(
+        input(&mut session, &ubx_frame!(0x05, 0x01, &[0x06, 0x08])),
+        1
+    );
+    assert_eq!(
+        session.poll_event(),
+        Some(SessionEvent::Acked {
+            class: 0x06,
+            msg_id: 0x08
+        })
+    );
+    assert!(session.is_idle());
+    assert_eq!(session.poll_timeout(), None);
+}
+
+#[test]
+fn test_session_ignores_corrupted_ack() {
+    let mut session = Session::new(
+        ParserBuilder::new()
+            .with_protocol::<Proto23>()
+            .accept_invalid_checksums(true)
+            .with_fixed_buffer::<1024>(),
+    );
+    let cfg_rate = CfgRateBuilder {
+        measure_rate_ms: 200,
+        nav_rate: 1,
+        time_ref: AlignmentToReferenceTime::Gps,
+    }
+    .into_packet_bytes();
+    session.send_cfg(&cfg_rate);
+    assert_eq!(session.poll_output().unwrap(), cfg_rate);
+
+    let mut ack = ubx_frame!(0x05, 0x01, &[0x06, 0x08]);
+    *ack.last_mut().unwrap() ^= 0xff;
+    let mut it = session.handle_input(&ack);
+    assert!(matches!(
+        it.next(),
+        Some(Err(ParserError::InvalidChecksum { .. }))
+    ));
+    assert!(it.next().is_none());
+    drop(it);
+    assert!(session.poll_event().is_none());
+    assert!(!session.is_idle());
+
+    assert_eq!(
+        input(&mut session, &ubx_frame!(0x05, 0x01, &[0x06, 0x08])),
+        1
+    );
+    assert!(matches!(
+        session.poll_event(),
+        Some(SessionEvent::Acked { .. })
+    ));
+    assert!(session.is_idle());
+}
+
+#[test]
+fn test_session_poll_timeout() {
+    let mut session = session();
+    session.handle_timeout(Duration::from_secs(10));
+    session.poll::<MonHw>();
+    let poll = session.poll_output().unwrap();
+    assert_eq!(poll[2..4], [0x0a, 0x09]);
+
+    session.handle_timeout(Duration::from_millis(10_050));
+    assert!(session.poll_output().is_none());
+    session.handle_timeout(Duration::from_millis(10_100));
+    assert_eq!(session.poll_output().unwrap(), poll);
+    assert!(session.poll_event().is_none());
+
+    session.handle_timeout(Duration::from_millis(10_200));
+    assert!(session.poll_output().is_none());
+    assert_eq!(
+        session.poll_event(),
+        Some(SessionEvent::TimedOut {
+            class: 0x0a,
+            msg_id: 0x09
+        })
+    );
+    assert!(session.is_idle());
+}
+
+#[test]
+fn test_session_schedule() {
+    let mut session = session();
+    session.schedule::<NavStatus>(Duration::from_secs(1));
+    assert_eq!(session.poll_timeout(), Some(Duration::ZERO));
+
+    session.handle_timeout(Duration::ZERO);
+    assert_eq!(session.poll_output().unwrap()[2..4], [0x01, 0x03]);
+    assert!(session.poll_output().is_none());
+
+    let mut nav_status = [0; 16];
+    nav_status[4] = 3;
+    let nav_status = ubx_frame!(0x01, 0x03, &nav_status);
+    let mut it = session.handle_input(&nav_status);
+    assert!(matches!(
+        it.next(),
+        Some(Ok(UbxPacket::Proto23(PacketRef::NavStatus(_))))
+    ));
+    assert!(it.next().is_none());
+    drop(it);
+    assert_eq!(
+        session.poll_event(),
+        Some(SessionEvent::Responded {
+            class: 0x01,
+            msg_id: 0x03
+        })
+    );
+
+    // The next poll is due a period after the first one
+    assert_eq!(session.poll_timeout(), Some(Duration::from_secs(1)));
+    session.handle_timeout(Duration::from_millis(500));
+    assert!(session.poll_output().is_none());
+    session.handle_timeout(Duration::from_secs(1));
+    assert!(session.poll_output().is_some());
+
+    // Unanswered, so no new poll is queued while retrying
+    session.handle_timeout(Duration::from_secs(2));
+    assert_eq!(session.poll_output().unwrap()[2..4], [0x01, 0x03]);
+    assert!(session.poll_output().is_none());
+
+    session.unschedule::<NavStatus>();
+    assert_eq!(input(&mut session, &nav_status), 1);
+    assert!(session.is_idle());
+    assert_eq!(session.poll_timeout(), None);
+}
@@ -0,0 +1,116 @@
+#![cfg(any(feature = "ubx_proto23", feature = "ubx_proto27"))]
+
+use std::io::{self, Read, Write};
+#[cfg(feature = "ubx_proto23")]
+use std::time::Duration;
+use ublox::simple::Positions;
+#[cfg(feature = "ubx_proto23")]
+use ublox::{proto23::Proto23, ubx_frame, GnssFixType};
+
+/// Port replaying `input` in chunks and recording the frames written to it
+struct MockPort {
+    input: Vec<Vec<u8>>,
+    output: Vec<u8>,
+}
+
+impl Read for MockPort {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.input.is_empty() {
+            return Err(io::ErrorKind::TimedOut.into());
+        }
+        let chunk = self.input.remove(0);
+        buf[..chunk.len()].copy_from_slice(&chunk);
+        Ok(chunk.len())
+    }
+}
+
+impl Write for MockPort {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.output.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "ubx_proto23")]
+fn nav_pvt(itow: u32, fix_type: u8, fix_ok: bool, lat: i32) -> Vec<u8> {
+    let mut payload = [0; 92];
+    payload[..4].copy_from_slice(&itow.to_le_bytes());
+    payload[20] = fix_type;
+    payload[21] = fix_ok.into();
+    payload[23] = 7;
+    payload[28..32].copy_from_slice(&lat.to_le_bytes());
+    ubx_frame!(0x01, 0x07, payload)
+}
+
+#[cfg(feature = "ubx_proto23")]
+#[test]
+fn test_positions_configures_and_skips_invalid_fixes() {
+    let mut acks = ubx_frame!(0x05, 0x01, [0x06, 0x08]);
+    acks.extend(ubx_frame!(0x05, 0x01, [0x06, 0x01]));
+    let mut two_fixes = nav_pvt(3000, 3, true, 476_000_000);
+    two_fixes.extend(nav_pvt(4000, 2, true, 476_000_010));
+    let port = MockPort {
+        input: vec![
+            acks,
+            nav_pvt(1000, 0, false, 0),
+            nav_pvt(2000, 3, false, 0),
+            two_fixes,
+        ],
+        output: Vec::new(),
+    };
+
+    let mut positions = Positions::<_, Proto23>::new(port).unwrap();
+    let output = &positions.port_mut().output;
+    assert_eq!(output[2..4], [0x06, 0x08]);
+    assert_eq!(output[16..18], [0x06, 0x01]);
+    assert_eq!(output[20..23], [0x01, 0x07, 0x01]);
+
+    let position = positions.next().unwrap().unwrap();
+    assert_eq!(position.fix_type, GnssFixType::Fix3D);
+    assert_eq!(position.num_satellites, 7);
+    assert!((position.lat - 47.6).abs() < 1e-9);
+    let position = positions.next_within(Duration::ZERO).unwrap();
+    assert_eq!(position.fix_type, GnssFixType::Fix2D);
+
+    let err = positions
+        .next_within(Duration::from_millis(10))
+        .unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::TimedOut);
+}
+
+#[cfg(feature = "ubx_proto27")]
+#[test]
+fn test_positions_configures_with_cfg_valset() {
+    use ublox::{cfg_val::CfgKey, proto27::Proto27};
+
+    let port = MockPort {
+        input: Vec::new(),
+        output: Vec::new(),
+    };
+    let mut positions = Positions::<_, Proto27>::new(port).unwrap();
+    let output = &positions.port_mut().output;
+    // UBX-CFG-VALSET of CFG-RATE-MEAS and CFG-RATE-NAV
+    assert_eq!(output[2..4], [0x06, 0x8a]);
+    assert_eq!(output[10..14], (CfgKey::RateMeas as u32).to_le_bytes());
+    assert_eq!(output[14..16], 1000u16.to_le_bytes());
+    assert_eq!(output[16..20], (CfgKey::RateNav as u32).to_le_bytes());
+    // UBX-CFG-VALSET of CFG-MSGOUT-UBX_NAV_PVT_I2C and the other ports
+    let nav_pvt = &output[24..];
+    assert_eq!(nav_pvt[2..4], [0x06, 0x8a]);
+    assert_eq!(
+        nav_pvt[10..14],
+        (CfgKey::MsgOutUbxNavPvtI2c as u32).to_le_bytes()
+    );
+    assert_eq!(nav_pvt[14], 1);
+}
+
+#[cfg(feature = "ubx_proto23")]
+#[test]
+fn test_read_position_fails_on_closed_port() {
+    let err = ublox::simple::read_position(io::Cursor::new(Vec::new()), Duration::from_secs(1))
+        .unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+}
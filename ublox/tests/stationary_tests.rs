@@ -0,0 +1,100 @@
+#![cfg(feature = "ubx_proto23")]
+
+use ublox::{
+    cfg_val::CfgVal,
+    proto23::Proto23,
+    stationary::{MotionState, StaticHold, StationaryDetector},
+    UbxPacket, UbxProtocol,
+};
+
+/// UBX-NAV-PVT payload with a 3D fix, the given ground speed and speed accuracy \[mm/s\] and
+/// horizontal accuracy \[mm\], and a longitude of `lon` 1e-7 degrees
+fn nav_pvt(speed: i32, speed_acc: u32, h_acc: u32, lon: i32) -> [u8; 92] {
+    let mut payload = [0; 92];
+    payload[20] = 3;
+    // GPS_FIX_OK
+    payload[21] = 0x01;
+    payload[24..28].copy_from_slice(&lon.to_le_bytes());
+    payload[40..44].copy_from_slice(&h_acc.to_le_bytes());
+    payload[48..52].copy_from_slice(&speed.to_le_bytes());
+    payload[60..64].copy_from_slice(&speed.to_le_bytes());
+    payload[68..72].copy_from_slice(&speed_acc.to_le_bytes());
+    payload
+}
+
+fn update(detector: &mut StationaryDetector, payload: &[u8]) -> Option<MotionState> {
+    let packet: UbxPacket = Proto23::match_packet(0x01, 0x07, payload).unwrap().into();
+    detector.update(&packet)
+}
+
+#[test]
+fn static_hold_settings() {
+    let hold = StaticHold::new(0.8, 20);
+    let packet = hold.cfg_nav5().into_packet_bytes();
+    // mask, static hold threshold in cm/s and max distance in m
+    assert_eq!(packet[6..8], [0x40, 0x00]);
+    assert_eq!(packet[6 + 22], 80);
+    assert_eq!(packet[6 + 28..6 + 30], [20, 0]);
+
+    assert_eq!(
+        hold.cfg_items().values(),
+        [CfgVal::MotGnssSpeedThrs(80), CfgVal::MotGnssDistThrs(20)]
+    );
+    assert_eq!(
+        StaticHold::new(5.0, 0).cfg_items().values()[0],
+        CfgVal::MotGnssSpeedThrs(255)
+    );
+}
+
+#[test]
+fn becomes_stationary_after_still_fixes() {
+    let mut detector = StationaryDetector::with_thresholds(0.5, 10.0, 3);
+    assert_eq!(detector.position().map(|p| p.lon), None);
+
+    assert_eq!(
+        update(&mut detector, &nav_pvt(200, 100, 3_000, 10)),
+        Some(MotionState::Unknown)
+    );
+    assert_eq!(
+        update(&mut detector, &nav_pvt(210, 100, 2_000, 20)),
+        Some(MotionState::Unknown)
+    );
+    assert_eq!(
+        update(&mut detector, &nav_pvt(0, 100, 4_000, 30)),
+        Some(MotionState::Stationary)
+    );
+    // The most accurate still fix is kept, not the last one
+    assert_eq!(
+        update(&mut detector, &nav_pvt(300, 100, 5_000, 40)),
+        Some(MotionState::Stationary)
+    );
+    assert!(detector.is_stationary());
+    assert!((detector.position().unwrap().lon - 20e-7).abs() < 1e-12);
+
+    assert_eq!(
+        update(&mut detector, &nav_pvt(1_500, 100, 2_000, 50)),
+        Some(MotionState::Moving)
+    );
+    assert!((detector.position().unwrap().lon - 50e-7).abs() < 1e-12);
+}
+
+#[test]
+fn ignores_inaccurate_and_uncertain_fixes() {
+    let mut detector = StationaryDetector::with_thresholds(0.5, 10.0, 2);
+    update(&mut detector, &nav_pvt(100, 100, 2_000, 10));
+    // Too inaccurate, breaks the run of still fixes
+    update(&mut detector, &nav_pvt(100, 100, 50_000, 20));
+    assert_eq!(detector.state(), MotionState::Unknown);
+    // Slow, but the speed is too uncertain to tell
+    update(&mut detector, &nav_pvt(100, 800, 2_000, 30));
+    assert_eq!(detector.state(), MotionState::Unknown);
+    update(&mut detector, &nav_pvt(100, 100, 2_000, 40));
+    assert_eq!(
+        update(&mut detector, &nav_pvt(100, 100, 2_000, 50)),
+        Some(MotionState::Stationary)
+    );
+
+    detector.reset();
+    assert_eq!(detector.state(), MotionState::Unknown);
+    assert!(detector.position().is_none());
+}
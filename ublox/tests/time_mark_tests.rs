@@ -0,0 +1,144 @@
+#![cfg(feature = "ubx_proto23")]
+
+use ublox::{
+    proto23::Proto23,
+    tim_tm2::TimTm2TimeBase,
+    time_mark::{Edge, EventTimestamper, GpsTime, TimeMark},
+    UbxPacket, UbxProtocol,
+};
+
+const NEW_RISING_EDGE: u8 = 0x80;
+const NEW_FALLING_EDGE: u8 = 0x04;
+const TIME_VALID: u8 = 0x40;
+const GNSS_TIME_BASE: u8 = 1 << 3;
+
+/// UBX-TIM-TM2 payload with both edges at the same week, 1 ms apart
+fn tim_tm2(ch: u8, flags: u8, week: u16, tow_ms: u32, tow_sub_ms_ns: u32) -> [u8; 28] {
+    let mut payload = [0; 28];
+    payload[0] = ch;
+    payload[1] = flags;
+    payload[2..4].copy_from_slice(&7u16.to_le_bytes());
+    payload[4..6].copy_from_slice(&week.to_le_bytes());
+    payload[6..8].copy_from_slice(&week.to_le_bytes());
+    payload[8..12].copy_from_slice(&tow_ms.to_le_bytes());
+    payload[12..16].copy_from_slice(&tow_sub_ms_ns.to_le_bytes());
+    payload[16..20].copy_from_slice(&(tow_ms + 1).to_le_bytes());
+    payload[20..24].copy_from_slice(&tow_sub_ms_ns.to_le_bytes());
+    payload[24..28].copy_from_slice(&30u32.to_le_bytes());
+    payload
+}
+
+/// UBX-ESF-MEAS payload without measurements, with a time mark sent on EXTINT1
+fn esf_meas_time_mark(calib_tag: u32) -> [u8; 12] {
+    let mut payload = [0; 12];
+    // timeMarkSent = 2, calibTtagValid
+    payload[4..6].copy_from_slice(&0x000au16.to_le_bytes());
+    payload[8..12].copy_from_slice(&calib_tag.to_le_bytes());
+    payload
+}
+
+fn update(timestamper: &mut EventTimestamper, msg_id: u8, payload: &[u8]) -> Vec<TimeMark> {
+    let class = if msg_id == 0x03 { 0x0d } else { 0x10 };
+    let packet = UbxPacket::Proto23(Proto23::match_packet(class, msg_id, payload).unwrap());
+    timestamper.update(&packet).collect()
+}
+
+#[test]
+fn rising_and_falling_edges() {
+    let mut timestamper = EventTimestamper::new();
+    let payload = tim_tm2(
+        1,
+        NEW_RISING_EDGE | NEW_FALLING_EDGE | TIME_VALID | GNSS_TIME_BASE,
+        2300,
+        1_000,
+        999_999,
+    );
+    let marks = update(&mut timestamper, 0x03, &payload);
+    assert_eq!(marks.len(), 2);
+
+    assert_eq!(marks[0].channel, 1);
+    assert_eq!(marks[0].edge, Edge::Rising);
+    assert_eq!(marks[0].count, 7);
+    assert_eq!(marks[0].accuracy_ns, 30);
+    assert_eq!(marks[0].time_base, TimTm2TimeBase::Gnss);
+    assert_eq!(
+        marks[0].time,
+        GpsTime {
+            week: 2300,
+            tow_ns: 1_000_999_999,
+        }
+    );
+    assert_eq!(marks[1].edge, Edge::Falling);
+    assert_eq!(marks[1].time.tow_ns, 1_001_999_999);
+}
+
+#[test]
+fn edges_without_valid_time_are_ignored() {
+    let mut timestamper = EventTimestamper::new();
+    let payload = tim_tm2(0, NEW_RISING_EDGE, 2300, 1_000, 0);
+    assert!(update(&mut timestamper, 0x03, &payload).is_empty());
+
+    let payload = tim_tm2(0, TIME_VALID, 2300, 1_000, 0);
+    assert!(update(&mut timestamper, 0x03, &payload).is_empty());
+}
+
+#[test]
+fn esf_calib_tag_after_time_mark() {
+    let mut timestamper = EventTimestamper::new();
+    let payload = tim_tm2(1, NEW_RISING_EDGE | TIME_VALID, 2300, 5_000, 250);
+    update(&mut timestamper, 0x03, &payload);
+    assert!(!timestamper.is_esf_calibrated());
+    assert_eq!(timestamper.esf_gps_time(1_000), None);
+
+    assert!(update(&mut timestamper, 0x02, &esf_meas_time_mark(40_000)).is_empty());
+    assert!(timestamper.is_esf_calibrated());
+    assert_eq!(
+        timestamper.esf_gps_time(40_100),
+        Some(GpsTime {
+            week: 2300,
+            tow_ns: 5_100_000_250,
+        })
+    );
+    assert_eq!(
+        timestamper.esf_gps_time(39_000).unwrap().tow_ns,
+        4_000_000_250
+    );
+}
+
+#[test]
+fn esf_time_mark_before_tim_tm2_and_week_rollover() {
+    let mut timestamper = EventTimestamper::new();
+    update(&mut timestamper, 0x02, &esf_meas_time_mark(u32::MAX - 10));
+    assert!(!timestamper.is_esf_calibrated());
+
+    // The last millisecond of the week, on EXTINT0 which the ESF-MEAS mark was not sent on
+    let payload = tim_tm2(0, NEW_RISING_EDGE | TIME_VALID, 2300, 604_799_999, 0);
+    update(&mut timestamper, 0x03, &payload);
+    assert!(!timestamper.is_esf_calibrated());
+
+    let payload = tim_tm2(1, NEW_RISING_EDGE | TIME_VALID, 2300, 604_799_999, 0);
+    update(&mut timestamper, 0x03, &payload);
+    assert_eq!(
+        timestamper.esf_gps_time(5),
+        Some(GpsTime {
+            week: 2301,
+            tow_ns: 15_000_000,
+        })
+    );
+}
+
+#[test]
+fn gps_time_arithmetic() {
+    let time = GpsTime {
+        week: 2300,
+        tow_ns: 500,
+    };
+    assert_eq!(
+        time.add_nanos(-1_000),
+        GpsTime {
+            week: 2299,
+            tow_ns: 604_800_000_000_000 - 500,
+        }
+    );
+    assert_eq!(time.add_nanos(0), time);
+}
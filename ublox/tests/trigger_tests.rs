@@ -0,0 +1,148 @@
+use core::time::Duration;
+
+use ublox::{
+    cfg_tp5::{CfgTp5, CfgTp5Flags, CfgTp5TimePulseMode},
+    tim_tp::{TimTp, TimTpTimeBase},
+    time_mark::{Edge, GpsTime},
+    trigger::{TriggerPlan, TriggerPlanError},
+    UbxPacketRecv,
+};
+
+/// UBX-TIM-TP payload with a valid quantization error
+fn tim_tp(week: u16, tow_ms: u32, tow_sub_ms: u32, q_err: i32) -> [u8; 16] {
+    let mut payload = [0; 16];
+    payload[0..4].copy_from_slice(&tow_ms.to_le_bytes());
+    payload[4..8].copy_from_slice(&tow_sub_ms.to_le_bytes());
+    payload[8..12].copy_from_slice(&q_err.to_le_bytes());
+    payload[12..14].copy_from_slice(&week.to_le_bytes());
+    payload
+}
+
+#[test]
+fn periods_must_divide_a_second() {
+    for period in [
+        Duration::ZERO,
+        Duration::from_millis(300),
+        Duration::from_millis(1500),
+        Duration::from_nanos(100_500),
+    ] {
+        assert_eq!(
+            TriggerPlan::new(period),
+            Err(TriggerPlanError::PeriodNotFractionOfSecond),
+            "{period:?}"
+        );
+    }
+    let plan = TriggerPlan::new(Duration::from_millis(125)).unwrap();
+    assert_eq!(plan.triggers_per_second(), 8);
+    assert_eq!(plan.period(), Duration::from_millis(125));
+    assert_eq!(
+        TriggerPlan::new(Duration::from_secs(1))
+            .unwrap()
+            .triggers_per_second(),
+        1
+    );
+}
+
+#[test]
+fn pulses_fit_in_the_period() {
+    let plan = TriggerPlan::new(Duration::from_millis(100)).unwrap();
+    assert_eq!(
+        plan.with_pulse_len(Duration::from_millis(100)),
+        Err(TriggerPlanError::InvalidPulseLen)
+    );
+    assert_eq!(
+        plan.with_pulse_len(Duration::from_nanos(999)),
+        Err(TriggerPlanError::InvalidPulseLen)
+    );
+    assert!(plan.with_pulse_len(Duration::from_millis(99)).is_ok());
+}
+
+#[test]
+fn cfg_tp5_outputs_only_when_locked() {
+    let frame = TriggerPlan::new(Duration::from_millis(250))
+        .unwrap()
+        .with_pulse_len(Duration::from_micros(500))
+        .unwrap()
+        .with_edge(Edge::Falling)
+        .with_user_delay_ns(-35)
+        .with_output(CfgTp5TimePulseMode::TimePulse2)
+        .cfg_tp5()
+        .into_packet_bytes();
+    let tp5 = CfgTp5::parse_payload(&frame[6..frame.len() - 2]).unwrap();
+
+    assert_eq!(tp5.tp_idx(), CfgTp5TimePulseMode::TimePulse2);
+    assert_eq!(tp5.freq_period_lock(), 250_000.0);
+    assert_eq!(tp5.pulse_len_ratio_lock(), 500.0);
+    assert_eq!(tp5.pulse_len_ratio(), 0.0);
+    assert_eq!(tp5.user_delay(), -35.0);
+    let flags = tp5.flags();
+    assert!(flags.contains(
+        CfgTp5Flags::ACTIVE
+            | CfgTp5Flags::LOCK_GNSS_FREQ
+            | CfgTp5Flags::LOCKED_OTHER_SET
+            | CfgTp5Flags::IS_LENGTH
+            | CfgTp5Flags::ALIGN_TO_TOW
+    ));
+    assert!(!flags.intersects(CfgTp5Flags::IS_FREQ | CfgTp5Flags::POLARITY));
+}
+
+#[test]
+fn schedule_from_tim_tp() {
+    let plan = TriggerPlan::new(Duration::from_millis(500))
+        .unwrap()
+        .with_user_delay_ns(-1_000);
+    // Half a millisecond after the second, 2^31 * 2^-32 ms
+    let payload = tim_tp(2300, 604_799_000, 1 << 31, -120);
+    let schedule = plan.schedule(&TimTp::parse_payload(&payload).unwrap());
+
+    assert_eq!(
+        schedule.pulse,
+        GpsTime {
+            week: 2300,
+            tow_ns: 604_799_000_500_000,
+        }
+    );
+    assert_eq!(schedule.time_base, TimTpTimeBase::Gnss);
+    assert_eq!(schedule.q_err_ps, Some(-120));
+    assert!(!schedule.is_aligned());
+    assert_eq!(
+        schedule.triggers().collect::<Vec<_>>(),
+        [
+            GpsTime {
+                week: 2300,
+                tow_ns: 604_799_000_499_000,
+            },
+            GpsTime {
+                week: 2300,
+                tow_ns: 604_799_500_499_000,
+            },
+        ]
+    );
+
+    // The next pulse, in the next week, with an invalid quantization error
+    let mut payload = tim_tp(2301, 0, 0, 0);
+    payload[14] = 0x10;
+    let schedule = plan.schedule(&TimTp::parse_payload(&payload).unwrap());
+    assert!(schedule.is_aligned());
+    assert_eq!(schedule.q_err_ps, None);
+    assert_eq!(
+        schedule.triggers().next(),
+        Some(GpsTime {
+            week: 2300,
+            tow_ns: 604_799_999_999_000,
+        })
+    );
+}
+
+#[test]
+fn deviation_from_nearest_trigger() {
+    let plan = TriggerPlan::new(Duration::from_millis(100))
+        .unwrap()
+        .with_user_delay_ns(2_000);
+    let at = |tow_ns| plan.deviation_ns(GpsTime { week: 2300, tow_ns });
+
+    assert_eq!(at(10_000_002_000), 0);
+    assert_eq!(at(10_100_002_050), 50);
+    assert_eq!(at(10_199_999_000), -3_000);
+    assert_eq!(at(10_050_002_000), 50_000_000);
+}
@@ -0,0 +1,125 @@
+#![cfg(feature = "ubx_proto23")]
+
+use core::time::Duration;
+use ublox::{
+    proto23::Proto23,
+    ttff::{Ttff, TtffSource, TtffTimer},
+    GnssFixType,
+};
+
+#[path = "common/mod.rs"]
+mod common;
+use common::packet;
+
+fn nav_pvt(fix_type: u8, flags: u8) -> [u8; 92] {
+    let mut payload = [0; 92];
+    payload[20] = fix_type;
+    payload[21] = flags;
+    payload
+}
+
+fn nav_status(fix_type: u8, flags: u8, ttff_ms: u32) -> [u8; 16] {
+    let mut payload = [0; 16];
+    payload[4] = fix_type;
+    payload[5] = flags;
+    payload[8..12].copy_from_slice(&ttff_ms.to_le_bytes());
+    payload
+}
+
+#[test]
+fn test_ttff_from_nav_pvt() {
+    let mut timer = TtffTimer::new();
+    let fix = nav_pvt(3, 1);
+    let no_fix = nav_pvt(0, 0);
+    let unflagged_fix = nav_pvt(3, 0);
+
+    // Ignored until started
+    assert_eq!(
+        timer.update(&packet::<Proto23>(1, 7, &fix), Duration::ZERO),
+        None
+    );
+
+    timer.start(Duration::from_secs(10));
+    assert!(timer.is_running());
+    assert_eq!(
+        timer.update(&packet::<Proto23>(1, 7, &no_fix), Duration::from_secs(11)),
+        None
+    );
+    assert_eq!(
+        timer.update(
+            &packet::<Proto23>(1, 7, &unflagged_fix),
+            Duration::from_secs(12)
+        ),
+        None
+    );
+
+    let expected = Ttff {
+        duration: Duration::from_secs(30),
+        fix_type: GnssFixType::Fix3D,
+        source: TtffSource::Host,
+    };
+    assert_eq!(
+        timer.update(&packet::<Proto23>(1, 7, &fix), Duration::from_secs(40)),
+        Some(expected)
+    );
+    assert_eq!(
+        timer.update(&packet::<Proto23>(1, 7, &fix), Duration::from_secs(41)),
+        None
+    );
+    assert_eq!(timer.ttff(), Some(expected));
+    assert!(!timer.is_running());
+
+    timer.start(Duration::from_secs(50));
+    assert_eq!(timer.ttff(), None);
+}
+
+#[test]
+fn test_ttff_from_nav_status() {
+    let mut timer = TtffTimer::new();
+    timer.start(Duration::ZERO);
+
+    let not_measured = nav_status(2, 1, 0);
+    assert_eq!(
+        timer.update(
+            &packet::<Proto23>(1, 3, &not_measured),
+            Duration::from_secs(5)
+        ),
+        Some(Ttff {
+            duration: Duration::from_secs(5),
+            fix_type: GnssFixType::Fix2D,
+            source: TtffSource::Host,
+        })
+    );
+
+    timer.start(Duration::ZERO);
+    let time_only = nav_status(5, 1, 1_000);
+    assert_eq!(
+        timer.update(&packet::<Proto23>(1, 3, &time_only), Duration::from_secs(5)),
+        None
+    );
+    let measured = nav_status(3, 1, 4_200);
+    assert_eq!(
+        timer.update(&packet::<Proto23>(1, 3, &measured), Duration::from_secs(5)),
+        Some(Ttff {
+            duration: Duration::from_millis(4_200),
+            fix_type: GnssFixType::Fix3D,
+            source: TtffSource::Receiver,
+        })
+    );
+}
+
+#[test]
+fn test_start_type_reset_packet() {
+    use ublox::{ttff::StartType, verify_frame};
+
+    let packet = StartType::Cold.reset_packet();
+    let frame = verify_frame(&packet).unwrap();
+    assert_eq!((frame.class, frame.msg_id), (0x06, 0x04));
+    assert_eq!(frame.payload, [0xff, 0xff, 0x02, 0x00]);
+
+    let packet = StartType::Warm.reset_packet();
+    assert_eq!(
+        verify_frame(&packet).unwrap().payload,
+        [0x01, 0x00, 0x02, 0x00]
+    );
+}
@@ -0,0 +1,83 @@
+#![cfg(all(feature = "ubx_proto23", feature = "std"))]
+
+use ublox::{
+    proto23::{PacketRef, Proto23},
+    PositionLLA, UbxProtocol, Velocity, VelocityECEF, VelocityNED,
+};
+
+fn assert_close(a: f64, b: f64) {
+    assert!((a - b).abs() < 1e-9, "{a} != {b}");
+}
+
+#[test]
+fn nav_vel_ecef_scales_centimeters_per_second() {
+    let mut payload = [0u8; 20];
+    payload[4..8].copy_from_slice(&150_i32.to_le_bytes());
+    payload[8..12].copy_from_slice(&(-250_i32).to_le_bytes());
+    payload[12..16].copy_from_slice(&5_i32.to_le_bytes());
+    payload[16..20].copy_from_slice(&30_u32.to_le_bytes());
+
+    let Ok(PacketRef::NavVelECEF(vel)) = Proto23::match_packet(0x01, 0x11, &payload) else {
+        panic!("Not a UBX-NAV-VELECEF");
+    };
+    assert_eq!(vel.ecef_vx_raw(), 150);
+    assert_close(vel.ecef_vy_mps(), -2.5);
+    assert_close(vel.speed_accuracy_estimate(), 0.3);
+    let ecef = VelocityECEF::from(&vel);
+    assert_close(ecef.x, 1.5);
+    assert_close(ecef.y, -2.5);
+    assert_close(ecef.z, 0.05);
+}
+
+#[test]
+fn nav_vel_ned_and_ecef_give_the_same_velocity() {
+    let mut payload = [0u8; 36];
+    payload[4..8].copy_from_slice(&300_i32.to_le_bytes());
+    payload[8..12].copy_from_slice(&(-400_i32).to_le_bytes());
+    payload[12..16].copy_from_slice(&100_i32.to_le_bytes());
+    let Ok(PacketRef::NavVelNed(vel)) = Proto23::match_packet(0x01, 0x12, &payload) else {
+        panic!("Not a UBX-NAV-VELNED");
+    };
+    let ned = VelocityNED::from(&vel);
+    assert_eq!(
+        ned,
+        VelocityNED {
+            north: 3.0,
+            east: -4.0,
+            down: 1.0
+        }
+    );
+
+    let at = PositionLLA {
+        lon: 8.5,
+        lat: 47.4,
+        alt: 400.0,
+    };
+    let ecef = ned.to_ecef(&at);
+    assert_close(
+        (ecef.x.powi(2) + ecef.y.powi(2) + ecef.z.powi(2)).sqrt(),
+        26.0_f64.sqrt(),
+    );
+    let back = ecef.to_ned(&at);
+    assert_close(back.north, 3.0);
+    assert_close(back.east, -4.0);
+    assert_close(back.down, 1.0);
+
+    let velocity = Velocity::from(ned);
+    assert_close(velocity.speed, 5.0);
+    assert_close(velocity.heading, 360.0 - 53.130_102_354_155_98);
+}
+
+#[test]
+fn nav_pvt_provides_ned_velocity() {
+    let mut payload = [0u8; 92];
+    payload[48..52].copy_from_slice(&(-1_000_i32).to_le_bytes());
+    payload[52..56].copy_from_slice(&2_000_i32.to_le_bytes());
+    let Ok(PacketRef::NavPvt(pvt)) = Proto23::match_packet(0x01, 0x07, &payload) else {
+        panic!("Not a UBX-NAV-PVT");
+    };
+    let ned = VelocityNED::from(&pvt);
+    assert_close(ned.north, -1.0);
+    assert_close(ned.east, 2.0);
+    assert_close(ned.down, 0.0);
+}
@@ -0,0 +1,292 @@
+//! Configuration key database: the key table of `ublox::cfg_val` from `cfg_keys.json`
+//!
+//! The description lists the keys by group, each key with its id, the type of its value as
+//! given by the interface description (`L`, `U1` ... `R8`), its scale and the layers it can be
+//! set in. Enumerations and bitfields name the type of the ublox crate they are decoded into.
+
+use std::{collections::HashSet, fmt::Write};
+
+use serde::Deserialize;
+
+use crate::{push_doc, Error, File};
+
+/// Path of the generated file, included by `ublox::cfg_val`
+const PATH: &str = "ublox/src/ubx_packets/generated/cfg_keys.rs";
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Description {
+    groups: Vec<Group>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Group {
+    /// Name of the group, e.g. `CFG-UART1`
+    name: Option<String>,
+    description: Option<String>,
+    keys: Vec<Key>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Key {
+    /// Name of the key in the ublox crate, e.g. `Uart1Baudrate`
+    item: String,
+    /// Key id as a hexadecimal number
+    id: String,
+    #[serde(rename = "type")]
+    value_type: ValueType,
+    /// Type of the ublox crate an `E1` value is decoded into
+    #[serde(rename = "enum")]
+    enumeration: Option<String>,
+    /// Type of the ublox crate an `X*` value is decoded into, its unsigned integer by default
+    bitfield: Option<String>,
+    /// Scale of the raw value, as a Rust float literal
+    scale: Option<String>,
+    /// Layers the key can be set in
+    layers: Vec<Layer>,
+    description: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+enum ValueType {
+    L,
+    U1,
+    U2,
+    U4,
+    U8,
+    I1,
+    I2,
+    I4,
+    E1,
+    X1,
+    X2,
+    X4,
+    X8,
+    R4,
+    R8,
+}
+
+impl ValueType {
+    /// Size of the value as encoded in bits 28 to 30 of the key id
+    const fn size_id(self) -> u32 {
+        match self {
+            Self::L => 1,
+            Self::U1 | Self::I1 | Self::E1 | Self::X1 => 2,
+            Self::U2 | Self::I2 | Self::X2 => 3,
+            Self::U4 | Self::I4 | Self::X4 | Self::R4 => 4,
+            Self::U8 | Self::X8 | Self::R8 => 5,
+        }
+    }
+
+    /// The Rust type of the values without an enumeration or bitfield type
+    const fn rust_type(self) -> Option<&'static str> {
+        match self {
+            Self::L => Some("bool"),
+            Self::U1 | Self::X1 => Some("u8"),
+            Self::U2 | Self::X2 => Some("u16"),
+            Self::U4 | Self::X4 => Some("u32"),
+            Self::U8 | Self::X8 => Some("u64"),
+            Self::I1 => Some("i8"),
+            Self::I2 => Some("i16"),
+            Self::I4 => Some("i32"),
+            Self::R4 => Some("f32"),
+            Self::R8 => Some("f64"),
+            Self::E1 => None,
+        }
+    }
+
+    const fn is_integer(self) -> bool {
+        !matches!(self, Self::L | Self::E1 | Self::R4 | Self::R8)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+enum Layer {
+    Ram,
+    Bbr,
+    Flash,
+}
+
+impl Layer {
+    /// Name of the `CfgLayerSet` flag
+    const fn flag(self) -> &'static str {
+        match self {
+            Self::Ram => "RAM",
+            Self::Bbr => "BBR",
+            Self::Flash => "FLASH",
+        }
+    }
+}
+
+impl Key {
+    fn rust_type(&self) -> Result<&str, Error> {
+        match (self.value_type, &self.enumeration, &self.bitfield) {
+            (ValueType::E1, Some(enumeration), None) => Ok(enumeration),
+            (ValueType::E1, None, _) => Err(Error::invalid(&self.item, "E1 without enum")),
+            (
+                ValueType::X1 | ValueType::X2 | ValueType::X4 | ValueType::X8,
+                None,
+                Some(bitfield),
+            ) => Ok(bitfield),
+            (value_type, None, None) => Ok(value_type.rust_type().expect("not an enumeration")),
+            _ => Err(Error::invalid(
+                &self.item,
+                "enum or bitfield on a value of another type",
+            )),
+        }
+    }
+
+    fn id(&self) -> Result<u32, Error> {
+        let id = self
+            .id
+            .strip_prefix("0x")
+            .and_then(|id| u32::from_str_radix(id, 16).ok())
+            .ok_or_else(|| Error::invalid(&self.item, format!("invalid id {}", self.id)))?;
+        if (id >> 28) & 0b111 != self.value_type.size_id() {
+            return Err(Error::invalid(
+                &self.item,
+                format!("id {} does not match type {:?}", self.id, self.value_type),
+            ));
+        }
+        Ok(id)
+    }
+
+    fn check_scale(&self) -> Result<(), Error> {
+        let Some(scale) = &self.scale else {
+            return Ok(());
+        };
+        if !self.value_type.is_integer() || self.enumeration.is_some() || self.bitfield.is_some() {
+            return Err(Error::invalid(&self.item, "scale of a non-integer value"));
+        }
+        match scale.parse::<f64>() {
+            Ok(scale) if scale > 0.0 => Ok(()),
+            _ => Err(Error::invalid(&self.item, format!("invalid scale {scale}"))),
+        }
+    }
+
+    fn layers(&self) -> Result<String, Error> {
+        let mut seen = HashSet::new();
+        if self.layers.is_empty() || !self.layers.iter().all(|layer| seen.insert(layer)) {
+            return Err(Error::invalid(&self.item, "empty or repeated layers"));
+        }
+        Ok(self
+            .layers
+            .iter()
+            .map(|layer| layer.flag())
+            .collect::<Vec<_>>()
+            .join(" | "))
+    }
+}
+
+/// The generated file of `description`
+pub fn files(description: &str) -> Result<Vec<File>, Error> {
+    Ok(vec![File {
+        path: PATH.to_string(),
+        source: generate(description)?,
+    }])
+}
+
+/// Generate the `cfg_val!` key table and the `cfg_scale!` scales from `description`
+pub fn generate(description: &str) -> Result<String, Error> {
+    let description: Description = serde_json::from_str(description)?;
+    let mut items = HashSet::new();
+    let mut ids = HashSet::new();
+    let mut table = String::from("cfg_val! {\n");
+    let mut scales = String::from("cfg_scale! {\n");
+    for (i, group) in description.groups.iter().enumerate() {
+        if i > 0 {
+            table.push('\n');
+        }
+        match (&group.name, &group.description) {
+            (Some(name), Some(description)) => writeln!(table, "  // {name}: {description}"),
+            (Some(name), None) => writeln!(table, "  // {name}"),
+            (None, _) => Ok(()),
+        }
+        .expect("writing to a String");
+        for key in &group.keys {
+            let id = key.id()?;
+            if !items.insert(&key.item) || !ids.insert(id) {
+                return Err(Error::invalid(&key.item, "repeated item or id"));
+            }
+            key.check_scale()?;
+            if let Some(description) = &key.description {
+                push_doc(&mut table, "  ", description);
+            }
+            writeln!(
+                table,
+                "  {}, 0x{id:08x}, {}, {},",
+                key.item,
+                key.rust_type()?,
+                key.layers()?
+            )
+            .expect("writing to a String");
+            if let Some(scale) = &key.scale {
+                writeln!(scales, "    {}: {scale},", key.item).expect("writing to a String");
+            }
+        }
+    }
+    table.push_str("}\n");
+    scales.push_str("}\n");
+    Ok(format!("{table}\n{scales}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generates_key_rows() {
+        let description = r#"{
+            "groups": [{
+                "name": "CFG-RATE",
+                "keys": [
+                    {"item": "RateMeas", "id": "0x30210001", "type": "U2", "scale": "1e-3",
+                     "layers": ["RAM", "BBR"], "description": "Measurement period\n\nIn ms"},
+                    {"item": "RateTimeref", "id": "0x20210003", "type": "E1",
+                     "enum": "AlignmentToReferenceTime", "layers": ["RAM"]}
+                ]
+            }]
+        }"#;
+        assert_eq!(
+            generate(description).unwrap(),
+            "cfg_val! {
+  // CFG-RATE
+  /// Measurement period
+  ///
+  /// In ms
+  RateMeas, 0x30210001, u16, RAM | BBR,
+  RateTimeref, 0x20210003, AlignmentToReferenceTime, RAM,
+}
+
+cfg_scale! {
+    RateMeas: 1e-3,
+}
+"
+        );
+    }
+
+    #[test]
+    fn rejects_inconsistent_keys() {
+        let key = |key: &str| format!(r#"{{"groups": [{{"keys": [{key}]}}]}}"#);
+        for invalid in [
+            // Size of a U4 in the id of a U2
+            r#"{"item": "A", "id": "0x40210001", "type": "U2", "layers": ["RAM"]}"#,
+            r#"{"item": "A", "id": "0x20210003", "type": "E1", "layers": ["RAM"]}"#,
+            r#"{"item": "A", "id": "0x10210003", "type": "L", "scale": "0.1", "layers": ["RAM"]}"#,
+            r#"{"item": "A", "id": "0x30210001", "type": "U2", "layers": []}"#,
+            r#"{"item": "A", "id": "0x30210001", "type": "U2", "layers": ["RAM", "RAM"]}"#,
+        ] {
+            assert!(
+                matches!(generate(&key(invalid)), Err(Error::Invalid { .. })),
+                "{invalid}"
+            );
+        }
+        assert!(matches!(
+            generate(&key(r#"{"item": "A", "id": "0x30210001", "type": "U2"}"#)),
+            Err(Error::Json(_))
+        ));
+    }
+}
@@ -0,0 +1,136 @@
+//! Generation of the ublox sources from the u-blox interface descriptions in `ublox/interface`
+//!
+//! The generated files are committed. Run `cargo run -p ublox_codegen` after changing a
+//! description, the tests of this crate fail while a generated file is out of date.
+
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+pub mod cfg_keys;
+pub mod packets;
+
+/// An interface description and the generator of its source files
+pub struct Generator {
+    /// Path of the interface description, relative to the workspace
+    pub description: &'static str,
+    generate: fn(&str) -> Result<Vec<File>, Error>,
+}
+
+/// A source file generated from an interface description
+#[derive(Debug)]
+pub struct File {
+    /// Path of the file, relative to the workspace
+    pub path: String,
+    pub source: String,
+}
+
+/// The interface descriptions and their generators
+pub const GENERATORS: &[Generator] = &[
+    Generator {
+        description: "ublox/interface/cfg_keys.json",
+        generate: cfg_keys::files,
+    },
+    Generator {
+        description: "ublox/interface/packets.json",
+        generate: packets::files,
+    },
+];
+
+impl Generator {
+    /// Generate the files from the description in the `workspace` directory
+    pub fn generate(&self, workspace: &Path) -> Result<Vec<File>, Error> {
+        let description = fs::read_to_string(workspace.join(self.description))?;
+        let mut files = (self.generate)(&description)?;
+        for file in &mut files {
+            file.source = format!(
+                "// Generated by ublox_codegen from {}, do not edit\n\n{}",
+                self.description, file.source
+            );
+        }
+        Ok(files)
+    }
+}
+
+impl File {
+    /// The committed version of the file in the `workspace` directory
+    pub fn committed(&self, workspace: &Path) -> Result<String, Error> {
+        Ok(fs::read_to_string(workspace.join(&self.path))?)
+    }
+}
+
+/// The workspace directory, the parent of this crate's directory
+pub fn workspace_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("the crate is in the workspace")
+        .to_path_buf()
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    /// The description is no valid JSON or does not match the expected layout
+    Json(serde_json::Error),
+    /// An entry of the description is inconsistent, e.g. a key id not matching its type
+    Invalid {
+        name: String,
+        reason: String,
+    },
+}
+
+impl Error {
+    fn invalid(name: &str, reason: impl Into<String>) -> Self {
+        Self::Invalid {
+            name: name.to_string(),
+            reason: reason.into(),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "I/O error: {e}"),
+            Self::Json(e) => write!(f, "invalid description: {e}"),
+            Self::Invalid { name, reason } => write!(f, "invalid description of {name}: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            Self::Json(e) => Some(e),
+            Self::Invalid { .. } => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Self::Json(e)
+    }
+}
+
+/// Append the lines of `text` to `out` as `///` comments indented by `indent`
+pub(crate) fn push_doc(out: &mut String, indent: &str, text: &str) {
+    for line in text.lines() {
+        out.push_str(indent);
+        if line.is_empty() {
+            out.push_str("///\n");
+        } else {
+            out.push_str("/// ");
+            out.push_str(line);
+            out.push('\n');
+        }
+    }
+}
@@ -0,0 +1,48 @@
+//! Regenerate the generated ublox sources, or with `--check` only verify that they are up to
+//! date
+
+use std::{env, fs, process::ExitCode};
+
+use ublox_codegen::{workspace_dir, GENERATORS};
+
+fn main() -> ExitCode {
+    let check = match env::args().nth(1).as_deref() {
+        None => false,
+        Some("--check") => true,
+        Some(_) => {
+            eprintln!("usage: ublox_codegen [--check]");
+            return ExitCode::FAILURE;
+        },
+    };
+    let workspace = workspace_dir();
+    let mut outdated = false;
+    for generator in GENERATORS {
+        let files = match generator.generate(&workspace) {
+            Ok(files) => files,
+            Err(e) => {
+                eprintln!("{}: {e}", generator.description);
+                return ExitCode::FAILURE;
+            },
+        };
+        for file in files {
+            let committed = file.committed(&workspace).ok();
+            if committed.as_deref() == Some(file.source.as_str()) {
+                continue;
+            }
+            if check {
+                eprintln!("{} is out of date", file.path);
+                outdated = true;
+            } else if let Err(e) = fs::write(workspace.join(&file.path), &file.source) {
+                eprintln!("{}: {e}", file.path);
+                return ExitCode::FAILURE;
+            } else {
+                println!("Generated {}", file.path);
+            }
+        }
+    }
+    if outdated {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
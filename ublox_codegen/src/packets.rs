@@ -0,0 +1,460 @@
+//! Packet definitions: one module of `ublox::ubx_packets::packets` per packet of `packets.json`
+//!
+//! The description lists the fixed length packets received from the device, each with its class,
+//! id, length and fields in payload order. A field has the type given by the interface
+//! description (`U1` ... `R8`), optionally a unit and scale, and `X*` fields a bitfield decoded
+//! into a `bitflags` type. The generated modules are registered in the `packetref_proto*.rs`
+//! packet lists of the protocols supporting them.
+
+use std::{collections::HashSet, fmt::Write};
+
+use serde::Deserialize;
+
+use crate::{push_doc, Error, File};
+
+/// Directory of the generated modules
+const DIRECTORY: &str = "ublox/src/ubx_packets/packets";
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Description {
+    packets: Vec<Packet>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Packet {
+    /// Name of the packet in the interface description, e.g. `UBX-NAV-TIMEGLO`
+    name: String,
+    /// Name of the packet struct, e.g. `NavTimeGlo`
+    #[serde(rename = "struct")]
+    struct_name: String,
+    /// Name of the generated module, e.g. `nav_time_glo`
+    module: String,
+    /// Class as a hexadecimal byte
+    class: String,
+    /// Message id as a hexadecimal byte
+    id: String,
+    /// Payload length, checked against the fields
+    length: usize,
+    description: String,
+    fields: Vec<Field>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Field {
+    /// Name of the field in the interface description, e.g. `iTOW`
+    name: String,
+    /// Name of the field in the packet struct, e.g. `itow`
+    field: String,
+    #[serde(rename = "type")]
+    field_type: FieldType,
+    /// Number of elements of a `U1` array, e.g. of reserved bytes
+    count: Option<usize>,
+    unit: Option<String>,
+    /// Scale of the raw value, as a Rust float literal, generating the accessor in `unit`
+    scale: Option<String>,
+    bitfield: Option<Bitfield>,
+    description: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Bitfield {
+    /// Name of the generated `bitflags` type
+    #[serde(rename = "type")]
+    type_name: String,
+    bits: Vec<Bit>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Bit {
+    /// Name of the bit in the interface description, e.g. `todValid`
+    name: String,
+    /// Name of the flag constant, e.g. `VALID_TOD`
+    flag: String,
+    bit: u32,
+    description: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+enum FieldType {
+    U1,
+    U2,
+    U4,
+    I1,
+    I2,
+    I4,
+    X1,
+    X2,
+    X4,
+    R4,
+    R8,
+}
+
+impl FieldType {
+    const fn size(self) -> usize {
+        match self {
+            Self::U1 | Self::I1 | Self::X1 => 1,
+            Self::U2 | Self::I2 | Self::X2 => 2,
+            Self::U4 | Self::I4 | Self::X4 | Self::R4 => 4,
+            Self::R8 => 8,
+        }
+    }
+
+    const fn rust_type(self) -> &'static str {
+        match self {
+            Self::U1 | Self::X1 => "u8",
+            Self::U2 | Self::X2 => "u16",
+            Self::U4 | Self::X4 => "u32",
+            Self::I1 => "i8",
+            Self::I2 => "i16",
+            Self::I4 => "i32",
+            Self::R4 => "f32",
+            Self::R8 => "f64",
+        }
+    }
+
+    const fn is_bitfield(self) -> bool {
+        matches!(self, Self::X1 | Self::X2 | Self::X4)
+    }
+
+    const fn is_integer(self) -> bool {
+        !matches!(self, Self::R4 | Self::R8)
+    }
+}
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn parse_byte(packet: &str, value: &str) -> Result<u8, Error> {
+    value
+        .strip_prefix("0x")
+        .filter(|value| value.len() == 2)
+        .and_then(|value| u8::from_str_radix(value, 16).ok())
+        .ok_or_else(|| Error::invalid(packet, format!("invalid class or id {value}")))
+}
+
+impl Field {
+    /// Size of the field in the payload
+    fn size(&self, packet: &str) -> Result<usize, Error> {
+        match (self.field_type, self.count) {
+            (field_type, None) => Ok(field_type.size()),
+            (FieldType::U1, Some(count)) if count > 0 => Ok(count),
+            _ => Err(Error::invalid(
+                packet,
+                format!("{}: count of a non-U1 field or of zero", self.name),
+            )),
+        }
+    }
+
+    fn check(&self, packet: &str) -> Result<(), Error> {
+        let invalid = |reason: &str| Error::invalid(packet, format!("{}: {reason}", self.name));
+        if !is_identifier(&self.field) {
+            return Err(invalid("invalid field name"));
+        }
+        if self.scale.is_some() {
+            if !self.field_type.is_integer() || self.count.is_some() || self.bitfield.is_some() {
+                return Err(invalid("scale of a non-integer field"));
+            }
+            if self.unit.is_none() {
+                return Err(invalid("scale without unit"));
+            }
+            match self.scale.as_deref().map(str::parse::<f64>) {
+                Some(Ok(scale)) if scale > 0.0 => {},
+                _ => return Err(invalid("invalid scale")),
+            }
+        }
+        let Some(bitfield) = &self.bitfield else {
+            return Ok(());
+        };
+        if !self.field_type.is_bitfield() || self.count.is_some() {
+            return Err(invalid("bitfield of a non-X field"));
+        }
+        if !is_identifier(&bitfield.type_name) {
+            return Err(invalid("invalid bitfield type name"));
+        }
+        let mut flags = HashSet::new();
+        let mut bits = HashSet::new();
+        for bit in &bitfield.bits {
+            if !is_identifier(&bit.flag)
+                || bit.bit as usize >= self.field_type.size() * 8
+                || !flags.insert(&bit.flag)
+                || !bits.insert(bit.bit)
+            {
+                return Err(invalid(&format!(
+                    "invalid or repeated bit {} ({})",
+                    bit.name, bit.flag
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    fn rust_type(&self) -> String {
+        match self.count {
+            Some(count) => format!("[u8; {count}]"),
+            None => self.field_type.rust_type().to_string(),
+        }
+    }
+
+    fn write(&self, out: &mut String) {
+        if let Some(description) = &self.description {
+            let doc = match (&self.unit, &self.scale) {
+                (Some(unit), None) => format!("{description} ({unit})."),
+                _ => format!("{description}."),
+            };
+            push_doc(out, "    ", &doc);
+        }
+        match (&self.bitfield, &self.scale, &self.unit) {
+            (Some(bitfield), ..) => {
+                writeln!(out, "    #[ubx(map_type = {})]", bitfield.type_name)
+            },
+            (None, Some(scale), Some(unit)) => {
+                writeln!(out, "    #[ubx(scale = {scale}, unit = \"{unit}\")]")
+            },
+            _ => Ok(()),
+        }
+        .expect("writing to a String");
+        writeln!(out, "    {}: {},", self.field, self.rust_type()).expect("writing to a String");
+    }
+}
+
+impl Bitfield {
+    fn write(&self, out: &mut String, field: &Field, packet: &Packet) {
+        writeln!(
+            out,
+            "\n#[ubx_extend_bitflags]\n#[ubx(from, rest_reserved)]\nbitflags! {{\n    /// {} of `{}`\n    #[derive(Default, Debug)]\n    pub struct {}: {} {{",
+            field.description.as_deref().unwrap_or("Flags"),
+            packet.struct_name,
+            self.type_name,
+            field.field_type.rust_type()
+        )
+        .expect("writing to a String");
+        for bit in &self.bits {
+            push_doc(out, "        ", &format!("{}.", bit.description));
+            writeln!(out, "        const {} = 0x{:x};", bit.flag, 1u32 << bit.bit)
+                .expect("writing to a String");
+        }
+        out.push_str("    }\n}\n");
+    }
+}
+
+impl Packet {
+    fn check(&self) -> Result<(u8, u8), Error> {
+        let class = parse_byte(&self.name, &self.class)?;
+        let id = parse_byte(&self.name, &self.id)?;
+        if !is_identifier(&self.struct_name) || !is_identifier(&self.module) {
+            return Err(Error::invalid(&self.name, "invalid struct or module name"));
+        }
+        let mut names = HashSet::new();
+        let mut length = 0;
+        for field in &self.fields {
+            field.check(&self.name)?;
+            if !names.insert(&field.field) {
+                return Err(Error::invalid(
+                    &self.name,
+                    format!("repeated field {}", field.field),
+                ));
+            }
+            length += field.size(&self.name)?;
+        }
+        if length != self.length {
+            return Err(Error::invalid(
+                &self.name,
+                format!("fields of {length} bytes in a payload of {}", self.length),
+            ));
+        }
+        Ok((class, id))
+    }
+
+    /// The module defining the packet and the `bitflags` types of its bitfields
+    fn generate(&self) -> Result<String, Error> {
+        let (class, id) = self.check()?;
+        let has_bitfields = self.fields.iter().any(|field| field.bitfield.is_some());
+        let mut out = String::new();
+        if has_bitfields {
+            out.push_str("use bitflags::bitflags;\n\n");
+        }
+        out.push_str(
+            "#[cfg(feature = \"serde\")]\nuse super::SerializeUbxPacketFields;\n#[cfg(feature = \"serde\")]\nuse crate::serde::ser::SerializeMap;\n\nuse crate::{error::ParserError, UbxPacketMeta};\n",
+        );
+        out.push_str(if has_bitfields {
+            "use ublox_derive::{ubx_extend_bitflags, ubx_packet_recv};\n\n"
+        } else {
+            "use ublox_derive::ubx_packet_recv;\n\n"
+        });
+        push_doc(
+            &mut out,
+            "",
+            &format!("{} ({})", self.description, self.name),
+        );
+        writeln!(
+            out,
+            "#[ubx_packet_recv]\n#[ubx(class = 0x{class:02x}, id = 0x{id:02x}, fixed_payload_len = {})]\nstruct {} {{",
+            self.length, self.struct_name
+        )
+        .expect("writing to a String");
+        for (i, field) in self.fields.iter().enumerate() {
+            if i > 0 {
+                out.push('\n');
+            }
+            field.write(&mut out);
+        }
+        out.push_str("}\n");
+        for field in &self.fields {
+            if let Some(bitfield) = &field.bitfield {
+                bitfield.write(&mut out, field, self);
+            }
+        }
+        Ok(out)
+    }
+}
+
+/// The packet modules of `description`
+pub fn files(description: &str) -> Result<Vec<File>, Error> {
+    let description: Description = serde_json::from_str(description)?;
+    let mut names = HashSet::new();
+    let mut ids = HashSet::new();
+    let mut files = Vec::new();
+    for packet in &description.packets {
+        let source = packet.generate()?;
+        if !names.insert(&packet.struct_name)
+            || !names.insert(&packet.module)
+            || !ids.insert((&packet.class, &packet.id))
+        {
+            return Err(Error::invalid(
+                &packet.name,
+                "repeated struct, module, class and id",
+            ));
+        }
+        files.push(File {
+            path: format!("{DIRECTORY}/{}.rs", packet.module),
+            source,
+        });
+    }
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn packet(fields: &str, length: usize) -> String {
+        format!(
+            r#"{{"packets": [{{"name": "UBX-NAV-TEST", "struct": "NavTest", "module": "nav_test",
+                "class": "0x01", "id": "0x7f", "length": {length},
+                "description": "Test solution", "fields": [{fields}]}}]}}"#
+        )
+    }
+
+    #[test]
+    fn generates_packet_modules() {
+        let description = packet(
+            r#"{"name": "iTOW", "field": "itow", "type": "U4", "unit": "ms",
+                "description": "GPS time of week"},
+               {"name": "reserved0", "field": "reserved0", "type": "U1", "count": 2},
+               {"name": "velN", "field": "vel_n", "type": "I2", "unit": "m/s", "scale": "1e-2",
+                "description": "North velocity"},
+               {"name": "valid", "field": "valid", "type": "X1", "description": "Validity flags",
+                "bitfield": {"type": "NavTestFlags", "bits": [
+                  {"name": "towValid", "flag": "VALID_TOW", "bit": 0, "description": "Valid tow"},
+                  {"name": "velValid", "flag": "VALID_VEL", "bit": 3, "description": "Valid vel"}
+                ]}}"#,
+            9,
+        );
+        let files = files(&description).unwrap();
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].path, "ublox/src/ubx_packets/packets/nav_test.rs");
+        assert_eq!(
+            files[0].source,
+            r#"use bitflags::bitflags;
+
+#[cfg(feature = "serde")]
+use super::SerializeUbxPacketFields;
+#[cfg(feature = "serde")]
+use crate::serde::ser::SerializeMap;
+
+use crate::{error::ParserError, UbxPacketMeta};
+use ublox_derive::{ubx_extend_bitflags, ubx_packet_recv};
+
+/// Test solution (UBX-NAV-TEST)
+#[ubx_packet_recv]
+#[ubx(class = 0x01, id = 0x7f, fixed_payload_len = 9)]
+struct NavTest {
+    /// GPS time of week (ms).
+    itow: u32,
+
+    reserved0: [u8; 2],
+
+    /// North velocity.
+    #[ubx(scale = 1e-2, unit = "m/s")]
+    vel_n: i16,
+
+    /// Validity flags.
+    #[ubx(map_type = NavTestFlags)]
+    valid: u8,
+}
+
+#[ubx_extend_bitflags]
+#[ubx(from, rest_reserved)]
+bitflags! {
+    /// Validity flags of `NavTest`
+    #[derive(Default, Debug)]
+    pub struct NavTestFlags: u8 {
+        /// Valid tow.
+        const VALID_TOW = 0x1;
+        /// Valid vel.
+        const VALID_VEL = 0x8;
+    }
+}
+"#
+        );
+    }
+
+    #[test]
+    fn rejects_inconsistent_packets() {
+        for (fields, length) in [
+            // Payload length not matching the fields
+            (r#"{"name": "a", "field": "a", "type": "U4"}"#, 2),
+            (
+                r#"{"name": "a", "field": "a", "type": "R4", "unit": "m", "scale": "1e-2"}"#,
+                4,
+            ),
+            (
+                r#"{"name": "a", "field": "a", "type": "U2", "scale": "1e-2"}"#,
+                2,
+            ),
+            (
+                r#"{"name": "a", "field": "a", "type": "U2", "count": 2}"#,
+                4,
+            ),
+            (r#"{"name": "a", "field": "a b", "type": "U1"}"#, 1),
+            (
+                r#"{"name": "a", "field": "a", "type": "U1"}, {"name": "b", "field": "a", "type": "U1"}"#,
+                2,
+            ),
+            (
+                r#"{"name": "a", "field": "a", "type": "X1", "bitfield": {"type": "F", "bits": [
+                    {"name": "b", "flag": "B", "bit": 8, "description": ""}]}}"#,
+                1,
+            ),
+            (
+                r#"{"name": "a", "field": "a", "type": "U1", "bitfield": {"type": "F", "bits": []}}"#,
+                1,
+            ),
+        ] {
+            assert!(
+                matches!(files(&packet(fields, length)), Err(Error::Invalid { .. })),
+                "{fields}"
+            );
+        }
+    }
+}
@@ -0,0 +1,19 @@
+//! The committed generated files must match their interface descriptions
+
+use ublox_codegen::{workspace_dir, GENERATORS};
+
+#[test]
+fn generated_files_are_up_to_date() {
+    let workspace = workspace_dir();
+    for generator in GENERATORS {
+        for file in generator.generate(&workspace).unwrap() {
+            let committed = file.committed(&workspace).unwrap_or_default();
+            assert!(
+                file.source == committed,
+                "{} is out of date with {}, run `cargo run -p ublox_codegen`",
+                file.path,
+                generator.description
+            );
+        }
+    }
+}
@@ -0,0 +1,16 @@
+//! Layout of the UBX frame: sync chars, header, payload and checksum
+
+/// UBX Sync Character 1 (0xB5 = 'µ')
+pub const UBX_SYNC_CHAR_1: u8 = 0xB5;
+/// UBX Sync Character 2 (0x62 = 'b')
+pub const UBX_SYNC_CHAR_2: u8 = 0x62;
+pub const UBX_SYNC_SIZE: usize = 2;
+pub const UBX_PAYLOAD_SIZE_LEN: usize = 2;
+pub const UBX_CLASS_LEN: usize = 1;
+pub const UBX_ID_LEN: usize = 1;
+pub const UBX_HEADER_LEN: usize = UBX_SYNC_SIZE + UBX_CLASS_LEN + UBX_ID_LEN + UBX_PAYLOAD_SIZE_LEN;
+pub const UBX_CHECKSUM_LEN: usize = 2;
+
+pub const UBX_CLASS_OFFSET: usize = UBX_SYNC_SIZE; // After SYNC_CHAR_1, SYNC_CHAR_2
+pub const UBX_MSG_ID_OFFSET: usize = UBX_CLASS_OFFSET + UBX_CLASS_LEN; // After CLASS
+pub const UBX_LENGTH_OFFSET: usize = UBX_MSG_ID_OFFSET + UBX_ID_LEN; // After MSG_ID
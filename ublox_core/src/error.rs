@@ -0,0 +1,157 @@
+use core::fmt;
+
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum MemWriterError<E> {
+    NotEnoughMem,
+    Custom(E),
+}
+
+impl<E: core::fmt::Display> fmt::Display for MemWriterError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MemWriterError::NotEnoughMem => f.write_str("Not enough memory error"),
+            MemWriterError::Custom(e) => write!(f, "MemWriterError: {e}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E> std::error::Error for MemWriterError<E> where E: std::error::Error {}
+
+/// Error that possible during packets parsing
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ParserError {
+    InvalidChecksum {
+        expect: u16,
+        got: u16,
+    },
+    /// Returned when the CRC-24Q of an RTCM3 frame does not match its trailer
+    InvalidRtcmCrc {
+        expect: u32,
+        got: u32,
+    },
+    /// Returned when the frame CRC-4 in the header of a SPARTN frame does not match
+    InvalidSpartnFrameCrc {
+        expect: u8,
+        got: u8,
+    },
+    /// Returned when the message CRC ending a SPARTN frame does not match
+    InvalidSpartnCrc {
+        expect: u32,
+        got: u32,
+    },
+    InvalidField {
+        packet: &'static str,
+        field: &'static str,
+    },
+    InvalidPacketLen {
+        packet: &'static str,
+        expect: usize,
+        got: usize,
+    },
+    /// Returned when the parser buffer is not big enough to store the packet
+    OutOfMemory {
+        // The required size of the buffer to store the packet payload (and checksum if applicable)
+        required_size: usize,
+    },
+    /// Returned when a partial frame is abandoned after waiting longer than the partial frame
+    /// timeout of the parser for its end
+    PartialFrameTimeout {
+        /// Bytes of the frame received before it was abandoned
+        received: usize,
+    },
+}
+
+impl fmt::Display for ParserError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParserError::InvalidChecksum { expect, got } => write!(
+                f,
+                "Not valid packet's checksum, expect 0x{expect:02x}, got 0x{got:02x}"
+            ),
+            ParserError::InvalidRtcmCrc { expect, got } => write!(
+                f,
+                "Not valid RTCM3 frame's CRC, expect 0x{expect:06x}, got 0x{got:06x}"
+            ),
+            ParserError::InvalidSpartnFrameCrc { expect, got } => write!(
+                f,
+                "Not valid SPARTN frame CRC, expect 0x{expect:x}, got 0x{got:x}"
+            ),
+            ParserError::InvalidSpartnCrc { expect, got } => write!(
+                f,
+                "Not valid SPARTN message CRC, expect 0x{expect:x}, got 0x{got:x}"
+            ),
+            ParserError::InvalidField { packet, field } => {
+                write!(f, "Invalid field {field} of packet {packet}")
+            },
+            ParserError::InvalidPacketLen {
+                packet,
+                expect,
+                got,
+            } => write!(
+                f,
+                "Invalid packet({packet}) length, expect {expect}, got {got}"
+            ),
+            ParserError::OutOfMemory { required_size } => write!(
+                f,
+                "Insufficient parser buffer size, required {required_size} bytes"
+            ),
+            ParserError::PartialFrameTimeout { received } => write!(
+                f,
+                "Partial frame abandoned after {received} bytes without its end"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParserError {}
+
+/// Error returned by [verify_frame](crate::verify_frame)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ChecksumError {
+    /// The frame does not start with the UBX sync chars
+    InvalidSync,
+    /// The frame length does not match the payload length in its header
+    InvalidLength { expect: usize, got: usize },
+    /// The received checksum (`expect`) does not match the one calculated over the frame (`got`)
+    Mismatch { expect: u16, got: u16 },
+}
+
+impl fmt::Display for ChecksumError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChecksumError::InvalidSync => f.write_str("Missing UBX sync chars"),
+            ChecksumError::InvalidLength { expect, got } => {
+                write!(f, "Invalid UBX frame length, expect {expect}, got {got}")
+            },
+            ChecksumError::Mismatch { expect, got } => write!(
+                f,
+                "Not valid packet's checksum, expect 0x{expect:02x}, got 0x{got:02x}"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ChecksumError {}
+
+impl From<ChecksumError> for ParserError {
+    fn from(err: ChecksumError) -> Self {
+        match err {
+            ChecksumError::InvalidSync => ParserError::InvalidField {
+                packet: "UBX",
+                field: "sync",
+            },
+            ChecksumError::InvalidLength { expect, got } => ParserError::InvalidPacketLen {
+                packet: "UBX",
+                expect,
+                got,
+            },
+            ChecksumError::Mismatch { expect, got } => ParserError::InvalidChecksum { expect, got },
+        }
+    }
+}
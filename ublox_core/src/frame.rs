@@ -0,0 +1,82 @@
+use crate::{
+    constants::{
+        UBX_CHECKSUM_LEN, UBX_CLASS_OFFSET, UBX_HEADER_LEN, UBX_LENGTH_OFFSET, UBX_MSG_ID_OFFSET,
+        UBX_SYNC_CHAR_1, UBX_SYNC_CHAR_2, UBX_SYNC_SIZE,
+    },
+    ChecksumError,
+};
+
+/// The checksum is calculated over the packet, starting and including
+/// the CLASS field, up until, but excluding, the checksum field.
+/// So slice should starts with class id.
+/// Return ck_a and ck_b
+pub fn ubx_checksum(data: &[u8]) -> (u8, u8) {
+    let mut ck_a = 0_u8;
+    let mut ck_b = 0_u8;
+    for byte in data {
+        ck_a = ck_a.overflowing_add(*byte).0;
+        ck_b = ck_b.overflowing_add(ck_a).0;
+    }
+    (ck_a, ck_b)
+}
+
+/// Class, message id and payload of a UBX frame checked by [verify_frame]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FrameParts<'a> {
+    pub class: u8,
+    pub msg_id: u8,
+    pub payload: &'a [u8],
+}
+
+/// Verify the length and checksum of a complete UBX frame, from the sync chars up to and
+/// including the checksum, without going through a parser
+///
+/// ```
+/// use ublox_core::{verify_frame, ChecksumError};
+///
+/// let mut frame = [0xb5, 0x62, 0x05, 0x01, 0x02, 0x00, 0x04, 0x05, 0x11, 0x38];
+/// let parts = verify_frame(&frame).unwrap();
+/// assert_eq!((parts.class, parts.msg_id, parts.payload), (0x05, 0x01, &[0x04, 0x05][..]));
+///
+/// frame[9] = 0x39;
+/// assert_eq!(
+///     verify_frame(&frame),
+///     Err(ChecksumError::Mismatch { expect: 0x3911, got: 0x3811 })
+/// );
+/// ```
+pub fn verify_frame(frame: &[u8]) -> Result<FrameParts<'_>, ChecksumError> {
+    if frame.len() < UBX_SYNC_SIZE || frame[..UBX_SYNC_SIZE] != [UBX_SYNC_CHAR_1, UBX_SYNC_CHAR_2] {
+        return Err(ChecksumError::InvalidSync);
+    }
+    let pack_len = match frame.get(UBX_LENGTH_OFFSET..UBX_HEADER_LEN) {
+        Some(len) => usize::from(u16::from_le_bytes([len[0], len[1]])),
+        None => {
+            return Err(ChecksumError::InvalidLength {
+                expect: UBX_HEADER_LEN + UBX_CHECKSUM_LEN,
+                got: frame.len(),
+            })
+        },
+    };
+    let checksum_offset = UBX_HEADER_LEN + pack_len;
+    if frame.len() != checksum_offset + UBX_CHECKSUM_LEN {
+        return Err(ChecksumError::InvalidLength {
+            expect: checksum_offset + UBX_CHECKSUM_LEN,
+            got: frame.len(),
+        });
+    }
+
+    let (ck_a, ck_b) = ubx_checksum(&frame[UBX_CLASS_OFFSET..checksum_offset]);
+    let (received_ck_a, received_ck_b) = (frame[checksum_offset], frame[checksum_offset + 1]);
+    if (ck_a, ck_b) != (received_ck_a, received_ck_b) {
+        return Err(ChecksumError::Mismatch {
+            expect: u16::from_le_bytes([received_ck_a, received_ck_b]),
+            got: u16::from_le_bytes([ck_a, ck_b]),
+        });
+    }
+
+    Ok(FrameParts {
+        class: frame[UBX_CLASS_OFFSET],
+        msg_id: frame[UBX_MSG_ID_OFFSET],
+        payload: &frame[UBX_HEADER_LEN..checksum_offset],
+    })
+}
@@ -0,0 +1,21 @@
+//! UBX framing shared by the [ublox](https://crates.io/crates/ublox) crate: the frame layout
+//! and checksum, the parser errors and the traits implemented by the packet definitions.
+//!
+//! This crate changes rarely and keeps a stable API, while the message definitions of
+//! `ublox` grow with each protocol version. Applications use it through `ublox`, which
+//! re-exports all of its items.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub mod constants;
+mod error;
+mod frame;
+mod packet;
+
+pub use crate::{
+    error::{ChecksumError, MemWriterError, ParserError},
+    frame::{ubx_checksum, verify_frame, FrameParts},
+    packet::{
+        MemWriter, PacketMeta, PayloadField, PayloadFieldKind, UbxPacketCreator, UbxPacketMeta,
+    },
+};
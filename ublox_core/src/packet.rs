@@ -0,0 +1,95 @@
+use crate::MemWriterError;
+
+/// Information about concrete UBX protocol's packet
+pub trait UbxPacketMeta {
+    const CLASS: u8;
+    const ID: u8;
+    /// Name of the packet type, e.g. `NavPvt`, generated by the derive. Empty for packet
+    /// types implemented by hand without one.
+    const NAME: &'static str = "";
+    const FIXED_PAYLOAD_LEN: Option<u16>;
+    const MAX_PAYLOAD_LEN: u16;
+    /// Layout of the payload fields up to the first field of variable length, e.g. to
+    /// generate payloads for tests
+    const PAYLOAD_FIELDS: &'static [PayloadField] = &[];
+    /// Length of the payload covered by [UbxPacketMeta::PAYLOAD_FIELDS]
+    const PAYLOAD_FIELDS_LEN: u16 = 0;
+}
+
+/// A field of a packet payload, as listed by [UbxPacketMeta::PAYLOAD_FIELDS]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PayloadField {
+    /// Name of the field in the packet definition, e.g. `reserved1`
+    pub name: &'static str,
+    /// Offset of the field in the payload
+    pub offset: u16,
+    /// Size of the field in bytes
+    pub size: u16,
+    pub kind: PayloadFieldKind,
+}
+
+impl PayloadField {
+    /// Whether the field is reserved, real receivers send zeros in it
+    pub fn is_reserved(&self) -> bool {
+        self.kind == PayloadFieldKind::Reserved
+    }
+
+    /// Whether the field is the message version
+    pub fn is_version(&self) -> bool {
+        self.kind == PayloadFieldKind::Version
+    }
+}
+
+/// Role of a [PayloadField], told by the derive from the field name
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PayloadFieldKind {
+    Data,
+    /// A `reserved*` field
+    Reserved,
+    /// The `version` field
+    Version,
+}
+
+/// [UbxPacketMeta] of a packet type known at runtime, as listed by the
+/// `UbxProtocol::supported_messages()` of the ublox crate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PacketMeta {
+    pub class: u8,
+    pub msg_id: u8,
+    /// Name of the packet type, e.g. `NavPvt`
+    pub name: &'static str,
+    pub fixed_payload_len: Option<u16>,
+    pub max_payload_len: u16,
+}
+
+/// Abstraction for buffer creation/reallocation
+/// to storing packet
+pub trait MemWriter {
+    type Error;
+    /// make sure that we have at least `len` bytes for writing
+    fn reserve_allocate(&mut self, len: usize) -> Result<(), MemWriterError<Self::Error>>;
+    fn write(&mut self, buf: &[u8]) -> Result<(), MemWriterError<Self::Error>>;
+}
+
+#[cfg(feature = "std")]
+impl MemWriter for Vec<u8> {
+    type Error = std::io::Error;
+
+    fn reserve_allocate(&mut self, len: usize) -> Result<(), MemWriterError<Self::Error>> {
+        self.reserve(len);
+        Ok(())
+    }
+    fn write(&mut self, buf: &[u8]) -> Result<(), MemWriterError<Self::Error>> {
+        let ret = <dyn std::io::Write>::write(self, buf).map_err(MemWriterError::Custom)?;
+        if ret == buf.len() {
+            Ok(())
+        } else {
+            Err(MemWriterError::NotEnoughMem)
+        }
+    }
+}
+
+pub trait UbxPacketCreator {
+    /// Create packet and store bytes sequence to somewhere using `out`
+    fn create_packet<T: MemWriter>(self, out: &mut T) -> Result<(), MemWriterError<T::Error>>;
+}
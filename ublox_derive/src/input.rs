@@ -1,6 +1,6 @@
 use crate::types::packetflag::PacketFlag;
 use crate::types::recvpackets::RecvPackets;
-use crate::types::{PackDesc, UbxExtendEnum};
+use crate::types::{PackDesc, PayloadLen, UbxExtendEnum};
 use proc_macro2::TokenStream;
 
 use syn::{
@@ -47,17 +47,108 @@ pub fn parse_packet_description(
         generics,
     };
 
-    if ret.header.payload_len.fixed().map(usize::from) == ret.packet_payload_size() {
-        Ok(ret)
-    } else {
-        Err(Error::new(
-            main_sp,
+    for (index, f) in ret.fields.iter().enumerate() {
+        let Some(ref count) = f.map.repeat else {
+            continue;
+        };
+        if index + 1 != ret.fields.len() {
+            return Err(Error::new(
+                f.name.span(),
+                "Repeated groups are only supported as last field",
+            ));
+        }
+        if !ret
+            .field_with_offset(count)
+            .is_some_and(|(c, _)| c.ty == syn::parse_quote!(u8) || c.ty == syn::parse_quote!(u16))
+        {
+            return Err(Error::new(
+                count.span(),
+                "The repeat count should be an earlier u8 or u16 field",
+            ));
+        }
+    }
+
+    for bit in ret.fields.iter().filter_map(|f| f.map.apply_mask.as_ref()) {
+        if ret.mask_field(bit).is_none() {
+            return Err(Error::new(
+                bit.span(),
+                "No field is mapped to the type of this mask bit",
+            ));
+        }
+    }
+
+    if let Some(min) = ret.header.min_payload_len {
+        let mut off = 0;
+        for f in &ret.fields {
+            if off == usize::from(min) {
+                break;
+            }
+            off = off.saturating_add(f.size_bytes.map_or(usize::MAX, |size| size.get()));
+        }
+        if off != usize::from(min) || ret.packet_payload_size().is_none() {
+            return Err(Error::new(
+                main_sp,
+                "min_payload_len should end at a field, followed by fixed size fields",
+            ));
+        }
+        let max = match ret.header.payload_len {
+            PayloadLen::Fixed(len) | PayloadLen::Max(len) => usize::from(len),
+        };
+        if ret.packet_payload_size() != Some(max) {
+            return Err(Error::new(
+                main_sp,
+                format!(
+                    "Calculated packet size ({:?}) doesn't match specified ({:?})",
+                    ret.packet_payload_size(),
+                    ret.header.payload_len
+                ),
+            ));
+        }
+        return Ok(ret);
+    }
+
+    check_payload_len(&ret, main_sp)?;
+    Ok(ret)
+}
+
+/// Compare the `fixed_payload_len` or `max_payload_len` of the packet with the sizes of its
+/// fields, so that a wrong field type or length fails the build instead of every parse
+fn check_payload_len(pack: &PackDesc, span: proc_macro2::Span) -> syn::Result<()> {
+    let fields_size = pack.packet_payload_size();
+    match (pack.header.payload_len, fields_size) {
+        (PayloadLen::Fixed(len), Some(size)) if usize::from(len) == size => Ok(()),
+        (PayloadLen::Fixed(len), Some(size)) => Err(Error::new(
+            span,
+            format!("fixed_payload_len = {len} doesn't match the {size} bytes of the fields"),
+        )),
+        (PayloadLen::Fixed(len), None) => Err(Error::new(
+            span,
             format!(
-                "Calculated packet size ({:?}) doesn't match specified ({:?})",
-                ret.packet_payload_size(),
-                ret.header.payload_len
+                "fixed_payload_len = {len} for a packet with a field of variable size, \
+                 use max_payload_len"
             ),
-        ))
+        )),
+        (PayloadLen::Max(_), Some(size)) => Err(Error::new(
+            span,
+            format!("All fields have a fixed size, use fixed_payload_len = {size}"),
+        )),
+        (PayloadLen::Max(len), None) => {
+            let fixed_size = pack
+                .packet_payload_size_except_last_field()
+                .or_else(|| pack.packet_payload_size_except_size_fn())
+                .unwrap_or(0);
+            if fixed_size > usize::from(len) {
+                Err(Error::new(
+                    span,
+                    format!(
+                        "max_payload_len = {len} is less than the {fixed_size} bytes of the fixed \
+                         size fields"
+                    ),
+                ))
+            } else {
+                Ok(())
+            }
+        },
     }
 }
 
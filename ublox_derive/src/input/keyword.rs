@@ -1,7 +1,12 @@
 syn::custom_keyword!(map_type);
 syn::custom_keyword!(scale);
 syn::custom_keyword!(alias);
+syn::custom_keyword!(unit);
+syn::custom_keyword!(repeat);
+syn::custom_keyword!(chars);
 syn::custom_keyword!(default_for_builder);
+syn::custom_keyword!(setters);
+syn::custom_keyword!(apply_mask);
 syn::custom_keyword!(may_fail);
 syn::custom_keyword!(from);
 syn::custom_keyword!(is_valid);
@@ -7,13 +7,23 @@ pub struct PackFieldMap {
     pub map_type: Option<MapType>,
     pub scale: Option<syn::LitFloat>,
     pub alias: Option<Ident>,
+    pub unit: Option<syn::LitStr>,
+    pub repeat: Option<Ident>,
+    pub chars: bool,
     pub convert_may_fail: bool,
     pub get_as_ref: bool,
+    pub apply_mask: Option<syn::Path>,
 }
 
 impl PackFieldMap {
     pub(crate) fn is_none(&self) -> bool {
-        self.map_type.is_none() && self.scale.is_none() && self.alias.is_none()
+        self.map_type.is_none()
+            && self.scale.is_none()
+            && self.alias.is_none()
+            && self.unit.is_none()
+            && self.repeat.is_none()
+            && !self.chars
+            && self.apply_mask.is_none()
     }
 }
 
@@ -40,6 +50,17 @@ impl Parse for PackFieldMap {
                 input.parse::<keyword::alias>()?;
                 input.parse::<Token![=]>()?;
                 map.alias = Some(input.parse()?);
+            } else if lookahead.peek(keyword::unit) {
+                input.parse::<keyword::unit>()?;
+                input.parse::<Token![=]>()?;
+                map.unit = Some(input.parse()?);
+            } else if lookahead.peek(keyword::chars) {
+                input.parse::<keyword::chars>()?;
+                map.chars = true;
+            } else if lookahead.peek(keyword::repeat) {
+                input.parse::<keyword::repeat>()?;
+                input.parse::<Token![=]>()?;
+                map.repeat = Some(input.parse()?);
             } else if lookahead.peek(keyword::may_fail) {
                 input.parse::<keyword::may_fail>()?;
                 map.convert_may_fail = true;
@@ -58,6 +79,10 @@ impl Parse for PackFieldMap {
             } else if lookahead.peek(keyword::get_as_ref) {
                 input.parse::<keyword::get_as_ref>()?;
                 map.get_as_ref = true;
+            } else if lookahead.peek(keyword::apply_mask) {
+                input.parse::<keyword::apply_mask>()?;
+                input.parse::<Token![=]>()?;
+                map.apply_mask = Some(input.parse()?);
             } else if lookahead.peek(keyword::into) {
                 input.parse::<keyword::into>()?;
                 input.parse::<Token![=]>()?;
@@ -84,3 +109,29 @@ impl Parse for PackFieldMap {
         Ok(map)
     }
 }
+
+/// Units accepted by `unit = "..."` and the suffix of the accessor they generate
+pub(crate) const UNITS: &[(&str, &str)] = &[
+    ("deg", "degrees"),
+    ("rad", "radians"),
+    ("m", "meters"),
+    ("cm", "centimeters"),
+    ("mm", "millimeters"),
+    ("m/s", "mps"),
+    ("m/s^2", "mps2"),
+    ("deg/s", "dps"),
+    ("s", "seconds"),
+    ("ms", "millis"),
+    ("us", "micros"),
+    ("ns", "nanos"),
+    ("Hz", "hz"),
+    ("degC", "celsius"),
+];
+
+/// Accessor suffix for `unit`, if it is supported
+pub(crate) fn unit_suffix(unit: &str) -> Option<&'static str> {
+    UNITS
+        .iter()
+        .find(|(name, _)| *name == unit)
+        .map(|(_, suffix)| *suffix)
+}
@@ -9,7 +9,10 @@ use proc_macro2::Span;
 use quote::ToTokens as _;
 use syn::{spanned::Spanned, Attribute, Error, Fields, Ident, Type};
 
-use super::{packfieldmap::PackFieldMap, StructFlags};
+use super::{
+    packfieldmap::{self, PackFieldMap},
+    MapType, StructFlags,
+};
 
 pub(crate) fn parse_ubx_extend_attrs(
     ubx_extend_name: &str,
@@ -96,6 +99,7 @@ pub(super) fn parse_ubx_attr(attrs: &[Attribute], struct_name: &Ident) -> syn::R
     let mut fixed_payload_len = None;
     let mut flags = Vec::new();
     let mut max_payload_len = None;
+    let mut min_payload_len = None;
 
     for e in &meta.nested {
         match e {
@@ -140,6 +144,17 @@ pub(super) fn parse_ubx_attr(attrs: &[Attribute], struct_name: &Ident) -> syn::R
                         syn::Lit::Int(x) => Some(x.base10_parse::<u16>()?),
                         _ => return Err(Error::new(lit.span(), "Should be integer literal")),
                     };
+                } else if path.is_ident("min_payload_len") {
+                    if min_payload_len.is_some() {
+                        return Err(Error::new(
+                            e.span(),
+                            "Duplicate \"min_payload_len\" attribute",
+                        ));
+                    }
+                    min_payload_len = match lit {
+                        syn::Lit::Int(x) => Some(x.base10_parse::<u16>()?),
+                        _ => return Err(Error::new(lit.span(), "Should be integer literal")),
+                    };
                 } else if path.is_ident("flags") {
                     if !flags.is_empty() {
                         return Err(Error::new(path.span(), "Duplicate flags"));
@@ -176,10 +191,20 @@ pub(super) fn parse_ubx_attr(attrs: &[Attribute], struct_name: &Ident) -> syn::R
         },
     };
 
+    if let Some(min) = min_payload_len {
+        if !matches!(payload_len, PayloadLen::Max(max) if min < max) {
+            return Err(Error::new(
+                meta.span(),
+                "min_payload_len should be less than max_payload_len",
+            ));
+        }
+    }
+
     Ok(PackHeader {
         class,
         id,
         payload_len,
+        min_payload_len,
         flags,
     })
 }
@@ -221,6 +246,14 @@ pub(super) fn parse_fields(fields: Fields) -> syn::Result<Vec<PackField>> {
             ..
         } = f;
         let size_bytes = field_size_bytes(&ty)?;
+        // 24-bit integers are accessed as their 32-bit counterpart, see PackField::is_int24
+        let ty = if ty == syn::parse_quote!(u24) {
+            syn::parse_quote!(u32)
+        } else if ty == syn::parse_quote!(i24) {
+            syn::parse_quote!(i32)
+        } else {
+            ty
+        };
 
         let name = name.ok_or_else(|| Error::new(f_sp, "No field name"))?;
         let comment = extract_item_comment(&attrs)?;
@@ -237,6 +270,62 @@ pub(super) fn parse_fields(fields: Fields) -> syn::Result<Vec<PackField>> {
             }
         }
 
+        if let Some(ref unit) = map.unit {
+            let suffix = packfieldmap::unit_suffix(&unit.value()).ok_or_else(|| {
+                let units: Vec<_> = packfieldmap::UNITS.iter().map(|(unit, _)| *unit).collect();
+                Error::new(
+                    unit.span(),
+                    format!("Unsupported unit, expected one of: {}", units.join(", ")),
+                )
+            })?;
+            if let Some(ref alias) = map.alias {
+                return Err(Error::new(
+                    alias.span(),
+                    "unit already names the scaled accessor, remove alias",
+                ));
+            }
+            map.alias = Some(quote::format_ident!("{}_{}", name, suffix));
+            map.map_type.get_or_insert_with(|| MapType {
+                ty: syn::parse_quote! { f64 },
+                from_fn: None,
+                is_valid_fn: None,
+                into_fn: None,
+                size_fn: None,
+            });
+        }
+
+        if map.chars {
+            let is_byte_array =
+                matches!(ty, Type::Array(ref array) if *array.elem == syn::parse_quote!(u8));
+            if !is_byte_array || map.map_type.is_some() {
+                return Err(Error::new(
+                    ty.span(),
+                    "Character fields are byte arrays without map_type",
+                ));
+            }
+            map.map_type = Some(MapType {
+                ty: syn::parse_quote! { Result<&str, core::str::Utf8Error> },
+                from_fn: Some(quote::quote! { crate::str_from_chars }),
+                is_valid_fn: None,
+                into_fn: None,
+                size_fn: None,
+            });
+            map.get_as_ref = true;
+        }
+
+        match (&ty, &map.repeat) {
+            (Type::Slice(_), None) => {
+                return Err(Error::new(ty.span(), "Repeated groups need a repeat count"));
+            },
+            (Type::Slice(_), Some(_)) | (_, None) => {},
+            (_, Some(repeat)) => {
+                return Err(Error::new(
+                    repeat.span(),
+                    "Only repeated groups of blocks, e.g. `[NavSatSvInfo]`, have a repeat count",
+                ));
+            },
+        }
+
         if let Some(ref map_ty) = map.map_type {
             if map_ty.ty == ty {
                 return Err(Error::new(
@@ -261,11 +350,13 @@ pub(super) fn parse_fields(fields: Fields) -> syn::Result<Vec<PackField>> {
 }
 
 fn field_size_bytes(ty: &Type) -> syn::Result<Option<NonZeroUsize>> {
-    let valid_types: [(Type, NonZeroUsize); 8] = [
+    let valid_types: [(Type, NonZeroUsize); 10] = [
         (syn::parse_quote!(u8), NonZeroUsize::new(1).unwrap()),
         (syn::parse_quote!(i8), NonZeroUsize::new(1).unwrap()),
         (syn::parse_quote!(u16), NonZeroUsize::new(2).unwrap()),
         (syn::parse_quote!(i16), NonZeroUsize::new(2).unwrap()),
+        (syn::parse_quote!(u24), NonZeroUsize::new(3).unwrap()),
+        (syn::parse_quote!(i24), NonZeroUsize::new(3).unwrap()),
         (syn::parse_quote!(u32), NonZeroUsize::new(4).unwrap()),
         (syn::parse_quote!(i32), NonZeroUsize::new(4).unwrap()),
         (syn::parse_quote!(f32), NonZeroUsize::new(4).unwrap()),
@@ -290,7 +381,7 @@ fn field_size_bytes(ty: &Type) -> syn::Result<Option<NonZeroUsize>> {
                 "Cannot interpret array length",
             ))
         }
-    } else if let syn::Type::Reference(_) = ty {
+    } else if let syn::Type::Reference(_) | syn::Type::Slice(_) = ty {
         Ok(None)
     } else {
         let mut valid_type_names = String::with_capacity(200);
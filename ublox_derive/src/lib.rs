@@ -169,6 +169,9 @@ fn generate_code_for_recv_send_packet(
     code.extend(send_code);
     let recv_code = output::gen_recv_code::generate_recv_code_for_packet(dbg_ctx, &pack_desc);
     code.extend(recv_code);
+    code.extend(output::gen_send_code::generate_builder_from_recv(
+        &pack_desc,
+    ));
 
     Ok(code)
 }
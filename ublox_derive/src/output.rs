@@ -15,6 +15,7 @@ mod util;
 
 pub fn generate_types_for_packet(_dbg_ctx: DebugContext, pack_descr: &PackDesc) -> TokenStream {
     let name = Ident::new(&pack_descr.name, Span::call_site());
+    let name_str = &pack_descr.name;
     let class = pack_descr.header.class;
     let id = pack_descr.header.id;
     let fixed_payload_len = match pack_descr.header.payload_len.fixed() {
@@ -26,6 +27,60 @@ pub fn generate_types_for_packet(_dbg_ctx: DebugContext, pack_descr: &PackDesc)
         PayloadLen::Fixed(x) => x,
         PayloadLen::Max(x) => x,
     };
+    let mut payload_fields = Vec::new();
+    let mut offset_consts = Vec::new();
+    let mut offset = 0u16;
+    for field in &pack_descr.fields {
+        let Some(size) = field.size_bytes else {
+            break;
+        };
+        let size = u16::try_from(size.get()).expect("field size overflows u16");
+        let field_name = field.name.to_string();
+        let kind = if field_name.starts_with("reserved") {
+            quote! { Reserved }
+        } else if field_name == "version" {
+            quote! { Version }
+        } else {
+            quote! { Data }
+        };
+        payload_fields.push(quote! {
+            crate::PayloadField {
+                name: #field_name,
+                offset: #offset,
+                size: #size,
+                kind: crate::PayloadFieldKind::#kind,
+            }
+        });
+        let const_name = format_ident!("OFFSET_{}", field_name.to_uppercase());
+        let const_doc = format!("Byte offset of `{field_name}` in the payload");
+        let const_offset = usize::from(offset);
+        offset_consts.push(quote! {
+            #[doc = #const_doc]
+            pub const #const_name: usize = #const_offset;
+        });
+        offset += size;
+    }
+    let field_consts = pack_descr.fields.iter().map(|field| {
+        let field_name = field.name.to_string();
+        let const_name = format_ident!("FIELD_{}", field_name.to_uppercase());
+        let const_doc = format!(
+            "Field `{field_name}`, to select it in a [Projection](crate::projection::Projection)"
+        );
+        quote! {
+            #[doc = #const_doc]
+            pub const #const_name: crate::projection::Field =
+                crate::projection::Field::new(#field_name);
+        }
+    });
+    let offset_impl = if offset_consts.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            impl #name {
+                #(#offset_consts)*
+            }
+        }
+    };
     quote! {
 
         #[doc = #struct_comment]
@@ -33,8 +88,16 @@ pub fn generate_types_for_packet(_dbg_ctx: DebugContext, pack_descr: &PackDesc)
         impl UbxPacketMeta for #name {
             const CLASS: u8 = #class;
             const ID: u8 = #id;
+            const NAME: &'static str = #name_str;
             const FIXED_PAYLOAD_LEN: Option<u16> = #fixed_payload_len;
             const MAX_PAYLOAD_LEN: u16 = #max_payload_len;
+            const PAYLOAD_FIELDS: &'static [crate::PayloadField] = &[#(#payload_fields),*];
+            const PAYLOAD_FIELDS_LEN: u16 = #offset;
+        }
+        #offset_impl
+        #[cfg(feature = "serde")]
+        impl #name {
+            #(#field_consts)*
         }
     }
 }
@@ -117,6 +180,7 @@ pub fn generate_code_to_extend_bitflags(bitflags: BitFlagsMacro) -> syn::Result<
         },
     };
 
+    let defmt_format = format!("{name}({{:#x}})");
     let serialize_fn = format_ident!("serialize_{}", repr_ty.to_token_stream().to_string());
     let serde = quote! {
         #[cfg(feature = "serde")]
@@ -128,6 +192,13 @@ pub fn generate_code_to_extend_bitflags(bitflags: BitFlagsMacro) -> syn::Result<
                 serializer.#serialize_fn(self.bits())
             }
         }
+
+        #[cfg(feature = "defmt")]
+        impl defmt::Format for #name {
+            fn format(&self, f: defmt::Formatter) {
+                defmt::write!(f, #defmt_format, self.bits())
+            }
+        }
     };
 
     Ok(quote! {
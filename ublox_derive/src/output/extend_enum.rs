@@ -95,6 +95,20 @@ pub(crate) fn generate_code_to_extend_enum(ubx_enum: &UbxExtendEnum) -> TokenStr
         enum_variants.push(quote! { #id = #val });
     }
 
+    let known_values = ubx_enum.variants.iter().map(|(_, val)| val);
+    let known_values_code = quote! {
+        impl #name {
+            /// Raw values of the declared variants, e.g. to generate valid payloads in fuzz
+            /// tests
+            pub const KNOWN_VALUES: &'static [#repr_ty] = &[#(#known_values),*];
+        }
+    };
+
+    let defmt_branches = variants.iter().map(|(id, _)| {
+        let text = id.to_string();
+        quote! { #name :: #id => defmt::write!(f, #text) }
+    });
+
     let code = quote! {
         #(#attrs)*
         pub enum #name {
@@ -103,6 +117,7 @@ pub(crate) fn generate_code_to_extend_enum(ubx_enum: &UbxExtendEnum) -> TokenStr
 
         #from_code
         #to_code
+        #known_values_code
 
         #[cfg(feature = "serde")]
         impl serde::Serialize for #name {
@@ -113,6 +128,15 @@ pub(crate) fn generate_code_to_extend_enum(ubx_enum: &UbxExtendEnum) -> TokenStr
                 serializer.serialize_u8(*self as u8)
             }
         }
+
+        #[cfg(feature = "defmt")]
+        impl defmt::Format for #name {
+            fn format(&self, f: defmt::Formatter) {
+                match self {
+                    #(#defmt_branches),*
+                }
+            }
+        }
     };
     code
 }
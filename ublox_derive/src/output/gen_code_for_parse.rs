@@ -17,8 +17,12 @@ pub fn generate_code_for_parse(recv_packs: &RecvPackets) -> TokenStream {
     let mut class_id_matches_owned = Vec::with_capacity(recv_packs.all_packets.len());
 
     let mut serializers = Vec::with_capacity(recv_packs.all_packets.len());
+    let mut serializers_owned = Vec::with_capacity(recv_packs.all_packets.len());
 
     let mut len_matches_ref = Vec::with_capacity(recv_packs.all_packets.len());
+    let mut payload_matches_ref = Vec::with_capacity(recv_packs.all_packets.len());
+    let mut payload_matches_owned = Vec::with_capacity(recv_packs.all_packets.len());
+    let mut packet_metas = Vec::with_capacity(recv_packs.all_packets.len());
 
     for name in &recv_packs.all_packets {
         let ref_name = format_ident!("{}Ref", name);
@@ -38,9 +42,7 @@ pub fn generate_code_for_parse(recv_packs: &RecvPackets) -> TokenStream {
 
         matches_owned.push(quote! {
             (#name::CLASS, #name::ID) if <#owned_name>::validate(payload).is_ok()  => {
-                let mut bytes = [0u8; #owned_name::PACKET_SIZE];
-                bytes.clone_from_slice(payload);
-                Ok(#union_enum_name_owned::#name(#owned_name(bytes)))
+                Ok(#union_enum_name_owned::#name(#ref_name(payload).into()))
             }
         });
         matches_ref_to_owned.push(quote! {
@@ -62,10 +64,33 @@ pub fn generate_code_for_parse(recv_packs: &RecvPackets) -> TokenStream {
             }
             .serialize(serializer)
         });
+        serializers_owned.push(quote! {
+            #union_enum_name_owned::#name(ref msg) => PacketSerializer {
+                class: #name::CLASS,
+                msg_id: #name::ID,
+                msg,
+            }
+            .serialize(serializer)
+        });
 
         len_matches_ref.push(quote! {
             #union_enum_name_ref::#name(ref packet) => packet.payload_len(),
         });
+        payload_matches_ref.push(quote! {
+            #union_enum_name_ref::#name(ref packet) => packet.0,
+        });
+        payload_matches_owned.push(quote! {
+            #union_enum_name_owned::#name(ref packet) => packet.as_bytes(),
+        });
+        packet_metas.push(quote! {
+            crate::PacketMeta {
+                class: #name::CLASS,
+                msg_id: #name::ID,
+                name: #name::NAME,
+                fixed_payload_len: #name::FIXED_PAYLOAD_LEN,
+                max_payload_len: #name::MAX_PAYLOAD_LEN,
+            }
+        });
     }
 
     let unknown_var_ref = format_ident!("{}Ref", &recv_packs.unknown_ty);
@@ -93,6 +118,8 @@ pub fn generate_code_for_parse(recv_packs: &RecvPackets) -> TokenStream {
         }
     };
 
+    let all_packets = &recv_packs.all_packets;
+
     let fn_match_packet = match_packet::generate_fn_match_packet(
         &union_enum_name_ref,
         &matches_ref,
@@ -106,6 +133,9 @@ pub fn generate_code_for_parse(recv_packs: &RecvPackets) -> TokenStream {
     );
 
     quote! {
+        #[cfg(feature = "alloc")]
+        use alloc::vec::Vec;
+
         #[doc = "All possible packets enum"]
         #[derive(Debug)]
         #[non_exhaustive]
@@ -114,7 +144,7 @@ pub fn generate_code_for_parse(recv_packs: &RecvPackets) -> TokenStream {
             Unknown(#unknown_var_ref<'a>)
         }
         #[doc = "All possible packets enum, owning the underlying data"]
-        #[derive(Debug)]
+        #[derive(Debug, Clone)]
         #[non_exhaustive]
         pub enum #union_enum_name_owned {
             #(#pack_enum_variants_owned),*,
@@ -140,6 +170,43 @@ pub fn generate_code_for_parse(recv_packs: &RecvPackets) -> TokenStream {
                     #union_enum_name_ref::Unknown(ref pack) => pack.payload.len(),
                 }
             }
+
+            #[doc = "The raw payload, without the frame header and checksum"]
+            #[inline]
+            pub fn payload(&self) -> &'a [u8] {
+                match *self {
+                    #(#payload_matches_ref)*
+                    #union_enum_name_ref::Unknown(ref pack) => pack.payload,
+                }
+            }
+
+            #[doc = "Class, id and payload of the packet, e.g. to rebuild its frame"]
+            #[inline]
+            pub fn as_raw(&self) -> #unknown_var_ref<'a> {
+                let (class, msg_id) = self.class_and_msg_id();
+                #unknown_var_ref {
+                    payload: self.payload(),
+                    class,
+                    msg_id,
+                }
+            }
+
+            #[doc = "Length of the frame, from the sync characters to the checksum"]
+            #[inline]
+            pub fn frame_len(&self) -> usize {
+                self.as_raw().frame_len()
+            }
+
+            #[doc = "The frame rebuilt from the packet, with its length and checksum computed again. Use `CheckedPacket::to_frame_vec` to verify them against the received frame."]
+            #[cfg(any(feature = "std", feature = "alloc"))]
+            pub fn to_frame_vec(&self) -> Vec<u8> {
+                self.as_raw().to_frame_bytes()
+            }
+
+            #[doc = "Rebuild the frame at the start of `buf` like `to_frame_vec`, returning its length"]
+            pub fn extend_to_slice(&self, buf: &mut [u8]) -> Result<usize, crate::error::MemWriterError<core::convert::Infallible>> {
+                self.as_raw().extend_to_slice(buf)
+            }
         }
         impl #union_enum_name_owned {
             pub fn class_and_msg_id(&self) -> (u8, u8) {
@@ -148,6 +215,26 @@ pub fn generate_code_for_parse(recv_packs: &RecvPackets) -> TokenStream {
                     #union_enum_name_owned::Unknown(ref pack) => (pack.class, pack.msg_id),
                 }
             }
+
+            #[doc = "The raw payload, without the frame header and checksum"]
+            #[inline]
+            pub fn payload(&self) -> &[u8] {
+                match *self {
+                    #(#payload_matches_owned)*
+                    #union_enum_name_owned::Unknown(ref pack) => &pack.payload[..pack.payload_len],
+                }
+            }
+
+            #[doc = "Class, id and payload of the packet, e.g. to rebuild its frame"]
+            #[inline]
+            pub fn as_raw(&self) -> #unknown_var_ref<'_> {
+                let (class, msg_id) = self.class_and_msg_id();
+                #unknown_var_ref {
+                    payload: self.payload(),
+                    class,
+                    msg_id,
+                }
+            }
         }
 
         #fn_match_packet
@@ -167,6 +254,29 @@ pub fn generate_code_for_parse(recv_packs: &RecvPackets) -> TokenStream {
             [a, b][(a < b) as usize]
         }
         pub(crate) const MAX_PAYLOAD_LEN: u16 = #max_payload_len_calc;
+
+        #[doc = "The packets of the enum, in the order they are matched"]
+        pub(crate) const SUPPORTED_MESSAGES: &[crate::PacketMeta] = &[#(#packet_metas),*];
+        #[cfg(feature = "defmt")]
+        impl defmt::Format for #union_enum_name_ref<'_> {
+            fn format(&self, f: defmt::Formatter) {
+                match *self {
+                    #(#union_enum_name_ref::#all_packets(ref packet) => packet.format(f),)*
+                    #union_enum_name_ref::Unknown(ref pack) => pack.format(f),
+                }
+            }
+        }
+
+        #[cfg(feature = "defmt")]
+        impl defmt::Format for #union_enum_name_owned {
+            fn format(&self, f: defmt::Formatter) {
+                match *self {
+                    #(#union_enum_name_owned::#all_packets(ref packet) => packet.format(f),)*
+                    #union_enum_name_owned::Unknown(ref pack) => pack.format(f),
+                }
+            }
+        }
+
         #[cfg(feature = "serde")]
         pub struct PacketSerializer<'a, T> {
             class: u8,
@@ -200,5 +310,23 @@ pub fn generate_code_for_parse(recv_packs: &RecvPackets) -> TokenStream {
                 }
             }
         }
+
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for #union_enum_name_owned {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                match *self {
+                    #(#serializers_owned),*,
+                    #union_enum_name_owned::Unknown(ref pack) => #unknown_var_ref {
+                        payload: &pack.payload[..pack.payload_len],
+                        class: pack.class,
+                        msg_id: pack.msg_id,
+                    }
+                    .serialize(serializer),
+                }
+            }
+        }
     }
 }
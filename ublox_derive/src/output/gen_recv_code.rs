@@ -15,6 +15,8 @@ pub fn generate_recv_code_for_packet(dbg_ctx: DebugContext, pack_descr: &PackDes
         PayloadLen::Max(value) => value,
     }
     .into();
+    // Packets of variable size keep the length of their payload next to the buffer
+    let variable_size = matches!(pack_descr.header.payload_len, PayloadLen::Max(_));
 
     let mut getters: Vec<TokenStream> = Vec::with_capacity(pack_descr.fields.len());
     let mut field_validators: Vec<TokenStream> = Vec::new();
@@ -34,8 +36,53 @@ pub fn generate_recv_code_for_packet(dbg_ctx: DebugContext, pack_descr: &PackDes
     let struct_comment = &pack_descr.comment;
     let validator = generate_validator(pack_descr, pack_name, &ref_name, field_validators);
     let debug_impl = util::generate_debug_impl(pack_name, &ref_name, &owned_name, pack_descr);
-    let serialize_impl = util::generate_serialize_impl(pack_name, &ref_name, pack_descr);
-    let from_ref_impl = generate_from_ref_impl(&ref_name, &owned_name, packet_size);
+    let serialize_impl =
+        util::generate_serialize_impl(pack_name, &ref_name, &owned_name, pack_descr);
+    let defmt_impl = util::generate_defmt_impl(pack_name, &ref_name, &owned_name, pack_descr);
+    let display_impl = util::generate_display_impl(pack_name, &ref_name, &owned_name, pack_descr);
+    let from_ref_impl = generate_from_ref_impl(&ref_name, &owned_name, packet_size, variable_size);
+    let recv_impl = generate_recv_impl(pack_name, &ref_name, &owned_name);
+    let (owned_struct, owned_bytes) = if variable_size {
+        (
+            quote! {
+                #[doc = #struct_comment]
+                #[doc = "Owns the underlying buffer of data, contains accessor methods to retrieve data."]
+                #[derive(Clone)]
+                pub struct #owned_name(pub(crate) [u8; #packet_size], pub(crate) usize);
+            },
+            quote! {
+                #[inline]
+                pub fn as_bytes(&self) -> &[u8] {
+                    &self.0[..self.1]
+                }
+
+                #[inline]
+                pub fn payload_len(&self) -> usize {
+                    self.1
+                }
+            },
+        )
+    } else {
+        (
+            quote! {
+                #[doc = #struct_comment]
+                #[doc = "Owns the underlying buffer of data, contains accessor methods to retrieve data."]
+                #[derive(Clone)]
+                pub struct #owned_name(pub(crate) [u8; #packet_size]);
+            },
+            quote! {
+                #[inline]
+                pub fn as_bytes(&self) -> &[u8] {
+                    &self.0
+                }
+
+                #[inline]
+                pub fn payload_len(&self) -> usize {
+                    #packet_size
+                }
+            },
+        )
+    };
 
     quote! {
         #[doc = #struct_comment]
@@ -61,18 +108,12 @@ pub fn generate_recv_code_for_packet(dbg_ctx: DebugContext, pack_descr: &PackDes
             #validator
         }
 
-        #[doc = #struct_comment]
-        #[doc = "Owns the underlying buffer of data, contains accessor methods to retrieve data."]
-        #[derive(Clone)]
-        pub struct #owned_name(pub(crate) [u8; #packet_size]);
+        #owned_struct
 
         impl #owned_name {
             pub(crate) const PACKET_SIZE: usize = #packet_size;
 
-            #[inline]
-            pub fn as_bytes(&self) -> &[u8] {
-                &self.0
-            }
+            #owned_bytes
 
             #(#getters)*
 
@@ -80,8 +121,11 @@ pub fn generate_recv_code_for_packet(dbg_ctx: DebugContext, pack_descr: &PackDes
         }
 
         #from_ref_impl
+        #recv_impl
         #debug_impl
+        #display_impl
         #serialize_impl
+        #defmt_impl
     }
 }
 
@@ -91,7 +135,25 @@ fn generate_validator(
     ref_name: &syn::Ident,
     field_validators: Vec<TokenStream>,
 ) -> TokenStream {
-    let validator = if let Some(payload_len) = pack_descr.packet_payload_size() {
+    let validator = if let (Some(min), PayloadLen::Max(max)) = (
+        pack_descr.header.min_payload_len,
+        pack_descr.header.payload_len,
+    ) {
+        let (min, max) = (usize::from(min), usize::from(max));
+        quote! {
+            pub(crate) fn validate(payload: &[u8]) -> Result<(), ParserError> {
+                let got = payload.len();
+                if got < #min {
+                    Err(ParserError::InvalidPacketLen{ packet: #pack_name, expect: #min, got })
+                } else if got > #max {
+                    Err(ParserError::InvalidPacketLen{ packet: #pack_name, expect: #max, got })
+                } else {
+                    #(#field_validators)*
+                    Ok(())
+                }
+            }
+        }
+    } else if let Some(payload_len) = pack_descr.packet_payload_size() {
         quote! {
             pub(crate) fn validate(payload: &[u8]) -> Result<(), ParserError> {
                 let expect = #payload_len;
@@ -132,9 +194,22 @@ fn generate_validator(
             }
         };
 
+        // The owned packets copy the payload into a buffer of the maximum length
+        let max_check = if let PayloadLen::Max(max) = pack_descr.header.payload_len {
+            let max = usize::from(max);
+            quote! {
+                if got > #max {
+                    return Err(ParserError::InvalidPacketLen{ packet: #pack_name, expect: #max, got });
+                }
+            }
+        } else {
+            quote! {}
+        };
+
         quote! {
             pub(crate) fn validate(payload: &[u8]) -> Result<(), ParserError> {
                 let got = payload.len();
+                #max_check
                 let min = #min_size;
                 if got >= min {
                     #(#field_validators)*
@@ -152,14 +227,20 @@ fn generate_from_ref_impl(
     ref_name: &syn::Ident,
     owned_name: &syn::Ident,
     packet_size: usize,
+    variable_size: bool,
 ) -> TokenStream {
+    let owned = if variable_size {
+        quote! { Self(dst, src.len()) }
+    } else {
+        quote! { Self(dst) }
+    };
     quote! {
         impl<'a> From<&#ref_name<'a>> for #owned_name {
             fn from(packet: &#ref_name<'a>) -> Self {
                 let src = packet.as_bytes();
                 let mut dst = [0u8; #packet_size];
                 dst[..src.len()].clone_from_slice(src);
-                Self(dst)
+                #owned
             }
         }
 
@@ -171,6 +252,34 @@ fn generate_from_ref_impl(
     }
 }
 
+fn generate_recv_impl(
+    pack_name: &str,
+    ref_name: &syn::Ident,
+    owned_name: &syn::Ident,
+) -> TokenStream {
+    let main_name = format_ident!("{}", pack_name);
+    quote! {
+        impl crate::UbxPacketRecv for #main_name {
+            type Ref<'a> = #ref_name<'a>;
+            type Owned = #owned_name;
+
+            fn parse_payload(payload: &[u8]) -> Result<#ref_name<'_>, ParserError> {
+                #ref_name::validate(payload)?;
+                Ok(#ref_name(payload))
+            }
+        }
+
+        impl<'a> core::convert::TryFrom<&'a [u8]> for #ref_name<'a> {
+            type Error = ParserError;
+
+            /// Validate a bare payload, without its frame, and wrap it
+            fn try_from(payload: &'a [u8]) -> Result<Self, ParserError> {
+                <#main_name as crate::UbxPacketRecv>::parse_payload(payload)
+            }
+        }
+    }
+}
+
 fn process_fields<'a>(
     dbg_ctx: DebugContext,
     pack_descr: &'a PackDesc,
@@ -186,7 +295,19 @@ fn process_fields<'a>(
         let get_name = f.intermediate_field_name();
         let field_comment = &f.comment;
 
-        if let Some(size_bytes) = f.size_bytes.map(|x| x.get()) {
+        if let Some(block) = f.repeated_block() {
+            process_repeated_field(
+                pack_descr,
+                f,
+                pack_name,
+                field_comment,
+                get_name,
+                block,
+                *off,
+                getters,
+                field_validators,
+            );
+        } else if let Some(size_bytes) = f.size_bytes.map(|x| x.get()) {
             process_fixed_size_field(
                 f,
                 pack_name,
@@ -194,6 +315,7 @@ fn process_fields<'a>(
                 get_name,
                 ty,
                 *off,
+                pack_descr.is_optional_at(*off).then_some(*off + size_bytes),
                 getters,
                 field_validators,
             );
@@ -224,15 +346,35 @@ fn process_fixed_size_field(
     get_name: &syn::Ident,
     ty: &syn::Type,
     off: usize,
+    optional_end: Option<usize>,
     getters: &mut Vec<TokenStream>,
     field_validators: &mut Vec<TokenStream>,
 ) {
     let get_raw = util::get_raw_field_code(f, off, quote! {self.0});
     let new_line = quote! { let val = #get_raw;  };
     let mut get_value_lines = vec![new_line];
+    // Fields past `min_payload_len` are only there in the longer versions of the packet
+    let (check_len, ret_val) = match optional_end {
+        Some(end) => (
+            quote! {
+                if self.payload_len() < #end {
+                    return None;
+                }
+            },
+            quote! { Some(val) },
+        ),
+        None => (quote! {}, quote! { val }),
+    };
+    let wrap_ty = |ty: &dyn quote::ToTokens| {
+        if optional_end.is_some() {
+            quote! { Option<#ty> }
+        } else {
+            quote! { #ty }
+        }
+    };
 
     if let Some(ref out_ty) = f.map.map_type {
-        let get_raw_name = format_ident!("{}_raw", get_name);
+        let get_raw_name = f.raw_field_name();
 
         let slicetype = syn::parse_str("&[u8]").unwrap();
         let raw_ty = if f.is_field_raw_ty_byte_array() {
@@ -240,19 +382,21 @@ fn process_fixed_size_field(
         } else {
             &f.ty
         };
+        let raw_ret_ty = wrap_ty(raw_ty);
         getters.push(quote! {
             #[doc = #field_comment]
             #[inline]
-            pub fn #get_raw_name(&self) -> #raw_ty {
+            pub fn #get_raw_name(&self) -> #raw_ret_ty {
+                #check_len
                 #(#get_value_lines)*
-                val
+                #ret_val
             }
         });
 
         if f.map.convert_may_fail {
             let get_val = util::get_raw_field_code(f, off, quote! { payload });
             let is_valid_fn = &out_ty.is_valid_fn;
-            field_validators.push(quote! {
+            let validator = quote! {
                 let val = #get_val;
                 if !#is_valid_fn(val) {
                     return Err(ParserError::InvalidField{
@@ -260,6 +404,14 @@ fn process_fixed_size_field(
                         field: stringify!(#get_name)
                     });
                 }
+            };
+            field_validators.push(match optional_end {
+                Some(end) => quote! {
+                    if payload.len() >= #end {
+                        #validator
+                    }
+                },
+                None => validator,
             });
         }
         let from_fn = &out_ty.from_fn;
@@ -271,12 +423,60 @@ fn process_fixed_size_field(
     if let Some(ref scale) = f.map.scale {
         get_value_lines.push(quote! { let val = val * #scale; });
     }
+    let ret_ty = wrap_ty(ty);
+    let unit_doc = f.map.unit.as_ref().map(|unit| {
+        let unit_doc = format!("Unit: {}", unit.value());
+        quote! {
+            #[doc = ""]
+            #[doc = #unit_doc]
+        }
+    });
     getters.push(quote! {
         #[doc = #field_comment]
+        #unit_doc
         #[inline]
-        pub fn #get_name(&self) -> #ty {
+        pub fn #get_name(&self) -> #ret_ty {
+            #check_len
             #(#get_value_lines)*
-            val
+            #ret_val
+        }
+    });
+}
+
+/// Repeated group of `count` blocks, iterated over as the `Ref` of the block type
+#[allow(clippy::too_many_arguments, reason = "Yes we need to refactor...")]
+fn process_repeated_field(
+    pack_descr: &PackDesc,
+    f: &PackField,
+    pack_name: &String,
+    field_comment: &str,
+    get_name: &syn::Ident,
+    block: &syn::Type,
+    off: usize,
+    getters: &mut Vec<TokenStream>,
+    field_validators: &mut Vec<TokenStream>,
+) {
+    let count = f.map.repeat.as_ref().expect("repeated group without count");
+    let (count_field, count_off) = pack_descr
+        .field_with_offset(count)
+        .expect("count of repeated group checked while parsing");
+    let get_count = util::get_raw_field_code(count_field, count_off, quote! { payload });
+
+    getters.push(quote! {
+        #[doc = #field_comment]
+        #[inline]
+        pub fn #get_name(&self) -> crate::RepeatedIter<'_, #block> {
+            crate::RepeatedIter::new(&self.as_bytes()[#off..])
+        }
+    });
+    field_validators.push(quote! {
+        let count = usize::from(#get_count);
+        let expect = #off + count * crate::RepeatedIter::<#block>::BLOCK_SIZE;
+        if payload.len() != expect {
+            return Err(ParserError::InvalidPacketLen{ packet: #pack_name, expect, got: payload.len() });
+        }
+        for block in payload[#off..].chunks_exact(crate::RepeatedIter::<#block>::BLOCK_SIZE) {
+            <#block as crate::UbxPacketRecv>::parse_payload(block)?;
         }
     });
 }
@@ -307,7 +507,7 @@ fn process_variable_size_field<'a>(
         quote! { #off.. }
     };
 
-    let mut get_value_lines = vec![quote! { &self.0[#range] }];
+    let mut get_value_lines = vec![quote! { &self.as_bytes()[#range] }];
     if let Some(ref out_ty) = f.map.map_type {
         let get_raw = &get_value_lines[0];
         let new_line = quote! { let val = #get_raw ;  };
@@ -15,6 +15,7 @@ pub fn generate_send_code_for_packet(_dbg_ctx: DebugContext, pack_descr: &PackDe
     let mut pack_fields = Vec::with_capacity(pack_descr.fields.len());
     let mut write_fields = Vec::with_capacity(pack_descr.fields.len());
     let mut extend_fields = Vec::with_capacity(pack_descr.fields.len());
+    let mut len_fields = Vec::new();
     let mut off = 6usize;
     let mut repeatable_block_seen = false;
     for f in pack_descr.fields.iter() {
@@ -47,6 +48,9 @@ pub fn generate_send_code_for_packet(_dbg_ctx: DebugContext, pack_descr: &PackDe
                             out.extend(bytes.iter().copied());
                         }
                     });
+                    len_fields.push(quote! {
+                        len += #into_fn(self.#name).as_ref().len();
+                    });
                 } else {
                     // First repeatable block field: treat as iterator
                     extend_fields.push(quote! {
@@ -54,6 +58,11 @@ pub fn generate_send_code_for_packet(_dbg_ctx: DebugContext, pack_descr: &PackDe
                           len_bytes += f.extend_to(out);
                         }
                     });
+                    len_fields.push(quote! {
+                        for f in self.#name.clone() {
+                            len += f.extend_to(&mut crate::ubx_packets::ByteSink);
+                        }
+                    });
 
                     builder_needs_lifetime = true;
                     repeatable_block_seen = true;
@@ -62,27 +71,35 @@ pub fn generate_send_code_for_packet(_dbg_ctx: DebugContext, pack_descr: &PackDe
             },
         };
 
-        if let Some(into_fn) = f.map.map_type.as_ref().map(|x| &x.into_fn) {
-            pack_fields.push(quote! {
+        let mut bytes = if let Some(into_fn) = f.map.map_type.as_ref().map(|x| &x.into_fn) {
+            quote! {
                 let bytes = #into_fn(self.#name).to_le_bytes()
-            });
+            }
         } else if !f.is_field_raw_ty_byte_array() {
-            pack_fields.push(quote! {
+            quote! {
               let bytes = self.#name.to_le_bytes()
-            });
+            }
         } else {
-            pack_fields.push(quote! {
+            quote! {
               let bytes: &[u8] = &self.#name;
-            });
+            }
+        };
+        if f.is_int24() {
+            // The most significant byte of the 32-bit value is dropped
+            bytes = quote! {
+                #bytes;
+                let bytes = [bytes[0], bytes[1], bytes[2]]
+            };
         }
+        pack_fields.push(bytes.clone());
 
-        write_fields.push(pack_fields.last().unwrap().clone());
+        write_fields.push(bytes.clone());
         write_fields.push(quote! {
             out.write(&bytes)?;
             checksum_calc.update(&bytes)
         });
 
-        extend_fields.push(pack_fields.last().unwrap().clone());
+        extend_fields.push(bytes);
         extend_fields.push(quote! {
             len_bytes += bytes.len();
             out.extend(bytes);
@@ -148,6 +165,20 @@ pub fn generate_send_code_for_packet(_dbg_ctx: DebugContext, pack_descr: &PackDe
                     ret[Self::PACKET_LEN - 1] = ck_b;
                     ret
                 }
+
+                /// Length of the frame, [Self::PACKET_LEN]
+                #[inline]
+                pub const fn frame_len(&self) -> usize {
+                    Self::PACKET_LEN
+                }
+
+                /// Write the frame to the start of `buf`, e.g. a DMA buffer, returning its length
+                #[inline]
+                pub fn extend_to_slice(self, buf: &mut [u8]) -> Result<usize, crate::error::MemWriterError<core::convert::Infallible>> {
+                    let out = buf.get_mut(..Self::PACKET_LEN).ok_or(crate::error::MemWriterError::NotEnoughMem)?;
+                    out.copy_from_slice(&self.into_packet_bytes());
+                    Ok(Self::PACKET_LEN)
+                }
             }
             impl From<#payload_struct> for [u8; #packet_size] {
                 fn from(x: #payload_struct) -> Self {
@@ -172,6 +203,8 @@ pub fn generate_send_code_for_packet(_dbg_ctx: DebugContext, pack_descr: &PackDe
             }
         });
     } else {
+        // Header, fixed size fields and checksum
+        let fixed_frame_len = off + 2;
         ret.extend(quote! {
           impl #payload_struct_lifetime #payload_struct #payload_struct_lifetime {
               #[cfg(feature = "alloc")]
@@ -205,9 +238,115 @@ pub fn generate_send_code_for_packet(_dbg_ctx: DebugContext, pack_descr: &PackDe
                   out.extend(core::iter::once(ck_a));
                   out.extend(core::iter::once(ck_b));
               }
+
+              /// Length of the frame [extend_to](Self::extend_to) writes
+              pub fn frame_len(&self) -> usize {
+                  let mut len = #fixed_frame_len;
+                  #(#len_fields)*
+                  len
+              }
+
+              /// Write the frame to the start of `buf`, e.g. a DMA buffer, returning its length
+              pub fn extend_to_slice(self, buf: &mut [u8]) -> Result<usize, crate::error::MemWriterError<core::convert::Infallible>> {
+                  let len = self.frame_len();
+                  let out = buf.get_mut(..len).ok_or(crate::error::MemWriterError::NotEnoughMem)?;
+                  self.extend_to(&mut crate::ubx_packets::SliceWriter::new(out));
+                  Ok(len)
+              }
           }
         })
     }
 
+    if pack_descr.header.flags.contains(&PacketFlag::Setters) {
+        let setters = generate_setters(pack_descr);
+        ret.extend(quote! {
+            impl #payload_struct_lifetime #payload_struct #payload_struct_lifetime {
+                #(#setters)*
+            }
+        });
+    }
+
     ret
 }
+
+/// `From<&XxxRef>` and `From<&XxxOwned>` for the builder of a packet that is both received
+/// and sent, copying every field to modify a polled configuration and send it back. Only
+/// generated for fixed size packets whose fields all have a value type, i.e. without
+/// `get_as_ref` or `chars` fields. The builders of variable size packets hold their repeated
+/// blocks in a type of their own, so their conversion, e.g. for `CfgGnssBuilder`, is written
+/// by hand.
+pub fn generate_builder_from_recv(pack_descr: &PackDesc) -> TokenStream {
+    if pack_descr.packet_payload_size().is_none()
+        || pack_descr.header.min_payload_len.is_some()
+        || pack_descr
+            .fields
+            .iter()
+            .any(|f| f.map.get_as_ref || f.map.chars)
+    {
+        return TokenStream::new();
+    }
+    let builder = format_ident!("{}Builder", pack_descr.name);
+    let ref_name = format_ident!("{}Ref", pack_descr.name);
+    let owned_name = format_ident!("{}Owned", pack_descr.name);
+
+    let fields = pack_descr.fields.iter().map(|f| {
+        let name = f.intermediate_field_name();
+        quote! { #name: packet.#name() }
+    });
+
+    quote! {
+        impl From<&#ref_name<'_>> for #builder {
+            fn from(packet: &#ref_name<'_>) -> Self {
+                Self {
+                    #(#fields),*
+                }
+            }
+        }
+        impl From<&#owned_name> for #builder {
+            fn from(packet: &#owned_name) -> Self {
+                Self::from(&#ref_name(packet.as_bytes()))
+            }
+        }
+    }
+}
+
+/// `set_<field>` builder methods, which also set the field's bit in the parameter mask if it
+/// has an `apply_mask` attribute. Reserved fields and mask fields are left out.
+fn generate_setters(pack_descr: &PackDesc) -> Vec<TokenStream> {
+    let mask_fields: Vec<&Ident> = pack_descr
+        .fields
+        .iter()
+        .filter_map(|f| f.map.apply_mask.as_ref())
+        .filter_map(|bit| pack_descr.mask_field(bit))
+        .map(|f| f.intermediate_field_name())
+        .collect();
+
+    pack_descr
+        .fields
+        .iter()
+        .filter(|f| !f.name.to_string().starts_with("reserved"))
+        .filter(|f| !mask_fields.contains(&f.intermediate_field_name()))
+        .map(|f| {
+            let name = f.intermediate_field_name();
+            let ty = f.intermediate_type();
+            let field_comment = &f.comment;
+            let setter = format_ident!("set_{}", name);
+            let apply_mask = f.map.apply_mask.as_ref().map(|bit| {
+                let mask = pack_descr
+                    .mask_field(bit)
+                    .expect("mask field checked while parsing")
+                    .intermediate_field_name();
+                quote! { self.#mask |= #bit; }
+            });
+            quote! {
+                #[doc = #field_comment]
+                #[inline]
+                pub fn #setter(mut self, #name: #ty) -> Self {
+                    self.#name = #name;
+                    #apply_mask
+                    self
+                }
+            }
+        })
+        .collect()
+}
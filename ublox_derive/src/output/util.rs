@@ -39,27 +39,152 @@ pub(super) fn generate_debug_impl(
     }
 }
 
-pub(super) fn generate_serialize_impl(
-    _pack_name: &str,
+/// `Display` listing the fields but the reserved ones, one per line with their value scaled
+/// and their unit, similar to the message view of u-center, with the `display` feature
+pub(super) fn generate_display_impl(
+    pack_name: &str,
     ref_name: &Ident,
+    owned_name: &Ident,
     pack_descr: &PackDesc,
 ) -> TokenStream {
-    let fields = pack_descr.fields.iter().map(|field| {
-        let field_name = &field.name;
-        let field_accessor = field.intermediate_field_name();
-        if field.size_bytes.is_some() || field.is_optional() {
-            quote! {
-                state.serialize_entry(stringify!(#field_name), &self.#field_accessor())?;
+    let fields: Vec<TokenStream> = pack_descr
+        .fields
+        .iter()
+        .filter(|field| !field.name.to_string().starts_with("reserved"))
+        .map(|field| {
+            let label = format!("\n  {}: ", field.name);
+            let field_accessor = field.intermediate_field_name();
+            let unit = field
+                .map
+                .unit
+                .as_ref()
+                .map(|unit| format!(" {}", unit.value()))
+                .unwrap_or_default();
+            if field.map.chars {
+                quote! {
+                    f.write_str(#label)?;
+                    f.write_str(self.#field_accessor().unwrap_or("<invalid UTF-8>"))?;
+                }
+            } else if field.is_optional() {
+                quote! {
+                    f.write_str(#label)?;
+                    match self.#field_accessor() {
+                        Some(value) => write!(f, "{:?}{}", value, #unit)?,
+                        None => f.write_str("-")?,
+                    }
+                }
+            } else {
+                quote! {
+                    f.write_str(#label)?;
+                    write!(f, "{:?}{}", self.#field_accessor(), #unit)?;
+                }
             }
+        })
+        .collect();
+
+    quote! {
+        #[cfg(feature = "display")]
+        impl core::fmt::Display for #ref_name<'_> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str(#pack_name)?;
+                #(#fields)*
+                Ok(())
+            }
+        }
+        #[cfg(feature = "display")]
+        impl core::fmt::Display for #owned_name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str(#pack_name)?;
+                #(#fields)*
+                Ok(())
+            }
+        }
+    }
+}
+
+/// `defmt::Format` writing the fields like `Debug`. Fields of primitive types are encoded by
+/// `defmt`, the others are formatted on the target with their `Debug` implementation.
+pub(super) fn generate_defmt_impl(
+    pack_name: &str,
+    ref_name: &Ident,
+    owned_name: &Ident,
+    pack_descr: &PackDesc,
+) -> TokenStream {
+    let mut format = format!("{pack_name} {{{{");
+    let mut args = Vec::with_capacity(pack_descr.fields.len());
+    for (i, field) in pack_descr.fields.iter().enumerate() {
+        let separator = if i == 0 { " " } else { ", " };
+        format.push_str(&format!("{separator}{}: {{}}", field.name));
+        let field_accessor = field.intermediate_field_name();
+        if is_defmt_primitive(field.intermediate_type()) {
+            args.push(quote! { self.#field_accessor() });
         } else {
-            quote! {
-                state.serialize_entry(
-                    stringify!(#field_name),
-                    &FieldIter(self.#field_accessor())
-                )?;
+            args.push(quote! { defmt::Debug2Format(&self.#field_accessor()) });
+        }
+    }
+    format.push_str(" }}");
+
+    quote! {
+        #[cfg(feature = "defmt")]
+        impl defmt::Format for #ref_name<'_> {
+            fn format(&self, f: defmt::Formatter) {
+                defmt::write!(f, #format, #(#args),*)
+            }
+        }
+        #[cfg(feature = "defmt")]
+        impl defmt::Format for #owned_name {
+            fn format(&self, f: defmt::Formatter) {
+                defmt::write!(f, #format, #(#args),*)
             }
         }
-    });
+    }
+}
+
+fn is_defmt_primitive(ty: &Type) -> bool {
+    const PRIMITIVES: &[&str] = &[
+        "u8", "u16", "u32", "u64", "i8", "i16", "i32", "i64", "f32", "f64", "bool",
+    ];
+    match ty {
+        Type::Path(path) => path
+            .path
+            .get_ident()
+            .is_some_and(|ident| PRIMITIVES.iter().any(|p| ident == p)),
+        Type::Array(array) => is_defmt_primitive(&array.elem),
+        _ => false,
+    }
+}
+
+pub(super) fn generate_serialize_impl(
+    _pack_name: &str,
+    ref_name: &Ident,
+    owned_name: &Ident,
+    pack_descr: &PackDesc,
+) -> TokenStream {
+    let fields: Vec<TokenStream> = pack_descr
+        .fields
+        .iter()
+        .map(|field| {
+            let field_name = &field.name;
+            let field_accessor = field.intermediate_field_name();
+            if field.map.chars {
+                // The text, or none if it is not UTF-8
+                quote! {
+                    state.serialize_entry(stringify!(#field_name), &self.#field_accessor().ok())?;
+                }
+            } else if field.size_bytes.is_some() || field.is_optional() {
+                quote! {
+                    state.serialize_entry(stringify!(#field_name), &self.#field_accessor())?;
+                }
+            } else {
+                quote! {
+                    state.serialize_entry(
+                        stringify!(#field_name),
+                        &FieldIter(self.#field_accessor())
+                    )?;
+                }
+            }
+        })
+        .collect();
     quote! {
         #[cfg(feature = "serde")]
         impl SerializeUbxPacketFields for #ref_name<'_> {
@@ -83,6 +208,29 @@ pub(super) fn generate_serialize_impl(
                 state.end()
             }
         }
+
+        #[cfg(feature = "serde")]
+        impl SerializeUbxPacketFields for #owned_name {
+            fn serialize_fields<S>(&self, state: &mut S) -> Result<(), S::Error>
+            where
+                S: serde::ser::SerializeMap,
+            {
+                #(#fields)*
+                Ok(())
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for #owned_name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                let mut state = serializer.serialize_map(None)?;
+                self.serialize_fields(&mut state)?;
+                state.end()
+            }
+        }
     }
 }
 
@@ -110,6 +258,14 @@ pub(super) fn get_raw_field_code(
         quote! { &#data[#cur_off .. (#cur_off + #size_bytes)] }
     } else if field.is_field_raw_ty_byte_array() {
         quote! { [#(#bytes),*] }
+    } else if field.is_int24() {
+        let unsigned: Type = parse_quote! { u32 };
+        if *raw_ty == unsigned {
+            quote! { u32::from_le_bytes([#(#bytes),*, 0]) }
+        } else {
+            // Sign extension by the arithmetic shift
+            quote! { i32::from_le_bytes([0, #(#bytes),*]) >> 8 }
+        }
     } else if size_bytes.get() != 1 || *raw_ty == signed_byte {
         quote! { <#raw_ty>::from_le_bytes([#(#bytes),*]) }
     } else {
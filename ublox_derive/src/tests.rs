@@ -43,8 +43,85 @@ fn test_ubx_packet_recv_simple() {
             impl UbxPacketMeta for Test {
                 const CLASS: u8 = 1u8;
                 const ID: u8 = 2u8;
+                const NAME: &'static str = "Test";
                 const FIXED_PAYLOAD_LEN: Option<u16> = Some(16u16);
                 const MAX_PAYLOAD_LEN: u16 = 16u16;
+                const PAYLOAD_FIELDS: &'static [crate::PayloadField] = &[
+                    crate::PayloadField {
+                        name: "itow",
+                        offset: 0u16,
+                        size: 4u16,
+                        kind: crate::PayloadFieldKind::Data,
+                    },
+                    crate::PayloadField {
+                        name: "lat",
+                        offset: 4u16,
+                        size: 4u16,
+                        kind: crate::PayloadFieldKind::Data,
+                    },
+                    crate::PayloadField {
+                        name: "a",
+                        offset: 8u16,
+                        size: 1u16,
+                        kind: crate::PayloadFieldKind::Data,
+                    },
+                    crate::PayloadField {
+                        name: "reserved1",
+                        offset: 9u16,
+                        size: 5u16,
+                        kind: crate::PayloadFieldKind::Reserved,
+                    },
+                    crate::PayloadField {
+                        name: "flags",
+                        offset: 14u16,
+                        size: 1u16,
+                        kind: crate::PayloadFieldKind::Data,
+                    },
+                    crate::PayloadField {
+                        name: "b",
+                        offset: 15u16,
+                        size: 1u16,
+                        kind: crate::PayloadFieldKind::Data,
+                    },
+                ];
+                const PAYLOAD_FIELDS_LEN: u16 = 16u16;
+            }
+
+            impl Test {
+                    #[doc = "Byte offset of `itow` in the payload"]
+                    pub const OFFSET_ITOW: usize = 0usize;
+                    #[doc = "Byte offset of `lat` in the payload"]
+                    pub const OFFSET_LAT: usize = 4usize;
+                    #[doc = "Byte offset of `a` in the payload"]
+                    pub const OFFSET_A: usize = 8usize;
+                    #[doc = "Byte offset of `reserved1` in the payload"]
+                    pub const OFFSET_RESERVED1: usize = 9usize;
+                    #[doc = "Byte offset of `flags` in the payload"]
+                    pub const OFFSET_FLAGS: usize = 14usize;
+                    #[doc = "Byte offset of `b` in the payload"]
+                    pub const OFFSET_B: usize = 15usize;
+            }
+
+            #[cfg(feature = "serde")]
+            impl Test {
+                    #[doc = "Field `itow`, to select it in a [Projection](crate::projection::Projection)"]
+                    pub const FIELD_ITOW: crate::projection::Field =
+                        crate::projection::Field::new("itow");
+                    #[doc = "Field `lat`, to select it in a [Projection](crate::projection::Projection)"]
+                    pub const FIELD_LAT: crate::projection::Field =
+                        crate::projection::Field::new("lat");
+                    #[doc = "Field `a`, to select it in a [Projection](crate::projection::Projection)"]
+                    pub const FIELD_A: crate::projection::Field =
+                        crate::projection::Field::new("a");
+                    #[doc = "Field `reserved1`, to select it in a [Projection](crate::projection::Projection)"]
+                    pub const FIELD_RESERVED1: crate::projection::Field =
+                        crate::projection::Field::new("reserved1");
+                    #[doc = "Field `flags`, to select it in a [Projection](crate::projection::Projection)"]
+                    pub const FIELD_FLAGS: crate::projection::Field =
+                        crate::projection::Field::new("flags");
+                    #[doc = "Field `b`, to select it in a [Projection](crate::projection::Projection)"]
+                    pub const FIELD_B: crate::projection::Field =
+                        crate::projection::Field::new("b");
             }
 
             #[doc = "Some comment"]
@@ -175,6 +252,10 @@ fn test_ubx_packet_recv_simple() {
                 pub fn as_bytes(&self) -> &[u8] {
                     &self.0
                 }
+                #[inline]
+                pub fn payload_len(&self) -> usize {
+                    16usize
+                }
                 #[doc = ""]
                 #[inline]
                 pub fn itow(&self) -> u32 {
@@ -282,6 +363,25 @@ fn test_ubx_packet_recv_simple() {
                 }
             }
 
+            impl crate::UbxPacketRecv for Test {
+                type Ref<'a> = TestRef<'a>;
+                type Owned = TestOwned;
+
+                fn parse_payload(payload: &[u8]) -> Result<TestRef<'_>, ParserError> {
+                    TestRef::validate(payload)?;
+                    Ok(TestRef(payload))
+                }
+            }
+
+            impl<'a> core::convert::TryFrom<&'a [u8]> for TestRef<'a> {
+                type Error = ParserError;
+
+                /// Validate a bare payload, without its frame, and wrap it
+                fn try_from(payload: &'a [u8]) -> Result<Self, ParserError> {
+                    <Test as crate::UbxPacketRecv>::parse_payload(payload)
+                }
+            }
+
             impl core::fmt::Debug for TestRef<'_> {
                 fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
                     f.debug_struct("Test")
@@ -306,6 +406,40 @@ fn test_ubx_packet_recv_simple() {
                         .finish()
                 }
             }
+            #[cfg(feature = "display")]
+            impl core::fmt::Display for TestRef<'_> {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    f.write_str("Test")?;
+                    f.write_str("\n  itow: ")?;
+                    write!(f, "{:?}{}", self.itow(), "")?;
+                    f.write_str("\n  lat: ")?;
+                    write!(f, "{:?}{}", self.lat_degrees(), "")?;
+                    f.write_str("\n  a: ")?;
+                    write!(f, "{:?}{}", self.a(), "")?;
+                    f.write_str("\n  flags: ")?;
+                    write!(f, "{:?}{}", self.flags(), "")?;
+                    f.write_str("\n  b: ")?;
+                    write!(f, "{:?}{}", self.b(), "")?;
+                    Ok(())
+                }
+            }
+            #[cfg(feature = "display")]
+            impl core::fmt::Display for TestOwned {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    f.write_str("Test")?;
+                    f.write_str("\n  itow: ")?;
+                    write!(f, "{:?}{}", self.itow(), "")?;
+                    f.write_str("\n  lat: ")?;
+                    write!(f, "{:?}{}", self.lat_degrees(), "")?;
+                    f.write_str("\n  a: ")?;
+                    write!(f, "{:?}{}", self.a(), "")?;
+                    f.write_str("\n  flags: ")?;
+                    write!(f, "{:?}{}", self.flags(), "")?;
+                    f.write_str("\n  b: ")?;
+                    write!(f, "{:?}{}", self.b(), "")?;
+                    Ok(())
+                }
+            }
             #[cfg(feature = "serde")]
             impl SerializeUbxPacketFields for TestRef<'_> {
                 fn serialize_fields<S>(&self, state: &mut S) -> Result<(), S::Error>
@@ -332,10 +466,284 @@ fn test_ubx_packet_recv_simple() {
                     state.end()
                 }
             }
+            #[cfg(feature = "serde")]
+            impl SerializeUbxPacketFields for TestOwned {
+                fn serialize_fields<S>(&self, state: &mut S) -> Result<(), S::Error>
+                where
+                    S: serde::ser::SerializeMap,
+                {
+                    state.serialize_entry(stringify!(itow), &self.itow())?;
+                    state.serialize_entry(stringify!(lat), &self.lat_degrees())?;
+                    state.serialize_entry(stringify!(a), &self.a())?;
+                    state.serialize_entry(stringify!(reserved1), &self.reserved1())?;
+                    state.serialize_entry(stringify!(flags), &self.flags())?;
+                    state.serialize_entry(stringify!(b), &self.b())?;
+                    Ok(())
+                }
+            }
+            #[cfg(feature = "serde")]
+            impl serde::Serialize for TestOwned {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: serde::Serializer,
+                {
+                    let mut state = serializer.serialize_map(None)?;
+                    self.serialize_fields(&mut state)?;
+                    state.end()
+                }
+            }
+            #[cfg(feature = "defmt")]
+            impl defmt::Format for TestRef<'_> {
+                fn format(&self, f: defmt::Formatter) {
+                    defmt::write!(
+                        f,
+                        "Test {{ itow: {}, lat: {}, a: {}, reserved1: {}, flags: {}, b: {} }}",
+                        self.itow(),
+                        self.lat_degrees(),
+                        self.a(),
+                        self.reserved1(),
+                        defmt::Debug2Format(&self.flags()),
+                        self.b()
+                    )
+                }
+            }
+            #[cfg(feature = "defmt")]
+            impl defmt::Format for TestOwned {
+                fn format(&self, f: defmt::Formatter) {
+                    defmt::write!(
+                        f,
+                        "Test {{ itow: {}, lat: {}, a: {}, reserved1: {}, flags: {}, b: {} }}",
+                        self.itow(),
+                        self.lat_degrees(),
+                        self.a(),
+                        self.reserved1(),
+                        defmt::Debug2Format(&self.flags()),
+                        self.b()
+                    )
+                }
+            }
         },
     );
 }
 
+#[test]
+fn test_ubx_packet_recv_unit() {
+    let src_code = quote! {
+        #[ubx_packet_recv]
+        #[ubx(class = 1, id = 2, fixed_payload_len = 4)]
+        struct Test {
+            #[doc = "Latitude"]
+            #[ubx(scale = 1e-7, unit = "deg")]
+            lat: i32,
+        }
+    };
+    let code: syn::ItemStruct = syn::parse2(src_code).unwrap();
+    let tokens = generate_code_for_recv_packet(code.ident, code.attrs, code.fields, code.generics)
+        .unwrap()
+        .to_string();
+
+    assert!(tokens.contains("pub fn lat_raw (& self) -> i32"));
+    assert!(tokens.contains("pub fn lat_degrees (& self) -> f64"));
+    assert!(tokens.contains("\"Unit: deg\""));
+    assert!(!tokens.contains("lat_degrees_raw"));
+
+    let src_code = quote! {
+        #[ubx_packet_recv]
+        #[ubx(class = 1, id = 2, fixed_payload_len = 4)]
+        struct Test {
+            #[ubx(scale = 1e-7, unit = "furlong")]
+            lat: i32,
+        }
+    };
+    let code: syn::ItemStruct = syn::parse2(src_code).unwrap();
+    let err = generate_code_for_recv_packet(code.ident, code.attrs, code.fields, code.generics)
+        .unwrap_err();
+    assert!(err.to_string().starts_with("Unsupported unit"));
+}
+
+#[test]
+fn test_ubx_packet_recv_display() {
+    let src_code = quote! {
+        #[ubx_packet_recv]
+        #[ubx(class = 1, id = 2, fixed_payload_len = 8)]
+        struct Test {
+            reserved1: [u8; 4],
+            #[ubx(scale = 1e-7, unit = "deg")]
+            lat: i32,
+        }
+    };
+    let code: syn::ItemStruct = syn::parse2(src_code).unwrap();
+    let tokens = generate_code_for_recv_packet(code.ident, code.attrs, code.fields, code.generics)
+        .unwrap()
+        .to_string();
+
+    assert!(tokens.contains(
+        "# [cfg (feature = \"display\")] impl core :: fmt :: Display for TestRef < '_ >"
+    ));
+    assert!(tokens
+        .contains("# [cfg (feature = \"display\")] impl core :: fmt :: Display for TestOwned"));
+    assert!(tokens.contains(
+        "f . write_str (\"\\n  lat: \") ? ; write ! (f , \"{:?}{}\" , self . lat_degrees () , \" deg\") ? ;"
+    ));
+    assert!(!tokens.contains("\\n  reserved1: "));
+}
+
+#[test]
+fn test_ubx_packet_recv_min_payload_len() {
+    let src_code = quote! {
+        #[ubx_packet_recv]
+        #[ubx(class = 1, id = 2, max_payload_len = 8, min_payload_len = 4)]
+        struct Test {
+            itow: u32,
+            #[ubx(map_type = f64, scale = 1e-2)]
+            heading: i32,
+        }
+    };
+    let code: syn::ItemStruct = syn::parse2(src_code).unwrap();
+    let tokens = generate_code_for_recv_packet(code.ident, code.attrs, code.fields, code.generics)
+        .unwrap()
+        .to_string();
+
+    assert!(tokens.contains("pub fn itow (& self) -> u32"));
+    assert!(tokens.contains("pub fn heading_raw (& self) -> Option < i32 >"));
+    assert!(tokens.contains("pub fn heading (& self) -> Option < f64 >"));
+    assert!(tokens.contains("if self . payload_len () < 8usize { return None ; }"));
+    assert!(tokens.contains("if got < 4usize"));
+    assert!(tokens.contains("const FIXED_PAYLOAD_LEN : Option < u16 > = None"));
+
+    // The minimum length has to end at a field
+    let src_code = quote! {
+        #[ubx_packet_recv]
+        #[ubx(class = 1, id = 2, max_payload_len = 8, min_payload_len = 6)]
+        struct Test {
+            itow: u32,
+            heading: i32,
+        }
+    };
+    let code: syn::ItemStruct = syn::parse2(src_code).unwrap();
+    assert!(
+        generate_code_for_recv_packet(code.ident, code.attrs, code.fields, code.generics).is_err()
+    );
+}
+
+#[test]
+fn test_ubx_packet_recv_payload_len_mismatch() {
+    let src_code = quote! {
+        #[ubx_packet_recv]
+        #[ubx(class = 1, id = 2, fixed_payload_len = 6)]
+        struct Test {
+            itow: u32,
+            reserved1: [u8; 3],
+        }
+    };
+    let code: syn::ItemStruct = syn::parse2(src_code).unwrap();
+    let err = generate_code_for_recv_packet(code.ident, code.attrs, code.fields, code.generics)
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "fixed_payload_len = 6 doesn't match the 7 bytes of the fields"
+    );
+
+    let src_code = quote! {
+        #[ubx_packet_recv]
+        #[ubx(class = 1, id = 2, max_payload_len = 4)]
+        struct Test {
+            itow: u32,
+            num_svs: u8,
+            #[ubx(repeat = num_svs)]
+            svs: [TestSv],
+        }
+    };
+    let code: syn::ItemStruct = syn::parse2(src_code).unwrap();
+    let err = generate_code_for_recv_packet(code.ident, code.attrs, code.fields, code.generics)
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "max_payload_len = 4 is less than the 5 bytes of the fixed size fields"
+    );
+}
+
+#[test]
+fn test_ubx_packet_recv_repeat() {
+    let src_code = quote! {
+        #[ubx_packet_recv]
+        #[ubx(class = 1, id = 2, max_payload_len = 100)]
+        struct Test {
+            itow: u32,
+            num_svs: u8,
+            #[doc = "Satellites"]
+            #[ubx(repeat = num_svs)]
+            svs: [TestSv],
+        }
+    };
+    let code: syn::ItemStruct = syn::parse2(src_code).unwrap();
+    let tokens = generate_code_for_recv_packet(code.ident, code.attrs, code.fields, code.generics)
+        .unwrap()
+        .to_string();
+
+    assert!(tokens.contains(
+        "pub fn svs (& self) -> crate :: RepeatedIter < '_ , TestSv > { \
+         crate :: RepeatedIter :: new (& self . as_bytes () [5usize ..]) }"
+    ));
+    assert!(tokens.contains("let count = usize :: from (payload [4usize]) ;"));
+    assert!(tokens.contains("< TestSv as crate :: UbxPacketRecv > :: parse_payload (block) ?"));
+
+    // The count has to be an earlier integer field
+    for fields in [
+        quote! { #[ubx(repeat = num_svs)] svs: [TestSv], num_svs: u8, },
+        quote! { num_svs: u32, #[ubx(repeat = num_svs)] svs: [TestSv], },
+        quote! { num_svs: u8, svs: [TestSv], },
+    ] {
+        let src_code = quote! {
+            #[ubx_packet_recv]
+            #[ubx(class = 1, id = 2, max_payload_len = 100)]
+            struct Test {
+                #fields
+            }
+        };
+        let code: syn::ItemStruct = syn::parse2(src_code).unwrap();
+        assert!(
+            generate_code_for_recv_packet(code.ident, code.attrs, code.fields, code.generics)
+                .is_err()
+        );
+    }
+}
+
+#[test]
+fn test_ubx_packet_recv_chars() {
+    let src_code = quote! {
+        #[ubx_packet_recv]
+        #[ubx(class = 1, id = 2, fixed_payload_len = 10)]
+        struct Test {
+            #[ubx(chars)]
+            version: [u8; 10],
+        }
+    };
+    let code: syn::ItemStruct = syn::parse2(src_code).unwrap();
+    let tokens = generate_code_for_recv_packet(code.ident, code.attrs, code.fields, code.generics)
+        .unwrap()
+        .to_string();
+
+    assert!(
+        tokens.contains("pub fn version (& self) -> Result < & str , core :: str :: Utf8Error >")
+    );
+    assert!(tokens.contains("crate :: str_from_chars"));
+
+    // Only byte arrays hold characters
+    let src_code = quote! {
+        #[ubx_packet_recv]
+        #[ubx(class = 1, id = 2, fixed_payload_len = 4)]
+        struct Test {
+            #[ubx(chars)]
+            version: u32,
+        }
+    };
+    let code: syn::ItemStruct = syn::parse2(src_code).unwrap();
+    assert!(
+        generate_code_for_recv_packet(code.ident, code.attrs, code.fields, code.generics).is_err()
+    );
+}
+
 #[test]
 fn test_ubx_packet_recv_dyn_len() {
     let src_code = quote! {
@@ -366,8 +774,33 @@ fn test_ubx_packet_recv_dyn_len() {
             impl UbxPacketMeta for Test {
                 const CLASS: u8 = 1u8;
                 const ID: u8 = 2u8;
+                const NAME: &'static str = "Test";
                 const FIXED_PAYLOAD_LEN: Option<u16> = None;
                 const MAX_PAYLOAD_LEN: u16 = 38u16;
+                const PAYLOAD_FIELDS: &'static [crate::PayloadField] = &[
+                    crate::PayloadField {
+                        name: "f1",
+                        offset: 0u16,
+                        size: 8u16,
+                        kind: crate::PayloadFieldKind::Data,
+                    },
+                ];
+                const PAYLOAD_FIELDS_LEN: u16 = 8u16;
+            }
+
+            impl Test {
+                    #[doc = "Byte offset of `f1` in the payload"]
+                    pub const OFFSET_F1: usize = 0usize;
+            }
+
+            #[cfg(feature = "serde")]
+            impl Test {
+                    #[doc = "Field `f1`, to select it in a [Projection](crate::projection::Projection)"]
+                    pub const FIELD_F1: crate::projection::Field =
+                        crate::projection::Field::new("f1");
+                    #[doc = "Field `rest`, to select it in a [Projection](crate::projection::Projection)"]
+                    pub const FIELD_REST: crate::projection::Field =
+                        crate::projection::Field::new("rest");
             }
 
             #[doc = ""]
@@ -404,11 +837,18 @@ fn test_ubx_packet_recv_dyn_len() {
                 #[doc = ""]
                 #[inline]
                 pub fn rest(&self) -> &[u8] {
-                    &self.0[8usize..]
+                    &self.as_bytes()[8usize..]
                 }
 
                 pub(crate) fn validate(payload: &[u8]) -> Result<(), ParserError> {
                     let got = payload.len();
+                    if got > 38usize {
+                        return Err(ParserError::InvalidPacketLen {
+                            packet: "Test",
+                            expect: 38usize,
+                            got,
+                        });
+                    }
                     let min = 8usize;
                     if got >= min {
                         Ok(())
@@ -424,12 +864,16 @@ fn test_ubx_packet_recv_dyn_len() {
             #[doc = ""]
             #[doc = "Owns the underlying buffer of data, contains accessor methods to retrieve data."]
             #[derive(Clone)]
-            pub struct TestOwned(pub(crate) [u8; 38usize]);
+            pub struct TestOwned(pub(crate) [u8; 38usize], pub(crate) usize);
             impl TestOwned {
                 pub(crate) const PACKET_SIZE: usize = 38usize;
                 #[inline]
                 pub fn as_bytes(&self) -> &[u8] {
-                    &self.0
+                    &self.0[..self.1]
+                }
+                #[inline]
+                pub fn payload_len(&self) -> usize {
+                    self.1
                 }
                 #[doc = ""]
                 #[inline]
@@ -447,10 +891,17 @@ fn test_ubx_packet_recv_dyn_len() {
                 #[doc = ""]
                 #[inline]
                 pub fn rest(&self) -> &[u8] {
-                    &self.0[8usize..]
+                    &self.as_bytes()[8usize..]
                 }
                 pub(crate) fn validate(payload: &[u8]) -> Result<(), ParserError> {
                     let got = payload.len();
+                    if got > 38usize {
+                        return Err(ParserError::InvalidPacketLen {
+                            packet: "Test",
+                            expect: 38usize,
+                            got,
+                        });
+                    }
                     let min = 8usize;
                     if got >= min {
                         Ok(())
@@ -468,7 +919,7 @@ fn test_ubx_packet_recv_dyn_len() {
                     let src = packet.as_bytes();
                     let mut dst = [0u8; 38usize];
                     dst[..src.len()].clone_from_slice(src);
-                    Self(dst)
+                    Self(dst, src.len())
                 }
             }
             impl<'a> From<TestRef<'a>> for TestOwned {
@@ -477,6 +928,25 @@ fn test_ubx_packet_recv_dyn_len() {
                 }
             }
 
+            impl crate::UbxPacketRecv for Test {
+                type Ref<'a> = TestRef<'a>;
+                type Owned = TestOwned;
+
+                fn parse_payload(payload: &[u8]) -> Result<TestRef<'_>, ParserError> {
+                    TestRef::validate(payload)?;
+                    Ok(TestRef(payload))
+                }
+            }
+
+            impl<'a> core::convert::TryFrom<&'a [u8]> for TestRef<'a> {
+                type Error = ParserError;
+
+                /// Validate a bare payload, without its frame, and wrap it
+                fn try_from(payload: &'a [u8]) -> Result<Self, ParserError> {
+                    <Test as crate::UbxPacketRecv>::parse_payload(payload)
+                }
+            }
+
             impl core::fmt::Debug for TestRef<'_> {
                 fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
                     f.debug_struct("Test")
@@ -494,6 +964,28 @@ fn test_ubx_packet_recv_dyn_len() {
                         .finish()
                 }
             }
+            #[cfg(feature = "display")]
+            impl core::fmt::Display for TestRef<'_> {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    f.write_str("Test")?;
+                    f.write_str("\n  f1: ")?;
+                    write!(f, "{:?}{}", self.f1(), "")?;
+                    f.write_str("\n  rest: ")?;
+                    write!(f, "{:?}{}", self.rest(), "")?;
+                    Ok(())
+                }
+            }
+            #[cfg(feature = "display")]
+            impl core::fmt::Display for TestOwned {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    f.write_str("Test")?;
+                    f.write_str("\n  f1: ")?;
+                    write!(f, "{:?}{}", self.f1(), "")?;
+                    f.write_str("\n  rest: ")?;
+                    write!(f, "{:?}{}", self.rest(), "")?;
+                    Ok(())
+                }
+            }
             #[cfg(feature = "serde")]
             impl SerializeUbxPacketFields for TestRef<'_> {
                 fn serialize_fields<S>(&self, state: &mut S) -> Result<(), S::Error>
@@ -519,10 +1011,120 @@ fn test_ubx_packet_recv_dyn_len() {
                     state.end()
                 }
             }
+            #[cfg(feature = "serde")]
+            impl SerializeUbxPacketFields for TestOwned {
+                fn serialize_fields<S>(&self, state: &mut S) -> Result<(), S::Error>
+                where
+                    S: serde::ser::SerializeMap,
+                {
+                    state.serialize_entry(stringify!(f1), &self.f1())?;
+                    state.serialize_entry(
+                        stringify!(rest),
+                        &FieldIter(self.rest()),
+                    )?;
+                    Ok(())
+                }
+            }
+            #[cfg(feature = "serde")]
+            impl serde::Serialize for TestOwned {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: serde::Serializer,
+                {
+                    let mut state = serializer.serialize_map(None)?;
+                    self.serialize_fields(&mut state)?;
+                    state.end()
+                }
+            }
+            #[cfg(feature = "defmt")]
+            impl defmt::Format for TestRef<'_> {
+                fn format(&self, f: defmt::Formatter) {
+                    defmt::write!(
+                        f,
+                        "Test {{ f1: {}, rest: {} }}",
+                        defmt::Debug2Format(&self.f1()),
+                        self.rest()
+                    )
+                }
+            }
+            #[cfg(feature = "defmt")]
+            impl defmt::Format for TestOwned {
+                fn format(&self, f: defmt::Formatter) {
+                    defmt::write!(
+                        f,
+                        "Test {{ f1: {}, rest: {} }}",
+                        defmt::Debug2Format(&self.f1()),
+                        self.rest()
+                    )
+                }
+            }
         },
     );
 }
 
+#[test]
+fn test_ubx_packet_recv_send_builder_from_recv() {
+    let src_code = quote! {
+        #[ubx_packet_recv_send]
+        #[ubx(class = 1, id = 2, fixed_payload_len = 9)]
+        struct Test {
+            #[ubx(map_type = f64, scale = 1e-2)]
+            pdop: u16,
+            reserved1: [u8; 6],
+            flags: u8,
+        }
+    };
+    let code: syn::ItemStruct = syn::parse2(src_code).unwrap();
+    let tokens =
+        generate_code_for_recv_send_packet(code.ident, code.attrs, code.fields, code.generics)
+            .unwrap()
+            .to_string();
+
+    assert!(tokens.contains("impl From < & TestRef < '_ >> for TestBuilder"));
+    assert!(tokens.contains(
+        "Self { pdop : packet . pdop () , reserved1 : packet . reserved1 () , flags : packet . flags () }"
+    ));
+    assert!(tokens.contains(
+        "impl From < & TestOwned > for TestBuilder { \
+         fn from (packet : & TestOwned) -> Self { Self :: from (& TestRef (packet . as_bytes ())) } }"
+    ));
+}
+
+#[test]
+fn test_ubx_packet_recv_send_int24() {
+    let src_code = quote! {
+        #[ubx_packet_recv_send]
+        #[ubx(class = 1, id = 2, fixed_payload_len = 7)]
+        struct Test {
+            count: u24,
+            #[ubx(map_type = f64, scale = 1e-3)]
+            offset: i24,
+            flags: u8,
+        }
+    };
+    let code: syn::ItemStruct = syn::parse2(src_code).unwrap();
+    let tokens =
+        generate_code_for_recv_send_packet(code.ident, code.attrs, code.fields, code.generics)
+            .unwrap()
+            .to_string();
+
+    // Accessed as 32-bit integers, the signed one sign extended
+    assert!(tokens.contains(
+        "pub fn count (& self) -> u32 { let val = u32 :: from_le_bytes \
+         ([self . 0 [0usize] , self . 0 [1usize] , self . 0 [2usize] , 0]) ; val }"
+    ));
+    assert!(tokens.contains(
+        "pub fn offset_raw (& self) -> i32 { let val = i32 :: from_le_bytes \
+         ([0 , self . 0 [3usize] , self . 0 [4usize] , self . 0 [5usize]]) >> 8 ; val }"
+    ));
+    // Written as three bytes
+    assert!(tokens.contains(
+        "let bytes = self . count . to_le_bytes () ; let bytes = [bytes [0] , bytes [1] , bytes [2]] ; \
+         ret [6usize] = bytes [0usize] ; ret [7usize] = bytes [1usize] ; ret [8usize] = bytes [2usize]"
+    ));
+    assert!(tokens.contains("name : \"offset\" , offset : 3u16 , size : 3u16"));
+}
+
 #[test]
 fn test_ubx_packet_send() {
     let src_code = quote! {
@@ -554,8 +1156,52 @@ fn test_ubx_packet_send() {
             impl UbxPacketMeta for Test {
                 const CLASS: u8 = 1u8;
                 const ID: u8 = 2u8;
+                const NAME: &'static str = "Test";
                 const FIXED_PAYLOAD_LEN: Option<u16> = Some(9u16);
                 const MAX_PAYLOAD_LEN: u16 = 9u16;
+                const PAYLOAD_FIELDS: &'static [crate::PayloadField] = &[
+                    crate::PayloadField {
+                        name: "itow",
+                        offset: 0u16,
+                        size: 4u16,
+                        kind: crate::PayloadFieldKind::Data,
+                    },
+                    crate::PayloadField {
+                        name: "lat",
+                        offset: 4u16,
+                        size: 4u16,
+                        kind: crate::PayloadFieldKind::Data,
+                    },
+                    crate::PayloadField {
+                        name: "a",
+                        offset: 8u16,
+                        size: 1u16,
+                        kind: crate::PayloadFieldKind::Data,
+                    },
+                ];
+                const PAYLOAD_FIELDS_LEN: u16 = 9u16;
+            }
+
+            impl Test {
+                    #[doc = "Byte offset of `itow` in the payload"]
+                    pub const OFFSET_ITOW: usize = 0usize;
+                    #[doc = "Byte offset of `lat` in the payload"]
+                    pub const OFFSET_LAT: usize = 4usize;
+                    #[doc = "Byte offset of `a` in the payload"]
+                    pub const OFFSET_A: usize = 8usize;
+            }
+
+            #[cfg(feature = "serde")]
+            impl Test {
+                    #[doc = "Field `itow`, to select it in a [Projection](crate::projection::Projection)"]
+                    pub const FIELD_ITOW: crate::projection::Field =
+                        crate::projection::Field::new("itow");
+                    #[doc = "Field `lat`, to select it in a [Projection](crate::projection::Projection)"]
+                    pub const FIELD_LAT: crate::projection::Field =
+                        crate::projection::Field::new("lat");
+                    #[doc = "Field `a`, to select it in a [Projection](crate::projection::Projection)"]
+                    pub const FIELD_A: crate::projection::Field =
+                        crate::projection::Field::new("a");
             }
 
             #[doc = "Some comment"]
@@ -601,6 +1247,25 @@ fn test_ubx_packet_send() {
                     ret[Self::PACKET_LEN - 1] = ck_b;
                     ret
                 }
+
+                #[doc = r" Length of the frame, [Self::PACKET_LEN]"]
+                #[inline]
+                pub const fn frame_len(&self) -> usize {
+                    Self::PACKET_LEN
+                }
+
+                #[doc = r" Write the frame to the start of `buf`, e.g. a DMA buffer, returning its length"]
+                #[inline]
+                pub fn extend_to_slice(
+                    self,
+                    buf: &mut [u8]
+                ) -> Result<usize, crate::error::MemWriterError<core::convert::Infallible>> {
+                    let out = buf
+                        .get_mut(..Self::PACKET_LEN)
+                        .ok_or(crate::error::MemWriterError::NotEnoughMem)?;
+                    out.copy_from_slice(&self.into_packet_bytes());
+                    Ok(Self::PACKET_LEN)
+                }
             }
             impl From<TestBuilder> for [u8; 17usize] {
                 fn from(x: TestBuilder) -> Self {
@@ -669,11 +1334,14 @@ fn test_upgrade_enum() {
 
     let mut reserved_fields = Vec::with_capacity(256);
     let mut rev_reserved_fields = Vec::with_capacity(256);
+    let mut defmt_reserved_fields = Vec::with_capacity(256);
     for i in 6..=255 {
         let val = i as u8;
         let ident = quote::format_ident!("Reserved{}", val);
         reserved_fields.push(quote! { #ident = #val });
         rev_reserved_fields.push(quote! { #val => GpsFix::#ident });
+        let text = ident.to_string();
+        defmt_reserved_fields.push(quote! { GpsFix::#ident => defmt::write!(f, #text) });
     }
 
     run_compare_test(
@@ -704,6 +1372,11 @@ fn test_upgrade_enum() {
                     }
                 }
             }
+            impl GpsFix {
+                #[doc = r" Raw values of the declared variants, e.g. to generate valid payloads in fuzz"]
+                #[doc = r" tests"]
+                pub const KNOWN_VALUES: &'static [u8] = &[0u8, 1u8, 2u8, 3u8, 4u8, 5u8];
+            }
             #[cfg(feature = "serde")]
             impl serde::Serialize for GpsFix {
                 fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -713,6 +1386,20 @@ fn test_upgrade_enum() {
                     serializer.serialize_u8(*self as u8)
                 }
             }
+            #[cfg(feature = "defmt")]
+            impl defmt::Format for GpsFix {
+                fn format(&self, f: defmt::Formatter) {
+                    match self {
+                        GpsFix::NoFix => defmt::write!(f, "NoFix"),
+                        GpsFix::DeadReckoningOnly => defmt::write!(f, "DeadReckoningOnly"),
+                        GpsFix::Fix2D => defmt::write!(f, "Fix2D"),
+                        GpsFix::Fix3D => defmt::write!(f, "Fix3D"),
+                        GpsFix::GPSPlusDeadReckoning => defmt::write!(f, "GPSPlusDeadReckoning"),
+                        GpsFix::TimeOnlyFix => defmt::write!(f, "TimeOnlyFix"),
+                        #(#defmt_reserved_fields),*
+                    }
+                }
+            }
         },
     );
 }
@@ -734,6 +1421,9 @@ fn test_define_recv_packets() {
     run_compare_test(
         output,
         quote! {
+            #[cfg(feature = "alloc")]
+            use alloc::vec::Vec;
+
             #[doc = "All possible packets enum"]
             #[derive(Debug)]
             #[non_exhaustive]
@@ -743,7 +1433,7 @@ fn test_define_recv_packets() {
                 Unknown(UnknownPacketRef<'a>),
             }
             #[doc = "All possible packets enum, owning the underlying data"]
-            #[derive(Debug)]
+            #[derive(Debug, Clone)]
             #[non_exhaustive]
             pub enum PacketOwned {
                 Pack1(Pack1Owned),
@@ -769,6 +1459,46 @@ fn test_define_recv_packets() {
                         PacketRef::Unknown(ref pack) => pack.payload.len(),
                     }
                 }
+                #[doc = "The raw payload, without the frame header and checksum"]
+                #[inline]
+                pub fn payload(&self) -> &'a [u8] {
+                    match *self {
+                        PacketRef::Pack1(ref packet) => packet.0,
+                        PacketRef::Pack2(ref packet) => packet.0,
+                        PacketRef::Unknown(ref pack) => pack.payload,
+                    }
+                }
+
+                #[doc = "Class, id and payload of the packet, e.g. to rebuild its frame"]
+                #[inline]
+                pub fn as_raw(&self) -> UnknownPacketRef<'a> {
+                    let (class, msg_id) = self.class_and_msg_id();
+                    UnknownPacketRef {
+                        payload: self.payload(),
+                        class,
+                        msg_id,
+                    }
+                }
+
+                #[doc = "Length of the frame, from the sync characters to the checksum"]
+                #[inline]
+                pub fn frame_len(&self) -> usize {
+                    self.as_raw().frame_len()
+                }
+
+                #[doc = "The frame rebuilt from the packet, with its length and checksum computed again. Use `CheckedPacket::to_frame_vec` to verify them against the received frame."]
+                #[cfg(any(feature = "std", feature = "alloc"))]
+                pub fn to_frame_vec(&self) -> Vec<u8> {
+                    self.as_raw().to_frame_bytes()
+                }
+
+                #[doc = "Rebuild the frame at the start of `buf` like `to_frame_vec`, returning its length"]
+                pub fn extend_to_slice(
+                    &self,
+                    buf: &mut [u8]
+                ) -> Result<usize, crate::error::MemWriterError<core::convert::Infallible>> {
+                    self.as_raw().extend_to_slice(buf)
+                }
             }
             impl PacketOwned {
                 pub fn class_and_msg_id(&self) -> (u8, u8) {
@@ -778,6 +1508,26 @@ fn test_define_recv_packets() {
                         PacketOwned::Unknown(ref pack) => (pack.class, pack.msg_id),
                     }
                 }
+                #[doc = "The raw payload, without the frame header and checksum"]
+                #[inline]
+                pub fn payload(&self) -> &[u8] {
+                    match *self {
+                        PacketOwned::Pack1(ref packet) => packet.as_bytes(),
+                        PacketOwned::Pack2(ref packet) => packet.as_bytes(),
+                        PacketOwned::Unknown(ref pack) => &pack.payload[..pack.payload_len],
+                    }
+                }
+
+                #[doc = "Class, id and payload of the packet, e.g. to rebuild its frame"]
+                #[inline]
+                pub fn as_raw(&self) -> UnknownPacketRef<'_> {
+                    let (class, msg_id) = self.class_and_msg_id();
+                    UnknownPacketRef {
+                        payload: self.payload(),
+                        class,
+                        msg_id,
+                    }
+                }
             }
 
             pub(crate) fn match_packet(
@@ -806,14 +1556,10 @@ fn test_define_recv_packets() {
             ) -> Result<PacketOwned, ParserError> {
                 match (class, msg_id) {
                     (Pack1::CLASS, Pack1::ID) if <Pack1Owned>::validate(payload).is_ok() => {
-                        let mut bytes = [0u8; Pack1Owned::PACKET_SIZE];
-                        bytes.clone_from_slice(payload);
-                        Ok(PacketOwned::Pack1(Pack1Owned(bytes)))
+                        Ok(PacketOwned::Pack1(Pack1Ref(payload).into()))
                     },
                     (Pack2::CLASS, Pack2::ID) if <Pack2Owned>::validate(payload).is_ok() => {
-                        let mut bytes = [0u8; Pack2Owned::PACKET_SIZE];
-                        bytes.clone_from_slice(payload);
-                        Ok(PacketOwned::Pack2(Pack2Owned(bytes)))
+                        Ok(PacketOwned::Pack2(Pack2Ref(payload).into()))
                     },
                     _ => {
                         let mut payload_copy = [0u8; MAX_PAYLOAD_LEN as usize];
@@ -861,6 +1607,46 @@ fn test_define_recv_packets() {
                 max_u16(Pack1::MAX_PAYLOAD_LEN, 0u16),
             );
 
+            #[doc = "The packets of the enum, in the order they are matched"]
+            pub(crate) const SUPPORTED_MESSAGES: &[crate::PacketMeta] = &[
+                crate::PacketMeta {
+                    class: Pack1::CLASS,
+                    msg_id: Pack1::ID,
+                    name: Pack1::NAME,
+                    fixed_payload_len: Pack1::FIXED_PAYLOAD_LEN,
+                    max_payload_len: Pack1::MAX_PAYLOAD_LEN,
+                },
+                crate::PacketMeta {
+                    class: Pack2::CLASS,
+                    msg_id: Pack2::ID,
+                    name: Pack2::NAME,
+                    fixed_payload_len: Pack2::FIXED_PAYLOAD_LEN,
+                    max_payload_len: Pack2::MAX_PAYLOAD_LEN,
+                },
+            ];
+
+            #[cfg(feature = "defmt")]
+            impl defmt::Format for PacketRef<'_> {
+                fn format(&self, f: defmt::Formatter) {
+                    match *self {
+                        PacketRef::Pack1(ref packet) => packet.format(f),
+                        PacketRef::Pack2(ref packet) => packet.format(f),
+                        PacketRef::Unknown(ref pack) => pack.format(f),
+                    }
+                }
+            }
+
+            #[cfg(feature = "defmt")]
+            impl defmt::Format for PacketOwned {
+                fn format(&self, f: defmt::Formatter) {
+                    match *self {
+                        PacketOwned::Pack1(ref packet) => packet.format(f),
+                        PacketOwned::Pack2(ref packet) => packet.format(f),
+                        PacketOwned::Unknown(ref pack) => pack.format(f),
+                    }
+                }
+            }
+
             #[cfg(feature = "serde")]
             pub struct PacketSerializer<'a, T> {
                 class: u8,
@@ -905,6 +1691,35 @@ fn test_define_recv_packets() {
                     }
                 }
             }
+
+            #[cfg(feature = "serde")]
+            impl serde::Serialize for PacketOwned {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: serde::Serializer,
+                {
+                    match *self {
+                        PacketOwned::Pack1(ref msg) => PacketSerializer {
+                            class: Pack1::CLASS,
+                            msg_id: Pack1::ID,
+                            msg,
+                        }
+                        .serialize(serializer),
+                        PacketOwned::Pack2(ref msg) => PacketSerializer {
+                            class: Pack2::CLASS,
+                            msg_id: Pack2::ID,
+                            msg,
+                        }
+                        .serialize(serializer),
+                        PacketOwned::Unknown(ref pack) => UnknownPacketRef {
+                            payload: &pack.payload[..pack.payload_len],
+                            class: pack.class,
+                            msg_id: pack.msg_id,
+                        }
+                        .serialize(serializer),
+                    }
+                }
+            }
         },
     );
 }
@@ -969,6 +1784,13 @@ fn test_extend_bitflags() {
                     serializer.serialize_u8(self.bits())
                 }
             }
+
+            #[cfg(feature = "defmt")]
+            impl defmt::Format for Test {
+                fn format(&self, f: defmt::Formatter) {
+                    defmt::write!(f, "Test({:#x})", self.bits())
+                }
+            }
         },
     );
 }
@@ -1093,3 +1915,213 @@ fn panic_on_parse_error(name: &str, src_cnt: &str, err: &Error) -> ! {
         col_s = start.column,
     );
 }
+
+#[test]
+fn test_ubx_packet_send_setters() {
+    let src_code = quote! {
+        #[ubx_packet_send]
+        #[ubx(class = 1, id = 2, fixed_payload_len = 4, flags = "default_for_builder, setters")]
+        #[doc = "Some comment"]
+        struct Test {
+            #[ubx(map_type = TestParams)]
+            mask: u8,
+            #[doc = "this is a"]
+            #[ubx(apply_mask = TestParams::A)]
+            a: u8,
+            reserved1: u8,
+            b: u8,
+        }
+    };
+    let src_code = src_code.to_string();
+
+    let code: syn::ItemStruct = syn::parse_str(&src_code).unwrap_or_else(|err| {
+        panic_on_parse_error("test_ubx_packet_send_setters", &src_code, &err)
+    });
+    let tokens = generate_code_for_send_packet(code.ident, code.attrs, code.fields, code.generics)
+        .unwrap_or_else(|err| {
+            panic_on_parse_error("test_ubx_packet_send_setters", &src_code, &err)
+        });
+
+    run_compare_test(
+        tokens,
+        quote! {
+            #[doc = "Some comment"]
+            pub struct Test;
+
+            impl UbxPacketMeta for Test {
+                const CLASS: u8 = 1u8;
+                const ID: u8 = 2u8;
+                const NAME: &'static str = "Test";
+                const FIXED_PAYLOAD_LEN: Option<u16> = Some(4u16);
+                const MAX_PAYLOAD_LEN: u16 = 4u16;
+                const PAYLOAD_FIELDS: &'static [crate::PayloadField] = &[
+                    crate::PayloadField {
+                        name: "mask",
+                        offset: 0u16,
+                        size: 1u16,
+                        kind: crate::PayloadFieldKind::Data,
+                    },
+                    crate::PayloadField {
+                        name: "a",
+                        offset: 1u16,
+                        size: 1u16,
+                        kind: crate::PayloadFieldKind::Data,
+                    },
+                    crate::PayloadField {
+                        name: "reserved1",
+                        offset: 2u16,
+                        size: 1u16,
+                        kind: crate::PayloadFieldKind::Reserved,
+                    },
+                    crate::PayloadField {
+                        name: "b",
+                        offset: 3u16,
+                        size: 1u16,
+                        kind: crate::PayloadFieldKind::Data,
+                    },
+                ];
+                const PAYLOAD_FIELDS_LEN: u16 = 4u16;
+            }
+
+            impl Test {
+                    #[doc = "Byte offset of `mask` in the payload"]
+                    pub const OFFSET_MASK: usize = 0usize;
+                    #[doc = "Byte offset of `a` in the payload"]
+                    pub const OFFSET_A: usize = 1usize;
+                    #[doc = "Byte offset of `reserved1` in the payload"]
+                    pub const OFFSET_RESERVED1: usize = 2usize;
+                    #[doc = "Byte offset of `b` in the payload"]
+                    pub const OFFSET_B: usize = 3usize;
+            }
+
+            #[cfg(feature = "serde")]
+            impl Test {
+                    #[doc = "Field `mask`, to select it in a [Projection](crate::projection::Projection)"]
+                    pub const FIELD_MASK: crate::projection::Field =
+                        crate::projection::Field::new("mask");
+                    #[doc = "Field `a`, to select it in a [Projection](crate::projection::Projection)"]
+                    pub const FIELD_A: crate::projection::Field =
+                        crate::projection::Field::new("a");
+                    #[doc = "Field `reserved1`, to select it in a [Projection](crate::projection::Projection)"]
+                    pub const FIELD_RESERVED1: crate::projection::Field =
+                        crate::projection::Field::new("reserved1");
+                    #[doc = "Field `b`, to select it in a [Projection](crate::projection::Projection)"]
+                    pub const FIELD_B: crate::projection::Field =
+                        crate::projection::Field::new("b");
+            }
+
+            #[doc = "Some comment"]
+            #[doc = "Struct that is used to construct packets, see the crate-level documentation for more information"]
+            #[derive(Default)]
+            pub struct TestBuilder {
+                #[doc = ""]
+                pub mask: TestParams,
+                #[doc = "this is a"]
+                pub a: u8,
+                #[doc = ""]
+                pub reserved1: u8,
+                #[doc = ""]
+                pub b: u8,
+            }
+            impl TestBuilder {
+                pub const PACKET_LEN: usize = 12usize;
+
+                #[inline]
+                pub fn into_packet_bytes(self) -> [u8; Self::PACKET_LEN] {
+                    let mut ret = [0u8; Self::PACKET_LEN];
+                    ret[0] = crate::constants::UBX_SYNC_CHAR_1;
+                    ret[1] = crate::constants::UBX_SYNC_CHAR_2;
+                    ret[2] = Test::CLASS;
+                    ret[3] = Test::ID;
+                    let pack_len_bytes = 4u16.to_le_bytes();
+                    ret[4] = pack_len_bytes[0];
+                    ret[5] = pack_len_bytes[1];
+                    let bytes = <TestParams>::into_raw(self.mask).to_le_bytes();
+                    ret[6usize] = bytes[0usize];
+                    let bytes = self.a.to_le_bytes();
+                    ret[7usize] = bytes[0usize];
+                    let bytes = self.reserved1.to_le_bytes();
+                    ret[8usize] = bytes[0usize];
+                    let bytes = self.b.to_le_bytes();
+                    ret[9usize] = bytes[0usize];
+                    let (ck_a, ck_b) = ubx_checksum(&ret[2..(Self::PACKET_LEN - 2)]);
+                    ret[Self::PACKET_LEN - 2] = ck_a;
+                    ret[Self::PACKET_LEN - 1] = ck_b;
+                    ret
+                }
+
+                #[doc = r" Length of the frame, [Self::PACKET_LEN]"]
+                #[inline]
+                pub const fn frame_len(&self) -> usize {
+                    Self::PACKET_LEN
+                }
+
+                #[doc = r" Write the frame to the start of `buf`, e.g. a DMA buffer, returning its length"]
+                #[inline]
+                pub fn extend_to_slice(
+                    self,
+                    buf: &mut [u8]
+                ) -> Result<usize, crate::error::MemWriterError<core::convert::Infallible>> {
+                    let out = buf
+                        .get_mut(..Self::PACKET_LEN)
+                        .ok_or(crate::error::MemWriterError::NotEnoughMem)?;
+                    out.copy_from_slice(&self.into_packet_bytes());
+                    Ok(Self::PACKET_LEN)
+                }
+            }
+            impl From<TestBuilder> for [u8; 12usize] {
+                fn from(x: TestBuilder) -> Self {
+                    x.into_packet_bytes()
+                }
+            }
+            impl UbxPacketCreator for TestBuilder {
+                #[inline]
+                fn create_packet<T: MemWriter>(self, out: &mut T) -> Result<(), MemWriterError<T::Error>> {
+                    out.reserve_allocate(12usize)?;
+                    let len_bytes = 4u16.to_le_bytes();
+                    let header = [
+                        crate::constants::UBX_SYNC_CHAR_1,
+                        crate::constants::UBX_SYNC_CHAR_2,
+                        Test::CLASS,
+                        Test::ID,
+                        len_bytes[0],
+                        len_bytes[1],
+                    ];
+                    out.write(&header)?;
+                    let mut checksum_calc = crate::ubx_packets::UbxChecksumCalc::default();
+                    checksum_calc.update(&header[2..]);
+                    let bytes = <TestParams>::into_raw(self.mask).to_le_bytes();
+                    out.write(&bytes)?;
+                    checksum_calc.update(&bytes);
+                    let bytes = self.a.to_le_bytes();
+                    out.write(&bytes)?;
+                    checksum_calc.update(&bytes);
+                    let bytes = self.reserved1.to_le_bytes();
+                    out.write(&bytes)?;
+                    checksum_calc.update(&bytes);
+                    let bytes = self.b.to_le_bytes();
+                    out.write(&bytes)?;
+                    checksum_calc.update(&bytes);
+                    let (ck_a, ck_b) = checksum_calc.result();
+                    out.write(&[ck_a, ck_b])?;
+                    Ok(())
+                }
+            }
+            impl TestBuilder {
+                #[doc = "this is a"]
+                #[inline]
+                pub fn set_a(mut self, a: u8) -> Self {
+                    self.a = a;
+                    self.mask |= TestParams::A;
+                    self
+                }
+                #[doc = ""]
+                #[inline]
+                pub fn set_b(mut self, b: u8) -> Self {
+                    self.b = b;
+                    self
+                }
+            }
+        },
+    );
+}
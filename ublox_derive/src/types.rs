@@ -1,7 +1,7 @@
 use packetflag::PacketFlag;
 use packfield::PackField;
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{quote, ToTokens};
 use syn::{Attribute, Generics, Ident, Lifetime, Type};
 
 pub(crate) mod packetflag;
@@ -23,6 +23,25 @@ impl PackDesc {
         PackDesc::fields_size(self.fields.iter())
     }
 
+    /// The field named `name` and its offset, if all fields before it have a fixed size
+    pub fn field_with_offset(&self, name: &Ident) -> Option<(&PackField, usize)> {
+        let mut off = 0;
+        for f in &self.fields {
+            if f.name == *name {
+                return Some((f, off));
+            }
+            off += f.size_bytes?.get();
+        }
+        None
+    }
+
+    /// Whether the field at offset `off` is past the `min_payload_len` of the packet
+    pub fn is_optional_at(&self, off: usize) -> bool {
+        self.header
+            .min_payload_len
+            .is_some_and(|min| off >= usize::from(min))
+    }
+
     pub fn packet_payload_size_except_last_field(&self) -> Option<usize> {
         PackDesc::fields_size(self.fields.iter().rev().skip(1))
     }
@@ -42,6 +61,25 @@ impl PackDesc {
         Some(ret)
     }
 
+    /// The parameter mask field an `apply_mask` bit such as `CfgNav5Params::DYN` belongs to,
+    /// i.e. the field mapped to the bit's type
+    pub fn mask_field(&self, bit: &syn::Path) -> Option<&PackField> {
+        let mask_ty = syn::Path {
+            leading_colon: bit.leading_colon,
+            segments: bit
+                .segments
+                .iter()
+                .take(bit.segments.len().saturating_sub(1))
+                .cloned()
+                .collect(),
+        };
+        let mask_ty = mask_ty.into_token_stream().to_string();
+        self.fields.iter().find(|f| {
+            f.has_intermediate_type()
+                && f.intermediate_type().into_token_stream().to_string() == mask_ty
+        })
+    }
+
     /// Returns lifetimes if the packet has any on the form `<'a, 'b, 'c>`
     pub(crate) fn lifetime_tokens(&self) -> Option<TokenStream> {
         let lifetimes: Vec<Lifetime> = self
@@ -64,6 +102,9 @@ pub struct PackHeader {
     pub class: u8,
     pub id: u8,
     pub payload_len: PayloadLen,
+    /// Payload length of the older versions of a packet growing at the end, the fields
+    /// past it are optional
+    pub min_payload_len: Option<u16>,
     pub flags: Vec<PacketFlag>,
 }
 
@@ -5,6 +5,8 @@ use crate::input::keyword;
 #[derive(PartialEq, Eq, Clone, Copy)]
 pub enum PacketFlag {
     DefaultForBuilder,
+    /// Generate a `set_<field>` method on the builder for every field but the reserved ones
+    Setters,
 }
 
 impl Parse for PacketFlag {
@@ -14,6 +16,9 @@ impl Parse for PacketFlag {
         if lookahead.peek(keyword::default_for_builder) {
             input.parse::<keyword::default_for_builder>()?;
             Ok(PacketFlag::DefaultForBuilder)
+        } else if lookahead.peek(keyword::setters) {
+            input.parse::<keyword::setters>()?;
+            Ok(PacketFlag::Setters)
         } else {
             Err(lookahead.error())
         }
@@ -38,6 +38,27 @@ impl PackField {
     pub fn intermediate_field_name(&self) -> &Ident {
         self.map.alias.as_ref().unwrap_or(&self.name)
     }
+    /// Name of the accessor returning the value as stored in the payload
+    pub fn raw_field_name(&self) -> Ident {
+        let name = if self.map.unit.is_some() {
+            &self.name
+        } else {
+            self.intermediate_field_name()
+        };
+        quote::format_ident!("{}_raw", name)
+    }
+    /// Type of the blocks of a repeated group, `NavSatSvInfo` for `[NavSatSvInfo]`
+    pub fn repeated_block(&self) -> Option<&Type> {
+        match self.ty {
+            Type::Slice(ref slice) if self.map.repeat.is_some() => Some(&slice.elem),
+            _ => None,
+        }
+    }
+    /// Whether the field was declared as `u24` or `i24`: three little-endian bytes, accessed as
+    /// `u32` or `i32`
+    pub fn is_int24(&self) -> bool {
+        self.size_bytes.is_some_and(|size| size.get() == 3) && !self.is_field_raw_ty_byte_array()
+    }
     pub fn is_field_raw_ty_byte_array(&self) -> bool {
         if let syn::Type::Array(ref fixed_array) = self.ty {
             *fixed_array.elem == syn::parse_quote!(u8)
@@ -9,8 +9,16 @@ pub struct PackFieldMapDesc {
     pub map_type: Option<MapTypeDesc>,
     pub scale: Option<syn::LitFloat>,
     pub alias: Option<Ident>,
+    /// Unit of the scaled value, the raw value keeps the field name
+    pub unit: Option<syn::LitStr>,
+    /// Field holding the number of blocks of a repeated group
+    pub repeat: Option<Ident>,
+    /// Fixed size character field, read as the text up to its NUL padding
+    pub chars: bool,
     pub convert_may_fail: bool,
     pub get_as_ref: bool,
+    /// Bit of a parameter mask field to set when the field is set through the builder
+    pub apply_mask: Option<syn::Path>,
 }
 
 #[derive(Debug)]
@@ -67,8 +75,12 @@ impl PackFieldMapDesc {
             map_type,
             scale: x.scale,
             alias: x.alias,
+            unit: x.unit,
+            repeat: x.repeat,
+            chars: x.chars,
             convert_may_fail: x.convert_may_fail,
             get_as_ref: x.get_as_ref,
+            apply_mask: x.apply_mask,
         }
     }
 }